@@ -1,5 +1,5 @@
 use calico_consensus_core::{
-    config::Config,
+    config::{assume_valid::AssumeValid, retention::RetentionPolicy, Config},
     network::{NetworkId, NetworkType},
 };
 use calico_core::calicod_env::version;
@@ -36,8 +36,16 @@ pub struct Args {
     pub rpclisten_borsh: Option<WrpcNetAddress>,
     #[serde_as(as = "Option<DisplayFromStr>")]
     pub rpclisten_json: Option<WrpcNetAddress>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub rpclisten_http: Option<ContextualNetAddress>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub metrics_listen: Option<ContextualNetAddress>,
     #[serde(rename = "unsaferpc")]
     pub unsafe_rpc: bool,
+    pub rpc_auth_config: Option<String>,
+    pub rpc_tls_cert: Option<String>,
+    pub rpc_tls_key: Option<String>,
+    pub rpc_tls_client_ca: Option<String>,
     pub wrpc_verbose: bool,
     #[serde(rename = "loglevel")]
     pub log_level: String,
@@ -53,13 +61,22 @@ pub struct Args {
     #[serde(rename = "uacomment")]
     pub user_agent_comments: Vec<String>,
     pub utxoindex: bool,
+    pub chainindex: bool,
     pub reset_db: bool,
+    pub reindex: bool,
+    pub backup_dir: Option<String>,
+    pub restore_from: Option<String>,
     #[serde(rename = "outpeers")]
     pub outbound_target: usize,
     #[serde(rename = "maxinpeers")]
     pub inbound_limit: usize,
     #[serde(rename = "rpcmaxclients")]
     pub rpc_max_clients: usize,
+    pub rpc_rate_limit: u64,
+    pub rpc_ip_rate_limit: u64,
+    pub rpc_notification_replay: usize,
+    pub rpc_notification_replay_size: usize,
+    pub rpc_notification_replay_on_disk: bool,
     pub max_tracked_addresses: usize,
     pub enable_unsynced_mining: bool,
     pub enable_mainnet_mining: bool,
@@ -69,12 +86,20 @@ pub struct Args {
     pub devnet: bool,
     pub simnet: bool,
     pub archival: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub retention: RetentionPolicy,
+    #[serde_as(as = "DisplayFromStr")]
+    pub assume_valid: AssumeValid,
     pub sanity: bool,
+    pub audit: bool,
     pub yes: bool,
     #[serde_as(as = "Option<DisplayFromStr>")]
     pub externalip: Option<ContextualNetAddress>,
     pub perf_metrics: bool,
     pub perf_metrics_interval_sec: u64,
+    pub metrics_history_seconds: usize,
+    pub metrics_history_minutes: usize,
+    pub metrics_history_hours: usize,
     pub block_template_cache_lifetime: Option<u64>,
 
     #[cfg(feature = "devnet-prealloc")]
@@ -99,13 +124,28 @@ impl Default for Args {
             no_log_files: false,
             rpclisten_borsh: None,
             rpclisten_json: None,
+            rpclisten_http: None,
+            metrics_listen: None,
             unsafe_rpc: false,
+            rpc_auth_config: None,
+            rpc_tls_cert: None,
+            rpc_tls_key: None,
+            rpc_tls_client_ca: None,
             async_threads: num_cpus::get(),
             utxoindex: false,
+            chainindex: false,
             reset_db: false,
+            reindex: false,
+            backup_dir: None,
+            restore_from: None,
             outbound_target: 8,
             inbound_limit: 128,
             rpc_max_clients: 128,
+            rpc_rate_limit: 0,
+            rpc_ip_rate_limit: 0,
+            rpc_notification_replay: 0,
+            rpc_notification_replay_size: 64,
+            rpc_notification_replay_on_disk: false,
             max_tracked_addresses: 0,
             enable_unsynced_mining: false,
             enable_mainnet_mining: true,
@@ -114,7 +154,10 @@ impl Default for Args {
             devnet: false,
             simnet: false,
             archival: false,
+            retention: RetentionPolicy::Full,
+            assume_valid: AssumeValid::NetworkDefault,
             sanity: false,
+            audit: false,
             logdir: None,
             rpclisten: None,
             wrpc_verbose: false,
@@ -126,6 +169,9 @@ impl Default for Args {
             yes: false,
             perf_metrics: false,
             perf_metrics_interval_sec: 10,
+            metrics_history_seconds: 3_600,
+            metrics_history_minutes: 1_440,
+            metrics_history_hours: 720,
             externalip: None,
             block_template_cache_lifetime: None,
 
@@ -147,13 +193,17 @@ impl Default for Args {
 impl Args {
     pub fn apply_to_config(&self, config: &mut Config) {
         config.utxoindex = self.utxoindex;
+        config.chainindex = self.chainindex;
         config.disable_upnp = self.disable_upnp;
         config.unsafe_rpc = self.unsafe_rpc;
         config.enable_unsynced_mining = self.enable_unsynced_mining;
         config.enable_mainnet_mining = self.enable_mainnet_mining;
         config.is_archival = self.archival;
+        config.retention = self.retention;
+        config.assume_valid = self.assume_valid;
         // TODO: change to `config.enable_sanity_checks = self.sanity` when we reach stable versions
         config.enable_sanity_checks = true;
+        config.enable_audit = self.audit;
         config.user_agent_comments.clone_from(&self.user_agent_comments);
         config.block_template_cache_lifetime = self.block_template_cache_lifetime;
         config.p2p_listen_address = self.listen.unwrap_or(ContextualNetAddress::unspecified());
@@ -250,7 +300,59 @@ pub fn cli() -> Command {
                 .value_parser(clap::value_parser!(WrpcNetAddress))
                 .help("Interface:port to listen for wRPC JSON connections (default port: 24110, testnet: 24210)."),
         )
+        .arg(
+            Arg::new("rpclisten-http")
+                .long("rpclisten-http")
+                .value_name("IP[:PORT]")
+                .num_args(0..=1)
+                .require_equals(true)
+                .default_missing_value("127.0.0.1")
+                .value_parser(clap::value_parser!(ContextualNetAddress))
+                .help("Interface:port to listen for JSON-RPC 2.0 over HTTP requests (default: 127.0.0.1, port: 25110, testnet: 25210)."),
+        )
+        .arg(
+            Arg::new("metrics-listen")
+                .long("metrics-listen")
+                .value_name("IP[:PORT]")
+                .num_args(0..=1)
+                .require_equals(true)
+                .default_missing_value("127.0.0.1")
+                .value_parser(clap::value_parser!(ContextualNetAddress))
+                .help("Interface:port to serve OpenMetrics scrapes on /metrics (default: 127.0.0.1, port: 26110, testnet: 26210)."),
+        )
         .arg(arg!(--unsaferpc "Enable RPC commands which affect the state of the node"))
+        .arg(
+            Arg::new("rpc-auth-config")
+                .long("rpc-auth-config")
+                .value_name("FILE")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(String))
+                .help("Path of an RPC authorization config file (API keys, roles and per-method permissions)."),
+        )
+        .arg(
+            Arg::new("rpc-tls-cert")
+                .long("rpc-tls-cert")
+                .value_name("FILE")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(String))
+                .help("Path of the PEM certificate chain served by the gRPC and wRPC servers (enables TLS, reloaded on SIGHUP)."),
+        )
+        .arg(
+            Arg::new("rpc-tls-key")
+                .long("rpc-tls-key")
+                .value_name("FILE")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(String))
+                .help("Path of the PEM private key of the RPC TLS certificate (reloaded on SIGHUP)."),
+        )
+        .arg(
+            Arg::new("rpc-tls-client-ca")
+                .long("rpc-tls-client-ca")
+                .value_name("FILE")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(String))
+                .help("Path of the PEM CA certificates verifying RPC client certificates (enables mutual TLS)."),
+        )
         .arg(
             Arg::new("connect-peers")
                 .long("connect")
@@ -301,7 +403,57 @@ pub fn cli() -> Command {
                 .value_parser(clap::value_parser!(usize))
                 .help("Max number of RPC clients for standard connections (default: 128)."),
         )
+        .arg(
+            Arg::new("rpc-rate-limit")
+                .long("rpc-rate-limit")
+                .value_name("UNITS/S")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help("Max RPC request cost units per second of every RPC connection, bursts up to 10 seconds (default: 0, unlimited)."),
+        )
+        .arg(
+            Arg::new("rpc-ip-rate-limit")
+                .long("rpc-ip-rate-limit")
+                .value_name("UNITS/S")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help("Max RPC request cost units per second of all RPC connections from the same IP (default: 0, unlimited)."),
+        )
+        .arg(
+            Arg::new("rpc-notification-replay")
+                .long("rpc-notification-replay")
+                .value_name("COUNT")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help("Number of VirtualChainChanged and UtxosChanged notifications retained by the wRPC servers for clients resuming a subscription (default: 0, disabled)."),
+        )
+        .arg(
+            Arg::new("rpc-notification-replay-size")
+                .long("rpc-notification-replay-size")
+                .value_name("MB")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help("Maximum size in megabytes of the notifications retained by each wRPC server for clients resuming a subscription (default: 64)."),
+        )
+        .arg(arg!(--"rpc-notification-replay-on-disk" "Retain the notifications of resumable subscriptions in files under the application directory instead of memory."))
         .arg(arg!(--"reset-db" "Reset database before starting node. It's needed when switching between subnetworks."))
+        .arg(arg!(--"reindex" "Rebuild the consensus state derived from the stored headers and block bodies before starting the node."))
+        .arg(
+            Arg::new("backup-dir")
+                .long("backup-dir")
+                .value_name("DIR")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(String))
+                .help("Directory of the database checkpoints created on SIGUSR1 or by RPC without a target directory (default: <appdir>/<network>/backups)."),
+        )
+        .arg(
+            Arg::new("restore-from")
+                .long("restore-from")
+                .value_name("DIR")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(String))
+                .help("Replace the node databases with a database checkpoint before starting the node."),
+        )
         .arg(arg!(--"enable-unsynced-mining" "Allow the node to accept blocks from RPC while not synced (this flag is mainly used for testing)"))
         .arg(
             Arg::new("enable-mainnet-mining")
@@ -311,6 +463,7 @@ pub fn cli() -> Command {
                 .help("Allow mainnet mining (currently enabled by default while the flag is kept for backwards compatibility)"),
         )
         .arg(arg!(--utxoindex "Enable the UTXO index"))
+        .arg(arg!(--chainindex "Enable the chain block index by DAA score and timestamp"))
         .arg(
             Arg::new("max-tracked-addresses")
                 .long("max-tracked-addresses")
@@ -332,7 +485,24 @@ Setting to 0 prevents the preallocation and sets the maximum to {}, leading to 0
         .arg(arg!(--devnet "Use the development test network"))
         .arg(arg!(--simnet "Use the simulation test network"))
         .arg(arg!(--archival "Run as an archival node: avoids deleting old block data when moving the pruning point (Warning: heavy disk usage)"))
+        .arg(
+            Arg::new("retention")
+                .long("retention")
+                .value_name("POLICY")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(RetentionPolicy))
+                .help("Block data kept by an archival node below the pruning point: full (default), bodies:<days> (headers forever, bodies for the given number of days), chain-bodies (only selected chain block bodies) or acceptance-data (acceptance data without bodies). Requires --archival."),
+        )
+        .arg(
+            Arg::new("assume-valid")
+                .long("assume-valid")
+                .value_name("HASH")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(AssumeValid))
+                .help("Skip transaction script checks for blocks in the past of the given block during IBD: default (the block hard-coded for the network; none ships yet, so all blocks are currently fully validated), none (fully validate all blocks) or a block hash. Structure, PoW, mass and UTXO validation are always performed."),
+        )
         .arg(arg!(--sanity "Enable various sanity checks which might be compute-intensive (mostly performed during pruning)"))
+        .arg(arg!(--audit "Continuously re-verify consensus invariants of new chain blocks in the background and report violations to the audit log and the ConsensusAuditViolation notification (testnet, devnet and simnet only)"))
         .arg(arg!(--yes "Answer yes to all interactive console questions"))
         .arg(
             Arg::new("user_agent_comments")
@@ -358,6 +528,27 @@ Setting to 0 prevents the preallocation and sets the maximum to {}, leading to 0
                 .value_parser(clap::value_parser!(u64))
                 .help("Interval in seconds for performance metrics collection."),
         )
+        .arg(
+            Arg::new("metrics-history-seconds")
+                .long("metrics-history-seconds")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help("Number of 1 second samples retained in the metrics history (default: 3600, 0 to disable)."),
+        )
+        .arg(
+            Arg::new("metrics-history-minutes")
+                .long("metrics-history-minutes")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help("Number of 1 minute samples retained in the metrics history (default: 1440, 0 to disable)."),
+        )
+        .arg(
+            Arg::new("metrics-history-hours")
+                .long("metrics-history-hours")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help("Number of 1 hour samples retained in the metrics history (default: 720, 0 to disable)."),
+        )
         .arg(arg!(--"disable-upnp" "Disable upnp"))
         .arg(arg!(--"nodnsseed" "Disable DNS seeding for peers"))
         .arg(arg!(--"nogrpc" "Disable gRPC server"))
@@ -416,7 +607,13 @@ impl Args {
             rpclisten: m.get_one::<ContextualNetAddress>("rpclisten").cloned().or(defaults.rpclisten),
             rpclisten_borsh: m.get_one::<WrpcNetAddress>("rpclisten-borsh").cloned().or(defaults.rpclisten_borsh),
             rpclisten_json: m.get_one::<WrpcNetAddress>("rpclisten-json").cloned().or(defaults.rpclisten_json),
+            rpclisten_http: m.get_one::<ContextualNetAddress>("rpclisten-http").cloned().or(defaults.rpclisten_http),
+            metrics_listen: m.get_one::<ContextualNetAddress>("metrics-listen").cloned().or(defaults.metrics_listen),
             unsafe_rpc: arg_match_unwrap_or::<bool>(&m, "unsaferpc", defaults.unsafe_rpc),
+            rpc_auth_config: m.get_one::<String>("rpc-auth-config").cloned().or(defaults.rpc_auth_config),
+            rpc_tls_cert: m.get_one::<String>("rpc-tls-cert").cloned().or(defaults.rpc_tls_cert),
+            rpc_tls_key: m.get_one::<String>("rpc-tls-key").cloned().or(defaults.rpc_tls_key),
+            rpc_tls_client_ca: m.get_one::<String>("rpc-tls-client-ca").cloned().or(defaults.rpc_tls_client_ca),
             wrpc_verbose: false,
             log_level: arg_match_unwrap_or::<String>(&m, "log_level", defaults.log_level),
             async_threads: arg_match_unwrap_or::<usize>(&m, "async_threads", defaults.async_threads),
@@ -426,22 +623,45 @@ impl Args {
             outbound_target: arg_match_unwrap_or::<usize>(&m, "outpeers", defaults.outbound_target),
             inbound_limit: arg_match_unwrap_or::<usize>(&m, "maxinpeers", defaults.inbound_limit),
             rpc_max_clients: arg_match_unwrap_or::<usize>(&m, "rpcmaxclients", defaults.rpc_max_clients),
+            rpc_rate_limit: arg_match_unwrap_or::<u64>(&m, "rpc-rate-limit", defaults.rpc_rate_limit),
+            rpc_ip_rate_limit: arg_match_unwrap_or::<u64>(&m, "rpc-ip-rate-limit", defaults.rpc_ip_rate_limit),
+            rpc_notification_replay: arg_match_unwrap_or::<usize>(&m, "rpc-notification-replay", defaults.rpc_notification_replay),
+            rpc_notification_replay_size: arg_match_unwrap_or::<usize>(
+                &m,
+                "rpc-notification-replay-size",
+                defaults.rpc_notification_replay_size,
+            ),
+            rpc_notification_replay_on_disk: arg_match_unwrap_or::<bool>(
+                &m,
+                "rpc-notification-replay-on-disk",
+                defaults.rpc_notification_replay_on_disk,
+            ),
             max_tracked_addresses: arg_match_unwrap_or::<usize>(&m, "max-tracked-addresses", defaults.max_tracked_addresses),
             reset_db: arg_match_unwrap_or::<bool>(&m, "reset-db", defaults.reset_db),
+            reindex: arg_match_unwrap_or::<bool>(&m, "reindex", defaults.reindex),
+            backup_dir: m.get_one::<String>("backup-dir").cloned().or(defaults.backup_dir),
+            restore_from: m.get_one::<String>("restore-from").cloned().or(defaults.restore_from),
             enable_unsynced_mining: arg_match_unwrap_or::<bool>(&m, "enable-unsynced-mining", defaults.enable_unsynced_mining),
             enable_mainnet_mining: arg_match_unwrap_or::<bool>(&m, "enable-mainnet-mining", defaults.enable_mainnet_mining),
             utxoindex: arg_match_unwrap_or::<bool>(&m, "utxoindex", defaults.utxoindex),
+            chainindex: arg_match_unwrap_or::<bool>(&m, "chainindex", defaults.chainindex),
             testnet: arg_match_unwrap_or::<bool>(&m, "testnet", defaults.testnet),
             testnet_suffix: arg_match_unwrap_or::<u32>(&m, "netsuffix", defaults.testnet_suffix),
             devnet: arg_match_unwrap_or::<bool>(&m, "devnet", defaults.devnet),
             simnet: arg_match_unwrap_or::<bool>(&m, "simnet", defaults.simnet),
             archival: arg_match_unwrap_or::<bool>(&m, "archival", defaults.archival),
+            retention: arg_match_unwrap_or::<RetentionPolicy>(&m, "retention", defaults.retention),
+            assume_valid: arg_match_unwrap_or::<AssumeValid>(&m, "assume-valid", defaults.assume_valid),
             sanity: arg_match_unwrap_or::<bool>(&m, "sanity", defaults.sanity),
+            audit: arg_match_unwrap_or::<bool>(&m, "audit", defaults.audit),
             yes: arg_match_unwrap_or::<bool>(&m, "yes", defaults.yes),
             user_agent_comments: arg_match_many_unwrap_or::<String>(&m, "user_agent_comments", defaults.user_agent_comments),
             externalip: m.get_one::<ContextualNetAddress>("externalip").cloned(),
             perf_metrics: arg_match_unwrap_or::<bool>(&m, "perf-metrics", defaults.perf_metrics),
             perf_metrics_interval_sec: arg_match_unwrap_or::<u64>(&m, "perf-metrics-interval-sec", defaults.perf_metrics_interval_sec),
+            metrics_history_seconds: arg_match_unwrap_or::<usize>(&m, "metrics-history-seconds", defaults.metrics_history_seconds),
+            metrics_history_minutes: arg_match_unwrap_or::<usize>(&m, "metrics-history-minutes", defaults.metrics_history_minutes),
+            metrics_history_hours: arg_match_unwrap_or::<usize>(&m, "metrics-history-hours", defaults.metrics_history_hours),
             // Note: currently used programmatically by benchmarks and not exposed to CLI users
             block_template_cache_lifetime: defaults.block_template_cache_lifetime,
             disable_upnp: arg_match_unwrap_or::<bool>(&m, "disable-upnp", defaults.disable_upnp),
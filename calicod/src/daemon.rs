@@ -1,17 +1,33 @@
-use std::{fs, path::PathBuf, process::exit, sync::Arc, time::Duration};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::exit,
+    sync::Arc,
+    time::Duration,
+};
 
 use async_channel::unbounded;
+use calico_chainindex::{api::ChainIndexProxy, ChainIndex};
 use calico_consensus_core::{
-    config::ConfigBuilder,
+    config::{retention::RetentionPolicy, ConfigBuilder},
     errors::config::{ConfigError, ConfigResult},
 };
 use calico_consensus_notify::{root::ConsensusNotificationRoot, service::NotifyService};
-use calico_core::{calicod_env::version, task::tick::TickService};
+use calico_core::{
+    calicod_env::{get_app_dir, get_home_dir, version},
+    task::tick::TickService,
+};
 use calico_core::{core::Core, debug, info};
 use calico_database::prelude::CachePolicy;
 use calico_grpc_server::service::GrpcService;
-use calico_notify::{address::tracker::Tracker, subscription::context::SubscriptionContext};
-use calico_rpc_service::service::RpcCoreService;
+use calico_notify::{address::tracker::Tracker, replay::ReplayOptions, subscription::context::SubscriptionContext};
+use calico_rpc_service::{
+    auth::{RpcAuthConfig, RpcAuthorizer},
+    checkpoint::DatabaseCheckpointService,
+    rate_limit::{RpcRateLimitConfig, RpcRateLimiter},
+    service::RpcCoreService,
+    tls::{RpcTlsConfig, RpcTlsOptions},
+};
 use calico_txscript::caches::TxScriptCacheCounters;
 use calico_utils::git;
 use calico_utils::networking::ContextualNetAddress;
@@ -26,6 +42,7 @@ use calico_consensus::{
 use calico_consensusmanager::ConsensusManager;
 use calico_core::task::runtime::AsyncRuntime;
 use calico_index_processor::service::IndexService;
+use calico_metrics_core::history::MetricsHistoryConfig;
 use calico_mining::{
     manager::{MiningManager, MiningManagerProxy},
     monitor::MiningMonitor,
@@ -33,6 +50,10 @@ use calico_mining::{
 };
 use calico_p2p_flows::{flow_context::FlowContext, service::P2pService};
 
+use calico_http_server::{
+    metrics::{MetricsOptions, MetricsService},
+    service::{HttpService, Options as HttpServerOptions},
+};
 use calico_perf_monitor::{builder::Builder as PerfMonitorBuilder, counters::CountersSnapshot};
 use calico_utxoindex::{api::UtxoIndexProxy, UtxoIndex};
 use calico_wrpc_server::service::{Options as WrpcServerOptions, WebSocketCounters as WrpcServerCounters, WrpcEncoding, WrpcService};
@@ -46,29 +67,17 @@ pub const DESIRED_DAEMON_SOFT_FD_LIMIT: u64 = 8 * 1024;
 /// this value may impact the database performance).
 pub const MINIMUM_DAEMON_SOFT_FD_LIMIT: u64 = 4 * 1024;
 
-use crate::args::Args;
+use crate::{args::Args, reindex::reindex, restore::restore};
 
 const DEFAULT_DATA_DIR: &str = "datadir";
-const CONSENSUS_DB: &str = "consensus";
+const DEFAULT_BACKUP_DIR: &str = "backups";
+pub const CONSENSUS_DB: &str = "consensus";
 const UTXOINDEX_DB: &str = "utxoindex";
-const META_DB: &str = "meta";
+const CHAININDEX_DB: &str = "chainindex";
+pub const META_DB: &str = "meta";
 const META_DB_FILE_LIMIT: i32 = 5;
 const DEFAULT_LOG_DIR: &str = "logs";
-
-fn get_home_dir() -> PathBuf {
-    #[cfg(target_os = "windows")]
-    return dirs::data_local_dir().unwrap();
-    #[cfg(not(target_os = "windows"))]
-    return dirs::home_dir().unwrap();
-}
-
-/// Get the default application directory.
-pub fn get_app_dir() -> PathBuf {
-    #[cfg(target_os = "windows")]
-    return get_home_dir().join("rusty-calico");
-    #[cfg(not(target_os = "windows"))]
-    return get_home_dir().join(".rusty-calico");
-}
+const NOTIFICATION_REPLAY_DIR: &str = "replay";
 
 pub fn validate_args(args: &Args) -> ConfigResult<()> {
     #[cfg(feature = "devnet-prealloc")]
@@ -97,6 +106,24 @@ pub fn validate_args(args: &Args) -> ConfigResult<()> {
     if args.max_tracked_addresses > Tracker::MAX_ADDRESS_UPPER_BOUND {
         return Err(ConfigError::MaxTrackedAddressesTooHigh(Tracker::MAX_ADDRESS_UPPER_BOUND));
     }
+    if args.rpc_tls_cert.is_some() ^ args.rpc_tls_key.is_some() {
+        return Err(ConfigError::MissingRpcTlsCertOrKey);
+    }
+    if args.rpc_tls_client_ca.is_some() && args.rpc_tls_cert.is_none() {
+        return Err(ConfigError::RpcTlsClientCaWithoutTls);
+    }
+    if args.reindex && args.reset_db {
+        return Err(ConfigError::MixedReindexAndResetDb);
+    }
+    if args.restore_from.is_some() && (args.reset_db || args.reindex) {
+        return Err(ConfigError::MixedRestoreAndResetDbOrReindex);
+    }
+    if args.retention != RetentionPolicy::Full && !args.archival {
+        return Err(ConfigError::RetentionWithoutArchival);
+    }
+    if args.audit && !(args.testnet || args.devnet || args.simnet) {
+        return Err(ConfigError::AuditOnMainnet);
+    }
     Ok(())
 }
 
@@ -216,6 +243,13 @@ pub fn create_core_with_runtime(runtime: &Runtime, args: &Args, fd_total_budget:
     } else {
         0
     };
+    let chainindex_files_limit = if args.chainindex {
+        let chainindex_files_limit = fd_remaining * 5 / 100;
+        fd_remaining -= chainindex_files_limit;
+        chainindex_files_limit
+    } else {
+        0
+    };
     // Make sure args forms a valid set of properties
     if let Err(err) = validate_args(args) {
         println!("{}", err);
@@ -233,6 +267,7 @@ pub fn create_core_with_runtime(runtime: &Runtime, args: &Args, fd_total_budget:
 
     let app_dir = get_app_dir_from_args(args);
     let db_dir = app_dir.join(network.to_prefixed()).join(DEFAULT_DATA_DIR);
+    let replay_dir = app_dir.join(network.to_prefixed()).join(NOTIFICATION_REPLAY_DIR);
 
     // Print package name and version
     info!("{} v{}", env!("CARGO_PKG_NAME"), git::with_short_hash(version()));
@@ -251,6 +286,7 @@ pub fn create_core_with_runtime(runtime: &Runtime, args: &Args, fd_total_budget:
 
     let consensus_db_dir = db_dir.join(CONSENSUS_DB);
     let utxoindex_db_dir = db_dir.join(UTXOINDEX_DB);
+    let chainindex_db_dir = db_dir.join(CHAININDEX_DB);
     let meta_db_dir = db_dir.join(META_DB);
 
     let mut is_db_reset_needed = args.reset_db;
@@ -264,12 +300,30 @@ do you confirm? (answer y/n or pass --yes to the Calicod command line to confirm
         fs::remove_dir_all(&db_dir).unwrap();
     }
 
+    // Restore Condition: User requested to replace the databases with a database checkpoint
+    if let Some(restore_from) = args.restore_from.as_ref() {
+        let msg = format!(
+            "Restore from {restore_from} was requested -- this means the current databases will be fully replaced by the checkpoint,
+do you confirm? (answer y/n or pass --yes to the Calicod command line to confirm all interactive questions)"
+        );
+        get_user_approval_or_exit(&msg, args.yes);
+        info!("Restoring databases from {}", restore_from);
+        if let Err(err) = restore(Path::new(restore_from), &db_dir, config.genesis.hash) {
+            println!("Restore failed: {err}");
+            exit(1);
+        }
+    }
+
     fs::create_dir_all(consensus_db_dir.as_path()).unwrap();
     fs::create_dir_all(meta_db_dir.as_path()).unwrap();
     if args.utxoindex {
         info!("Utxoindex Data directory {}", utxoindex_db_dir.display());
         fs::create_dir_all(utxoindex_db_dir.as_path()).unwrap();
     }
+    if args.chainindex {
+        info!("Chainindex Data directory {}", chainindex_db_dir.display());
+        fs::create_dir_all(chainindex_db_dir.as_path()).unwrap();
+    }
 
     // DB used for addresses store and for multi-consensus management
     let mut meta_db = calico_database::prelude::ConnBuilder::default()
@@ -341,6 +395,9 @@ do you confirm? (answer y/n or pass --yes to the Calicod command line to confirm
         if args.utxoindex {
             fs::create_dir_all(utxoindex_db_dir.as_path()).unwrap();
         }
+        if args.chainindex {
+            fs::create_dir_all(chainindex_db_dir.as_path()).unwrap();
+        }
 
         // Reopen the DB
         meta_db = calico_database::prelude::ConnBuilder::default()
@@ -392,6 +449,12 @@ do you confirm? (answer y/n or pass --yes to the Calicod command line to confirm
         tx_script_cache_counters.clone(),
         fd_remaining,
     ));
+    if args.reindex {
+        if let Err(err) = reindex(&consensus_factory, &config) {
+            println!("Reindex failed: {err}");
+            exit(1);
+        }
+    }
     let consensus_manager = Arc::new(ConsensusManager::new(consensus_factory));
     let consensus_monitor = Arc::new(ConsensusMonitor::new(processing_counters.clone(), tick_service.clone()));
 
@@ -413,15 +476,26 @@ do you confirm? (answer y/n or pass --yes to the Calicod command line to confirm
     let system_info = SystemInfo::default();
 
     let notify_service = Arc::new(NotifyService::new(notification_root.clone(), notification_recv, subscription_context.clone()));
-    let index_service: Option<Arc<IndexService>> = if args.utxoindex {
+    let index_service: Option<Arc<IndexService>> = if args.utxoindex || args.chainindex {
         // Use only a single thread for none-consensus databases
-        let utxoindex_db = calico_database::prelude::ConnBuilder::default()
-            .with_db_path(utxoindex_db_dir)
-            .with_files_limit(utxo_files_limit)
-            .build()
-            .unwrap();
-        let utxoindex = UtxoIndexProxy::new(UtxoIndex::new(consensus_manager.clone(), utxoindex_db).unwrap());
-        let index_service = Arc::new(IndexService::new(&notify_service.notifier(), subscription_context.clone(), Some(utxoindex)));
+        let utxoindex = args.utxoindex.then(|| {
+            let utxoindex_db = calico_database::prelude::ConnBuilder::default()
+                .with_db_path(utxoindex_db_dir)
+                .with_files_limit(utxo_files_limit)
+                .build()
+                .unwrap();
+            UtxoIndexProxy::new(UtxoIndex::new(consensus_manager.clone(), utxoindex_db).unwrap())
+        });
+        let chainindex = args.chainindex.then(|| {
+            let chainindex_db = calico_database::prelude::ConnBuilder::default()
+                .with_db_path(chainindex_db_dir)
+                .with_files_limit(chainindex_files_limit)
+                .build()
+                .unwrap();
+            ChainIndexProxy::new(ChainIndex::new(consensus_manager.clone(), chainindex_db).unwrap())
+        });
+        let index_service =
+            Arc::new(IndexService::new(&notify_service.notifier(), subscription_context.clone(), utxoindex, chainindex));
         Some(index_service)
     } else {
         None
@@ -429,17 +503,25 @@ do you confirm? (answer y/n or pass --yes to the Calicod command line to confirm
 
     let (address_manager, port_mapping_extender_svc) = AddressManager::new(config.clone(), meta_db, tick_service.clone());
 
-    let mining_manager = MiningManagerProxy::new(Arc::new(MiningManager::new_with_extended_config(
-        config.target_time_per_block,
-        false,
-        config.max_block_mass,
-        config.ram_scale,
-        config.block_template_cache_lifetime,
-        mining_counters.clone(),
-    )));
+    let mining_manager = MiningManagerProxy::new(Arc::new(
+        MiningManager::new_with_extended_config(
+            config.target_time_per_block,
+            false,
+            config.max_block_mass,
+            config.ram_scale,
+            config.block_template_cache_lifetime,
+            mining_counters.clone(),
+        )
+        .with_notification_root(notification_root.clone(), config.prefix()),
+    ));
     let mining_monitor =
         Arc::new(MiningMonitor::new(mining_manager.clone(), mining_counters, tx_script_cache_counters.clone(), tick_service.clone()));
 
+    let backup_dir =
+        args.backup_dir.as_ref().map(PathBuf::from).unwrap_or_else(|| app_dir.join(network.to_prefixed()).join(DEFAULT_BACKUP_DIR));
+    let checkpoint_service =
+        Arc::new(DatabaseCheckpointService::new(consensus_manager.clone(), notification_root.clone(), backup_dir));
+
     let flow_context = Arc::new(FlowContext::new(
         consensus_manager.clone(),
         address_manager,
@@ -463,11 +545,13 @@ do you confirm? (answer y/n or pass --yes to the Calicod command line to confirm
     let rpc_core_service = Arc::new(RpcCoreService::new(
         consensus_manager.clone(),
         notify_service.notifier(),
-        index_service.as_ref().map(|x| x.notifier()),
+        // The index notifier only relays UTXO events, so the RPC only sources them from it when the UTXO index is enabled
+        index_service.as_ref().filter(|x| x.utxoindex().is_some()).map(|x| x.notifier()),
         mining_manager,
         flow_context,
         subscription_context,
-        index_service.as_ref().map(|x| x.utxoindex().unwrap()),
+        index_service.as_ref().and_then(|x| x.utxoindex()),
+        index_service.as_ref().and_then(|x| x.chainindex()),
         config.clone(),
         core.clone(),
         processing_counters,
@@ -477,7 +561,35 @@ do you confirm? (answer y/n or pass --yes to the Calicod command line to confirm
         p2p_tower_counters.clone(),
         grpc_tower_counters.clone(),
         system_info,
+        Arc::new(RpcRateLimiter::new(RpcRateLimitConfig { connection_rate: args.rpc_rate_limit, ip_rate: args.rpc_ip_rate_limit })),
+        MetricsHistoryConfig {
+            seconds: args.metrics_history_seconds,
+            minutes: args.metrics_history_minutes,
+            hours: args.metrics_history_hours,
+        },
+        checkpoint_service.clone(),
     ));
+    let rpc_authorizer = args.rpc_auth_config.as_ref().map(|path| {
+        let auth_config = fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|content| toml::from_str::<RpcAuthConfig>(&content).map_err(|err| err.to_string()))
+            .unwrap_or_else(|err| {
+                println!("Invalid RPC authorization config file {path}: {err}");
+                exit(1);
+            });
+        info!("RPC authorization enabled with {} API key(s)", auth_config.keys.len());
+        Arc::new(RpcAuthorizer::new(auth_config))
+    });
+    let rpc_tls = args.rpc_tls_cert.as_ref().zip(args.rpc_tls_key.as_ref()).map(|(cert, key)| {
+        let options =
+            RpcTlsOptions { cert: cert.into(), key: key.into(), client_ca: args.rpc_tls_client_ca.as_ref().map(PathBuf::from) };
+        let tls = RpcTlsConfig::new(options).unwrap_or_else(|err| {
+            println!("Invalid RPC TLS configuration: {err}");
+            exit(1);
+        });
+        info!("RPC TLS enabled{}", if tls.is_mutual() { " with client certificate verification" } else { "" });
+        Arc::new(tls)
+    });
     let grpc_service_broadcasters: usize = 3; // TODO: add a command line argument or derive from other arg/config/host-related fields
     let grpc_service = if !args.disable_grpc {
         Some(Arc::new(GrpcService::new(
@@ -487,6 +599,8 @@ do you confirm? (answer y/n or pass --yes to the Calicod command line to confirm
             args.rpc_max_clients,
             grpc_service_broadcasters,
             grpc_tower_counters,
+            rpc_authorizer.clone(),
+            rpc_tls.clone(),
         )))
     } else {
         None
@@ -503,6 +617,10 @@ do you confirm? (answer y/n or pass --yes to the Calicod command line to confirm
         async_runtime.register(Arc::new(port_mapping_extender_svc))
     };
     async_runtime.register(rpc_core_service.clone());
+    async_runtime.register(checkpoint_service);
+    if let Some(rpc_tls) = rpc_tls.clone() {
+        async_runtime.register(rpc_tls)
+    }
     if let Some(grpc_service) = grpc_service {
         async_runtime.register(grpc_service)
     }
@@ -513,12 +631,18 @@ do you confirm? (answer y/n or pass --yes to the Calicod command line to confirm
     let wrpc_service_tasks: usize = 2; // num_cpus::get() / 2;
                                        // Register wRPC servers based on command line arguments
     [
-        (args.rpclisten_borsh.clone(), WrpcEncoding::Borsh, wrpc_borsh_counters),
-        (args.rpclisten_json.clone(), WrpcEncoding::SerdeJson, wrpc_json_counters),
+        (args.rpclisten_borsh.clone(), WrpcEncoding::Borsh, "wrpc-borsh", wrpc_borsh_counters),
+        (args.rpclisten_json.clone(), WrpcEncoding::SerdeJson, "wrpc-json", wrpc_json_counters),
     ]
     .into_iter()
-    .filter_map(|(listen_address, encoding, wrpc_server_counters)| {
+    .filter_map(|(listen_address, encoding, name, wrpc_server_counters)| {
         listen_address.map(|listen_address| {
+            // Each server retains its own notifications
+            let notification_replay = (args.rpc_notification_replay > 0).then(|| {
+                ReplayOptions::new(args.rpc_notification_replay)
+                    .with_max_size(args.rpc_notification_replay_size * 1024 * 1024)
+                    .with_directory(args.rpc_notification_replay_on_disk.then(|| replay_dir.join(name)))
+            });
             Arc::new(WrpcService::new(
                 wrpc_service_tasks,
                 Some(rpc_core_service.clone()),
@@ -527,6 +651,9 @@ do you confirm? (answer y/n or pass --yes to the Calicod command line to confirm
                 WrpcServerOptions {
                     listen_address: listen_address.to_address(&network.network_type, &encoding).to_string(), // TODO: use a normalized ContextualNetAddress instead of a String
                     verbose: args.wrpc_verbose,
+                    authorizer: rpc_authorizer.clone(),
+                    tls: rpc_tls.clone(),
+                    notification_replay,
                     ..WrpcServerOptions::default()
                 },
             ))
@@ -534,6 +661,26 @@ do you confirm? (answer y/n or pass --yes to the Calicod command line to confirm
     })
     .for_each(|server| async_runtime.register(server));
 
+    // Register the JSON-RPC over HTTP server if requested
+    if let Some(listen_address) = args.rpclisten_http {
+        async_runtime.register(Arc::new(HttpService::new(
+            rpc_core_service.clone(),
+            HttpServerOptions {
+                listen_address: listen_address.normalize(network.network_type.default_http_rpc_port()).to_string(),
+                authorizer: rpc_authorizer.clone(),
+                tls: rpc_tls.clone(),
+            },
+        )));
+    }
+
+    // Register the OpenMetrics exporter if requested
+    if let Some(listen_address) = args.metrics_listen {
+        async_runtime.register(Arc::new(MetricsService::new(
+            rpc_core_service.clone(),
+            MetricsOptions { listen_address: listen_address.normalize(network.network_type.default_metrics_port()).to_string() },
+        )));
+    }
+
     // Consensus must start first in order to init genesis in stores
     core.bind(consensus_manager);
     core.bind(async_runtime);
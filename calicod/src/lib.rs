@@ -1,2 +1,4 @@
 pub mod args;
 pub mod daemon;
+pub mod reindex;
+pub mod restore;
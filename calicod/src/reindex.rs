@@ -0,0 +1,323 @@
+//!
+//! Rebuilds the consensus state derived from the stored block data (GHOSTDAG, reachability, DAA,
+//! acceptance data, UTXO diffs and the virtual state), without downloading the DAG again.
+//!
+//! The headers and bodies of the active consensus are replayed into a staging consensus, which is
+//! committed as the new active consensus once the replay completes. The active consensus is left
+//! untouched if the replay fails.
+//!
+//! The replay reads the raw block data only: headers are walked along the stored relations in the
+//! topological order of their parents, so that corrupted derived data is rebuilt rather than copied
+//! forward. A node holding the history since genesis replays it from genesis. A pruned node replays
+//! it from the pruning point, importing the pruning point proof and trusted data, and its pruning
+//! point UTXO set, which cannot be rebuilt, is checked against the UTXO commitment of the pruning
+//! point header.
+//!
+
+use calico_consensus::consensus::factory::Factory as ConsensusFactory;
+use calico_consensus_core::{
+    api::BlockValidationFuture,
+    block::Block,
+    config::Config,
+    errors::{block::RuleError, consensus::ConsensusError, pruning::PruningImportError},
+    BlockHashMap, BlockHashSet,
+};
+use calico_consensusmanager::{ConsensusFactory as _, ConsensusSessionBlocking};
+use calico_core::info;
+use calico_hashes::Hash;
+use calico_muhash::MuHash;
+use calico_p2p_lib::{
+    common::ProtocolError,
+    convert::model::trusted::{TrustedDataEntry, TrustedDataPackage},
+};
+use futures::{executor::block_on, future::try_join_all};
+use std::collections::VecDeque;
+use thiserror::Error;
+
+/// Number of blocks queued for processing at once
+const REINDEX_BATCH_SIZE: usize = 1000;
+
+/// Number of pruning point UTXOs copied at once
+const UTXO_CHUNK_SIZE: usize = 1000;
+
+#[derive(Error, Debug)]
+pub enum ReindexError {
+    #[error(transparent)]
+    ConsensusError(#[from] ConsensusError),
+
+    #[error(transparent)]
+    RuleError(#[from] RuleError),
+
+    #[error(transparent)]
+    PruningImportError(#[from] PruningImportError),
+
+    #[error("unable to build the pruning point trusted data: {0}")]
+    TrustedDataError(#[from] ProtocolError),
+
+    #[error("the archival history starts at {0} rather than at genesis and cannot be replayed, resync the node with --reset-db")]
+    ArchivalHistoryNotFromGenesis(Hash),
+
+    #[error("the pruning point UTXO set hashes to {1} but the pruning point header commits to {0}, resync the node with --reset-db")]
+    UtxoCommitmentMismatch(Hash, Hash),
+
+    #[error("a consensus processor of the reindexed consensus panicked")]
+    ProcessorPanicked,
+}
+
+pub type ReindexResult<T> = std::result::Result<T, ReindexError>;
+
+/// Deletes the staging consensus entry once dropped, unless committed, so that neither an error nor a
+/// panic leaves a partially reindexed consensus behind
+struct StagingEntryGuard<'a> {
+    factory: &'a ConsensusFactory,
+    committed: bool,
+}
+
+impl Drop for StagingEntryGuard<'_> {
+    fn drop(&mut self) {
+        if !self.committed {
+            self.factory.delete_staging_entry();
+        }
+    }
+}
+
+/// Replays the block data of the active consensus into a new consensus and makes it the active one
+pub fn reindex(factory: &ConsensusFactory, config: &Config) -> ReindexResult<()> {
+    let (active, active_ctl) = factory.new_active_consensus();
+    let source = active.unguarded_session_blocking();
+    if source.get_headers_selected_tip() == config.genesis.hash {
+        info!("Reindex: the consensus holds no block besides genesis, nothing to reindex");
+        return Ok(());
+    }
+
+    // The source is the history root of an archival node and the pruning point otherwise
+    let root = source.get_source();
+    let from_genesis = root == config.genesis.hash;
+    if config.is_archival && !from_genesis {
+        return Err(ReindexError::ArchivalHistoryNotFromGenesis(root));
+    }
+
+    let mut staging_entry = StagingEntryGuard { factory, committed: false };
+    let (staging, staging_ctl) =
+        if from_genesis { factory.new_staging_consensus_with_genesis() } else { factory.new_staging_consensus() };
+    let handles = staging_ctl.start();
+    let target = staging.unguarded_session_blocking();
+
+    info!("Reindex: replaying the consensus data from {}", if from_genesis { "genesis" } else { "the pruning point" });
+    let max_blocks = REINDEX_BATCH_SIZE.max(config.mergeset_size_limit as usize + 1);
+    let result = Reindexer { source: &source, target: &target, max_blocks }.run(root, from_genesis);
+
+    drop(target);
+    staging_ctl.stop();
+    let panicked = handles.into_iter().map(|handle| handle.join()).filter(|joined| joined.is_err()).count() > 0;
+    let result = result.and_then(|()| if panicked { Err(ReindexError::ProcessorPanicked) } else { Ok(()) });
+    if result.is_ok() {
+        staging_ctl.make_active();
+        staging_entry.committed = true;
+    }
+    drop((staging, staging_ctl, source, active, active_ctl));
+    drop(staging_entry);
+    result?;
+
+    // Deletes the replaced consensus, unless the node is archival
+    factory.delete_inactive_consensus_entries();
+    info!("Reindex completed successfully");
+    Ok(())
+}
+
+struct Reindexer<'a> {
+    source: &'a ConsensusSessionBlocking<'static>,
+    target: &'a ConsensusSessionBlocking<'static>,
+    max_blocks: usize,
+}
+
+impl Reindexer<'_> {
+    fn run(&self, root: Hash, from_genesis: bool) -> ReindexResult<()> {
+        let roots = if from_genesis { vec![root] } else { self.import_pruning_point_anticone(root)? };
+
+        let (headers, leaves) = self.replay_headers(roots)?;
+        info!("Reindex: replayed {} headers", headers);
+
+        if !from_genesis {
+            self.target.validate_pruning_points()?;
+            self.import_pruning_point_utxo_set(root)?;
+        }
+
+        // Bodies in the past of the headers selected tip first, so that the virtual follows the selected chain
+        let mut bodies = 0;
+        for high in std::iter::once(self.target.get_headers_selected_tip()).chain(leaves) {
+            bodies += self.replay_bodies(high)?;
+        }
+        info!("Reindex: replayed {} block bodies", bodies);
+        Ok(())
+    }
+
+    /// Applies the pruning point proof and imports the pruning point anticone along with the trusted
+    /// data required for validating the blocks in its future, as done by an IBD with headers proof.
+    /// Returns the imported blocks, from which the headers are replayed.
+    fn import_pruning_point_anticone(&self, pruning_point: Hash) -> ReindexResult<Vec<Hash>> {
+        let proof = (*self.source.get_pruning_point_proof()).clone();
+        let pruning_points = self.source.pruning_point_headers();
+        let trusted_data = self.source.get_pruning_point_anticone_and_trusted_data()?;
+        let entries = trusted_data
+            .anticone
+            .iter()
+            .map(|&hash| Ok(TrustedDataEntry::new(self.source.get_block(hash)?, vec![], vec![])))
+            .collect::<ReindexResult<Vec<_>>>()?;
+        let package = TrustedDataPackage::new(trusted_data.daa_window_blocks.clone(), trusted_data.ghostdag_blocks.clone());
+        let trusted_set = package.build_trusted_subdag(entries)?;
+
+        self.target.apply_pruning_proof(proof, &trusted_set)?;
+        self.target.import_pruning_points(pruning_points);
+        info!("Reindex: importing {} trusted blocks", trusted_set.len());
+        for tb in trusted_set {
+            block_on(self.target.validate_and_insert_trusted_block(tb).virtual_state_task)?;
+        }
+        Ok(std::iter::once(pruning_point).chain(trusted_data.anticone.iter().copied()).collect())
+    }
+
+    /// Copies the pruning point UTXO set, checks it against the pruning point UTXO commitment and imports it
+    fn import_pruning_point_utxo_set(&self, pruning_point: Hash) -> ReindexResult<()> {
+        let mut multiset = MuHash::new();
+        let mut from_outpoint = None;
+        loop {
+            let chunk = self.source.get_pruning_point_utxos(pruning_point, from_outpoint, UTXO_CHUNK_SIZE, from_outpoint.is_some())?;
+            self.target.append_imported_pruning_point_utxos(&chunk, &mut multiset);
+            if chunk.len() < UTXO_CHUNK_SIZE {
+                break;
+            }
+            from_outpoint = Some(chunk.last().expect("not empty by prev condition").0);
+        }
+        let commitment = self.source.get_header(pruning_point)?.utxo_commitment;
+        let imported = multiset.clone().finalize();
+        if imported != commitment {
+            return Err(ReindexError::UtxoCommitmentMismatch(commitment, imported));
+        }
+        self.target.import_pruning_point_utxo_set(pruning_point, multiset)?;
+        Ok(())
+    }
+
+    /// Replays the stored headers in the future of `roots`, every header being replayed after its parents.
+    /// Returns the count of replayed headers along with the replayed blocks having no children.
+    fn replay_headers(&self, roots: Vec<Hash>) -> ReindexResult<(usize, Vec<Hash>)> {
+        let mut walk = Walk::default();
+        for root in roots {
+            walk.discover(self.source.get_block_children(root).unwrap_or_default());
+        }
+        let mut jobs = Vec::with_capacity(self.max_blocks);
+        let mut leaves = vec![];
+        let mut count = 0;
+        loop {
+            let Some(hash) = walk.queue.pop_front() else {
+                if !jobs.is_empty() {
+                    count += jobs.len();
+                    block_on(try_join_all(jobs.drain(..)))?;
+                    walk.flushed();
+                } else if !walk.release_outside_parents() {
+                    break;
+                }
+                continue;
+            };
+            if walk.submitted.contains(&hash) || self.target.get_block_status(hash).is_some() {
+                continue;
+            }
+            let header = self.source.get_header(hash)?;
+            let pending_parent = header
+                .direct_parents()
+                .iter()
+                .copied()
+                .find(|&parent| !walk.is_replayed(parent) && self.target.get_block_status(parent).is_none());
+            if let Some(parent) = pending_parent {
+                walk.waiting.entry(parent).or_default().push(hash);
+                continue;
+            }
+
+            jobs.push(self.target.validate_and_insert_block(Block::from_header_arc(header)).virtual_state_task);
+            let children = self.source.get_block_children(hash).unwrap_or_default();
+            if children.is_empty() {
+                leaves.push(hash);
+            }
+            walk.submit(hash, children);
+            if jobs.len() >= self.max_blocks {
+                count += jobs.len();
+                block_on(try_join_all(jobs.drain(..)))?;
+                walk.flushed();
+            }
+        }
+        Ok((count, leaves))
+    }
+
+    /// Replays the bodies held by the source consensus which are missing in the past of `high`, returning their count
+    fn replay_bodies(&self, high: Hash) -> ReindexResult<usize> {
+        let hashes = self.target.get_missing_block_body_hashes(high)?;
+        let mut count = 0;
+        for chunk in hashes.chunks(REINDEX_BATCH_SIZE) {
+            let jobs = chunk
+                .iter()
+                .copied()
+                .filter(|&hash| self.source.get_block_status(hash).is_some_and(|status| status.has_block_body()))
+                .map(|hash| Ok(self.target.validate_and_insert_block(self.source.get_block(hash)?).virtual_state_task))
+                .collect::<ReindexResult<Vec<BlockValidationFuture>>>()?;
+            count += jobs.len();
+            block_on(try_join_all(jobs))?;
+        }
+        Ok(count)
+    }
+}
+
+/// State of the topological walk over the stored headers
+#[derive(Default)]
+struct Walk {
+    /// Blocks to be replayed once their parents are
+    queue: VecDeque<Hash>,
+    /// Blocks queued, waiting or submitted but not yet flushed, so that a block is queued once
+    discovered: BlockHashSet,
+    /// Blocks waiting for the replay of a parent, keyed by this parent
+    waiting: BlockHashMap<Vec<Hash>>,
+    /// Blocks submitted for validation since the last flush
+    submitted: BlockHashSet,
+    /// Parents never reached by the walk, hence outside the replayed DAG
+    outside: BlockHashSet,
+}
+
+impl Walk {
+    fn discover(&mut self, blocks: Vec<Hash>) {
+        for block in blocks {
+            if self.discovered.insert(block) {
+                self.queue.push_back(block);
+            }
+        }
+    }
+
+    fn is_replayed(&self, parent: Hash) -> bool {
+        self.submitted.contains(&parent) || self.outside.contains(&parent)
+    }
+
+    fn submit(&mut self, block: Hash, children: Vec<Hash>) {
+        self.submitted.insert(block);
+        if let Some(waiting) = self.waiting.remove(&block) {
+            self.queue.extend(waiting);
+        }
+        self.discover(children);
+    }
+
+    /// Forgets the blocks submitted so far, which are now known to the target consensus
+    fn flushed(&mut self) {
+        for block in self.submitted.drain() {
+            self.discovered.remove(&block);
+        }
+    }
+
+    /// Once the walk is exhausted, the parents still waited for were not reached from the roots, so they are
+    /// out of the replayed DAG. Their children are requeued, their validation reporting these parents if missing.
+    fn release_outside_parents(&mut self) -> bool {
+        if self.waiting.is_empty() {
+            return false;
+        }
+        for (parent, waiting) in self.waiting.drain() {
+            self.outside.insert(parent);
+            self.queue.extend(waiting);
+        }
+        true
+    }
+}
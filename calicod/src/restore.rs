@@ -0,0 +1,128 @@
+//!
+//! Restores the node databases from a database checkpoint (see `--restore-from`).
+//!
+//! A checkpoint reproduces the layout of the data directory, holding the active consensus
+//! database under `consensus/` and the meta database under `meta/`. The data directory is
+//! replaced by a copy of the checkpoint, leaving the checkpoint itself untouched. The UTXO
+//! index is not part of a checkpoint and is rebuilt on startup if enabled.
+//!
+//! The checkpoint is copied next to the data directory and verified there, and only then
+//! swapped in, so a failed restore leaves the current databases in place.
+//!
+
+use crate::daemon::{CONSENSUS_DB, META_DB};
+use calico_consensus::{
+    consensus::factory::MultiConsensusManagementStore,
+    model::stores::past_pruning_points::{DbPastPruningPointsStore, PastPruningPointsStoreReader},
+};
+use calico_database::prelude::{CachePolicy, ConnBuilder, ConnBuilderError, StoreError};
+use calico_hashes::Hash;
+use std::{
+    ffi::OsString,
+    fs, io,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+const RESTORE_DB_FILE_LIMIT: i32 = 10;
+
+#[derive(Error, Debug)]
+pub enum RestoreError {
+    #[error("{} is not a database checkpoint: missing {1} directory", .0.display())]
+    NotACheckpoint(PathBuf, &'static str),
+
+    #[error("{} is not a database checkpoint: its meta database does not list a consensus database it contains", .0.display())]
+    MissingActiveConsensus(PathBuf),
+
+    #[error("the checkpoint belongs to the network with genesis {0}, while the node runs on the network with genesis {1}")]
+    NetworkMismatch(Hash, Hash),
+
+    #[error(transparent)]
+    ConnBuilderError(#[from] ConnBuilderError),
+
+    #[error(transparent)]
+    StoreError(#[from] StoreError),
+
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+/// Replaces the data directory `db_dir` with a copy of the checkpoint in `checkpoint_dir`, which must hold
+/// the consensus and meta databases of the network with the given `genesis`
+pub fn restore(checkpoint_dir: &Path, db_dir: &Path, genesis: Hash) -> Result<(), RestoreError> {
+    for database in [CONSENSUS_DB, META_DB] {
+        if !checkpoint_dir.join(database).is_dir() {
+            return Err(RestoreError::NotACheckpoint(checkpoint_dir.to_path_buf(), database));
+        }
+    }
+
+    // A leftover of an interrupted restore is discarded
+    let restoring_dir = sibling_dir(db_dir, "restoring");
+    if restoring_dir.exists() {
+        fs::remove_dir_all(&restoring_dir)?;
+    }
+    let restored = copy_dir(checkpoint_dir, &restoring_dir)
+        .map_err(RestoreError::from)
+        .and_then(|()| verify(&restoring_dir, checkpoint_dir, genesis));
+    if let Err(err) = restored {
+        fs::remove_dir_all(&restoring_dir).ok();
+        return Err(err);
+    }
+
+    if db_dir.exists() {
+        let replaced_dir = sibling_dir(db_dir, "replaced");
+        if replaced_dir.exists() {
+            fs::remove_dir_all(&replaced_dir)?;
+        }
+        fs::rename(db_dir, &replaced_dir)?;
+        if let Err(err) = fs::rename(&restoring_dir, db_dir) {
+            fs::rename(&replaced_dir, db_dir)?;
+            return Err(err.into());
+        }
+        fs::remove_dir_all(&replaced_dir)?;
+    } else {
+        fs::rename(&restoring_dir, db_dir)?;
+    }
+    Ok(())
+}
+
+/// Verifies that the meta database in `db_dir`, a copy of `checkpoint_dir`, lists an active consensus whose
+/// database is present, and that the consensus database belongs to the network with the given `genesis`
+fn verify(db_dir: &Path, checkpoint_dir: &Path, genesis: Hash) -> Result<(), RestoreError> {
+    let meta_db = ConnBuilder::default().with_db_path(db_dir.join(META_DB)).with_files_limit(RESTORE_DB_FILE_LIMIT).build()?;
+    let consensus_dir = MultiConsensusManagementStore::new(meta_db)
+        .active_consensus_dir_name()?
+        .map(|dir_name| db_dir.join(CONSENSUS_DB).join(dir_name))
+        .filter(|consensus_dir| consensus_dir.is_dir())
+        .ok_or_else(|| RestoreError::MissingActiveConsensus(checkpoint_dir.to_path_buf()))?;
+
+    // The first past pruning point of any consensus is the genesis of its network
+    let consensus_db = ConnBuilder::default().with_db_path(consensus_dir).with_files_limit(RESTORE_DB_FILE_LIMIT).build()?;
+    let checkpoint_genesis = DbPastPruningPointsStore::new(consensus_db, CachePolicy::Empty).get(0)?;
+    if checkpoint_genesis != genesis {
+        return Err(RestoreError::NetworkMismatch(checkpoint_genesis, genesis));
+    }
+    Ok(())
+}
+
+/// Returns a directory next to `dir` named after it with the given `suffix`, so that it is on the same
+/// file system and can be renamed into `dir`
+fn sibling_dir(dir: &Path, suffix: &str) -> PathBuf {
+    let mut name = dir.file_name().map(OsString::from).unwrap_or_default();
+    name.push(format!(".{suffix}"));
+    dir.with_file_name(name)
+}
+
+fn copy_dir(source: &Path, destination: &Path) -> io::Result<()> {
+    fs::create_dir_all(destination)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let target = destination.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
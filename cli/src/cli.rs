@@ -376,6 +376,12 @@ impl CalicoCli {
                                 },
                                 Events::AccountCreate { .. } => { },
                                 Events::AccountUpdate { .. } => { },
+                                Events::AccountDiscoveryProgress { account_index, change, scanned, last_used } => {
+                                    let chain = if change { "change" } else { "receive" };
+                                    let last_used = last_used.map(|index| format!(", last used index {index}")).unwrap_or_default();
+                                    tprintln!(this, "Scanning account #{account_index} {chain} addresses: {scanned}{last_used}");
+                                },
+                                Events::AccountDiscoveryComplete { .. } => { },
                                 Events::DaaScoreChange { current_daa_score } => {
                                     if this.is_mutted() && this.flags.get(Track::Daa) {
                                         tprintln!(this, "{NOTIFY} DAA: {current_daa_score}");
@@ -1,6 +1,7 @@
 use calico_wallet_core::account::BIP32_ACCOUNT_KIND;
 use calico_wallet_core::account::LEGACY_ACCOUNT_KIND;
 use calico_wallet_core::account::MULTISIG_ACCOUNT_KIND;
+use calico_wallet_core::wallet::{DiscoveredAccount, DEFAULT_ACCOUNT_GAP_LIMIT, DEFAULT_ADDRESS_GAP_LIMIT};
 
 use crate::imports::*;
 use crate::wizards;
@@ -189,6 +190,7 @@ impl Account {
                         &[
                             ("account watch bip32", "Import a extended public key for a watch-only bip32 account"),
                             ("account watch multisig", "Import extended public keys for a watch-only multisig account"),
+                            ("account watch external", "Import an extended public key for an account signed by an external signer"),
                         ],
                         None,
                     )?;
@@ -209,9 +211,12 @@ impl Account {
                     "multisig" => {
                         wizards::account::multisig_watch(&ctx, account_name).await?;
                     }
+                    "external" => {
+                        wizards::account::external(&ctx, account_name).await?;
+                    }
                     _ => {
                         tprintln!(ctx, "Unknown account watch type: '{watch_kind}'");
-                        tprintln!(ctx, "supported watch types are: 'bip32' or 'multisig'\r\n");
+                        tprintln!(ctx, "supported watch types are: 'bip32', 'multisig' or 'external'\r\n");
                         return Ok(());
                     }
                 }
@@ -234,6 +239,17 @@ impl Account {
 
                 self.derivation_scan(&ctx, start, count, window, sweep).await?;
             }
+            "rescan" => {
+                if argv.len() > 2 {
+                    tprintln!(ctx, "Usage: 'account rescan [<address gap limit>] [<account gap limit>]'");
+                    return Ok(());
+                }
+
+                let address_gap_limit = argv.first().map(|v| v.parse::<u32>()).transpose()?.unwrap_or(DEFAULT_ADDRESS_GAP_LIMIT);
+                let account_gap_limit = argv.get(1).map(|v| v.parse::<u32>()).transpose()?.unwrap_or(DEFAULT_ACCOUNT_GAP_LIMIT);
+
+                self.rescan(&ctx, address_gap_limit, account_gap_limit).await?;
+            }
             v => {
                 tprintln!(ctx, "Unknown command: '{v}'\r\n");
                 return self.display_help(ctx, argv).await;
@@ -258,6 +274,11 @@ impl Account {
                     "sweep [<derivations>] or sweep [<start>] [<derivations>]",
                     "Sweep extended address derivation chain (legacy accounts)",
                 ),
+                (
+                    "rescan [<address gap limit>] [<account gap limit>]",
+                    "Discover BIP44 accounts of a private key, create missing accounts and restore address indexes \
+                (defaults: 20 addresses, 1 account)",
+                ),
                 // ("purge", "Purge an account from the wallet"),
             ],
             None,
@@ -266,6 +287,43 @@ impl Account {
         Ok(())
     }
 
+    async fn rescan(self: &Arc<Self>, ctx: &Arc<CalicoCli>, address_gap_limit: u32, account_gap_limit: u32) -> Result<()> {
+        let wallet = ctx.wallet();
+        if !wallet.is_connected() {
+            tprintln!(ctx, "{}", style("Wallet is not connected to the network").magenta());
+            return Ok(());
+        }
+
+        let prv_key_data_info = ctx.select_private_key().await?;
+        let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+        let payment_secret = if prv_key_data_info.is_encrypted() {
+            Some(Secret::new(ctx.term().ask(true, "Enter payment password: ").await?.trim().as_bytes().to_vec()))
+        } else {
+            None
+        };
+        let _ = ctx.notifier().show(Notification::Processing).await;
+
+        let guard = wallet.guard();
+        let guard = guard.lock().await;
+        let accounts = wallet
+            .restore_bip44_accounts(
+                &wallet_secret,
+                prv_key_data_info.id,
+                payment_secret.as_ref(),
+                address_gap_limit,
+                account_gap_limit,
+                &guard,
+            )
+            .await?;
+
+        tprintln!(ctx, "Discovered {} account(s):", accounts.len());
+        for DiscoveredAccount { account_index, receive_index, change_index } in accounts {
+            tprintln!(ctx, "  account #{account_index}: receive index {receive_index}, change index {change_index}");
+        }
+
+        Ok(())
+    }
+
     async fn derivation_scan(
         self: &Arc<Self>,
         ctx: &Arc<CalicoCli>,
@@ -10,7 +10,20 @@ impl Connect {
         if let Some(wrpc_client) = ctx.wallet().try_wrpc_client().as_ref() {
             let network_id = ctx.wallet().network_id()?;
 
-            let arg_or_server_address = argv.first().cloned().or_else(|| ctx.wallet().settings().get(WalletSettings::Server));
+            let (flags, args): (Vec<_>, Vec<_>) = argv.into_iter().partition(|arg| arg.starts_with("--"));
+            let mut auth_token = ctx.wallet().settings().get::<String>(WalletSettings::ApiKey);
+            for flag in flags {
+                if let Some(key) = flag.strip_prefix("--api-key=") {
+                    auth_token = Some(key.to_string());
+                } else if flag == "--anonymous" {
+                    auth_token = Some(String::new());
+                } else {
+                    tprintln!(ctx, "usage: connect [<url>|public] [--api-key=<key>|--anonymous]");
+                    return Ok(());
+                }
+            }
+
+            let arg_or_server_address = args.first().cloned().or_else(|| ctx.wallet().settings().get(WalletSettings::Server));
             let (is_public, url) = match arg_or_server_address.as_deref() {
                 Some("public") => {
                     tprintln!(ctx, "Connecting to a public node");
@@ -45,6 +58,8 @@ impl Connect {
                 }
             }
 
+            wrpc_client.set_auth_token(auth_token.as_deref()).map_err(|e| e.to_string())?;
+
             let options = ConnectOptions {
                 block_async_connect: true,
                 strategy: ConnectStrategy::Fallback,
@@ -0,0 +1,250 @@
+use crate::imports::*;
+use calico_addresses::Version;
+use calico_consensus_core::tx::{TransactionOutpoint, UtxoEntry};
+use calico_txscript::{htlc_redeem_script, time_lock_redeem_script, HtlcScript, TimeLock, TimeLockScript};
+use calico_wallet_core::account::pssb::{finalize_psst_htlc_claim, finalize_psst_htlc_refund, finalize_psst_time_lock};
+use calico_wallet_psst::prelude::{
+    script_sig_to_address, unlock_time_locked_utxos_as_pssb, unlock_utxos_as_pssb, Bundle, Finalizer, PSST,
+};
+
+#[derive(Default, Handler)]
+#[help("Create and redeem hash-time-locked (HTLC) and time-locked contracts")]
+pub struct Contract;
+
+impl Contract {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, mut argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<CalicoCli>()?;
+
+        if !ctx.wallet().is_open() {
+            return Err(Error::WalletIsNotOpen);
+        }
+
+        if argv.len() < 2 {
+            return self.display_help(ctx, argv).await;
+        }
+
+        let contract = argv.remove(0);
+        let action = argv.remove(0);
+
+        match (contract.as_str(), action.as_str()) {
+            ("htlc", "create") => {
+                if argv.len() < 4 || argv.len() > 5 {
+                    return self.display_help(ctx, argv).await;
+                }
+                let recipient = Address::try_from(argv[0].as_str())?;
+                let hash: [u8; 32] =
+                    Self::parse_hex(&argv[1])?.try_into().map_err(|_| Error::custom("The HTLC hash must be a 32-byte SHA256 hash"))?;
+                let lock_time = Self::parse_u64(&argv[2])?;
+                let refund = Self::signing_address(&ctx)?;
+                let redeem_script = htlc_redeem_script(&hash, &Self::pub_key(&recipient)?, &Self::pub_key(&refund)?, lock_time)
+                    .map_err(|err| Error::custom(err.to_string()))?;
+                self.fund(&ctx, redeem_script, argv.get(3), argv.get(4)).await?;
+            }
+            ("htlc", "claim") => {
+                if argv.len() < 2 || argv.len() > 3 {
+                    return self.display_help(ctx, argv).await;
+                }
+                let redeem_script = Self::parse_hex(&argv[0])?;
+                let htlc = HtlcScript::parse(&redeem_script).ok_or_else(|| Error::custom("Invalid HTLC redeem script"))?;
+                let preimage = Self::parse_hex(&argv[1])?;
+                let signer = Self::contract_signer(&ctx, &htlc.recipient)?;
+                self.redeem(&ctx, redeem_script, signer, None, argv.get(2), |psst| finalize_psst_htlc_claim(psst, &preimage)).await?;
+            }
+            ("htlc", "refund") => {
+                if argv.is_empty() || argv.len() > 2 {
+                    return self.display_help(ctx, argv).await;
+                }
+                let redeem_script = Self::parse_hex(&argv[0])?;
+                let htlc = HtlcScript::parse(&redeem_script).ok_or_else(|| Error::custom("Invalid HTLC redeem script"))?;
+                let signer = Self::contract_signer(&ctx, &htlc.refund)?;
+                let lock = Some(TimeLock::Absolute(htlc.lock_time));
+                self.redeem(&ctx, redeem_script, signer, lock, argv.get(1), finalize_psst_htlc_refund).await?;
+            }
+            ("timelock", "create") => {
+                if argv.len() < 3 || argv.len() > 4 {
+                    return self.display_help(ctx, argv).await;
+                }
+                let value = Self::parse_u64(&argv[1])?;
+                let lock = match argv[0].as_str() {
+                    "absolute" => TimeLock::Absolute(value),
+                    "relative" => TimeLock::Relative(value),
+                    v => return Err(Error::custom(format!("Unknown time lock type '{v}', expecting 'absolute' or 'relative'"))),
+                };
+                let owner = Self::signing_address(&ctx)?;
+                let redeem_script =
+                    time_lock_redeem_script(&Self::pub_key(&owner)?, lock).map_err(|err| Error::custom(err.to_string()))?;
+                self.fund(&ctx, redeem_script, argv.get(2), argv.get(3)).await?;
+            }
+            ("timelock", "redeem") => {
+                if argv.is_empty() || argv.len() > 2 {
+                    return self.display_help(ctx, argv).await;
+                }
+                let redeem_script = Self::parse_hex(&argv[0])?;
+                let time_lock =
+                    TimeLockScript::parse(&redeem_script).ok_or_else(|| Error::custom("Invalid time-locked redeem script"))?;
+                let signer = Self::contract_signer(&ctx, &time_lock.pub_key)?;
+                self.redeem(&ctx, redeem_script, signer, Some(time_lock.lock), argv.get(1), finalize_psst_time_lock).await?;
+            }
+            (_, "address") => {
+                if argv.len() != 1 {
+                    return self.display_help(ctx, argv).await;
+                }
+                let redeem_script = Self::parse_hex(&argv[0])?;
+                let address = script_sig_to_address(&redeem_script, ctx.wallet().address_prefix()?)?;
+                if let Some(htlc) = HtlcScript::parse(&redeem_script) {
+                    tprintln!(ctx, "HTLC contract");
+                    tprintln!(ctx, "  hash: {}", htlc.hash.as_slice().to_hex());
+                    tprintln!(ctx, "  recipient key: {}", htlc.recipient.to_hex());
+                    tprintln!(ctx, "  refund key: {}", htlc.refund.to_hex());
+                    tprintln!(ctx, "  refund lock time: {}", htlc.lock_time);
+                } else if let Some(time_lock) = TimeLockScript::parse(&redeem_script) {
+                    tprintln!(ctx, "Time-locked contract");
+                    tprintln!(ctx, "  key: {}", time_lock.pub_key.to_hex());
+                    match time_lock.lock {
+                        TimeLock::Absolute(lock_time) => tprintln!(ctx, "  lock time: {lock_time}"),
+                        TimeLock::Relative(sequence) => tprintln!(ctx, "  sequence: {sequence}"),
+                    }
+                } else {
+                    twarnln!(ctx, "Unknown contract script");
+                }
+                tprintln!(ctx, "  P2SH address: {address}");
+            }
+            _ => {
+                tprintln!(ctx, "unknown command: '{contract} {action}'\r\n");
+                return self.display_help(ctx, argv).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Send `amount` to the P2SH address of the `redeem_script`.
+    async fn fund(
+        &self,
+        ctx: &Arc<CalicoCli>,
+        redeem_script: Vec<u8>,
+        amount: Option<&String>,
+        priority_fee: Option<&String>,
+    ) -> Result<()> {
+        let account = ctx.wallet().account()?;
+        let address = script_sig_to_address(&redeem_script, ctx.wallet().address_prefix()?)?;
+        let amount_sompi = try_parse_required_nonzero_calico_as_sompi_u64(amount)?;
+        let priority_fee_sompi = try_parse_optional_calico_as_sompi_i64(priority_fee)?.unwrap_or(0);
+        let outputs = PaymentOutputs::from((address.clone(), amount_sompi));
+        let abortable = Abortable::default();
+        let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
+        let _ = ctx.notifier().show(Notification::Processing).await;
+
+        let (summary, _ids) =
+            account.send(outputs.into(), priority_fee_sompi.into(), None, wallet_secret, payment_secret, &abortable, None).await?;
+
+        tprintln!(ctx, "Contract funded - {summary}");
+        tprintln!(ctx, "P2SH address: {address}");
+        tprintln!(ctx, "Redeem script: {}", redeem_script.to_hex());
+        Ok(())
+    }
+
+    /// Spend all UTXOs locked by the `redeem_script` to the `signer` address,
+    /// signing with the key of the `signer` address.
+    async fn redeem<F>(
+        &self,
+        ctx: &Arc<CalicoCli>,
+        redeem_script: Vec<u8>,
+        signer: Address,
+        lock: Option<TimeLock>,
+        priority_fee: Option<&String>,
+        finalize: F,
+    ) -> Result<()>
+    where
+        F: Fn(PSST<Finalizer>) -> std::result::Result<PSST<Finalizer>, calico_wallet_core::error::Error>,
+    {
+        let account = ctx.wallet().account()?;
+        let address = script_sig_to_address(&redeem_script, ctx.wallet().address_prefix()?)?;
+        let priority_fee_sompi = u64::try_from(try_parse_optional_calico_as_sompi_i64(priority_fee)?.unwrap_or(0))
+            .map_err(|_| Error::custom("The priority fee must not be negative"))?;
+
+        let spend_utxos = ctx.wallet().rpc_api().get_utxos_by_addresses(vec![address.clone()]).await?;
+        if spend_utxos.is_empty() {
+            twarnln!(ctx, "No UTXOs found at contract address {address}");
+            return Ok(());
+        }
+        let references: Vec<(UtxoEntry, TransactionOutpoint)> =
+            spend_utxos.iter().map(|entry| (entry.utxo_entry.clone().into(), entry.outpoint.into())).collect();
+
+        let bundle = match lock {
+            Some(lock) => unlock_time_locked_utxos_as_pssb(references, &signer, redeem_script, priority_fee_sompi, lock)?,
+            None => unlock_utxos_as_pssb(references, &signer, redeem_script, priority_fee_sompi)?,
+        };
+
+        let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
+        let _ = ctx.notifier().show(Notification::Processing).await;
+        let signed = account.clone().pssb_sign(&bundle, wallet_secret, payment_secret, Some(&signer)).await?;
+
+        let mut finalized = Bundle::new();
+        for inner in signed.iter() {
+            finalized.add_psst(finalize(PSST::<Finalizer>::from(inner.clone()))?);
+        }
+
+        let ids = account.pssb_broadcast(&finalized).await?;
+        tprintln!(ctx, "Redeemed {} UTXO(s) from {address}, transaction IDs:", spend_utxos.len());
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+        Ok(())
+    }
+
+    /// Address whose key is used for contract signing (the account receive address).
+    fn signing_address(ctx: &Arc<CalicoCli>) -> Result<Address> {
+        let address = ctx.wallet().account()?.receive_address()?;
+        if address.version != Version::PubKey {
+            return Err(Error::custom("Contracts require an account with Schnorr (PubKey) addresses"));
+        }
+        Ok(address)
+    }
+
+    /// Account address holding the contract key. Contracts are created with
+    /// the receive address current at the time of creation, so all receive
+    /// and change addresses derived by the account are searched for the key.
+    fn contract_signer(ctx: &Arc<CalicoCli>, pub_key: &[u8]) -> Result<Address> {
+        let address = Address::new(ctx.wallet().address_prefix()?, Version::PubKey, pub_key);
+        let derivation = ctx.wallet().account()?.as_derivation_capable()?.derivation();
+        for manager in [derivation.receive_address_manager(), derivation.change_address_manager()] {
+            // deriving the range also registers the addresses for signing
+            if manager.get_range(0..manager.index() + 1)?.contains(&address) {
+                return Ok(address);
+            }
+        }
+        Err(Error::custom(format!("The contract key ({address}) does not belong to the current account")))
+    }
+
+    fn pub_key(address: &Address) -> Result<Vec<u8>> {
+        match address.version {
+            Version::PubKey => Ok(address.payload.to_vec()),
+            _ => Err(Error::custom(format!("Address {address} is not a Schnorr (PubKey) address"))),
+        }
+    }
+
+    fn parse_hex(input: &str) -> Result<Vec<u8>> {
+        Vec::<u8>::from_hex(input).map_err(|err| Error::custom(format!("Invalid hex data: {err}")))
+    }
+
+    fn parse_u64(input: &str) -> Result<u64> {
+        input.parse::<u64>().map_err(|err| Error::custom(format!("Invalid value '{input}': {err}")))
+    }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<CalicoCli>, _argv: Vec<String>) -> Result<()> {
+        ctx.term().help(
+            &[
+                ("contract htlc create <recipient address> <sha256 hash> <lock time> <amount> [priority fee]", "Fund an HTLC claimable by the recipient revealing the hash preimage, refundable to this account after the lock time (DAA score)"),
+                ("contract htlc claim <redeem script> <preimage> [priority fee]", "Claim HTLC funds by revealing the preimage"),
+                ("contract htlc refund <redeem script> [priority fee]", "Refund HTLC funds after the lock time"),
+                ("contract timelock create <absolute|relative> <lock time|sequence> <amount> [priority fee]", "Fund a contract spendable by this account once the time lock is satisfied"),
+                ("contract timelock redeem <redeem script> [priority fee]", "Redeem time-locked contract funds"),
+                ("contract <htlc|timelock> address <redeem script>", "Print contract details and P2SH address"),
+            ],
+            None,
+        )?;
+
+        Ok(())
+    }
+}
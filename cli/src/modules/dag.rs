@@ -0,0 +1,197 @@
+use crate::imports::*;
+use calico_rpc_core::ToRpcHex;
+use std::{collections::HashSet, fmt::Write as _, path::Path};
+use workflow_store::fs;
+
+#[derive(Default, Handler)]
+#[help("Export a sub-DAG of the connected node as GraphML, DOT or JSON")]
+pub struct Dag;
+
+impl Dag {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, mut argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<CalicoCli>()?;
+
+        if argv.len() < 3 {
+            return self.display_help(ctx, argv).await;
+        }
+
+        let action = argv.remove(0);
+        let range = match action.as_str() {
+            "export" => RpcDagRange::Hashes { low_hash: RpcHash::from_hex(&argv[0])?, high_hash: RpcHash::from_hex(&argv[1])? },
+            "export-daa" => RpcDagRange::DaaScores {
+                low_daa_score: argv[0].parse::<u64>().map_err(|_| Error::custom(format!("invalid DAA score: {}", argv[0])))?,
+                high_daa_score: argv[1].parse::<u64>().map_err(|_| Error::custom(format!("invalid DAA score: {}", argv[1])))?,
+            },
+            _ => return self.display_help(ctx, argv).await,
+        };
+        let format = argv.get(2).map(|format| format.parse::<DagFormat>()).transpose()?.unwrap_or_default();
+
+        let response = ctx.wallet().rpc_api().export_dag(range).await?;
+        let document = match format {
+            DagFormat::GraphMl => to_graphml(&response),
+            DagFormat::Dot => to_dot(&response),
+            DagFormat::Json => serde_json::to_string_pretty(&response)?,
+        };
+
+        match argv.get(3) {
+            Some(file) => {
+                fs::write_string(Path::new(file), &document).await?;
+                tprintln!(ctx, "Exported {} blocks to {file}", response.blocks.len());
+            }
+            None => ctx.term().writeln(document.crlf()),
+        }
+
+        Ok(())
+    }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<CalicoCli>, _argv: Vec<String>) -> Result<()> {
+        ctx.term().help(
+            &[
+                (
+                    "dag export <low hash> <high hash> [graphml|dot|json] [file]",
+                    "Export the blocks in the future of the low block and in the past of the high block",
+                ),
+                (
+                    "dag export-daa <low DAA score> <high DAA score> [graphml|dot|json] [file]",
+                    "Export the blocks between the selected chain blocks bounding a DAA score range",
+                ),
+            ],
+            None,
+        )?;
+
+        tprintln!(ctx, "The default format is graphml. Edges to parents outside of the exported sub-DAG are omitted.");
+        tprintln!(ctx, "Blocks are coloured as they are in the merge set of the exported chain block merging them.");
+
+        Ok(())
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+enum DagFormat {
+    #[default]
+    GraphMl,
+    Dot,
+    Json,
+}
+
+impl std::str::FromStr for DagFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "graphml" => Ok(DagFormat::GraphMl),
+            "dot" => Ok(DagFormat::Dot),
+            "json" => Ok(DagFormat::Json),
+            _ => Err(Error::custom(format!("unknown export format '{s}', expected graphml, dot or json"))),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum BlockColor {
+    Blue,
+    Red,
+    /// Not merged by any chain block of the export
+    Unmerged,
+}
+
+impl BlockColor {
+    fn as_str(&self) -> &'static str {
+        match self {
+            BlockColor::Blue => "blue",
+            BlockColor::Red => "red",
+            BlockColor::Unmerged => "unmerged",
+        }
+    }
+}
+
+/// Colours the blocks as they are in the merge set of the chain block merging them
+fn block_colors(response: &ExportDagResponse) -> HashMap<RpcHash, BlockColor> {
+    let mut colors = HashMap::new();
+    for block in response.blocks.iter().filter(|block| block.is_chain_block) {
+        colors.insert(block.hash, BlockColor::Blue);
+        colors.extend(block.mergeset_blues.iter().map(|&hash| (hash, BlockColor::Blue)));
+        colors.extend(block.mergeset_reds.iter().map(|&hash| (hash, BlockColor::Red)));
+    }
+    colors
+}
+
+/// Parents of the block within the exported sub-DAG, paired with whether the parent is the selected one
+fn edges<'a>(block: &'a RpcDagBlock, hashes: &'a HashSet<RpcHash>) -> impl Iterator<Item = (RpcHash, bool)> + 'a {
+    block.parents.iter().filter(|parent| hashes.contains(parent)).map(|&parent| (parent, parent == block.selected_parent))
+}
+
+fn to_dot(response: &ExportDagResponse) -> String {
+    let colors = block_colors(response);
+    let hashes = response.blocks.iter().map(|block| block.hash).collect::<HashSet<_>>();
+    let mut dot = String::from("digraph dag {\n    rankdir=RL;\n    node [style=filled, fontname=monospace];\n");
+    for block in response.blocks.iter() {
+        let color = colors.get(&block.hash).copied().unwrap_or(BlockColor::Unmerged);
+        let fill = match color {
+            BlockColor::Blue => "lightblue",
+            BlockColor::Red => "salmon",
+            BlockColor::Unmerged => "lightgray",
+        };
+        let shape = if block.is_chain_block { "doublecircle" } else { "circle" };
+        let hash = block.hash.to_string();
+        writeln!(
+            dot,
+            "    \"{hash}\" [label=\"{}\\nblue score {}\\nDAA score {}\", fillcolor={fill}, shape={shape}];",
+            &hash[..8],
+            block.blue_score,
+            block.daa_score
+        )
+        .unwrap();
+        for (parent, is_selected_parent) in edges(block, &hashes) {
+            let style = if is_selected_parent { "bold" } else { "solid" };
+            writeln!(dot, "    \"{hash}\" -> \"{parent}\" [style={style}];").unwrap();
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+fn to_graphml(response: &ExportDagResponse) -> String {
+    const KEYS: [(&str, &str, &str); 8] = [
+        ("blueScore", "node", "long"),
+        ("blueWork", "node", "string"),
+        ("daaScore", "node", "long"),
+        ("color", "node", "string"),
+        ("isChainBlock", "node", "boolean"),
+        ("mergesetBlues", "node", "string"),
+        ("mergesetReds", "node", "string"),
+        ("isSelectedParent", "edge", "boolean"),
+    ];
+
+    let colors = block_colors(response);
+    let hashes = response.blocks.iter().map(|block| block.hash).collect::<HashSet<_>>();
+    let mut graphml =
+        String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+    for (name, domain, kind) in KEYS {
+        writeln!(graphml, "  <key id=\"{name}\" for=\"{domain}\" attr.name=\"{name}\" attr.type=\"{kind}\"/>").unwrap();
+    }
+    graphml.push_str("  <graph id=\"dag\" edgedefault=\"directed\">\n");
+    for block in response.blocks.iter() {
+        let color = colors.get(&block.hash).copied().unwrap_or(BlockColor::Unmerged);
+        let join = |hashes: &[RpcHash]| hashes.iter().map(|hash| hash.to_string()).collect::<Vec<_>>().join(" ");
+        writeln!(graphml, "    <node id=\"{}\">", block.hash).unwrap();
+        writeln!(graphml, "      <data key=\"blueScore\">{}</data>", block.blue_score).unwrap();
+        writeln!(graphml, "      <data key=\"blueWork\">{}</data>", block.blue_work.to_rpc_hex()).unwrap();
+        writeln!(graphml, "      <data key=\"daaScore\">{}</data>", block.daa_score).unwrap();
+        writeln!(graphml, "      <data key=\"color\">{}</data>", color.as_str()).unwrap();
+        writeln!(graphml, "      <data key=\"isChainBlock\">{}</data>", block.is_chain_block).unwrap();
+        writeln!(graphml, "      <data key=\"mergesetBlues\">{}</data>", join(&block.mergeset_blues)).unwrap();
+        writeln!(graphml, "      <data key=\"mergesetReds\">{}</data>", join(&block.mergeset_reds)).unwrap();
+        graphml.push_str("    </node>\n");
+        for (parent, is_selected_parent) in edges(block, &hashes) {
+            writeln!(
+                graphml,
+                "    <edge source=\"{}\" target=\"{parent}\"><data key=\"isSelectedParent\">{is_selected_parent}</data></edge>",
+                block.hash
+            )
+            .unwrap();
+        }
+    }
+    graphml.push_str("  </graph>\n</graphml>\n");
+    graphml
+}
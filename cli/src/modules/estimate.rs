@@ -1,5 +1,5 @@
 use crate::imports::*;
-use calico_wallet_core::tx::PaymentDestination;
+use calico_wallet_core::tx::{CoinSelectionStrategy, PaymentDestination};
 
 #[derive(Default, Handler)]
 #[help("Estimate the fees for a transaction of a given amount")]
@@ -12,20 +12,32 @@ impl Estimate {
         let account = ctx.wallet().account()?;
 
         if argv.is_empty() {
-            tprintln!(ctx, "usage: estimate <amount> [<priority fee>]\n\nArguments:\n  <amount>        The amount for which you want to estimate the transaction fees.\n  [<priority fee>]  (Optional) The additional fee to prioritize the transaction.");
+            tprintln!(ctx, "usage: estimate <amount> [<priority fee>] [<coin selection>]\n\nArguments:\n  <amount>        The amount for which you want to estimate the transaction fees.\n  [<priority fee>]  (Optional) The additional fee to prioritize the transaction.\n  [<coin selection>]  (Optional) The coin selection strategy; if omitted, estimates for all strategies are displayed.");
             return Ok(());
         }
 
         let amount_sompi = try_parse_required_nonzero_calico_as_sompi_u64(argv.first())?;
         let priority_fee_sompi = try_parse_optional_calico_as_sompi_i64(argv.get(1))?.unwrap_or(0);
+        let strategies = if let Some(strategy) = argv.get(2) {
+            vec![strategy.parse::<CoinSelectionStrategy>()?]
+        } else {
+            CoinSelectionStrategy::list().to_vec()
+        };
         let abortable = Abortable::default();
 
         // just use any address for an estimate (change address)
         let change_address = account.change_address()?;
-        let destination = PaymentDestination::PaymentOutputs(PaymentOutputs::from((change_address.clone(), amount_sompi)));
-        let estimate = account.estimate(destination, priority_fee_sompi.into(), None, &abortable).await?;
-
-        tprintln!(ctx, "Estimated Fees: {estimate}");
+        for strategy in strategies {
+            let destination = PaymentDestination::PaymentOutputs(PaymentOutputs::from((change_address.clone(), amount_sompi)));
+            match account
+                .clone()
+                .estimate_with_coin_selection(destination, priority_fee_sompi.into(), None, strategy, &abortable)
+                .await
+            {
+                Ok(estimate) => tprintln!(ctx, "{}: Estimated Fees: {estimate}", strategy.to_string().pad_to_width(18)),
+                Err(err) => tprintln!(ctx, "{}: {err}", strategy.to_string().pad_to_width(18)),
+            }
+        }
 
         Ok(())
     }
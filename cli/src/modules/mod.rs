@@ -5,8 +5,10 @@ pub mod address;
 pub mod broadcast;
 pub mod close;
 pub mod connect;
+pub mod contract;
 #[path = "create-unsigned-tx.rs"]
 pub mod create_unsigned_tx;
+pub mod dag;
 pub mod details;
 pub mod disconnect;
 pub mod estimate;
@@ -57,9 +59,9 @@ pub fn register_handlers(cli: &Arc<CalicoCli>) -> Result<()> {
         cli,
         cli.handlers(),
         [
-            account, address, close, connect, details, disconnect, estimate, exit, export, guide, help, history, rpc, list, miner,
-            message, monitor, mute, network, node, open, ping, pssb, reload, select, send, server, settings, sweep, track, transfer,
-            wallet,
+            account, address, close, connect, contract, dag, details, disconnect, estimate, exit, export, guide, help, history, rpc,
+            list, miner, message, monitor, mute, network, node, open, ping, pssb, reload, select, send, server, settings, sweep,
+            track, transfer, wallet,
             // halt,
             // theme,  start, stop
         ]
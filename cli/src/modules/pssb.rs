@@ -2,6 +2,7 @@
 
 use crate::imports::*;
 use calico_addresses::Prefix;
+use calico_consensus_core::config::params::Params;
 use calico_consensus_core::tx::{TransactionOutpoint, UtxoEntry};
 use calico_wallet_core::account::pssb::finalize_psst_one_or_more_sig_and_redeem_script;
 use calico_wallet_psst::{
@@ -192,6 +193,45 @@ impl Pssb {
                     Err(e) => terrorln!(ctx, "Send error {:?}", e),
                 }
             }
+            "combine" => {
+                if argv.len() < 2 {
+                    return self.display_help(ctx, argv).await;
+                }
+                let mut pssb = Self::parse_input_pssb(argv.remove(0).as_str())?;
+                for input in argv.iter() {
+                    pssb = pssb.combine(&Self::parse_input_pssb(input.as_str())?)?;
+                }
+                let pssb_pack = String::try_from(pssb)?;
+                tprintln!(ctx, "{pssb_pack}");
+            }
+            "finalize" => {
+                if argv.len() != 1 {
+                    return self.display_help(ctx, argv).await;
+                }
+                let pssb = Self::parse_input_pssb(argv.first().unwrap().as_str())?;
+                let pssb_pack = String::try_from(pssb.finalize()?)?;
+                tprintln!(ctx, "{pssb_pack}");
+            }
+            "extract" => {
+                if argv.len() != 1 {
+                    return self.display_help(ctx, argv).await;
+                }
+                let pssb = Self::parse_input_pssb(argv.first().unwrap().as_str())?;
+                let network_id = ctx.wallet().network_id()?;
+                for extracted in pssb.extract(&Params::from(network_id))? {
+                    tprintln!(ctx, "Transaction {}", extracted.transaction.id());
+                    tprintln!(ctx, "  mass: {}", extracted.mass);
+                    tprintln!(ctx, "  fee: {}", sompi_to_calico_string_with_suffix(extracted.fee, &network_id.into()));
+                    tprintln!(ctx, "{}", serde_json::to_string_pretty(&extracted.transaction)?);
+                }
+            }
+            "inspect" => {
+                if argv.len() != 1 {
+                    return self.display_help(ctx, argv).await;
+                }
+                let pssb = Self::parse_input_pssb(argv.first().unwrap().as_str())?;
+                tprintln!(ctx, "{}", pssb.inspect(ctx.wallet().network_id()?, sompi_to_calico_string_with_suffix));
+            }
             "debug" => {
                 if argv.len() != 1 {
                     return self.display_help(ctx, argv).await;
@@ -250,6 +290,10 @@ impl Pssb {
                 ("pssb create <address> <amount> <priority fee>", "Create a PSSB from single send transaction"),
                 ("pssb sign <pssb>", "Sign given PSSB"),
                 ("pssb send <pssb>", "Broadcast bundled transactions"),
+                ("pssb combine <pssb> <pssb> [<pssb> ...]", "Combine signatures supplied by multiple signers into a single PSSB"),
+                ("pssb finalize <pssb>", "Finalize all inputs of a fully signed PSSB"),
+                ("pssb extract <pssb>", "Extract and verify transactions (mass and fees) from a finalized PSSB"),
+                ("pssb inspect <pssb>", "Print PSSB signing status, amounts and fees"),
                 ("pssb debug <payload>", "Print PSSB debug view"),
                 ("pssb parse <payload>", "Print PSSB formatted view"),
                 ("pssb script lock <payload> <amount> [priority fee]", "Generate a PSSB with one send transaction to given P2SH payload. Optional public key placeholder in payload: {{pubkey}}"),
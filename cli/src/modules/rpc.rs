@@ -156,6 +156,11 @@ impl Rpc {
                 let result = rpc.shutdown_call(None, ShutdownRequest {}).await?;
                 self.println(&ctx, result);
             }
+            RpcApiOps::CreateDatabaseCheckpoint => {
+                let directory = (!argv.is_empty()).then(|| argv.remove(0));
+                let result = rpc.create_database_checkpoint_call(None, CreateDatabaseCheckpointRequest { directory }).await?;
+                self.println(&ctx, result);
+            }
             // RpcApiOps::GetHeaders => {
             //     let result = rpc.get_headers_call(GetHeadersRequest {  }).await?;
             //     self.println(&ctx, result);
@@ -1,4 +1,5 @@
 use crate::imports::*;
+use calico_wallet_core::tx::CoinSelectionStrategy;
 
 #[derive(Default, Handler)]
 #[help("Send a Calico transaction to a public address")]
@@ -12,23 +13,30 @@ impl Send {
         let account = ctx.wallet().account()?;
 
         if argv.len() < 2 {
-            tprintln!(ctx, "Usage: send <address> <amount> <priority fee>");
+            tprintln!(ctx, "Usage: send <address> <amount> <priority fee> [<coin selection>]");
+            tprintln!(ctx, "");
+            tprintln!(ctx, "Coin selection strategies:");
+            for strategy in CoinSelectionStrategy::list() {
+                tprintln!(ctx, "  {}: {}", strategy.to_string().pad_to_width(18), strategy.describe());
+            }
             return Ok(());
         }
 
         let address = Address::try_from(argv.first().unwrap().as_str())?;
         let amount_sompi = try_parse_required_nonzero_calico_as_sompi_u64(argv.get(1))?;
         let priority_fee_sompi = try_parse_optional_calico_as_sompi_i64(argv.get(2))?.unwrap_or(0);
+        let coin_selection = argv.get(3).map(|strategy| strategy.parse::<CoinSelectionStrategy>()).transpose()?.unwrap_or_default();
         let outputs = PaymentOutputs::from((address.clone(), amount_sompi));
         let abortable = Abortable::default();
         let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
 
         // let ctx_ = ctx.clone();
         let (summary, _ids) = account
-            .send(
+            .send_with_coin_selection(
                 outputs.into(),
                 priority_fee_sompi.into(),
                 None,
+                coin_selection,
                 wallet_secret,
                 payment_secret,
                 &abortable,
@@ -2,14 +2,22 @@ use crate::imports::*;
 use calico_wrpc_client::parse::parse_host;
 
 #[derive(Default, Handler)]
-#[help("Configure the RPC server address")]
+#[help("Configure the RPC server address and API key")]
 pub struct Server;
 
 impl Server {
     async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
         let ctx = ctx.clone().downcast_arc::<CalicoCli>()?;
 
-        if let Some(url) = argv.first() {
+        if let Some(key) = argv.first().and_then(|arg| arg.strip_prefix("--api-key=")) {
+            if key.is_empty() {
+                ctx.wallet().settings().clear(WalletSettings::ApiKey).await?;
+                tprintln!(ctx, "RPC server API key cleared");
+            } else {
+                ctx.wallet().settings().set(WalletSettings::ApiKey, key).await?;
+                tprintln!(ctx, "RPC server API key set");
+            }
+        } else if let Some(url) = argv.first() {
             let Ok(_) = parse_host(url) else {
                 tprintln!(ctx, "Invalid host: {url}");
                 return Ok(());
@@ -12,7 +12,10 @@ impl Settings {
         // let list = WalletSettings::list();
         let list = WalletSettings::into_iter()
             .map(|setting| {
-                let value: String = ctx.wallet().settings().get(setting.clone()).unwrap_or_else(|| "-".to_string());
+                let value: String = match ctx.wallet().settings().get::<String>(setting.clone()) {
+                    Some(_) if setting == WalletSettings::ApiKey => "********".to_string(),
+                    value => value.unwrap_or_else(|| "-".to_string()),
+                };
                 let descr = setting.describe();
                 (setting.as_str().to_lowercase(), value, descr)
             })
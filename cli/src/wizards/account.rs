@@ -113,6 +113,40 @@ pub(crate) async fn bip32_watch(ctx: &Arc<CalicoCli>, name: Option<&str>) -> Res
     Ok(())
 }
 
+pub(crate) async fn external(ctx: &Arc<CalicoCli>, name: Option<&str>) -> Result<()> {
+    let term = ctx.term();
+    let wallet = ctx.wallet();
+
+    let name = if let Some(name) = name {
+        Some(name.to_string())
+    } else {
+        Some(term.ask(false, "Please enter account name (optional, press <enter> to skip): ").await?.trim().to_string())
+    };
+
+    let mut xpub_keys = Vec::with_capacity(1);
+    let xpub_key = term.ask(false, "Enter extended public key of the external signer: ").await?;
+    xpub_keys.push(xpub_key.trim().to_owned());
+
+    tprintln!(ctx, "");
+    tprintln!(ctx, "Signer endpoints can be specified as 'stdio:<command>', 'tcp:<host:port>' or 'unix:<path>'.");
+    tprintln!(ctx, "If no endpoint is specified, transactions must be signed manually using 'pssb' commands.");
+    tprintln!(ctx, "");
+    let endpoint = term.ask(false, "Enter signer endpoint (optional, press <enter> to skip): ").await?.trim().to_string();
+    let endpoint = (!endpoint.is_empty()).then_some(endpoint);
+
+    let wallet_secret = Secret::new(term.ask(true, "Enter wallet password: ").await?.trim().as_bytes().to_vec());
+    if wallet_secret.as_ref().is_empty() {
+        return Err(Error::WalletSecretRequired);
+    }
+
+    let account_create_args_external = AccountCreateArgsExternal::new(name, xpub_keys, endpoint);
+    let account = wallet.create_account_external(&wallet_secret, account_create_args_external).await?;
+
+    tprintln!(ctx, "\naccount created: {}\n", account.get_list_string()?);
+    wallet.select(Some(&account)).await?;
+    Ok(())
+}
+
 pub(crate) async fn multisig_watch(ctx: &Arc<CalicoCli>, name: Option<&str>) -> Result<()> {
     let term = ctx.term();
 
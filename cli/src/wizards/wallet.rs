@@ -4,7 +4,7 @@ use crate::result::Result;
 use calico_bip32::{Language, Mnemonic, WordCount};
 use calico_wallet_core::{
     storage::{make_filename, Hint},
-    wallet::WalletGuard,
+    wallet::{WalletGuard, DEFAULT_ACCOUNT_GAP_LIMIT, DEFAULT_ADDRESS_GAP_LIMIT},
 };
 
 pub(crate) async fn create(
@@ -179,7 +179,26 @@ pub(crate) async fn create(
     term.writeln("");
 
     wallet.open(&wallet_secret, name.map(String::from), WalletOpenArgs::default_with_legacy_accounts(), &guard).await?;
-    wallet.activate_accounts(None, &guard).await?;
+
+    if import_with_mnemonic && wallet.is_connected() {
+        tprintln!(ctx, "Discovering accounts...");
+        let accounts = wallet
+            .restore_bip44_accounts(
+                &wallet_secret,
+                prv_key_data_id,
+                payment_secret.as_ref(),
+                DEFAULT_ADDRESS_GAP_LIMIT,
+                DEFAULT_ACCOUNT_GAP_LIMIT,
+                &guard,
+            )
+            .await?;
+        tprintln!(ctx, "Restored {} account(s)", accounts.len());
+    } else {
+        if import_with_mnemonic {
+            twarnln!(ctx, "Wallet is not connected to the network, use 'account rescan' to discover additional accounts");
+        }
+        wallet.activate_accounts(None, &guard).await?;
+    }
 
     Ok(())
 }
@@ -1,8 +1,11 @@
-use calico_consensus_core::api::{ConsensusApi, DynConsensus};
+use calico_consensus_core::{
+    api::{stats::StorageStats, ConsensusApi, DbCheckpointInfo, DynConsensus},
+    errors::consensus::ConsensusResult,
+};
 use calico_core::{core::Core, debug, service::Service};
 use itertools::Itertools;
 use parking_lot::RwLock;
-use std::{collections::VecDeque, ops::Deref, sync::Arc, thread::JoinHandle};
+use std::{collections::VecDeque, ops::Deref, path::Path, sync::Arc, thread::JoinHandle};
 
 mod batch;
 mod session;
@@ -44,6 +47,19 @@ pub trait ConsensusFactory: Sync + Send {
     /// Delete the staging consensus entry and its database (this is done even if the node is archival
     /// since staging reflects non-final data)
     fn delete_staging_entry(&self);
+
+    /// Create a checkpoint of the active consensus database and of the multi-consensus management database
+    /// under `directory`, reproducing the layout of the data directory. `on_checkpointed` is called with
+    /// the name of each database once its checkpoint is created
+    fn create_checkpoint(
+        &self,
+        active: &ConsensusInstance,
+        directory: &Path,
+        on_checkpointed: &dyn Fn(&str),
+    ) -> ConsensusResult<DbCheckpointInfo>;
+
+    /// Storage engine stats of the multi-consensus management database
+    fn meta_storage_stats(&self) -> StorageStats;
 }
 
 /// Test-only mock factory
@@ -69,6 +85,14 @@ impl ConsensusFactory for MockFactory {
     fn delete_staging_entry(&self) {
         unimplemented!()
     }
+
+    fn create_checkpoint(&self, _: &ConsensusInstance, _: &Path, _: &dyn Fn(&str)) -> ConsensusResult<DbCheckpointInfo> {
+        unimplemented!()
+    }
+
+    fn meta_storage_stats(&self) -> StorageStats {
+        unimplemented!()
+    }
 }
 
 /// Defines a trait which handles consensus resets for external parts of the system. We avoid using
@@ -171,6 +195,19 @@ impl ConsensusManager {
     pub fn delete_staging_entry(&self) {
         self.factory.delete_staging_entry();
     }
+
+    /// Creates a checkpoint of the current consensus and of the management databases under `directory`.
+    /// The current consensus cannot be replaced by a staging consensus while the checkpoint is created.
+    pub fn create_checkpoint(&self, directory: &Path, on_checkpointed: &dyn Fn(&str)) -> ConsensusResult<DbCheckpointInfo> {
+        // Holding the read lock blocks `StagingConsensus::commit`
+        let g = self.inner.read();
+        self.factory.create_checkpoint(&g.current.consensus, directory, on_checkpointed)
+    }
+
+    /// Storage engine stats of the multi-consensus management database
+    pub fn meta_storage_stats(&self) -> StorageStats {
+        self.factory.meta_storage_stats()
+    }
 }
 
 impl Service for ConsensusManager {
@@ -297,10 +297,26 @@ impl ConsensusSessionOwned {
         self.clone().spawn_blocking(move |c| c.is_chain_ancestor_of(low, high)).await
     }
 
+    pub async fn async_is_dag_ancestor_of(&self, low: Hash, high: Hash) -> ConsensusResult<bool> {
+        self.clone().spawn_blocking(move |c| c.is_dag_ancestor_of(low, high)).await
+    }
+
+    pub async fn async_get_chain_path(&self, from: Hash, to: Hash, chain_path_limit: Option<usize>) -> ConsensusResult<ChainPath> {
+        self.clone().spawn_blocking(move |c| c.get_chain_path(from, to, chain_path_limit)).await
+    }
+
     pub async fn async_get_hashes_between(&self, low: Hash, high: Hash, max_blocks: usize) -> ConsensusResult<(Vec<Hash>, Hash)> {
         self.clone().spawn_blocking(move |c| c.get_hashes_between(low, high, max_blocks)).await
     }
 
+    pub async fn async_get_sub_dag(&self, low: Hash, high: Hash, max_blocks: u64) -> ConsensusResult<Vec<Hash>> {
+        self.clone().spawn_blocking(move |c| c.get_sub_dag(low, high, max_blocks)).await
+    }
+
+    pub async fn async_get_chain_block_by_daa_score(&self, daa_score: u64) -> ConsensusResult<Hash> {
+        self.clone().spawn_blocking(move |c| c.get_chain_block_by_daa_score(daa_score)).await
+    }
+
     pub async fn async_get_header(&self, hash: Hash) -> ConsensusResult<Arc<Header>> {
         self.clone().spawn_blocking(move |c| c.get_header(hash)).await
     }
@@ -330,6 +346,10 @@ impl ConsensusSessionOwned {
         self.clone().spawn_blocking(move |c| c.get_anticone(hash)).await
     }
 
+    pub async fn async_get_anticone_size(&self, hash: Hash, max_traversal_allowed: Option<u64>) -> ConsensusResult<u64> {
+        self.clone().spawn_blocking(move |c| c.get_anticone_size(hash, max_traversal_allowed)).await
+    }
+
     pub async fn async_get_pruning_point_proof(&self) -> Arc<PruningPointProof> {
         self.clone().spawn_blocking(|c| c.get_pruning_point_proof()).await
     }
@@ -1,6 +1,6 @@
 use calico_muhash::MuHash;
 use futures_util::future::BoxFuture;
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 use crate::{
     acceptance_data::AcceptanceData,
@@ -43,6 +43,13 @@ pub struct BlockValidationFutures {
     pub virtual_state_task: BlockValidationFuture,
 }
 
+/// The virtual state captured by a database checkpoint
+#[derive(Debug, Clone, Copy)]
+pub struct DbCheckpointInfo {
+    pub sink: Hash,
+    pub virtual_daa_score: u64,
+}
+
 /// Abstracts the consensus external API
 #[allow(unused_variables)]
 pub trait ConsensusApi: Send + Sync {
@@ -227,6 +234,18 @@ pub trait ConsensusApi: Send + Sync {
         unimplemented!()
     }
 
+    fn is_dag_ancestor_of(&self, low: Hash, high: Hash) -> ConsensusResult<bool> {
+        unimplemented!()
+    }
+
+    /// Returns the chain path from `from` to `to`, i.e. the chain blocks of `from` down to their common chain
+    /// ancestor (`removed`) and the chain blocks from there up to `to` (`added`). Both blocks must have the source
+    /// on their chain. The added chain blocks are truncated to `chain_path_limit`, while more removed chain blocks
+    /// than the limit fail the call.
+    fn get_chain_path(&self, from: Hash, to: Hash, chain_path_limit: Option<usize>) -> ConsensusResult<ChainPath> {
+        unimplemented!()
+    }
+
     fn get_hashes_between(&self, low: Hash, high: Hash, max_blocks: usize) -> ConsensusResult<(Vec<Hash>, Hash)> {
         unimplemented!()
     }
@@ -246,11 +265,29 @@ pub trait ConsensusApi: Send + Sync {
         unimplemented!()
     }
 
+    /// Returns the blocks in `past(high) ∩ future(low)`, including `low` and `high`, ordered topologically
+    /// by blue work. Fails if `low` is not in the past of `high` or if the sub-DAG exceeds `max_blocks` blocks
+    fn get_sub_dag(&self, low: Hash, high: Hash, max_blocks: u64) -> ConsensusResult<Vec<Hash>> {
+        unimplemented!()
+    }
+
+    /// Returns the highest selected chain block with a DAA score lower than or equal to `daa_score`,
+    /// or the source if there is no such block
+    fn get_chain_block_by_daa_score(&self, daa_score: u64) -> ConsensusResult<Hash> {
+        unimplemented!()
+    }
+
     /// Returns the anticone of block `hash` from the POV of `virtual`
     fn get_anticone(&self, hash: Hash) -> ConsensusResult<Vec<Hash>> {
         unimplemented!()
     }
 
+    /// Returns the size of the anticone of block `hash` from the POV of `virtual`, traversing at most
+    /// `max_traversal_allowed` blocks
+    fn get_anticone_size(&self, hash: Hash, max_traversal_allowed: Option<u64>) -> ConsensusResult<u64> {
+        unimplemented!()
+    }
+
     fn get_pruning_point_proof(&self) -> Arc<PruningPointProof> {
         unimplemented!()
     }
@@ -362,6 +399,12 @@ pub trait ConsensusApi: Send + Sync {
     fn finality_point(&self) -> Hash {
         unimplemented!()
     }
+
+    /// Creates a checkpoint of the consensus database at `directory`, which must not exist. The virtual
+    /// state is frozen while the checkpoint is created, so the checkpoint captures a committed virtual state.
+    fn create_checkpoint(&self, directory: &Path) -> ConsensusResult<DbCheckpointInfo> {
+        unimplemented!()
+    }
 }
 
 pub type DynConsensus = Arc<dyn ConsensusApi>;
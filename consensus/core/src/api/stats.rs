@@ -43,6 +43,24 @@ pub struct VirtualStateStats {
     pub past_median_time: u64,
 }
 
+/// Storage engine stats, as estimated by the database. Each stat is `None` if the DB backend does not
+/// report it (the in-memory backend reports none of them)
+#[derive(Clone, Default)]
+pub struct StorageStats {
+    /// Total size of the SST files
+    pub sst_files_size: Option<u64>,
+    /// Estimated size of the live data
+    pub live_data_size: Option<u64>,
+    /// Estimated number of keys
+    pub num_keys: Option<u64>,
+    /// Size of the active and unflushed memtables
+    pub memtables_size: Option<u64>,
+    /// Estimated number of bytes compaction needs to rewrite
+    pub pending_compaction_bytes: Option<u64>,
+    /// Number of currently running compactions
+    pub running_compactions: Option<u64>,
+}
+
 pub struct ConsensusStats {
     /// Block and header counts
     pub block_counts: BlockCount,
@@ -52,4 +70,7 @@ pub struct ConsensusStats {
 
     /// Virtual-related stats
     pub virtual_stats: VirtualStateStats,
+
+    /// Database stats
+    pub storage_stats: StorageStats,
 }
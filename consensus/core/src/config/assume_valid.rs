@@ -0,0 +1,99 @@
+//! The network default assume-valid blocks.
+//!
+//! The defaults are bumped as part of cutting a release:
+//! 1. On a node which fully validated the network with `--assume-valid=none`, take the selected chain block
+//!    whose DAA score is roughly one week (about 600K DAA scores at mainnet's 1 BPS) below the sink, so it is well past
+//!    finality depth by the time the release is adopted.
+//! 2. Confirm the same hash is on the selected chain of a second, independently synced node.
+//! 3. Set it as [`MAINNET_ASSUME_VALID_BLOCK`] (or [`TESTNET_ASSUME_VALID_BLOCK`]) in the release PR, quoting
+//!    the DAA score it was taken at so reviewers can repeat the check.
+//!
+//! Until the first release setting one, the constants are unset and the network default fully validates all blocks.
+
+use crate::network::NetworkType;
+use calico_hashes::Hash;
+use std::{fmt::Display, str::FromStr};
+
+/// The mainnet assume-valid block, see the module docs for how it is updated
+pub const MAINNET_ASSUME_VALID_BLOCK: Option<Hash> = None;
+
+/// The testnet assume-valid block, see the module docs for how it is updated. It is reset whenever the testnet is
+pub const TESTNET_ASSUME_VALID_BLOCK: Option<Hash> = None;
+
+/// Returns the assume-valid block hard-coded for the network, if any.
+///
+/// Devnet and simnet are regularly reset and never get one.
+pub fn network_assume_valid_block(network_type: NetworkType) -> Option<Hash> {
+    match network_type {
+        NetworkType::Mainnet => MAINNET_ASSUME_VALID_BLOCK,
+        NetworkType::Testnet => TESTNET_ASSUME_VALID_BLOCK,
+        NetworkType::Devnet | NetworkType::Simnet => None,
+    }
+}
+
+/// Defines the block whose past is assumed to hold valid transaction scripts.
+///
+/// Blocks in the past of the assume-valid block (inclusive) skip signature and script checks when their
+/// UTXO state is verified, while still going through full structure, PoW, mass and UTXO validation.
+/// The assume-valid block is only honored once it is known to be on the headers selected chain.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AssumeValid {
+    /// Use the block hard-coded for the network, if any
+    #[default]
+    NetworkDefault,
+    /// Fully validate the scripts of all blocks
+    Disabled,
+    /// Assume the scripts of the given block and its past to be valid
+    Block(Hash),
+}
+
+impl AssumeValid {
+    /// Resolves the assume-valid block for the given network
+    pub fn block(&self, network_type: NetworkType) -> Option<Hash> {
+        match self {
+            AssumeValid::NetworkDefault => network_assume_valid_block(network_type),
+            AssumeValid::Disabled => None,
+            AssumeValid::Block(hash) => Some(*hash),
+        }
+    }
+}
+
+impl Display for AssumeValid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssumeValid::NetworkDefault => f.write_str("default"),
+            AssumeValid::Disabled => f.write_str("none"),
+            AssumeValid::Block(hash) => write!(f, "{hash}"),
+        }
+    }
+}
+
+impl FromStr for AssumeValid {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(AssumeValid::NetworkDefault),
+            "none" | "0" => Ok(AssumeValid::Disabled),
+            _ => Hash::from_str(s)
+                .map(AssumeValid::Block)
+                .map_err(|_| format!("invalid assume-valid value '{s}', expected one of: default, none, <block hash>")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assume_valid_parsing() {
+        for assume_valid in [AssumeValid::NetworkDefault, AssumeValid::Disabled, AssumeValid::Block(Hash::from_u64_word(42))] {
+            assert_eq!(assume_valid, assume_valid.to_string().parse().unwrap());
+        }
+        assert_eq!(AssumeValid::Disabled, "0".parse().unwrap());
+        assert!("abc".parse::<AssumeValid>().is_err());
+        assert_eq!(AssumeValid::Disabled.block(NetworkType::Mainnet), None);
+        assert_eq!(AssumeValid::Block(Hash::from_u64_word(42)).block(NetworkType::Simnet), Some(Hash::from_u64_word(42)));
+    }
+}
@@ -1,7 +1,9 @@
+pub mod assume_valid;
 pub mod bps;
 pub mod constants;
 pub mod genesis;
 pub mod params;
+pub mod retention;
 
 use calico_utils::networking::{ContextualNetAddress, NetAddress};
 
@@ -12,9 +14,13 @@ use std::sync::Arc;
 
 use std::ops::Deref;
 
+use calico_hashes::Hash;
+
 use {
+    assume_valid::AssumeValid,
     constants::perf::{PerfParams, PERF_PARAMS},
     params::Params,
+    retention::RetentionPolicy,
 };
 
 /// Various consensus configurations all bundled up under a single struct. Use `Config::new` for directly building from
@@ -35,13 +41,26 @@ pub struct Config {
     /// Indicates whether this node is an archival node
     pub is_archival: bool,
 
+    /// Defines which block data is kept by an archival node for blocks in the past of the pruning point
+    pub retention: RetentionPolicy,
+
+    /// Defines the block whose past skips transaction script checks during UTXO validation
+    pub assume_valid: AssumeValid,
+
     /// Enable various sanity checks which might be compute-intensive (mostly performed during pruning)
     pub enable_sanity_checks: bool,
 
+    /// Enable a background audit re-verifying consensus invariants of every new selected chain block
+    /// (ghostdag, reachability, DAA window, coinbase reward and UTXO commitment). Only meant for test networks
+    pub enable_audit: bool,
+
     // TODO: move non-consensus parameters like utxoindex to a higher scoped Config
     /// Enable the UTXO index
     pub utxoindex: bool,
 
+    /// Enable the chain block index by DAA score and timestamp
+    pub chainindex: bool,
+
     /// Enable RPC commands which affect the state of the node
     pub unsafe_rpc: bool,
 
@@ -81,8 +100,12 @@ impl Config {
             perf,
             process_genesis: true,
             is_archival: false,
+            retention: RetentionPolicy::Full,
+            assume_valid: AssumeValid::NetworkDefault,
             enable_sanity_checks: false,
+            enable_audit: false,
             utxoindex: false,
+            chainindex: false,
             unsafe_rpc: false,
             enable_unsynced_mining: false,
             enable_mainnet_mining: false,
@@ -98,6 +121,11 @@ impl Config {
         }
     }
 
+    /// Returns the assume-valid block resolved for the configured network, if any
+    pub fn assume_valid_block(&self) -> Option<Hash> {
+        self.assume_valid.block(self.net.network_type)
+    }
+
     pub fn to_builder(&self) -> ConfigBuilder {
         ConfigBuilder { config: self.clone() }
     }
@@ -162,11 +190,21 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn set_retention_policy(mut self, retention: RetentionPolicy) -> Self {
+        self.config.retention = retention;
+        self
+    }
+
     pub fn enable_sanity_checks(mut self) -> Self {
         self.config.enable_sanity_checks = true;
         self
     }
 
+    pub fn enable_audit(mut self) -> Self {
+        self.config.enable_audit = true;
+        self
+    }
+
     pub fn skip_adding_genesis(mut self) -> Self {
         self.config.process_genesis = false;
         self
@@ -0,0 +1,82 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Defines which block data an archival node keeps for blocks in the past of the pruning point.
+///
+/// Headers, relations, reachability and GHOSTDAG data are always kept by archival nodes, so the
+/// policy only affects block bodies and the data derived from them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RetentionPolicy {
+    /// Keep all block data forever
+    #[default]
+    Full,
+    /// Keep block bodies and their derived data only for blocks not older than the given number of days
+    BodiesForDays(u64),
+    /// Keep the bodies and the acceptance data of selected chain blocks only
+    ChainBodies,
+    /// Keep the acceptance data of selected chain blocks, but no block bodies
+    AcceptanceData,
+}
+
+impl RetentionPolicy {
+    /// Returns whether the bodies of blocks which are not selected chain blocks are kept
+    pub fn keeps_merged_bodies(&self) -> bool {
+        matches!(self, RetentionPolicy::Full)
+    }
+
+    /// Returns whether the bodies of selected chain blocks are kept
+    pub fn keeps_chain_bodies(&self) -> bool {
+        matches!(self, RetentionPolicy::Full | RetentionPolicy::ChainBodies)
+    }
+
+    /// Returns whether the acceptance data of selected chain blocks is kept
+    pub fn keeps_acceptance_data(&self) -> bool {
+        !matches!(self, RetentionPolicy::BodiesForDays(_))
+    }
+}
+
+impl Display for RetentionPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RetentionPolicy::Full => f.write_str("full"),
+            RetentionPolicy::BodiesForDays(days) => write!(f, "bodies:{days}"),
+            RetentionPolicy::ChainBodies => f.write_str("chain-bodies"),
+            RetentionPolicy::AcceptanceData => f.write_str("acceptance-data"),
+        }
+    }
+}
+
+impl FromStr for RetentionPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "full" => Ok(RetentionPolicy::Full),
+            "chain-bodies" => Ok(RetentionPolicy::ChainBodies),
+            "acceptance-data" => Ok(RetentionPolicy::AcceptanceData),
+            _ => match s.strip_prefix("bodies:").map(u64::from_str) {
+                Some(Ok(days)) if days > 0 => Ok(RetentionPolicy::BodiesForDays(days)),
+                Some(_) => Err(format!("invalid number of days in retention policy '{s}', expected a positive integer")),
+                None => {
+                    Err(format!("unknown retention policy '{s}', expected one of: full, bodies:<days>, chain-bodies, acceptance-data"))
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retention_policy_parsing() {
+        for policy in
+            [RetentionPolicy::Full, RetentionPolicy::BodiesForDays(30), RetentionPolicy::ChainBodies, RetentionPolicy::AcceptanceData]
+        {
+            assert_eq!(policy, policy.to_string().parse().unwrap());
+        }
+        assert!("bodies:0".parse::<RetentionPolicy>().is_err());
+        assert!("bodies:".parse::<RetentionPolicy>().is_err());
+        assert!("headers".parse::<RetentionPolicy>().is_err());
+    }
+}
@@ -31,6 +31,14 @@ pub const SEQUENCE_LOCK_TIME_MASK: u64 = 0x00000000ffffffff;
 // as a relative lock time.
 pub const SEQUENCE_LOCK_TIME_DISABLED: u64 = 1 << 63;
 
+/// MAXIMUM_STANDARD_TRANSACTION_MASS is the maximum mass allowed for transactions that
+/// are considered standard and will therefore be relayed and considered for mining.
+pub const MAXIMUM_STANDARD_TRANSACTION_MASS: u64 = 100_000;
+
+/// MINIMUM_RELAY_TRANSACTION_FEE specifies the default minimum transaction fee for a transaction to be accepted to
+/// the mempool and relayed. It is specified in sompi per 1kg (or 1000 grams) of transaction mass.
+pub const MINIMUM_RELAY_TRANSACTION_FEE: u64 = 1000;
+
 /// UNACCEPTED_DAA_SCORE is used to for UtxoEntries that were created by
 /// transactions in the mempool, or otherwise not-yet-accepted transactions.
 pub const UNACCEPTED_DAA_SCORE: u64 = u64::MAX;
@@ -17,6 +17,24 @@ pub enum ConfigError {
     #[error("Configuration: --max-tracked-addresses cannot be set above {0}")]
     MaxTrackedAddressesTooHigh(usize),
 
+    #[error("Configuration: --rpc-tls-cert and --rpc-tls-key have to be used together")]
+    MissingRpcTlsCertOrKey,
+
+    #[error("Configuration: --rpc-tls-client-ca requires --rpc-tls-cert and --rpc-tls-key")]
+    RpcTlsClientCaWithoutTls,
+
+    #[error("Configuration: --reindex and --reset-db cannot be used together")]
+    MixedReindexAndResetDb,
+
+    #[error("Configuration: --restore-from cannot be used together with --reset-db or --reindex")]
+    MixedRestoreAndResetDbOrReindex,
+
+    #[error("Configuration: --retention requires --archival")]
+    RetentionWithoutArchival,
+
+    #[error("Configuration: --audit is only available on testnet, devnet and simnet")]
+    AuditOnMainnet,
+
     #[cfg(feature = "devnet-prealloc")]
     #[error("Cannot preallocate UTXOs on any network except devnet")]
     PreallocUtxosOnNonDevnet,
@@ -32,6 +32,9 @@ pub enum ConsensusError {
     #[error("difficulty error: {0}")]
     DifficultyError(#[from] DifficultyError),
 
+    #[error("database checkpoint error: {0}")]
+    CheckpointError(String),
+
     #[error("{0}")]
     General(&'static str),
 }
@@ -66,6 +66,24 @@ impl NetworkType {
         }
     }
 
+    pub fn default_http_rpc_port(&self) -> u16 {
+        match self {
+            NetworkType::Mainnet => 25110,
+            NetworkType::Testnet => 25210,
+            NetworkType::Simnet => 25510,
+            NetworkType::Devnet => 25610,
+        }
+    }
+
+    pub fn default_metrics_port(&self) -> u16 {
+        match self {
+            NetworkType::Mainnet => 26110,
+            NetworkType::Testnet => 26210,
+            NetworkType::Simnet => 26510,
+            NetworkType::Devnet => 26610,
+        }
+    }
+
     pub fn iter() -> impl Iterator<Item = Self> {
         static NETWORK_TYPES: [NetworkType; 4] =
             [NetworkType::Mainnet, NetworkType::Testnet, NetworkType::Devnet, NetworkType::Simnet];
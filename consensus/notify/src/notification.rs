@@ -1,4 +1,5 @@
-use calico_consensus_core::{acceptance_data::AcceptanceData, block::Block, utxo::utxo_diff::UtxoDiff};
+use calico_addresses::Address;
+use calico_consensus_core::{acceptance_data::AcceptanceData, block::Block, tx::TransactionId, utxo::utxo_diff::UtxoDiff};
 use calico_hashes::Hash;
 use calico_notify::{
     events::EventType,
@@ -42,6 +43,18 @@ pub enum Notification {
 
     #[display(fmt = "NewBlockTemplate notification")]
     NewBlockTemplate(NewBlockTemplateNotification),
+
+    #[display(fmt = "MempoolTransactionAdded notification: transaction id {}", "_0.transaction_id")]
+    MempoolTransactionAdded(MempoolTransactionAddedNotification),
+
+    #[display(fmt = "MempoolTransactionRemoved notification: transaction id {} ({})", "_0.transaction_id", "_0.reason")]
+    MempoolTransactionRemoved(MempoolTransactionRemovedNotification),
+
+    #[display(fmt = "DatabaseCheckpoint notification: {} ({}/{} databases) at {}", "_0.state", "_0.completed_databases", "_0.total_databases", "_0.directory")]
+    DatabaseCheckpoint(DatabaseCheckpointNotification),
+
+    #[display(fmt = "ConsensusAuditViolation notification: {} check failed for block {}: {}", "_0.check", "_0.block_hash", "_0.details")]
+    ConsensusAuditViolation(ConsensusAuditViolationNotification),
 }
 }
 
@@ -181,3 +194,122 @@ pub struct PruningPointUtxoSetOverrideNotification {}
 
 #[derive(Debug, Clone)]
 pub struct NewBlockTemplateNotification {}
+
+#[derive(Debug, Clone)]
+pub struct MempoolTransactionAddedNotification {
+    pub transaction_id: TransactionId,
+    /// Addresses involved in the transaction inputs and outputs
+    pub addresses: Arc<Vec<Address>>,
+}
+
+impl MempoolTransactionAddedNotification {
+    pub fn new(transaction_id: TransactionId, addresses: Arc<Vec<Address>>) -> Self {
+        Self { transaction_id, addresses }
+    }
+}
+
+/// Reason of the removal of a transaction from the mempool
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum MempoolRemovalReason {
+    /// Included in a block accepted by the virtual chain
+    #[display(fmt = "accepted")]
+    Accepted,
+    /// Evicted to make room for a transaction with a higher fee rate
+    #[display(fmt = "evicted")]
+    Evicted,
+    /// Replaced by a double spending transaction paying a higher fee (RBF)
+    #[display(fmt = "replaced by fee")]
+    ReplacedByFee,
+    /// Expired before being included in a block
+    #[display(fmt = "expired")]
+    Expired,
+    /// No longer valid in the virtual state, as a double spend or by failing validation
+    #[display(fmt = "invalidated")]
+    Invalidated,
+    /// Spending outputs missing from the virtual UTXO set, most likely because the transaction itself was
+    /// included in a block not yet handled by the mempool, possibly also because of a double spend
+    #[display(fmt = "missing outpoints")]
+    MissingOutpoints,
+    /// Removed along with an ancestor transaction it spends outputs of
+    #[display(fmt = "ancestor removed")]
+    AncestorRemoved,
+}
+
+#[derive(Debug, Clone)]
+pub struct MempoolTransactionRemovedNotification {
+    pub transaction_id: TransactionId,
+    /// Addresses involved in the transaction inputs and outputs
+    pub addresses: Arc<Vec<Address>>,
+    pub reason: MempoolRemovalReason,
+}
+
+impl MempoolTransactionRemovedNotification {
+    pub fn new(transaction_id: TransactionId, addresses: Arc<Vec<Address>>, reason: MempoolRemovalReason) -> Self {
+        Self { transaction_id, addresses, reason }
+    }
+}
+
+/// State of a database checkpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum DatabaseCheckpointState {
+    #[display(fmt = "started")]
+    Started,
+    /// Some of the databases were checkpointed
+    #[display(fmt = "in progress")]
+    InProgress,
+    #[display(fmt = "completed")]
+    Completed,
+    #[display(fmt = "failed")]
+    Failed,
+}
+
+#[derive(Debug, Clone)]
+pub struct DatabaseCheckpointNotification {
+    /// Directory of the checkpoint
+    pub directory: String,
+    pub state: DatabaseCheckpointState,
+    pub completed_databases: u32,
+    pub total_databases: u32,
+    /// Sink of the virtual state captured by the checkpoint, once completed
+    pub sink: Option<Hash>,
+    /// DAA score of the virtual state captured by the checkpoint, once completed
+    pub virtual_daa_score: Option<u64>,
+    /// Reason of the failure, if failed
+    pub error: Option<String>,
+}
+
+impl DatabaseCheckpointNotification {
+    pub fn new(directory: String, state: DatabaseCheckpointState, completed_databases: u32, total_databases: u32) -> Self {
+        Self { directory, state, completed_databases, total_databases, sink: None, virtual_daa_score: None, error: None }
+    }
+}
+
+/// Consensus invariant checked by the audit mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum ConsensusAuditCheck {
+    #[display(fmt = "ghostdag")]
+    Ghostdag,
+    #[display(fmt = "reachability")]
+    Reachability,
+    #[display(fmt = "daa window")]
+    DaaWindow,
+    #[display(fmt = "coinbase reward")]
+    CoinbaseReward,
+    #[display(fmt = "utxo commitment")]
+    UtxoCommitment,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConsensusAuditViolationNotification {
+    pub check: ConsensusAuditCheck,
+    /// Block for which the invariant was found violated
+    pub block_hash: Hash,
+    /// Description of the violation
+    pub details: String,
+}
+
+impl ConsensusAuditViolationNotification {
+    pub fn new(check: ConsensusAuditCheck, block_hash: Hash, details: String) -> Self {
+        Self { check, block_hash, details }
+    }
+}
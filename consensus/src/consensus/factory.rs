@@ -1,8 +1,12 @@
 #[cfg(feature = "devnet-prealloc")]
 use super::utxo_set_override::{set_genesis_utxo_commitment_from_config, set_initial_utxo_set};
-use super::{ctl::Ctl, Consensus};
+use super::{ctl::Ctl, storage_stats, Consensus};
 use crate::{model::stores::U64Key, pipeline::ProcessingCounters};
-use calico_consensus_core::config::Config;
+use calico_consensus_core::{
+    api::{stats::StorageStats, DbCheckpointInfo},
+    config::Config,
+    errors::consensus::{ConsensusError, ConsensusResult},
+};
 use calico_consensus_notify::root::ConsensusNotificationRoot;
 use calico_consensusmanager::{ConsensusFactory, ConsensusInstance, DynConsensusCtl, SessionLock};
 use calico_core::{debug, time::unix_now, warn};
@@ -14,12 +18,18 @@ use calico_database::{
 };
 use itertools::Itertools;
 
+use calico_database::prelude::WriteBatch;
 use calico_txscript::caches::TxScriptCacheCounters;
 use calico_utils::mem_size::MemSizeEstimator;
 use parking_lot::RwLock;
-use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, error::Error, fs, path::PathBuf, sync::Arc};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ConsensusEntry {
@@ -109,6 +119,21 @@ impl MultiConsensusManagementStore {
         }
     }
 
+    /// Storage engine stats of the management DB
+    pub fn storage_stats(&self) -> StorageStats {
+        storage_stats(&self.db)
+    }
+
+    /// Creates a checkpoint of the management DB at `path`, which must not exist
+    pub fn create_checkpoint(&self, path: &Path) -> StoreResult<()> {
+        self.db.create_checkpoint(path)
+    }
+
+    /// The name of the management DB directory, if the DB is on disk
+    pub fn db_dir_name(&self) -> Option<String> {
+        self.db.path().and_then(Path::file_name).map(|name| name.to_string_lossy().into_owned())
+    }
+
     /// The entry type signifies whether the returned entry is an existing/new consensus
     pub fn active_consensus_entry(&mut self) -> StoreResult<ConsensusEntryType> {
         let mut metadata = self.metadata.read()?;
@@ -270,6 +295,49 @@ impl Factory {
         factory.delete_inactive_consensus_entries();
         factory
     }
+
+    /// Creates a new empty staging consensus which processes genesis as its first block, so that
+    /// the whole DAG can be replayed into it (as opposed to a pruning point import)
+    pub fn new_staging_consensus_with_genesis(&self) -> (ConsensusInstance, DynConsensusCtl) {
+        self.create_staging_consensus(true)
+    }
+
+    fn create_staging_consensus(&self, process_genesis: bool) -> (ConsensusInstance, DynConsensusCtl) {
+        assert!(!self.notification_root.is_closed());
+
+        let entry = self.management_store.write().new_staging_consensus_entry().unwrap();
+        let dir = self.db_root_dir.join(entry.directory_name);
+        let db = calico_database::prelude::ConnBuilder::default()
+            .with_db_path(dir)
+            .with_parallelism(self.db_parallelism)
+            .with_files_limit(self.fd_budget / 2) // active and staging consensuses should have equal budgets
+            .build()
+            .unwrap();
+
+        let mut config = self.config.clone();
+        config.process_genesis = process_genesis;
+        let session_lock = SessionLock::new();
+        let consensus = Arc::new(Consensus::new(
+            db.clone(),
+            Arc::new(config),
+            session_lock.clone(),
+            self.notification_root.clone(),
+            self.counters.clone(),
+            self.tx_script_cache_counters.clone(),
+            entry.creation_timestamp,
+        ));
+
+        #[cfg(feature = "devnet-prealloc")]
+        if process_genesis {
+            set_initial_utxo_set(&self.config.initial_utxo_set, consensus.clone(), self.config.params.genesis.hash);
+        }
+
+        (ConsensusInstance::new(session_lock, consensus.clone()), Arc::new(Ctl::new(self.management_store.clone(), db, consensus)))
+    }
+}
+
+pub(super) fn checkpoint_error(err: impl ToString) -> ConsensusError {
+    ConsensusError::CheckpointError(err.to_string())
 }
 
 impl ConsensusFactory for Factory {
@@ -322,29 +390,7 @@ impl ConsensusFactory for Factory {
     }
 
     fn new_staging_consensus(&self) -> (ConsensusInstance, DynConsensusCtl) {
-        assert!(!self.notification_root.is_closed());
-
-        let entry = self.management_store.write().new_staging_consensus_entry().unwrap();
-        let dir = self.db_root_dir.join(entry.directory_name);
-        let db = calico_database::prelude::ConnBuilder::default()
-            .with_db_path(dir)
-            .with_parallelism(self.db_parallelism)
-            .with_files_limit(self.fd_budget / 2) // active and staging consensuses should have equal budgets
-            .build()
-            .unwrap();
-
-        let session_lock = SessionLock::new();
-        let consensus = Arc::new(Consensus::new(
-            db.clone(),
-            Arc::new(self.config.to_builder().skip_adding_genesis().build()),
-            session_lock.clone(),
-            self.notification_root.clone(),
-            self.counters.clone(),
-            self.tx_script_cache_counters.clone(),
-            entry.creation_timestamp,
-        ));
-
-        (ConsensusInstance::new(session_lock, consensus.clone()), Arc::new(Ctl::new(self.management_store.clone(), db, consensus)))
+        self.create_staging_consensus(false)
     }
 
     fn close(&self) {
@@ -400,4 +446,33 @@ impl ConsensusFactory for Factory {
             write_guard.cancel_staging_consensus().unwrap();
         }
     }
+
+    fn create_checkpoint(
+        &self,
+        active: &ConsensusInstance,
+        directory: &Path,
+        on_checkpointed: &dyn Fn(&str),
+    ) -> ConsensusResult<DbCheckpointInfo> {
+        // Keep the management store locked so that the entries in the meta checkpoint match the consensus checkpoint
+        let management_store = self.management_store.read();
+        let active_dir_name = management_store
+            .active_consensus_dir_name()
+            .map_err(checkpoint_error)?
+            .ok_or(ConsensusError::General("no active consensus to checkpoint"))?;
+        let meta_dir_name =
+            management_store.db_dir_name().ok_or(ConsensusError::General("the management database is not stored on disk"))?;
+        let consensus_root_dir = directory.join(self.db_root_dir.file_name().expect("the consensus root dir has a name"));
+        fs::create_dir_all(&consensus_root_dir).map_err(checkpoint_error)?;
+
+        let info = active.unguarded_session_blocking().create_checkpoint(&consensus_root_dir.join(&active_dir_name))?;
+        on_checkpointed(&active_dir_name);
+
+        management_store.create_checkpoint(&directory.join(&meta_dir_name)).map_err(checkpoint_error)?;
+        on_checkpointed(&meta_dir_name);
+        Ok(info)
+    }
+
+    fn meta_storage_stats(&self) -> StorageStats {
+        self.management_store.read().storage_stats()
+    }
 }
@@ -25,10 +25,12 @@ use crate::{
             statuses::StatusesStoreReader,
             tips::TipsStoreReader,
             utxo_set::{UtxoSetStore, UtxoSetStoreReader},
+            virtual_state::{DbVirtualStateStore, VirtualStateStoreReader},
             DB,
         },
     },
     pipeline::{
+        audit_processor::processor::{AuditProcessingMessage, AuditProcessor},
         body_processor::BlockBodyProcessor,
         deps_manager::{BlockProcessingMessage, BlockResultSender, BlockTask, VirtualStateProcessingMessage},
         header_processor::HeaderProcessor,
@@ -45,8 +47,8 @@ use calico_consensus_core::{
     acceptance_data::AcceptanceData,
     api::{
         args::{TransactionValidationArgs, TransactionValidationBatchArgs},
-        stats::BlockCount,
-        BlockValidationFutures, ConsensusApi, ConsensusStats,
+        stats::{BlockCount, StorageStats},
+        BlockValidationFutures, ConsensusApi, ConsensusStats, DbCheckpointInfo,
     },
     block::{Block, BlockTemplate, TemplateBuildMode, TemplateTransactionSelector, VirtualStateApproxId},
     blockhash::BlockHashExtensions,
@@ -77,7 +79,7 @@ use crossbeam_channel::{
 };
 use itertools::Itertools;
 
-use calico_database::prelude::StoreResultExtensions;
+use calico_database::prelude::{ConnBuilder, StoreResultExtensions};
 use calico_hashes::Hash;
 use calico_muhash::MuHash;
 use calico_txscript::caches::TxScriptCacheCounters;
@@ -89,6 +91,7 @@ use std::{
     future::Future,
     iter::once,
     ops::Deref,
+    path::Path,
     sync::{atomic::Ordering, Arc},
 };
 use std::{
@@ -97,12 +100,29 @@ use std::{
 };
 use tokio::sync::oneshot;
 
-use self::{services::ConsensusServices, storage::ConsensusStorage};
+use self::{factory::checkpoint_error, services::ConsensusServices, storage::ConsensusStorage};
 
 use crate::model::stores::selected_chain::SelectedChainStoreReader;
 
 use std::cmp;
 
+/// Reads the storage stats of `db` from its properties, which are cheap to query. Stats not reported by the
+/// DB backend are left out
+pub(crate) fn storage_stats(db: &DB) -> StorageStats {
+    let property = |name: &str| db.property_int_value(name).ok().flatten();
+    StorageStats {
+        sst_files_size: property("rocksdb.total-sst-files-size"),
+        live_data_size: property("rocksdb.estimate-live-data-size"),
+        num_keys: property("rocksdb.estimate-num-keys"),
+        memtables_size: property("rocksdb.cur-size-all-mem-tables"),
+        pending_compaction_bytes: property("rocksdb.estimate-pending-compaction-bytes"),
+        running_compactions: property("rocksdb.num-running-compactions"),
+    }
+}
+
+/// The number of files the checkpoint DB may open while its virtual state is read back
+const CHECKPOINT_DB_FILE_LIMIT: i32 = 10;
+
 pub struct Consensus {
     // DB
     db: Arc<DB>,
@@ -115,6 +135,7 @@ pub struct Consensus {
     pub(super) body_processor: Arc<BlockBodyProcessor>,
     pub(super) virtual_processor: Arc<VirtualStateProcessor>,
     pub(super) pruning_processor: Arc<PruningProcessor>,
+    pub(super) audit_processor: Option<Arc<AuditProcessor>>,
 
     // Storage
     pub(super) storage: Arc<ConsensusStorage>,
@@ -197,6 +218,9 @@ impl Consensus {
             CrossbeamSender<PruningProcessingMessage>,
             CrossbeamReceiver<PruningProcessingMessage>,
         ) = bounded_crossbeam(2);
+        // The audit channel is unbounded since every added chain block must eventually be audited
+        let (audit_sender, audit_receiver): (CrossbeamSender<AuditProcessingMessage>, CrossbeamReceiver<AuditProcessingMessage>) =
+            unbounded_crossbeam();
 
         //
         // Thread-pools
@@ -265,8 +289,10 @@ impl Consensus {
             virtual_receiver,
             pruning_sender,
             pruning_receiver.clone(),
+            config.enable_audit.then_some(audit_sender),
             virtual_pool,
             params,
+            config.assume_valid_block(),
             db.clone(),
             &storage,
             &services,
@@ -285,6 +311,17 @@ impl Consensus {
             is_consensus_exiting.clone(),
         ));
 
+        let audit_processor = config.enable_audit.then(|| {
+            Arc::new(AuditProcessor::new(
+                audit_receiver,
+                &storage,
+                &services,
+                &config,
+                pruning_lock.clone(),
+                notification_root.clone(),
+            ))
+        });
+
         // Ensure the relations stores are initialized
         header_processor.init();
         // Ensure that some pruning point is registered
@@ -304,6 +341,7 @@ impl Consensus {
             body_processor,
             virtual_processor,
             pruning_processor,
+            audit_processor,
             storage,
             services,
             pruning_lock,
@@ -322,12 +360,16 @@ impl Consensus {
         let virtual_processor = self.virtual_processor.clone();
         let pruning_processor = self.pruning_processor.clone();
 
-        vec![
+        let mut handles = vec![
             thread::Builder::new().name("header-processor".to_string()).spawn(move || header_processor.worker()).unwrap(),
             thread::Builder::new().name("body-processor".to_string()).spawn(move || body_processor.worker()).unwrap(),
             thread::Builder::new().name("virtual-processor".to_string()).spawn(move || virtual_processor.worker()).unwrap(),
             thread::Builder::new().name("pruning-processor".to_string()).spawn(move || pruning_processor.worker()).unwrap(),
-        ]
+        ];
+        if let Some(audit_processor) = self.audit_processor.clone() {
+            handles.push(thread::Builder::new().name("audit-processor".to_string()).spawn(move || audit_processor.worker()).unwrap());
+        }
+        handles
     }
 
     /// Acquires a consensus session, blocking data-pruning from occurring until released
@@ -471,6 +513,7 @@ impl ConsensusApi for Consensus {
             // turns out to be not fast enough then we should maintain an atomic integer holding this value
             num_tips: self.get_tips_len() as u64,
             virtual_stats: self.lkg_virtual_state.load().as_ref().into(),
+            storage_stats: storage_stats(&self.db),
         }
     }
 
@@ -809,6 +852,31 @@ impl ConsensusApi for Consensus {
         Ok(self.services.reachability_service.is_chain_ancestor_of(low, high))
     }
 
+    fn is_dag_ancestor_of(&self, low: Hash, high: Hash) -> ConsensusResult<bool> {
+        let _guard = self.pruning_lock.blocking_read();
+        self.validate_block_exists(low)?;
+        self.validate_block_exists(high)?;
+        Ok(self.services.reachability_service.is_dag_ancestor_of(low, high))
+    }
+
+    fn get_chain_path(&self, from: Hash, to: Hash, chain_path_limit: Option<usize>) -> ConsensusResult<ChainPath> {
+        let _guard = self.pruning_lock.blocking_read();
+        self.validate_block_exists(from)?;
+        self.validate_block_exists(to)?;
+
+        // Verify that source is on the chain of both blocks, so they share a common chain ancestor
+        let source = self.get_source();
+        for hash in [from, to] {
+            self.services
+                .reachability_service
+                .is_chain_ancestor_of(source, hash)
+                .then_some(())
+                .ok_or(ConsensusError::General("the queried hash does not have source on its chain"))?;
+        }
+
+        Ok(self.services.dag_traversal_manager.calculate_bounded_chain_path(from, to, chain_path_limit)?)
+    }
+
     // max_blocks has to be greater than the merge set size limit
     fn get_hashes_between(&self, low: Hash, high: Hash, max_blocks: usize) -> ConsensusResult<(Vec<Hash>, Hash)> {
         let _guard = self.pruning_lock.blocking_read();
@@ -834,6 +902,49 @@ impl ConsensusApi for Consensus {
         Ok(self.services.dag_traversal_manager.antipast(hash, std::iter::once(context), max_traversal_allowed)?)
     }
 
+    fn get_sub_dag(&self, low: Hash, high: Hash, max_blocks: u64) -> ConsensusResult<Vec<Hash>> {
+        let _guard = self.pruning_lock.blocking_read();
+        self.validate_block_exists(low)?;
+        self.validate_block_exists(high)?;
+
+        // Ghostdag data is only complete in future(source)
+        if !self.services.reachability_service.is_dag_ancestor_of(self.get_source(), low) {
+            return Err(ConsensusError::General("the low block is not in the future of the pruning point"));
+        }
+        if !self.services.reachability_service.is_dag_ancestor_of(low, high) {
+            return Err(ConsensusError::General("the low block is not in the past of the high block"));
+        }
+
+        let mut hashes = self.services.dag_traversal_manager.sub_dag(low, high, Some(max_blocks))?;
+        hashes.sort_by_cached_key(|&hash| SortableBlock::new(hash, self.ghostdag_primary_store.get_blue_work(hash).unwrap()));
+        Ok(hashes)
+    }
+
+    fn get_chain_block_by_daa_score(&self, daa_score: u64) -> ConsensusResult<Hash> {
+        let _guard = self.pruning_lock.blocking_read();
+        let source = self.get_source();
+        let sc_read = self.storage.selected_chain_store.read();
+        let daa_score_at =
+            |index| self.headers_store.get_daa_score(sc_read.get_by_index(index).expect("store lock is acquired")).unwrap();
+
+        // DAA scores strictly increase along the selected chain, so the highest chain block whose DAA score does not
+        // exceed `daa_score` is found by a binary search over the selected chain indices between the source and the sink
+        let (mut low, mut high) =
+            (sc_read.get_by_hash(source).map_err(|_| ConsensusError::MissingData(source))?, sc_read.get_tip().unwrap().0);
+        if daa_score_at(low) > daa_score {
+            return Ok(source);
+        }
+        while low < high {
+            let mid = low + (high - low + 1) / 2;
+            if daa_score_at(mid) <= daa_score {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        Ok(sc_read.get_by_index(low).expect("store lock is acquired"))
+    }
+
     fn get_anticone(&self, hash: Hash) -> ConsensusResult<Vec<Hash>> {
         let _guard = self.pruning_lock.blocking_read();
         self.validate_block_exists(hash)?;
@@ -841,6 +952,13 @@ impl ConsensusApi for Consensus {
         Ok(self.services.dag_traversal_manager.anticone(hash, virtual_state.parents.iter().copied(), None)?)
     }
 
+    fn get_anticone_size(&self, hash: Hash, max_traversal_allowed: Option<u64>) -> ConsensusResult<u64> {
+        let _guard = self.pruning_lock.blocking_read();
+        self.validate_block_exists(hash)?;
+        let virtual_state = self.lkg_virtual_state.load();
+        Ok(self.services.dag_traversal_manager.anticone_size(hash, virtual_state.parents.iter().copied(), max_traversal_allowed)?)
+    }
+
     fn get_pruning_point_proof(&self) -> Arc<PruningPointProof> {
         // PRUNE SAFETY: proof is cached before the prune op begins and the
         // pruning point cannot move during the prune so the cache remains valid
@@ -1050,4 +1168,18 @@ impl ConsensusApi for Consensus {
     fn finality_point(&self) -> Hash {
         self.virtual_processor.virtual_finality_point(&self.lkg_virtual_state.load().ghostdag_data, self.pruning_point())
     }
+
+    fn create_checkpoint(&self, directory: &Path) -> ConsensusResult<DbCheckpointInfo> {
+        // A checkpoint is a consistent point-in-time view of the DB, so the virtual state it captured is read back
+        // from it. Freezing the virtual state instead would stall virtual processing while the checkpoint is created,
+        // which is a full copy when the checkpoint directory is on another file system.
+        self.db.create_checkpoint(directory).map_err(checkpoint_error)?;
+        let checkpoint_db = ConnBuilder::default()
+            .with_db_path(directory.to_path_buf())
+            .with_files_limit(CHECKPOINT_DB_FILE_LIMIT)
+            .build()
+            .map_err(checkpoint_error)?;
+        let virtual_state = DbVirtualStateStore::new(checkpoint_db, Default::default()).get().map_err(checkpoint_error)?;
+        Ok(DbCheckpointInfo { sink: virtual_state.ghostdag_data.selected_parent, virtual_daa_score: virtual_state.daa_score })
+    }
 }
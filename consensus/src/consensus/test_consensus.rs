@@ -2,8 +2,14 @@ use async_channel::Sender;
 use calico_consensus_core::coinbase::MinerData;
 use calico_consensus_core::tx::ScriptPublicKey;
 use calico_consensus_core::{
-    api::ConsensusApi, block::MutableBlock, blockstatus::BlockStatus, header::Header, merkle::calc_hash_merkle_root,
-    subnets::SUBNETWORK_ID_COINBASE, tx::Transaction,
+    api::{stats::StorageStats, ConsensusApi, DbCheckpointInfo},
+    block::MutableBlock,
+    blockstatus::BlockStatus,
+    errors::consensus::ConsensusResult,
+    header::Header,
+    merkle::calc_hash_merkle_root,
+    subnets::SUBNETWORK_ID_COINBASE,
+    tx::Transaction,
 };
 use calico_consensus_notify::{notification::Notification, root::ConsensusNotificationRoot};
 use calico_consensusmanager::{ConsensusFactory, ConsensusInstance, DynConsensusCtl};
@@ -13,10 +19,10 @@ use calico_hashes::Hash;
 use calico_notify::subscription::context::SubscriptionContext;
 use parking_lot::RwLock;
 
-use calico_database::create_temp_db;
-use calico_database::prelude::ConnBuilder;
+use calico_database::prelude::{ConnBuilder, WriteBatch};
+use calico_database::{create_memory_db, create_temp_db};
 use std::future::Future;
-use std::{sync::Arc, thread::JoinHandle};
+use std::{path::Path, sync::Arc, thread::JoinHandle};
 
 use crate::pipeline::virtual_processor::test_block_builder::TestBlockBuilder;
 use crate::processes::window::WindowManager;
@@ -27,12 +33,20 @@ use crate::{
     model::{
         services::reachability::MTReachabilityService,
         stores::{
-            ghostdag::DbGhostdagStore, headers::HeaderStoreReader, pruning::PruningStoreReader, reachability::DbReachabilityStore,
-            virtual_state::VirtualStores, DB,
+            ghostdag::DbGhostdagStore,
+            headers::HeaderStoreReader,
+            pruning::{DbPruningStore, PruningStoreReader},
+            reachability::DbReachabilityStore,
+            utxo_diffs::DbUtxoDiffsStore,
+            virtual_state::VirtualStores,
+            DB,
         },
     },
     params::Params,
-    pipeline::{body_processor::BlockBodyProcessor, virtual_processor::VirtualStateProcessor, ProcessingCounters},
+    pipeline::{
+        audit_processor::processor::AuditProcessor, body_processor::BlockBodyProcessor, virtual_processor::VirtualStateProcessor,
+        ProcessingCounters,
+    },
     test_helpers::header_from_precomputed_hash,
 };
 
@@ -89,6 +103,17 @@ impl TestConsensus {
     /// Creates a test consensus instance based on `config` with a temp DB and no notifier
     pub fn new(config: &Config) -> Self {
         let (db_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        Self::with_db_lifetime(db, db_lifetime, config)
+    }
+
+    /// Creates a test consensus instance based on `config` with an in-memory DB and no notifier.
+    /// This avoids the disk and compaction costs of RocksDB for tests which do not need the data to persist
+    pub fn new_in_memory(config: &Config) -> Self {
+        let (db_lifetime, db) = create_memory_db!(ConnBuilder::default());
+        Self::with_db_lifetime(db, db_lifetime, config)
+    }
+
+    fn with_db_lifetime(db: Arc<DB>, db_lifetime: DbLifetime, config: &Config) -> Self {
         let (dummy_notification_sender, _) = async_channel::unbounded();
         let notification_root = Arc::new(ConsensusNotificationRoot::new(dummy_notification_sender));
         let counters = Default::default();
@@ -230,6 +255,21 @@ impl TestConsensus {
         self.consensus.headers_store.clone()
     }
 
+    pub fn pruning_point_store(&self) -> &Arc<RwLock<DbPruningStore>> {
+        &self.consensus.storage.pruning_point_store
+    }
+
+    /// Sets the history root, as if the node had synced its history starting from the given chain block
+    pub fn set_history_root(&self, history_root: Hash) {
+        let mut batch = WriteBatch::default();
+        self.consensus.storage.pruning_point_store.write().set_history_root(&mut batch, history_root).unwrap();
+        self.consensus.db.write(batch).unwrap();
+    }
+
+    pub fn utxo_diffs_store(&self) -> &Arc<DbUtxoDiffsStore> {
+        &self.consensus.storage.utxo_diffs_store
+    }
+
     pub fn virtual_stores(&self) -> Arc<RwLock<VirtualStores>> {
         self.consensus.virtual_stores.clone()
     }
@@ -249,6 +289,10 @@ impl TestConsensus {
     pub fn ghostdag_manager(&self) -> &DbGhostdagManager {
         &self.consensus.services.ghostdag_primary_manager
     }
+
+    pub fn audit_processor(&self) -> Option<&Arc<AuditProcessor>> {
+        self.consensus.audit_processor.as_ref()
+    }
 }
 
 impl std::ops::Deref for TestConsensus {
@@ -305,4 +349,13 @@ impl ConsensusFactory for TestConsensusFactory {
     fn delete_staging_entry(&self) {
         unimplemented!()
     }
+
+    fn create_checkpoint(&self, _: &ConsensusInstance, _: &Path, _: &dyn Fn(&str)) -> ConsensusResult<DbCheckpointInfo> {
+        unimplemented!()
+    }
+
+    fn meta_storage_stats(&self) -> StorageStats {
+        // The test consensus has no management database
+        StorageStats::default()
+    }
 }
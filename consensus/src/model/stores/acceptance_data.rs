@@ -4,12 +4,12 @@ use calico_consensus_core::acceptance_data::MergesetBlockAcceptanceData;
 use calico_consensus_core::BlockHasher;
 use calico_database::prelude::CachePolicy;
 use calico_database::prelude::StoreError;
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::DB;
 use calico_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
 use calico_database::registry::DatabaseStorePrefixes;
 use calico_hashes::Hash;
 use calico_utils::mem_size::MemSizeEstimator;
-use rocksdb::WriteBatch;
 use serde::Deserialize;
 use serde::Serialize;
 use std::sync::Arc;
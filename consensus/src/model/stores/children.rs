@@ -8,10 +8,10 @@ use calico_database::prelude::DbWriter;
 use calico_database::prelude::ReadLock;
 use calico_database::prelude::StoreError;
 use calico_database::prelude::StoreResult;
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::DB;
 use calico_database::registry::DatabaseStorePrefixes;
 use calico_hashes::Hash;
-use rocksdb::WriteBatch;
 use std::sync::Arc;
 
 pub trait ChildrenStoreReader {
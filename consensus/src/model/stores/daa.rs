@@ -3,11 +3,11 @@ use std::sync::Arc;
 use calico_consensus_core::{BlockHashSet, BlockHasher};
 use calico_database::prelude::CachePolicy;
 use calico_database::prelude::StoreError;
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::DB;
 use calico_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
 use calico_database::registry::DatabaseStorePrefixes;
 use calico_hashes::Hash;
-use rocksdb::WriteBatch;
 
 pub trait DaaStoreReader {
     fn get_mergeset_non_daa(&self, hash: Hash) -> Result<Arc<BlockHashSet>, StoreError>;
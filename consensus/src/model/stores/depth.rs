@@ -3,12 +3,12 @@ use std::sync::Arc;
 use calico_consensus_core::BlockHasher;
 use calico_database::prelude::CachePolicy;
 use calico_database::prelude::StoreError;
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::DB;
 use calico_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
 use calico_database::registry::DatabaseStorePrefixes;
 use calico_hashes::Hash;
 use calico_utils::mem_size::MemSizeEstimator;
-use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
 
 pub trait DepthStoreReader {
@@ -8,10 +8,10 @@ use calico_database::prelude::{CachePolicy, StoreError};
 use calico_database::registry::{DatabaseStorePrefixes, SEPARATOR};
 use calico_hashes::Hash;
 
+use calico_database::prelude::WriteBatch;
 use calico_utils::mem_size::MemSizeEstimator;
 use itertools::EitherOrBoth::{Both, Left, Right};
 use itertools::Itertools;
-use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
 use std::iter::once;
 use std::{cell::RefCell, sync::Arc};
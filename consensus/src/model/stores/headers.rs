@@ -1,13 +1,13 @@
 use std::sync::Arc;
 
 use calico_consensus_core::{header::Header, BlockHasher, BlockLevel};
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::{BatchDbWriter, CachedDbAccess};
 use calico_database::prelude::{CachePolicy, DB};
 use calico_database::prelude::{StoreError, StoreResult};
 use calico_database::registry::DatabaseStorePrefixes;
 use calico_hashes::Hash;
 use calico_utils::mem_size::MemSizeEstimator;
-use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
 
 pub trait HeaderStoreReader {
@@ -1,9 +1,9 @@
 use crate::processes::ghostdag::ordering::SortableBlock;
 use calico_database::prelude::StoreResult;
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::DB;
 use calico_database::prelude::{BatchDbWriter, CachedDbItem, DirectDbWriter};
 use calico_database::registry::DatabaseStorePrefixes;
-use rocksdb::WriteBatch;
 use std::sync::Arc;
 
 /// Reader API for `SelectedTipStore`.
@@ -1,11 +1,11 @@
 use std::sync::Arc;
 
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
 use calico_database::prelude::{CachePolicy, DB};
 use calico_database::prelude::{StoreError, StoreResult};
 use calico_database::registry::DatabaseStorePrefixes;
 use calico_hashes::Hash;
-use rocksdb::WriteBatch;
 
 use super::U64Key;
 
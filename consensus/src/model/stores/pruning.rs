@@ -1,11 +1,11 @@
 use std::sync::Arc;
 
 use calico_database::prelude::StoreResult;
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::DB;
 use calico_database::prelude::{BatchDbWriter, CachedDbItem, DirectDbWriter};
 use calico_database::registry::DatabaseStorePrefixes;
 use calico_hashes::Hash;
-use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Serialize, Deserialize)]
@@ -42,6 +42,10 @@ pub trait PruningStoreReader {
     /// This is usually the pruning point, though it might lag a bit behind until data prune completes (and for archival
     /// nodes it will remain the initial syncing point or the last pruning point before turning to an archive)
     fn history_root(&self) -> StoreResult<Hash>;
+
+    /// Represents the point below which archival block data was trimmed according to the configured retention
+    /// policy (i.e., data of blocks in its past is only held as far as the policy retains it)
+    fn retention_root(&self) -> StoreResult<Hash>;
 }
 
 pub trait PruningStore: PruningStoreReader {
@@ -54,6 +58,7 @@ pub struct DbPruningStore {
     db: Arc<DB>,
     access: CachedDbItem<PruningPointInfo>,
     history_root_access: CachedDbItem<Hash>,
+    retention_root_access: CachedDbItem<Hash>,
 }
 
 impl DbPruningStore {
@@ -61,7 +66,8 @@ impl DbPruningStore {
         Self {
             db: Arc::clone(&db),
             access: CachedDbItem::new(db.clone(), DatabaseStorePrefixes::PruningPoint.into()),
-            history_root_access: CachedDbItem::new(db, DatabaseStorePrefixes::HistoryRoot.into()),
+            history_root_access: CachedDbItem::new(db.clone(), DatabaseStorePrefixes::HistoryRoot.into()),
+            retention_root_access: CachedDbItem::new(db, DatabaseStorePrefixes::RetentionRoot.into()),
         }
     }
 
@@ -76,6 +82,10 @@ impl DbPruningStore {
     pub fn set_history_root(&mut self, batch: &mut WriteBatch, history_root: Hash) -> StoreResult<()> {
         self.history_root_access.write(BatchDbWriter::new(batch), &history_root)
     }
+
+    pub fn set_retention_root(&mut self, batch: &mut WriteBatch, retention_root: Hash) -> StoreResult<()> {
+        self.retention_root_access.write(BatchDbWriter::new(batch), &retention_root)
+    }
 }
 
 impl PruningStoreReader for DbPruningStore {
@@ -98,6 +108,10 @@ impl PruningStoreReader for DbPruningStore {
     fn history_root(&self) -> StoreResult<Hash> {
         self.history_root_access.read()
     }
+
+    fn retention_root(&self) -> StoreResult<Hash> {
+        self.retention_root_access.read()
+    }
 }
 
 impl PruningStore for DbPruningStore {
@@ -2,11 +2,11 @@ use std::sync::Arc;
 
 use calico_database::prelude::CachePolicy;
 use calico_database::prelude::StoreResult;
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::DB;
 use calico_database::prelude::{BatchDbWriter, CachedDbItem};
 use calico_database::registry::DatabaseStorePrefixes;
 use calico_hashes::Hash;
-use rocksdb::WriteBatch;
 
 use super::utxo_set::DbUtxoSetStore;
 
@@ -11,10 +11,10 @@ use calico_database::{
 };
 use calico_hashes::Hash;
 
+use calico_database::prelude::WriteBatch;
 use calico_utils::mem_size::MemSizeEstimator;
 use itertools::Itertools;
 use parking_lot::{RwLockUpgradableReadGuard, RwLockWriteGuard};
-use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::hash_map::Entry::{Occupied, Vacant},
@@ -1,5 +1,6 @@
 use calico_consensus_core::BlockHashSet;
 use calico_consensus_core::{blockhash::BlockHashes, BlockHashMap, BlockHasher, BlockLevel};
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::{BatchDbWriter, CachePolicy, DbWriter};
 use calico_database::prelude::{CachedDbAccess, DbKey, DirectDbWriter};
 use calico_database::prelude::{DirectWriter, MemoryWriter};
@@ -8,7 +9,6 @@ use calico_database::prelude::{StoreResult, DB};
 use calico_database::registry::{DatabaseStorePrefixes, SEPARATOR};
 use calico_hashes::Hash;
 use itertools::Itertools;
-use rocksdb::WriteBatch;
 use std::collections::hash_map::Entry;
 use std::collections::HashSet;
 use std::iter::once;
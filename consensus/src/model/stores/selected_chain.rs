@@ -1,8 +1,8 @@
 use calico_consensus_core::blockstatus::BlockStatus;
 use calico_consensus_core::ChainPath;
+use calico_database::prelude::WriteBatch;
 use calico_database::registry::DatabaseStorePrefixes;
 use parking_lot::RwLockWriteGuard;
-use rocksdb::WriteBatch;
 
 use std::sync::Arc;
 
@@ -1,7 +1,7 @@
 use calico_consensus_core::{blockstatus::BlockStatus, BlockHasher};
+use calico_database::prelude::WriteBatch;
 use calico_database::registry::DatabaseStorePrefixes;
 use parking_lot::{RwLock, RwLockWriteGuard};
-use rocksdb::WriteBatch;
 use std::sync::Arc;
 
 use calico_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
@@ -7,11 +7,11 @@ use calico_database::prelude::DbWriter;
 use calico_database::prelude::ReadLock;
 use calico_database::prelude::StoreResult;
 use calico_database::prelude::StoreResultExtensions;
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::DB;
 use calico_database::prelude::{BatchDbWriter, DirectDbWriter};
 use calico_database::registry::DatabaseStorePrefixes;
 use calico_hashes::Hash;
-use rocksdb::WriteBatch;
 
 /// Reader API for `TipsStore`.
 pub trait TipsStoreReader {
@@ -3,11 +3,11 @@ use std::sync::Arc;
 use calico_consensus_core::{utxo::utxo_diff::UtxoDiff, BlockHasher};
 use calico_database::prelude::CachePolicy;
 use calico_database::prelude::StoreError;
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::DB;
 use calico_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
 use calico_database::registry::DatabaseStorePrefixes;
 use calico_hashes::Hash;
-use rocksdb::WriteBatch;
 
 /// Store for holding the UTXO difference (delta) of a block relative to its selected parent.
 /// Note that this data is lazy-computed only for blocks which are candidates to being chain
@@ -1,13 +1,13 @@
 use calico_consensus_core::BlockHasher;
 use calico_database::prelude::CachePolicy;
 use calico_database::prelude::StoreError;
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::DB;
 use calico_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
 use calico_database::registry::DatabaseStorePrefixes;
 use calico_hashes::Hash;
 use calico_math::Uint3072;
 use calico_muhash::MuHash;
-use rocksdb::WriteBatch;
 use std::sync::Arc;
 
 pub trait UtxoMultisetsStoreReader {
@@ -6,11 +6,11 @@ use calico_consensus_core::{
     },
 };
 use calico_database::prelude::StoreResultExtensions;
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::DB;
 use calico_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
 use calico_database::prelude::{CachePolicy, StoreError};
 use calico_hashes::Hash;
-use rocksdb::WriteBatch;
 use std::{error::Error, fmt::Display, sync::Arc};
 
 type UtxoCollectionIterator<'a> = Box<dyn Iterator<Item = Result<(TransactionOutpoint, UtxoEntry), Box<dyn Error>>> + 'a>;
@@ -112,7 +112,18 @@ impl DbUtxoSetStore {
     }
 
     pub fn iterator(&self) -> impl Iterator<Item = Result<(TransactionOutpoint, Arc<UtxoEntry>), Box<dyn Error>>> + '_ {
-        self.access.iterator().map(|iter_result| match iter_result {
+        self.access.iterator().map(Self::decode_entry)
+    }
+
+    /// Iterates the UTXO set as it is at the time of the call, so that the store can be updated meanwhile
+    pub fn snapshot_iterator(&self) -> impl Iterator<Item = Result<(TransactionOutpoint, Arc<UtxoEntry>), Box<dyn Error>>> + '_ {
+        self.access.snapshot_iterator().map(Self::decode_entry)
+    }
+
+    fn decode_entry(
+        iter_result: Result<(Box<[u8]>, Arc<UtxoEntry>), Box<dyn Error>>,
+    ) -> Result<(TransactionOutpoint, Arc<UtxoEntry>), Box<dyn Error>> {
+        match iter_result {
             Ok((key_bytes, utxo_entry)) => match UtxoKey::try_from(key_bytes.as_ref()) {
                 Ok(utxo_key) => {
                     let outpoint: TransactionOutpoint = utxo_key.into();
@@ -121,7 +132,7 @@ impl DbUtxoSetStore {
                 Err(e) => Err(e.into()),
             },
             Err(e) => Err(e),
-        })
+        }
     }
 
     /// Clear the store completely in DB and cache
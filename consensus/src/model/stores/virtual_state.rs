@@ -7,13 +7,13 @@ use calico_consensus_core::{
     block::VirtualStateApproxId, coinbase::BlockRewardData, config::genesis::GenesisBlock, tx::TransactionId,
     utxo::utxo_diff::UtxoDiff, BlockHashMap, BlockHashSet, HashMapCustomHasher,
 };
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::{BatchDbWriter, CachedDbItem, DirectDbWriter, StoreResultExtensions};
 use calico_database::prelude::{CachePolicy, StoreResult};
 use calico_database::prelude::{StoreError, DB};
 use calico_database::registry::DatabaseStorePrefixes;
 use calico_hashes::Hash;
 use calico_muhash::MuHash;
-use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
 
 use super::ghostdag::GhostdagData;
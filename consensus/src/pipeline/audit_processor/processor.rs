@@ -0,0 +1,391 @@
+//! The audit processor re-verifies consensus invariants of blocks which were already accepted by the pipeline.
+//!
+//! Checks are performed from the data committed to the stores, recomputing it where possible with the same
+//! managers used during validation, and violations are only reported (to the audit log and as notifications)
+//! without altering the consensus state. The processor is meant for test networks and is disabled by default.
+
+use crate::{
+    consensus::{
+        services::{ConsensusServices, DbGhostdagManager, DbWindowManager},
+        storage::ConsensusStorage,
+    },
+    model::{
+        services::{
+            reachability::{MTReachabilityService, ReachabilityService},
+            relations::MTRelationsService,
+        },
+        stores::{
+            block_transactions::BlockTransactionsStoreReader,
+            daa::DaaStoreReader,
+            ghostdag::{GhostdagData, GhostdagStoreReader},
+            headers::HeaderStoreReader,
+            reachability::{DbReachabilityStore, ReachabilityStoreReader},
+            relations::{DbRelationsStore, RelationsStoreReader},
+            statuses::StatusesStoreReader,
+            utxo_diffs::UtxoDiffsStoreReader,
+            utxo_multisets::UtxoMultisetsStoreReader,
+            virtual_state::VirtualStateStoreReader,
+        },
+    },
+    processes::{coinbase::CoinbaseManager, window::WindowManager},
+};
+use calico_consensus_core::{
+    blockhash::BlockHashExtensions, blockstatus::BlockStatus::StatusUTXOValid, config::Config, muhash::MuHashExtensions,
+    utxo::utxo_diff::ImmutableUtxoDiff, KType,
+};
+use calico_consensus_notify::{
+    notification::{ConsensusAuditCheck, ConsensusAuditViolationNotification, Notification},
+    root::ConsensusNotificationRoot,
+};
+use calico_consensusmanager::SessionLock;
+use calico_core::{error, info, log::AUDIT_LOG_TARGET};
+use calico_database::prelude::StoreResultExtensions;
+use calico_hashes::Hash;
+use calico_muhash::MuHash;
+use calico_notify::notifier::Notify;
+use crossbeam_channel::Receiver as CrossbeamReceiver;
+use itertools::Itertools;
+use std::{
+    ops::Deref,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Minimal interval between two full recomputations of the virtual UTXO set commitment
+const VIRTUAL_UTXO_SET_AUDIT_INTERVAL: Duration = Duration::from_secs(600);
+
+pub enum AuditProcessingMessage {
+    Exit,
+    Process { added_chain_blocks: Vec<Hash> },
+}
+
+type AuditResult = Result<(), String>;
+
+/// A processor dedicated to auditing the consensus invariants of new selected chain blocks
+pub struct AuditProcessor {
+    // Channels
+    receiver: CrossbeamReceiver<AuditProcessingMessage>,
+
+    // Storage
+    storage: Arc<ConsensusStorage>,
+
+    // Managers and Services
+    ghostdag_manager: DbGhostdagManager,
+    reachability_service: MTReachabilityService<DbReachabilityStore>,
+    relations_service: MTRelationsService<DbRelationsStore>,
+    window_manager: DbWindowManager,
+    coinbase_manager: CoinbaseManager,
+
+    // Config
+    ghostdag_k: KType,
+
+    // Pruning lock
+    pruning_lock: SessionLock,
+
+    // Notifier
+    notification_root: Arc<ConsensusNotificationRoot>,
+}
+
+impl Deref for AuditProcessor {
+    type Target = ConsensusStorage;
+
+    fn deref(&self) -> &Self::Target {
+        &self.storage
+    }
+}
+
+impl AuditProcessor {
+    pub fn new(
+        receiver: CrossbeamReceiver<AuditProcessingMessage>,
+        storage: &Arc<ConsensusStorage>,
+        services: &Arc<ConsensusServices>,
+        config: &Config,
+        pruning_lock: SessionLock,
+        notification_root: Arc<ConsensusNotificationRoot>,
+    ) -> Self {
+        Self {
+            receiver,
+            storage: storage.clone(),
+            ghostdag_manager: services.ghostdag_primary_manager.clone(),
+            reachability_service: services.reachability_service.clone(),
+            relations_service: services.relations_service.clone(),
+            window_manager: services.window_manager.clone(),
+            coinbase_manager: services.coinbase_manager.clone(),
+            ghostdag_k: config.params.ghostdag_k,
+            pruning_lock,
+            notification_root,
+        }
+    }
+
+    pub fn worker(self: &Arc<Self>) {
+        info!(target: AUDIT_LOG_TARGET, "Consensus audit is enabled: new selected chain blocks are re-verified in the background");
+        let mut last_virtual_utxo_set_audit = Instant::now();
+        while let Ok(AuditProcessingMessage::Process { added_chain_blocks }) = self.receiver.recv() {
+            // Collect all pending messages so that the audit keeps up with the chain even when it lags behind
+            let mut exiting = false;
+            let mut blocks = added_chain_blocks;
+            for msg in self.receiver.try_iter() {
+                match msg {
+                    AuditProcessingMessage::Exit => {
+                        exiting = true;
+                        break;
+                    }
+                    AuditProcessingMessage::Process { added_chain_blocks } => blocks.extend(added_chain_blocks),
+                }
+            }
+
+            self.audit_chain_blocks(blocks.into_iter().unique());
+
+            if last_virtual_utxo_set_audit.elapsed() >= VIRTUAL_UTXO_SET_AUDIT_INTERVAL {
+                self.audit_virtual_utxo_set();
+                last_virtual_utxo_set_audit = Instant::now();
+            }
+
+            if exiting {
+                break;
+            }
+        }
+    }
+
+    pub(crate) fn audit_chain_blocks(&self, blocks: impl Iterator<Item = Hash>) {
+        // Prevent data pruning while the blocks are being audited
+        let _prune_guard = self.pruning_lock.blocking_read();
+        for block in blocks {
+            for (check, details) in self.audit_chain_block(block) {
+                self.report(check, block, details);
+            }
+        }
+    }
+
+    /// Runs all checks on the block and returns the violations found
+    pub(crate) fn audit_chain_block(&self, block: Hash) -> Vec<(ConsensusAuditCheck, String)> {
+        // Only audit blocks with full UTXO data and regular parents (i.e., skip blocks synced with trusted data)
+        if self.statuses_store.read().get(block).unwrap_option() != Some(StatusUTXOValid) {
+            return vec![];
+        }
+        let Some(parents) = self.relations_service.get_parents(block).unwrap_option() else {
+            return vec![];
+        };
+        if parents.is_empty() || parents.iter().any(|parent| parent.is_origin()) {
+            return vec![];
+        }
+        let ghostdag_data = self.ghostdag_primary_store.get_data(block).unwrap();
+
+        [
+            (ConsensusAuditCheck::Ghostdag, self.check_ghostdag(&parents, &ghostdag_data)),
+            (ConsensusAuditCheck::Reachability, self.check_reachability(block, &parents, &ghostdag_data)),
+            (ConsensusAuditCheck::DaaWindow, self.check_daa_window(block, &ghostdag_data)),
+            (ConsensusAuditCheck::CoinbaseReward, self.check_coinbase_reward(block, &ghostdag_data)),
+            (ConsensusAuditCheck::UtxoCommitment, self.check_utxo_commitment(block, &ghostdag_data)),
+        ]
+        .into_iter()
+        .filter_map(|(check, result)| result.err().map(|details| (check, details)))
+        .collect()
+    }
+
+    /// Recomputes the GHOSTDAG data of the block from its parents and checks the k-cluster bounds
+    fn check_ghostdag(&self, parents: &[Hash], ghostdag_data: &GhostdagData) -> AuditResult {
+        let computed = self.ghostdag_manager.ghostdag(parents);
+        if computed.blue_score != ghostdag_data.blue_score
+            || computed.blue_work != ghostdag_data.blue_work
+            || computed.selected_parent != ghostdag_data.selected_parent
+        {
+            return Err(format!(
+                "stored blue score {}, blue work {} and selected parent {} differ from recomputed blue score {}, blue work {} and selected parent {}",
+                ghostdag_data.blue_score,
+                ghostdag_data.blue_work,
+                ghostdag_data.selected_parent,
+                computed.blue_score,
+                computed.blue_work,
+                computed.selected_parent
+            ));
+        }
+        if computed.mergeset_blues != ghostdag_data.mergeset_blues || computed.mergeset_reds != ghostdag_data.mergeset_reds {
+            return Err(format!(
+                "stored mergeset ({} blues, {} reds) differs from recomputed mergeset ({} blues, {} reds)",
+                ghostdag_data.mergeset_blues.len(),
+                ghostdag_data.mergeset_reds.len(),
+                computed.mergeset_blues.len(),
+                computed.mergeset_reds.len()
+            ));
+        }
+        if computed.blues_anticone_sizes != ghostdag_data.blues_anticone_sizes {
+            return Err("stored blues anticone sizes differ from recomputed ones".to_string());
+        }
+        if ghostdag_data.mergeset_blues.len() > self.ghostdag_k as usize + 1 {
+            return Err(format!(
+                "{} mergeset blues exceed the k+1 bound (k = {})",
+                ghostdag_data.mergeset_blues.len(),
+                self.ghostdag_k
+            ));
+        }
+        if let Some((blue, size)) = ghostdag_data.blues_anticone_sizes.iter().find(|(_, size)| **size > self.ghostdag_k) {
+            return Err(format!(
+                "blue {} has an anticone of size {} exceeding k = {} in the block blue set",
+                blue, size, self.ghostdag_k
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks the reachability tree position and interval of the block, as well as its reachability from its parents
+    fn check_reachability(&self, block: Hash, parents: &[Hash], ghostdag_data: &GhostdagData) -> AuditResult {
+        {
+            let reachability_read = self.reachability_store.read();
+            let interval = reachability_read.get_interval(block).unwrap();
+            let tree_parent = reachability_read.get_parent(block).unwrap();
+            if tree_parent != ghostdag_data.selected_parent {
+                return Err(format!("tree parent {} differs from the selected parent {}", tree_parent, ghostdag_data.selected_parent));
+            }
+            let parent_interval = reachability_read.get_interval(tree_parent).unwrap();
+            if !parent_interval.strictly_contains(interval) {
+                return Err(format!(
+                    "interval {} is not strictly contained in the tree parent interval {}",
+                    interval, parent_interval
+                ));
+            }
+            for sibling in reachability_read.get_children(tree_parent).unwrap().iter().copied().filter(|&sibling| sibling != block) {
+                let sibling_interval = reachability_read.get_interval(sibling).unwrap();
+                if !interval.is_disjoint(sibling_interval) {
+                    return Err(format!(
+                        "interval {} intersects the interval {} of tree sibling {}",
+                        interval, sibling_interval, sibling
+                    ));
+                }
+            }
+        }
+        if !self.reachability_service.is_chain_ancestor_of(ghostdag_data.selected_parent, block) {
+            return Err(format!("selected parent {} is not a chain ancestor", ghostdag_data.selected_parent));
+        }
+        if let Some(parent) = parents.iter().copied().find(|&parent| !self.reachability_service.is_dag_ancestor_of(parent, block)) {
+            return Err(format!("parent {} is not a DAG ancestor", parent));
+        }
+        Ok(())
+    }
+
+    /// Rebuilds the DAA window of the block and compares the resulting DAA score, non-DAA mergeset and difficulty with the stored ones
+    fn check_daa_window(&self, block: Hash, ghostdag_data: &GhostdagData) -> AuditResult {
+        let header = self.headers_store.get_header(block).unwrap();
+        let daa_window =
+            self.window_manager.block_daa_window(ghostdag_data).map_err(|err| format!("cannot rebuild the DAA window: {err}"))?;
+        if daa_window.daa_score != header.daa_score {
+            return Err(format!("header DAA score {} differs from recomputed DAA score {}", header.daa_score, daa_window.daa_score));
+        }
+        let mergeset_non_daa = self.daa_excluded_store.get_mergeset_non_daa(block).unwrap();
+        if *mergeset_non_daa != daa_window.mergeset_non_daa {
+            return Err(format!(
+                "stored non-DAA mergeset ({} blocks) differs from recomputed non-DAA mergeset ({} blocks)",
+                mergeset_non_daa.len(),
+                daa_window.mergeset_non_daa.len()
+            ));
+        }
+        let bits = self.window_manager.calculate_difficulty_bits(ghostdag_data, &daa_window);
+        if bits != header.bits {
+            return Err(format!("header difficulty bits {} differ from recomputed bits {}", header.bits, bits));
+        }
+        Ok(())
+    }
+
+    /// Checks that the coinbase outputs of the block do not exceed the subsidies of its DAA mergeset plus the fees
+    /// of the transactions it accepted, and match them exactly when the whole mergeset is in the DAA window
+    fn check_coinbase_reward(&self, block: Hash, ghostdag_data: &GhostdagData) -> AuditResult {
+        let (Some(transactions), Some(selected_parent_transactions)) = (
+            self.block_transactions_store.get(block).unwrap_option(),
+            self.block_transactions_store.get(ghostdag_data.selected_parent).unwrap_option(),
+        ) else {
+            return Ok(());
+        };
+        let mergeset_diff = self.utxo_diffs_store.get(block).unwrap();
+        let mergeset_non_daa = self.daa_excluded_store.get_mergeset_non_daa(block).unwrap();
+
+        // The mergeset diff holds the selected parent coinbase outputs and the net effect of all accepted transactions,
+        // so the accepted fees are the spent amounts minus the created amounts excluding the selected parent coinbase
+        let spent: u64 = mergeset_diff.removed().values().map(|entry| entry.amount).sum();
+        let created: u64 = mergeset_diff.added().values().map(|entry| entry.amount).sum();
+        let selected_parent_reward: u64 = selected_parent_transactions[0].outputs.iter().map(|output| output.value).sum();
+        let Some(fees) = (spent + selected_parent_reward).checked_sub(created) else {
+            return Err(format!(
+                "accepted transactions create {} more sompi than they spend",
+                created - spent - selected_parent_reward
+            ));
+        };
+
+        let subsidies: u64 = ghostdag_data
+            .unordered_mergeset()
+            .filter(|merged| !mergeset_non_daa.contains(merged))
+            .map(|merged| self.coinbase_manager.calc_block_subsidy(self.headers_store.get_daa_score(merged).unwrap()))
+            .sum();
+        let expected = subsidies + fees;
+        let actual: u64 = transactions[0].outputs.iter().map(|output| output.value).sum();
+        if actual > expected || (mergeset_non_daa.is_empty() && actual != expected) {
+            return Err(format!(
+                "coinbase pays {} sompi while the mergeset subsidies and accepted fees sum to {} sompi ({} non-DAA mergeset blocks)",
+                actual,
+                expected,
+                mergeset_non_daa.len()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Applies the mergeset diff of the block to the UTXO multiset of its selected parent and compares the result
+    /// with the header UTXO commitment and with the stored block multiset
+    fn check_utxo_commitment(&self, block: Hash, ghostdag_data: &GhostdagData) -> AuditResult {
+        let Some(mut multiset) = self.utxo_multisets_store.get(ghostdag_data.selected_parent).unwrap_option() else {
+            return Ok(());
+        };
+        let header = self.headers_store.get_header(block).unwrap();
+        let mergeset_diff = self.utxo_diffs_store.get(block).unwrap();
+        mergeset_diff.removed().iter().for_each(|(outpoint, entry)| multiset.remove_utxo(outpoint, entry));
+        mergeset_diff.added().iter().for_each(|(outpoint, entry)| multiset.add_utxo(outpoint, entry));
+        let computed = multiset.finalize();
+        if computed != header.utxo_commitment {
+            return Err(format!("header UTXO commitment {} differs from recomputed commitment {}", header.utxo_commitment, computed));
+        }
+        let stored = self.utxo_multisets_store.get(block).unwrap().finalize();
+        if stored != header.utxo_commitment {
+            return Err(format!(
+                "header UTXO commitment {} differs from stored multiset commitment {}",
+                header.utxo_commitment, stored
+            ));
+        }
+        Ok(())
+    }
+
+    /// Recomputes the multiset of the full virtual UTXO set and compares it with the one of the virtual state.
+    ///
+    /// The virtual stores are only read-locked while capturing the virtual state along with a snapshot of the UTXO set,
+    /// so virtual state commits are not delayed by the traversal
+    pub(crate) fn audit_virtual_utxo_set(&self) {
+        let start = Instant::now();
+        let virtual_read = self.virtual_stores.read();
+        let virtual_state = virtual_read.state.get().unwrap();
+        let utxo_set = virtual_read.utxo_set.clone();
+        let utxos = utxo_set.snapshot_iterator();
+        drop(virtual_read);
+
+        let mut multiset = MuHash::new();
+        for (outpoint, entry) in utxos.map(|r| r.unwrap()) {
+            multiset.add_utxo(&outpoint, &entry);
+        }
+
+        let computed = multiset.finalize();
+        let expected = virtual_state.multiset.clone().finalize();
+        if computed != expected {
+            self.report(
+                ConsensusAuditCheck::UtxoCommitment,
+                virtual_state.ghostdag_data.selected_parent,
+                format!("virtual UTXO set commitment {} differs from the virtual state commitment {}", computed, expected),
+            );
+        } else {
+            info!(target: AUDIT_LOG_TARGET, "Virtual UTXO set commitment audited in {} ms", start.elapsed().as_millis());
+        }
+    }
+
+    fn report(&self, check: ConsensusAuditCheck, block_hash: Hash, details: String) {
+        error!(target: AUDIT_LOG_TARGET, "Consensus audit violation: {} check failed for block {}: {}", check, block_hash, details);
+        self.notification_root
+            .notify(Notification::ConsensusAuditViolation(ConsensusAuditViolationNotification::new(check, block_hash, details)))
+            .expect("expecting an open unbounded channel");
+    }
+}
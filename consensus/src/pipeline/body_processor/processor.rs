@@ -32,12 +32,12 @@ use calico_consensus_notify::{
     root::ConsensusNotificationRoot,
 };
 use calico_consensusmanager::SessionLock;
+use calico_database::prelude::WriteBatch;
 use calico_hashes::Hash;
 use calico_notify::notifier::Notify;
 use crossbeam_channel::{Receiver, Sender};
 use parking_lot::RwLock;
 use rayon::ThreadPool;
-use rocksdb::WriteBatch;
 use std::sync::{atomic::Ordering, Arc};
 
 pub struct BlockBodyProcessor {
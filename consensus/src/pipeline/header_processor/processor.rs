@@ -35,6 +35,7 @@ use calico_consensus_core::{
     BlockHashSet, BlockLevel,
 };
 use calico_consensusmanager::SessionLock;
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::{StoreResultEmptyTuple, StoreResultExtensions};
 use calico_hashes::Hash;
 use calico_utils::vec::VecExtensions;
@@ -42,7 +43,6 @@ use crossbeam_channel::{Receiver, Sender};
 use itertools::Itertools;
 use parking_lot::RwLock;
 use rayon::ThreadPool;
-use rocksdb::WriteBatch;
 use std::sync::{atomic::Ordering, Arc};
 
 use super::super::ProcessingCounters;
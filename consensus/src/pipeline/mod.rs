@@ -1,3 +1,4 @@
+pub mod audit_processor;
 pub mod body_processor;
 pub mod deps_manager;
 pub mod header_processor;
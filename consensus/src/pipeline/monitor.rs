@@ -62,6 +62,13 @@ impl ConsensusMonitor {
                 if delta.body_counts != 0 { delta.mass_counts as f64 / delta.body_counts as f64 } else{ 0f64 },
             );
 
+            if delta.assumed_valid_block_counts > 0 {
+                info!(
+                    "Skipped script checks of {} transactions in {} UTXO-validated blocks assumed valid",
+                    delta.script_skipped_txs_counts, delta.assumed_valid_block_counts
+                );
+            }
+
             if delta.chain_disqualified_counts > 0 {
                 warn!(
                     "Consensus detected UTXO-invalid blocks which are disqualified from the virtual selected chain (possibly due to inheritance): {} disqualified vs. {} valid chain blocks",
@@ -1 +1,3 @@
 pub mod processor;
+#[cfg(test)]
+mod tests;
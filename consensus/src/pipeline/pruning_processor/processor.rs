@@ -25,14 +25,15 @@ use crate::{
 use calico_consensus_core::{
     blockhash::ORIGIN,
     blockstatus::BlockStatus::StatusHeaderOnly,
-    config::Config,
+    config::{retention::RetentionPolicy, Config},
     muhash::MuHashExtensions,
     pruning::{PruningPointProof, PruningPointTrustedData},
     trusted::ExternalGhostdagData,
     BlockHashMap, BlockHashSet, BlockLevel,
 };
 use calico_consensusmanager::SessionLock;
-use calico_core::{debug, info, warn};
+use calico_core::{debug, info, time::unix_now, warn};
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::{BatchDbWriter, MemoryWriter, StoreResultExtensions, DB};
 use calico_hashes::Hash;
 use calico_muhash::MuHash;
@@ -40,7 +41,6 @@ use calico_utils::iter::IterExtensions;
 use crossbeam_channel::Receiver as CrossbeamReceiver;
 use itertools::Itertools;
 use parking_lot::RwLockUpgradableReadGuard;
-use rocksdb::WriteBatch;
 use std::{
     collections::{hash_map::Entry::Vacant, VecDeque},
     ops::Deref,
@@ -242,7 +242,12 @@ impl PruningProcessor {
 
     fn prune(&self, new_pruning_point: Hash) {
         if self.config.is_archival {
-            warn!("The node is configured as an archival node -- avoiding data pruning. Note this might lead to heavy disk usage.");
+            match self.config.retention {
+                RetentionPolicy::Full => {
+                    warn!("The node is configured as an archival node -- avoiding data pruning. Note this might lead to heavy disk usage.")
+                }
+                retention => self.apply_retention_policy(retention, new_pruning_point),
+            }
             return;
         }
 
@@ -521,6 +526,101 @@ impl PruningProcessor {
         }
     }
 
+    /// Trims the block data of an archival node according to its retention policy. Headers and DAG relations are
+    /// kept, and blocks are processed in selected chain order through the mergesets of the chain blocks between the
+    /// former retention root and the new one, so the operation can be resumed if interrupted
+    fn apply_retention_policy(&self, retention: RetentionPolicy, new_pruning_point: Hash) {
+        let pruning_point_read = self.pruning_point_store.read();
+        // Initially, the retention root is the history root, a chain ancestor of the pruning point below which no block data is held
+        let prev_root = pruning_point_read
+            .retention_root()
+            .unwrap_option()
+            .or_else(|| pruning_point_read.history_root().unwrap_option())
+            .unwrap_or(self.config.genesis.hash);
+        drop(pruning_point_read);
+
+        let new_root = match retention {
+            RetentionPolicy::BodiesForDays(days) => {
+                // Bodies are trimmed up to the highest chain block which is older than the retention period. The former
+                // root might be newer than that (e.g., a recently synced history root), in which case nothing is trimmed
+                let cutoff = unix_now().saturating_sub(days.saturating_mul(24 * 60 * 60 * 1000));
+                self.reachability_service
+                    .backward_chain_iterator(new_pruning_point, prev_root, true)
+                    .find(|&hash| self.headers_store.get_timestamp(hash).unwrap() <= cutoff)
+                    .unwrap_or(prev_root)
+            }
+            _ => new_pruning_point,
+        };
+        if new_root == prev_root {
+            return;
+        }
+
+        info!("Block data retention ({}): waiting for consensus write permissions...", retention);
+
+        let mut prune_guard = self.pruning_lock.blocking_write();
+        let mut lock_acquire_time = Instant::now();
+        let (mut chain_blocks, mut trimmed) = (0, 0);
+
+        info!("Starting block data retention from {} to {}", prev_root, new_root);
+
+        for chain_block in self.reachability_service.forward_chain_iterator(prev_root, new_root, true).skip(1) {
+            // If we have the lock for more than a few milliseconds, release and recapture to allow consensus progress
+            if lock_acquire_time.elapsed() > Duration::from_millis(5) {
+                // An exit signal was received. Exit from this long running process.
+                if self.is_consensus_exiting.load(Ordering::Relaxed) {
+                    drop(prune_guard);
+                    info!("Block data retention interrupted: Process is exiting");
+                    return;
+                }
+                prune_guard.blocking_yield();
+                lock_acquire_time = Instant::now();
+            }
+
+            // The mergeset of a chain block is exactly its past which is not in the past of its selected parent,
+            // hence by walking up the chain each block in the past of the new root is visited exactly once
+            let ghostdag = self.ghostdag_primary_store.get_data(chain_block).unwrap();
+            let mut batch = WriteBatch::default();
+            let mut statuses_write = self.statuses_store.write();
+            for current in ghostdag.unordered_mergeset() {
+                let is_chain_block = current == ghostdag.selected_parent;
+
+                // UTXO state data is never needed below the pruning point
+                self.utxo_multisets_store.delete_batch(&mut batch, current).unwrap();
+                self.utxo_diffs_store.delete_batch(&mut batch, current).unwrap();
+
+                if !(is_chain_block && retention.keeps_acceptance_data()) {
+                    self.acceptance_data_store.delete_batch(&mut batch, current).unwrap();
+                }
+
+                let keeps_body = if is_chain_block { retention.keeps_chain_bodies() } else { retention.keeps_merged_bodies() };
+                if !keeps_body {
+                    self.block_transactions_store.delete_batch(&mut batch, current).unwrap();
+                    if statuses_write.get(current).unwrap_option().is_some_and(|s| s.has_block_body()) {
+                        // Similar to pruning, a valid block whose body was deleted is marked as header-only
+                        statuses_write.set_batch(&mut batch, current, StatusHeaderOnly).unwrap();
+                        trimmed += 1;
+                    }
+                }
+            }
+
+            // Advance the retention root along with the trimmed data so that an interrupted operation resumes from here
+            let mut pruning_point_write = self.pruning_point_store.write();
+            pruning_point_write.set_retention_root(&mut batch, chain_block).unwrap();
+            self.db.write(batch).unwrap();
+            drop(pruning_point_write);
+            drop(statuses_write);
+
+            chain_blocks += 1;
+            if chain_blocks % 1000 == 0 {
+                info!("Block data retention: traversed {} chain blocks, trimmed {} block bodies...", chain_blocks, trimmed);
+            }
+        }
+
+        drop(prune_guard);
+
+        info!("Block data retention completed: traversed {} chain blocks, trimmed {} block bodies", chain_blocks, trimmed);
+    }
+
     fn past_pruning_points(&self) -> BlockHashSet {
         (0..self.pruning_point_store.read().get().unwrap().index)
             .map(|index| self.past_pruning_points_store.get(index).unwrap())
@@ -0,0 +1,159 @@
+use crate::{
+    consensus::test_consensus::TestConsensus,
+    model::{
+        services::reachability::ReachabilityService,
+        stores::{headers::HeaderStoreReader, pruning::PruningStoreReader},
+    },
+};
+use calico_consensus_core::{
+    api::ConsensusApi,
+    coinbase::MinerData,
+    config::{params::MAINNET_PARAMS, retention::RetentionPolicy, ConfigBuilder},
+    tx::ScriptPublicKey,
+};
+use calico_core::time::unix_now;
+use calico_database::prelude::StoreResultExtensions;
+use calico_hashes::Hash;
+use std::time::Duration;
+
+fn new_consensus(retention: RetentionPolicy) -> TestConsensus {
+    let config = ConfigBuilder::new(MAINNET_PARAMS)
+        .skip_proof_of_work()
+        .edit_consensus_params(|p| {
+            p.finality_depth = 2;
+            p.mergeset_size_limit = 2;
+            p.ghostdag_k = 2;
+            p.merge_depth = 3;
+            p.pruning_depth = 100;
+        })
+        .set_archival()
+        .set_retention_policy(retention)
+        .build();
+    TestConsensus::new(&config)
+}
+
+/// Mines a DAG which forks into two parallel blocks every few blocks, so that the selected chain merges blocks which
+/// are not chain blocks. Blocks up to `old_blocks` are timestamped long ago, and blocks above it are timestamped now.
+/// `on_block` is called with each mined block. Returns all mined blocks.
+async fn mine_dag(consensus: &TestConsensus, old_blocks: u64, mut on_block: impl FnMut(u64, Hash)) -> Vec<Hash> {
+    let params = consensus.params().clone();
+    let mut tips = vec![params.genesis.hash];
+    let mut blocks = vec![];
+    for i in 1..params.pruning_depth + params.finality_depth + 100 {
+        let width = if i % 10 == 0 { 2 } else { 1 };
+        let mut row = vec![];
+        for j in 0..width {
+            let hash: Hash = (i * 10 + j).into();
+            // Distinct extra data keeps the coinbase transactions of parallel blocks apart
+            let miner_data = MinerData::new(ScriptPublicKey::from_vec(0, vec![]), vec![j as u8]);
+            let mut block = consensus.build_utxo_valid_block_with_parents(hash, tips.clone(), miner_data, vec![]);
+            if i <= old_blocks {
+                block.header.timestamp = params.genesis.timestamp + i * params.target_time_per_block;
+            }
+            consensus.validate_and_insert_block(block.to_immutable()).virtual_state_task.await.unwrap();
+            on_block(i, hash);
+            row.push(hash);
+        }
+        blocks.extend(row.iter().copied());
+        tips = row;
+    }
+    blocks
+}
+
+async fn wait_for_retention_root(consensus: &TestConsensus, expected: impl Fn() -> Hash) -> Hash {
+    loop {
+        let expected = expected();
+        if consensus.pruning_point_store().read().retention_root().unwrap_option() == Some(expected) {
+            return expected;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
+
+/// Asserts that block data in the past of the retention root was trimmed exactly as the policy defines, and that
+/// all other block data was kept
+fn assert_retained_data(consensus: &TestConsensus, blocks: &[Hash], retention: RetentionPolicy, retention_root: Option<Hash>) {
+    let reachability = consensus.reachability_service();
+    let sink = consensus.get_sink();
+    for &hash in blocks {
+        let trimmed = retention_root.is_some_and(|root| hash != root && reachability.is_dag_ancestor_of(hash, root));
+        let is_chain_block = reachability.is_chain_ancestor_of(hash, sink);
+        let keeps_body = if is_chain_block { retention.keeps_chain_bodies() } else { retention.keeps_merged_bodies() };
+        assert_eq!(
+            consensus.get_block_status(hash).unwrap().has_block_body(),
+            !trimmed || keeps_body,
+            "block body of {hash} (chain block: {is_chain_block}, trimmed: {trimmed})"
+        );
+        if is_chain_block {
+            assert_eq!(
+                consensus.get_block_acceptance_data(hash).is_ok(),
+                !trimmed || retention.keeps_acceptance_data(),
+                "acceptance data of {hash} (trimmed: {trimmed})"
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn retention_policy_test() {
+    for retention in [RetentionPolicy::Full, RetentionPolicy::ChainBodies, RetentionPolicy::AcceptanceData] {
+        let consensus = new_consensus(retention);
+        let wait_handles = consensus.init();
+        let blocks = mine_dag(&consensus, 0, |_, _| {}).await;
+
+        let retention_root = match retention {
+            RetentionPolicy::Full => None,
+            // Block data is trimmed up to the pruning point
+            _ => Some(wait_for_retention_root(&consensus, || consensus.pruning_point()).await),
+        };
+        assert_ne!(retention_root, Some(consensus.params().genesis.hash));
+        assert_retained_data(&consensus, &blocks, retention, retention_root);
+        consensus.shutdown(wait_handles);
+    }
+}
+
+#[tokio::test]
+async fn retention_policy_bodies_for_days_test() {
+    let retention = RetentionPolicy::BodiesForDays(1);
+    let consensus = new_consensus(retention);
+    let wait_handles = consensus.init();
+    let old_blocks = 50;
+    let blocks = mine_dag(&consensus, old_blocks, |_, _| {}).await;
+
+    // Block data is trimmed up to the last chain block which is older than the retention period
+    let cutoff = unix_now() - 24 * 60 * 60 * 1000;
+    let last_old_chain_block = || {
+        consensus
+            .reachability_service()
+            .backward_chain_iterator(consensus.pruning_point(), consensus.params().genesis.hash, true)
+            .find(|&hash| consensus.headers_store().get_timestamp(hash).unwrap() <= cutoff)
+            .unwrap()
+    };
+    let retention_root = wait_for_retention_root(&consensus, last_old_chain_block).await;
+    assert!(consensus.get_header(retention_root).unwrap().daa_score >= old_blocks);
+    assert_retained_data(&consensus, &blocks, retention, Some(retention_root));
+    consensus.shutdown(wait_handles);
+}
+
+#[tokio::test]
+async fn retention_policy_bodies_for_days_recent_history_test() {
+    let retention = RetentionPolicy::BodiesForDays(1);
+    let consensus = new_consensus(retention);
+    let wait_handles = consensus.init();
+
+    // The node synced its history recently, so no chain block in the future of its history root is old enough to be trimmed
+    let blocks = mine_dag(&consensus, 0, |i, hash| {
+        if i == 1 {
+            consensus.set_history_root(hash);
+        }
+    })
+    .await;
+    while consensus.pruning_point() == consensus.params().genesis.hash {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    // Shutting down waits for the pruning processor to handle the pending pruning point moves
+    consensus.shutdown(wait_handles);
+    assert!(consensus.pruning_point_store().read().retention_root().unwrap_option().is_none());
+    assert_retained_data(&consensus, &blocks, retention, None);
+}
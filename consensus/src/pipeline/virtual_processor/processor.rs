@@ -20,6 +20,7 @@ use crate::{
             depth::{DbDepthStore, DepthStoreReader},
             ghostdag::{DbGhostdagStore, GhostdagData, GhostdagStoreReader},
             headers::{DbHeadersStore, HeaderStoreReader},
+            headers_selected_tip::{DbHeadersSelectedTipStore, HeadersSelectedTipStoreReader},
             past_pruning_points::DbPastPruningPointsStore,
             pruning::{DbPruningStore, PruningStoreReader},
             pruning_utxoset::PruningUtxosetStores,
@@ -36,8 +37,9 @@ use crate::{
     },
     params::Params,
     pipeline::{
-        deps_manager::VirtualStateProcessingMessage, pruning_processor::processor::PruningProcessingMessage,
-        virtual_processor::utxo_validation::UtxoProcessingContext, ProcessingCounters,
+        audit_processor::processor::AuditProcessingMessage, deps_manager::VirtualStateProcessingMessage,
+        pruning_processor::processor::PruningProcessingMessage, virtual_processor::utxo_validation::UtxoProcessingContext,
+        ProcessingCounters,
     },
     processes::{
         coinbase::CoinbaseManager,
@@ -79,6 +81,7 @@ use calico_notify::{events::EventType, notifier::Notify};
 
 use super::errors::{PruningImportError, PruningImportResult};
 use calico_consensus_core::tx::ValidatedTransaction;
+use calico_database::prelude::WriteBatch;
 use calico_utils::binary_heap::BinaryHeapExtensions;
 use crossbeam_channel::{Receiver as CrossbeamReceiver, Sender as CrossbeamSender};
 use itertools::Itertools;
@@ -88,7 +91,6 @@ use rayon::{
     prelude::{IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator},
     ThreadPool,
 };
-use rocksdb::WriteBatch;
 use std::{
     cmp::min,
     collections::{BinaryHeap, HashMap, VecDeque},
@@ -101,6 +103,7 @@ pub struct VirtualStateProcessor {
     receiver: CrossbeamReceiver<VirtualStateProcessingMessage>,
     pruning_sender: CrossbeamSender<PruningProcessingMessage>,
     pruning_receiver: CrossbeamReceiver<PruningProcessingMessage>,
+    audit_sender: Option<CrossbeamSender<AuditProcessingMessage>>,
 
     // Thread pool
     pub(super) thread_pool: Arc<ThreadPool>,
@@ -113,6 +116,7 @@ pub struct VirtualStateProcessor {
     pub(super) max_block_parents: u8,
     pub(super) mergeset_size_limit: u64,
     pub(super) pruning_depth: u64,
+    pub(super) assume_valid: Option<Hash>,
 
     // Stores
     pub(super) statuses_store: Arc<RwLock<DbStatusesStore>>,
@@ -125,6 +129,7 @@ pub struct VirtualStateProcessor {
     pub(super) body_tips_store: Arc<RwLock<DbTipsStore>>,
     pub(super) depth_store: Arc<DbDepthStore>,
     pub(super) selected_chain_store: Arc<RwLock<DbSelectedChainStore>>,
+    pub(super) headers_selected_tip_store: Arc<RwLock<DbHeadersSelectedTipStore>>,
 
     // Utxo-related stores
     pub(super) utxo_diffs_store: Arc<DbUtxoDiffsStore>,
@@ -168,8 +173,10 @@ impl VirtualStateProcessor {
         receiver: CrossbeamReceiver<VirtualStateProcessingMessage>,
         pruning_sender: CrossbeamSender<PruningProcessingMessage>,
         pruning_receiver: CrossbeamReceiver<PruningProcessingMessage>,
+        audit_sender: Option<CrossbeamSender<AuditProcessingMessage>>,
         thread_pool: Arc<ThreadPool>,
         params: &Params,
+        assume_valid: Option<Hash>,
         db: Arc<DB>,
         storage: &Arc<ConsensusStorage>,
         services: &Arc<ConsensusServices>,
@@ -181,12 +188,14 @@ impl VirtualStateProcessor {
             receiver,
             pruning_sender,
             pruning_receiver,
+            audit_sender,
             thread_pool,
 
             genesis: params.genesis.clone(),
             max_block_parents: params.max_block_parents,
             mergeset_size_limit: params.mergeset_size_limit,
             pruning_depth: params.pruning_depth,
+            assume_valid,
 
             db,
             statuses_store: storage.statuses_store.clone(),
@@ -199,6 +208,7 @@ impl VirtualStateProcessor {
             body_tips_store: storage.body_tips_store.clone(),
             depth_store: storage.depth_store.clone(),
             selected_chain_store: storage.selected_chain_store.clone(),
+            headers_selected_tip_store: storage.headers_selected_tip_store.clone(),
             utxo_diffs_store: storage.utxo_diffs_store.clone(),
             utxo_multisets_store: storage.utxo_multisets_store.clone(),
             acceptance_data_store: storage.acceptance_data_store.clone(),
@@ -253,6 +263,9 @@ impl VirtualStateProcessor {
 
         // Pass the exit signal on to the following processor
         self.pruning_sender.send(PruningProcessingMessage::Exit).unwrap();
+        if let Some(audit_sender) = self.audit_sender.as_ref() {
+            audit_sender.send(AuditProcessingMessage::Exit).unwrap();
+        }
     }
 
     fn resolve_virtual(self: &Arc<Self>) {
@@ -309,6 +322,13 @@ impl VirtualStateProcessor {
         let _consume = self.pruning_receiver.try_iter().count();
         self.pruning_sender.send(PruningProcessingMessage::Process { sink_ghostdag_data }).unwrap();
 
+        // Hand the new chain blocks to the audit processor, if enabled
+        if let Some(audit_sender) = self.audit_sender.as_ref() {
+            if !chain_path.added.is_empty() {
+                audit_sender.send(AuditProcessingMessage::Process { added_chain_blocks: chain_path.added.clone() }).unwrap();
+            }
+        }
+
         // Emit notifications
         let accumulated_diff = Arc::new(accumulated_diff);
         let virtual_parents = Arc::new(new_virtual_state.parents.clone());
@@ -353,8 +373,16 @@ impl VirtualStateProcessor {
     /// The provided `diff` is assumed to initially hold the UTXO diff of `from` from virtual.
     /// The function returns the top-most UTXO-valid block on `chain(to)` which is ideally
     /// `to` itself (with the exception of returning `from` if `to` is already known to be UTXO disqualified).
-    /// When returning it is guaranteed that `diff` holds the diff of the returned block from virtual
-    fn calculate_utxo_state_relatively(&self, stores: &VirtualStores, diff: &mut UtxoDiff, from: Hash, to: Hash) -> Hash {
+    /// When returning it is guaranteed that `diff` holds the diff of the returned block from virtual.
+    /// `assume_valid` is the assume-valid block as resolved by [`Self::assume_valid_chain_block`] for the current pass
+    fn calculate_utxo_state_relatively(
+        &self,
+        stores: &VirtualStores,
+        diff: &mut UtxoDiff,
+        from: Hash,
+        to: Hash,
+        mut assume_valid: Option<Hash>,
+    ) -> Hash {
         // Avoid reorging if disqualified status is already known
         if self.statuses_store.read().get(to).unwrap() == StatusDisqualifiedFromChain {
             return from;
@@ -384,6 +412,8 @@ impl VirtualStateProcessor {
         // Walk back up to the new virtual selected parent candidate
         let mut chain_block_counter = 0;
         let mut chain_disqualified_counter = 0;
+        let mut assumed_valid_block_counter = 0;
+        let mut script_skipped_txs_counter = 0;
         for (selected_parent, current) in self.reachability_service.forward_chain_iterator(split_point, to, true).tuple_windows() {
             if selected_parent != diff_point {
                 // This indicates that the selected parent is disqualified, propagate up and continue
@@ -411,10 +441,19 @@ impl VirtualStateProcessor {
                     let selected_parent_utxo_view = (&stores.utxo_set).compose(&*diff);
 
                     let mut ctx = UtxoProcessingContext::new(mergeset_data.into(), selected_parent_multiset_hash);
+                    // Chain blocks are visited in forward order, so once a block falls outside the past of the
+                    // assume-valid block so do all the blocks above it
+                    assume_valid = assume_valid.filter(|&av| self.reachability_service.is_dag_ancestor_of(current, av));
+                    ctx.assumed_valid = assume_valid.is_some();
 
                     self.calculate_utxo_state(&mut ctx, &selected_parent_utxo_view, pov_daa_score);
                     let res = self.verify_expected_utxo_state(&mut ctx, &selected_parent_utxo_view, &header);
 
+                    if ctx.assumed_valid {
+                        assumed_valid_block_counter += 1;
+                        script_skipped_txs_counter += ctx.script_skipped_txs;
+                    }
+
                     if let Err(rule_error) = res {
                         info!("Block {} is disqualified from virtual chain: {}", current, rule_error);
                         self.statuses_store.write().set(current, StatusDisqualifiedFromChain).unwrap();
@@ -440,10 +479,26 @@ impl VirtualStateProcessor {
         if chain_disqualified_counter > 0 {
             self.counters.chain_disqualified_counts.fetch_add(chain_disqualified_counter, Ordering::Relaxed);
         }
+        if assumed_valid_block_counter > 0 {
+            self.counters.assumed_valid_block_counts.fetch_add(assumed_valid_block_counter, Ordering::Relaxed);
+            self.counters.script_skipped_txs_counts.fetch_add(script_skipped_txs_counter, Ordering::Relaxed);
+        }
 
         diff_point
     }
 
+    /// Returns the assume-valid block if it should be honored for the current virtual resolving pass, i.e., if its
+    /// header is on the headers selected chain. Blocks in its past (inclusive) skip script verification of the
+    /// transactions they accept, while blocks from competing branches keep being fully validated.
+    fn assume_valid_chain_block(&self) -> Option<Hash> {
+        let assume_valid = self.assume_valid?;
+        if !self.reachability_service.has_reachability_data(assume_valid) {
+            return None;
+        }
+        let headers_selected_tip = self.headers_selected_tip_store.read().get().unwrap().hash;
+        self.reachability_service.is_chain_ancestor_of(assume_valid, headers_selected_tip).then_some(assume_valid)
+    }
+
     fn commit_utxo_state(&self, current: Hash, mergeset_diff: UtxoDiff, multiset: MuHash, acceptance_data: AcceptanceData) {
         let mut batch = WriteBatch::default();
         self.utxo_diffs_store.insert_batch(&mut batch, current, Arc::new(mergeset_diff)).unwrap();
@@ -575,6 +630,9 @@ impl VirtualStateProcessor {
         // The initial diff point is the previous sink
         let mut diff_point = prev_sink;
 
+        // Resolved once for the whole search since it requires the headers selected tip
+        let assume_valid = self.assume_valid_chain_block();
+
         // We maintain the following invariant: `heap` is an antichain.
         // It holds at step 0 since tips are an antichain, and remains through the loop
         // since we check that every pushed block is not in the past of current heap
@@ -582,7 +640,7 @@ impl VirtualStateProcessor {
         loop {
             let candidate = heap.pop().expect("valid sink must exist").hash;
             if self.reachability_service.is_chain_ancestor_of(finality_point, candidate) {
-                diff_point = self.calculate_utxo_state_relatively(stores, diff, diff_point, candidate);
+                diff_point = self.calculate_utxo_state_relatively(stores, diff, diff_point, candidate, assume_valid);
                 if diff_point == candidate {
                     // This indicates that candidate has valid UTXO state and that `diff` represents its diff from virtual
 
@@ -1,16 +1,40 @@
-use crate::{consensus::test_consensus::TestConsensus, model::services::reachability::ReachabilityService};
+use crate::{
+    config::Config,
+    consensus::test_consensus::TestConsensus,
+    model::{
+        services::reachability::ReachabilityService,
+        stores::{
+            ghostdag::{GhostdagStore, GhostdagStoreReader},
+            utxo_diffs::UtxoDiffsStore,
+            utxo_set::UtxoSetStore,
+        },
+    },
+};
 use calico_consensus_core::{
     api::ConsensusApi,
     block::{Block, BlockTemplate, MutableBlock, TemplateBuildMode, TemplateTransactionSelector},
     blockhash,
     blockstatus::BlockStatus,
     coinbase::MinerData,
-    config::{params::MAINNET_PARAMS, ConfigBuilder},
-    tx::{ScriptPublicKey, ScriptVec, Transaction},
+    config::{assume_valid::AssumeValid, params::MAINNET_PARAMS, ConfigBuilder},
+    constants::TX_VERSION,
+    sign::sign,
+    subnets::SUBNETWORK_ID_NATIVE,
+    tx::{ScriptPublicKey, ScriptVec, SignableTransaction, Transaction, TransactionInput, TransactionOutput},
+    utxo::{utxo_collection::UtxoCollection, utxo_diff::UtxoDiff},
     BlockHashSet,
 };
+use calico_consensus_notify::notification::{ConsensusAuditCheck, Notification};
 use calico_hashes::Hash;
-use std::{collections::VecDeque, thread::JoinHandle};
+use calico_notify::{
+    scope::ConsensusAuditViolationScope, subscriber::SubscriptionManager, subscription::context::SubscriptionContext,
+};
+use std::{
+    collections::VecDeque,
+    iter::once,
+    sync::{atomic::Ordering, Arc},
+    thread::JoinHandle,
+};
 
 struct OnetimeTxSelector {
     txs: Option<Vec<Transaction>>,
@@ -154,8 +178,17 @@ impl TestContext {
 
 #[tokio::test]
 async fn template_mining_sanity_test() {
+    template_mining_sanity(TestConsensus::new).await
+}
+
+#[tokio::test]
+async fn template_mining_sanity_in_memory_test() {
+    template_mining_sanity(TestConsensus::new_in_memory).await
+}
+
+async fn template_mining_sanity(new_consensus: fn(&Config) -> TestConsensus) {
     let config = ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().build();
-    let mut ctx = TestContext::new(TestConsensus::new(&config));
+    let mut ctx = TestContext::new(new_consensus(&config));
     let rounds = 10;
     let width = 3;
     for _ in 0..rounds {
@@ -171,6 +204,15 @@ async fn template_mining_sanity_test() {
 
 #[tokio::test]
 async fn antichain_merge_test() {
+    antichain_merge(TestConsensus::new).await
+}
+
+#[tokio::test]
+async fn antichain_merge_in_memory_test() {
+    antichain_merge(TestConsensus::new_in_memory).await
+}
+
+async fn antichain_merge(new_consensus: fn(&Config) -> TestConsensus) {
     let config = ConfigBuilder::new(MAINNET_PARAMS)
         .skip_proof_of_work()
         .edit_consensus_params(|p| {
@@ -179,7 +221,7 @@ async fn antichain_merge_test() {
         })
         .build();
 
-    let mut ctx = TestContext::new(TestConsensus::new(&config));
+    let mut ctx = TestContext::new(new_consensus(&config));
 
     // Build a large 32-wide antichain
     ctx.build_block_template_row(0..32)
@@ -196,8 +238,207 @@ async fn antichain_merge_test() {
     ctx.assert_tips_num(1);
 }
 
+#[tokio::test]
+async fn assume_valid_test() {
+    let config = ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().build();
+
+    // Mine a chain and keep its blocks
+    let mut blocks = Vec::new();
+    {
+        let mut ctx = TestContext::new(TestConsensus::new(&config));
+        for _ in 0..10 {
+            ctx.build_block_template_row(0..1);
+            blocks.push(ctx.current_templates[0].block.clone().to_immutable());
+            ctx.validate_and_insert_row().await.assert_valid_utxo_tip();
+        }
+    }
+
+    // Sync the chain into a node assuming the middle chain block valid
+    let mut ctx = sync_headers_first(&config, &blocks, blocks[4].hash()).await;
+    ctx.assert_valid_utxo_tip();
+
+    // Only the assume-valid block and its past skip script checks
+    assert_eq!(ctx.consensus.processing_counters().assumed_valid_block_counts.load(Ordering::Relaxed), 5);
+}
+
+#[tokio::test]
+async fn assume_valid_invalid_signature_test() {
+    let config = ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().edit_consensus_params(|p| p.coinbase_maturity = 10).build();
+
+    // A fully validating node disqualifies the last chain block, which carries a transaction with an invalid signature
+    let blocks = mine_chain_with_invalid_signature(&config).await;
+    let invalid_block = blocks.last().unwrap().hash();
+    let selected_parent = blocks[blocks.len() - 2].hash();
+
+    // The block is accepted by a node assuming it valid, which skips the scripts of its transaction
+    let ctx = sync_headers_first(&config, &blocks, invalid_block).await;
+    assert_eq!(ctx.consensus.get_block_status(invalid_block), Some(BlockStatus::StatusUTXOValid));
+    assert_eq!(ctx.consensus.get_sink(), invalid_block);
+    assert_eq!(ctx.consensus.processing_counters().script_skipped_txs_counts.load(Ordering::Relaxed), 1);
+    drop(ctx);
+
+    // The block is disqualified by a node assuming its selected parent valid, since it is outside the assume-valid past
+    let ctx = sync_headers_first(&config, &blocks, selected_parent).await;
+    assert_eq!(ctx.consensus.get_block_status(invalid_block), Some(BlockStatus::StatusDisqualifiedFromChain));
+    assert_eq!(ctx.consensus.get_sink(), selected_parent);
+    assert_eq!(ctx.consensus.processing_counters().script_skipped_txs_counts.load(Ordering::Relaxed), 0);
+}
+
+/// Mines a chain on a fully validating node, ending with a block which spends a mature coinbase output with
+/// an invalid signature. Returns the mined blocks.
+async fn mine_chain_with_invalid_signature(config: &Config) -> Vec<Block> {
+    let secp = secp256k1::Secp256k1::new();
+    let (secret_key, _) = secp.generate_keypair(&mut rand::thread_rng());
+    let keypair = secp256k1::Keypair::from_secret_key(&secp, &secret_key);
+    let (public_key, _) = keypair.x_only_public_key();
+    let script_public_key = ScriptPublicKey::new(0, ScriptVec::from_iter(once(0x20).chain(public_key.serialize()).chain(once(0xac))));
+
+    let mut ctx = TestContext::new(TestConsensus::new(config));
+    ctx.miner_data = MinerData::new(script_public_key.clone(), vec![]);
+    let mut blocks = Vec::new();
+    for _ in 0..config.coinbase_maturity + 5 {
+        ctx.build_block_template_row(0..1);
+        blocks.push(ctx.current_templates[0].block.clone().to_immutable());
+        ctx.validate_and_insert_row().await.assert_valid_utxo_tip();
+    }
+
+    // Spend a mature coinbase output of the miner
+    let virtual_daa_score = ctx.consensus.get_virtual_daa_score();
+    let (outpoint, entry) = ctx
+        .consensus
+        .get_virtual_utxos(None, 1000, false)
+        .into_iter()
+        .find(|(_, entry)| {
+            entry.script_public_key == script_public_key && entry.block_daa_score + config.coinbase_maturity < virtual_daa_score
+        })
+        .unwrap();
+    let output = TransactionOutput::new(entry.amount - 10_000, script_public_key);
+    let unsigned_tx = Transaction::new(
+        TX_VERSION,
+        vec![TransactionInput::new(outpoint, vec![], 0, 1)],
+        vec![output],
+        0,
+        SUBNETWORK_ID_NATIVE,
+        0,
+        vec![],
+    );
+    let tx = sign(SignableTransaction::with_entries(unsigned_tx, vec![entry]), keypair).tx;
+
+    // Tamper with the signature, which is excluded from the transaction ID and so leaves the UTXO commitments of the block valid
+    let mut block = ctx
+        .consensus
+        .build_block_template(ctx.miner_data.clone(), Box::new(OnetimeTxSelector::new(vec![tx])), TemplateBuildMode::Standard)
+        .unwrap()
+        .block;
+    ctx.simulated_time += config.target_time_per_block;
+    block.header.timestamp = ctx.simulated_time;
+    block.transactions[1].inputs[0].signature_script[1] ^= 1;
+    block.header.hash_merkle_root = ctx.consensus.calc_transaction_hash_merkle_root(&block.transactions, block.header.daa_score);
+    block.header.finalize();
+    let block = block.to_immutable();
+    ctx.validate_and_insert_block(block.clone()).await;
+    assert_eq!(ctx.consensus.get_block_status(block.hash()), Some(BlockStatus::StatusDisqualifiedFromChain));
+    blocks.push(block);
+    blocks
+}
+
+/// Syncs the given chain headers-first into a new node which assumes the `assume_valid` block valid
+async fn sync_headers_first(config: &Config, blocks: &[Block], assume_valid: Hash) -> TestContext {
+    let config = config.to_builder().apply_args(|config| config.assume_valid = AssumeValid::Block(assume_valid)).build();
+    let mut ctx = TestContext::new(TestConsensus::new(&config));
+    for block in blocks.iter() {
+        let status = ctx.consensus.validate_and_insert_block(Block::from_header_arc(block.header.clone())).virtual_state_task.await;
+        assert!(status.unwrap().is_header_only());
+    }
+    for block in blocks.iter() {
+        ctx.validate_and_insert_block(block.clone()).await;
+    }
+    ctx
+}
+
+#[tokio::test]
+async fn audit_test() {
+    let config = ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().enable_audit().build();
+    let mut ctx = TestContext::new(TestConsensus::new(&config));
+    for _ in 0..10 {
+        ctx.build_block_template_row(0..3).validate_and_insert_row().await.assert_valid_utxo_tip();
+    }
+
+    // All the selected chain blocks, including the ones merging red and non-selected blue blocks, pass the audit
+    let audit_processor = ctx.consensus.audit_processor().unwrap();
+    let genesis = ctx.consensus.params().genesis.hash;
+    let mut current = ctx.consensus.get_sink();
+    while current != genesis {
+        assert_eq!(audit_processor.audit_chain_block(current), vec![], "audit violations for block {current}");
+        current = ctx.consensus.ghostdag_store().get_selected_parent(current).unwrap();
+    }
+}
+
+#[tokio::test]
+async fn audit_violation_test() {
+    let config = ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().enable_audit().build();
+    let (notification_sender, notification_receiver) = async_channel::unbounded();
+    let consensus = TestConsensus::with_notifier(&config, notification_sender, SubscriptionContext::new());
+    consensus.notification_root().start_notify(Default::default(), ConsensusAuditViolationScope {}.into()).await.unwrap();
+    let mut ctx = TestContext::new(consensus);
+    for _ in 0..10 {
+        ctx.build_block_template_row(0..1).validate_and_insert_row().await.assert_valid_utxo_tip();
+    }
+
+    let audit_processor = ctx.consensus.audit_processor().unwrap();
+    let sink = ctx.consensus.get_sink();
+    let selected_parent = ctx.consensus.ghostdag_store().get_selected_parent(sink).unwrap();
+    audit_processor.audit_chain_blocks([sink, selected_parent].into_iter());
+    audit_processor.audit_virtual_utxo_set();
+    assert!(notification_receiver.is_empty());
+
+    // Corrupt the GHOSTDAG data of the sink
+    let mut ghostdag_data = (*ctx.consensus.ghostdag_store().get_data(sink).unwrap()).clone();
+    ghostdag_data.blue_score += 1;
+    ctx.consensus.ghostdag_store().delete(sink).unwrap();
+    ctx.consensus.ghostdag_store().insert(sink, Arc::new(ghostdag_data)).unwrap();
+
+    // Corrupt the mergeset UTXO diff of the selected parent
+    ctx.consensus.utxo_diffs_store().delete(selected_parent).unwrap();
+    ctx.consensus.utxo_diffs_store().insert(selected_parent, Arc::new(UtxoDiff::default())).unwrap();
+
+    // Remove an entry from the virtual UTXO set
+    let virtual_stores = ctx.consensus.virtual_stores();
+    let mut virtual_write = virtual_stores.write();
+    let (outpoint, entry) = virtual_write.utxo_set.iterator().next().unwrap().unwrap();
+    let removed = UtxoCollection::from([(outpoint, (*entry).clone())]);
+    virtual_write.utxo_set.write_diff(&UtxoDiff::new(UtxoCollection::default(), removed)).unwrap();
+    drop(virtual_write);
+
+    audit_processor.audit_chain_blocks([sink, selected_parent].into_iter());
+    audit_processor.audit_virtual_utxo_set();
+    let violations: Vec<_> = std::iter::from_fn(|| notification_receiver.try_recv().ok())
+        .map(|notification| match notification {
+            Notification::ConsensusAuditViolation(violation) => (violation.check, violation.block_hash),
+            notification => panic!("unexpected notification {notification}"),
+        })
+        .collect();
+    for expected in [
+        (ConsensusAuditCheck::Ghostdag, sink),
+        (ConsensusAuditCheck::UtxoCommitment, selected_parent),
+        // The virtual UTXO set violation is reported for the virtual selected parent
+        (ConsensusAuditCheck::UtxoCommitment, sink),
+    ] {
+        assert!(violations.contains(&expected), "missing audit violation {expected:?} in {violations:?}");
+    }
+}
+
 #[tokio::test]
 async fn basic_utxo_disqualified_test() {
+    basic_utxo_disqualified(TestConsensus::new).await
+}
+
+#[tokio::test]
+async fn basic_utxo_disqualified_in_memory_test() {
+    basic_utxo_disqualified(TestConsensus::new_in_memory).await
+}
+
+async fn basic_utxo_disqualified(new_consensus: fn(&Config) -> TestConsensus) {
     calico_core::log::try_init_logger("info");
     let config = ConfigBuilder::new(MAINNET_PARAMS)
         .skip_proof_of_work()
@@ -207,7 +448,7 @@ async fn basic_utxo_disqualified_test() {
         })
         .build();
 
-    let mut ctx = TestContext::new(TestConsensus::new(&config));
+    let mut ctx = TestContext::new(new_consensus(&config));
 
     // Mine a valid chain
     for _ in 0..10 {
@@ -228,6 +469,15 @@ async fn basic_utxo_disqualified_test() {
 
 #[tokio::test]
 async fn double_search_disqualified_test() {
+    double_search_disqualified(TestConsensus::new).await
+}
+
+#[tokio::test]
+async fn double_search_disqualified_in_memory_test() {
+    double_search_disqualified(TestConsensus::new_in_memory).await
+}
+
+async fn double_search_disqualified(new_consensus: fn(&Config) -> TestConsensus) {
     // TODO: add non-coinbase transactions and concurrency in order to complicate the test
 
     calico_core::log::try_init_logger("info");
@@ -239,7 +489,7 @@ async fn double_search_disqualified_test() {
             p.min_difficulty_window_len = p.legacy_difficulty_window_size;
         })
         .build();
-    let mut ctx = TestContext::new(TestConsensus::new(&config));
+    let mut ctx = TestContext::new(new_consensus(&config));
 
     // Mine 3 valid blocks over genesis
     ctx.build_block_template_row(0..3)
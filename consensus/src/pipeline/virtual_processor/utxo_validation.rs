@@ -43,6 +43,10 @@ pub(super) struct UtxoProcessingContext<'a> {
     pub accepted_tx_ids: Vec<TransactionId>,
     pub mergeset_acceptance_data: Vec<MergesetBlockAcceptanceData>,
     pub mergeset_rewards: BlockHashMap<BlockRewardData>,
+    /// Indicates that the block is in the past of the assume-valid block, so transaction script checks are skipped
+    pub assumed_valid: bool,
+    /// The number of transactions whose script checks were skipped due to `assumed_valid`
+    pub script_skipped_txs: u64,
 }
 
 impl<'a> UtxoProcessingContext<'a> {
@@ -55,6 +59,8 @@ impl<'a> UtxoProcessingContext<'a> {
             accepted_tx_ids: Vec::with_capacity(1), // We expect at least the selected parent coinbase tx
             mergeset_rewards: BlockHashMap::with_capacity(mergeset_size),
             mergeset_acceptance_data: Vec::with_capacity(mergeset_size),
+            assumed_valid: false,
+            script_skipped_txs: 0,
         }
     }
 
@@ -95,7 +101,15 @@ impl VirtualStateProcessor {
 
             // No need to fully validate selected parent transactions since selected parent txs were already validated
             // as part of selected parent UTXO state verification with the exact same UTXO context.
-            let validation_flags = if is_selected_parent { TxValidationFlags::SkipScriptChecks } else { TxValidationFlags::Full };
+            let validation_flags = if is_selected_parent {
+                TxValidationFlags::SkipScriptChecks
+            } else if ctx.assumed_valid {
+                // The mergeset is in the past of the block, hence in the past of the assume-valid block as well
+                ctx.script_skipped_txs += txs.len() as u64 - 1;
+                TxValidationFlags::SkipScriptChecks
+            } else {
+                TxValidationFlags::Full
+            };
             let (validated_transactions, inner_multiset) =
                 self.validate_transactions_with_muhash_in_parallel(&txs, &composed_view, pov_daa_score, validation_flags);
 
@@ -147,7 +161,8 @@ impl VirtualStateProcessor {
     ///     1. The block header includes the expected `utxo_commitment`.
     ///     2. The block header includes the expected `accepted_id_merkle_root`.
     ///     3. The block coinbase transaction rewards the mergeset blocks correctly.
-    ///     4. All non-coinbase block transactions are valid against its own UTXO view.
+    ///     4. All non-coinbase block transactions are valid against its own UTXO view (script checks are
+    ///        skipped if the block is in the past of the assume-valid block).
     pub(super) fn verify_expected_utxo_state<V: UtxoView + Sync>(
         &self,
         ctx: &mut UtxoProcessingContext,
@@ -180,8 +195,14 @@ impl VirtualStateProcessor {
 
         // Verify all transactions are valid in context
         let current_utxo_view = selected_parent_utxo_view.compose(&ctx.mergeset_diff);
+        let validation_flags = if ctx.assumed_valid {
+            ctx.script_skipped_txs += txs.len() as u64 - 1;
+            TxValidationFlags::SkipScriptChecks
+        } else {
+            TxValidationFlags::Full
+        };
         let validated_transactions =
-            self.validate_transactions_in_parallel(&txs, &current_utxo_view, header.daa_score, TxValidationFlags::Full);
+            self.validate_transactions_in_parallel(&txs, &current_utxo_view, header.daa_score, validation_flags);
         if validated_transactions.len() < txs.len() - 1 {
             // Some non-coinbase transactions are invalid
             return Err(InvalidTransactionsInUtxoContext(txs.len() - 1 - validated_transactions.len(), txs.len() - 1));
@@ -10,10 +10,10 @@ use std::{
     time::{Duration, Instant},
 };
 
+use calico_database::prelude::WriteBatch;
 use calico_math::int::SignedInteger;
 use itertools::Itertools;
 use parking_lot::{Mutex, RwLock};
-use rocksdb::WriteBatch;
 
 use calico_consensus_core::{
     blockhash::{self, BlockHashExtensions, BlockHashes, ORIGIN},
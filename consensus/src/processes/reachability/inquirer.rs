@@ -267,11 +267,11 @@ mod tests {
     };
     use calico_consensus_core::blockhash::ORIGIN;
     use calico_database::prelude::ConnBuilder;
+    use calico_database::prelude::WriteBatch;
     use calico_database::{create_temp_db, prelude::CachePolicy};
     use itertools::Itertools;
     use parking_lot::RwLock;
     use rand::seq::IteratorRandom;
-    use rocksdb::WriteBatch;
     use std::{iter::once, ops::Deref};
 
     #[test]
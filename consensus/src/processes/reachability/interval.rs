@@ -153,6 +153,10 @@ impl Interval {
     pub fn strictly_contains(&self, other: Self) -> bool {
         self.start <= other.start && other.end < self.end
     }
+
+    pub fn is_disjoint(&self, other: Self) -> bool {
+        self.end < other.start || other.end < self.start
+    }
 }
 
 /// Returns a fraction for each size in sizes
@@ -252,6 +256,14 @@ mod tests {
         assert!(!Interval::new(1, 100).contains(Interval::new(150, 160)));
     }
 
+    #[test]
+    fn test_is_disjoint() {
+        assert!(Interval::new(1, 100).is_disjoint(Interval::new(101, 200)));
+        assert!(Interval::new(101, 200).is_disjoint(Interval::new(1, 100)));
+        assert!(!Interval::new(1, 100).is_disjoint(Interval::new(100, 200)));
+        assert!(!Interval::new(1, 100).is_disjoint(Interval::new(50, 60)));
+    }
+
     #[test]
     fn test_split_exponential() {
         struct Test {
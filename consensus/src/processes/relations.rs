@@ -7,10 +7,10 @@ use calico_consensus_core::{
     blockhash::{BlockHashIteratorExtensions, BlockHashes, ORIGIN},
     BlockHashSet,
 };
+use calico_database::prelude::WriteBatch;
 use calico_database::prelude::{BatchDbWriter, DbWriter, DirectWriter, StoreError};
 use calico_hashes::Hash;
 use itertools::Itertools;
-use rocksdb::WriteBatch;
 
 /// Initializes this relations store with an `origin` root
 pub fn init<S: RelationsStore + ChildrenStore + ?Sized>(relations: &mut S) {
@@ -32,17 +32,35 @@ impl<T: GhostdagStoreReader, U: ReachabilityStoreReader, V: RelationsStoreReader
     }
 
     pub fn calculate_chain_path(&self, from: Hash, to: Hash, chain_path_added_limit: Option<usize>) -> ChainPath {
+        let (removed, common_ancestor) = self.chain_path_removed(from, to, None).expect("the traversal is unbounded");
+        self.chain_path_added(removed, common_ancestor, to, chain_path_added_limit)
+    }
+
+    /// Calculates the chain path like [`Self::calculate_chain_path`], except that `chain_path_limit` bounds
+    /// the removed chain blocks as well. Fails if more chain blocks than the limit are to be removed.
+    pub fn calculate_bounded_chain_path(&self, from: Hash, to: Hash, chain_path_limit: Option<usize>) -> TraversalResult<ChainPath> {
+        let (removed, common_ancestor) = self.chain_path_removed(from, to, chain_path_limit)?;
+        Ok(self.chain_path_added(removed, common_ancestor, to, chain_path_limit))
+    }
+
+    /// Returns the chain blocks of `from` which are not chain ancestors of `to`, from the highest, along with the
+    /// common chain ancestor below them
+    fn chain_path_removed(&self, from: Hash, to: Hash, limit: Option<usize>) -> TraversalResult<(Vec<Hash>, Hash)> {
         let mut removed = Vec::new();
-        let mut common_ancestor = from;
         for current in self.reachability_service.default_backward_chain_iterator(from) {
-            if !self.reachability_service.is_chain_ancestor_of(current, to) {
-                removed.push(current);
-            } else {
-                common_ancestor = current;
-                break;
+            if self.reachability_service.is_chain_ancestor_of(current, to) {
+                return Ok((removed, current));
+            }
+            if let Some(limit) = limit.filter(|&limit| removed.len() >= limit) {
+                return Err(TraversalError::ReachedMaxTraversalAllowed(removed.len() as u64 + 1, limit as u64));
             }
+            removed.push(current);
         }
-        if chain_path_added_limit.is_none() {
+        Ok((removed, from))
+    }
+
+    fn chain_path_added(&self, removed: Vec<Hash>, common_ancestor: Hash, to: Hash, limit: Option<usize>) -> ChainPath {
+        if limit.is_none() {
             // Use backward chain iterator
             // It is more intuitive to use forward iterator here, but going downwards the selected chain is faster.
             let mut added = self.reachability_service.backward_chain_iterator(to, common_ancestor, false).collect_vec();
@@ -54,7 +72,7 @@ impl<T: GhostdagStoreReader, U: ReachabilityStoreReader, V: RelationsStoreReader
             .reachability_service
             .forward_chain_iterator(common_ancestor, to, true)
             .skip(1)
-            .take(chain_path_added_limit.unwrap()) // we handle is_none so we may unwrap. 
+            .take(limit.unwrap()) // we handle is_none so we may unwrap. 
             .collect_vec();
         ChainPath { added, removed }
     }
@@ -65,7 +83,21 @@ impl<T: GhostdagStoreReader, U: ReachabilityStoreReader, V: RelationsStoreReader
         tips: impl Iterator<Item = Hash>,
         max_traversal_allowed: Option<u64>,
     ) -> TraversalResult<Vec<Hash>> {
-        self.antipast_traversal(tips, block, max_traversal_allowed, true)
+        let mut anticone = Vec::new();
+        self.antipast_traversal(tips, block, max_traversal_allowed, true, |hash| anticone.push(hash))?;
+        Ok(anticone)
+    }
+
+    /// Returns the size of the anticone of `block` without collecting it
+    pub fn anticone_size(
+        &self,
+        block: Hash,
+        tips: impl Iterator<Item = Hash>,
+        max_traversal_allowed: Option<u64>,
+    ) -> TraversalResult<u64> {
+        let mut anticone_size = 0;
+        self.antipast_traversal(tips, block, max_traversal_allowed, true, |_| anticone_size += 1)?;
+        Ok(anticone_size)
     }
 
     pub fn antipast(
@@ -74,7 +106,9 @@ impl<T: GhostdagStoreReader, U: ReachabilityStoreReader, V: RelationsStoreReader
         tips: impl Iterator<Item = Hash>,
         max_traversal_allowed: Option<u64>,
     ) -> TraversalResult<Vec<Hash>> {
-        self.antipast_traversal(tips, block, max_traversal_allowed, false)
+        let mut antipast = Vec::new();
+        self.antipast_traversal(tips, block, max_traversal_allowed, false, |hash| antipast.push(hash))?;
+        Ok(antipast)
     }
 
     fn antipast_traversal(
@@ -83,7 +117,8 @@ impl<T: GhostdagStoreReader, U: ReachabilityStoreReader, V: RelationsStoreReader
         block: Hash,
         max_traversal_allowed: Option<u64>,
         return_anticone_only: bool,
-    ) -> Result<Vec<Hash>, TraversalError> {
+        mut visit: impl FnMut(Hash),
+    ) -> Result<(), TraversalError> {
         /*
            In some cases we search for the anticone of the pruning point starting from virtual parents.
            This means we might traverse ~pruning_depth blocks which are all stored in the visited set.
@@ -92,7 +127,7 @@ impl<T: GhostdagStoreReader, U: ReachabilityStoreReader, V: RelationsStoreReader
            wide. On the other hand, even at 10 BPS, pruning depth is around 2M blocks which is approx 64MB, a modest
            memory peak which happens at most once a in a pruning period (since pruning anticone is cached).
         */
-        let mut output = Vec::new(); // Anticone or antipast, depending on args
+        let mut output_count = 0; // Anticone or antipast size, depending on args
         let mut queue = VecDeque::from_iter(tips);
         let mut visited = BlockHashSet::from_iter(queue.iter().copied());
         let mut traversal_count = 0;
@@ -116,13 +151,14 @@ impl<T: GhostdagStoreReader, U: ReachabilityStoreReader, V: RelationsStoreReader
                     "[TRAVERSAL MANAGER] Traversal count: {}, queue size: {}, anticone size: {}, visited size: {}",
                     traversal_count,
                     queue.len(),
-                    output.len(),
+                    output_count,
                     visited.len()
                 );
             }
             // At this point, we know `current` is in antipast of `block`. The second condition is there to check if it's in the anticone
             if !return_anticone_only || !self.reachability_service.is_dag_ancestor_of(block, current) {
-                output.push(current);
+                output_count += 1;
+                visit(current);
             }
 
             for parent in self.relations_store.get_parents(current).unwrap().iter().copied() {
@@ -132,6 +168,33 @@ impl<T: GhostdagStoreReader, U: ReachabilityStoreReader, V: RelationsStoreReader
             }
         }
 
+        Ok(())
+    }
+
+    /// Returns the blocks in `past(high) ∩ future(low)`, including `low` and `high`, in BFS order from `high`.
+    /// Assumes `low` is a DAG ancestor of `high`. Fails if more than `max_traversal_allowed` blocks are traversed.
+    pub fn sub_dag(&self, low: Hash, high: Hash, max_traversal_allowed: Option<u64>) -> TraversalResult<Vec<Hash>> {
+        let mut output = Vec::new();
+        let mut queue = VecDeque::from([high]);
+        let mut visited = BlockHashSet::from_iter([high]);
+        while let Some(current) = queue.pop_front() {
+            output.push(current);
+            if let Some(max_traversal_allowed) = max_traversal_allowed {
+                if output.len() as u64 > max_traversal_allowed {
+                    return Err(TraversalError::ReachedMaxTraversalAllowed(output.len() as u64, max_traversal_allowed));
+                }
+            }
+            if current == low {
+                continue;
+            }
+            for parent in self.relations_store.get_parents(current).unwrap().iter().copied() {
+                // Parents outside of future(low) are not part of the sub-DAG
+                if self.reachability_service.is_dag_ancestor_of(low, parent) && visited.insert(parent) {
+                    queue.push_back(parent);
+                }
+            }
+        }
+
         Ok(output)
     }
 
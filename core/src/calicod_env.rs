@@ -1,3 +1,6 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
 pub fn version() -> &'static str {
     env!("CARGO_PKG_VERSION")
 }
@@ -5,3 +8,21 @@ pub fn version() -> &'static str {
 pub fn name() -> &'static str {
     "calicod"
 }
+
+/// Get the home directory of the user, under which the application directory is located by default.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_home_dir() -> PathBuf {
+    #[cfg(target_os = "windows")]
+    return dirs::data_local_dir().unwrap();
+    #[cfg(not(target_os = "windows"))]
+    return dirs::home_dir().unwrap();
+}
+
+/// Get the default application directory.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_app_dir() -> PathBuf {
+    #[cfg(target_os = "windows")]
+    return get_home_dir().join("rusty-calico");
+    #[cfg(not(target_os = "windows"))]
+    return get_home_dir().join(".rusty-calico");
+}
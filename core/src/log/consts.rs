@@ -2,6 +2,7 @@ pub const DEFAULT_LOGGER_ENV: &str = "RUST_LOG";
 
 pub const LOG_FILE_NAME: &str = "rusty-calico.log";
 pub const ERR_LOG_FILE_NAME: &str = "rusty-calico_err.log";
+pub const AUDIT_LOG_FILE_NAME: &str = "rusty-calico_audit.log";
 
 pub const LOG_ARCHIVE_SUFFIX: &str = ".{}.gz";
 
@@ -132,6 +132,13 @@ impl Builder {
         self
     }
 
+    /// Routes the records of a target to an additional appender, keeping the level possibly defined by the specs.
+    pub fn target_appender(&mut self, name: &str, appender: &'static str, default_level: LevelFilter) -> &mut Self {
+        let (appenders, _) = self.loggers.entry(name.to_string()).or_insert_with(|| (self.appenders.clone(), default_level));
+        appenders.push(appender);
+        self
+    }
+
     pub fn build(&mut self) -> Loggers {
         let loggers_map = mem::take(&mut self.loggers);
         let loggers =
@@ -17,6 +17,9 @@ cfg_if::cfg_if! {
     }
 }
 
+/// Log target of the consensus invariant audit records, which are also written to a dedicated file
+pub const AUDIT_LOG_TARGET: &str = "consensus_audit";
+
 pub fn set_log_level(level: LevelFilter) {
     workflow_log::set_log_level(level);
 }
@@ -30,15 +33,26 @@ pub fn init_logger(log_dir: Option<&str>, filters: &str) {
     const CONSOLE_APPENDER: &str = "stdout";
     const LOG_FILE_APPENDER: &str = "log_file";
     const ERR_LOG_FILE_APPENDER: &str = "err_log_file";
+    const AUDIT_LOG_FILE_APPENDER: &str = "audit_log_file";
 
     let level = LevelFilter::Info;
-    let loggers = logger::Builder::new().root_level(level).parse_env(DEFAULT_LOGGER_ENV).parse_expression(filters).build();
+    let mut builder = logger::Builder::new();
+    builder.root_level(level).parse_env(DEFAULT_LOGGER_ENV).parse_expression(filters);
+    if log_dir.is_some() {
+        builder.target_appender(AUDIT_LOG_TARGET, AUDIT_LOG_FILE_APPENDER, level);
+    }
+    let loggers = builder.build();
 
     let mut stdout_appender = AppenderSpec::console(CONSOLE_APPENDER, None);
     let mut file_appender = log_dir.map(|x| AppenderSpec::roller(LOG_FILE_APPENDER, None, x, LOG_FILE_NAME));
     let mut err_file_appender =
         log_dir.map(|x| AppenderSpec::roller(ERR_LOG_FILE_APPENDER, Some(LevelFilter::Warn), x, ERR_LOG_FILE_NAME));
-    let appenders = once(&mut stdout_appender).chain(&mut file_appender).chain(&mut err_file_appender).map(|x| x.appender());
+    let mut audit_file_appender = log_dir.map(|x| AppenderSpec::roller(AUDIT_LOG_FILE_APPENDER, None, x, AUDIT_LOG_FILE_NAME));
+    let appenders = once(&mut stdout_appender)
+        .chain(&mut file_appender)
+        .chain(&mut err_file_appender)
+        .chain(&mut audit_file_appender)
+        .map(|x| x.appender());
 
     let config = Config::builder()
         .appenders(appenders)
@@ -10,9 +10,16 @@ use calico_txscript_errors::TxScriptError;
 use smallvec::SmallVec;
 use std::iter::once;
 
+mod htlc;
 mod multisig;
+mod timelock;
 
+pub use htlc::{htlc_claim_signature_script, htlc_redeem_script, htlc_refund_signature_script, HtlcScript, HTLC_PREIMAGE_SIZE};
 pub use multisig::{multisig_redeem_script, multisig_redeem_script_ecdsa, Error as MultisigCreateError};
+pub use timelock::{
+    absolute_time_lock_redeem_script, relative_time_lock_redeem_script, time_lock_redeem_script, time_lock_signature_script,
+    Error as TimeLockCreateError, TimeLock, TimeLockScript,
+};
 
 /// Creates a new script to pay a transaction output to a 32-byte pubkey.
 fn pay_to_pub_key(address_payload: &[u8]) -> ScriptVec {
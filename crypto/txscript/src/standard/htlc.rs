@@ -0,0 +1,239 @@
+use super::timelock::{check_sig_opcode, decode_u64, parse_items, Error, ScriptItem};
+use crate::opcodes::codes::{OpCheckLockTimeVerify, OpElse, OpEndIf, OpEqualVerify, OpFalse, OpIf, OpSHA256, OpSize, OpTrue};
+use crate::script_builder::ScriptBuilder;
+
+/// Length of the HTLC secret preimage.
+pub const HTLC_PREIMAGE_SIZE: usize = 32;
+
+/// Hash-time-locked contract redeem script:
+///
+/// ```text
+/// OpIf
+///     OpSize <32> OpEqualVerify OpSHA256 <hash> OpEqualVerify <recipient pubkey>
+/// OpElse
+///     <lock time> OpCheckLockTimeVerify <refund pubkey>
+/// OpEndIf
+/// OpCheckSig[ECDSA]
+/// ```
+///
+/// The output can be claimed by the recipient revealing the 32-byte preimage
+/// of `hash` (SHA256) or refunded to the sender once the spending transaction
+/// lock time reaches `lock_time`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtlcScript {
+    pub hash: [u8; 32],
+    pub recipient: Vec<u8>,
+    pub refund: Vec<u8>,
+    pub lock_time: u64,
+}
+
+impl HtlcScript {
+    pub fn is_ecdsa(&self) -> bool {
+        self.recipient.len() == 33
+    }
+
+    /// Parse an HTLC redeem script created by [`htlc_redeem_script`].
+    pub fn parse(script: &[u8]) -> Option<Self> {
+        let items = parse_items(script)?;
+        if items.len() != 14 {
+            return None;
+        }
+        let data = |index: usize| match &items[index] {
+            ScriptItem::Data(data) => Some(data),
+            ScriptItem::Op(_) => None,
+        };
+
+        let parsed = Self {
+            hash: data(5)?.as_slice().try_into().ok()?,
+            recipient: data(7)?.clone(),
+            refund: data(11)?.clone(),
+            lock_time: decode_u64(data(9)?)?,
+        };
+        // opcodes are verified by matching the canonical encoding of the parsed script
+        (htlc_redeem_script(&parsed.hash, &parsed.recipient, &parsed.refund, parsed.lock_time).ok()?.as_slice() == script)
+            .then_some(parsed)
+    }
+}
+
+/// Creates an HTLC redeem script (see [`HtlcScript`]). `recipient` and `refund` are
+/// public keys of the same type (32-byte Schnorr or 33-byte ECDSA public keys).
+pub fn htlc_redeem_script(hash: &[u8; 32], recipient: &[u8], refund: &[u8], lock_time: u64) -> Result<Vec<u8>, Error> {
+    let check_sig = check_sig_opcode(recipient)?;
+    if check_sig_opcode(refund)? != check_sig {
+        return Err(Error::ErrMixedPubKeyTypes);
+    }
+
+    let mut builder = ScriptBuilder::new();
+    builder
+        .add_op(OpIf)?
+        .add_op(OpSize)?
+        .add_i64(HTLC_PREIMAGE_SIZE as i64)?
+        .add_op(OpEqualVerify)?
+        .add_op(OpSHA256)?
+        .add_data(hash)?
+        .add_op(OpEqualVerify)?
+        .add_data(recipient)?
+        .add_op(OpElse)?
+        .add_lock_time(lock_time)?
+        .add_op(OpCheckLockTimeVerify)?
+        .add_data(refund)?
+        .add_op(OpEndIf)?
+        .add_op(check_sig)?;
+    Ok(builder.drain())
+}
+
+/// Generates a signature script claiming an HTLC P2SH output by revealing the `preimage`.
+/// The `signature` is expected to include the sighash type byte.
+pub fn htlc_claim_signature_script(redeem_script: &[u8], signature: &[u8], preimage: &[u8]) -> Result<Vec<u8>, Error> {
+    Ok(ScriptBuilder::new().add_data(signature)?.add_data(preimage)?.add_op(OpTrue)?.add_data(redeem_script)?.drain())
+}
+
+/// Generates a signature script refunding an HTLC P2SH output after its lock time has passed.
+/// The `signature` is expected to include the sighash type byte.
+pub fn htlc_refund_signature_script(redeem_script: &[u8], signature: &[u8]) -> Result<Vec<u8>, Error> {
+    Ok(ScriptBuilder::new().add_data(signature)?.add_op(OpFalse)?.add_data(redeem_script)?.drain())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::timelock::{time_lock_redeem_script, time_lock_signature_script, TimeLock, TimeLockScript};
+    use super::*;
+    use crate::{caches::Cache, pay_to_script_hash_script, TxScriptEngine};
+    use calico_consensus_core::{
+        hashing::{
+            sighash::{calc_schnorr_signature_hash, SigHashReusedValuesUnsync},
+            sighash_type::SIG_HASH_ALL,
+        },
+        subnets::SUBNETWORK_ID_NATIVE,
+        tx::*,
+    };
+    use core::str::FromStr;
+    use secp256k1::Keypair;
+    use sha2::{Digest, Sha256};
+
+    fn kp() -> [Keypair; 2] {
+        let kp1 = Keypair::from_seckey_slice(
+            secp256k1::SECP256K1,
+            hex::decode("1d99c236b1f37b3b845336e6c568ba37e9ced4769d83b7a096eec446b940d160").unwrap().as_slice(),
+        )
+        .unwrap();
+        let kp2 = Keypair::from_seckey_slice(
+            secp256k1::SECP256K1,
+            hex::decode("349ca0c824948fed8c2c568ce205e9d9be4468ef099cad76e3e5ec918954aca4").unwrap().as_slice(),
+        )
+        .unwrap();
+        [kp1, kp2]
+    }
+
+    /// Spends a P2SH output locked by `redeem_script` with a transaction having the specified
+    /// `lock_time` and input `sequence`, using `signature_script` to build the input signature
+    /// script from the produced signature. Returns the script engine execution result.
+    fn spend(
+        redeem_script: &[u8],
+        lock_time: u64,
+        sequence: u64,
+        kp: &Keypair,
+        signature_script: impl FnOnce(&[u8]) -> Vec<u8>,
+    ) -> Result<(), calico_txscript_errors::TxScriptError> {
+        let prev_tx_id = TransactionId::from_str("63020db736215f8b1105a9281f7bcbb6473d965ecc45bb2fb5da59bd35e6ff84").unwrap();
+        let tx = Transaction::new(
+            0,
+            vec![TransactionInput {
+                previous_outpoint: TransactionOutpoint { transaction_id: prev_tx_id, index: 0 },
+                signature_script: vec![],
+                sequence,
+                sig_op_count: 1,
+            }],
+            vec![],
+            lock_time,
+            SUBNETWORK_ID_NATIVE,
+            0,
+            vec![],
+        );
+        let entries = vec![UtxoEntry {
+            amount: 12793000000000,
+            script_public_key: pay_to_script_hash_script(redeem_script),
+            block_daa_score: 36151168,
+            is_coinbase: false,
+        }];
+        let mut tx = MutableTransaction::with_entries(tx, entries);
+
+        let reused_values = SigHashReusedValuesUnsync::new();
+        let sig_hash = calc_schnorr_signature_hash(&tx.as_verifiable(), 0, SIG_HASH_ALL, &reused_values);
+        let msg = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice()).unwrap();
+        let signature: Vec<u8> = kp.sign_schnorr(msg).as_ref().iter().copied().chain([SIG_HASH_ALL.to_u8()]).collect();
+        tx.tx.inputs[0].signature_script = signature_script(&signature);
+
+        let tx = tx.as_verifiable();
+        let (input, entry) = tx.populated_inputs().next().unwrap();
+        let cache = Cache::new(10_000);
+        TxScriptEngine::from_transaction_input(&tx, input, 0, entry, &reused_values, &cache).unwrap().execute()
+    }
+
+    #[test]
+    fn test_htlc_script() {
+        let [recipient, refund] = kp();
+        let preimage = [7u8; HTLC_PREIMAGE_SIZE];
+        let hash: [u8; 32] = Sha256::digest(preimage).into();
+        let lock_time = 1_000_000;
+        let script = htlc_redeem_script(
+            &hash,
+            &recipient.x_only_public_key().0.serialize(),
+            &refund.x_only_public_key().0.serialize(),
+            lock_time,
+        )
+        .unwrap();
+
+        let parsed = HtlcScript::parse(&script).unwrap();
+        assert_eq!(parsed.hash, hash);
+        assert_eq!(parsed.lock_time, lock_time);
+        assert_eq!(parsed.recipient, recipient.x_only_public_key().0.serialize().to_vec());
+        assert!(!parsed.is_ecdsa());
+        assert!(HtlcScript::parse(&script[1..]).is_none());
+        assert!(TimeLockScript::parse(&script).is_none());
+
+        // claim by the recipient revealing the preimage
+        let claim = |kp: &Keypair, preimage: &[u8]| {
+            spend(&script, 0, u64::MAX, kp, |sig| htlc_claim_signature_script(&script, sig, preimage).unwrap())
+        };
+        assert!(claim(&recipient, &preimage).is_ok());
+        assert!(claim(&recipient, &[8u8; HTLC_PREIMAGE_SIZE]).is_err());
+        assert!(claim(&recipient, &preimage[1..]).is_err());
+        assert!(claim(&refund, &preimage).is_err());
+
+        // refund by the sender after the lock time
+        let refund_script = |sig: &[u8]| htlc_refund_signature_script(&script, sig).unwrap();
+        assert!(spend(&script, lock_time, 0, &refund, refund_script).is_ok());
+        assert!(spend(&script, lock_time - 1, 0, &refund, refund_script).is_err());
+        assert!(spend(&script, lock_time, u64::MAX, &refund, refund_script).is_err());
+        assert!(spend(&script, lock_time, 0, &recipient, refund_script).is_err());
+
+        // mixed key types are rejected
+        assert_eq!(
+            htlc_redeem_script(&hash, &recipient.x_only_public_key().0.serialize(), &refund.public_key().serialize(), lock_time),
+            Err(Error::ErrMixedPubKeyTypes)
+        );
+    }
+
+    #[test]
+    fn test_time_lock_script() {
+        let [kp, other] = kp();
+        let pub_key = kp.x_only_public_key().0.serialize();
+
+        let script = time_lock_redeem_script(&pub_key, TimeLock::Absolute(5000)).unwrap();
+        assert_eq!(TimeLockScript::parse(&script), Some(TimeLockScript { lock: TimeLock::Absolute(5000), pub_key: pub_key.to_vec() }));
+        let sign = |sig: &[u8]| time_lock_signature_script(&script, sig).unwrap();
+        assert!(spend(&script, 5000, 0, &kp, sign).is_ok());
+        assert!(spend(&script, 4999, 0, &kp, sign).is_err());
+        assert!(spend(&script, 5000, u64::MAX, &kp, sign).is_err());
+        assert!(spend(&script, 5000, 0, &other, sign).is_err());
+
+        let script = time_lock_redeem_script(&pub_key, TimeLock::Relative(10)).unwrap();
+        assert_eq!(TimeLockScript::parse(&script), Some(TimeLockScript { lock: TimeLock::Relative(10), pub_key: pub_key.to_vec() }));
+        let sign = |sig: &[u8]| time_lock_signature_script(&script, sig).unwrap();
+        assert!(spend(&script, 0, 10, &kp, sign).is_ok());
+        assert!(spend(&script, 0, 9, &kp, sign).is_err());
+
+        assert_eq!(time_lock_redeem_script(&[0u8; 20], TimeLock::Relative(10)), Err(Error::ErrInvalidPubKeyLength(20)));
+    }
+}
@@ -0,0 +1,165 @@
+use crate::opcodes::codes::{
+    Op16, Op1Negate, OpCheckLockTimeVerify, OpCheckSequenceVerify, OpCheckSig, OpCheckSigECDSA, OpData75, OpFalse, OpPushData1,
+    OpPushData2, OpPushData4, OpTrue,
+};
+use crate::script_builder::{ScriptBuilder, ScriptBuilderError};
+use thiserror::Error;
+
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+pub enum Error {
+    // ErrInvalidPubKeyLength is returned when the provided public key
+    // is neither a 32-byte Schnorr nor a 33-byte ECDSA public key.
+    #[error("invalid public key length {0}")]
+    ErrInvalidPubKeyLength(usize),
+    // ErrMixedPubKeyTypes is returned when a script spendable by multiple
+    // keys is created from both Schnorr and ECDSA public keys.
+    #[error("public keys should be of the same type")]
+    ErrMixedPubKeyTypes,
+    #[error(transparent)]
+    ScriptBuilderError(#[from] ScriptBuilderError),
+}
+
+/// Kind of time lock enforced by a time-locked script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeLock {
+    /// Absolute lock (`OpCheckLockTimeVerify`): the spending transaction lock time
+    /// (a DAA score or a timestamp) must be greater or equal to the value.
+    Absolute(u64),
+    /// Relative lock (`OpCheckSequenceVerify`): the spending input sequence
+    /// must be greater or equal to the value.
+    Relative(u64),
+}
+
+impl TimeLock {
+    pub fn value(&self) -> u64 {
+        match self {
+            TimeLock::Absolute(value) | TimeLock::Relative(value) => *value,
+        }
+    }
+
+    fn opcode(&self) -> u8 {
+        match self {
+            TimeLock::Absolute(_) => OpCheckLockTimeVerify,
+            TimeLock::Relative(_) => OpCheckSequenceVerify,
+        }
+    }
+}
+
+/// Time-locked redeem script (`<lock> OpCheckLockTimeVerify|OpCheckSequenceVerify <pubkey> OpCheckSig[ECDSA]`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeLockScript {
+    pub lock: TimeLock,
+    pub pub_key: Vec<u8>,
+}
+
+impl TimeLockScript {
+    pub fn is_ecdsa(&self) -> bool {
+        self.pub_key.len() == 33
+    }
+
+    /// Parse a time-locked redeem script created by [`time_lock_redeem_script`].
+    pub fn parse(script: &[u8]) -> Option<Self> {
+        let items = parse_items(script)?;
+        let [ScriptItem::Data(lock), ScriptItem::Op(lock_op), ScriptItem::Data(pub_key), ScriptItem::Op(_)] = items.as_slice() else {
+            return None;
+        };
+        let lock = match *lock_op {
+            OpCheckLockTimeVerify => TimeLock::Absolute(decode_u64(lock)?),
+            OpCheckSequenceVerify => TimeLock::Relative(decode_u64(lock)?),
+            _ => return None,
+        };
+        let parsed = Self { lock, pub_key: pub_key.clone() };
+        // the signature opcode is verified by matching the canonical encoding of the parsed script
+        (time_lock_redeem_script(&parsed.pub_key, lock).ok()?.as_slice() == script).then_some(parsed)
+    }
+}
+
+/// Creates a redeem script spendable by `pub_key` (a 32-byte Schnorr or a 33-byte ECDSA
+/// public key) once the specified time `lock` has been satisfied.
+pub fn time_lock_redeem_script(pub_key: &[u8], lock: TimeLock) -> Result<Vec<u8>, Error> {
+    let check_sig = check_sig_opcode(pub_key)?;
+    let mut builder = ScriptBuilder::new();
+    match lock {
+        TimeLock::Absolute(lock_time) => builder.add_lock_time(lock_time)?,
+        TimeLock::Relative(sequence) => builder.add_sequence(sequence)?,
+    };
+    builder.add_op(lock.opcode())?;
+    builder.add_data(pub_key)?;
+    builder.add_op(check_sig)?;
+    Ok(builder.drain())
+}
+
+/// Creates a redeem script spendable by `pub_key` once the spending transaction
+/// lock time reaches `lock_time` (a DAA score or a timestamp in milliseconds).
+pub fn absolute_time_lock_redeem_script(pub_key: &[u8], lock_time: u64) -> Result<Vec<u8>, Error> {
+    time_lock_redeem_script(pub_key, TimeLock::Absolute(lock_time))
+}
+
+/// Creates a redeem script spendable by `pub_key` once the spending input sequence reaches `sequence`.
+pub fn relative_time_lock_redeem_script(pub_key: &[u8], sequence: u64) -> Result<Vec<u8>, Error> {
+    time_lock_redeem_script(pub_key, TimeLock::Relative(sequence))
+}
+
+/// Generates a signature script spending a time-locked P2SH output.
+/// The `signature` is expected to include the sighash type byte.
+pub fn time_lock_signature_script(redeem_script: &[u8], signature: &[u8]) -> Result<Vec<u8>, Error> {
+    Ok(ScriptBuilder::new().add_data(signature)?.add_data(redeem_script)?.drain())
+}
+
+/// Returns the signature checking opcode matching the public key type.
+pub(super) fn check_sig_opcode(pub_key: &[u8]) -> Result<u8, Error> {
+    match pub_key.len() {
+        32 => Ok(OpCheckSig),
+        33 => Ok(OpCheckSigECDSA),
+        len => Err(Error::ErrInvalidPubKeyLength(len)),
+    }
+}
+
+/// Decodes a lock time or a sequence pushed by [`ScriptBuilder::add_lock_time`] or [`ScriptBuilder::add_sequence`].
+pub(super) fn decode_u64(data: &[u8]) -> Option<u64> {
+    (data.len() <= 8).then(|| {
+        let mut bytes = [0u8; 8];
+        bytes[..data.len()].copy_from_slice(data);
+        u64::from_le_bytes(bytes)
+    })
+}
+
+/// Script element as seen by the standard script parsers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum ScriptItem {
+    Data(Vec<u8>),
+    Op(u8),
+}
+
+/// Splits the script into data pushes and opcodes. Small integer opcodes
+/// are represented as data pushes of the corresponding value.
+pub(super) fn parse_items(mut script: &[u8]) -> Option<Vec<ScriptItem>> {
+    let mut items = Vec::new();
+    while let Some((&op, rest)) = script.split_first() {
+        let (len, rest) = match op {
+            OpFalse => (0, rest),
+            0x01..=OpData75 => (op as usize, rest),
+            OpPushData1 => (*rest.first()? as usize, &rest[1..]),
+            OpPushData2 => (u16::from_le_bytes(rest.get(..2)?.try_into().ok()?) as usize, &rest[2..]),
+            OpPushData4 => (u32::from_le_bytes(rest.get(..4)?.try_into().ok()?) as usize, &rest[4..]),
+            Op1Negate => {
+                items.push(ScriptItem::Data(vec![0x81]));
+                script = rest;
+                continue;
+            }
+            OpTrue..=Op16 => {
+                items.push(ScriptItem::Data(vec![op - OpTrue + 1]));
+                script = rest;
+                continue;
+            }
+            _ => {
+                items.push(ScriptItem::Op(op));
+                script = rest;
+                continue;
+            }
+        };
+        items.push(ScriptItem::Data(rest.get(..len)?.to_vec()));
+        script = &rest[len..];
+    }
+    Some(items)
+}
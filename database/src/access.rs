@@ -1,8 +1,12 @@
-use crate::{cache::CachePolicy, db::DB, errors::StoreError};
+use crate::{
+    cache::CachePolicy,
+    db::{DbIterator, DB},
+    errors::StoreError,
+};
 
 use super::prelude::{Cache, DbKey, DbWriter};
 use calico_utils::mem_size::MemSizeEstimator;
-use rocksdb::{Direction, IterateBounds, IteratorMode, ReadOptions};
+use rocksdb::IterateBounds;
 use serde::{de::DeserializeOwned, Serialize};
 use std::{collections::hash_map::RandomState, error::Error, hash::BuildHasher, sync::Arc};
 
@@ -71,16 +75,33 @@ where
         TData: DeserializeOwned, // We need `DeserializeOwned` since the slice coming from `db.get_pinned` has short lifetime
     {
         let prefix_key = DbKey::prefix_only(&self.prefix);
-        let mut read_opts = ReadOptions::default();
-        read_opts.set_iterate_range(rocksdb::PrefixRange(prefix_key.as_ref()));
-        self.db.iterator_opt(IteratorMode::From(prefix_key.as_ref(), Direction::Forward), read_opts).map(move |iter_result| {
-            match iter_result {
-                Ok((key, data_bytes)) => match bincode::deserialize(&data_bytes) {
-                    Ok(data) => Ok((key[prefix_key.prefix_len()..].into(), data)),
-                    Err(e) => Err(e.into()),
-                },
+        self.deserialize_entries(self.db.prefix_iterator(prefix_key.as_ref(), None), prefix_key.prefix_len())
+    }
+
+    /// Like [`Self::iterator`], except that entries written after the call are not observed
+    pub fn snapshot_iterator(&self) -> impl Iterator<Item = Result<(Box<[u8]>, TData), Box<dyn Error>>> + '_
+    where
+        TKey: Clone + AsRef<[u8]>,
+        TData: DeserializeOwned,
+    {
+        let prefix_key = DbKey::prefix_only(&self.prefix);
+        self.deserialize_entries(self.db.snapshot_prefix_iterator(prefix_key.as_ref()), prefix_key.prefix_len())
+    }
+
+    fn deserialize_entries<'a>(
+        &'a self,
+        db_iterator: DbIterator<'a>,
+        prefix_len: usize,
+    ) -> impl Iterator<Item = Result<(Box<[u8]>, TData), Box<dyn Error>>> + 'a
+    where
+        TData: DeserializeOwned,
+    {
+        db_iterator.map(move |iter_result| match iter_result {
+            Ok((key, data_bytes)) => match bincode::deserialize(&data_bytes) {
+                Ok(data) => Ok((key[prefix_len..].into(), data)),
                 Err(e) => Err(e.into()),
-            }
+            },
+            Err(e) => Err(e.into()),
         })
     }
 
@@ -187,14 +208,9 @@ where
             },
         );
 
-        let mut read_opts = ReadOptions::default();
-        read_opts.set_iterate_range(rocksdb::PrefixRange(db_key.as_ref()));
-
         let mut db_iterator = match seek_from {
-            Some(seek_key) => {
-                self.db.iterator_opt(IteratorMode::From(DbKey::new(&self.prefix, seek_key).as_ref(), Direction::Forward), read_opts)
-            }
-            None => self.db.iterator_opt(IteratorMode::Start, read_opts),
+            Some(seek_key) => self.db.prefix_iterator(db_key.as_ref(), Some(DbKey::new(&self.prefix, seek_key).as_ref())),
+            None => self.db.prefix_iterator(db_key.as_ref(), None),
         };
 
         if skip_first {
@@ -219,15 +235,20 @@ where
 mod tests {
     use super::*;
     use crate::{
-        create_temp_db,
-        prelude::{BatchDbWriter, ConnBuilder, DirectDbWriter},
+        create_memory_db, create_temp_db,
+        prelude::{BatchDbWriter, ConnBuilder, DirectDbWriter, WriteBatch},
     };
     use calico_hashes::Hash;
-    use rocksdb::WriteBatch;
 
     #[test]
     fn test_delete_all() {
         let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        test_delete_all_with_db(db);
+        let (_lifetime, db) = create_memory_db!(ConnBuilder::default());
+        test_delete_all_with_db(db);
+    }
+
+    fn test_delete_all_with_db(db: Arc<DB>) {
         let access = CachedDbAccess::<Hash, u64>::new(db.clone(), CachePolicy::Count(2), vec![1, 2]);
 
         access.write_many(DirectDbWriter::new(&db), &mut (0..16).map(|i| (i.into(), 2))).unwrap();
@@ -1,35 +1,84 @@
-use rocksdb::{DBWithThreadMode, MultiThreaded};
-use std::ops::{Deref, DerefMut};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use crate::prelude::StoreResult;
+pub use backend::{DbBackend, DbIterator, DbKeyValue, DbSlice};
+pub use batch::{WriteBatch, WriteOp};
 use calico_utils::fd_budget::FDGuard;
-pub use conn_builder::ConnBuilder;
+pub use conn_builder::{ConnBuilder, InMemory};
+pub use memory::MemoryDbBackend;
+pub use rocks::RocksDbBackend;
+use rocksdb::{DBWithThreadMode, MultiThreaded};
 
+mod backend;
+mod batch;
 mod conn_builder;
+mod memory;
+mod rocks;
 
 /// The DB type used for Calicod stores
 pub struct DB {
-    inner: DBWithThreadMode<MultiThreaded>,
-    _fd_guard: FDGuard,
+    backend: Box<dyn DbBackend>,
+    _fd_guard: Option<FDGuard>,
 }
 
 impl DB {
     pub fn new(inner: DBWithThreadMode<MultiThreaded>, fd_guard: FDGuard) -> Self {
-        Self { inner, _fd_guard: fd_guard }
+        Self::with_backend(Box::new(RocksDbBackend::new(inner)), Some(fd_guard))
     }
-}
 
-impl DerefMut for DB {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.inner
+    pub fn with_backend(backend: Box<dyn DbBackend>, fd_guard: Option<FDGuard>) -> Self {
+        Self { backend, _fd_guard: fd_guard }
+    }
+
+    /// Creates a DB held entirely in memory
+    pub fn in_memory() -> Self {
+        Self::with_backend(Box::new(MemoryDbBackend::new()), None)
     }
-}
 
-impl Deref for DB {
-    type Target = DBWithThreadMode<MultiThreaded>;
+    pub fn get_pinned<K: AsRef<[u8]>>(&self, key: K) -> Result<Option<DbSlice<'_>>, rocksdb::Error> {
+        self.backend.get_pinned(key.as_ref())
+    }
+
+    pub fn put<K, V>(&self, key: K, value: V) -> Result<(), rocksdb::Error>
+    where
+        K: AsRef<[u8]>,
+        V: AsRef<[u8]>,
+    {
+        self.backend.put(key.as_ref(), value.as_ref())
+    }
+
+    pub fn delete<K: AsRef<[u8]>>(&self, key: K) -> Result<(), rocksdb::Error> {
+        self.backend.delete(key.as_ref())
+    }
+
+    /// Applies all operations of the batch atomically
+    pub fn write(&self, batch: WriteBatch) -> Result<(), rocksdb::Error> {
+        self.backend.write(batch)
+    }
+
+    /// Iterates the entries whose keys start with `prefix` in ascending key order, starting from `seek_from` if provided
+    pub fn prefix_iterator(&self, prefix: impl AsRef<[u8]>, seek_from: Option<&[u8]>) -> DbIterator<'_> {
+        self.backend.prefix_iterator(prefix.as_ref(), seek_from)
+    }
+
+    /// Iterates the entries whose keys start with `prefix` in ascending key order, as they are at the time of the call
+    pub fn snapshot_prefix_iterator(&self, prefix: impl AsRef<[u8]>) -> DbIterator<'_> {
+        self.backend.snapshot_prefix_iterator(prefix.as_ref())
+    }
+
+    pub fn property_int_value(&self, name: &str) -> Result<Option<u64>, rocksdb::Error> {
+        self.backend.property_int_value(name)
+    }
+
+    /// Returns the path of the DB on disk, or `None` for in-memory DBs
+    pub fn path(&self) -> Option<&Path> {
+        self.backend.path()
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.inner
+    /// Creates a consistent point-in-time copy of the DB at `path`, which must not exist.
+    /// Only supported by the RocksDB backend.
+    pub fn create_checkpoint(&self, path: impl AsRef<Path>) -> StoreResult<()> {
+        self.backend.create_checkpoint(path.as_ref())
     }
 }
 
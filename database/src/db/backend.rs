@@ -0,0 +1,66 @@
+use super::WriteBatch;
+use crate::prelude::{StoreError, StoreResult};
+use rocksdb::DBPinnableSlice;
+use std::{ops::Deref, path::Path, sync::Arc};
+
+/// A key-value pair as yielded by DB iterators
+pub type DbKeyValue = (Box<[u8]>, Box<[u8]>);
+
+/// An iterator over DB entries in ascending key order
+pub type DbIterator<'a> = Box<dyn Iterator<Item = Result<DbKeyValue, rocksdb::Error>> + 'a>;
+
+/// A value read from the DB, possibly pinned by the backend in order to avoid a copy
+pub enum DbSlice<'a> {
+    Pinned(DBPinnableSlice<'a>),
+    Shared(Arc<[u8]>),
+}
+
+impl Deref for DbSlice<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            DbSlice::Pinned(slice) => slice,
+            DbSlice::Shared(slice) => slice,
+        }
+    }
+}
+
+impl AsRef<[u8]> for DbSlice<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+/// The key-value operations backing the DB stores
+pub trait DbBackend: Send + Sync {
+    fn get_pinned(&self, key: &[u8]) -> Result<Option<DbSlice<'_>>, rocksdb::Error>;
+
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<(), rocksdb::Error>;
+
+    fn delete(&self, key: &[u8]) -> Result<(), rocksdb::Error>;
+
+    /// Applies all operations of the batch atomically
+    fn write(&self, batch: WriteBatch) -> Result<(), rocksdb::Error>;
+
+    /// Iterates the entries whose keys start with `prefix`, starting from `seek_from` if provided
+    fn prefix_iterator(&self, prefix: &[u8], seek_from: Option<&[u8]>) -> DbIterator<'_>;
+
+    /// Iterates the entries whose keys start with `prefix` as they are at the time of the call, ignoring later writes
+    fn snapshot_prefix_iterator(&self, prefix: &[u8]) -> DbIterator<'_>;
+
+    /// Returns the value of a backend specific integer property, if supported
+    fn property_int_value(&self, _name: &str) -> Result<Option<u64>, rocksdb::Error> {
+        Ok(None)
+    }
+
+    /// Returns the path of the DB on disk, if any
+    fn path(&self) -> Option<&Path> {
+        None
+    }
+
+    /// Creates a consistent point-in-time copy of the DB at `path`, which must not exist
+    fn create_checkpoint(&self, _path: &Path) -> StoreResult<()> {
+        Err(StoreError::Unsupported("checkpoint"))
+    }
+}
@@ -0,0 +1,80 @@
+/// A single write operation recorded in a [`WriteBatch`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WriteOp {
+    Put(Box<[u8]>, Box<[u8]>),
+    Delete(Box<[u8]>),
+    /// Deletes the keys in the range `[from, to)`
+    DeleteRange(Box<[u8]>, Box<[u8]>),
+}
+
+/// A batch of write operations which the DB backend applies atomically, in insertion order.
+///
+/// The batch keeps its own log of operations, which each backend replays into its native form
+/// when the batch is written.
+#[derive(Default)]
+pub struct WriteBatch {
+    ops: Vec<WriteOp>,
+}
+
+impl WriteBatch {
+    pub fn put<K, V>(&mut self, key: K, value: V)
+    where
+        K: AsRef<[u8]>,
+        V: AsRef<[u8]>,
+    {
+        self.ops.push(WriteOp::Put(key.as_ref().into(), value.as_ref().into()));
+    }
+
+    pub fn delete<K: AsRef<[u8]>>(&mut self, key: K) {
+        self.ops.push(WriteOp::Delete(key.as_ref().into()));
+    }
+
+    pub fn delete_range<K: AsRef<[u8]>>(&mut self, from: K, to: K) {
+        self.ops.push(WriteOp::DeleteRange(from.as_ref().into(), to.as_ref().into()));
+    }
+
+    /// Returns the number of recorded operations
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Returns the recorded operations in insertion order
+    pub fn ops(&self) -> &[WriteOp] {
+        &self.ops
+    }
+
+    pub fn into_ops(self) -> Vec<WriteOp> {
+        self.ops
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_batch_ops() {
+        let mut batch = WriteBatch::default();
+        assert!(batch.is_empty());
+        assert!(batch.ops().is_empty());
+
+        batch.put([1u8, 2], [7u8; 300]);
+        batch.delete([3u8]);
+        batch.delete_range([4u8, 0], [5u8, 0]);
+        batch.put([0u8; 0], [0u8; 0]);
+        assert_eq!(batch.len(), 4);
+        assert_eq!(
+            batch.into_ops(),
+            vec![
+                WriteOp::Put([1, 2].into(), [7; 300].into()),
+                WriteOp::Delete([3].into()),
+                WriteOp::DeleteRange([4, 0].into(), [5, 0].into()),
+                WriteOp::Put([].into(), [].into())
+            ]
+        );
+    }
+}
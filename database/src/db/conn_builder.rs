@@ -1,14 +1,19 @@
-use crate::db::DB;
+use crate::{db::DB, errors::ConnBuilderError};
 use rocksdb::{DBWithThreadMode, MultiThreaded};
 use std::{path::PathBuf, sync::Arc};
 
 #[derive(Debug)]
 pub struct Unspecified;
 
+/// Marks a builder of a DB held entirely in memory (see [`ConnBuilder::in_memory`])
+#[derive(Debug)]
+pub struct InMemory;
+
 #[derive(Debug)]
 pub struct ConnBuilder<Path, const STATS_ENABLED: bool, StatsPeriod, FDLimit> {
     db_path: Path,
     create_if_missing: bool,
+    read_only: bool,
     parallelism: usize,
     files_limit: FDLimit,
     mem_budget: usize,
@@ -20,6 +25,7 @@ impl Default for ConnBuilder<Unspecified, false, Unspecified, Unspecified> {
         ConnBuilder {
             db_path: Unspecified,
             create_if_missing: true,
+            read_only: false,
             parallelism: 1,
             mem_budget: 64 * 1024 * 1024,
             stats_period: Unspecified,
@@ -34,6 +40,20 @@ impl<Path, const STATS_ENABLED: bool, StatsPeriod, FDLimit> ConnBuilder<Path, ST
             db_path,
             files_limit: self.files_limit,
             create_if_missing: self.create_if_missing,
+            read_only: self.read_only,
+            parallelism: self.parallelism,
+            mem_budget: self.mem_budget,
+            stats_period: self.stats_period,
+        }
+    }
+    /// Builds a DB held entirely in memory rather than on disk. Such a DB is discarded once dropped, and the
+    /// path, files limit and RocksDB tuning options do not apply to it
+    pub fn in_memory(self) -> ConnBuilder<InMemory, STATS_ENABLED, StatsPeriod, FDLimit> {
+        ConnBuilder {
+            db_path: InMemory,
+            files_limit: self.files_limit,
+            create_if_missing: self.create_if_missing,
+            read_only: self.read_only,
             parallelism: self.parallelism,
             mem_budget: self.mem_budget,
             stats_period: self.stats_period,
@@ -42,6 +62,10 @@ impl<Path, const STATS_ENABLED: bool, StatsPeriod, FDLimit> ConnBuilder<Path, ST
     pub fn with_create_if_missing(self, create_if_missing: bool) -> ConnBuilder<Path, STATS_ENABLED, StatsPeriod, FDLimit> {
         ConnBuilder { create_if_missing, ..self }
     }
+    /// Opens the database in read-only mode, allowing it to be inspected while another process holds it open for writing
+    pub fn with_read_only(self, read_only: bool) -> ConnBuilder<Path, STATS_ENABLED, StatsPeriod, FDLimit> {
+        ConnBuilder { read_only, ..self }
+    }
     pub fn with_parallelism(self, parallelism: impl Into<usize>) -> ConnBuilder<Path, STATS_ENABLED, StatsPeriod, FDLimit> {
         ConnBuilder { parallelism: parallelism.into(), ..self }
     }
@@ -53,6 +77,7 @@ impl<Path, const STATS_ENABLED: bool, StatsPeriod, FDLimit> ConnBuilder<Path, ST
             db_path: self.db_path,
             files_limit: files_limit.into(),
             create_if_missing: self.create_if_missing,
+            read_only: self.read_only,
             parallelism: self.parallelism,
             mem_budget: self.mem_budget,
             stats_period: self.stats_period,
@@ -65,6 +90,7 @@ impl<Path, FDLimit> ConnBuilder<Path, false, Unspecified, FDLimit> {
         ConnBuilder {
             db_path: self.db_path,
             create_if_missing: self.create_if_missing,
+            read_only: self.read_only,
             parallelism: self.parallelism,
             files_limit: self.files_limit,
             mem_budget: self.mem_budget,
@@ -78,6 +104,7 @@ impl<Path, StatsPeriod, FDLimit> ConnBuilder<Path, true, StatsPeriod, FDLimit> {
         ConnBuilder {
             db_path: self.db_path,
             create_if_missing: self.create_if_missing,
+            read_only: self.read_only,
             parallelism: self.parallelism,
             files_limit: self.files_limit,
             mem_budget: self.mem_budget,
@@ -88,6 +115,7 @@ impl<Path, StatsPeriod, FDLimit> ConnBuilder<Path, true, StatsPeriod, FDLimit> {
         ConnBuilder {
             db_path: self.db_path,
             create_if_missing: self.create_if_missing,
+            read_only: self.read_only,
             parallelism: self.parallelism,
             files_limit: self.files_limit,
             mem_budget: self.mem_budget,
@@ -107,34 +135,51 @@ macro_rules! default_opts {
         let guard = calico_utils::fd_budget::acquire_guard($self.files_limit)?;
         opts.set_max_open_files($self.files_limit);
         opts.create_if_missing($self.create_if_missing);
-        Ok((opts, guard))
+        Ok::<_, ConnBuilderError>((opts, guard))
+    }};
+}
+
+macro_rules! open_db {
+    ($self: expr, $opts: expr) => {{
+        let path = $self.db_path.to_str().unwrap();
+        if $self.read_only {
+            <DBWithThreadMode<MultiThreaded>>::open_for_read_only(&$opts, path, false)?
+        } else {
+            <DBWithThreadMode<MultiThreaded>>::open(&$opts, path)?
+        }
     }};
 }
 
 impl ConnBuilder<PathBuf, false, Unspecified, i32> {
-    pub fn build(self) -> Result<Arc<DB>, calico_utils::fd_budget::Error> {
+    pub fn build(self) -> Result<Arc<DB>, ConnBuilderError> {
         let (opts, guard) = default_opts!(self)?;
-        let db = Arc::new(DB::new(<DBWithThreadMode<MultiThreaded>>::open(&opts, self.db_path.to_str().unwrap()).unwrap(), guard));
+        let db = Arc::new(DB::new(open_db!(self, opts), guard));
         Ok(db)
     }
 }
 
 impl ConnBuilder<PathBuf, true, Unspecified, i32> {
-    pub fn build(self) -> Result<Arc<DB>, calico_utils::fd_budget::Error> {
+    pub fn build(self) -> Result<Arc<DB>, ConnBuilderError> {
         let (mut opts, guard) = default_opts!(self)?;
         opts.enable_statistics();
-        let db = Arc::new(DB::new(<DBWithThreadMode<MultiThreaded>>::open(&opts, self.db_path.to_str().unwrap()).unwrap(), guard));
+        let db = Arc::new(DB::new(open_db!(self, opts), guard));
         Ok(db)
     }
 }
 
 impl ConnBuilder<PathBuf, true, u32, i32> {
-    pub fn build(self) -> Result<Arc<DB>, calico_utils::fd_budget::Error> {
+    pub fn build(self) -> Result<Arc<DB>, ConnBuilderError> {
         let (mut opts, guard) = default_opts!(self)?;
         opts.enable_statistics();
         opts.set_report_bg_io_stats(true);
         opts.set_stats_dump_period_sec(self.stats_period);
-        let db = Arc::new(DB::new(<DBWithThreadMode<MultiThreaded>>::open(&opts, self.db_path.to_str().unwrap()).unwrap(), guard));
+        let db = Arc::new(DB::new(open_db!(self, opts), guard));
         Ok(db)
     }
 }
+
+impl<const STATS_ENABLED: bool, StatsPeriod, FDLimit> ConnBuilder<InMemory, STATS_ENABLED, StatsPeriod, FDLimit> {
+    pub fn build(self) -> Result<Arc<DB>, ConnBuilderError> {
+        Ok(Arc::new(DB::in_memory()))
+    }
+}
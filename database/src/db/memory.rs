@@ -0,0 +1,150 @@
+use super::{
+    backend::{DbBackend, DbIterator, DbKeyValue, DbSlice},
+    WriteBatch, WriteOp,
+};
+use parking_lot::RwLock;
+use std::{
+    collections::BTreeMap,
+    ops::Bound::{Excluded, Included, Unbounded},
+    sync::Arc,
+};
+
+type MemoryMap = BTreeMap<Box<[u8]>, Arc<[u8]>>;
+
+/// A DB backend holding all data in memory, which is discarded once the DB is dropped.
+///
+/// Batches are applied under a single write lock so readers observe them atomically. Unlike
+/// RocksDB iterators, prefix iterators do not hold a snapshot and observe writes which are applied
+/// while iterating.
+#[derive(Default)]
+pub struct MemoryDbBackend {
+    map: RwLock<MemoryMap>,
+}
+
+impl MemoryDbBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn delete_range(map: &mut MemoryMap, from: &[u8], to: &[u8]) {
+        if from >= to {
+            return;
+        }
+        let keys = map.range::<[u8], _>((Included(from), Excluded(to))).map(|(key, _)| key.clone()).collect::<Vec<_>>();
+        for key in keys {
+            map.remove(&key);
+        }
+    }
+}
+
+impl DbBackend for MemoryDbBackend {
+    fn get_pinned(&self, key: &[u8]) -> Result<Option<DbSlice<'_>>, rocksdb::Error> {
+        Ok(self.map.read().get(key).cloned().map(DbSlice::Shared))
+    }
+
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<(), rocksdb::Error> {
+        self.map.write().insert(key.into(), value.into());
+        Ok(())
+    }
+
+    fn delete(&self, key: &[u8]) -> Result<(), rocksdb::Error> {
+        self.map.write().remove(key);
+        Ok(())
+    }
+
+    fn write(&self, batch: WriteBatch) -> Result<(), rocksdb::Error> {
+        let mut map = self.map.write();
+        for op in batch.into_ops() {
+            match op {
+                WriteOp::Put(key, value) => {
+                    map.insert(key, value.into());
+                }
+                WriteOp::Delete(key) => {
+                    map.remove(&key);
+                }
+                WriteOp::DeleteRange(from, to) => Self::delete_range(&mut map, &from, &to),
+            }
+        }
+        Ok(())
+    }
+
+    fn prefix_iterator(&self, prefix: &[u8], seek_from: Option<&[u8]>) -> DbIterator<'_> {
+        let start = seek_from.filter(|&seek_key| seek_key > prefix).unwrap_or(prefix);
+        Box::new(MemoryIterator { map: &self.map, prefix: prefix.into(), next: Some((start.into(), true)) })
+    }
+
+    fn snapshot_prefix_iterator(&self, prefix: &[u8]) -> DbIterator<'_> {
+        // The map holds no snapshots, so the entries are copied under the read lock
+        let entries = self
+            .map
+            .read()
+            .range::<[u8], _>((Included(prefix), Unbounded))
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| Ok((key.clone(), value.as_ref().into())))
+            .collect::<Vec<_>>();
+        Box::new(entries.into_iter())
+    }
+}
+
+/// Iterates the map by looking up the successor of the last yielded key at each step, so the map
+/// is not locked between steps
+struct MemoryIterator<'a> {
+    map: &'a RwLock<MemoryMap>,
+    prefix: Box<[u8]>,
+    /// The key to continue from, and whether it is included
+    next: Option<(Box<[u8]>, bool)>,
+}
+
+impl Iterator for MemoryIterator<'_> {
+    type Item = Result<DbKeyValue, rocksdb::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (from, inclusive) = self.next.take()?;
+        let lower = if inclusive { Included(&from[..]) } else { Excluded(&from[..]) };
+        let map = self.map.read();
+        let (key, value) = map.range::<[u8], _>((lower, Unbounded)).next().filter(|(key, _)| key.starts_with(&self.prefix))?;
+        self.next = Some((key.clone(), false));
+        Some(Ok((key.clone(), value.as_ref().into())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(backend: &MemoryDbBackend, prefix: &[u8], seek_from: Option<&[u8]>) -> Vec<Vec<u8>> {
+        backend.prefix_iterator(prefix, seek_from).map(|item| item.unwrap().0.into_vec()).collect()
+    }
+
+    #[test]
+    fn test_memory_backend() {
+        let backend = MemoryDbBackend::new();
+        for key in [[1u8, 1], [1, 2], [1, 3], [2, 1], [2, 255]] {
+            backend.put(&key, &key[1..]).unwrap();
+        }
+        assert_eq!(&*backend.get_pinned(&[1, 2]).unwrap().unwrap(), &[2]);
+        assert!(backend.get_pinned(&[3]).unwrap().is_none());
+
+        assert_eq!(collect(&backend, &[1], None), vec![vec![1, 1], vec![1, 2], vec![1, 3]]);
+        assert_eq!(collect(&backend, &[1], Some(&[1, 2])), vec![vec![1, 2], vec![1, 3]]);
+        assert_eq!(collect(&backend, &[2], None), vec![vec![2, 1], vec![2, 255]]);
+        assert_eq!(collect(&backend, &[], None).len(), 5);
+
+        // Batch operations are applied in order
+        let mut batch = WriteBatch::default();
+        batch.delete_range([1u8, 1], [1, 3]);
+        batch.put([1u8, 2], [7]);
+        batch.delete([2u8, 1]);
+        backend.write(batch).unwrap();
+        assert_eq!(collect(&backend, &[1], None), vec![vec![1, 2], vec![1, 3]]);
+        assert_eq!(&*backend.get_pinned(&[1, 2]).unwrap().unwrap(), &[7]);
+        assert_eq!(collect(&backend, &[2], None), vec![vec![2, 255]]);
+
+        // Snapshot iterators ignore writes applied after their creation
+        let snapshot = backend.snapshot_prefix_iterator(&[1]);
+        backend.delete(&[1, 3]).unwrap();
+        backend.put(&[1, 4], &[4]).unwrap();
+        assert_eq!(snapshot.map(|item| item.unwrap().0.into_vec()).collect::<Vec<_>>(), vec![vec![1, 2], vec![1, 3]]);
+        assert_eq!(collect(&backend, &[1], None), vec![vec![1, 2], vec![1, 4]]);
+    }
+}
@@ -0,0 +1,102 @@
+use super::{
+    backend::{DbBackend, DbIterator, DbSlice},
+    WriteBatch, WriteOp,
+};
+use crate::prelude::StoreResult;
+use rocksdb::{checkpoint::Checkpoint, DBWithThreadMode, Direction, IteratorMode, MultiThreaded, ReadOptions};
+use std::path::Path;
+
+/// The RocksDB backend used for persisting node data
+pub struct RocksDbBackend {
+    inner: DBWithThreadMode<MultiThreaded>,
+}
+
+impl RocksDbBackend {
+    pub fn new(inner: DBWithThreadMode<MultiThreaded>) -> Self {
+        Self { inner }
+    }
+}
+
+impl DbBackend for RocksDbBackend {
+    fn get_pinned(&self, key: &[u8]) -> Result<Option<DbSlice<'_>>, rocksdb::Error> {
+        Ok(self.inner.get_pinned(key)?.map(DbSlice::Pinned))
+    }
+
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<(), rocksdb::Error> {
+        self.inner.put(key, value)
+    }
+
+    fn delete(&self, key: &[u8]) -> Result<(), rocksdb::Error> {
+        self.inner.delete(key)
+    }
+
+    fn write(&self, batch: WriteBatch) -> Result<(), rocksdb::Error> {
+        let mut native_batch = rocksdb::WriteBatch::default();
+        for op in batch.into_ops() {
+            match op {
+                WriteOp::Put(key, value) => native_batch.put(key, value),
+                WriteOp::Delete(key) => native_batch.delete(key),
+                WriteOp::DeleteRange(from, to) => native_batch.delete_range(from, to),
+            }
+        }
+        self.inner.write(native_batch)
+    }
+
+    fn prefix_iterator(&self, prefix: &[u8], seek_from: Option<&[u8]>) -> DbIterator<'_> {
+        let mut read_opts = ReadOptions::default();
+        read_opts.set_iterate_range(rocksdb::PrefixRange(prefix));
+        let mode = match seek_from {
+            Some(seek_key) => IteratorMode::From(seek_key, Direction::Forward),
+            None => IteratorMode::Start,
+        };
+        Box::new(self.inner.iterator_opt(mode, read_opts))
+    }
+
+    fn snapshot_prefix_iterator(&self, prefix: &[u8]) -> DbIterator<'_> {
+        // RocksDB iterators read from an implicit snapshot taken when they are created
+        self.prefix_iterator(prefix, None)
+    }
+
+    fn property_int_value(&self, name: &str) -> Result<Option<u64>, rocksdb::Error> {
+        self.inner.property_int_value(name)
+    }
+
+    fn path(&self) -> Option<&Path> {
+        Some(self.inner.path())
+    }
+
+    fn create_checkpoint(&self, path: &Path) -> StoreResult<()> {
+        // Files are hard linked when the target is on the same file system, so this is cheap even for large DBs
+        Ok(Checkpoint::new(&self.inner)?.create_checkpoint(path)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        create_memory_db, create_temp_db, load_existing_db,
+        prelude::{ConnBuilder, StoreError},
+        utils::get_calico_tempdir,
+    };
+
+    #[test]
+    fn test_create_checkpoint() {
+        let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        db.put([1u8, 1], [1]).unwrap();
+
+        let checkpoint_tempdir = get_calico_tempdir();
+        let checkpoint_path = checkpoint_tempdir.path().join("checkpoint");
+        db.create_checkpoint(&checkpoint_path).unwrap();
+        // Writes following the checkpoint are not part of it
+        db.put([1u8, 2], [2]).unwrap();
+        // The target is expected not to exist
+        assert!(db.create_checkpoint(&checkpoint_path).is_err());
+
+        let (_checkpoint_lifetime, checkpoint_db) = load_existing_db!(&checkpoint_path, ConnBuilder::default().with_files_limit(10));
+        assert_eq!(&*checkpoint_db.get_pinned([1u8, 1]).unwrap().unwrap(), &[1]);
+        assert!(checkpoint_db.get_pinned([1u8, 2]).unwrap().is_none());
+
+        let (_lifetime, db) = create_memory_db!(ConnBuilder::default());
+        assert!(matches!(db.create_checkpoint(checkpoint_tempdir.path().join("memory")), Err(StoreError::Unsupported(_))));
+    }
+}
@@ -18,6 +18,9 @@ pub enum StoreError {
     #[error("data inconsistency: {0}")]
     DataInconsistency(String),
 
+    #[error("operation not supported by the DB backend: {0}")]
+    Unsupported(&'static str),
+
     #[error("rocksdb error {0}")]
     DbError(#[from] rocksdb::Error),
 
@@ -27,6 +30,15 @@ pub enum StoreError {
 
 pub type StoreResult<T> = std::result::Result<T, StoreError>;
 
+#[derive(Error, Debug)]
+pub enum ConnBuilderError {
+    #[error(transparent)]
+    FdBudgetError(#[from] calico_utils::fd_budget::Error),
+
+    #[error("rocksdb error {0}")]
+    DbError(#[from] rocksdb::Error),
+}
+
 pub trait StoreResultExtensions<T> {
     /// Unwrap or assert that the error is key not fund in which case `None` is returned
     fn unwrap_option(self) -> Option<T>;
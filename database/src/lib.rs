@@ -19,6 +19,9 @@ pub mod prelude {
     pub use super::key::DbKey;
     pub use super::set_access::{CachedDbSetAccess, DbSetAccess, ReadLock};
     pub use super::writer::{BatchDbWriter, DbWriter, DirectDbWriter, DirectWriter, MemoryWriter};
-    pub use db::{delete_db, ConnBuilder, DB};
-    pub use errors::{StoreError, StoreResult, StoreResultEmptyTuple, StoreResultExtensions};
+    pub use db::{
+        delete_db, ConnBuilder, DbBackend, DbIterator, DbKeyValue, DbSlice, InMemory, MemoryDbBackend, RocksDbBackend, WriteBatch,
+        WriteOp, DB,
+    };
+    pub use errors::{ConnBuilderError, StoreError, StoreResult, StoreResultEmptyTuple, StoreResultExtensions};
 }
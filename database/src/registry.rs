@@ -36,6 +36,7 @@ pub enum DatabaseStorePrefixes {
     UtxoMultisets = 26,
     VirtualUtxoset = 27,
     VirtualState = 28,
+    RetentionRoot = 29,
 
     // ---- Decomposed reachability stores ----
     ReachabilityTreeChildren = 30,
@@ -53,6 +54,10 @@ pub enum DatabaseStorePrefixes {
     UtxoIndex = 192,
     UtxoIndexTips = 193,
     CirculatingSupply = 194,
+    ChainIndexBlocks = 195,
+    ChainIndexByDaaScore = 196,
+    ChainIndexByTimestamp = 197,
+    ChainIndexSink = 198,
 
     // ---- Separator ----
     /// Reserved as a separator
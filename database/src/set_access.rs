@@ -2,7 +2,7 @@ use crate::{cache::CachePolicy, db::DB, errors::StoreError};
 
 use super::prelude::{Cache, DbKey, DbWriter};
 use parking_lot::{RwLock, RwLockReadGuard};
-use rocksdb::{IterateBounds, IteratorMode, ReadOptions};
+use rocksdb::IterateBounds;
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
     collections::{hash_map::RandomState, HashSet},
@@ -162,10 +162,7 @@ where
         TData: DeserializeOwned,
     {
         let db_key = DbKey::new_with_bucket(&self.prefix, &key, []);
-        let mut read_opts = ReadOptions::default();
-        read_opts.set_iterate_range(rocksdb::PrefixRange(db_key.as_ref()));
-
-        let mut db_iterator = self.db.iterator_opt(IteratorMode::Start, read_opts);
+        let mut db_iterator = self.db.prefix_iterator(db_key.as_ref(), None);
 
         if skip_first {
             db_iterator.next();
@@ -197,15 +194,20 @@ where
 mod tests {
     use super::*;
     use crate::{
-        create_temp_db,
-        prelude::{BatchDbWriter, ConnBuilder, DirectDbWriter},
+        create_memory_db, create_temp_db,
+        prelude::{BatchDbWriter, ConnBuilder, DirectDbWriter, WriteBatch},
     };
     use calico_hashes::Hash;
-    use rocksdb::WriteBatch;
 
     #[test]
     fn test_delete_bucket() {
         let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        test_delete_bucket_with_db(db);
+        let (_lifetime, db) = create_memory_db!(ConnBuilder::default());
+        test_delete_bucket_with_db(db);
+    }
+
+    fn test_delete_bucket_with_db(db: Arc<DB>) {
         let access = DbSetAccess::<Hash, u64>::new(db.clone(), vec![1, 2]);
 
         for i in 0..16 {
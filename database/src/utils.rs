@@ -61,6 +61,16 @@ macro_rules! create_temp_db {
     }};
 }
 
+/// Creates a DB held entirely in memory, using the `in_memory` backend of the provided builder.
+/// Callers must keep the `TempDbLifetime` guard for as long as they wish the DB instance to exist.
+#[macro_export]
+macro_rules! create_memory_db {
+    ($conn_builder: expr) => {{
+        let db = $conn_builder.in_memory().build().unwrap();
+        ($crate::utils::DbLifetime::without_destroy(std::sync::Arc::downgrade(&db)), db)
+    }};
+}
+
 /// Creates a DB within the provided directory path.
 /// Callers must keep the `TempDbLifetime` guard for as long as they wish the DB instance to exist.
 #[macro_export]
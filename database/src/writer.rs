@@ -1,7 +1,5 @@
+use crate::prelude::{WriteBatch, DB};
 use calico_utils::refs::Refs;
-use rocksdb::WriteBatch;
-
-use crate::prelude::DB;
 
 /// Abstraction over direct/batched DB writing
 pub trait DbWriter {
@@ -0,0 +1,241 @@
+use crate::{
+    db::{block_hashes, block_key},
+    dump::DumpStore,
+    error::{DbToolError, DbToolResult},
+};
+use calico_consensus::model::stores::{
+    reachability::{DbReachabilityStore, ReachabilityStoreReader},
+    relations::{DbRelationsStore, RelationsStoreReader},
+    statuses::{DbStatusesStore, StatusesStoreReader},
+    virtual_state::{LkgVirtualState, VirtualStateStoreReader, VirtualStores},
+};
+use calico_consensus_core::{blockhash::BlockHashExtensions, muhash::MuHashExtensions};
+use calico_database::{
+    prelude::{CachePolicy, StoreError, DB},
+    registry::DatabaseStorePrefixes,
+};
+use calico_hashes::Hash;
+use calico_muhash::MuHash;
+use std::{fmt::Display, sync::Arc};
+
+/// Consistency checks which can be run over the consensus database
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Check {
+    Reachability,
+    Relations,
+    Statuses,
+    UtxoSet,
+}
+
+impl Check {
+    pub const ALL: [Check; 4] = [Check::Reachability, Check::Relations, Check::Statuses, Check::UtxoSet];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Check::Reachability => "reachability",
+            Check::Relations => "relations",
+            Check::Statuses => "statuses",
+            Check::UtxoSet => "utxo-set",
+        }
+    }
+}
+
+/// Runs the consistency checks, reporting every inconsistency found and returning their count
+pub struct Checker {
+    db: Arc<DB>,
+    reachability: DbReachabilityStore,
+    relations: DbRelationsStore,
+    statuses: DbStatusesStore,
+    inconsistencies: usize,
+}
+
+impl Checker {
+    pub fn new(db: Arc<DB>) -> Self {
+        Self {
+            reachability: DbReachabilityStore::new(db.clone(), CachePolicy::Empty, CachePolicy::Empty),
+            relations: DbRelationsStore::new(db.clone(), 0, CachePolicy::Empty, CachePolicy::Empty),
+            statuses: DbStatusesStore::new(db.clone(), CachePolicy::Empty),
+            db,
+            inconsistencies: 0,
+        }
+    }
+
+    pub fn run(&mut self, checks: &[Check]) -> DbToolResult<usize> {
+        for check in checks.iter().copied() {
+            println!("Checking {}...", check.name());
+            let inconsistencies = self.inconsistencies;
+            match check {
+                Check::Reachability => self.check_reachability()?,
+                Check::Relations => self.check_relations()?,
+                Check::Statuses => self.check_statuses()?,
+                Check::UtxoSet => self.check_utxo_set()?,
+            }
+            println!("  {} inconsistencies found", self.inconsistencies - inconsistencies);
+        }
+        Ok(self.inconsistencies)
+    }
+
+    fn report(&mut self, check: Check, message: impl Display) {
+        self.inconsistencies += 1;
+        println!("  [{}] {}", check.name(), message);
+    }
+
+    /// Every block interval is contained in the interval of its tree parent, and the
+    /// intervals of the tree children of a block are ordered and disjoint
+    fn check_reachability(&mut self) -> DbToolResult<()> {
+        let prefix = DumpStore::Reachability.key_prefix();
+        for hash in block_hashes(&self.db.clone(), &prefix) {
+            let hash = hash?;
+            let (start, end) = <(u64, u64)>::from(self.reachability.get_interval(hash)?);
+            let parent = self.reachability.get_parent(hash)?;
+            if parent.is_none() {
+                // The reachability root has no tree parent
+                continue;
+            }
+            match self.reachability.get_interval(parent) {
+                Ok(interval) => {
+                    let (parent_start, parent_end) = <(u64, u64)>::from(interval);
+                    if start < parent_start || end > parent_end {
+                        self.report(
+                            Check::Reachability,
+                            format!("interval [{start}, {end}] of {hash} is not contained in [{parent_start}, {parent_end}] of its parent {parent}"),
+                        );
+                    }
+                    if !self.reachability.get_children(parent)?.contains(&hash) {
+                        self.report(Check::Reachability, format!("{hash} is missing from the tree children of its parent {parent}"));
+                    }
+                }
+                Err(StoreError::KeyNotFound(_)) => {
+                    self.report(Check::Reachability, format!("tree parent {parent} of {hash} has no reachability data"))
+                }
+                Err(err) => return Err(err.into()),
+            }
+
+            let children = self.reachability.get_children(hash)?;
+            let mut previous_end: Option<u64> = None;
+            for child in children.iter().copied() {
+                let (child_start, child_end) = match self.reachability.get_interval(child) {
+                    Ok(interval) => <(u64, u64)>::from(interval),
+                    Err(StoreError::KeyNotFound(_)) => {
+                        self.report(Check::Reachability, format!("tree child {child} of {hash} has no reachability data"));
+                        continue;
+                    }
+                    Err(err) => return Err(err.into()),
+                };
+                if previous_end.is_some_and(|previous_end| child_start <= previous_end) {
+                    self.report(
+                        Check::Reachability,
+                        format!(
+                            "interval [{child_start}, {child_end}] of {child} overlaps or precedes its previous sibling under {hash}"
+                        ),
+                    );
+                }
+                previous_end = Some(child_end);
+            }
+        }
+        Ok(())
+    }
+
+    /// Every block is a child of each of its parents and a parent of each of its children.
+    /// Parents below the pruning point may be missing from the store.
+    fn check_relations(&mut self) -> DbToolResult<()> {
+        let prefix = DumpStore::Relations.key_prefix();
+        for hash in block_hashes(&self.db.clone(), &prefix) {
+            let hash = hash?;
+            for parent in self.relations.get_parents(hash)?.iter().copied() {
+                if self.relations.has(parent)? && !self.relations.get_children(parent)?.read().contains(&hash) {
+                    self.report(Check::Relations, format!("{hash} is missing from the children of its parent {parent}"));
+                }
+            }
+            let children = self.relations.get_children(hash)?.read().iter().copied().collect::<Vec<_>>();
+            for child in children {
+                match self.relations.get_parents(child) {
+                    Ok(parents) if parents.contains(&hash) => {}
+                    Ok(_) => self.report(Check::Relations, format!("{hash} is missing from the parents of its child {child}")),
+                    Err(StoreError::KeyNotFound(_)) => {
+                        self.report(Check::Relations, format!("child {child} of {hash} has no relations data"))
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Blocks with a body status have their transactions stored while header-only blocks do not
+    fn check_statuses(&mut self) -> DbToolResult<()> {
+        let prefix = DumpStore::Statuses.key_prefix();
+        let bodies_prefix: Vec<u8> = DatabaseStorePrefixes::BlockTransactions.into();
+        for hash in block_hashes(&self.db.clone(), &prefix) {
+            let hash = hash?;
+            let status = self.statuses.get(hash)?;
+            let has_body = self.db.get_pinned(block_key(&bodies_prefix, hash))?.is_some();
+            if status.has_block_body() && !has_body {
+                self.report(Check::Statuses, format!("{hash} has status {status:?} but no stored body"));
+            } else if !status.has_block_body() && has_body {
+                self.report(Check::Statuses, format!("{hash} has status {status:?} but a stored body"));
+            }
+        }
+        Ok(())
+    }
+
+    /// The MuHash of the virtual UTXO set matches the multiset commitment of the virtual state
+    fn check_utxo_set(&mut self) -> DbToolResult<()> {
+        let stores = VirtualStores::new(self.db.clone(), LkgVirtualState::default(), CachePolicy::Empty);
+        let state = match stores.state.get() {
+            Ok(state) => state,
+            Err(StoreError::KeyNotFound(_)) => {
+                self.report(Check::UtxoSet, "the virtual state is not initialized");
+                return Ok(());
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let mut multiset = MuHash::new();
+        let mut count = 0u64;
+        for item in stores.utxo_set.iterator() {
+            let (outpoint, entry) = item.map_err(|err| DbToolError::IteratorError("virtual UTXO set", err.to_string()))?;
+            multiset.add_utxo(&outpoint, &entry);
+            count += 1;
+        }
+        let computed: Hash = multiset.finalize();
+        let committed: Hash = state.multiset.clone().finalize();
+        println!("  {count} UTXO entries, MuHash {computed}");
+        if computed != committed {
+            self.report(Check::UtxoSet, format!("MuHash {computed} of the virtual UTXO set differs from the committed {committed}"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calico_consensus::{
+        consensus::test_consensus::TestConsensus, model::stores::relations::RelationsStore, params::MAINNET_PARAMS,
+    };
+    use calico_consensus_core::config::ConfigBuilder;
+    use calico_database::{create_temp_db, prelude::ConnBuilder, prelude::DirectDbWriter};
+
+    #[tokio::test]
+    async fn test_checks_over_a_broken_relation() {
+        let config = ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().build();
+        let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let (notification_sender, _notification_receiver) = async_channel::unbounded();
+        let consensus = TestConsensus::with_db(db.clone(), &config, notification_sender);
+        let wait_handles = consensus.init();
+        let genesis = config.genesis.hash;
+        consensus.add_utxo_valid_block_with_parents(1.into(), vec![genesis], vec![]).await.unwrap();
+        consensus.add_utxo_valid_block_with_parents(2.into(), vec![genesis], vec![]).await.unwrap();
+        consensus.add_utxo_valid_block_with_parents(3.into(), vec![1.into(), 2.into()], vec![]).await.unwrap();
+        consensus.shutdown(wait_handles);
+
+        assert_eq!(Checker::new(db.clone()).run(&Check::ALL).unwrap(), 0, "a sound database has no inconsistencies");
+
+        // Block 3 is no longer a child of its parent 1
+        let mut relations = DbRelationsStore::new(db.clone(), 0, CachePolicy::Empty, CachePolicy::Empty);
+        relations.delete_child(DirectDbWriter::new(&db), 1.into(), 3.into()).unwrap();
+        assert_eq!(Checker::new(db.clone()).run(&[Check::Relations]).unwrap(), 1);
+        assert_eq!(Checker::new(db.clone()).run(&[Check::Reachability, Check::Statuses, Check::UtxoSet]).unwrap(), 0);
+        assert_eq!(Checker::new(db).run(&Check::ALL).unwrap(), 1);
+    }
+}
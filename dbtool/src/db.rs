@@ -0,0 +1,86 @@
+use crate::error::{DbToolError, DbToolResult};
+use calico_consensus::consensus::factory::MultiConsensusManagementStore;
+use calico_database::{
+    prelude::{ConnBuilder, DB},
+    registry::DatabaseStorePrefixes,
+};
+use calico_hashes::{Hash, HASH_SIZE};
+use calico_utils::fd_budget;
+use std::{path::PathBuf, sync::Arc};
+
+const CONSENSUS_DB: &str = "consensus";
+const META_DB: &str = "meta";
+const META_DB_FILE_LIMIT: i32 = 5;
+
+/// The node databases, opened read-only so that they can be inspected while the node is running
+pub struct Databases {
+    pub meta: Arc<DB>,
+    pub consensus: Arc<DB>,
+    pub consensus_dir: PathBuf,
+}
+
+impl Databases {
+    /// Opens the meta database and the active consensus database found in `db_dir`
+    pub fn open(db_dir: PathBuf) -> DbToolResult<Self> {
+        let meta_dir = db_dir.join(META_DB);
+        let meta = open_read_only(meta_dir.clone(), META_DB_FILE_LIMIT)?;
+
+        // The management store initializes the metadata when missing, which a read-only database does not allow
+        if meta.get_pinned([DatabaseStorePrefixes::MultiConsensusMetadata as u8])?.is_none() {
+            return Err(DbToolError::NoActiveConsensus(meta_dir));
+        }
+        let dir_name = MultiConsensusManagementStore::new(meta.clone())
+            .active_consensus_dir_name()?
+            .ok_or_else(|| DbToolError::NoActiveConsensus(meta_dir.clone()))?;
+        let consensus_dir = db_dir.join(CONSENSUS_DB).join(dir_name);
+        let consensus = open_read_only(consensus_dir.clone(), (fd_budget::limit() - META_DB_FILE_LIMIT) / 2)?;
+
+        Ok(Self { meta, consensus, consensus_dir })
+    }
+}
+
+fn open_read_only(db_path: PathBuf, files_limit: i32) -> DbToolResult<Arc<DB>> {
+    if !db_path.exists() {
+        return Err(DbToolError::MissingDirectory(db_path));
+    }
+    Ok(ConnBuilder::default()
+        .with_db_path(db_path)
+        .with_create_if_missing(false)
+        .with_read_only(true)
+        .with_files_limit(files_limit)
+        .build()?)
+}
+
+/// Iterates over all the `(key, value)` pairs whose key starts with `prefix`
+pub fn prefix_iterator<'a>(db: &'a DB, prefix: &'a [u8]) -> impl Iterator<Item = DbToolResult<(Box<[u8]>, Box<[u8]>)>> + 'a {
+    db.prefix_iterator(prefix, None).map(|item| item.map_err(DbToolError::from))
+}
+
+/// Iterates over the block hashes keying a store, the hash being the last part of every key
+pub fn block_hashes<'a>(db: &'a DB, prefix: &'a [u8]) -> impl Iterator<Item = DbToolResult<Hash>> + 'a {
+    prefix_iterator(db, prefix)
+        .filter(move |item| item.as_ref().map_or(true, |(key, _)| key.len() >= prefix.len() + HASH_SIZE))
+        .map(|item| item.map(|(key, _)| Hash::from_slice(&key[key.len() - HASH_SIZE..])))
+}
+
+/// Builds the key of `hash` in the store of `prefix`
+pub fn block_key(prefix: &[u8], hash: Hash) -> Vec<u8> {
+    prefix.iter().copied().chain(hash.as_bytes()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calico_database::utils::get_calico_tempdir;
+
+    #[test]
+    fn test_open_errors() {
+        let tempdir = get_calico_tempdir();
+        let db_dir = tempdir.path().to_owned();
+        assert!(matches!(Databases::open(db_dir.clone()), Err(DbToolError::MissingDirectory(_))));
+
+        // A directory which is not a database is reported rather than panicking
+        std::fs::create_dir(db_dir.join(META_DB)).unwrap();
+        assert!(matches!(Databases::open(db_dir), Err(DbToolError::ConnBuilderError(_))));
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{
+    db::block_hashes,
+    error::{DbToolError, DbToolResult},
+};
+use calico_consensus::model::stores::{
+    ghostdag::{DbGhostdagStore, GhostdagStoreReader},
+    headers::{DbHeadersStore, HeaderStoreReader},
+    reachability::{DbReachabilityStore, ReachabilityStore, ReachabilityStoreReader},
+    relations::{DbRelationsStore, RelationsStoreReader},
+    statuses::{DbStatusesStore, StatusesStoreReader},
+    utxo_diffs::{DbUtxoDiffsStore, UtxoDiffsStoreReader},
+};
+use calico_consensus_core::{
+    tx::{TransactionOutpoint, UtxoEntry},
+    utxo::utxo_collection::UtxoCollection,
+};
+use calico_database::{
+    prelude::{CachePolicy, DB},
+    registry::{DatabaseStorePrefixes, SEPARATOR},
+};
+use calico_hashes::Hash;
+use std::{str::FromStr, sync::Arc};
+
+/// Stores whose entries can be dumped in a readable form
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DumpStore {
+    Headers,
+    Ghostdag,
+    Statuses,
+    UtxoDiffs,
+    Reachability,
+    Relations,
+}
+
+impl DumpStore {
+    pub const ALL: [DumpStore; 6] = [
+        DumpStore::Headers,
+        DumpStore::Ghostdag,
+        DumpStore::Statuses,
+        DumpStore::UtxoDiffs,
+        DumpStore::Reachability,
+        DumpStore::Relations,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            DumpStore::Headers => "headers",
+            DumpStore::Ghostdag => "ghostdag",
+            DumpStore::Statuses => "statuses",
+            DumpStore::UtxoDiffs => "utxo-diffs",
+            DumpStore::Reachability => "reachability",
+            DumpStore::Relations => "relations",
+        }
+    }
+
+    /// Prefix of the keys of the store entries, up to the block hash
+    pub fn key_prefix(self) -> Vec<u8> {
+        match self {
+            DumpStore::Headers => DatabaseStorePrefixes::Headers.into(),
+            DumpStore::Ghostdag => vec![DatabaseStorePrefixes::Ghostdag.into(), 0],
+            DumpStore::Statuses => DatabaseStorePrefixes::Statuses.into(),
+            DumpStore::UtxoDiffs => DatabaseStorePrefixes::UtxoDiffs.into(),
+            DumpStore::Reachability => vec![DatabaseStorePrefixes::Reachability.into(), SEPARATOR],
+            DumpStore::Relations => vec![DatabaseStorePrefixes::RelationsParents.into(), 0],
+        }
+    }
+}
+
+impl FromStr for DumpStore {
+    type Err = DbToolError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter().find(|store| store.name() == name).ok_or_else(|| {
+            DbToolError::UnknownStore(name.to_owned(), Self::ALL.iter().map(|store| store.name()).collect::<Vec<_>>().join(", "))
+        })
+    }
+}
+
+/// Decodes the entries of the consensus stores into a readable form
+pub struct Dumper {
+    db: Arc<DB>,
+    headers: DbHeadersStore,
+    ghostdag: DbGhostdagStore,
+    statuses: DbStatusesStore,
+    utxo_diffs: DbUtxoDiffsStore,
+    reachability: DbReachabilityStore,
+    relations: DbRelationsStore,
+}
+
+impl Dumper {
+    pub fn new(db: Arc<DB>) -> Self {
+        Self {
+            headers: DbHeadersStore::new(db.clone(), CachePolicy::Empty, CachePolicy::Empty),
+            ghostdag: DbGhostdagStore::new(db.clone(), 0, CachePolicy::Empty, CachePolicy::Empty),
+            statuses: DbStatusesStore::new(db.clone(), CachePolicy::Empty),
+            utxo_diffs: DbUtxoDiffsStore::new(db.clone(), CachePolicy::Empty),
+            reachability: DbReachabilityStore::new(db.clone(), CachePolicy::Empty, CachePolicy::Empty),
+            relations: DbRelationsStore::new(db.clone(), 0, CachePolicy::Empty, CachePolicy::Empty),
+            db,
+        }
+    }
+
+    /// Dumps the entry of `hash`, or the first `limit` entries of the store if no hash is provided
+    pub fn dump(&self, store: DumpStore, hash: Option<Hash>, limit: usize) -> DbToolResult<()> {
+        let hashes = match hash {
+            Some(hash) => vec![hash],
+            None => block_hashes(&self.db, &store.key_prefix()).take(limit).collect::<DbToolResult<Vec<_>>>()?,
+        };
+        for hash in hashes {
+            println!("{hash}");
+            match store {
+                DumpStore::Headers => self.dump_header(hash)?,
+                DumpStore::Ghostdag => self.dump_ghostdag(hash)?,
+                DumpStore::Statuses => println!("  status: {:?}", self.statuses.get(hash)?),
+                DumpStore::UtxoDiffs => self.dump_utxo_diff(hash)?,
+                DumpStore::Reachability => self.dump_reachability(hash)?,
+                DumpStore::Relations => self.dump_relations(hash)?,
+            }
+            println!();
+        }
+        Ok(())
+    }
+
+    fn dump_header(&self, hash: Hash) -> DbToolResult<()> {
+        let header = self.headers.get_header_with_block_level(hash)?;
+        println!("  block level: {}", header.block_level);
+        println!("  {:#?}", header.header);
+        Ok(())
+    }
+
+    fn dump_ghostdag(&self, hash: Hash) -> DbToolResult<()> {
+        let data = self.ghostdag.get_data(hash)?;
+        println!("  blue score: {}", data.blue_score);
+        println!("  blue work: {}", data.blue_work);
+        println!("  selected parent: {}", data.selected_parent);
+        println!("  mergeset blues: {}", format_hashes(&data.mergeset_blues));
+        println!("  mergeset reds: {}", format_hashes(&data.mergeset_reds));
+        for (blue, anticone_size) in data.blues_anticone_sizes.iter() {
+            println!("  blue anticone size of {blue}: {anticone_size}");
+        }
+        Ok(())
+    }
+
+    fn dump_utxo_diff(&self, hash: Hash) -> DbToolResult<()> {
+        let diff = self.utxo_diffs.get(hash)?;
+        println!("  added ({} entries):", diff.add.len());
+        print_utxo_collection(&diff.add);
+        println!("  removed ({} entries):", diff.remove.len());
+        print_utxo_collection(&diff.remove);
+        Ok(())
+    }
+
+    fn dump_reachability(&self, hash: Hash) -> DbToolResult<()> {
+        println!("  interval: {}", self.reachability.get_interval(hash)?);
+        println!("  height: {}", self.reachability.get_height(hash)?);
+        println!("  tree parent: {}", self.reachability.get_parent(hash)?);
+        println!("  tree children: {}", format_hashes(&self.reachability.get_children(hash)?));
+        println!("  future covering set: {}", format_hashes(&self.reachability.get_future_covering_set(hash)?));
+        Ok(())
+    }
+
+    fn dump_relations(&self, hash: Hash) -> DbToolResult<()> {
+        println!("  parents: {}", format_hashes(&self.relations.get_parents(hash)?));
+        let children = self.relations.get_children(hash)?.read().iter().copied().collect::<Vec<_>>();
+        println!("  children: {}", format_hashes(&children));
+        Ok(())
+    }
+}
+
+fn format_hashes(hashes: &[Hash]) -> String {
+    format!("[{}]", hashes.iter().map(|hash| hash.to_string()).collect::<Vec<_>>().join(", "))
+}
+
+fn print_utxo_collection(collection: &UtxoCollection) {
+    for (outpoint, entry) in collection.iter() {
+        println!("    {}", format_utxo(outpoint, entry));
+    }
+}
+
+pub fn format_utxo(outpoint: &TransactionOutpoint, entry: &UtxoEntry) -> String {
+    format!(
+        "{outpoint}: amount {}, daa score {}, coinbase {}, script v{} {}",
+        entry.amount,
+        entry.block_daa_score,
+        entry.is_coinbase,
+        entry.script_public_key.version(),
+        faster_hex::hex_string(entry.script_public_key.script())
+    )
+}
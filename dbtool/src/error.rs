@@ -0,0 +1,35 @@
+use calico_database::prelude::{ConnBuilderError, StoreError};
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DbToolError {
+    #[error("database directory {0} does not exist")]
+    MissingDirectory(PathBuf),
+
+    #[error("no active consensus is recorded in {0}")]
+    NoActiveConsensus(PathBuf),
+
+    #[error("unknown store '{0}', expected one of: {1}")]
+    UnknownStore(String, String),
+
+    #[error("invalid network '{0}': {1}")]
+    InvalidNetwork(String, String),
+
+    #[error("invalid block hash '{0}'")]
+    InvalidHash(String),
+
+    #[error("error while iterating over the {0} store: {1}")]
+    IteratorError(&'static str, String),
+
+    #[error(transparent)]
+    StoreError(#[from] StoreError),
+
+    #[error(transparent)]
+    RocksDbError(#[from] rocksdb::Error),
+
+    #[error(transparent)]
+    ConnBuilderError(#[from] ConnBuilderError),
+}
+
+pub type DbToolResult<T> = std::result::Result<T, DbToolError>;
@@ -0,0 +1,126 @@
+use calico_consensus_core::network::NetworkId;
+use calico_core::calicod_env::{get_app_dir, version};
+use calico_hashes::Hash;
+use check::{Check, Checker};
+use clap::{Arg, ArgMatches, Command};
+use db::Databases;
+use dump::{DumpStore, Dumper};
+use error::{DbToolError, DbToolResult};
+use std::{path::PathBuf, process::ExitCode, str::FromStr};
+
+mod check;
+mod db;
+mod dump;
+mod error;
+mod stores;
+
+const DEFAULT_DATA_DIR: &str = "datadir";
+const DEFAULT_DUMP_LIMIT: &str = "10";
+
+pub fn cli() -> Command {
+    Command::new("calico-dbtool")
+        .about(format!("{} (calico-dbtool) v{}", env!("CARGO_PKG_DESCRIPTION"), version()))
+        .version(env!("CARGO_PKG_VERSION"))
+        .subcommand_required(true)
+        .arg(Arg::new("appdir").long("appdir").short('b').value_name("DIR").global(true).help("Directory of the node data"))
+        .arg(
+            Arg::new("network")
+                .long("network")
+                .short('n')
+                .value_name("NETWORK")
+                .default_value("mainnet")
+                .global(true)
+                .help("Network of the node data (mainnet, testnet-10, devnet, simnet...)"),
+        )
+        .arg(
+            Arg::new("datadir")
+                .long("datadir")
+                .short('d')
+                .value_name("DIR")
+                .global(true)
+                .help("Database directory, overriding the one derived from the application directory and the network"),
+        )
+        .subcommand(
+            Command::new("stores").about("List the stores of the meta and consensus databases with their key counts and sizes"),
+        )
+        .subcommand(
+            Command::new("dump")
+                .about("Dump store entries decoded into a readable form")
+                .arg(
+                    Arg::new("store")
+                        .required(true)
+                        .value_parser(DumpStore::ALL.map(|store| store.name()))
+                        .help("Store to dump the entries of"),
+                )
+                .arg(Arg::new("hash").help("Hash of the block to dump, the first entries of the store being dumped if omitted"))
+                .arg(
+                    Arg::new("limit")
+                        .long("limit")
+                        .short('l')
+                        .value_name("COUNT")
+                        .default_value(DEFAULT_DUMP_LIMIT)
+                        .value_parser(clap::value_parser!(usize))
+                        .help("Maximum number of entries to dump"),
+                ),
+        )
+        .subcommand(
+            Command::new("check").about("Run consistency checks over the consensus database").arg(
+                Arg::new("checks")
+                    .num_args(0..)
+                    .value_parser(Check::ALL.map(|check| check.name()))
+                    .help("Checks to run, all of them being run if omitted"),
+            ),
+        )
+}
+
+fn db_dir(m: &ArgMatches) -> DbToolResult<PathBuf> {
+    if let Some(datadir) = m.get_one::<String>("datadir") {
+        return Ok(PathBuf::from(datadir));
+    }
+    let app_dir = m.get_one::<String>("appdir").map(PathBuf::from).unwrap_or_else(get_app_dir);
+    let network = m.get_one::<String>("network").unwrap();
+    let network = NetworkId::from_str(network).map_err(|err| DbToolError::InvalidNetwork(network.clone(), err.to_string()))?;
+    Ok(app_dir.join(network.to_prefixed()).join(DEFAULT_DATA_DIR))
+}
+
+fn run(m: ArgMatches) -> DbToolResult<ExitCode> {
+    let databases = Databases::open(db_dir(&m)?)?;
+    match m.subcommand() {
+        Some(("stores", _)) => {
+            stores::print_store_stats("meta", &databases.meta)?;
+            stores::print_store_stats(&databases.consensus_dir.display().to_string(), &databases.consensus)?;
+        }
+        Some(("dump", m)) => {
+            let store = DumpStore::from_str(m.get_one::<String>("store").unwrap())?;
+            let hash = m
+                .get_one::<String>("hash")
+                .map(|hash| Hash::from_str(hash).map_err(|_| DbToolError::InvalidHash(hash.clone())))
+                .transpose()?;
+            Dumper::new(databases.consensus).dump(store, hash, *m.get_one::<usize>("limit").unwrap())?;
+        }
+        Some(("check", m)) => {
+            let checks = match m.get_many::<String>("checks") {
+                Some(names) => Check::ALL.into_iter().filter(|check| names.clone().any(|name| name == check.name())).collect(),
+                None => Check::ALL.to_vec(),
+            };
+            let inconsistencies = Checker::new(databases.consensus).run(&checks)?;
+            if inconsistencies > 0 {
+                println!("{inconsistencies} inconsistencies found");
+                return Ok(ExitCode::FAILURE);
+            }
+            println!("No inconsistencies found");
+        }
+        _ => unreachable!("a subcommand is required"),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> ExitCode {
+    match run(cli().get_matches()) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
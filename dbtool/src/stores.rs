@@ -0,0 +1,49 @@
+use crate::error::DbToolResult;
+use calico_database::{prelude::DB, registry::DatabaseStorePrefixes};
+use num_traits::FromPrimitive;
+use std::collections::BTreeMap;
+
+/// Number of entries and total size of a store
+#[derive(Default)]
+pub struct StoreStats {
+    pub keys: u64,
+    pub key_bytes: u64,
+    pub value_bytes: u64,
+}
+
+/// Scans the whole database, aggregating the entries by the store prefix leading their keys
+pub fn store_stats(db: &DB) -> DbToolResult<BTreeMap<u8, StoreStats>> {
+    let mut stats = BTreeMap::<u8, StoreStats>::new();
+    for item in db.prefix_iterator(b"", None) {
+        let (key, value) = item?;
+        let Some(&prefix) = key.first() else { continue };
+        let entry = stats.entry(prefix).or_default();
+        entry.keys += 1;
+        entry.key_bytes += key.len() as u64;
+        entry.value_bytes += value.len() as u64;
+    }
+    Ok(stats)
+}
+
+pub fn store_name(prefix: u8) -> String {
+    match DatabaseStorePrefixes::from_u8(prefix) {
+        Some(store) => format!("{store:?}"),
+        None => format!("Unknown({prefix})"),
+    }
+}
+
+pub fn print_store_stats(title: &str, db: &DB) -> DbToolResult<()> {
+    let stats = store_stats(db)?;
+    println!("{title}");
+    println!("{:<32} {:>4} {:>14} {:>16}", "store", "id", "keys", "size (bytes)");
+    let mut total = StoreStats::default();
+    for (prefix, store) in stats.iter() {
+        println!("{:<32} {:>4} {:>14} {:>16}", store_name(*prefix), prefix, store.keys, store.key_bytes + store.value_bytes);
+        total.keys += store.keys;
+        total.key_bytes += store.key_bytes;
+        total.value_bytes += store.value_bytes;
+    }
+    println!("{:<32} {:>4} {:>14} {:>16}", "total", "", total.keys, total.key_bytes + total.value_bytes);
+    println!();
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use calico_consensusmanager::spawn_blocking;
+use calico_database::prelude::StoreResult;
+use calico_hashes::Hash;
+use parking_lot::RwLock;
+use std::{fmt::Debug, sync::Arc};
+
+use crate::{
+    errors::ChainIndexResult,
+    model::{IndexedChainBlock, IndexedChainBlocksPage},
+};
+
+/// Chain index API targeted at retrieval calls.
+pub trait ChainIndexApi: Send + Sync + Debug {
+    /// Retrieve the chain block with the highest DAA score not above `daa_score`,
+    /// or `None` if no indexed chain block qualifies.
+    ///
+    /// Note: Use a read lock when accessing this method
+    fn get_chain_block_by_daa_score(&self, daa_score: u64) -> StoreResult<Option<IndexedChainBlock>>;
+
+    /// Retrieve up to `limit` chain blocks with a timestamp in `[start_timestamp, end_timestamp)`, ordered by
+    /// timestamp and then hash. If `after` is set, the page starts right after the block `(start_timestamp, after)`,
+    /// allowing pagination by passing the timestamp and hash of the last block of the previous page.
+    ///
+    /// Note: Use a read lock when accessing this method
+    fn get_chain_blocks_by_time_range(
+        &self,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        after: Option<Hash>,
+        limit: usize,
+    ) -> StoreResult<IndexedChainBlocksPage>;
+
+    /// Retrieve the sink the chain index was last updated to.
+    ///
+    /// Note: Use a read lock when accessing this method
+    fn get_chain_index_sink(&self) -> StoreResult<Hash>;
+
+    /// Checks if the chain index's db is synced with consensus.
+    ///
+    /// Note:
+    /// 1) Use a read lock when accessing this method
+    /// 2) due to potential sync-gaps is_synced is unreliable while consensus is actively resolving virtual states.
+    fn is_synced(&self) -> ChainIndexResult<bool>;
+
+    /// Update the chain index with a virtual selected chain change, removing the reorged chain blocks first.
+    ///
+    /// Note: Use a write lock when accessing this method
+    fn update(&mut self, removed_chain_block_hashes: Arc<Vec<Hash>>, added_chain_block_hashes: Arc<Vec<Hash>>)
+        -> ChainIndexResult<()>;
+
+    /// Bring the chain index in sync with the consensus selected chain, catching up from the
+    /// indexed sink if possible or rebuilding the index from scratch otherwise.
+    ///
+    /// Note: Use a write lock when accessing this method
+    fn resync(&mut self) -> ChainIndexResult<()>;
+}
+
+/// Async proxy for the chain index
+#[derive(Debug, Clone)]
+pub struct ChainIndexProxy {
+    inner: Arc<RwLock<dyn ChainIndexApi>>,
+}
+
+impl ChainIndexProxy {
+    pub fn new(inner: Arc<RwLock<dyn ChainIndexApi>>) -> Self {
+        Self { inner }
+    }
+
+    pub async fn get_chain_block_by_daa_score(self, daa_score: u64) -> StoreResult<Option<IndexedChainBlock>> {
+        spawn_blocking(move || self.inner.read().get_chain_block_by_daa_score(daa_score)).await.unwrap()
+    }
+
+    pub async fn get_chain_blocks_by_time_range(
+        self,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        after: Option<Hash>,
+        limit: usize,
+    ) -> StoreResult<IndexedChainBlocksPage> {
+        spawn_blocking(move || self.inner.read().get_chain_blocks_by_time_range(start_timestamp, end_timestamp, after, limit))
+            .await
+            .unwrap()
+    }
+
+    pub async fn update(
+        self,
+        removed_chain_block_hashes: Arc<Vec<Hash>>,
+        added_chain_block_hashes: Arc<Vec<Hash>>,
+    ) -> ChainIndexResult<()> {
+        spawn_blocking(move || self.inner.write().update(removed_chain_block_hashes, added_chain_block_hashes)).await.unwrap()
+    }
+}
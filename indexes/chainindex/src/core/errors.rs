@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+use crate::IDENT;
+use calico_consensus_core::errors::consensus::ConsensusError;
+use calico_database::prelude::StoreError;
+
+/// Errors originating from the [`ChainIndex`](crate::ChainIndex).
+#[derive(Error, Debug)]
+pub enum ChainIndexError {
+    #[error("[{IDENT}]: {0}")]
+    StoreAccessError(#[from] StoreError),
+
+    #[error("[{IDENT}]: {0}")]
+    ConsensusError(#[from] ConsensusError),
+}
+
+/// Results originating from the [`ChainIndex`](crate::ChainIndex).
+pub type ChainIndexResult<T> = Result<T, ChainIndexError>;
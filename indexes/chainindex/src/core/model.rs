@@ -0,0 +1,22 @@
+use calico_hashes::Hash;
+use calico_utils::mem_size::MemSizeEstimator;
+use serde::{Deserialize, Serialize};
+
+/// A selected chain block as recorded by the chain index
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedChainBlock {
+    pub hash: Hash,
+    pub daa_score: u64,
+    pub timestamp: u64,
+}
+
+impl MemSizeEstimator for IndexedChainBlock {}
+
+/// A page of chain blocks ordered by ascending timestamp
+#[derive(Clone, Debug, Default)]
+pub struct IndexedChainBlocksPage {
+    pub blocks: Vec<IndexedChainBlock>,
+
+    /// Whether more chain blocks fall in the queried range after the last block of this page
+    pub has_more: bool,
+}
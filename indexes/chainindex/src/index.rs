@@ -0,0 +1,344 @@
+use crate::{
+    api::ChainIndexApi,
+    errors::{ChainIndexError, ChainIndexResult},
+    model::{IndexedChainBlock, IndexedChainBlocksPage},
+    stores::store_manager::Store,
+    IDENT,
+};
+use calico_consensus_core::api::ConsensusApi;
+use calico_consensusmanager::{ConsensusManager, ConsensusResetHandler};
+use calico_core::{info, trace};
+use calico_database::prelude::{StoreError, StoreResult, DB};
+use calico_hashes::Hash;
+use parking_lot::RwLock;
+use std::{
+    fmt::Debug,
+    sync::{Arc, Weak},
+};
+
+const RESYNC_CHUNK_SIZE: usize = 2048;
+
+/// ChainIndex indexes the virtual selected chain by DAA score and by timestamp,
+/// keeping itself in sync with the chain changes reported by consensus. Chain blocks
+/// below the consensus source are dropped from the index as consensus prunes them.
+/// Note: The ChainIndex struct by itself is not thread save, only correct usage of the supplied RwLock via `new` makes it so.
+/// please follow guidelines found in the comments under `chainindex::core::api::ChainIndexApi` for proper thread safety.
+pub struct ChainIndex {
+    consensus_manager: Arc<ConsensusManager>,
+    store: Store,
+    /// The consensus source the index was last pruned to
+    pruned_source: Option<Hash>,
+}
+
+impl ChainIndex {
+    /// Creates a new [`ChainIndex`] within a [`RwLock`]
+    pub fn new(consensus_manager: Arc<ConsensusManager>, db: Arc<DB>) -> ChainIndexResult<Arc<RwLock<Self>>> {
+        let mut chainindex = Self { consensus_manager: consensus_manager.clone(), store: Store::new(db), pruned_source: None };
+        if !chainindex.is_synced()? {
+            chainindex.resync()?;
+        }
+        let chainindex = Arc::new(RwLock::new(chainindex));
+        consensus_manager
+            .register_consensus_reset_handler(Arc::new(ChainIndexConsensusResetHandler::new(Arc::downgrade(&chainindex))));
+        Ok(chainindex)
+    }
+
+    /// Fetches the headers of the `added` chain blocks and commits the chain change to the store.
+    fn update_chain(&mut self, consensus: &dyn ConsensusApi, removed: &[Hash], added: &[Hash]) -> ChainIndexResult<()> {
+        let Some(&sink) = added.last() else {
+            return Ok(());
+        };
+        let added = added
+            .iter()
+            .map(|&hash| {
+                let header = consensus.get_header(hash)?;
+                Ok(IndexedChainBlock { hash, daa_score: header.daa_score, timestamp: header.timestamp })
+            })
+            .collect::<ChainIndexResult<Vec<_>>>()?;
+        self.store.update_chain(removed, &added, sink)?;
+        self.prune(consensus)
+    }
+
+    /// Removes the chain blocks below the consensus source, which consensus no longer serves, once the source moved.
+    fn prune(&mut self, consensus: &dyn ConsensusApi) -> ChainIndexResult<()> {
+        let source = consensus.get_source();
+        if self.pruned_source == Some(source) {
+            return Ok(());
+        }
+        let pruned = self.store.prune_below(consensus.get_header(source)?.daa_score)?;
+        trace!("[{0}] pruned {1} chain blocks below the consensus source {2}", IDENT, pruned, source);
+        self.pruned_source = Some(source);
+        Ok(())
+    }
+
+    /// Walks the selected chain from `low` to the consensus sink in chunks, applying the chain changes.
+    fn sync_from(&mut self, consensus: &dyn ConsensusApi, mut low: Hash) -> ChainIndexResult<()> {
+        loop {
+            let chain_path = consensus.get_virtual_chain_from_block(low, Some(RESYNC_CHUNK_SIZE))?;
+            trace!("[{0}] syncing with a batch of {1} chain blocks from consensus db", IDENT, chain_path.added.len());
+            self.update_chain(consensus, &chain_path.removed, &chain_path.added)?;
+            match chain_path.added.last() {
+                Some(&last) if chain_path.added.len() == RESYNC_CHUNK_SIZE => low = last,
+                _ => return Ok(()),
+            }
+        }
+    }
+}
+
+impl ChainIndexApi for ChainIndex {
+    fn get_chain_block_by_daa_score(&self, daa_score: u64) -> StoreResult<Option<IndexedChainBlock>> {
+        trace!("[{0}] retrieving the chain block at DAA score {1}", IDENT, daa_score);
+
+        self.store.get_chain_block_by_daa_score(daa_score)
+    }
+
+    fn get_chain_blocks_by_time_range(
+        &self,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        after: Option<Hash>,
+        limit: usize,
+    ) -> StoreResult<IndexedChainBlocksPage> {
+        trace!("[{0}] retrieving up to {1} chain blocks between timestamps {2} and {3}", IDENT, limit, start_timestamp, end_timestamp);
+
+        // Fetch one extra block to learn whether another page follows
+        let mut blocks = self.store.get_chain_blocks_by_time_range(start_timestamp, end_timestamp, after, limit.saturating_add(1))?;
+        let has_more = blocks.len() > limit;
+        blocks.truncate(limit);
+        Ok(IndexedChainBlocksPage { blocks, has_more })
+    }
+
+    fn get_chain_index_sink(&self) -> StoreResult<Hash> {
+        trace!("[{0}] retrieving sink", IDENT);
+
+        self.store.get_sink()
+    }
+
+    /// Checks to see if the [ChainIndex] is sync'd. This is done via comparing the indexed sink with the consensus sink.
+    ///
+    /// **Note:** Due to sync gaps between the chain index and consensus, this function is only reliable while consensus is not processing new blocks.
+    fn is_synced(&self) -> ChainIndexResult<bool> {
+        trace!("[{0}] checking sync status...", IDENT);
+
+        let consensus = self.consensus_manager.consensus();
+        let session = futures::executor::block_on(consensus.session_blocking());
+
+        match self.store.get_sink() {
+            Ok(sink) => {
+                let res = sink == session.get_sink();
+                trace!("[{0}] sync status is {1}", IDENT, res);
+                Ok(res)
+            }
+            Err(StoreError::KeyNotFound(_)) => {
+                // Means the chain index database is empty i.e. not sync'd.
+                trace!("[{0}] sync status is {1}", IDENT, false);
+                Ok(false)
+            }
+            Err(err) => Err(ChainIndexError::StoreAccessError(err)),
+        }
+    }
+
+    /// Applies the chain change reported by a `VirtualChainChanged` notification.
+    fn update(
+        &mut self,
+        removed_chain_block_hashes: Arc<Vec<Hash>>,
+        added_chain_block_hashes: Arc<Vec<Hash>>,
+    ) -> ChainIndexResult<()> {
+        trace!(
+            "[{0}] updating with {1} removed and {2} added chain blocks",
+            IDENT,
+            removed_chain_block_hashes.len(),
+            added_chain_block_hashes.len()
+        );
+
+        let consensus = self.consensus_manager.consensus();
+        let session = futures::executor::block_on(consensus.session_blocking());
+        self.update_chain(&*session, &removed_chain_block_hashes, &added_chain_block_hashes)
+    }
+
+    /// Catches up from the indexed sink when consensus still has it on a chain rooted at the pruning point,
+    /// otherwise deletes the chain index database and rebuilds it from the consensus source.
+    ///
+    /// **Note:** resyncing while consensus notifies of chain changes may result in a corrupted db.
+    fn resync(&mut self) -> ChainIndexResult<()> {
+        let consensus = self.consensus_manager.consensus();
+        let session = futures::executor::block_on(consensus.session_blocking());
+
+        if let Ok(sink) = self.store.get_sink() {
+            info!("Catching up the chain index...");
+            match self.sync_from(&*session, sink) {
+                Ok(()) => return Ok(()),
+                Err(ChainIndexError::ConsensusError(err)) => trace!("[{0}] catching up failed: {1}", IDENT, err),
+                Err(err) => return Err(err),
+            }
+        }
+
+        info!("Resyncing the chain index...");
+        self.store.delete_all()?;
+        let source = session.get_source();
+        self.update_chain(&*session, &[], &[source])?;
+        self.sync_from(&*session, source)
+    }
+}
+
+impl Debug for ChainIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChainIndex").finish()
+    }
+}
+
+struct ChainIndexConsensusResetHandler {
+    chainindex: Weak<RwLock<ChainIndex>>,
+}
+
+impl ChainIndexConsensusResetHandler {
+    fn new(chainindex: Weak<RwLock<ChainIndex>>) -> Self {
+        Self { chainindex }
+    }
+}
+
+impl ConsensusResetHandler for ChainIndexConsensusResetHandler {
+    fn handle_consensus_reset(&self) {
+        if let Some(chainindex) = self.chainindex.upgrade() {
+            chainindex.write().resync().unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{api::ChainIndexApi, ChainIndex};
+    use calico_consensus::{
+        config::ConfigBuilder,
+        consensus::test_consensus::TestConsensus,
+        params::{DEVNET_PARAMS, MAINNET_PARAMS},
+    };
+    use calico_consensus_core::{api::ConsensusApi, BlockHashSet};
+    use calico_consensusmanager::ConsensusManager;
+    use calico_database::create_temp_db;
+    use calico_database::prelude::ConnBuilder;
+    use calico_hashes::Hash;
+    use futures::executor::block_on;
+    use std::{sync::Arc, time::Duration};
+
+    #[test]
+    fn test_chainindex() {
+        calico_core::log::try_init_logger("INFO");
+
+        let (_chainindex_db_lifetime, chainindex_db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let config = ConfigBuilder::new(DEVNET_PARAMS).skip_proof_of_work().build();
+        let tc = Arc::new(TestConsensus::new(&config));
+        let wait_handles = tc.init();
+
+        // Selected chain: genesis <- 1 <- 2 <- 3
+        block_on(tc.add_utxo_valid_block_with_parents(1.into(), vec![config.genesis.hash], vec![])).unwrap();
+        block_on(tc.add_utxo_valid_block_with_parents(2.into(), vec![1.into()], vec![])).unwrap();
+        block_on(tc.add_utxo_valid_block_with_parents(3.into(), vec![2.into()], vec![])).unwrap();
+
+        let consensus_manager = Arc::new(ConsensusManager::from_consensus(tc.consensus_clone()));
+        let chainindex = ChainIndex::new(consensus_manager, chainindex_db).unwrap();
+        assert!(chainindex.read().is_synced().unwrap());
+        assert_eq!(chainindex.read().get_chain_index_sink().unwrap(), Hash::from(3));
+
+        let chain = [config.genesis.hash, 1.into(), 2.into(), 3.into()];
+        for hash in chain {
+            let daa_score = tc.get_header(hash).unwrap().daa_score;
+            assert_eq!(chainindex.read().get_chain_block_by_daa_score(daa_score).unwrap().unwrap().hash, hash);
+        }
+        assert_eq!(chainindex.read().get_chain_block_by_daa_score(u64::MAX).unwrap().unwrap().hash, Hash::from(3));
+
+        // Page through the whole chain, two blocks at a time
+        let collect_chain = || {
+            let mut indexed = Vec::new();
+            let (mut start_timestamp, mut after) = (0, None);
+            loop {
+                let page = chainindex.read().get_chain_blocks_by_time_range(start_timestamp, u64::MAX, after, 2).unwrap();
+                assert!(page.blocks.len() <= 2);
+                assert!(page.blocks.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+                if let Some(last) = page.blocks.last() {
+                    (start_timestamp, after) = (last.timestamp, Some(last.hash));
+                }
+                indexed.extend(page.blocks.iter().map(|block| block.hash));
+                if !page.has_more {
+                    return indexed;
+                }
+            }
+        };
+        let indexed = collect_chain();
+        assert_eq!(indexed.len(), chain.len());
+        assert_eq!(indexed.into_iter().collect::<BlockHashSet>(), chain.into_iter().collect::<BlockHashSet>());
+
+        // Reorg to the heavier chain genesis <- 4 <- 5 <- 6 <- 7
+        block_on(tc.add_utxo_valid_block_with_parents(4.into(), vec![config.genesis.hash], vec![])).unwrap();
+        block_on(tc.add_utxo_valid_block_with_parents(5.into(), vec![4.into()], vec![])).unwrap();
+        block_on(tc.add_utxo_valid_block_with_parents(6.into(), vec![5.into()], vec![])).unwrap();
+        block_on(tc.add_utxo_valid_block_with_parents(7.into(), vec![6.into()], vec![])).unwrap();
+        assert!(!chainindex.read().is_synced().unwrap());
+
+        let chain_path = tc.get_virtual_chain_from_block(3.into(), None).unwrap();
+        assert_eq!(chain_path.removed, vec![Hash::from(3), Hash::from(2), Hash::from(1)]);
+        chainindex.write().update(Arc::new(chain_path.removed), Arc::new(chain_path.added)).unwrap();
+        assert!(chainindex.read().is_synced().unwrap());
+
+        let chain = [config.genesis.hash, 4.into(), 5.into(), 6.into(), 7.into()];
+        let indexed = collect_chain();
+        assert_eq!(indexed.len(), chain.len());
+        assert_eq!(indexed.into_iter().collect::<BlockHashSet>(), chain.into_iter().collect::<BlockHashSet>());
+        let daa_score = tc.get_header(2.into()).unwrap().daa_score;
+        assert_ne!(chainindex.read().get_chain_block_by_daa_score(daa_score).unwrap().unwrap().hash, Hash::from(2));
+
+        tc.shutdown(wait_handles);
+    }
+
+    #[test]
+    fn test_chainindex_pruning() {
+        calico_core::log::try_init_logger("INFO");
+
+        let (_chainindex_db_lifetime, chainindex_db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let config = ConfigBuilder::new(MAINNET_PARAMS)
+            .skip_proof_of_work()
+            .edit_consensus_params(|p| {
+                p.finality_depth = 2;
+                p.mergeset_size_limit = 2;
+                p.ghostdag_k = 2;
+                p.merge_depth = 3;
+                p.pruning_depth = 100;
+            })
+            .build();
+        let tc = Arc::new(TestConsensus::new(&config));
+        let wait_handles = tc.init();
+
+        let consensus_manager = Arc::new(ConsensusManager::from_consensus(tc.consensus_clone()));
+        let chainindex = ChainIndex::new(consensus_manager, chainindex_db).unwrap();
+        let genesis_daa_score = tc.get_header(config.genesis.hash).unwrap().daa_score;
+
+        // Mine a chain long enough for the pruning point to advance, applying the chain changes as they come
+        let mut sink = config.genesis.hash;
+        let mut add_block = |i: u64| {
+            let hash: Hash = i.into();
+            block_on(tc.add_empty_utxo_valid_block_with_parents(hash, vec![sink])).unwrap();
+            chainindex.write().update(Arc::new(vec![]), Arc::new(vec![hash])).unwrap();
+            sink = hash;
+        };
+        let blocks = config.pruning_depth + config.finality_depth + 100;
+        (1..blocks).for_each(&mut add_block);
+        // The pruning point advances in the background, so the index is pruned by the first update which follows
+        while tc.get_source() == config.genesis.hash {
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        add_block(blocks);
+        let source = chainindex.read().pruned_source.unwrap();
+        assert_ne!(source, config.genesis.hash);
+
+        // Chain blocks below the source are no longer indexed
+        let source_daa_score = tc.get_header(source).unwrap().daa_score;
+        assert!(chainindex.read().get_chain_block_by_daa_score(genesis_daa_score).unwrap().is_none());
+        assert!(chainindex.read().get_chain_block_by_daa_score(source_daa_score - 1).unwrap().is_none());
+        assert_eq!(chainindex.read().get_chain_block_by_daa_score(source_daa_score).unwrap().unwrap().hash, source);
+        let page = chainindex.read().get_chain_blocks_by_time_range(0, u64::MAX, None, usize::MAX).unwrap();
+        assert!(page.blocks.iter().all(|block| block.daa_score >= source_daa_score));
+        assert_eq!(page.blocks.len() as u64, tc.get_header(sink).unwrap().daa_score - source_daa_score + 1);
+
+        tc.shutdown(wait_handles);
+    }
+}
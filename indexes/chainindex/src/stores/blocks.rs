@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use calico_database::{
+    prelude::{CachePolicy, CachedDbAccess, DbWriter, StoreError, StoreResult, DB},
+    registry::DatabaseStorePrefixes,
+};
+use calico_hashes::Hash;
+
+use crate::model::IndexedChainBlock;
+
+/// Reader API for `ChainIndexBlocksStore`.
+pub trait ChainIndexBlocksStoreReader {
+    fn get(&self, hash: Hash) -> StoreResult<Option<IndexedChainBlock>>;
+}
+
+/// Keeps the indexed entry of every chain block so that reorged blocks can be located in the other stores.
+pub trait ChainIndexBlocksStore: ChainIndexBlocksStoreReader {
+    fn insert(&mut self, writer: impl DbWriter, block: IndexedChainBlock) -> StoreResult<()>;
+    fn delete(&mut self, writer: impl DbWriter, hash: Hash) -> StoreResult<()>;
+    fn delete_all(&mut self, writer: impl DbWriter) -> StoreResult<()>;
+}
+
+/// A DB + cache implementation of `ChainIndexBlocksStore` trait
+#[derive(Clone)]
+pub struct DbChainIndexBlocksStore {
+    access: CachedDbAccess<Hash, IndexedChainBlock>,
+}
+
+impl DbChainIndexBlocksStore {
+    pub fn new(db: Arc<DB>, cache_policy: CachePolicy) -> Self {
+        Self { access: CachedDbAccess::new(db, cache_policy, DatabaseStorePrefixes::ChainIndexBlocks.into()) }
+    }
+}
+
+impl ChainIndexBlocksStoreReader for DbChainIndexBlocksStore {
+    fn get(&self, hash: Hash) -> StoreResult<Option<IndexedChainBlock>> {
+        match self.access.read(hash) {
+            Ok(block) => Ok(Some(block)),
+            Err(StoreError::KeyNotFound(_)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl ChainIndexBlocksStore for DbChainIndexBlocksStore {
+    fn insert(&mut self, writer: impl DbWriter, block: IndexedChainBlock) -> StoreResult<()> {
+        self.access.write(writer, block.hash, block)
+    }
+
+    fn delete(&mut self, writer: impl DbWriter, hash: Hash) -> StoreResult<()> {
+        self.access.delete(writer, hash)
+    }
+
+    fn delete_all(&mut self, writer: impl DbWriter) -> StoreResult<()> {
+        self.access.delete_all(writer)
+    }
+}
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use calico_database::{
+    prelude::{CachePolicy, CachedDbAccess, DbWriter, StoreError, StoreResult, DB},
+    registry::DatabaseStorePrefixes,
+};
+use calico_hashes::Hash;
+
+/// Size of the [DaaScoreKey] in bytes.
+pub const DAA_SCORE_KEY_SIZE: usize = size_of::<u64>();
+
+/// DAA score key of a chain block.
+/// Consists of the big endian bytes of `u64::MAX - daa_score`, so that a forward seek lands
+/// on the chain block with the highest DAA score not above the sought one.
+#[derive(Eq, Hash, PartialEq, Debug, Copy, Clone)]
+struct DaaScoreKey([u8; DAA_SCORE_KEY_SIZE]);
+
+impl From<u64> for DaaScoreKey {
+    fn from(daa_score: u64) -> Self {
+        Self((u64::MAX - daa_score).to_be_bytes())
+    }
+}
+
+impl AsRef<[u8]> for DaaScoreKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Reader API for `ChainIndexByDaaScoreStore`.
+pub trait ChainIndexByDaaScoreStoreReader {
+    /// Get the hash of the chain block with the highest DAA score not above `daa_score`
+    fn get_at_or_below(&self, daa_score: u64) -> StoreResult<Option<Hash>>;
+
+    /// Get the hashes of all chain blocks with a DAA score below `daa_score`
+    fn get_all_below(&self, daa_score: u64) -> StoreResult<Vec<Hash>>;
+}
+
+/// Maps the DAA score of every chain block to its hash. DAA scores strictly increase along
+/// a selected chain so a score identifies at most one chain block.
+pub trait ChainIndexByDaaScoreStore: ChainIndexByDaaScoreStoreReader {
+    fn insert(&mut self, writer: impl DbWriter, daa_score: u64, hash: Hash) -> StoreResult<()>;
+    fn delete(&mut self, writer: impl DbWriter, daa_score: u64) -> StoreResult<()>;
+    fn delete_all(&mut self, writer: impl DbWriter) -> StoreResult<()>;
+}
+
+/// A DB + cache implementation of `ChainIndexByDaaScoreStore` trait
+#[derive(Clone)]
+pub struct DbChainIndexByDaaScoreStore {
+    access: CachedDbAccess<DaaScoreKey, Hash>,
+}
+
+impl DbChainIndexByDaaScoreStore {
+    pub fn new(db: Arc<DB>) -> Self {
+        Self { access: CachedDbAccess::new(db, CachePolicy::Empty, DatabaseStorePrefixes::ChainIndexByDaaScore.into()) }
+    }
+}
+
+impl ChainIndexByDaaScoreStoreReader for DbChainIndexByDaaScoreStore {
+    fn get_at_or_below(&self, daa_score: u64) -> StoreResult<Option<Hash>> {
+        match self.access.seek_iterator(None, Some(DaaScoreKey::from(daa_score)), 1, false).next() {
+            Some(Ok((_, hash))) => Ok(Some(hash)),
+            Some(Err(err)) => Err(StoreError::DataInconsistency(err.to_string())),
+            None => Ok(None),
+        }
+    }
+
+    fn get_all_below(&self, daa_score: u64) -> StoreResult<Vec<Hash>> {
+        let Some(highest) = daa_score.checked_sub(1) else {
+            return Ok(vec![]);
+        };
+        self.access
+            .seek_iterator(None, Some(DaaScoreKey::from(highest)), usize::MAX, false)
+            .map(|res| res.map(|(_, hash)| hash).map_err(|err| StoreError::DataInconsistency(err.to_string())))
+            .collect()
+    }
+}
+
+impl ChainIndexByDaaScoreStore for DbChainIndexByDaaScoreStore {
+    fn insert(&mut self, writer: impl DbWriter, daa_score: u64, hash: Hash) -> StoreResult<()> {
+        self.access.write(writer, DaaScoreKey::from(daa_score), hash)
+    }
+
+    fn delete(&mut self, writer: impl DbWriter, daa_score: u64) -> StoreResult<()> {
+        self.access.delete(writer, DaaScoreKey::from(daa_score))
+    }
+
+    fn delete_all(&mut self, writer: impl DbWriter) -> StoreResult<()> {
+        self.access.delete_all(writer)
+    }
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use calico_database::{
+    prelude::{CachePolicy, CachedDbAccess, DbWriter, StoreError, StoreResult, DB},
+    registry::DatabaseStorePrefixes,
+};
+use calico_hashes::{Hash, HASH_SIZE};
+
+use crate::model::IndexedChainBlock;
+
+/// Size of the [TimestampKey] in bytes.
+pub const TIMESTAMP_KEY_SIZE: usize = size_of::<u64>() + HASH_SIZE;
+
+/// Timestamp key of a chain block.
+/// Consists of 8 bytes of big endian timestamp, followed by 32 bytes of block hash, so that
+/// chain blocks sharing a timestamp are kept apart and iterate in a stable order.
+#[derive(Eq, Hash, PartialEq, Debug, Copy, Clone)]
+struct TimestampKey([u8; TIMESTAMP_KEY_SIZE]);
+
+impl TimestampKey {
+    fn new(timestamp: u64, hash: Hash) -> Self {
+        let mut bytes = [0; TIMESTAMP_KEY_SIZE];
+        bytes[..size_of::<u64>()].copy_from_slice(&timestamp.to_be_bytes());
+        bytes[size_of::<u64>()..].copy_from_slice(&hash.as_bytes());
+        Self(bytes)
+    }
+
+    fn timestamp(&self) -> u64 {
+        u64::from_be_bytes(self.0[..size_of::<u64>()].try_into().unwrap())
+    }
+
+    fn hash(&self) -> Hash {
+        Hash::from_slice(&self.0[size_of::<u64>()..])
+    }
+}
+
+impl AsRef<[u8]> for TimestampKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Reader API for `ChainIndexByTimestampStore`.
+pub trait ChainIndexByTimestampStoreReader {
+    /// Get up to `limit` chain blocks ordered by timestamp, starting at `start_timestamp`, or right after the
+    /// key `(start_timestamp, after)` if `after` is set, and stopping before `end_timestamp`.
+    fn get_range(
+        &self,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        after: Option<Hash>,
+        limit: usize,
+    ) -> StoreResult<Vec<IndexedChainBlock>>;
+}
+
+/// Maps the timestamp of every chain block to its DAA score. Timestamps are not monotonic along
+/// the selected chain, so the same timestamp may be shared by several chain blocks.
+pub trait ChainIndexByTimestampStore: ChainIndexByTimestampStoreReader {
+    fn insert(&mut self, writer: impl DbWriter, block: IndexedChainBlock) -> StoreResult<()>;
+    fn delete(&mut self, writer: impl DbWriter, timestamp: u64, hash: Hash) -> StoreResult<()>;
+    fn delete_all(&mut self, writer: impl DbWriter) -> StoreResult<()>;
+}
+
+/// A DB + cache implementation of `ChainIndexByTimestampStore` trait
+#[derive(Clone)]
+pub struct DbChainIndexByTimestampStore {
+    access: CachedDbAccess<TimestampKey, u64>,
+}
+
+impl DbChainIndexByTimestampStore {
+    pub fn new(db: Arc<DB>) -> Self {
+        Self { access: CachedDbAccess::new(db, CachePolicy::Empty, DatabaseStorePrefixes::ChainIndexByTimestamp.into()) }
+    }
+}
+
+impl ChainIndexByTimestampStoreReader for DbChainIndexByTimestampStore {
+    fn get_range(
+        &self,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        after: Option<Hash>,
+        limit: usize,
+    ) -> StoreResult<Vec<IndexedChainBlock>> {
+        let seek_key = TimestampKey::new(start_timestamp, after.unwrap_or_default());
+        self.access
+            .seek_iterator(None, Some(seek_key), usize::MAX, false)
+            .map(|res| {
+                let (key, daa_score) = res.map_err(|err| StoreError::DataInconsistency(err.to_string()))?;
+                let key = TimestampKey(<[u8; TIMESTAMP_KEY_SIZE]>::try_from(&key[..]).unwrap());
+                Ok((key, daa_score))
+            })
+            // The cursor block itself was returned by the previous page. Note that it might have been
+            // reorged out since, in which case the seek already lands on the following key.
+            .skip_while(|res| after.is_some() && matches!(res, Ok((key, _)) if *key == seek_key))
+            .take_while(|res| !matches!(res, Ok((key, _)) if key.timestamp() >= end_timestamp))
+            .take(limit)
+            .map(|res| res.map(|(key, daa_score)| IndexedChainBlock { hash: key.hash(), daa_score, timestamp: key.timestamp() }))
+            .collect()
+    }
+}
+
+impl ChainIndexByTimestampStore for DbChainIndexByTimestampStore {
+    fn insert(&mut self, writer: impl DbWriter, block: IndexedChainBlock) -> StoreResult<()> {
+        self.access.write(writer, TimestampKey::new(block.timestamp, block.hash), block.daa_score)
+    }
+
+    fn delete(&mut self, writer: impl DbWriter, timestamp: u64, hash: Hash) -> StoreResult<()> {
+        self.access.delete(writer, TimestampKey::new(timestamp, hash))
+    }
+
+    fn delete_all(&mut self, writer: impl DbWriter) -> StoreResult<()> {
+        self.access.delete_all(writer)
+    }
+}
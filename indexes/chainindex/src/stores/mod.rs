@@ -0,0 +1,5 @@
+mod blocks;
+mod by_daa_score;
+mod by_timestamp;
+mod sink;
+pub mod store_manager;
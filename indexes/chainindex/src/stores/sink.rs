@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use calico_database::{
+    prelude::{CachedDbItem, DbWriter, StoreResult, DB},
+    registry::DatabaseStorePrefixes,
+};
+use calico_hashes::Hash;
+
+/// Reader API for `ChainIndexSinkStore`.
+pub trait ChainIndexSinkStoreReader {
+    fn get(&self) -> StoreResult<Hash>;
+}
+
+pub trait ChainIndexSinkStore: ChainIndexSinkStoreReader {
+    fn set(&mut self, writer: impl DbWriter, sink: Hash) -> StoreResult<()>;
+    fn remove(&mut self, writer: impl DbWriter) -> StoreResult<()>;
+}
+
+/// A DB + cache implementation of `ChainIndexSinkStore` trait
+#[derive(Clone)]
+pub struct DbChainIndexSinkStore {
+    access: CachedDbItem<Hash>,
+}
+
+impl DbChainIndexSinkStore {
+    pub fn new(db: Arc<DB>) -> Self {
+        Self { access: CachedDbItem::new(db, DatabaseStorePrefixes::ChainIndexSink.into()) }
+    }
+}
+
+impl ChainIndexSinkStoreReader for DbChainIndexSinkStore {
+    fn get(&self) -> StoreResult<Hash> {
+        self.access.read()
+    }
+}
+
+impl ChainIndexSinkStore for DbChainIndexSinkStore {
+    fn set(&mut self, writer: impl DbWriter, sink: Hash) -> StoreResult<()> {
+        self.access.write(writer, &sink)
+    }
+
+    fn remove(&mut self, writer: impl DbWriter) -> StoreResult<()> {
+        self.access.remove(writer)
+    }
+}
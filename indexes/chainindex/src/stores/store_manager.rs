@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use calico_core::trace;
+use calico_database::prelude::{BatchDbWriter, CachePolicy, DirectDbWriter, StoreError, StoreResult, WriteBatch, DB};
+use calico_hashes::Hash;
+
+use crate::{
+    model::IndexedChainBlock,
+    stores::{
+        blocks::{ChainIndexBlocksStore, ChainIndexBlocksStoreReader, DbChainIndexBlocksStore},
+        by_daa_score::{ChainIndexByDaaScoreStore, ChainIndexByDaaScoreStoreReader, DbChainIndexByDaaScoreStore},
+        by_timestamp::{ChainIndexByTimestampStore, ChainIndexByTimestampStoreReader, DbChainIndexByTimestampStore},
+        sink::{ChainIndexSinkStore, ChainIndexSinkStoreReader, DbChainIndexSinkStore},
+    },
+    IDENT,
+};
+
+const BLOCKS_CACHE_SIZE: usize = 10_000;
+
+#[derive(Clone)]
+pub struct Store {
+    db: Arc<DB>,
+    blocks_store: DbChainIndexBlocksStore,
+    by_daa_score_store: DbChainIndexByDaaScoreStore,
+    by_timestamp_store: DbChainIndexByTimestampStore,
+    sink_store: DbChainIndexSinkStore,
+}
+
+impl Store {
+    pub fn new(db: Arc<DB>) -> Self {
+        Self {
+            db: db.clone(),
+            blocks_store: DbChainIndexBlocksStore::new(db.clone(), CachePolicy::Count(BLOCKS_CACHE_SIZE)),
+            by_daa_score_store: DbChainIndexByDaaScoreStore::new(db.clone()),
+            by_timestamp_store: DbChainIndexByTimestampStore::new(db.clone()),
+            sink_store: DbChainIndexSinkStore::new(db),
+        }
+    }
+
+    pub fn get_chain_block_by_daa_score(&self, daa_score: u64) -> StoreResult<Option<IndexedChainBlock>> {
+        match self.by_daa_score_store.get_at_or_below(daa_score)? {
+            Some(hash) => self.blocks_store.get(hash),
+            None => Ok(None),
+        }
+    }
+
+    pub fn get_chain_blocks_by_time_range(
+        &self,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        after: Option<Hash>,
+        limit: usize,
+    ) -> StoreResult<Vec<IndexedChainBlock>> {
+        self.by_timestamp_store.get_range(start_timestamp, end_timestamp, after, limit)
+    }
+
+    pub fn get_sink(&self) -> StoreResult<Hash> {
+        self.sink_store.get()
+    }
+
+    /// Atomically removes the `removed` chain blocks, inserts the `added` ones and sets the new sink.
+    pub fn update_chain(&mut self, removed: &[Hash], added: &[IndexedChainBlock], sink: Hash) -> StoreResult<()> {
+        let mut batch = WriteBatch::default();
+        let mut writer = BatchDbWriter::new(&mut batch);
+
+        for &hash in removed.iter() {
+            // Blocks which were never indexed (e.g. below the point the index was built from) are skipped
+            if let Some(block) = self.blocks_store.get(hash)? {
+                self.by_daa_score_store.delete(&mut writer, block.daa_score)?;
+                self.by_timestamp_store.delete(&mut writer, block.timestamp, block.hash)?;
+                self.blocks_store.delete(&mut writer, block.hash)?;
+            }
+        }
+        for &block in added.iter() {
+            self.blocks_store.insert(&mut writer, block)?;
+            self.by_daa_score_store.insert(&mut writer, block.daa_score, block.hash)?;
+            self.by_timestamp_store.insert(&mut writer, block)?;
+        }
+        self.sink_store.set(&mut writer, sink)?;
+
+        self.db.write(batch)?;
+        Ok(())
+    }
+
+    /// Atomically removes the chain blocks with a DAA score below `daa_score`
+    pub fn prune_below(&mut self, daa_score: u64) -> StoreResult<usize> {
+        let mut batch = WriteBatch::default();
+        let mut writer = BatchDbWriter::new(&mut batch);
+
+        let hashes = self.by_daa_score_store.get_all_below(daa_score)?;
+        for &hash in hashes.iter() {
+            let block = self
+                .blocks_store
+                .get(hash)?
+                .ok_or_else(|| StoreError::DataInconsistency(format!("chain block {hash} has no indexed entry")))?;
+            self.by_daa_score_store.delete(&mut writer, block.daa_score)?;
+            self.by_timestamp_store.delete(&mut writer, block.timestamp, block.hash)?;
+            self.blocks_store.delete(&mut writer, block.hash)?;
+        }
+
+        self.db.write(batch)?;
+        Ok(hashes.len())
+    }
+
+    /// Resets the chain index database
+    pub fn delete_all(&mut self) -> StoreResult<()> {
+        trace!("[{0}] attempting to clear chain index database...", IDENT);
+
+        let mut writer = DirectDbWriter::new(&self.db);
+        self.sink_store.remove(&mut writer)?;
+        self.by_timestamp_store.delete_all(&mut writer)?;
+        self.by_daa_score_store.delete_all(&mut writer)?;
+        self.blocks_store.delete_all(&mut writer)?;
+
+        trace!("[{0}] clearing chain index database - success!", IDENT);
+
+        Ok(())
+    }
+}
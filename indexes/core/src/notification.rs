@@ -1,11 +1,13 @@
-use crate::indexed_utxos::{UtxoChanges, UtxoSetByScriptPublicKey};
+use crate::indexed_utxos::{CompactUtxoCollection, UtxoChanges, UtxoSetByScriptPublicKey};
+use calico_consensus_core::tx::ScriptPublicKey;
 use calico_notify::{
     events::EventType,
     full_featured,
     notification::Notification as NotificationTrait,
     subscription::{
         context::SubscriptionContext,
-        single::{OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription},
+        filter::UtxoFilter,
+        single::{OverallSubscription, UtxosChangedState, UtxosChangedSubscription, VirtualChainChangedSubscription},
         Subscription,
     },
 };
@@ -105,11 +107,19 @@ impl UtxosChangedNotification {
         // and check existence over the larger set (O(1))
         let mut result = HashMap::default();
         let subscription_data = subscription.data();
-        if utxo_set.len() < subscription_data.len() {
+        let filter = subscription_data.filter();
+        let mut insert = |script_public_key: &ScriptPublicKey, collection: &CompactUtxoCollection| {
+            if let Some(collection) = Self::filter_collection(script_public_key, collection, filter) {
+                result.insert(script_public_key.clone(), collection);
+            }
+        };
+        if subscription_data.state() == UtxosChangedState::All {
+            utxo_set.iter().for_each(|(script_public_key, collection)| insert(script_public_key, collection));
+        } else if utxo_set.len() < subscription_data.len() {
             {
                 utxo_set.iter().for_each(|(script_public_key, collection)| {
                     if subscription_data.contains(script_public_key, context) {
-                        result.insert(script_public_key.clone(), collection.clone());
+                        insert(script_public_key, collection);
                     }
                 });
             }
@@ -118,11 +128,32 @@ impl UtxosChangedNotification {
             subscription_data.iter().for_each(|index| {
                 if let Some(script_public_key) = tracker_data.get_index(*index) {
                     if let Some(collection) = utxo_set.get(script_public_key) {
-                        result.insert(script_public_key.clone(), collection.clone());
+                        insert(script_public_key, collection);
                     }
                 }
             });
         }
         result
     }
+
+    /// Applies the subscription filter to the UTXOs locked by `script_public_key`, returning
+    /// `None` if no UTXO passes it. The script conditions are evaluated once per collection.
+    fn filter_collection(
+        script_public_key: &ScriptPublicKey,
+        collection: &CompactUtxoCollection,
+        filter: &UtxoFilter,
+    ) -> Option<CompactUtxoCollection> {
+        if !filter.matches_script(script_public_key) {
+            return None;
+        }
+        if !filter.has_amount_range() {
+            return Some(collection.clone());
+        }
+        let collection: CompactUtxoCollection = collection
+            .iter()
+            .filter(|(_, entry)| filter.matches_amount(entry.amount))
+            .map(|(outpoint, entry)| (*outpoint, *entry))
+            .collect();
+        (!collection.is_empty()).then_some(collection)
+    }
 }
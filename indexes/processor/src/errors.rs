@@ -1,3 +1,4 @@
+use calico_chainindex::errors::ChainIndexError;
 use calico_notify::events::EventType;
 use calico_utxoindex::errors::UtxoIndexError;
 use thiserror::Error;
@@ -7,6 +8,9 @@ pub enum IndexError {
     #[error("{0}")]
     UtxoIndexError(#[from] UtxoIndexError),
 
+    #[error("{0}")]
+    ChainIndexError(#[from] ChainIndexError),
+
     #[error("event type {0:?} is not supported")]
     NotSupported(EventType),
 }
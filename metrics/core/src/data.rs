@@ -495,6 +495,7 @@ impl TryFrom<GetMetricsResponse> for MetricsData {
             process_metrics,
             storage_metrics,
             custom_metrics: _,
+            rpc_method_metrics: _,
         } = response; //rpc.get_metrics(true, true, true, true, true, false).await?;
 
         let consensus_metrics = consensus_metrics.ok_or(Error::MissingData("Consensus Metrics"))?;
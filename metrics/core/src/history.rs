@@ -0,0 +1,203 @@
+//!
+//! In-node history of the [`Metric`] values, retained in ring buffers at a resolution of
+//! one second, one minute and one hour.
+//!
+//! Every resolution averages the snapshots falling in each of its time slots and publishes
+//! a sample once the slot is over, so the latest sample lags behind by up to one slot.
+//!
+
+use crate::data::{Metric, MetricGroup, MetricsSnapshot};
+use calico_rpc_core::RpcMetricsResolution;
+use std::{collections::VecDeque, sync::Mutex};
+
+/// Number of samples retained at every resolution, a resolution being disabled by a zero capacity
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MetricsHistoryConfig {
+    pub seconds: usize,
+    pub minutes: usize,
+    pub hours: usize,
+}
+
+impl Default for MetricsHistoryConfig {
+    fn default() -> Self {
+        // 1 hour of seconds, 1 day of minutes and 30 days of hours
+        Self { seconds: 3_600, minutes: 1_440, hours: 720 }
+    }
+}
+
+impl MetricsHistoryConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.seconds > 0 || self.minutes > 0 || self.hours > 0
+    }
+}
+
+/// Values of the metrics at a point in time
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetricsHistorySample {
+    /// Start of the time slot covered by the sample, in unix milliseconds
+    pub timestamp: u64,
+    pub values: Vec<f64>,
+}
+
+/// Values of the samples of an unfinished time slot
+struct PendingSlot {
+    timestamp: u64,
+    sums: Vec<f64>,
+    count: usize,
+}
+
+struct Tier {
+    duration_millis: u64,
+    capacity: usize,
+    samples: VecDeque<MetricsHistorySample>,
+    pending: Option<PendingSlot>,
+}
+
+impl Tier {
+    fn new(resolution: RpcMetricsResolution, capacity: usize) -> Self {
+        Self { duration_millis: resolution.duration_millis(), capacity, samples: VecDeque::with_capacity(capacity), pending: None }
+    }
+
+    fn push(&mut self, sample: MetricsHistorySample) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    fn record(&mut self, timestamp: u64, values: &[f64]) {
+        if self.capacity == 0 {
+            return;
+        }
+        let slot = timestamp - timestamp % self.duration_millis;
+        match self.pending.as_mut() {
+            Some(pending) if pending.timestamp == slot => {
+                pending.sums.iter_mut().zip(values).for_each(|(sum, value)| *sum += value);
+                pending.count += 1;
+            }
+            _ => {
+                if let Some(pending) = self.pending.take() {
+                    let values = pending.sums.into_iter().map(|sum| sum / pending.count as f64).collect();
+                    self.push(MetricsHistorySample { timestamp: pending.timestamp, values });
+                }
+                self.pending = Some(PendingSlot { timestamp: slot, sums: values.to_vec(), count: 1 });
+            }
+        }
+    }
+}
+
+/// Time series of all the metrics, downsampled in tiers of 1s, 1m and 1h
+pub struct MetricsHistory {
+    metrics: Vec<Metric>,
+    tiers: Mutex<[Tier; 3]>,
+}
+
+impl MetricsHistory {
+    pub fn new(config: MetricsHistoryConfig) -> Self {
+        let metrics =
+            [MetricGroup::System, MetricGroup::Storage, MetricGroup::Bandwidth, MetricGroup::Connections, MetricGroup::Network]
+                .iter()
+                .flat_map(|group| group.metrics().copied().collect::<Vec<_>>())
+                .collect();
+        let tiers = [
+            Tier::new(RpcMetricsResolution::Second, config.seconds),
+            Tier::new(RpcMetricsResolution::Minute, config.minutes),
+            Tier::new(RpcMetricsResolution::Hour, config.hours),
+        ];
+        Self { metrics, tiers: Mutex::new(tiers) }
+    }
+
+    /// Recorded metrics, in the order of the values of the samples
+    pub fn metrics(&self) -> &[Metric] {
+        &self.metrics
+    }
+
+    /// Looks a metric up by its name (ie. `NodeCpuUsage`)
+    pub fn metric(&self, name: &str) -> Option<Metric> {
+        self.metrics.iter().find(|metric| metric.as_str() == name).copied()
+    }
+
+    pub fn record(&self, snapshot: &MetricsSnapshot) {
+        // Values not being a number (ie. the CPU usage before the first measure) are recorded as zero
+        let values = self
+            .metrics
+            .iter()
+            .map(|metric| snapshot.get(metric))
+            .map(|value| if value.is_finite() { value } else { 0.0 })
+            .collect::<Vec<_>>();
+        let timestamp = snapshot.unixtime_millis as u64;
+        self.tiers.lock().unwrap().iter_mut().for_each(|tier| tier.record(timestamp, &values));
+    }
+
+    /// Samples of `metrics` at `resolution` with a timestamp in the range `[start, end]`, ordered by ascending timestamp
+    pub fn samples(
+        &self,
+        resolution: RpcMetricsResolution,
+        start: Option<u64>,
+        end: Option<u64>,
+        metrics: &[Metric],
+    ) -> Vec<MetricsHistorySample> {
+        let indexes = metrics
+            .iter()
+            .map(|metric| self.metrics.iter().position(|recorded| recorded == metric).expect("all metrics are recorded"))
+            .collect::<Vec<_>>();
+        let tiers = self.tiers.lock().unwrap();
+        let tier = match resolution {
+            RpcMetricsResolution::Second => &tiers[0],
+            RpcMetricsResolution::Minute => &tiers[1],
+            RpcMetricsResolution::Hour => &tiers[2],
+        };
+        tier.samples
+            .iter()
+            .filter(|sample| start.map_or(true, |start| sample.timestamp >= start) && end.map_or(true, |end| sample.timestamp <= end))
+            .map(|sample| MetricsHistorySample {
+                timestamp: sample.timestamp,
+                values: indexes.iter().map(|&index| sample.values[index]).collect(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::MetricsData;
+
+    fn snapshot(unixtime_millis: f64, mempool_size: u64) -> MetricsSnapshot {
+        let a = MetricsData::new(unixtime_millis - 1000.0);
+        let b = MetricsData { network_mempool_size: mempool_size, ..MetricsData::new(unixtime_millis) };
+        MetricsSnapshot::from((&a, &b))
+    }
+
+    #[test]
+    fn test_metrics_history_downsampling() {
+        let history = MetricsHistory::new(MetricsHistoryConfig { seconds: 90, minutes: 2, hours: 1 });
+        let metrics = [Metric::NetworkMempoolSize];
+
+        // Three minutes of one snapshot per second, the mempool size being the minute index
+        for second in 0..180u64 {
+            history.record(&snapshot((second * 1000) as f64, second / 60));
+        }
+
+        // The seconds are bounded by their capacity, the ongoing second being still pending
+        let seconds = history.samples(RpcMetricsResolution::Second, None, None, &metrics);
+        assert_eq!(seconds.len(), 90);
+        assert_eq!(seconds.first().unwrap().timestamp, 89_000);
+        assert_eq!(seconds.last().unwrap().values, vec![2.0]);
+
+        // The ongoing minute is not published yet
+        let minutes = history.samples(RpcMetricsResolution::Minute, None, None, &metrics);
+        assert_eq!(
+            minutes,
+            vec![
+                MetricsHistorySample { timestamp: 0, values: vec![0.0] },
+                MetricsHistorySample { timestamp: 60_000, values: vec![1.0] }
+            ]
+        );
+        assert!(history.samples(RpcMetricsResolution::Hour, None, None, &metrics).is_empty());
+
+        // Ranges are inclusive
+        let range = history.samples(RpcMetricsResolution::Second, Some(100_000), Some(110_000), &metrics);
+        assert_eq!(range.len(), 11);
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     mempool::{
         config::Config,
         model::tx::{MempoolTransaction, TransactionPostValidation, TransactionPreValidation, TxRemovalReason},
+        notify::MempoolNotifier,
         populate_entries_and_try_validate::{
             populate_mempool_transactions_in_parallel, validate_mempool_transaction, validate_mempool_transactions_in_parallel,
         },
@@ -20,6 +21,7 @@ use crate::{
     },
     MempoolCountersSnapshot, MiningCounters, P2pTxCountSample,
 };
+use calico_addresses::Prefix;
 use calico_consensus_core::{
     api::{
         args::{TransactionValidationArgs, TransactionValidationBatchArgs},
@@ -30,6 +32,7 @@ use calico_consensus_core::{
     errors::{block::RuleError as BlockRuleError, tx::TxRuleError},
     tx::{MutableTransaction, Transaction, TransactionId, TransactionOutput},
 };
+use calico_consensus_notify::root::ConsensusNotificationRoot;
 use calico_consensusmanager::{spawn_blocking, ConsensusProxy};
 use calico_core::{debug, error, info, time::Stopwatch, warn};
 use calico_mining_errors::{manager::MiningManagerError, mempool::RuleError};
@@ -77,6 +80,13 @@ impl MiningManager {
         Self { config, block_template_cache, mempool, counters }
     }
 
+    /// Reports the transactions entering and leaving the mempool transaction pool to `notification_root`,
+    /// with their addresses encoded with `prefix`.
+    pub fn with_notification_root(self, notification_root: Arc<ConsensusNotificationRoot>, prefix: Prefix) -> Self {
+        self.mempool.write().set_notifier(MempoolNotifier::new(notification_root, prefix));
+        self
+    }
+
     pub fn get_block_template(&self, consensus: &dyn ConsensusApi, miner_data: &MinerData) -> MiningManagerResult<BlockTemplate> {
         let virtual_state_approx_id = consensus.get_virtual_state_approx_id();
         let mut cache_lock = self.block_template_cache.lock(virtual_state_approx_id);
@@ -162,7 +172,7 @@ impl MiningManager {
 
                         let removal_result = if *err == TxRuleError::MissingTxOutpoints {
                             missing_outpoint += 1;
-                            mempool_write.remove_transaction(x, false, TxRemovalReason::Muted, "")
+                            mempool_write.remove_transaction(x, false, TxRemovalReason::BlockTemplateWithMissingOutpoints, "")
                         } else {
                             invalid += 1;
                             warn!("Remove per BBT invalid transaction and descendants");
@@ -614,7 +624,7 @@ impl MiningManager {
         for chunk in &expired_low_priority_transactions.iter().chunks(24) {
             let mut mempool = self.mempool.write();
             chunk.into_iter().for_each(|tx| {
-                if let Err(err) = mempool.remove_transaction(tx, true, TxRemovalReason::Muted, "") {
+                if let Err(err) = mempool.remove_transaction(tx, true, TxRemovalReason::Expired, "") {
                     warn!("Failed to remove transaction {} from mempool: {}", tx, err);
                 }
             });
@@ -28,15 +28,20 @@ mod tests {
             TransactionOutput, UtxoEntry,
         },
     };
+    use calico_consensus_notify::{
+        notification::{MempoolRemovalReason, Notification},
+        root::ConsensusNotificationRoot,
+    };
     use calico_hashes::Hash;
     use calico_mining_errors::mempool::RuleResult;
+    use calico_notify::{scope::MempoolTransactionRemovedScope, subscriber::SubscriptionManager};
     use calico_txscript::{
         pay_to_address_script, pay_to_script_hash_signature_script,
         test_helpers::{create_transaction, create_transaction_with_change, op_true_script},
     };
     use calico_utils::mem_size::MemSizeEstimator;
     use itertools::Itertools;
-    use std::{iter::once, sync::Arc};
+    use std::{collections::HashMap, iter::once, sync::Arc};
     use tokio::sync::mpsc::{error::TryRecvError, unbounded_channel};
 
     const TARGET_TIME_PER_BLOCK: u64 = 1_000;
@@ -1174,6 +1179,127 @@ mod tests {
         assert!(validate_and_insert_mutable_transaction(&mining_manager, consensus.as_ref(), too_big_tx.clone()).is_err());
     }
 
+    /// test_mempool_removal_notifications verifies the reason reported for each path removing transactions from the mempool,
+    /// the redeemers removed along with a transaction being reported with their own reason.
+    #[tokio::test]
+    async fn test_mempool_removal_notifications() {
+        const BASE_FEE: u64 = DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE;
+        const CHANGE: Option<u64> = Some(900 * SOMPI_PER_CALICO);
+        let (sender, receiver) = async_channel::unbounded();
+        let root = Arc::new(ConsensusNotificationRoot::new(sender));
+        root.start_notify(0, MempoolTransactionRemovedScope::default().into()).await.unwrap();
+        let removals = || {
+            let mut removals = HashMap::new();
+            while let Ok(notification) = receiver.try_recv() {
+                if let Notification::MempoolTransactionRemoved(notification) = notification {
+                    assert!(
+                        removals.insert(notification.transaction_id, notification.reason).is_none(),
+                        "a transaction must be reported once"
+                    );
+                }
+            }
+            removals
+        };
+        let expected = |removals: &[(Vec<Transaction>, MempoolRemovalReason)]| {
+            removals
+                .iter()
+                .flat_map(|(transactions, reason)| transactions.iter().map(|x| (x.id(), *reason)))
+                .collect::<HashMap<_, _>>()
+        };
+        let setup = |funding_count: usize| {
+            let consensus = Arc::new(ConsensusMock::new());
+            let counters = Arc::new(MiningCounters::default());
+            let mining_manager = MiningManager::new(TARGET_TIME_PER_BLOCK, false, MAX_BLOCK_MASS, None, counters)
+                .with_notification_root(root.clone(), Prefix::Testnet);
+            let funding_transactions = create_and_add_funding_transactions(&consensus, funding_count);
+            (consensus, mining_manager, funding_transactions)
+        };
+        let insert = |mining_manager: &MiningManager, consensus: &ConsensusMock, transactions: &[&[Transaction]]| {
+            validate_and_insert_transactions(
+                mining_manager,
+                consensus,
+                transactions.iter().flat_map(|x| x.iter()),
+                Priority::High,
+                Orphan::Forbidden,
+                RbfPolicy::Forbidden,
+            );
+            assert!(removals().is_empty(), "inserting transactions must not report removals");
+        };
+
+        // Transactions accepted or double spent by a block
+        let (consensus, mining_manager, funding_transactions) = setup(2);
+        let accepted = create_funded_transaction(select_transactions(&funding_transactions, &[0]), vec![0], None, BASE_FEE);
+        let double_spent = create_funded_transaction(select_transactions(&funding_transactions, &[1]), vec![0], CHANGE, BASE_FEE);
+        let redeemers = create_children_tree(&double_spent, 1);
+        insert(&mining_manager, consensus.as_ref(), &[&[accepted.clone(), double_spent.clone()], &redeemers]);
+        let double_spend = create_funded_transaction(select_transactions(&funding_transactions, &[1]), vec![0], None, BASE_FEE);
+        let block_transactions = build_block_transactions([&accepted, &double_spend].into_iter());
+        mining_manager.handle_new_block_transactions(consensus.as_ref(), 2, &block_transactions).unwrap();
+        assert_eq!(
+            removals(),
+            expected(&[
+                (vec![accepted], MempoolRemovalReason::Accepted),
+                (vec![double_spent], MempoolRemovalReason::Invalidated),
+                (redeemers, MempoolRemovalReason::AncestorRemoved),
+            ])
+        );
+
+        // Transaction replaced by fee
+        let (consensus, mining_manager, funding_transactions) = setup(1);
+        let replaced = create_funded_transaction(select_transactions(&funding_transactions, &[0]), vec![0], CHANGE, BASE_FEE);
+        let redeemers = create_children_tree(&replaced, 1);
+        insert(&mining_manager, consensus.as_ref(), &[&[replaced.clone()], &redeemers]);
+        let replacement = create_funded_transaction(select_transactions(&funding_transactions, &[0]), vec![0], None, BASE_FEE * 2);
+        mining_manager
+            .validate_and_insert_transaction(consensus.as_ref(), replacement, Priority::High, Orphan::Forbidden, RbfPolicy::Mandatory)
+            .unwrap();
+        assert_eq!(
+            removals(),
+            expected(&[(vec![replaced], MempoolRemovalReason::ReplacedByFee), (redeemers, MempoolRemovalReason::AncestorRemoved)])
+        );
+
+        // Transactions rejected while building a block template, the redeemers of a transaction with missing outpoints being kept
+        let (consensus, mining_manager, funding_transactions) = setup(2);
+        let missing = create_funded_transaction(select_transactions(&funding_transactions, &[0]), vec![0], CHANGE, BASE_FEE);
+        let kept_redeemers = create_children_tree(&missing, 1);
+        let invalid = create_funded_transaction(select_transactions(&funding_transactions, &[1]), vec![0], CHANGE, BASE_FEE);
+        let redeemers = create_children_tree(&invalid, 1);
+        insert(&mining_manager, consensus.as_ref(), &[&[missing.clone(), invalid.clone()], &kept_redeemers, &redeemers]);
+        consensus.set_status(missing.id(), Err(TxRuleError::MissingTxOutpoints));
+        consensus.set_status(invalid.id(), Err(TxRuleError::NotFinalized(0)));
+        mining_manager.get_block_template(consensus.as_ref(), &get_miner_data(Prefix::Testnet)).unwrap();
+        assert_eq!(
+            removals(),
+            expected(&[
+                (vec![missing], MempoolRemovalReason::MissingOutpoints),
+                (vec![invalid], MempoolRemovalReason::Invalidated),
+                (redeemers, MempoolRemovalReason::AncestorRemoved),
+            ])
+        );
+        assert!(kept_redeemers.iter().all(|x| mining_manager.has_transaction(&x.id(), TransactionQuery::TransactionsOnly)));
+
+        // High priority transactions failing revalidation
+        let (consensus, mining_manager, funding_transactions) = setup(2);
+        let missing = create_funded_transaction(select_transactions(&funding_transactions, &[0]), vec![0], CHANGE, BASE_FEE);
+        let kept_redeemers = create_children_tree(&missing, 1);
+        let invalid = create_funded_transaction(select_transactions(&funding_transactions, &[1]), vec![0], CHANGE, BASE_FEE);
+        let redeemers = create_children_tree(&invalid, 1);
+        insert(&mining_manager, consensus.as_ref(), &[&[missing.clone(), invalid.clone()], &kept_redeemers, &redeemers]);
+        consensus.set_status(missing.id(), Err(TxRuleError::MissingTxOutpoints));
+        consensus.set_status(invalid.id(), Err(TxRuleError::NotFinalized(0)));
+        let (sender, _receiver) = unbounded_channel();
+        mining_manager.revalidate_high_priority_transactions(consensus.as_ref(), sender);
+        assert_eq!(
+            removals(),
+            expected(&[
+                (vec![missing], MempoolRemovalReason::MissingOutpoints),
+                (vec![invalid], MempoolRemovalReason::Invalidated),
+                (redeemers, MempoolRemovalReason::AncestorRemoved),
+            ])
+        );
+        assert!(kept_redeemers.iter().all(|x| mining_manager.has_transaction(&x.id(), TransactionQuery::TransactionsOnly)));
+    }
+
     fn validate_and_insert_mutable_transaction(
         mining_manager: &MiningManager,
         consensus: &dyn ConsensusApi,
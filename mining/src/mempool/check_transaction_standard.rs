@@ -4,7 +4,7 @@ use crate::mempool::{
 };
 use calico_consensus_core::hashing::sighash::SigHashReusedValuesUnsync;
 use calico_consensus_core::{
-    constants::{MAX_SCRIPT_PUBLIC_KEY_VERSION, MAX_SOMPI},
+    constants::{MAXIMUM_STANDARD_TRANSACTION_MASS, MAX_SCRIPT_PUBLIC_KEY_VERSION, MAX_SOMPI},
     mass,
     tx::{MutableTransaction, PopulatedTransaction, TransactionOutput},
 };
@@ -33,10 +33,6 @@ const MAX_STANDARD_P2SH_SIG_OPS: u8 = 15;
 /// (1 + 15*74 + 3) + (15*34 + 3) + 23 = 1650
 const MAXIMUM_STANDARD_SIGNATURE_SCRIPT_SIZE: u64 = 1650;
 
-/// MAXIMUM_STANDARD_TRANSACTION_MASS is the maximum mass allowed for transactions that
-/// are considered standard and will therefore be relayed and considered for mining.
-const MAXIMUM_STANDARD_TRANSACTION_MASS: u64 = 100_000;
-
 impl Mempool {
     pub(crate) fn check_transaction_standard_in_isolation(&self, transaction: &MutableTransaction) -> NonStandardResult<()> {
         let transaction_id = transaction.id();
@@ -1,4 +1,4 @@
-use calico_consensus_core::constants::TX_VERSION;
+use calico_consensus_core::constants::{MINIMUM_RELAY_TRANSACTION_FEE, TX_VERSION};
 
 pub(crate) const DEFAULT_MAXIMUM_TRANSACTION_COUNT: usize = 1_000_000;
 pub(crate) const DEFAULT_MEMPOOL_SIZE_LIMIT: usize = 1_000_000_000;
@@ -16,7 +16,7 @@ pub(crate) const DEFAULT_MAXIMUM_ORPHAN_TRANSACTION_COUNT: u64 = 500;
 
 /// DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE specifies the minimum transaction fee for a transaction to be accepted to
 /// the mempool and relayed. It is specified in sompi per 1kg (or 1000 grams) of transaction mass.
-pub(crate) const DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE: u64 = 1000;
+pub(crate) const DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE: u64 = MINIMUM_RELAY_TRANSACTION_FEE;
 
 /// Standard transaction version range might be different from what consensus accepts, therefore
 /// we define separate values in mempool.
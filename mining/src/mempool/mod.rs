@@ -10,6 +10,7 @@ use crate::{
 use self::{
     config::Config,
     model::{accepted_transactions::AcceptedTransactions, orphan_pool::OrphanPool, pool::Pool, transactions_pool::TransactionsPool},
+    notify::MempoolNotifier,
     tx::Priority,
 };
 use calico_consensus_core::{
@@ -24,6 +25,7 @@ pub mod config;
 pub mod errors;
 pub(crate) mod handle_new_block_transactions;
 pub(crate) mod model;
+pub(crate) mod notify;
 pub(crate) mod populate_entries_and_try_validate;
 pub(crate) mod remove_transaction;
 pub(crate) mod replace_by_fee;
@@ -51,6 +53,7 @@ pub(crate) struct Mempool {
     orphan_pool: OrphanPool,
     accepted_transactions: AcceptedTransactions,
     counters: Arc<MiningCounters>,
+    notifier: Option<MempoolNotifier>,
 }
 
 impl Mempool {
@@ -58,7 +61,11 @@ impl Mempool {
         let transaction_pool = TransactionsPool::new(config.clone());
         let orphan_pool = OrphanPool::new(config.clone());
         let accepted_transactions = AcceptedTransactions::new(config.clone());
-        Self { config, transaction_pool, orphan_pool, accepted_transactions, counters }
+        Self { config, transaction_pool, orphan_pool, accepted_transactions, counters, notifier: None }
+    }
+
+    pub(crate) fn set_notifier(&mut self, notifier: MempoolNotifier) {
+        self.notifier = Some(notifier);
     }
 
     pub(crate) fn get_transaction(&self, transaction_id: &TransactionId, query: TransactionQuery) -> Option<MutableTransaction> {
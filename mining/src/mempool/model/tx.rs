@@ -74,7 +74,7 @@ pub(crate) struct TransactionPostValidation {
     pub accepted: Option<Arc<Transaction>>,
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub(crate) enum TxRemovalReason {
     Muted,
     Accepted,
@@ -84,6 +84,7 @@ pub(crate) enum TxRemovalReason {
     DoubleSpend,
     InvalidInBlockTemplate,
     RevalidationWithMissingOutpoints,
+    BlockTemplateWithMissingOutpoints,
     ReplacedByFee,
 }
 
@@ -98,12 +99,13 @@ impl TxRemovalReason {
             TxRemovalReason::DoubleSpend => "double spend",
             TxRemovalReason::InvalidInBlockTemplate => "invalid in block template",
             TxRemovalReason::RevalidationWithMissingOutpoints => "revalidation with missing outpoints",
+            TxRemovalReason::BlockTemplateWithMissingOutpoints => "block template with missing outpoints",
             TxRemovalReason::ReplacedByFee => "replaced by fee",
         }
     }
 
     pub(crate) fn verbose(&self) -> bool {
-        !matches!(self, TxRemovalReason::Muted)
+        !matches!(self, TxRemovalReason::Muted | TxRemovalReason::BlockTemplateWithMissingOutpoints)
     }
 }
 
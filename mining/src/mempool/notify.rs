@@ -0,0 +1,81 @@
+//!
+//! Notifications of the mempool transaction pool.
+//!
+//! The mempool reports the transactions entering and leaving its transaction pool to the
+//! consensus notification root, from where they follow the regular notification pipeline up
+//! to the RPC listeners. Orphan transactions are only reported once they get unorphaned.
+//! The redeemers removed along with a transaction are reported with their own reason.
+//!
+//! A notification is only built if the root has an active subscription to its event type.
+//!
+
+use crate::mempool::model::tx::TxRemovalReason;
+use calico_addresses::{Address, Prefix};
+use calico_consensus_core::tx::MutableTransaction;
+use calico_consensus_notify::{
+    notification::{MempoolRemovalReason, MempoolTransactionAddedNotification, MempoolTransactionRemovedNotification, Notification},
+    root::ConsensusNotificationRoot,
+};
+use calico_notify::{events::EventType, notifier::Notify};
+use calico_txscript::extract_script_pub_key_address;
+use itertools::Itertools;
+use std::sync::Arc;
+
+pub(crate) struct MempoolNotifier {
+    root: Arc<ConsensusNotificationRoot>,
+    prefix: Prefix,
+}
+
+impl MempoolNotifier {
+    pub(crate) fn new(root: Arc<ConsensusNotificationRoot>, prefix: Prefix) -> Self {
+        Self { root, prefix }
+    }
+
+    /// Collects the distinct addresses of the transaction outputs and of the UTXO entries spent by its inputs
+    fn addresses(&self, transaction: &MutableTransaction) -> Arc<Vec<Address>> {
+        let inputs = transaction.entries.iter().flatten().map(|entry| &entry.script_public_key);
+        let outputs = transaction.tx.outputs.iter().map(|output| &output.script_public_key);
+        Arc::new(inputs.chain(outputs).filter_map(|spk| extract_script_pub_key_address(spk, self.prefix).ok()).unique().collect())
+    }
+
+    fn notify(&self, notification: Notification) {
+        // The root channel is unbounded and only gets closed on shutdown
+        let _ = self.root.notify(notification);
+    }
+
+    pub(crate) fn transaction_added(&self, transaction: &MutableTransaction) {
+        if self.root.has_subscription(EventType::MempoolTransactionAdded) {
+            let addresses = self.addresses(transaction);
+            self.notify(Notification::MempoolTransactionAdded(MempoolTransactionAddedNotification::new(transaction.id(), addresses)));
+        }
+    }
+
+    pub(crate) fn transaction_removed(&self, transaction: &MutableTransaction, reason: MempoolRemovalReason) {
+        if self.root.has_subscription(EventType::MempoolTransactionRemoved) {
+            let addresses = self.addresses(transaction);
+            self.notify(Notification::MempoolTransactionRemoved(MempoolTransactionRemovedNotification::new(
+                transaction.id(),
+                addresses,
+                reason,
+            )));
+        }
+    }
+}
+
+impl From<TxRemovalReason> for MempoolRemovalReason {
+    fn from(reason: TxRemovalReason) -> Self {
+        match reason {
+            TxRemovalReason::Accepted => MempoolRemovalReason::Accepted,
+            TxRemovalReason::MakingRoom => MempoolRemovalReason::Evicted,
+            TxRemovalReason::ReplacedByFee => MempoolRemovalReason::ReplacedByFee,
+            TxRemovalReason::Expired => MempoolRemovalReason::Expired,
+            TxRemovalReason::RevalidationWithMissingOutpoints | TxRemovalReason::BlockTemplateWithMissingOutpoints => {
+                MempoolRemovalReason::MissingOutpoints
+            }
+            TxRemovalReason::Muted
+            | TxRemovalReason::Unorphaned
+            | TxRemovalReason::DoubleSpend
+            | TxRemovalReason::InvalidInBlockTemplate => MempoolRemovalReason::Invalidated,
+        }
+    }
+}
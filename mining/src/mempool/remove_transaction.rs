@@ -4,6 +4,7 @@ use crate::mempool::{
     Mempool,
 };
 use calico_consensus_core::tx::TransactionId;
+use calico_consensus_notify::notification::MempoolRemovalReason;
 use calico_core::debug;
 use calico_utils::iter::IterExtensions;
 
@@ -33,6 +34,13 @@ impl Mempool {
         for tx_id in removed_transactions.iter() {
             // Remove the tx from the transaction pool and the UTXO set (handled within the pool)
             let tx = self.transaction_pool.remove_transaction(tx_id)?;
+            if let Some(notifier) = self.notifier.as_ref() {
+                let reason = match tx_id == transaction_id {
+                    true => reason.into(),
+                    false => MempoolRemovalReason::AncestorRemoved,
+                };
+                notifier.transaction_removed(&tx.mtx, reason);
+            }
             // Update/remove descendent orphan txs (depending on `remove_redeemers`)
             let txs = self.orphan_pool.update_orphans_after_transaction_removed(&tx, remove_redeemers)?;
             removed_orphans.extend(txs.into_iter().map(|x| x.id()));
@@ -40,7 +48,8 @@ impl Mempool {
         removed_transactions.extend(removed_orphans);
 
         match reason {
-            TxRemovalReason::Muted => {}
+            // Expired transactions are logged in bulk by the mining manager
+            TxRemovalReason::Muted | TxRemovalReason::BlockTemplateWithMissingOutpoints | TxRemovalReason::Expired => {}
             TxRemovalReason::DoubleSpend => match removed_transactions.len() {
                 0 => {}
                 1 => debug!("Removed transaction ({}) {}{}", reason, removed_transactions[0], extra_info),
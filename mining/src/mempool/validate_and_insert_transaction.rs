@@ -120,12 +120,12 @@ impl Mempool {
         );
 
         // Add the transaction to the mempool as a MempoolTransaction and return a clone of the embedded Arc<Transaction>
-        let accepted_transaction = self
-            .transaction_pool
-            .add_transaction(transaction, consensus.get_virtual_daa_score(), priority, transaction_size)?
-            .mtx
-            .tx
-            .clone();
+        let mempool_transaction =
+            self.transaction_pool.add_transaction(transaction, consensus.get_virtual_daa_score(), priority, transaction_size)?;
+        if let Some(notifier) = self.notifier.as_ref() {
+            notifier.transaction_added(&mempool_transaction.mtx);
+        }
+        let accepted_transaction = mempool_transaction.mtx.tx.clone();
         Ok(TransactionPostValidation { removed: removed_transaction, accepted: Some(accepted_transaction) })
     }
 
@@ -82,6 +82,21 @@ impl ConsensusApi for ConsensusMock {
         _build_mode: TemplateBuildMode,
     ) -> Result<BlockTemplate, RuleError> {
         let mut txs = tx_selector.select_transactions();
+
+        // Reject the transactions registered with a predefined error status
+        let statuses = self.statuses.read();
+        let invalid_transactions = txs
+            .iter()
+            .filter_map(|tx| match statuses.get(&tx.id()) {
+                Some(Err(err)) => Some((tx.id(), err.clone())),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+        drop(statuses);
+        if !invalid_transactions.is_empty() {
+            return Err(RuleError::InvalidTransactionsInNewBlock(invalid_transactions));
+        }
+
         let coinbase_manager = CoinbaseManagerMock::new();
         let coinbase = coinbase_manager.expected_coinbase_transaction(miner_data.clone());
         txs.insert(0, coinbase.tx);
@@ -5,7 +5,8 @@ use crate::{
     events::{EventArray, EventType},
     listener::ListenerId,
     notification::Notification,
-    subscription::{context::SubscriptionContext, BroadcastingSingle, DynSubscription},
+    replay::{Backlog, Cursor},
+    subscription::{context::SubscriptionContext, BroadcastingSingle, DynSubscription, Single},
 };
 use async_channel::{Receiver, Sender};
 use calico_core::{debug, trace};
@@ -88,11 +89,13 @@ impl<C: Connection> Default for Plan<C> {
 }
 
 #[derive(Clone, Debug)]
-enum Ctl<C>
+enum Ctl<N, C>
 where
+    N: Notification,
     C: Connection,
 {
-    Register(DynSubscription, ListenerId, C),
+    /// Registers a listener subscription, replaying first the optional backlog to the listener
+    Register(DynSubscription, ListenerId, C, Option<Backlog<N>>),
     Unregister(EventType, ListenerId),
 }
 
@@ -106,7 +109,7 @@ where
     index: usize,
     context: SubscriptionContext,
     started: Arc<AtomicBool>,
-    ctl: Channel<Ctl<C>>,
+    ctl: Channel<Ctl<N, C>>,
     incoming: Receiver<N>,
     shutdown: Channel<()>,
     /// Sync channel, for handling of messages in predictable sequence; exclusively intended for tests.
@@ -153,13 +156,18 @@ where
             let mut plan = EventArray::<Plan<C>>::default();
             // Create a store for closed connections to be removed from the plan
             let mut purge: Vec<ListenerId> = Vec::new();
+            // Cursor of the last broadcast notification, delimiting the replay backlogs
+            let mut last_cursor: Cursor = 0;
             loop {
                 select_biased! {
                     ctl = self.ctl.recv().fuse() => {
                         if let Ok(ctl) = ctl {
                             match ctl {
-                                Ctl::Register(subscription, id, connection) => {
+                                Ctl::Register(subscription, id, connection, backlog) => {
                                     let event_type = subscription.event_type();
+                                    if let Some(backlog) = backlog {
+                                        self.replay(&**subscription, id, &connection, backlog, last_cursor, &context).await;
+                                    }
                                     plan[event_type].insert(subscription.broadcasting(&context), id, connection);
                                     debug!("[{}] insert {} subscription, count = {}, capacity = {}", self, event_type, plan[event_type].len(), plan[event_type].capacity());
                                 },
@@ -203,6 +211,10 @@ where
                             // Remove closed connections
                             purge.drain(..).for_each(|id| { plan[event].remove(&id); });
 
+                            if let Some(cursor) = notification.cursor() {
+                                last_cursor = cursor;
+                            }
+
                         } else {
                             break;
                         }
@@ -221,9 +233,36 @@ where
         });
     }
 
-    pub fn register(&self, subscription: DynSubscription, id: ListenerId, connection: C) -> Result<()> {
+    /// Sends to the listener the backlog notifications already broadcast by this broadcaster.
+    ///
+    /// The backlog notifications following `last_cursor` are still pending in the incoming channel
+    /// and will reach the listener through the regular broadcasting.
+    async fn replay(
+        &self,
+        subscription: &dyn Single,
+        id: ListenerId,
+        connection: &C,
+        backlog: Backlog<N>,
+        last_cursor: Cursor,
+        context: &SubscriptionContext,
+    ) {
+        let encoding = connection.encoding();
+        let mut count = 0;
+        for (_, notification) in backlog.into_iter().filter(|(cursor, _)| *cursor <= last_cursor) {
+            if let Some(applied_notification) = notification.apply_subscription(subscription, context) {
+                if connection.send(C::into_message(&applied_notification, &encoding)).await.is_err() {
+                    trace!("[{}] could not replay a notification to listener {id}", self);
+                    return;
+                }
+                count += 1;
+            }
+        }
+        debug!("[{}] replayed {count} notifications to listener {id}", self);
+    }
+
+    pub fn register(&self, subscription: DynSubscription, id: ListenerId, connection: C, backlog: Option<Backlog<N>>) -> Result<()> {
         assert!(subscription.active());
-        self.ctl.try_send(Ctl::Register(subscription, id, connection))?;
+        self.ctl.try_send(Ctl::Register(subscription, id, connection, backlog))?;
         Ok(())
     }
 
@@ -277,7 +316,7 @@ mod tests {
         /// Listeners, vector index = ListenerId
         listeners: Vec<Listener<TestConnection>>,
         subscription_context: SubscriptionContext,
-        ctl_sender: Sender<Ctl<TestConnection>>,
+        ctl_sender: Sender<Ctl<TestNotification, TestConnection>>,
         sync_receiver: Receiver<()>,
         notification_sender: Sender<TestNotification>,
         notification_receivers: Vec<Receiver<TestNotification>>,
@@ -341,6 +380,7 @@ mod tests {
                                     self.listeners[idx].subscriptions[event].clone(),
                                     idx as u64,
                                     self.listeners[idx].connection(),
+                                    None,
                                 ),
                                 false => Ctl::Unregister(event, idx as u64),
                             };
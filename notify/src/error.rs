@@ -26,6 +26,15 @@ pub enum Error {
     #[error("Invalid event type: {0}")]
     InvalidEventType(String),
 
+    #[error("Invalid UTXO filter: {0}")]
+    InvalidUtxoFilter(String),
+
+    #[error("notification gap: cursor {0} is not available anymore, the oldest replayable cursor is {1}")]
+    NotificationGap(u64, u64),
+
+    #[error("notification replay is not available for event type {0}")]
+    ReplayUnavailable(crate::events::EventType),
+
     #[error(transparent)]
     AddressError(#[from] crate::address::error::Error),
 }
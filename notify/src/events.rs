@@ -51,10 +51,14 @@ event_type_enum! {
         VirtualDaaScoreChanged,
         PruningPointUtxoSetOverride,
         NewBlockTemplate,
+        MempoolTransactionAdded,
+        MempoolTransactionRemoved,
+        DatabaseCheckpoint,
+        ConsensusAuditViolation,
     }
 }
 
-pub const EVENT_COUNT: usize = 9;
+pub const EVENT_COUNT: usize = 13;
 
 impl FromStr for EventType {
     type Err = Error;
@@ -70,6 +74,10 @@ impl FromStr for EventType {
             "virtual-daa-score-changed" => Ok(EventType::VirtualDaaScoreChanged),
             "pruning-point-utxo-set-override" => Ok(EventType::PruningPointUtxoSetOverride),
             "new-block-template" => Ok(EventType::NewBlockTemplate),
+            "mempool-transaction-added" => Ok(EventType::MempoolTransactionAdded),
+            "mempool-transaction-removed" => Ok(EventType::MempoolTransactionRemoved),
+            "database-checkpoint" => Ok(EventType::DatabaseCheckpoint),
+            "consensus-audit-violation" => Ok(EventType::ConsensusAuditViolation),
             _ => Err(Error::InvalidEventType(s.to_string())),
         }
     }
@@ -10,6 +10,7 @@ pub mod events;
 pub mod listener;
 pub mod notification;
 pub mod notifier;
+pub mod replay;
 pub mod root;
 pub mod scope;
 pub mod subscriber;
@@ -1,9 +1,9 @@
-use crate::subscription::context::SubscriptionContext;
+use crate::{replay::Cursor, subscription::context::SubscriptionContext};
 
 use super::{
     events::EventType,
     subscription::{
-        single::{OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription},
+        single::{MempoolTransactionSubscription, OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription},
         Single,
     },
 };
@@ -22,6 +22,18 @@ pub trait Notification: Clone + Debug + Display + Send + Sync + 'static {
     fn apply_utxos_changed_subscription(&self, subscription: &UtxosChangedSubscription, context: &SubscriptionContext)
         -> Option<Self>;
 
+    /// Applies a mempool transaction subscription to the notification.
+    ///
+    /// The default implementation ignores the address selection. Notification types carrying the addresses
+    /// involved in mempool transactions must override it.
+    fn apply_mempool_transaction_subscription(
+        &self,
+        subscription: &MempoolTransactionSubscription,
+        _context: &SubscriptionContext,
+    ) -> Option<Self> {
+        subscription.active().then(|| self.clone())
+    }
+
     fn apply_subscription(&self, subscription: &dyn Single, context: &SubscriptionContext) -> Option<Self> {
         match subscription.event_type() {
             EventType::VirtualChainChanged => self.apply_virtual_chain_changed_subscription(
@@ -30,11 +42,31 @@ pub trait Notification: Clone + Debug + Display + Send + Sync + 'static {
             ),
             EventType::UtxosChanged => self
                 .apply_utxos_changed_subscription(subscription.as_any().downcast_ref::<UtxosChangedSubscription>().unwrap(), context),
+            EventType::MempoolTransactionAdded | EventType::MempoolTransactionRemoved => self.apply_mempool_transaction_subscription(
+                subscription.as_any().downcast_ref::<MempoolTransactionSubscription>().unwrap(),
+                context,
+            ),
             _ => self.apply_overall_subscription(subscription.as_any().downcast_ref::<OverallSubscription>().unwrap(), context),
         }
     }
 
     fn event_type(&self) -> EventType;
+
+    /// Returns the replay cursor the notification is stamped with, if any
+    fn cursor(&self) -> Option<Cursor> {
+        None
+    }
+
+    /// Stamps the notification with a replay cursor.
+    ///
+    /// Notification types whose events are replayed by a notifier must carry the cursor
+    /// through [`Notification::apply_subscription`] and return it in [`Notification::cursor`].
+    fn set_cursor(&mut self, _cursor: Cursor) {}
+
+    /// Returns an estimation of the memory held by the notification, used to bound the size of replay buffers
+    fn estimated_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+    }
 }
 
 #[macro_export]
@@ -83,6 +115,7 @@ pub mod test_helpers {
     use calico_core::trace;
     use derive_more::Display;
     use std::sync::Arc;
+    use workflow_serializer::prelude::*;
 
     #[derive(Clone, Debug, Default, PartialEq, Eq)]
     pub struct BlockAddedNotification {
@@ -93,6 +126,7 @@ pub mod test_helpers {
     pub struct VirtualChainChangedNotification {
         pub data: u64,
         pub accepted_transaction_ids: Option<u64>,
+        pub cursor: Option<Cursor>,
     }
 
     #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -134,6 +168,7 @@ pub mod test_helpers {
                             return Some(TestNotification::VirtualChainChanged(VirtualChainChangedNotification {
                                 data: payload.data,
                                 accepted_transaction_ids: None,
+                                cursor: payload.cursor,
                             }));
                         }
                     }
@@ -181,6 +216,60 @@ pub mod test_helpers {
         fn event_type(&self) -> EventType {
             self.into()
         }
+
+        fn cursor(&self) -> Option<Cursor> {
+            match self {
+                TestNotification::VirtualChainChanged(payload) => payload.cursor,
+                _ => None,
+            }
+        }
+
+        fn set_cursor(&mut self, cursor: Cursor) {
+            if let TestNotification::VirtualChainChanged(payload) = self {
+                payload.cursor = Some(cursor);
+            }
+        }
+    }
+
+    impl Serializer for TestNotification {
+        fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+            match self {
+                TestNotification::BlockAdded(payload) => {
+                    store!(u16, &0, writer)?;
+                    store!(u64, &payload.data, writer)?;
+                }
+                TestNotification::VirtualChainChanged(payload) => {
+                    store!(u16, &1, writer)?;
+                    store!(u64, &payload.data, writer)?;
+                    store!(Option<u64>, &payload.accepted_transaction_ids, writer)?;
+                    store!(Option<Cursor>, &payload.cursor, writer)?;
+                }
+                TestNotification::UtxosChanged(payload) => {
+                    store!(u16, &2, writer)?;
+                    store!(u64, &payload.data, writer)?;
+                    store!(Vec<Address>, &payload.addresses, writer)?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl Deserializer for TestNotification {
+        fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+            match load!(u16, reader)? {
+                0 => Ok(TestNotification::BlockAdded(BlockAddedNotification { data: load!(u64, reader)? })),
+                1 => Ok(TestNotification::VirtualChainChanged(VirtualChainChangedNotification {
+                    data: load!(u64, reader)?,
+                    accepted_transaction_ids: load!(Option<u64>, reader)?,
+                    cursor: load!(Option<Cursor>, reader)?,
+                })),
+                2 => Ok(TestNotification::UtxosChanged(UtxosChangedNotification {
+                    data: load!(u64, reader)?,
+                    addresses: Arc::new(load!(Vec<Address>, reader)?),
+                })),
+                kind => Err(std::io::Error::new(std::io::ErrorKind::InvalidData, format!("invalid test notification kind {kind}"))),
+            }
+        }
     }
 
     /// A trait to help tests match notification received and expected thanks to some predefined data
@@ -12,9 +12,10 @@ use super::{
     events::{EventArray, EventSwitches, EventType},
     listener::{Listener, ListenerId},
     notification::Notification,
+    replay::{Cursor, ReplayBuffer, ReplayOptions},
     scope::Scope,
     subscriber::{Subscriber, SubscriptionManager},
-    subscription::{array::ArrayBuilder, Command, CompoundedSubscription, Mutation},
+    subscription::{array::ArrayBuilder, Command, CompoundedSubscription, DynSubscription, Mutation},
 };
 use async_channel::Sender;
 use async_trait::async_trait;
@@ -31,6 +32,7 @@ use std::{
     },
 };
 use workflow_core::channel::Channel;
+use workflow_serializer::prelude::{Deserializer, Serializer};
 
 pub trait Notify<N>: Send + Sync + Debug
 where
@@ -100,6 +102,14 @@ pub type DynNotify<N> = Arc<dyn Notify<N>>;
 /// meaning that, for a given type of subscription (see [`EventType`]), a notifier has at most a single subscriber,
 /// targeting a single _parent_.
 ///
+/// ### Resumable subscriptions
+///
+/// A notifier built with some [`ReplayOptions`] stamps the notifications of the replayed event types with a cursor
+/// and retains the latest ones (see [`crate::replay`]). A listener may then start a subscription with a scope carrying
+/// a resume cursor and get the notifications it missed before the live ones. Such a notifier has a single broadcaster,
+/// preserving the cursor order, and keeps the upstream subscriptions dropped by its listeners alive for as long as they
+/// can be resumed.
+///
 /// ### Special considerations
 ///
 /// A notifier is built with a specific set of enabled event types. It is however possible to manually subscribe
@@ -150,11 +160,39 @@ where
                 subscription_context,
                 broadcasters,
                 policies,
+                None,
                 _sync,
             )),
         }
     }
 
+    pub fn with_replay(
+        name: &'static str,
+        enabled_events: EventSwitches,
+        collectors: Vec<DynCollector<N>>,
+        subscribers: Vec<Arc<Subscriber>>,
+        subscription_context: SubscriptionContext,
+        policies: MutationPolicies,
+        replay: ReplayOptions,
+    ) -> Self
+    where
+        N: Serializer + Deserializer,
+    {
+        Self {
+            inner: Arc::new(Inner::new(
+                name,
+                enabled_events,
+                collectors,
+                subscribers,
+                subscription_context,
+                1,
+                policies,
+                Some(ReplayBuffer::new(replay)),
+                None,
+            )),
+        }
+    }
+
     pub fn subscription_context(&self) -> &SubscriptionContext {
         &self.inner.subscription_context
     }
@@ -267,6 +305,9 @@ where
     /// Mutation policies
     policies: MutationPolicies,
 
+    /// Replay buffer of resumable subscriptions
+    replay: Option<ReplayBuffer<N>>,
+
     /// Name of the notifier, used in logs
     pub name: &'static str,
 
@@ -287,9 +328,11 @@ where
         subscription_context: SubscriptionContext,
         broadcasters: usize,
         policies: MutationPolicies,
+        replay: Option<ReplayBuffer<N>>,
         _sync: Option<Sender<()>>,
     ) -> Self {
         assert!(broadcasters > 0, "a notifier requires a minimum of one broadcaster");
+        assert!(replay.is_none() || broadcasters == 1, "a notifier with replay requires exactly one broadcaster");
         let notification_channel = Channel::unbounded();
         let broadcasters = (0..broadcasters)
             .map(|idx| {
@@ -325,6 +368,7 @@ where
             enabled_subscriber,
             subscription_context,
             policies,
+            replay,
             name,
             _sync,
         }
@@ -373,7 +417,7 @@ where
                 .filter_map(|subscription| if subscription.active() { Some(subscription.event_type()) } else { None })
                 .collect_vec();
             events.drain(..).for_each(|event| {
-                let _ = self.execute_subscribe_command_impl(id, &mut listener, event.into(), Command::Stop);
+                let _ = self.execute_subscribe_command_impl(id, &mut listener, event.into(), Command::Stop, None);
             });
 
             // Close the listener
@@ -385,12 +429,19 @@ where
         Ok(())
     }
 
-    pub fn execute_subscribe_command(&self, id: ListenerId, scope: Scope, command: Command) -> Result<()> {
+    pub fn execute_subscribe_command(&self, id: ListenerId, mut scope: Scope, command: Command) -> Result<()> {
         let event = scope.event_type();
+        let resume_from = scope.take_resume_from().filter(|_| command == Command::Start);
         if self.enabled_events[event] {
+            if let Some(cursor) = resume_from {
+                match self.replay {
+                    Some(ref replay) if replay.handles(event) => replay.check(event, cursor)?,
+                    _ => return Err(Error::ReplayUnavailable(event)),
+                }
+            }
             let mut listeners = self.listeners.lock();
             if let Some(listener) = listeners.get_mut(&id) {
-                self.execute_subscribe_command_impl(id, listener, scope, command)?;
+                self.execute_subscribe_command_impl(id, listener, scope, command, resume_from)?;
             } else {
                 trace!("[Notifier {}] {command} notifying listener {id} about {scope} error: listener id not found", self.name);
             }
@@ -407,13 +458,14 @@ where
         listener: &mut Listener<C>,
         scope: Scope,
         command: Command,
+        resume_from: Option<Cursor>,
     ) -> Result<()> {
         let mut sync_feedback: bool = false;
         let event = scope.event_type();
         let scope_trace = format!("{scope}");
         debug!("[Notifier {}] {command} notifying about {scope_trace} to listener {id} - {}", self.name, listener.connection());
         let outcome = listener.mutate(Mutation::new(command, scope), self.policies, &self.subscription_context)?;
-        if outcome.has_changes() {
+        if outcome.has_changes() || resume_from.is_some() {
             trace!(
                 "[Notifier {}] {command} notifying listener {id} about {scope_trace} involves {} mutations",
                 self.name,
@@ -422,9 +474,11 @@ where
             // Update broadcasters
             match (listener.subscriptions[event].active(), outcome.mutated) {
                 (true, Some(subscription)) => {
-                    self.broadcasters
-                        .iter()
-                        .try_for_each(|broadcaster| broadcaster.register(subscription.clone(), id, listener.connection()))?;
+                    self.register_subscription(subscription, id, listener.connection(), resume_from)?;
+                }
+                (true, None) if resume_from.is_some() => {
+                    // The subscription is unchanged but the listener still expects the backlog
+                    self.register_subscription(listener.subscriptions[event].clone(), id, listener.connection(), resume_from)?;
                 }
                 (true, None) => {
                     sync_feedback = true;
@@ -433,7 +487,20 @@ where
                     self.broadcasters.iter().try_for_each(|broadcaster| broadcaster.unregister(event, id))?;
                 }
             }
-            self.apply_mutations(event, outcome.mutations, &self.subscription_context)?;
+            match self.replay {
+                Some(ref replay) if replay.handles(event) => {
+                    // Keep receiving the dropped scopes upstream for the listener to be able to resume them
+                    let (stops, starts): (Vec<_>, Vec<_>) =
+                        outcome.mutations.into_iter().partition(|mutation| mutation.command == Command::Stop);
+                    self.apply_mutations(event, starts, &self.subscription_context)?;
+                    if !stops.is_empty() {
+                        replay.linger(event, stops);
+                    }
+                }
+                _ => {
+                    self.apply_mutations(event, outcome.mutations, &self.subscription_context)?;
+                }
+            }
         } else {
             trace!("[Notifier {}] {command} notifying listener {id} about {scope_trace} is ignored (no mutation)", self.name);
             sync_feedback = true;
@@ -448,6 +515,27 @@ where
         Ok(())
     }
 
+    fn register_subscription(
+        &self,
+        subscription: DynSubscription,
+        id: ListenerId,
+        connection: C,
+        resume_from: Option<Cursor>,
+    ) -> Result<()> {
+        match (resume_from, self.replay.as_ref()) {
+            (Some(cursor), Some(replay)) => {
+                // A notifier with replay has a single broadcaster
+                replay.backlog(subscription.event_type(), cursor, |backlog| {
+                    self.broadcasters[0].register(subscription, id, connection, Some(backlog))
+                })
+            }
+            _ => self
+                .broadcasters
+                .iter()
+                .try_for_each(|broadcaster| broadcaster.register(subscription.clone(), id, connection.clone(), None)),
+        }
+    }
+
     fn apply_mutations(&self, event: EventType, mutations: Vec<Mutation>, context: &SubscriptionContext) -> Result<()> {
         let mut subscriptions = self.subscriptions.lock();
         // Compound mutations
@@ -455,6 +543,11 @@ where
         for mutation in mutations {
             compound_result = subscriptions[event].compound(mutation, context);
         }
+        if let Some(ref replay) = self.replay {
+            if replay.handles(event) {
+                replay.set_retained(event, subscriptions[event].active());
+            }
+        }
         // Report to the parent if any
         if let Some(mutation) = compound_result {
             if let Some(ref subscriber) = self.enabled_subscriber[event] {
@@ -469,8 +562,19 @@ where
     }
 
     fn notify(&self, notification: N) -> Result<()> {
-        if self.enabled_events[notification.event_type()] {
-            self.notification_channel.try_send(notification)?;
+        let event = notification.event_type();
+        if self.enabled_events[event] {
+            match self.replay {
+                Some(ref replay) if replay.handles(event) => {
+                    replay.record(notification, |notification| Ok(self.notification_channel.try_send(notification)?))?;
+                    for (event, mutations) in replay.expired() {
+                        self.apply_mutations(event, mutations, &self.subscription_context)?;
+                    }
+                }
+                _ => {
+                    self.notification_channel.try_send(notification)?;
+                }
+            }
         }
         Ok(())
     }
@@ -665,10 +769,14 @@ pub mod test_helpers {
             })
         };
         fn n(accepted_transaction_ids: Option<u64>) -> TestNotification {
-            TestNotification::VirtualChainChanged(VirtualChainChangedNotification { data: 0, accepted_transaction_ids })
+            TestNotification::VirtualChainChanged(VirtualChainChangedNotification { data: 0, accepted_transaction_ids, cursor: None })
         }
         fn e(accepted_transaction_ids: Option<u64>) -> Option<TestNotification> {
-            Some(TestNotification::VirtualChainChanged(VirtualChainChangedNotification { data: 0, accepted_transaction_ids }))
+            Some(TestNotification::VirtualChainChanged(VirtualChainChangedNotification {
+                data: 0,
+                accepted_transaction_ids,
+                cursor: None,
+            }))
         }
 
         set_steps_data(vec![
@@ -828,9 +936,11 @@ mod tests {
         converter::ConverterFrom,
         events::EVENT_TYPE_ARRAY,
         notification::test_helpers::*,
+        scope::{UtxosChangedScope, VirtualChainChangedScope},
         subscriber::test_helpers::{SubscriptionManagerMock, SubscriptionMessage},
     };
     use async_channel::{unbounded, Receiver, Sender};
+    use std::time::Duration;
     use tokio::time::timeout;
 
     const SUBSCRIPTION_MANAGER_ID: u64 = 0;
@@ -1004,4 +1114,86 @@ mod tests {
         let test = Test::new("UtxosChanged broadcast", 3, utxos_changed_test_steps(SUBSCRIPTION_MANAGER_ID));
         test.run().await;
     }
+
+    #[tokio::test]
+    async fn test_replay() {
+        calico_core::log::try_init_logger("trace,calico_notify=trace");
+        const IDENT: &str = "test";
+        const CAPACITY: usize = 4;
+        type TestConverter = ConverterFrom<TestNotification, TestNotification>;
+        type TestCollector = CollectorFrom<TestConverter>;
+        let (notification_sender, notification_receiver) = unbounded();
+        let (subscription_sender, subscription_receiver) = unbounded();
+        let collector = Arc::new(TestCollector::new(IDENT, notification_receiver, Arc::new(TestConverter::new())));
+        let subscription_manager = Arc::new(SubscriptionManagerMock::new(subscription_sender));
+        let subscriber = Arc::new(Subscriber::new(IDENT, EVENT_TYPE_ARRAY[..].into(), subscription_manager, SUBSCRIPTION_MANAGER_ID));
+        let notifier = Arc::new(TestNotifier::with_replay(
+            IDENT,
+            EVENT_TYPE_ARRAY[..].into(),
+            vec![collector],
+            vec![subscriber],
+            SubscriptionContext::new(),
+            Default::default(),
+            ReplayOptions::new(CAPACITY).with_events(&[EventType::VirtualChainChanged]),
+        ));
+        let (sender, receiver) = unbounded();
+        let id = notifier.register_new_listener(TestConnection::new(IDENT, sender, ChannelType::Closable), ListenerLifespan::Dynamic);
+        notifier.clone().start();
+
+        let notification =
+            |data: u64| TestNotification::VirtualChainChanged(VirtualChainChangedNotification { data, ..Default::default() });
+        let scope = || -> Scope { VirtualChainChangedScope::new(true).into() };
+        let receive = || async { timeout(SYNC_MAX_DELAY, receiver.recv()).await.unwrap().unwrap() };
+
+        // Nothing can be resumed before the event is received upstream
+        let unretained_scope = VirtualChainChangedScope::new(true).with_resume_from(Some(0)).into();
+        assert!(matches!(notifier.try_start_notify(id, unretained_scope), Err(Error::NotificationGap(..))));
+
+        // Receive a live stream of stamped notifications
+        notifier.try_start_notify(id, scope()).unwrap();
+        let upstream = timeout(SYNC_MAX_DELAY, subscription_receiver.recv()).await.unwrap().unwrap();
+        assert_eq!(upstream.mutation, Mutation::new(Command::Start, scope()));
+        let mut cursors = vec![];
+        for data in 0..6 {
+            notification_sender.send(notification(data)).await.unwrap();
+            let received = receive().await;
+            assert_eq!(received.data(), data);
+            cursors.push(received.cursor().expect("replayed notifications are stamped with a cursor"));
+        }
+        assert!(cursors.windows(2).all(|x| x[1] == x[0] + 1), "cursors must be consecutive");
+        notifier.try_stop_notify(id, scope()).unwrap();
+
+        // The dropped scope is still received upstream
+        assert!(timeout(Duration::from_millis(100), subscription_receiver.recv()).await.is_err());
+
+        // Notifications missed while not subscribed are replayed before the live ones
+        notification_sender.send(notification(6)).await.unwrap();
+        let resume_scope = VirtualChainChangedScope::new(true).with_resume_from(Some(cursors[3])).into();
+        notifier.try_start_notify(id, resume_scope).unwrap();
+        notification_sender.send(notification(7)).await.unwrap();
+        for data in 4..8 {
+            assert_eq!(receive().await.data(), data);
+        }
+
+        // Resuming from a cursor no longer retained is reported as a gap
+        let gap_scope = VirtualChainChangedScope::new(true).with_resume_from(Some(cursors[1])).into();
+        assert!(matches!(notifier.try_start_notify(id, gap_scope), Err(Error::NotificationGap(..))));
+        let unknown_scope = VirtualChainChangedScope::new(true).with_resume_from(Some(cursors[5] + 100)).into();
+        assert!(matches!(notifier.try_start_notify(id, unknown_scope), Err(Error::NotificationGap(..))));
+
+        // Resuming is only available for the replayed events
+        let utxos_scope = UtxosChangedScope::new(vec![]).with_resume_from(Some(cursors[5])).into();
+        assert!(matches!(notifier.try_start_notify(id, utxos_scope), Err(Error::ReplayUnavailable(EventType::UtxosChanged))));
+
+        // The dropped scope is stopped upstream once resuming from the drop point is no longer possible
+        notifier.try_stop_notify(id, scope()).unwrap();
+        for data in 8..=8 + CAPACITY as u64 {
+            notification_sender.send(notification(data)).await.unwrap();
+        }
+        let upstream = timeout(SYNC_MAX_DELAY, subscription_receiver.recv()).await.unwrap().unwrap();
+        assert_eq!(upstream.mutation, Mutation::new(Command::Stop, scope()));
+
+        notification_sender.close();
+        assert!(notifier.join().await.is_ok(), "notifier failed to stop");
+    }
 }
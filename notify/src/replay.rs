@@ -0,0 +1,637 @@
+//!
+//! Replay buffer of resumable notification streams.
+//!
+//! A notifier built with [`ReplayOptions`] stamps every notification of a replayed event type
+//! with a monotonically increasing [`Cursor`] and retains the latest ones in a buffer bounded both
+//! in number of notifications and in size, either in memory or in segment files on disk.
+//! A listener starting a subscription with a resume cursor first receives the retained
+//! notifications following the cursor and then the live stream, so that a client reconnecting
+//! after a network blip does not miss any event in between. A cursor older than the buffer
+//! content is rejected with an explicit [`Error::NotificationGap`], telling the client it has
+//! to resync from scratch.
+//!
+//! The notifier only receives the replayed events its listeners are subscribed to. When a listener
+//! drops a subscription, the matching upstream subscription is kept alive until resuming from the
+//! drop point would be reported as a gap anyway, so a reconnecting client finds the notifications
+//! of its former scope in the buffer. Resuming is also reported as a gap whenever the event was not
+//! continuously received upstream since the cursor.
+//!
+//! Notifications retained on disk are first kept in memory and handed over to a dedicated writer thread
+//! appending them to the segment files, so that notifying never waits on disk.
+//!
+//! The first cursor of a notifier is derived from the wall clock so that cursors handed out by a
+//! previous run of the node are reported as gaps rather than silently matched.
+//!
+
+use crate::{
+    error::{Error, Result},
+    events::{EventArray, EventSwitches, EventType},
+    notification::Notification,
+    subscription::Mutation,
+};
+use calico_core::{time::unix_now, warn};
+use parking_lot::Mutex;
+use std::{
+    collections::VecDeque,
+    fs::{self, File, OpenOptions},
+    io::{Read, Seek, SeekFrom, Write},
+    path::PathBuf,
+    sync::{mpsc, Arc},
+    thread::{self, JoinHandle},
+};
+use workflow_serializer::prelude::*;
+
+/// Position of a notification in the stream of a notifier
+pub type Cursor = u64;
+
+/// Notifications of a replay backlog, paired with their cursor
+pub type Backlog<N> = Vec<(Cursor, N)>;
+
+/// Event types supporting resumable subscriptions
+pub const REPLAYABLE_EVENTS: [EventType; 2] = [EventType::VirtualChainChanged, EventType::UtxosChanged];
+
+/// Default maximum size of the retained notifications
+pub const DEFAULT_REPLAY_MAX_SIZE: usize = 64 * 1024 * 1024;
+
+/// Number of cursors available per millisecond of the notifier start time
+const CURSORS_PER_MILLISECOND: u64 = 1 << 20;
+
+/// Number of segment files sharing the maximum size of a replay buffer stored on disk
+const SEGMENTS_PER_BUFFER: usize = 8;
+
+/// Extension of the segment files
+const SEGMENT_EXTENSION: &str = "replay";
+
+#[derive(Clone, Debug)]
+pub struct ReplayOptions {
+    /// Maximum number of retained notifications
+    pub capacity: usize,
+
+    /// Maximum size in bytes of the retained notifications, estimated when in memory and encoded when on disk
+    pub max_size: usize,
+
+    /// Directory holding the retained notifications, kept in memory if `None`
+    pub directory: Option<PathBuf>,
+
+    /// Replayed event types, a subset of [`REPLAYABLE_EVENTS`]
+    pub events: EventSwitches,
+}
+
+impl ReplayOptions {
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, max_size: DEFAULT_REPLAY_MAX_SIZE, directory: None, events: REPLAYABLE_EVENTS[..].into() }
+    }
+
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    pub fn with_directory(mut self, directory: Option<PathBuf>) -> Self {
+        self.directory = directory;
+        self
+    }
+
+    pub fn with_events(mut self, events: &[EventType]) -> Self {
+        assert!(events.iter().all(|x| REPLAYABLE_EVENTS.contains(x)), "only {REPLAYABLE_EVENTS:?} events can be replayed");
+        self.events = events.into();
+        self
+    }
+}
+
+/// Encoding of the notifications retained on disk
+#[derive(Debug)]
+struct Codec<N> {
+    encode: fn(&N) -> std::io::Result<Vec<u8>>,
+    decode: fn(&[u8]) -> std::io::Result<N>,
+}
+
+#[derive(Debug)]
+enum Content<N> {
+    Memory(N),
+    /// Waiting for the writer to append it to the segment files
+    Pending(N),
+    Disk {
+        segment: u64,
+        offset: u64,
+    },
+}
+
+#[derive(Debug)]
+struct Entry<N> {
+    cursor: Cursor,
+    event: EventType,
+    size: usize,
+    content: Content<N>,
+}
+
+impl<N> Entry<N> {
+    fn segment(&self) -> Option<u64> {
+        match self.content {
+            Content::Memory(_) | Content::Pending(_) => None,
+            Content::Disk { segment, .. } => Some(segment),
+        }
+    }
+}
+
+/// Append-only files holding the encoded notifications of a replay buffer
+#[derive(Debug)]
+struct Segments {
+    directory: PathBuf,
+    segment_size: u64,
+    /// Open segment files, oldest first
+    files: VecDeque<(u64, File)>,
+    /// Size of the last segment file
+    tail_size: u64,
+    next_id: u64,
+}
+
+impl Segments {
+    fn open(directory: PathBuf, max_size: usize) -> std::io::Result<Self> {
+        fs::create_dir_all(&directory)?;
+        // Segments of a previous run only hold cursors reported as gaps
+        for entry in fs::read_dir(&directory)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|x| x == SEGMENT_EXTENSION) {
+                fs::remove_file(path)?;
+            }
+        }
+        let segment_size = (max_size / SEGMENTS_PER_BUFFER).max(1) as u64;
+        Ok(Self { directory, segment_size, files: VecDeque::new(), tail_size: 0, next_id: 0 })
+    }
+
+    fn path(&self, id: u64) -> PathBuf {
+        self.directory.join(format!("{id:016x}.{SEGMENT_EXTENSION}"))
+    }
+
+    fn append(&mut self, bytes: &[u8]) -> std::io::Result<(u64, u64)> {
+        if self.files.is_empty() || self.tail_size >= self.segment_size {
+            let id = self.next_id;
+            let file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(self.path(id))?;
+            self.next_id += 1;
+            self.files.push_back((id, file));
+            self.tail_size = 0;
+        }
+        let (id, mut file) = self.files.back().map(|(id, file)| (*id, file)).unwrap();
+        let offset = self.tail_size;
+        file.seek(SeekFrom::Start(offset))?;
+        file.write_all(bytes)?;
+        self.tail_size += bytes.len() as u64;
+        Ok((id, offset))
+    }
+
+    fn read(&self, segment: u64, offset: u64, len: usize) -> std::io::Result<Vec<u8>> {
+        let Some((_, mut file)) = self.files.iter().map(|(id, file)| (*id, file)).find(|(id, _)| *id == segment) else {
+            return Err(std::io::Error::new(std::io::ErrorKind::NotFound, format!("replay segment {segment} not found")));
+        };
+        let mut bytes = vec![0; len];
+        file.seek(SeekFrom::Start(offset))?;
+        file.read_exact(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Removes the segment files older than `oldest`, all of them if `None`
+    fn release(&mut self, oldest: Option<u64>) {
+        while self.files.front().is_some_and(|(id, _)| !oldest.is_some_and(|oldest| *id >= oldest)) {
+            let (id, file) = self.files.pop_front().unwrap();
+            drop(file);
+            if let Err(err) = fs::remove_file(self.path(id)) {
+                warn!("Removing replay segment {} failed: {err}", self.path(id).display());
+            }
+        }
+        if self.files.is_empty() {
+            self.tail_size = 0;
+        }
+    }
+}
+
+impl Drop for Segments {
+    fn drop(&mut self) {
+        self.release(None);
+    }
+}
+
+/// Upstream stop mutations of a dropped subscription, applied once resuming from the drop point is no longer possible
+#[derive(Debug)]
+struct Lingering {
+    dropped_at: Cursor,
+    event: EventType,
+    mutations: Vec<Mutation>,
+}
+
+#[derive(Debug)]
+struct Entries<N> {
+    buffer: VecDeque<Entry<N>>,
+    /// Total size of the retained notifications
+    size: usize,
+    next: Cursor,
+    /// Cursor since which each event has been continuously received upstream
+    retained_since: EventArray<Option<Cursor>>,
+    lingering: VecDeque<Lingering>,
+}
+
+impl<N> Entries<N>
+where
+    N: Notification,
+{
+    fn oldest(&self) -> Cursor {
+        self.buffer.front().map_or(self.next, |entry| entry.cursor)
+    }
+
+    fn position(&self, cursor: Cursor) -> Option<usize> {
+        self.buffer.binary_search_by_key(&cursor, |entry| entry.cursor).ok()
+    }
+
+    /// Returns the notification with `cursor` if it is still waiting for the writer
+    fn pending(&self, cursor: Cursor) -> Option<N> {
+        match self.buffer[self.position(cursor)?].content {
+            Content::Pending(ref notification) => Some(notification.clone()),
+            _ => None,
+        }
+    }
+
+    /// Records that the notification with `cursor` was written to disk with `size` bytes
+    fn written(&mut self, cursor: Cursor, size: usize, segment: u64, offset: u64) {
+        if let Some(position) = self.position(cursor) {
+            let entry = &mut self.buffer[position];
+            self.size = self.size - entry.size + size;
+            entry.size = size;
+            entry.content = Content::Disk { segment, offset };
+        }
+    }
+
+    /// Drops the notifications up to `cursor` (inclusive)
+    fn discard(&mut self, cursor: Cursor) {
+        while self.buffer.front().is_some_and(|entry| entry.cursor <= cursor) {
+            let entry = self.buffer.pop_front().unwrap();
+            self.size -= entry.size;
+        }
+    }
+
+    fn load(&self, codec: &Codec<N>, segments: Option<&Mutex<Segments>>, entry: &Entry<N>) -> std::io::Result<N> {
+        match entry.content {
+            Content::Memory(ref notification) | Content::Pending(ref notification) => Ok(notification.clone()),
+            Content::Disk { segment, offset } => {
+                let bytes = segments.expect("disk entries have segments").lock().read(segment, offset, entry.size)?;
+                let mut notification = (codec.decode)(&bytes)?;
+                notification.set_cursor(entry.cursor);
+                Ok(notification)
+            }
+        }
+    }
+
+    fn evict(&mut self, capacity: usize, max_size: usize) {
+        while self.buffer.len() > capacity || self.size > max_size {
+            let entry = self.buffer.pop_front().unwrap();
+            self.size -= entry.size;
+        }
+    }
+}
+
+/// State shared by a replay buffer and its writer
+#[derive(Debug)]
+struct Shared<N> {
+    codec: Codec<N>,
+    entries: Mutex<Entries<N>>,
+    segments: Option<Mutex<Segments>>,
+}
+
+#[derive(Debug)]
+enum WriterMessage {
+    /// Append the pending notification with this cursor to the segment files
+    Append(Cursor),
+    /// Acknowledge once all previous messages are processed
+    #[cfg(test)]
+    Flush(mpsc::SyncSender<()>),
+}
+
+/// Thread appending the pending notifications of a replay buffer to its segment files
+#[derive(Debug)]
+struct Writer {
+    sender: mpsc::Sender<WriterMessage>,
+    handle: JoinHandle<()>,
+}
+
+impl Writer {
+    fn spawn<N: Notification>(shared: Arc<Shared<N>>, capacity: usize, max_size: usize) -> std::io::Result<Self> {
+        let (sender, receiver) = mpsc::channel();
+        let handle = thread::Builder::new().name("replay-writer".to_string()).spawn(move || {
+            for message in receiver {
+                match message {
+                    WriterMessage::Append(cursor) => Self::append(&shared, cursor, capacity, max_size),
+                    #[cfg(test)]
+                    WriterMessage::Flush(ack) => {
+                        let _ = ack.send(());
+                    }
+                }
+            }
+        })?;
+        Ok(Self { sender, handle })
+    }
+
+    fn append<N: Notification>(shared: &Shared<N>, cursor: Cursor, capacity: usize, max_size: usize) {
+        // Notifications evicted before being written are skipped
+        let Some(notification) = shared.entries.lock().pending(cursor) else {
+            return;
+        };
+        let segments = shared.segments.as_ref().expect("a writer has segments");
+        let written = (shared.codec.encode)(&notification).and_then(|bytes| {
+            let (segment, offset) = segments.lock().append(&bytes)?;
+            Ok((bytes.len(), segment, offset))
+        });
+        let oldest_segment = {
+            let mut entries = shared.entries.lock();
+            match written {
+                Ok((size, segment, offset)) => {
+                    entries.written(cursor, size, segment, offset);
+                    entries.evict(capacity, max_size);
+                }
+                Err(err) => {
+                    // Resuming before a notification that could not be retained must be reported as a gap
+                    warn!("Retaining the notification with cursor {cursor} failed: {err}");
+                    entries.discard(cursor);
+                }
+            }
+            // Notifications are written in cursor order, so a pending oldest entry means that none is on disk
+            entries.buffer.front().and_then(Entry::segment)
+        };
+        segments.lock().release(oldest_segment);
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ReplayBuffer<N>
+where
+    N: Notification,
+{
+    options: ReplayOptions,
+    shared: Arc<Shared<N>>,
+    /// Writer of the notifications retained on disk, if any
+    writer: Option<Writer>,
+}
+
+impl<N> ReplayBuffer<N>
+where
+    N: Notification,
+{
+    pub fn new(options: ReplayOptions) -> Self
+    where
+        N: Serializer + Deserializer,
+    {
+        assert!(options.capacity > 0, "a replay buffer requires a minimum capacity of one notification");
+        let codec = Codec {
+            encode: |notification: &N| {
+                let mut bytes = vec![];
+                notification.serialize(&mut bytes)?;
+                Ok(bytes)
+            },
+            decode: |mut bytes: &[u8]| N::deserialize(&mut bytes),
+        };
+        let segments = options.directory.clone().and_then(|directory| match Segments::open(directory.clone(), options.max_size) {
+            Ok(segments) => Some(segments),
+            Err(err) => {
+                warn!("Replay directory {} is unavailable, retaining the notifications in memory: {err}", directory.display());
+                None
+            }
+        });
+        let entries = Entries {
+            buffer: VecDeque::new(),
+            size: 0,
+            next: unix_now() * CURSORS_PER_MILLISECOND,
+            retained_since: Default::default(),
+            lingering: VecDeque::new(),
+        };
+        let shared = Arc::new(Shared { codec, entries: Mutex::new(entries), segments: segments.map(Mutex::new) });
+        let writer = shared.segments.as_ref().and_then(|_| match Writer::spawn(shared.clone(), options.capacity, options.max_size) {
+            Ok(writer) => Some(writer),
+            Err(err) => {
+                warn!("Starting the replay writer failed, retaining the notifications in memory: {err}");
+                None
+            }
+        });
+        Self { options, shared, writer }
+    }
+
+    #[inline(always)]
+    pub fn handles(&self, event: EventType) -> bool {
+        self.options.events[event]
+    }
+
+    /// Records whether `event` is currently received upstream
+    pub fn set_retained(&self, event: EventType, retained: bool) {
+        let mut entries = self.shared.entries.lock();
+        entries.retained_since[event] = match retained {
+            true => Some(entries.retained_since[event].unwrap_or(entries.next)),
+            false => None,
+        };
+    }
+
+    /// Delays the upstream stop `mutations` of a subscription to `event` dropped by a listener
+    pub fn linger(&self, event: EventType, mutations: Vec<Mutation>) {
+        let mut entries = self.shared.entries.lock();
+        let dropped_at = entries.next;
+        entries.lingering.push_back(Lingering { dropped_at, event, mutations });
+    }
+
+    /// Takes the delayed upstream stop mutations no longer needed by any resumed subscription
+    pub fn expired(&self) -> Vec<(EventType, Vec<Mutation>)> {
+        let mut entries = self.shared.entries.lock();
+        let oldest = entries.oldest();
+        let mut expired = vec![];
+        while entries.lingering.front().is_some_and(|x| x.dropped_at < oldest) {
+            let lingering = entries.lingering.pop_front().unwrap();
+            expired.push((lingering.event, lingering.mutations));
+        }
+        expired
+    }
+
+    /// Stamps `notification` with the next cursor, retains it and hands it over to `send`.
+    ///
+    /// `send` is called under the buffer lock so that notifications are sent in cursor order.
+    pub fn record<F>(&self, mut notification: N, send: F) -> Result<()>
+    where
+        F: FnOnce(N) -> Result<()>,
+    {
+        let mut entries = self.shared.entries.lock();
+        let cursor = entries.next;
+        entries.next += 1;
+        notification.set_cursor(cursor);
+        // The size of a notification retained on disk is only accounted for once written, meanwhile the
+        // in-memory tail is bounded by the capacity
+        let (size, content) = match self.writer {
+            Some(_) => (0, Content::Pending(notification.clone())),
+            None => (notification.estimated_size(), Content::Memory(notification.clone())),
+        };
+        entries.size += size;
+        entries.buffer.push_back(Entry { cursor, event: notification.event_type(), size, content });
+        entries.evict(self.options.capacity, self.options.max_size);
+        if let Some(ref writer) = self.writer {
+            if writer.sender.send(WriterMessage::Append(cursor)).is_err() {
+                // Resuming before a notification that could not be retained must be reported as a gap
+                warn!("Retaining the notification with cursor {cursor} failed: the replay writer is gone");
+                entries.discard(cursor);
+            }
+        }
+        send(notification)
+    }
+
+    /// Waits for the writer to process the notifications recorded so far
+    #[cfg(test)]
+    fn flush(&self) {
+        if let Some(ref writer) = self.writer {
+            let (ack, done) = mpsc::sync_channel(1);
+            writer.sender.send(WriterMessage::Flush(ack)).unwrap();
+            done.recv().unwrap();
+        }
+    }
+
+    /// Checks that the notifications of type `event` following `resume_from` are all still retained
+    pub fn check(&self, event: EventType, resume_from: Cursor) -> Result<()> {
+        Self::check_entries(&self.shared.entries.lock(), event, resume_from)
+    }
+
+    fn check_entries(entries: &Entries<N>, event: EventType, resume_from: Cursor) -> Result<()> {
+        let oldest = entries.oldest();
+        let retained = entries.retained_since[event].is_some_and(|since| resume_from >= since);
+        if !retained || resume_from >= entries.next || resume_from.saturating_add(1) < oldest {
+            return Err(Error::NotificationGap(resume_from, oldest.max(entries.retained_since[event].unwrap_or(entries.next))));
+        }
+        Ok(())
+    }
+
+    /// Collects the retained notifications of type `event` following `resume_from` and hands them over to `register`.
+    ///
+    /// `register` is called under the buffer lock so that no notification can be sent in between.
+    pub fn backlog<F>(&self, event: EventType, resume_from: Cursor, register: F) -> Result<()>
+    where
+        F: FnOnce(Backlog<N>) -> Result<()>,
+    {
+        let entries = self.shared.entries.lock();
+        Self::check_entries(&entries, event, resume_from)?;
+        let backlog = entries
+            .buffer
+            .iter()
+            .filter(|entry| entry.cursor > resume_from && entry.event == event)
+            .map(|entry| {
+                entries.load(&self.shared.codec, self.shared.segments.as_ref(), entry).map(|notification| (entry.cursor, notification))
+            })
+            .collect::<std::io::Result<_>>()
+            .map_err(|err| Error::General(format!("reading the replay buffer failed: {err}")))?;
+        register(backlog)
+    }
+}
+
+impl<N> Drop for ReplayBuffer<N>
+where
+    N: Notification,
+{
+    fn drop(&mut self) {
+        // Closing the channel stops the writer, which must be done with the segment files before they are removed
+        if let Some(Writer { sender, handle }) = self.writer.take() {
+            drop(sender);
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::test_helpers::{Data, TestNotification, VirtualChainChangedNotification};
+
+    fn notification(data: u64) -> TestNotification {
+        TestNotification::VirtualChainChanged(VirtualChainChangedNotification { data, ..Default::default() })
+    }
+
+    fn record(buffer: &ReplayBuffer<TestNotification>, data: u64) -> Cursor {
+        let mut cursor = None;
+        buffer
+            .record(notification(data), |notification| {
+                cursor = notification.cursor();
+                Ok(())
+            })
+            .unwrap();
+        cursor.unwrap()
+    }
+
+    fn backlog(buffer: &ReplayBuffer<TestNotification>, resume_from: Cursor) -> Result<Vec<(Cursor, u64)>> {
+        let mut data = vec![];
+        buffer.backlog(EventType::VirtualChainChanged, resume_from, |backlog| {
+            data = backlog.into_iter().map(|(cursor, notification)| (cursor, notification.data())).collect();
+            Ok(())
+        })?;
+        Ok(data)
+    }
+
+    #[test]
+    fn test_replay_buffer_limits() {
+        const EVENT: EventType = EventType::VirtualChainChanged;
+        let size = notification(0).estimated_size();
+        let buffer = ReplayBuffer::<TestNotification>::new(ReplayOptions::new(8).with_max_size(3 * size));
+
+        // Resuming requires the event to be received upstream since the cursor
+        let first = record(&buffer, 0);
+        assert!(matches!(buffer.check(EVENT, first), Err(Error::NotificationGap(..))));
+        buffer.set_retained(EVENT, true);
+        let cursors = (1..6).map(|data| record(&buffer, data)).collect::<Vec<_>>();
+        assert!(matches!(buffer.check(EVENT, first), Err(Error::NotificationGap(..))));
+
+        // The size limit retains the 3 latest notifications
+        assert_eq!(backlog(&buffer, cursors[1]).unwrap(), vec![(cursors[2], 3), (cursors[3], 4), (cursors[4], 5)]);
+        assert!(matches!(backlog(&buffer, cursors[0]), Err(Error::NotificationGap(..))));
+
+        // A notification exceeding the size limit on its own is not retained
+        let buffer = ReplayBuffer::<TestNotification>::new(ReplayOptions::new(8).with_max_size(size - 1));
+        buffer.set_retained(EVENT, true);
+        let first = record(&buffer, 0);
+        let second = record(&buffer, 1);
+        assert!(matches!(buffer.check(EVENT, first), Err(Error::NotificationGap(..))));
+        assert_eq!(backlog(&buffer, second).unwrap(), vec![]);
+
+        // Dropped scopes expire when resuming from the drop point becomes impossible
+        let buffer = ReplayBuffer::<TestNotification>::new(ReplayOptions::new(2));
+        buffer.set_retained(EVENT, true);
+        record(&buffer, 0);
+        buffer.linger(EVENT, vec![]);
+        record(&buffer, 1);
+        record(&buffer, 2);
+        assert!(buffer.expired().is_empty());
+        record(&buffer, 3);
+        assert_eq!(buffer.expired().len(), 1);
+        assert!(buffer.expired().is_empty());
+    }
+
+    #[test]
+    fn test_replay_buffer_on_disk() {
+        const EVENT: EventType = EventType::VirtualChainChanged;
+        let directory = tempfile::tempdir().unwrap();
+        std::fs::write(directory.path().join(format!("stale.{SEGMENT_EXTENSION}")), b"stale").unwrap();
+        let encoded_size = {
+            let mut bytes = vec![];
+            notification(0).serialize(&mut bytes).unwrap();
+            bytes.len()
+        };
+        let options = ReplayOptions::new(100).with_max_size(20 * encoded_size).with_directory(Some(directory.path().to_path_buf()));
+        let segments = || std::fs::read_dir(directory.path()).unwrap().count();
+        {
+            let buffer = ReplayBuffer::<TestNotification>::new(options);
+            assert_eq!(segments(), 0, "segments of a previous run must be removed");
+            buffer.set_retained(EVENT, true);
+            let cursors = (0..50).map(|data| record(&buffer, data)).collect::<Vec<_>>();
+
+            // Notifications can be resumed from before they are written
+            assert_eq!(backlog(&buffer, cursors[48]).unwrap(), vec![(cursors[49], 49)]);
+            buffer.flush();
+
+            // The retained notifications are read back from disk with their cursor
+            let expected = (31..50).map(|data| (cursors[data as usize], data)).collect::<Vec<_>>();
+            let replayed = backlog(&buffer, cursors[30]).unwrap();
+            assert_eq!(replayed, expected);
+            assert!(matches!(backlog(&buffer, cursors[28]), Err(Error::NotificationGap(..))));
+
+            // Segments holding only evicted notifications are removed
+            assert!(segments() <= SEGMENTS_PER_BUFFER + 1);
+        }
+        assert_eq!(segments(), 0, "segments are removed with the buffer");
+    }
+}
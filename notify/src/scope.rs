@@ -1,4 +1,4 @@
-use super::events::EventType;
+use super::{events::EventType, replay::Cursor, subscription::filter::UtxoFilter};
 use borsh::{BorshDeserialize, BorshSerialize};
 use calico_addresses::Address;
 use derive_more::Display;
@@ -45,6 +45,10 @@ pub enum Scope {
     VirtualDaaScoreChanged,
     PruningPointUtxoSetOverride,
     NewBlockTemplate,
+    MempoolTransactionAdded,
+    MempoolTransactionRemoved,
+    DatabaseCheckpoint,
+    ConsensusAuditViolation,
 }
 }
 
@@ -52,20 +56,61 @@ impl Scope {
     pub fn event_type(&self) -> EventType {
         self.into()
     }
+
+    /// Returns the cursor a resumable subscription starts after, if any
+    pub fn resume_from(&self) -> Option<Cursor> {
+        match self {
+            Scope::VirtualChainChanged(scope) => scope.resume_from,
+            Scope::UtxosChanged(scope) => scope.resume_from,
+            _ => None,
+        }
+    }
+
+    /// Takes the resume cursor out of the scope, leaving a plain subscription scope
+    pub fn take_resume_from(&mut self) -> Option<Cursor> {
+        match self {
+            Scope::VirtualChainChanged(scope) => scope.resume_from.take(),
+            Scope::UtxosChanged(scope) => scope.resume_from.take(),
+            _ => None,
+        }
+    }
+
+    fn set_resume_from(&mut self, resume_from: Option<Cursor>) {
+        match self {
+            Scope::VirtualChainChanged(scope) => scope.resume_from = resume_from,
+            Scope::UtxosChanged(scope) => scope.resume_from = resume_from,
+            _ => {}
+        }
+    }
 }
 
 impl Serializer for Scope {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &3, writer)?;
         store!(Scope, self, writer)?;
+        // The resume cursor is not part of the borsh layout of the scopes
+        store!(Option<Cursor>, &self.resume_from(), writer)?;
         Ok(())
     }
 }
 
 impl Deserializer for Scope {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
-        load!(Scope, reader)
+        let version = load!(u16, reader)?;
+        if version == 1 {
+            // Version 1 predates the UtxosChanged filter, all other variants are unchanged
+            let variant = load!(u8, reader)?;
+            if variant == EventType::UtxosChanged as u8 {
+                let addresses = load!(Vec<Address>, reader)?;
+                return Ok(UtxosChangedScope::new(addresses).into());
+            }
+            return Scope::deserialize_reader(&mut std::io::Read::chain([variant].as_slice(), reader));
+        }
+        let mut scope = load!(Scope, reader)?;
+        if version > 2 {
+            scope.set_resume_from(load!(Option<Cursor>, reader)?);
+        }
+        Ok(scope)
     }
 }
 
@@ -89,33 +134,52 @@ impl Deserializer for BlockAddedScope {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 pub struct VirtualChainChangedScope {
     pub include_accepted_transaction_ids: bool,
+    /// Cursor of the last notification received by a resuming client
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[borsh(skip)]
+    pub resume_from: Option<Cursor>,
 }
 
 impl VirtualChainChangedScope {
     pub fn new(include_accepted_transaction_ids: bool) -> Self {
-        Self { include_accepted_transaction_ids }
+        Self { include_accepted_transaction_ids, resume_from: None }
+    }
+
+    pub fn with_resume_from(mut self, resume_from: Option<Cursor>) -> Self {
+        self.resume_from = resume_from;
+        self
     }
 }
 
 impl std::fmt::Display for VirtualChainChangedScope {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "VirtualChainChangedScope{}", if self.include_accepted_transaction_ids { " with accepted transactions" } else { "" })
+        write!(
+            f,
+            "VirtualChainChangedScope{}",
+            if self.include_accepted_transaction_ids { " with accepted transactions" } else { "" }
+        )?;
+        if let Some(cursor) = self.resume_from {
+            write!(f, " resuming after cursor {cursor}")?;
+        }
+        Ok(())
     }
 }
 
 impl Serializer for VirtualChainChangedScope {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
         store!(bool, &self.include_accepted_transaction_ids, writer)?;
+        store!(Option<Cursor>, &self.resume_from, writer)?;
         Ok(())
     }
 }
 
 impl Deserializer for VirtualChainChangedScope {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let version = load!(u16, reader)?;
         let include_accepted_transaction_ids = load!(bool, reader)?;
-        Ok(Self { include_accepted_transaction_ids })
+        let resume_from = if version > 1 { load!(Option<Cursor>, reader)? } else { None };
+        Ok(Self { include_accepted_transaction_ids, resume_from })
     }
 }
 
@@ -156,22 +220,46 @@ impl Deserializer for FinalityConflictResolvedScope {
 #[derive(Clone, Debug, Default, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 pub struct UtxosChangedScope {
     pub addresses: Vec<Address>,
+    /// Content filter applied on top of the address selection
+    #[serde(default)]
+    pub filter: UtxoFilter,
+    /// Cursor of the last notification received by a resuming client
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[borsh(skip)]
+    pub resume_from: Option<Cursor>,
+}
+
+/// Formats the address selection of a scope, an empty selection standing for all addresses
+fn address_selection(addresses: &[Address]) -> String {
+    match addresses.len() {
+        0 => "all".to_string(),
+        1 => format!("{}", addresses[0]),
+        n => format!("{} addresses", n),
+    }
+}
+
+/// Returns true if both address selections hold the same addresses, regardless of their order
+fn same_addresses(a: &[Address], b: &[Address]) -> bool {
+    a.len() == b.len() && a.iter().all(|x| b.contains(x))
 }
 
 impl std::fmt::Display for UtxosChangedScope {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let addresses = match self.addresses.len() {
-            0 => "all".to_string(),
-            1 => format!("{}", self.addresses[0]),
-            n => format!("{} addresses", n),
-        };
-        write!(f, "UtxosChangedScope ({})", addresses)
+        let addresses = address_selection(&self.addresses);
+        match self.filter.is_empty() {
+            true => write!(f, "UtxosChangedScope ({})", addresses)?,
+            false => write!(f, "UtxosChangedScope ({}, filter: {})", addresses, self.filter)?,
+        }
+        if let Some(cursor) = self.resume_from {
+            write!(f, " resuming after cursor {cursor}")?;
+        }
+        Ok(())
     }
 }
 
 impl PartialEq for UtxosChangedScope {
     fn eq(&self, other: &Self) -> bool {
-        self.addresses.len() == other.addresses.len() && self.addresses.iter().all(|x| other.addresses.contains(x))
+        same_addresses(&self.addresses, &other.addresses) && self.filter == other.filter && self.resume_from == other.resume_from
     }
 }
 
@@ -179,23 +267,37 @@ impl Eq for UtxosChangedScope {}
 
 impl UtxosChangedScope {
     pub fn new(addresses: Vec<Address>) -> Self {
-        Self { addresses }
+        Self { addresses, filter: Default::default(), resume_from: None }
+    }
+
+    pub fn with_filter(mut self, filter: UtxoFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn with_resume_from(mut self, resume_from: Option<Cursor>) -> Self {
+        self.resume_from = resume_from;
+        self
     }
 }
 
 impl Serializer for UtxosChangedScope {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &3, writer)?;
         store!(Vec<Address>, &self.addresses, writer)?;
+        serialize!(UtxoFilter, &self.filter, writer)?;
+        store!(Option<Cursor>, &self.resume_from, writer)?;
         Ok(())
     }
 }
 
 impl Deserializer for UtxosChangedScope {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let version = load!(u16, reader)?;
         let addresses = load!(Vec<Address>, reader)?;
-        Ok(Self { addresses })
+        let filter = if version > 1 { deserialize!(UtxoFilter, reader)? } else { Default::default() };
+        let resume_from = if version > 2 { load!(Option<Cursor>, reader)? } else { None };
+        Ok(Self { addresses, filter, resume_from })
     }
 }
 
@@ -266,3 +368,137 @@ impl Deserializer for NewBlockTemplateScope {
         Ok(Self {})
     }
 }
+
+macro_rules! mempool_transaction_scope {
+    ($name:ident, $label:literal) => {
+        /// Scope of a mempool transaction subscription
+        ///
+        /// A transaction is selected if any of its inputs or outputs involves one of the addresses.
+        /// An empty address list selects all transactions.
+        #[derive(Clone, Debug, Default, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+        pub struct $name {
+            pub addresses: Vec<Address>,
+        }
+
+        impl $name {
+            pub fn new(addresses: Vec<Address>) -> Self {
+                Self { addresses }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{} ({})", $label, address_selection(&self.addresses))
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                same_addresses(&self.addresses, &other.addresses)
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl Serializer for $name {
+            fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                store!(u16, &1, writer)?;
+                store!(Vec<Address>, &self.addresses, writer)?;
+                Ok(())
+            }
+        }
+
+        impl Deserializer for $name {
+            fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+                let _version = load!(u16, reader)?;
+                let addresses = load!(Vec<Address>, reader)?;
+                Ok(Self { addresses })
+            }
+        }
+    };
+}
+
+mempool_transaction_scope!(MempoolTransactionAddedScope, "MempoolTransactionAddedScope");
+mempool_transaction_scope!(MempoolTransactionRemovedScope, "MempoolTransactionRemovedScope");
+
+#[derive(Clone, Display, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+pub struct DatabaseCheckpointScope {}
+
+impl Serializer for DatabaseCheckpointScope {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for DatabaseCheckpointScope {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+#[derive(Clone, Display, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+pub struct ConsensusAuditViolationScope {}
+
+impl Serializer for ConsensusAuditViolationScope {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for ConsensusAuditViolationScope {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::test_helpers::get_3_addresses;
+
+    #[test]
+    fn test_scope_serializer() {
+        let filter = UtxoFilter::default().with_amount_range(Some(1000), None);
+        let scopes: Vec<Scope> = vec![
+            UtxosChangedScope::new(get_3_addresses(true)).with_filter(filter.clone()).into(),
+            UtxosChangedScope::new(vec![]).with_resume_from(Some(42)).into(),
+            VirtualChainChangedScope::new(true).into(),
+            VirtualChainChangedScope::new(false).with_resume_from(Some(u64::MAX)).into(),
+            SinkBlueScoreChangedScope::default().into(),
+            MempoolTransactionAddedScope::new(get_3_addresses(false)).into(),
+            MempoolTransactionRemovedScope::default().into(),
+            DatabaseCheckpointScope::default().into(),
+            ConsensusAuditViolationScope::default().into(),
+        ];
+        for scope in scopes {
+            let mut bytes = vec![];
+            <Scope as Serializer>::serialize(&scope, &mut bytes).unwrap();
+            assert_eq!(<Scope as Deserializer>::deserialize(&mut bytes.as_slice()).unwrap(), scope);
+        }
+
+        // Version 1 payloads, predating UtxosChanged filters
+        let addresses = get_3_addresses(true);
+        let mut bytes = vec![];
+        store!(u16, &1, &mut bytes).unwrap();
+        store!(u8, &(EventType::UtxosChanged as u8), &mut bytes).unwrap();
+        store!(Vec<Address>, &addresses, &mut bytes).unwrap();
+        assert_eq!(<Scope as Deserializer>::deserialize(&mut bytes.as_slice()).unwrap(), UtxosChangedScope::new(addresses).into());
+
+        let mut bytes = vec![];
+        store!(u16, &1, &mut bytes).unwrap();
+        store!(u8, &(EventType::VirtualChainChanged as u8), &mut bytes).unwrap();
+        store!(bool, &true, &mut bytes).unwrap();
+        assert_eq!(<Scope as Deserializer>::deserialize(&mut bytes.as_slice()).unwrap(), VirtualChainChangedScope::new(true).into());
+
+        // Version 2 payloads, predating resumable subscriptions
+        let scope: Scope = UtxosChangedScope::new(addresses).with_filter(filter).into();
+        let mut bytes = vec![];
+        store!(u16, &2, &mut bytes).unwrap();
+        store!(Scope, &scope, &mut bytes).unwrap();
+        assert_eq!(<Scope as Deserializer>::deserialize(&mut bytes.as_slice()).unwrap(), scope);
+    }
+}
@@ -18,6 +18,9 @@ impl ArrayBuilder {
                     listener_id,
                     utxos_changed_capacity.unwrap_or_default(),
                 )),
+                EventType::MempoolTransactionAdded | EventType::MempoolTransactionRemoved => {
+                    Arc::new(single::MempoolTransactionSubscription::new(event_type, false, Default::default()))
+                }
                 _ => Arc::new(single::OverallSubscription::new(event_type, false)),
             };
             subscription
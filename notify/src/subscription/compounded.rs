@@ -2,10 +2,11 @@ use crate::{
     address::{error::Result, tracker::Counters},
     events::EventType,
     scope::{Scope, UtxosChangedScope, VirtualChainChangedScope},
-    subscription::{context::SubscriptionContext, Command, Compounded, Mutation, Subscription},
+    subscription::{context::SubscriptionContext, filter::UtxoFilter, Command, Compounded, Mutation, Subscription},
 };
 use calico_addresses::{Address, Prefix};
 use itertools::Itertools;
+use std::collections::HashMap;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct OverallSubscription {
@@ -149,19 +150,25 @@ impl Subscription for VirtualChainChangedSubscription {
     }
 }
 
+/// Compounded UtxosChanged subscriptions
+///
+/// Wildcard subscriptions are counted by filter. The upward subscription is a wildcard one as soon as some
+/// wildcard subscription is active, filtered by a filter covering all the filters if only filtered wildcard
+/// subscriptions are active, otherwise unfiltered since a filter cannot also cover an address set.
 #[derive(Clone, Default, Debug, PartialEq, Eq)]
 pub struct UtxosChangedSubscription {
     all: usize,
+    filtered: HashMap<UtxoFilter, usize>,
     indexes: Counters,
 }
 
 impl UtxosChangedSubscription {
     pub fn new() -> Self {
-        Self { all: 0, indexes: Counters::new() }
+        Self { all: 0, filtered: HashMap::new(), indexes: Counters::new() }
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
-        Self { all: 0, indexes: Counters::with_capacity(capacity) }
+        Self { all: 0, filtered: HashMap::new(), indexes: Counters::with_capacity(capacity) }
     }
 
     pub fn to_addresses(&self, prefix: Prefix, context: &SubscriptionContext) -> Vec<Address> {
@@ -180,50 +187,87 @@ impl UtxosChangedSubscription {
     pub fn unregister(&mut self, addresses: Vec<Address>, context: &SubscriptionContext) -> Vec<Address> {
         context.address_tracker.unregister(&mut self.indexes, addresses)
     }
+
+    fn has_addresses(&self) -> bool {
+        self.indexes.iter().any(|(_, &count)| count > 0)
+    }
+
+    /// Filter of the upward wildcard subscription, `None` if the upward subscription is not a wildcard one
+    fn wildcard_filter(&self) -> Option<UtxoFilter> {
+        if self.all > 0 || (!self.filtered.is_empty() && self.has_addresses()) {
+            Some(UtxoFilter::default())
+        } else if !self.filtered.is_empty() {
+            Some(UtxoFilter::covering(self.filtered.keys()))
+        } else {
+            None
+        }
+    }
+
+    fn wildcard_scope(filter: UtxoFilter) -> Scope {
+        UtxosChangedScope::default().with_filter(filter).into()
+    }
 }
 
 impl Compounded for UtxosChangedSubscription {
     fn compound(&mut self, mutation: Mutation, context: &SubscriptionContext) -> Option<Mutation> {
         assert_eq!(self.event_type(), mutation.event_type());
         if let Scope::UtxosChanged(scope) = mutation.scope {
-            match mutation.command {
+            let former_filter = self.wildcard_filter();
+            let address_mutation = match mutation.command {
                 Command::Start => {
                     if scope.addresses.is_empty() {
-                        // Add All
-                        self.all += 1;
-                        if self.all == 1 {
-                            return Some(Mutation::new(Command::Start, UtxosChangedScope::default().into()));
+                        // Add All(F)
+                        match scope.filter.is_empty() {
+                            true => self.all += 1,
+                            false => *self.filtered.entry(scope.filter).or_default() += 1,
                         }
+                        None
                     } else {
                         // Add(A)
                         let added = self.register(scope.addresses, context).expect("compounded always registers");
-                        if !added.is_empty() && self.all == 0 {
-                            return Some(Mutation::new(Command::Start, UtxosChangedScope::new(added).into()));
-                        }
+                        (!added.is_empty()).then(|| Mutation::new(Command::Start, UtxosChangedScope::new(added).into()))
                     }
                 }
                 Command::Stop => {
                     if !scope.addresses.is_empty() {
                         // Remove(R)
                         let removed = self.unregister(scope.addresses, context);
-                        if !removed.is_empty() && self.all == 0 {
-                            return Some(Mutation::new(Command::Stop, UtxosChangedScope::new(removed).into()));
-                        }
-                    } else {
+                        (!removed.is_empty()).then(|| Mutation::new(Command::Stop, UtxosChangedScope::new(removed).into()))
+                    } else if scope.filter.is_empty() {
                         // Remove All
                         assert!(self.all > 0);
                         self.all -= 1;
-                        if self.all == 0 {
-                            let addresses = self.to_addresses(Prefix::Mainnet, context);
-                            if !addresses.is_empty() {
-                                return Some(Mutation::new(Command::Start, UtxosChangedScope::new(addresses).into()));
-                            } else {
-                                return Some(Mutation::new(Command::Stop, UtxosChangedScope::default().into()));
-                            }
+                        None
+                    } else {
+                        // Remove All(F)
+                        let count = self.filtered.get_mut(&scope.filter).expect("a filtered wildcard subscription is active");
+                        *count -= 1;
+                        if *count == 0 {
+                            self.filtered.remove(&scope.filter);
                         }
+                        None
                     }
                 }
-            }
+            };
+            return match (former_filter, self.wildcard_filter()) {
+                // Address set => address set
+                (None, None) => address_mutation,
+                // Address set => All(F), masking the address set
+                (None, Some(filter)) => Some(Mutation::new(Command::Start, Self::wildcard_scope(filter))),
+                // All(F) => All(G)
+                (Some(former_filter), Some(filter)) => {
+                    (former_filter != filter).then(|| Mutation::new(Command::Start, Self::wildcard_scope(filter)))
+                }
+                // All(F) => address set, revealing it if not empty
+                (Some(_), None) => {
+                    let addresses = self.to_addresses(Prefix::Mainnet, context);
+                    if !addresses.is_empty() {
+                        Some(Mutation::new(Command::Start, UtxosChangedScope::new(addresses).into()))
+                    } else {
+                        Some(Mutation::new(Command::Stop, UtxosChangedScope::default().into()))
+                    }
+                }
+            };
         }
         None
     }
@@ -236,12 +280,14 @@ impl Subscription for UtxosChangedSubscription {
     }
 
     fn active(&self) -> bool {
-        self.all > 0 || !self.indexes.is_empty()
+        self.all > 0 || !self.filtered.is_empty() || self.has_addresses()
     }
 
     fn scope(&self, context: &SubscriptionContext) -> Scope {
-        let addresses = if self.all > 0 { vec![] } else { self.to_addresses(Prefix::Mainnet, context) };
-        Scope::UtxosChanged(UtxosChangedScope::new(addresses))
+        match self.wildcard_filter() {
+            Some(filter) => Self::wildcard_scope(filter),
+            None => UtxosChangedScope::new(self.to_addresses(Prefix::Mainnet, context)).into(),
+        }
     }
 }
 
@@ -255,6 +301,7 @@ mod tests {
         address::{test_helpers::get_3_addresses, tracker::Counter},
         scope::BlockAddedScope,
     };
+    use calico_txscript::script_class::ScriptClass;
     use std::panic::AssertUnwindSafe;
 
     struct Step {
@@ -314,7 +361,7 @@ mod tests {
     #[allow(clippy::redundant_clone)]
     fn test_virtual_chain_changed_compounding() {
         fn m(command: Command, include_accepted_transaction_ids: bool) -> Mutation {
-            Mutation { command, scope: Scope::VirtualChainChanged(VirtualChainChangedScope { include_accepted_transaction_ids }) }
+            Mutation { command, scope: Scope::VirtualChainChanged(VirtualChainChangedScope::new(include_accepted_transaction_ids)) }
         }
         let none = Box::<VirtualChainChangedSubscription>::default;
         let add_all = || m(Command::Start, true);
@@ -399,6 +446,7 @@ mod tests {
             ],
             final_state: Box::new(UtxosChangedSubscription {
                 all: 0,
+                filtered: HashMap::new(),
                 indexes: Counters::with_counters(vec![
                     Counter { index: 0, count: 0, locked: true },
                     Counter { index: 1, count: 0, locked: false },
@@ -413,4 +461,55 @@ mod tests {
         // let result = std::panic::catch_unwind(AssertUnwindSafe(|| state.compound(remove_0(), &test.context)));
         // assert!(result.is_err(), "{}: trying to remove an address when its counter is zero must panic", test.name);
     }
+
+    #[test]
+    #[allow(clippy::redundant_clone)]
+    fn test_utxos_changed_filter_compounding() {
+        let a_stock = get_3_addresses(true);
+        let f = UtxoFilter::default().with_script_classes(vec![ScriptClass::ScriptHash]).with_amount_range(Some(1000), None);
+        let g = UtxoFilter::default().with_script_classes(vec![ScriptClass::NonStandard]);
+        let fg = UtxoFilter::covering([&f, &g]);
+        let m = |command: Command, filter: &UtxoFilter| -> Mutation {
+            Mutation { command, scope: UtxosChangedScope::default().with_filter(filter.clone()).into() }
+        };
+        let m_0 =
+            |command: Command| -> Mutation { Mutation { command, scope: UtxosChangedScope::new(vec![a_stock[0].clone()]).into() } };
+        let none = Box::<UtxosChangedSubscription>::default;
+        let unfiltered = UtxoFilter::default();
+
+        let test = Test {
+            name: "UtxosChanged filtered",
+            context: SubscriptionContext::new(),
+            initial_state: none(),
+            steps: vec![
+                Step { name: "add all(f) 1", mutation: m(Command::Start, &f), result: Some(m(Command::Start, &f)) },
+                Step { name: "add all(f) 2", mutation: m(Command::Start, &f), result: None },
+                Step { name: "add all(g), covering f and g", mutation: m(Command::Start, &g), result: Some(m(Command::Start, &fg)) },
+                Step {
+                    name: "add a0, not covered by filters",
+                    mutation: m_0(Command::Start),
+                    result: Some(m(Command::Start, &unfiltered)),
+                },
+                Step { name: "remove a0", mutation: m_0(Command::Stop), result: Some(m(Command::Start, &fg)) },
+                Step { name: "add all", mutation: m(Command::Start, &unfiltered), result: Some(m(Command::Start, &unfiltered)) },
+                Step { name: "remove all", mutation: m(Command::Stop, &unfiltered), result: Some(m(Command::Start, &fg)) },
+                Step { name: "remove all(f) 2", mutation: m(Command::Stop, &f), result: None },
+                Step { name: "remove all(f) 1", mutation: m(Command::Stop, &f), result: Some(m(Command::Start, &g)) },
+                Step { name: "add a0, masked by all(g)", mutation: m_0(Command::Start), result: Some(m(Command::Start, &unfiltered)) },
+                Step { name: "remove all(g), revealing a0", mutation: m(Command::Stop, &g), result: Some(m_0(Command::Start)) },
+                Step { name: "remove a0", mutation: m_0(Command::Stop), result: Some(m_0(Command::Stop)) },
+            ],
+            final_state: Box::new(UtxosChangedSubscription {
+                all: 0,
+                filtered: HashMap::new(),
+                indexes: Counters::with_counters(vec![Counter { index: 0, count: 0, locked: false }]),
+            }),
+        };
+        let mut state = test.run();
+        assert!(!state.active());
+
+        // Removing an unknown filter must panic
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| state.compound(m(Command::Stop, &f), &test.context)));
+        assert!(result.is_err(), "{}: trying to remove an inactive filter must panic", test.name);
+    }
 }
@@ -2,19 +2,29 @@ use crate::{
     address::tracker::Tracker,
     listener::ListenerId,
     subscription::{
+        filter::UtxoFilter,
         single::{UtxosChangedState, UtxosChangedSubscription},
         DynSubscription,
     },
 };
-use std::{ops::Deref, sync::Arc};
+use parking_lot::Mutex;
+use std::{collections::HashMap, ops::Deref, sync::Arc};
 
 #[cfg(test)]
 use calico_addresses::Address;
 
+/// Broadcasting instances of the filtered wildcard UtxosChanged subscriptions, by filter
+#[derive(Debug)]
+struct FilteredSubscriptions {
+    subscriptions: HashMap<UtxoFilter, DynSubscription>,
+    next_listener_id: ListenerId,
+}
+
 #[derive(Debug)]
 pub struct SubscriptionContextInner {
     pub address_tracker: Tracker,
     pub utxos_changed_subscription_to_all: DynSubscription,
+    utxos_changed_filtered_subscriptions: Mutex<FilteredSubscriptions>,
 }
 
 impl SubscriptionContextInner {
@@ -26,17 +36,43 @@ impl SubscriptionContextInner {
 
     pub fn with_options(max_addresses: Option<usize>) -> Self {
         let address_tracker = Tracker::new(max_addresses);
-        let utxos_changed_subscription_all =
-            Arc::new(UtxosChangedSubscription::new(UtxosChangedState::All, Self::CONTEXT_LISTENER_ID));
-        Self { address_tracker, utxos_changed_subscription_to_all: utxos_changed_subscription_all }
+        Self::with_tracker(address_tracker)
     }
 
     #[cfg(test)]
     pub fn with_addresses(addresses: &[Address]) -> Self {
         let address_tracker = Tracker::with_addresses(addresses);
+        Self::with_tracker(address_tracker)
+    }
+
+    fn with_tracker(address_tracker: Tracker) -> Self {
         let utxos_changed_subscription_all =
             Arc::new(UtxosChangedSubscription::new(UtxosChangedState::All, Self::CONTEXT_LISTENER_ID));
-        Self { address_tracker, utxos_changed_subscription_to_all: utxos_changed_subscription_all }
+        let utxos_changed_filtered_subscriptions =
+            Mutex::new(FilteredSubscriptions { subscriptions: HashMap::new(), next_listener_id: Self::CONTEXT_LISTENER_ID - 1 });
+        Self {
+            address_tracker,
+            utxos_changed_subscription_to_all: utxos_changed_subscription_all,
+            utxos_changed_filtered_subscriptions,
+        }
+    }
+
+    /// Returns the unique broadcasting instance grouping all the wildcard UtxosChanged subscriptions filtered by `filter`
+    pub fn utxos_changed_subscription_to(&self, filter: UtxoFilter) -> DynSubscription {
+        if filter.is_empty() {
+            return self.utxos_changed_subscription_to_all.clone();
+        }
+        let mut filtered = self.utxos_changed_filtered_subscriptions.lock();
+        if let Some(subscription) = filtered.subscriptions.get(&filter) {
+            return subscription.clone();
+        }
+        // Drop the instances no broadcaster refers to anymore
+        filtered.subscriptions.retain(|_, subscription| Arc::strong_count(subscription) > 1);
+        let listener_id = filtered.next_listener_id;
+        filtered.next_listener_id -= 1;
+        let subscription: DynSubscription = Arc::new(UtxosChangedSubscription::with_filter(listener_id, filter.clone()));
+        filtered.subscriptions.insert(filter, subscription.clone());
+        subscription
     }
 }
 
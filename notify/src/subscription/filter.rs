@@ -0,0 +1,306 @@
+//!
+//! Content filters of UtxosChanged subscriptions.
+//!
+//! A [`UtxoFilter`] composes conditions on the script class, the amount and the
+//! script public key prefix of every UTXO carried by a UtxosChanged notification.
+//! A UTXO passes the filter when it meets every condition; a list condition is met
+//! by any of its items and an empty list accepts everything.
+//!
+//! Filters are evaluated by the broadcaster of the notifier holding the listener
+//! subscription, where wildcard subscriptions sharing a same filter are grouped.
+//! The filters of wildcard subscriptions are also propagated upwards, compounded
+//! into a [covering](UtxoFilter::covering) filter, so that a parent notifier only
+//! sends the UTXOs some listener may accept.
+//!
+
+use crate::error::{Error, Result};
+use borsh::{BorshDeserialize, BorshSerialize};
+use calico_consensus_core::tx::ScriptPublicKey;
+use calico_txscript::script_class::ScriptClass;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use workflow_serializer::prelude::*;
+
+/// Maximum number of script public key prefixes in a filter
+pub const MAX_SCRIPT_PREFIXES: usize = 256;
+
+/// Minimum length of the script public key prefixes of a selective filter
+///
+/// Covers the leading opcodes of the standard scripts plus a few bytes of the key or hash.
+pub const MIN_SELECTIVE_SCRIPT_PREFIX_LEN: usize = 5;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UtxoFilter {
+    /// Accepted script classes (any class if empty)
+    #[serde(default)]
+    pub script_classes: Vec<ScriptClass>,
+    /// Minimum amount in sompi, inclusive
+    #[serde(default)]
+    pub min_amount: Option<u64>,
+    /// Maximum amount in sompi, inclusive
+    #[serde(default)]
+    pub max_amount: Option<u64>,
+    /// Accepted script public key prefixes (any script if empty)
+    ///
+    /// A prefix matches scripts of the same version starting with the prefix script bytes.
+    #[serde(default)]
+    pub script_prefixes: Vec<ScriptPublicKey>,
+}
+
+impl UtxoFilter {
+    pub fn with_script_classes(mut self, script_classes: Vec<ScriptClass>) -> Self {
+        self.script_classes = script_classes;
+        self
+    }
+
+    pub fn with_amount_range(mut self, min_amount: Option<u64>, max_amount: Option<u64>) -> Self {
+        self.min_amount = min_amount;
+        self.max_amount = max_amount;
+        self
+    }
+
+    pub fn with_script_prefixes(mut self, script_prefixes: Vec<ScriptPublicKey>) -> Self {
+        self.script_prefixes = script_prefixes;
+        self
+    }
+
+    /// Returns true if the filter accepts every UTXO
+    pub fn is_empty(&self) -> bool {
+        self.script_classes.is_empty() && self.script_prefixes.is_empty() && !self.has_amount_range()
+    }
+
+    /// Returns true if the filter restricts the UTXO amount
+    #[inline(always)]
+    pub fn has_amount_range(&self) -> bool {
+        self.min_amount.is_some_and(|min| min > 0) || self.max_amount.is_some_and(|max| max < u64::MAX)
+    }
+
+    /// Returns true if the filter only accepts a small fraction of all UTXOs, either by
+    /// excluding the pay-to-pubkey script classes, which lock the bulk of the UTXOs, or
+    /// by selecting scripts with prefixes of at least [`MIN_SELECTIVE_SCRIPT_PREFIX_LEN`] bytes.
+    ///
+    /// An amount range alone is never selective.
+    pub fn is_selective(&self) -> bool {
+        let selective_classes = !self.script_classes.is_empty()
+            && self.script_classes.iter().all(|class| !matches!(class, ScriptClass::PubKey | ScriptClass::PubKeyECDSA));
+        let selective_prefixes = !self.script_prefixes.is_empty()
+            && self.script_prefixes.iter().all(|prefix| prefix.script().len() >= MIN_SELECTIVE_SCRIPT_PREFIX_LEN);
+        selective_classes || selective_prefixes
+    }
+
+    /// Returns a filter accepting every UTXO accepted by any of `filters`, possibly more.
+    ///
+    /// Each condition of the result is the union of the same condition of all `filters`,
+    /// dropped if some filter has none or if the union exceeds the filter limits. The
+    /// result does not depend on the order of `filters`.
+    pub fn covering<'a>(filters: impl IntoIterator<Item = &'a UtxoFilter>) -> Self {
+        let mut filters = filters.into_iter();
+        let Some(first) = filters.next() else {
+            return Self::default();
+        };
+        let mut covering = first.clone();
+        for filter in filters {
+            if covering.script_classes.is_empty() || filter.script_classes.is_empty() {
+                covering.script_classes.clear();
+            } else {
+                for class in filter.script_classes.iter() {
+                    if !covering.script_classes.contains(class) {
+                        covering.script_classes.push(class.clone());
+                    }
+                }
+            }
+            covering.min_amount = covering.min_amount.zip(filter.min_amount).map(|(a, b)| a.min(b));
+            covering.max_amount = covering.max_amount.zip(filter.max_amount).map(|(a, b)| a.max(b));
+            if covering.script_prefixes.is_empty() || filter.script_prefixes.is_empty() {
+                covering.script_prefixes.clear();
+            } else {
+                for prefix in filter.script_prefixes.iter() {
+                    if !covering.script_prefixes.contains(prefix) {
+                        covering.script_prefixes.push(prefix.clone());
+                    }
+                }
+            }
+        }
+        if covering.script_prefixes.len() > MAX_SCRIPT_PREFIXES {
+            covering.script_prefixes.clear();
+        }
+        // Keep the result independent of the order of `filters`
+        covering.script_classes.sort_by_key(|class| class.clone() as u8);
+        covering.script_prefixes.sort_by(|a, b| (a.version(), a.script()).cmp(&(b.version(), b.script())));
+        covering
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if let (Some(min), Some(max)) = (self.min_amount, self.max_amount) {
+            if min > max {
+                return Err(Error::InvalidUtxoFilter(format!("minimum amount {min} exceeds maximum amount {max}")));
+            }
+        }
+        if self.script_prefixes.len() > MAX_SCRIPT_PREFIXES {
+            return Err(Error::InvalidUtxoFilter(format!(
+                "{} script prefixes exceed the maximum of {MAX_SCRIPT_PREFIXES}",
+                self.script_prefixes.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns true if the script class and prefix conditions accept `script_public_key`
+    pub fn matches_script(&self, script_public_key: &ScriptPublicKey) -> bool {
+        (self.script_classes.is_empty() || self.script_classes.contains(&ScriptClass::from_script(script_public_key)))
+            && (self.script_prefixes.is_empty()
+                || self.script_prefixes.iter().any(|prefix| {
+                    prefix.version() == script_public_key.version() && script_public_key.script().starts_with(prefix.script())
+                }))
+    }
+
+    /// Returns true if the amount range accepts `amount`
+    #[inline(always)]
+    pub fn matches_amount(&self, amount: u64) -> bool {
+        !self.min_amount.is_some_and(|min| amount < min) && !self.max_amount.is_some_and(|max| amount > max)
+    }
+
+    /// Returns true if the filter accepts a UTXO of `amount` locked by `script_public_key`
+    pub fn matches(&self, script_public_key: &ScriptPublicKey, amount: u64) -> bool {
+        self.matches_amount(amount) && self.matches_script(script_public_key)
+    }
+}
+
+impl Display for UtxoFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut conditions = vec![];
+        if !self.script_classes.is_empty() {
+            conditions
+                .push(format!("classes {}", self.script_classes.iter().map(|class| class.to_string()).collect::<Vec<_>>().join("|")));
+        }
+        if self.has_amount_range() {
+            conditions.push(format!("amount {}..={}", self.min_amount.unwrap_or_default(), self.max_amount.unwrap_or(u64::MAX)));
+        }
+        if !self.script_prefixes.is_empty() {
+            conditions.push(format!("{} prefixes", self.script_prefixes.len()));
+        }
+        match conditions.is_empty() {
+            true => write!(f, "none"),
+            false => write!(f, "{}", conditions.join(", ")),
+        }
+    }
+}
+
+impl Serializer for UtxoFilter {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Vec<ScriptClass>, &self.script_classes, writer)?;
+        store!(Option<u64>, &self.min_amount, writer)?;
+        store!(Option<u64>, &self.max_amount, writer)?;
+        store!(Vec<ScriptPublicKey>, &self.script_prefixes, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for UtxoFilter {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let script_classes = load!(Vec<ScriptClass>, reader)?;
+        let min_amount = load!(Option<u64>, reader)?;
+        let max_amount = load!(Option<u64>, reader)?;
+        let script_prefixes = load!(Vec<ScriptPublicKey>, reader)?;
+        Ok(Self { script_classes, min_amount, max_amount, script_prefixes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calico_addresses::{Address, Prefix, Version};
+    use calico_txscript::pay_to_address_script;
+
+    #[test]
+    fn test_utxo_filter() {
+        let p2pk = pay_to_address_script(&Address::new(Prefix::Mainnet, Version::PubKey, &[1u8; 32]));
+        let p2sh = pay_to_address_script(&Address::new(Prefix::Mainnet, Version::ScriptHash, &[2u8; 32]));
+
+        let filter = UtxoFilter::default();
+        assert!(filter.is_empty());
+        assert!(filter.matches(&p2pk, 0) && filter.matches(&p2sh, u64::MAX));
+        assert!(UtxoFilter::default().with_amount_range(Some(0), Some(u64::MAX)).is_empty());
+
+        // Conditions of different kinds are all required
+        let filter = UtxoFilter::default().with_script_classes(vec![ScriptClass::ScriptHash]).with_amount_range(Some(1000), None);
+        assert!(!filter.is_empty());
+        assert!(filter.matches(&p2sh, 1000));
+        assert!(!filter.matches(&p2sh, 999));
+        assert!(!filter.matches(&p2pk, 1000));
+
+        // Any item of a list condition is sufficient
+        let filter = UtxoFilter::default().with_script_classes(vec![ScriptClass::PubKey, ScriptClass::ScriptHash]);
+        assert!(filter.matches(&p2pk, 1) && filter.matches(&p2sh, 1));
+
+        // Prefixes match the script version and the leading script bytes
+        let prefix = ScriptPublicKey::from_vec(p2pk.version(), p2pk.script()[..4].to_vec());
+        let filter = UtxoFilter::default().with_script_prefixes(vec![prefix.clone()]).with_amount_range(None, Some(5000));
+        assert!(filter.matches(&p2pk, 5000));
+        assert!(!filter.matches(&p2pk, 5001));
+        assert!(!filter.matches(&p2sh, 5000));
+        let other_version = ScriptPublicKey::from_vec(p2pk.version() + 1, prefix.script().to_vec());
+        assert!(!UtxoFilter::default().with_script_prefixes(vec![other_version]).matches(&p2pk, 1));
+
+        assert!(UtxoFilter::default().with_amount_range(Some(10), Some(9)).validate().is_err());
+        assert!(UtxoFilter::default().with_script_prefixes(vec![prefix.clone(); MAX_SCRIPT_PREFIXES + 1]).validate().is_err());
+
+        // Selectivity
+        assert!(!UtxoFilter::default().is_selective());
+        assert!(!UtxoFilter::default().with_amount_range(Some(1), None).is_selective());
+        let all_classes = vec![ScriptClass::NonStandard, ScriptClass::PubKey, ScriptClass::PubKeyECDSA, ScriptClass::ScriptHash];
+        assert!(!UtxoFilter::default().with_script_classes(all_classes).is_selective());
+        assert!(!UtxoFilter::default().with_script_classes(vec![ScriptClass::PubKey]).is_selective());
+        assert!(UtxoFilter::default().with_script_classes(vec![ScriptClass::ScriptHash]).is_selective());
+        assert!(UtxoFilter::default().with_script_prefixes(vec![prefix.clone()]).is_selective());
+        let short_prefix = ScriptPublicKey::from_vec(p2pk.version(), p2pk.script()[..1].to_vec());
+        assert!(!UtxoFilter::default().with_script_prefixes(vec![short_prefix, prefix.clone()]).is_selective());
+    }
+
+    #[test]
+    fn test_utxo_filter_covering() {
+        let p2pk = pay_to_address_script(&Address::new(Prefix::Mainnet, Version::PubKey, &[1u8; 32]));
+        let p2sh = pay_to_address_script(&Address::new(Prefix::Mainnet, Version::ScriptHash, &[2u8; 32]));
+        let p2pk_prefix = ScriptPublicKey::from_vec(p2pk.version(), p2pk.script()[..6].to_vec());
+        let p2sh_prefix = ScriptPublicKey::from_vec(p2sh.version(), p2sh.script()[..6].to_vec());
+
+        assert_eq!(UtxoFilter::covering([]), UtxoFilter::default());
+
+        let a = UtxoFilter::default().with_script_classes(vec![ScriptClass::ScriptHash]).with_amount_range(Some(1000), Some(2000));
+        let b = UtxoFilter::default().with_script_classes(vec![ScriptClass::NonStandard]).with_amount_range(Some(500), Some(1500));
+        assert_eq!(UtxoFilter::covering([&a]), a);
+        let covering = UtxoFilter::covering([&a, &b, &a]);
+        assert_eq!(
+            covering,
+            UtxoFilter::default()
+                .with_script_classes(vec![ScriptClass::NonStandard, ScriptClass::ScriptHash])
+                .with_amount_range(Some(500), Some(2000))
+        );
+        assert!(covering.is_selective());
+        assert_eq!(UtxoFilter::covering([&b, &a]), covering);
+
+        // A missing condition in any filter is missing in the covering filter
+        let c = UtxoFilter::default().with_script_prefixes(vec![p2pk_prefix.clone()]).with_amount_range(Some(10), None);
+        let covering = UtxoFilter::covering([&a, &c]);
+        assert_eq!(covering, UtxoFilter::default().with_amount_range(Some(10), None));
+        assert!(covering.validate().is_ok());
+
+        let d = UtxoFilter::default().with_script_prefixes(vec![p2sh_prefix.clone()]);
+        let covering = UtxoFilter::covering([&c, &d]);
+        assert_eq!(covering, UtxoFilter::default().with_script_prefixes(vec![p2pk_prefix.clone(), p2sh_prefix.clone()]));
+
+        // Every UTXO accepted by some filter is accepted by the covering filter
+        let filters = [a, b, c, d];
+        let covering = UtxoFilter::covering(filters.iter());
+        for spk in [&p2pk, &p2sh] {
+            for amount in [0, 10, 500, 1000, 1500, 2000, 2001, u64::MAX] {
+                if filters.iter().any(|filter| filter.matches(spk, amount)) {
+                    assert!(covering.matches(spk, amount));
+                }
+            }
+        }
+    }
+}
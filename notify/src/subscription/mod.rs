@@ -13,6 +13,7 @@ use std::{
 pub mod array;
 pub mod compounded;
 pub mod context;
+pub mod filter;
 pub mod single;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
@@ -200,9 +201,9 @@ impl MutateSingle for Arc<dyn Single> {
 pub trait BroadcastingSingle: Deref<Target = dyn Single> {
     /// Returns the broadcasting instance of the subscription.
     ///
-    /// This is used for grouping all the wildcard UtxosChanged subscriptions under
-    /// the same unique instance in the broadcaster plans, allowing message optimizations
-    /// during broadcasting of the notifications.
+    /// This is used for grouping all the wildcard UtxosChanged subscriptions sharing a
+    /// same filter under a unique instance in the broadcaster plans, allowing message
+    /// optimizations during broadcasting of the notifications.
     fn broadcasting(self, context: &SubscriptionContext) -> DynSubscription;
 }
 
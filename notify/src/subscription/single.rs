@@ -3,10 +3,10 @@ use crate::{
     error::Result,
     events::EventType,
     listener::ListenerId,
-    scope::{Scope, UtxosChangedScope, VirtualChainChangedScope},
+    scope::{MempoolTransactionAddedScope, MempoolTransactionRemovedScope, Scope, UtxosChangedScope, VirtualChainChangedScope},
     subscription::{
-        context::SubscriptionContext, BroadcastingSingle, Command, DynSubscription, Mutation, MutationOutcome, MutationPolicies,
-        Single, Subscription, UtxosChangedMutationPolicy,
+        context::SubscriptionContext, filter::UtxoFilter, BroadcastingSingle, Command, DynSubscription, Mutation, MutationOutcome,
+        MutationPolicies, Single, Subscription, UtxosChangedMutationPolicy,
     },
 };
 use calico_addresses::{Address, Prefix};
@@ -15,7 +15,7 @@ use calico_core::trace;
 use itertools::Itertools;
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::{
-    collections::hash_set,
+    collections::{hash_set, BTreeSet},
     fmt::{Debug, Display},
     hash::{Hash, Hasher},
     sync::{
@@ -236,12 +236,22 @@ pub struct UtxosChangedSubscriptionData {
     ///
     /// Can be mutated without affecting neither equality nor hash of the struct
     indexes: Indexes,
+
+    /// Content filter of the selected UTXOs
+    ///
+    /// Can be mutated without affecting neither equality nor hash of the struct
+    filter: UtxoFilter,
 }
 
 impl UtxosChangedSubscriptionData {
     fn with_capacity(state: UtxosChangedState, capacity: usize) -> Self {
         let indexes = Indexes::with_capacity(capacity);
-        Self { state, indexes }
+        Self { state, indexes, filter: Default::default() }
+    }
+
+    #[inline(always)]
+    pub fn state(&self) -> UtxosChangedState {
+        self.state
     }
 
     #[inline(always)]
@@ -253,6 +263,16 @@ impl UtxosChangedSubscriptionData {
         context.address_tracker.contains(&self.indexes, spk)
     }
 
+    #[inline(always)]
+    pub fn filter(&self) -> &UtxoFilter {
+        &self.filter
+    }
+
+    /// Returns true if a UTXO of `amount` locked by `spk` is selected by both the address set and the filter
+    pub fn selects(&self, spk: &ScriptPublicKey, amount: u64, context: &SubscriptionContext) -> bool {
+        (self.state == UtxosChangedState::All || self.contains(spk, context)) && self.filter.matches(spk, amount)
+    }
+
     pub fn len(&self) -> usize {
         self.indexes.len()
     }
@@ -292,16 +312,21 @@ impl UtxosChangedSubscriptionData {
         removed
     }
 
+    /// Returns true if the subscription selects all UTXOs, i.e. all addresses and no filter
     pub fn to_all(&self) -> bool {
-        matches!(self.state, UtxosChangedState::All)
+        matches!(self.state, UtxosChangedState::All) && self.filter.is_empty()
     }
 }
 
 impl Display for UtxosChangedSubscriptionData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.state {
-            UtxosChangedState::None | UtxosChangedState::All => write!(f, "{}", self.state),
-            UtxosChangedState::Selected => write!(f, "{}({})", self.state, self.indexes.len()),
+            UtxosChangedState::None | UtxosChangedState::All => write!(f, "{}", self.state)?,
+            UtxosChangedState::Selected => write!(f, "{}({})", self.state, self.indexes.len())?,
+        }
+        match self.filter.is_empty() {
+            true => Ok(()),
+            false => write!(f, " filtered by {}", self.filter),
         }
     }
 }
@@ -333,6 +358,13 @@ impl UtxosChangedSubscription {
         subscription
     }
 
+    /// Creates a wildcard subscription filtered by `filter`
+    pub fn with_filter(listener_id: ListenerId, filter: UtxoFilter) -> Self {
+        let subscription = Self::new(UtxosChangedState::All, listener_id);
+        subscription.data_mut().filter = filter;
+        subscription
+    }
+
     #[cfg(test)]
     pub fn with_addresses(active: bool, addresses: Vec<Address>, listener_id: ListenerId, context: &SubscriptionContext) -> Self {
         let state = match (active, addresses.is_empty()) {
@@ -359,7 +391,12 @@ impl UtxosChangedSubscription {
     }
 
     pub fn to_all(&self) -> bool {
-        matches!(self.data().state, UtxosChangedState::All)
+        self.data().to_all()
+    }
+
+    /// Scope of the upward mutations of a wildcard subscription
+    fn wildcard_scope(filter: &UtxoFilter) -> Scope {
+        UtxosChangedScope::default().with_filter(filter.clone()).into()
     }
 }
 
@@ -415,11 +452,20 @@ impl Single for UtxosChangedSubscription {
         context: &SubscriptionContext,
     ) -> Result<MutationOutcome> {
         assert_eq!(self.event_type(), mutation.event_type());
-        let outcome = if let Scope::UtxosChanged(scope) = mutation.scope {
+        let outcome = if let Scope::UtxosChanged(mut scope) = mutation.scope {
             let mut data = self.data_mut();
             let state = data.state;
             let mutation_type = UtxosChangedMutation::from((mutation.command, &scope));
-            match (state, mutation_type) {
+
+            // A start command sets the filter. The filter of a wildcard subscription is propagated upwards
+            // with its start and stop mutations and determines the broadcasting group of the subscription.
+            let former_filter = data.filter.clone();
+            if mutation.active() {
+                scope.filter.validate()?;
+                data.filter = std::mem::take(&mut scope.filter);
+            }
+
+            let outcome = match (state, mutation_type) {
                 (UtxosChangedState::None, UtxosChangedMutation::None | UtxosChangedMutation::Remove) => {
                     // State None + Mutations None or Remove(R) => No change
                     MutationOutcome::new()
@@ -441,7 +487,7 @@ impl Single for UtxosChangedSubscription {
                 (UtxosChangedState::None, UtxosChangedMutation::All) => {
                     // State None + Mutation All => Mutated new state All
                     data.update_state(UtxosChangedState::All);
-                    let mutations = vec![Mutation::new(mutation.command, UtxosChangedScope::default().into())];
+                    let mutations = vec![Mutation::new(mutation.command, Self::wildcard_scope(&data.filter))];
                     MutationOutcome::with_mutated(current.clone(), mutations)
                 }
                 (UtxosChangedState::Selected, UtxosChangedMutation::None) => {
@@ -511,16 +557,20 @@ impl Single for UtxosChangedSubscription {
                     let mutations = match policies.utxo_changed {
                         UtxosChangedMutationPolicy::AddressSet => vec![
                             Mutation::new(Command::Stop, UtxosChangedScope::new(removed).into()),
-                            Mutation::new(Command::Start, UtxosChangedScope::default().into()),
+                            Mutation::new(Command::Start, Self::wildcard_scope(&data.filter)),
+                        ],
+                        UtxosChangedMutationPolicy::Wildcard if data.filter.is_empty() => vec![],
+                        UtxosChangedMutationPolicy::Wildcard => vec![
+                            Mutation::new(Command::Start, Self::wildcard_scope(&data.filter)),
+                            Mutation::new(Command::Stop, UtxosChangedScope::default().into()),
                         ],
-                        UtxosChangedMutationPolicy::Wildcard => vec![],
                     };
                     MutationOutcome::with_mutated(current.clone(), mutations)
                 }
                 (UtxosChangedState::All, UtxosChangedMutation::None) => {
                     // State All + Mutation None => Mutated new state None
                     data.update_state(UtxosChangedState::None);
-                    let mutations = vec![Mutation::new(Command::Stop, UtxosChangedScope::default().into())];
+                    let mutations = vec![Mutation::new(Command::Stop, Self::wildcard_scope(&former_filter))];
                     MutationOutcome::with_mutated(current.clone(), mutations)
                 }
                 (UtxosChangedState::All, UtxosChangedMutation::Remove) => {
@@ -534,17 +584,33 @@ impl Single for UtxosChangedSubscription {
                     let mutations = match policies.utxo_changed {
                         UtxosChangedMutationPolicy::AddressSet => vec![
                             Mutation::new(Command::Start, UtxosChangedScope::new(added).into()),
-                            Mutation::new(Command::Stop, UtxosChangedScope::default().into()),
+                            Mutation::new(Command::Stop, Self::wildcard_scope(&former_filter)),
+                        ],
+                        UtxosChangedMutationPolicy::Wildcard if former_filter.is_empty() => vec![],
+                        UtxosChangedMutationPolicy::Wildcard => vec![
+                            Mutation::new(Command::Start, UtxosChangedScope::default().into()),
+                            Mutation::new(Command::Stop, Self::wildcard_scope(&former_filter)),
                         ],
-                        UtxosChangedMutationPolicy::Wildcard => vec![],
                     };
                     MutationOutcome::with_mutated(current.clone(), mutations)
                 }
+                (UtxosChangedState::All, UtxosChangedMutation::All) if data.filter != former_filter => {
+                    // State All(F) + Mutation All(G) => Mutated new state All(G), registered in another broadcasting group
+                    let mutations = vec![
+                        Mutation::new(Command::Start, Self::wildcard_scope(&data.filter)),
+                        Mutation::new(Command::Stop, Self::wildcard_scope(&former_filter)),
+                    ];
+                    MutationOutcome::with_mutated(current.clone(), mutations)
+                }
                 (UtxosChangedState::All, UtxosChangedMutation::All) => {
                     // State All <= Mutation All
                     MutationOutcome::new()
                 }
+            };
+            if !data.state.active() {
+                data.filter = Default::default();
             }
+            outcome
         } else {
             MutationOutcome::new()
         };
@@ -563,7 +629,133 @@ impl Subscription for UtxosChangedSubscription {
 
     fn scope(&self, context: &SubscriptionContext) -> Scope {
         // TODO: consider using a provided prefix
-        UtxosChangedScope::new(self.data().to_addresses(Prefix::Mainnet, context)).into()
+        let data = self.data();
+        UtxosChangedScope::new(data.to_addresses(Prefix::Mainnet, context)).with_filter(data.filter.clone()).into()
+    }
+}
+
+/// Subscription to MempoolTransactionAdded or MempoolTransactionRemoved notifications
+///
+/// The address selection is kept by the subscription and never propagated upwards, where the subscription
+/// is reduced to all or nothing. A stop command with some addresses has no effect on a subscription to all
+/// transactions.
+#[derive(Eq, PartialEq, Hash, Clone, Debug)]
+pub struct MempoolTransactionSubscription {
+    event_type: EventType,
+    active: bool,
+    /// Selected addresses, all if empty
+    addresses: BTreeSet<Address>,
+}
+
+impl MempoolTransactionSubscription {
+    pub fn new(event_type: EventType, active: bool, addresses: BTreeSet<Address>) -> Self {
+        assert!(matches!(event_type, EventType::MempoolTransactionAdded | EventType::MempoolTransactionRemoved));
+        Self { event_type, active, addresses }
+    }
+
+    /// Returns true if the subscription selects all transactions
+    pub fn to_all(&self) -> bool {
+        self.active && self.addresses.is_empty()
+    }
+
+    /// Returns true if a transaction involving `addresses` is selected by the subscription
+    pub fn selects<'a>(&self, mut addresses: impl Iterator<Item = &'a Address>) -> bool {
+        self.active && (self.addresses.is_empty() || addresses.any(|x| self.addresses.contains(x)))
+    }
+
+    fn scope_addresses(scope: &Scope) -> &[Address] {
+        match scope {
+            Scope::MempoolTransactionAdded(scope) => &scope.addresses,
+            Scope::MempoolTransactionRemoved(scope) => &scope.addresses,
+            _ => panic!("mempool transaction scope expected, got {scope}"),
+        }
+    }
+
+    fn mutated(&self, active: bool, addresses: BTreeSet<Address>) -> DynSubscription {
+        Arc::new(Self::new(self.event_type, active, addresses))
+    }
+}
+
+impl Single for MempoolTransactionSubscription {
+    fn apply_mutation(
+        &self,
+        _: &Arc<dyn Single>,
+        mutation: Mutation,
+        _: MutationPolicies,
+        _: &SubscriptionContext,
+    ) -> Result<MutationOutcome> {
+        assert_eq!(self.event_type(), mutation.event_type());
+        let addresses = Self::scope_addresses(&mutation.scope);
+        let outcome = match (mutation.command, addresses.is_empty()) {
+            (Command::Start, true) => {
+                if !self.active {
+                    MutationOutcome::with_mutated(self.mutated(true, BTreeSet::new()), vec![mutation])
+                } else if !self.addresses.is_empty() {
+                    MutationOutcome::with_mutated(self.mutated(true, BTreeSet::new()), vec![])
+                } else {
+                    MutationOutcome::new()
+                }
+            }
+            (Command::Start, false) => {
+                if !self.active {
+                    let mutated = self.mutated(true, addresses.iter().cloned().collect());
+                    MutationOutcome::with_mutated(mutated, vec![Mutation::new(Command::Start, self.event_type.into())])
+                } else if self.addresses.is_empty() {
+                    MutationOutcome::new()
+                } else {
+                    let mut selection = self.addresses.clone();
+                    selection.extend(addresses.iter().cloned());
+                    match selection.len() == self.addresses.len() {
+                        true => MutationOutcome::new(),
+                        false => MutationOutcome::with_mutated(self.mutated(true, selection), vec![]),
+                    }
+                }
+            }
+            (Command::Stop, true) => match self.active {
+                true => MutationOutcome::with_mutated(self.mutated(false, BTreeSet::new()), vec![mutation]),
+                false => MutationOutcome::new(),
+            },
+            (Command::Stop, false) => {
+                if !self.active || self.addresses.is_empty() {
+                    MutationOutcome::new()
+                } else {
+                    let mut selection = self.addresses.clone();
+                    addresses.iter().for_each(|x| {
+                        selection.remove(x);
+                    });
+                    if selection.len() == self.addresses.len() {
+                        MutationOutcome::new()
+                    } else if selection.is_empty() {
+                        let mutated = self.mutated(false, selection);
+                        MutationOutcome::with_mutated(mutated, vec![Mutation::new(Command::Stop, self.event_type.into())])
+                    } else {
+                        MutationOutcome::with_mutated(self.mutated(true, selection), vec![])
+                    }
+                }
+            }
+        };
+        Ok(outcome)
+    }
+}
+
+impl Subscription for MempoolTransactionSubscription {
+    #[inline(always)]
+    fn event_type(&self) -> EventType {
+        self.event_type
+    }
+
+    #[inline(always)]
+    fn active(&self) -> bool {
+        self.active
+    }
+
+    fn scope(&self, _context: &SubscriptionContext) -> Scope {
+        let addresses = self.addresses.iter().cloned().collect_vec();
+        match self.event_type {
+            EventType::MempoolTransactionAdded => MempoolTransactionAddedScope::new(addresses).into(),
+            EventType::MempoolTransactionRemoved => MempoolTransactionRemovedScope::new(addresses).into(),
+            _ => unreachable!(),
+        }
     }
 }
 
@@ -572,9 +764,13 @@ impl BroadcastingSingle for DynSubscription {
         match self.event_type() {
             EventType::UtxosChanged => {
                 let utxos_changed_subscription = self.as_any().downcast_ref::<UtxosChangedSubscription>().unwrap();
-                match utxos_changed_subscription.to_all() {
-                    true => context.utxos_changed_subscription_to_all.clone(),
-                    false => self,
+                let filter = {
+                    let data = utxos_changed_subscription.data();
+                    (data.state == UtxosChangedState::All).then(|| data.filter.clone())
+                };
+                match filter {
+                    Some(filter) => context.utxos_changed_subscription_to(filter),
+                    None => self,
                 }
             }
             _ => self,
@@ -820,7 +1016,7 @@ mod tests {
             Arc::new(VirtualChainChangedSubscription { active, include_accepted_transaction_ids })
         }
         fn m(command: Command, include_accepted_transaction_ids: bool) -> Mutation {
-            Mutation { command, scope: Scope::VirtualChainChanged(VirtualChainChangedScope { include_accepted_transaction_ids }) }
+            Mutation { command, scope: Scope::VirtualChainChanged(VirtualChainChangedScope::new(include_accepted_transaction_ids)) }
         }
 
         // Subscriptions
@@ -1076,4 +1272,179 @@ mod tests {
         ]);
         tests.run(&context)
     }
+
+    #[test]
+    fn test_utxos_changed_filter_mutation() {
+        let context = SubscriptionContext::new();
+        let filter = UtxoFilter::default().with_amount_range(Some(1000), None);
+        let other_filter = UtxoFilter::default().with_amount_range(Some(2000), None);
+        let all = |command: Command, filter: &UtxoFilter| {
+            Mutation::new(command, UtxosChangedScope::default().with_filter(filter.clone()).into())
+        };
+        let mut subscription: DynSubscription = Arc::new(UtxosChangedSubscription::new(UtxosChangedState::None, 1));
+        let mut other: DynSubscription = Arc::new(UtxosChangedSubscription::new(UtxosChangedState::None, 2));
+
+        // The filter of a wildcard subscription is propagated upwards
+        let outcome = subscription.mutate(all(Command::Start, &filter), Default::default(), &context).unwrap();
+        assert!(outcome.has_new_state());
+        assert_eq!(outcome.mutations, vec![all(Command::Start, &filter)]);
+        let utxos_changed = subscription.as_any().downcast_ref::<UtxosChangedSubscription>().unwrap();
+        assert_eq!(utxos_changed.state(), UtxosChangedState::All);
+        assert!(!utxos_changed.to_all());
+        assert_eq!(subscription.scope(&context), UtxosChangedScope::default().with_filter(filter.clone()).into());
+
+        // Wildcard subscriptions sharing a same filter share a same broadcasting instance
+        let broadcasting = subscription.clone().broadcasting(&context);
+        assert!(!Arc::ptr_eq(&broadcasting, &context.utxos_changed_subscription_to_all));
+        other.mutate(all(Command::Start, &filter), Default::default(), &context).unwrap();
+        assert!(Arc::ptr_eq(&other.clone().broadcasting(&context), &broadcasting));
+
+        // Changing the filter registers the subscription anew and replaces the upward filter
+        let outcome = other.mutate(all(Command::Start, &other_filter), Default::default(), &context).unwrap();
+        assert!(outcome.has_new_state());
+        assert_eq!(outcome.mutations, vec![all(Command::Start, &other_filter), all(Command::Stop, &filter)]);
+        assert!(!Arc::ptr_eq(&other.clone().broadcasting(&context), &broadcasting));
+        let outcome = other.mutate(all(Command::Start, &other_filter), Default::default(), &context).unwrap();
+        assert!(!outcome.has_changes());
+
+        // Dropping the filter of a wildcard subscription groups it with the unfiltered ones
+        let outcome = subscription.mutate(all(Command::Start, &UtxoFilter::default()), Default::default(), &context).unwrap();
+        assert!(outcome.has_new_state());
+        assert_eq!(outcome.mutations, vec![all(Command::Start, &UtxoFilter::default()), all(Command::Stop, &filter)]);
+        assert!(Arc::ptr_eq(&subscription.clone().broadcasting(&context), &context.utxos_changed_subscription_to_all));
+
+        // Stopping the subscription stops the upward filtered subscription
+        let outcome = other.mutate(all(Command::Stop, &UtxoFilter::default()), Default::default(), &context).unwrap();
+        assert!(outcome.has_new_state());
+        assert_eq!(outcome.mutations, vec![all(Command::Stop, &other_filter)]);
+
+        // Under the wildcard policy, an address set is reduced to an unfiltered wildcard
+        let policies = MutationPolicies::new(UtxosChangedMutationPolicy::Wildcard);
+        let mut subscription: DynSubscription = Arc::new(UtxosChangedSubscription::new(UtxosChangedState::None, 3));
+        let addresses = get_3_addresses(false);
+        subscription.mutate(Mutation::new(Command::Start, UtxosChangedScope::new(addresses).into()), policies, &context).unwrap();
+        let outcome = subscription.mutate(all(Command::Start, &filter), policies, &context).unwrap();
+        assert_eq!(outcome.mutations, vec![all(Command::Start, &filter), all(Command::Stop, &UtxoFilter::default())]);
+
+        // Invalid filters are rejected
+        let invalid = UtxoFilter::default().with_amount_range(Some(2), Some(1));
+        assert!(subscription.mutate(all(Command::Start, &invalid), Default::default(), &context).is_err());
+    }
+
+    #[test]
+    fn test_mempool_transaction_mutation() {
+        let context = SubscriptionContext::new();
+        let a = |indexes: &[usize]| -> Vec<Address> {
+            let addresses = get_3_addresses(true);
+            indexes.iter().map(|x| addresses[*x].clone()).collect()
+        };
+        let s = |active: bool, indexes: &[usize]| -> DynSubscription {
+            Arc::new(MempoolTransactionSubscription::new(EventType::MempoolTransactionAdded, active, a(indexes).into_iter().collect()))
+        };
+        let m = |command: Command, indexes: &[usize]| -> Mutation {
+            Mutation { command, scope: Scope::MempoolTransactionAdded(MempoolTransactionAddedScope::new(a(indexes))) }
+        };
+
+        // Subscriptions
+        let none = || s(false, &[]);
+        let all = || s(true, &[]);
+        let selected_0 = || s(true, &[0]);
+        let selected_01 = || s(true, &[0, 1]);
+
+        // Mutations
+        let start_all = || m(Command::Start, &[]);
+        let stop_all = || m(Command::Stop, &[]);
+        let start_0 = || m(Command::Start, &[0]);
+        let start_1 = || m(Command::Start, &[1]);
+        let stop_0 = || m(Command::Stop, &[0]);
+        let stop_01 = || m(Command::Stop, &[0, 1]);
+
+        // Tests
+        let tests = MutationTests::new(vec![
+            MutationTest {
+                name: "MempoolTransactionSubscription None to All",
+                state: none(),
+                mutation: start_all(),
+                new_state: all(),
+                outcome: MutationOutcome::with_mutated(all(), vec![start_all()]),
+            },
+            MutationTest {
+                name: "MempoolTransactionSubscription None to Selected 0 (propagated as all)",
+                state: none(),
+                mutation: start_0(),
+                new_state: selected_0(),
+                outcome: MutationOutcome::with_mutated(selected_0(), vec![start_all()]),
+            },
+            MutationTest {
+                name: "MempoolTransactionSubscription None to None (stop set)",
+                state: none(),
+                mutation: stop_0(),
+                new_state: none(),
+                outcome: MutationOutcome::new(),
+            },
+            MutationTest {
+                name: "MempoolTransactionSubscription Selected 0 to 01 (add set)",
+                state: selected_0(),
+                mutation: start_1(),
+                new_state: selected_01(),
+                outcome: MutationOutcome::with_mutated(selected_01(), vec![]),
+            },
+            MutationTest {
+                name: "MempoolTransactionSubscription Selected 01 to 01 (add set with total intersection)",
+                state: selected_01(),
+                mutation: start_1(),
+                new_state: selected_01(),
+                outcome: MutationOutcome::new(),
+            },
+            MutationTest {
+                name: "MempoolTransactionSubscription Selected 01 to All (add all)",
+                state: selected_01(),
+                mutation: start_all(),
+                new_state: all(),
+                outcome: MutationOutcome::with_mutated(all(), vec![]),
+            },
+            MutationTest {
+                name: "MempoolTransactionSubscription Selected 01 to 0 (remove set)",
+                state: selected_01(),
+                mutation: m(Command::Stop, &[1, 2]),
+                new_state: selected_0(),
+                outcome: MutationOutcome::with_mutated(selected_0(), vec![]),
+            },
+            MutationTest {
+                name: "MempoolTransactionSubscription Selected 01 to None (remove superset)",
+                state: selected_01(),
+                mutation: stop_01(),
+                new_state: none(),
+                outcome: MutationOutcome::with_mutated(none(), vec![stop_all()]),
+            },
+            MutationTest {
+                name: "MempoolTransactionSubscription All to All (remove set)",
+                state: all(),
+                mutation: stop_0(),
+                new_state: all(),
+                outcome: MutationOutcome::new(),
+            },
+            MutationTest {
+                name: "MempoolTransactionSubscription All to All (add set)",
+                state: all(),
+                mutation: start_0(),
+                new_state: all(),
+                outcome: MutationOutcome::new(),
+            },
+            MutationTest {
+                name: "MempoolTransactionSubscription All to None",
+                state: all(),
+                mutation: stop_all(),
+                new_state: none(),
+                outcome: MutationOutcome::with_mutated(none(), vec![stop_all()]),
+            },
+        ]);
+        tests.run(&context);
+
+        let addresses = get_3_addresses(true);
+        assert!(selected_01().as_any().downcast_ref::<MempoolTransactionSubscription>().unwrap().selects(addresses[1..].iter()));
+        assert!(!selected_0().as_any().downcast_ref::<MempoolTransactionSubscription>().unwrap().selects(addresses[1..].iter()));
+        assert!(all().as_any().downcast_ref::<MempoolTransactionSubscription>().unwrap().selects([].iter()));
+        assert!(!none().as_any().downcast_ref::<MempoolTransactionSubscription>().unwrap().selects(addresses.iter()));
+    }
 }
@@ -0,0 +1,230 @@
+//!
+//! Cost model of the RPC methods, used by the RPC servers for rate limiting and
+//! request cost accounting.
+//!
+//! Costs are expressed in abstract units where a simple query costs 1 unit.
+//! The cost of a call is the base cost of its method (see [`RpcApiOps::cost`])
+//! plus a variable cost depending on the request size (see [`RpcRequestCost`]).
+//!
+
+use crate::{api::ops::RpcApiOps, model::message::*};
+
+impl RpcApiOps {
+    /// Base cost of a call to the method
+    pub fn cost(&self) -> u64 {
+        match self {
+            RpcApiOps::GetBlocks
+            | RpcApiOps::GetVirtualChainFromBlock
+            | RpcApiOps::EstimateNetworkHashesPerSecond
+            | RpcApiOps::CreateDatabaseCheckpoint
+            | RpcApiOps::ExportDag
+            | RpcApiOps::GetChainPathBetween => 20,
+            RpcApiOps::GetMempoolEntries
+            | RpcApiOps::GetHeaders
+            | RpcApiOps::GetConnectedPeerInfo
+            | RpcApiOps::GetPeerAddresses
+            | RpcApiOps::GetAnticoneSize
+            | RpcApiOps::GetBlocksByTimeRange => 10,
+            RpcApiOps::GetUtxosByAddresses | RpcApiOps::GetBalancesByAddresses | RpcApiOps::GetMempoolEntriesByAddresses => 5,
+            RpcApiOps::SubmitBlock
+            | RpcApiOps::GetBlockTemplate
+            | RpcApiOps::SubmitTransaction
+            | RpcApiOps::SubmitTransactionReplacement
+            | RpcApiOps::GetBlock
+            | RpcApiOps::GetMetrics
+            | RpcApiOps::GetCoinSupply
+            | RpcApiOps::GetCurrentBlockColor
+            | RpcApiOps::GetBlockMergeset => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// Variable cost of a request on top of the base cost of its method
+pub trait RpcRequestCost {
+    fn extra_cost(&self) -> u64 {
+        0
+    }
+}
+
+/// Total cost of a call to `op` with `request`
+pub fn request_cost<T: RpcRequestCost>(op: RpcApiOps, request: &T) -> u64 {
+    op.cost().saturating_add(request.extra_cost())
+}
+
+macro_rules! default_cost {
+    ($($request:ty),* $(,)?) => {
+        $(impl RpcRequestCost for $request {})*
+    };
+}
+
+default_cost!(
+    PingRequest,
+    GetMetricsRequest,
+    GetSystemInfoRequest,
+    GetConnectionsRequest,
+    GetServerInfoRequest,
+    GetSyncStatusRequest,
+    GetCurrentNetworkRequest,
+    SubmitBlockRequest,
+    GetBlockTemplateRequest,
+    GetPeerAddressesRequest,
+    GetSinkRequest,
+    GetMempoolEntryRequest,
+    GetConnectedPeerInfoRequest,
+    AddPeerRequest,
+    SubmitTransactionRequest,
+    SubmitTransactionReplacementRequest,
+    GetBlockRequest,
+    GetSubnetworkRequest,
+    GetBlockCountRequest,
+    GetBlockDagInfoRequest,
+    ResolveFinalityConflictRequest,
+    ShutdownRequest,
+    CreateDatabaseCheckpointRequest,
+    GetBalanceByAddressRequest,
+    GetSinkBlueScoreRequest,
+    BanRequest,
+    UnbanRequest,
+    GetInfoRequest,
+    GetCoinSupplyRequest,
+    GetFeeEstimateRequest,
+    GetFeeEstimateExperimentalRequest,
+    GetCurrentBlockColorRequest,
+    IsDagAncestorOfRequest,
+    GetBlockMergesetRequest,
+    GetChainBlockByDaaScoreRequest,
+);
+
+impl RpcRequestCost for GetMetricsHistoryRequest {
+    fn extra_cost(&self) -> u64 {
+        match self.resolution {
+            RpcMetricsResolution::Second => 10,
+            _ => 2,
+        }
+    }
+}
+
+impl RpcRequestCost for GetUtxosByAddressesRequest {
+    fn extra_cost(&self) -> u64 {
+        self.addresses.len() as u64
+    }
+}
+
+impl RpcRequestCost for GetBalancesByAddressesRequest {
+    fn extra_cost(&self) -> u64 {
+        self.addresses.len() as u64 / 2
+    }
+}
+
+impl RpcRequestCost for GetMempoolEntriesByAddressesRequest {
+    fn extra_cost(&self) -> u64 {
+        self.addresses.len() as u64
+    }
+}
+
+impl RpcRequestCost for GetMempoolEntriesRequest {
+    fn extra_cost(&self) -> u64 {
+        if self.include_orphan_pool {
+            10
+        } else {
+            0
+        }
+    }
+}
+
+impl RpcRequestCost for GetBlocksRequest {
+    fn extra_cost(&self) -> u64 {
+        match (self.include_blocks, self.include_transactions) {
+            (true, true) => 200,
+            (true, false) => 50,
+            _ => 0,
+        }
+    }
+}
+
+impl RpcRequestCost for GetVirtualChainFromBlockRequest {
+    fn extra_cost(&self) -> u64 {
+        if self.include_accepted_transaction_ids {
+            50
+        } else {
+            0
+        }
+    }
+}
+
+impl RpcRequestCost for GetHeadersRequest {
+    fn extra_cost(&self) -> u64 {
+        self.limit / 100
+    }
+}
+
+impl RpcRequestCost for EstimateNetworkHashesPerSecondRequest {
+    fn extra_cost(&self) -> u64 {
+        self.window_size as u64 / 100
+    }
+}
+
+impl RpcRequestCost for GetDaaScoreTimestampEstimateRequest {
+    fn extra_cost(&self) -> u64 {
+        self.daa_scores.len() as u64 / 10
+    }
+}
+
+/// Number of blocks traversed by DAG queries per cost unit
+const BLOCKS_PER_COST_UNIT: u64 = 50;
+
+impl RpcRequestCost for ExportDagRequest {
+    fn extra_cost(&self) -> u64 {
+        // A range of hashes may span up to the largest export
+        let blocks = match self.range {
+            RpcDagRange::DaaScores { low_daa_score, high_daa_score } => high_daa_score.saturating_sub(low_daa_score).saturating_add(1),
+            RpcDagRange::Hashes { .. } => Self::MAX_BLOCKS,
+        };
+        blocks.min(Self::MAX_BLOCKS) / BLOCKS_PER_COST_UNIT
+    }
+}
+
+impl RpcRequestCost for GetAnticoneSizeRequest {
+    fn extra_cost(&self) -> u64 {
+        // The anticone size is unknown beforehand, the traversal is charged up to its bound
+        Self::MAX_TRAVERSAL / BLOCKS_PER_COST_UNIT
+    }
+}
+
+impl RpcRequestCost for GetChainPathBetweenRequest {
+    fn extra_cost(&self) -> u64 {
+        // The path length is unknown beforehand, the path is charged up to its bound
+        Self::MAX_CHAIN_BLOCKS / BLOCKS_PER_COST_UNIT
+    }
+}
+
+impl RpcRequestCost for GetBlocksByTimeRangeRequest {
+    fn extra_cost(&self) -> u64 {
+        self.effective_limit() as u64 / 50
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_cost() {
+        assert_eq!(request_cost(RpcApiOps::GetInfo, &GetInfoRequest {}), 1);
+        assert_eq!(request_cost(RpcApiOps::GetBlocks, &GetBlocksRequest::new(None, true, true)), 220);
+        assert_eq!(request_cost(RpcApiOps::GetBlocks, &GetBlocksRequest::new(None, false, false)), 20);
+        assert_eq!(request_cost(RpcApiOps::GetHeaders, &GetHeadersRequest::new(Default::default(), 1000, true)), 20);
+        let time_range = |limit| GetBlocksByTimeRangeRequest { start_timestamp: 0, end_timestamp: u64::MAX, after_hash: None, limit };
+        assert_eq!(request_cost(RpcApiOps::GetBlocksByTimeRange, &time_range(100)), 12);
+        assert_eq!(request_cost(RpcApiOps::GetBlocksByTimeRange, &time_range(0)), 30);
+        assert_eq!(request_cost(RpcApiOps::GetBlocksByTimeRange, &time_range(u32::MAX)), 30);
+        let export = |range| ExportDagRequest { range };
+        let daa_scores = |low_daa_score, high_daa_score| RpcDagRange::DaaScores { low_daa_score, high_daa_score };
+        assert_eq!(request_cost(RpcApiOps::ExportDag, &export(daa_scores(1_000, 1_499))), 30);
+        assert_eq!(request_cost(RpcApiOps::ExportDag, &export(daa_scores(0, u64::MAX))), 120);
+        let hashes = RpcDagRange::Hashes { low_hash: Default::default(), high_hash: Default::default() };
+        assert_eq!(request_cost(RpcApiOps::ExportDag, &export(hashes)), 120);
+        assert_eq!(request_cost(RpcApiOps::GetAnticoneSize, &GetAnticoneSizeRequest { hash: Default::default() }), 110);
+    }
+}
@@ -3,6 +3,7 @@
 //!
 
 pub mod connection;
+pub mod cost;
 pub mod ctl;
 pub mod notifications;
 pub mod ops;
@@ -2,13 +2,14 @@
 //! RPC notifications that can be sent to clients.
 //!
 
-use crate::model::message::*;
+use crate::model::{message::*, RpcHash, RpcTransactionId, RpcUtxosByAddressesEntry};
 use calico_notify::{
     events::EventType,
     notification::{full_featured, Notification as NotificationTrait},
+    replay::Cursor,
     subscription::{
         context::SubscriptionContext,
-        single::{OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription},
+        single::{MempoolTransactionSubscription, OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription},
         Subscription,
     },
 };
@@ -48,6 +49,18 @@ pub enum Notification {
 
     #[display(fmt = "NewBlockTemplate notification")]
     NewBlockTemplate(NewBlockTemplateNotification),
+
+    #[display(fmt = "MempoolTransactionAdded notification: transaction id {}", "_0.transaction_id")]
+    MempoolTransactionAdded(MempoolTransactionAddedNotification),
+
+    #[display(fmt = "MempoolTransactionRemoved notification: transaction id {} ({})", "_0.transaction_id", "_0.reason")]
+    MempoolTransactionRemoved(MempoolTransactionRemovedNotification),
+
+    #[display(fmt = "DatabaseCheckpoint notification: {} ({}/{} databases) at {}", "_0.state", "_0.completed_databases", "_0.total_databases", "_0.directory")]
+    DatabaseCheckpoint(DatabaseCheckpointNotification),
+
+    #[display(fmt = "ConsensusAuditViolation notification: {} check failed for block {}: {}", "_0.check", "_0.block_hash", "_0.details")]
+    ConsensusAuditViolation(ConsensusAuditViolationNotification),
 }
 }
 
@@ -64,6 +77,10 @@ impl Notification {
             Notification::VirtualDaaScoreChanged(v) => to_value(&v),
             Notification::SinkBlueScoreChanged(v) => to_value(&v),
             Notification::VirtualChainChanged(v) => to_value(&v),
+            Notification::MempoolTransactionAdded(v) => to_value(&v),
+            Notification::MempoolTransactionRemoved(v) => to_value(&v),
+            Notification::DatabaseCheckpoint(v) => to_value(&v),
+            Notification::ConsensusAuditViolation(v) => to_value(&v),
         }
     }
 }
@@ -89,6 +106,7 @@ impl NotificationTrait for Notification {
                             removed_chain_block_hashes: payload.removed_chain_block_hashes.clone(),
                             added_chain_block_hashes: payload.added_chain_block_hashes.clone(),
                             accepted_transaction_ids: Arc::new(vec![]),
+                            cursor: payload.cursor,
                         }));
                     }
                 }
@@ -112,9 +130,60 @@ impl NotificationTrait for Notification {
         }
     }
 
+    fn apply_mempool_transaction_subscription(
+        &self,
+        subscription: &MempoolTransactionSubscription,
+        _context: &SubscriptionContext,
+    ) -> Option<Self> {
+        let addresses = match self {
+            Notification::MempoolTransactionAdded(notification) => &notification.addresses,
+            Notification::MempoolTransactionRemoved(notification) => &notification.addresses,
+            _ => return None,
+        };
+        subscription.selects(addresses.iter()).then(|| self.clone())
+    }
+
     fn event_type(&self) -> EventType {
         self.into()
     }
+
+    fn cursor(&self) -> Option<Cursor> {
+        match self {
+            Notification::VirtualChainChanged(notification) => notification.cursor,
+            Notification::UtxosChanged(notification) => notification.cursor,
+            _ => None,
+        }
+    }
+
+    fn set_cursor(&mut self, cursor: Cursor) {
+        match self {
+            Notification::VirtualChainChanged(notification) => notification.cursor = Some(cursor),
+            Notification::UtxosChanged(notification) => notification.cursor = Some(cursor),
+            _ => {}
+        }
+    }
+
+    fn estimated_size(&self) -> usize {
+        let entries_size = |entries: &[RpcUtxosByAddressesEntry]| {
+            entries.iter().map(|entry| std::mem::size_of_val(entry) + entry.utxo_entry.script_public_key.script().len()).sum::<usize>()
+        };
+        std::mem::size_of::<Self>()
+            + match self {
+                Notification::VirtualChainChanged(notification) => {
+                    (notification.removed_chain_block_hashes.len() + notification.added_chain_block_hashes.len())
+                        * std::mem::size_of::<RpcHash>()
+                        + notification
+                            .accepted_transaction_ids
+                            .iter()
+                            .map(|x| {
+                                std::mem::size_of_val(x) + x.accepted_transaction_ids.len() * std::mem::size_of::<RpcTransactionId>()
+                            })
+                            .sum::<usize>()
+                }
+                Notification::UtxosChanged(notification) => entries_size(&notification.added) + entries_size(&notification.removed),
+                _ => 0,
+            }
+    }
 }
 
 impl Serializer for Notification {
@@ -157,6 +226,22 @@ impl Serializer for Notification {
                 store!(u16, &8, writer)?;
                 serialize!(NewBlockTemplateNotification, notification, writer)?;
             }
+            Notification::MempoolTransactionAdded(notification) => {
+                store!(u16, &9, writer)?;
+                serialize!(MempoolTransactionAddedNotification, notification, writer)?;
+            }
+            Notification::MempoolTransactionRemoved(notification) => {
+                store!(u16, &10, writer)?;
+                serialize!(MempoolTransactionRemovedNotification, notification, writer)?;
+            }
+            Notification::DatabaseCheckpoint(notification) => {
+                store!(u16, &11, writer)?;
+                serialize!(DatabaseCheckpointNotification, notification, writer)?;
+            }
+            Notification::ConsensusAuditViolation(notification) => {
+                store!(u16, &12, writer)?;
+                serialize!(ConsensusAuditViolationNotification, notification, writer)?;
+            }
         }
         Ok(())
     }
@@ -202,6 +287,22 @@ impl Deserializer for Notification {
                 let notification = deserialize!(NewBlockTemplateNotification, reader)?;
                 Ok(Notification::NewBlockTemplate(notification))
             }
+            9 => {
+                let notification = deserialize!(MempoolTransactionAddedNotification, reader)?;
+                Ok(Notification::MempoolTransactionAdded(notification))
+            }
+            10 => {
+                let notification = deserialize!(MempoolTransactionRemovedNotification, reader)?;
+                Ok(Notification::MempoolTransactionRemoved(notification))
+            }
+            11 => {
+                let notification = deserialize!(DatabaseCheckpointNotification, reader)?;
+                Ok(Notification::DatabaseCheckpoint(notification))
+            }
+            12 => {
+                let notification = deserialize!(ConsensusAuditViolationNotification, reader)?;
+                Ok(Notification::ConsensusAuditViolation(notification))
+            }
             _ => Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid variant")),
         }
     }
@@ -40,6 +40,10 @@ pub enum RpcApiOps {
     NotifyVirtualDaaScoreChanged = 16,
     NotifyVirtualChainChanged = 17,
     NotifySinkBlueScoreChanged = 18,
+    NotifyMempoolTransactionAdded = 19,
+    NotifyMempoolTransactionRemoved = 20,
+    NotifyDatabaseCheckpoint = 21,
+    NotifyConsensusAuditViolation = 22,
 
     // Notification ops required by wRPC
 
@@ -54,6 +58,10 @@ pub enum RpcApiOps {
     VirtualDaaScoreChangedNotification = 66,
     PruningPointUtxoSetOverrideNotification = 67,
     NewBlockTemplateNotification = 68,
+    MempoolTransactionAddedNotification = 69,
+    MempoolTransactionRemovedNotification = 70,
+    DatabaseCheckpointNotification = 71,
+    ConsensusAuditViolationNotification = 72,
 
     // RPC methods
     /// Ping the node to check if connection is alive
@@ -136,6 +144,24 @@ pub enum RpcApiOps {
     GetFeeEstimateExperimental = 148,
     /// Block color determination by iterating DAG.
     GetCurrentBlockColor = 149,
+    /// Get the samples of node metrics retained over a time range
+    GetMetricsHistory = 150,
+    /// Create a point-in-time copy of the node databases
+    CreateDatabaseCheckpoint = 151,
+    /// Export the GHOSTDAG data of a sub-DAG
+    ExportDag = 152,
+    /// Check whether a block is in the past of another block
+    IsDagAncestorOf = 153,
+    /// Get the GHOSTDAG merge set of a block
+    GetBlockMergeset = 154,
+    /// Get the size of the anticone of a block
+    GetAnticoneSize = 155,
+    /// Get the selected chain path between two blocks
+    GetChainPathBetween = 156,
+    /// Get the selected chain block at a DAA score from the chain index
+    GetChainBlockByDaaScore = 157,
+    /// Get the selected chain blocks within a timestamp range from the chain index
+    GetBlocksByTimeRange = 158,
 }
 
 impl RpcApiOps {
@@ -151,12 +177,106 @@ impl RpcApiOps {
                 | RpcApiOps::NotifyFinalityConflictResolved
                 | RpcApiOps::NotifySinkBlueScoreChanged
                 | RpcApiOps::NotifyVirtualDaaScoreChanged
+                | RpcApiOps::NotifyMempoolTransactionAdded
+                | RpcApiOps::NotifyMempoolTransactionRemoved
+                | RpcApiOps::NotifyDatabaseCheckpoint
+                | RpcApiOps::NotifyConsensusAuditViolation
                 | RpcApiOps::Subscribe
                 | RpcApiOps::Unsubscribe
         )
     }
 }
 
+/// Expands `callback!(args.. [Ping, AddPeer, ..])`, appending the request/response
+/// methods served by the wRPC and HTTP routers and called by the wRPC client.
+/// This is the single table of these methods; a method added to [`RpcApiOps`]
+/// and to the [`RpcApi`](crate::api::rpc::RpcApi) trait only needs to be listed here.
+///
+/// ```ignore
+/// let interface = with_rpc_methods!(build_http_server_interface!(server, Server, Connection, RpcApiOps,));
+/// ```
+#[macro_export]
+macro_rules! with_rpc_methods {
+    ($callback:ident!($($args:tt)*)) => {
+        $callback!(
+            $($args)*
+            [
+                Ping,
+                AddPeer,
+                Ban,
+                CreateDatabaseCheckpoint,
+                EstimateNetworkHashesPerSecond,
+                ExportDag,
+                GetBalanceByAddress,
+                GetBalancesByAddresses,
+                GetAnticoneSize,
+                GetBlock,
+                GetBlockCount,
+                GetBlockDagInfo,
+                GetBlockMergeset,
+                GetBlocks,
+                GetBlockTemplate,
+                GetChainBlockByDaaScore,
+                GetBlocksByTimeRange,
+                GetChainPathBetween,
+                GetCurrentBlockColor,
+                GetCoinSupply,
+                GetConnectedPeerInfo,
+                GetConnections,
+                GetCurrentNetwork,
+                GetDaaScoreTimestampEstimate,
+                GetFeeEstimate,
+                GetFeeEstimateExperimental,
+                GetHeaders,
+                GetInfo,
+                GetMempoolEntries,
+                GetMempoolEntriesByAddresses,
+                GetMempoolEntry,
+                GetMetrics,
+                GetMetricsHistory,
+                GetPeerAddresses,
+                GetServerInfo,
+                GetSink,
+                GetSinkBlueScore,
+                GetSubnetwork,
+                GetSyncStatus,
+                GetSystemInfo,
+                GetUtxosByAddresses,
+                GetVirtualChainFromBlock,
+                IsDagAncestorOf,
+                ResolveFinalityConflict,
+                Shutdown,
+                SubmitBlock,
+                SubmitTransaction,
+                SubmitTransactionReplacement,
+                Unban,
+            ]
+        )
+    };
+}
+
+impl RpcApiOps {
+    /// Subscription method of the `event` notifications, under which subscribing and
+    /// unsubscribing are handled whatever the protocol is
+    pub fn subscription(event: EventType) -> Self {
+        match event {
+            EventType::BlockAdded => RpcApiOps::NotifyBlockAdded,
+            EventType::VirtualChainChanged => RpcApiOps::NotifyVirtualChainChanged,
+            // gRPC clients subscribe to both finality conflict events with NotifyFinalityConflict
+            EventType::FinalityConflict | EventType::FinalityConflictResolved => RpcApiOps::NotifyFinalityConflict,
+            EventType::UtxosChanged => RpcApiOps::NotifyUtxosChanged,
+            EventType::SinkBlueScoreChanged => RpcApiOps::NotifySinkBlueScoreChanged,
+            EventType::VirtualDaaScoreChanged => RpcApiOps::NotifyVirtualDaaScoreChanged,
+            EventType::PruningPointUtxoSetOverride => RpcApiOps::NotifyPruningPointUtxoSetOverride,
+            EventType::NewBlockTemplate => RpcApiOps::NotifyNewBlockTemplate,
+            EventType::MempoolTransactionAdded => RpcApiOps::NotifyMempoolTransactionAdded,
+            EventType::MempoolTransactionRemoved => RpcApiOps::NotifyMempoolTransactionRemoved,
+            EventType::DatabaseCheckpoint => RpcApiOps::NotifyDatabaseCheckpoint,
+            EventType::ConsensusAuditViolation => RpcApiOps::NotifyConsensusAuditViolation,
+        }
+    }
+}
+
 impl From<RpcApiOps> for u32 {
     fn from(item: RpcApiOps) -> Self {
         item as u32
@@ -177,6 +297,10 @@ impl From<EventType> for RpcApiOps {
             EventType::VirtualDaaScoreChanged => RpcApiOps::VirtualDaaScoreChangedNotification,
             EventType::PruningPointUtxoSetOverride => RpcApiOps::PruningPointUtxoSetOverrideNotification,
             EventType::NewBlockTemplate => RpcApiOps::NewBlockTemplateNotification,
+            EventType::MempoolTransactionAdded => RpcApiOps::MempoolTransactionAddedNotification,
+            EventType::MempoolTransactionRemoved => RpcApiOps::MempoolTransactionRemovedNotification,
+            EventType::DatabaseCheckpoint => RpcApiOps::DatabaseCheckpointNotification,
+            EventType::ConsensusAuditViolation => RpcApiOps::ConsensusAuditViolationNotification,
         }
     }
 }
@@ -71,6 +71,7 @@ pub trait RpcApi: Sync + Send + AnySync {
                 consensus_metrics,
                 storage_metrics,
                 custom_metrics,
+                rpc_method_metrics: false,
             },
         )
         .await
@@ -81,6 +82,22 @@ pub trait RpcApi: Sync + Send + AnySync {
         request: GetMetricsRequest,
     ) -> RpcResult<GetMetricsResponse>;
 
+    /// Requests the samples of node metrics retained by the node over a time range, at a given resolution.
+    async fn get_metrics_history(
+        &self,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        resolution: RpcMetricsResolution,
+        metrics: Vec<String>,
+    ) -> RpcResult<GetMetricsHistoryResponse> {
+        self.get_metrics_history_call(None, GetMetricsHistoryRequest { start_time, end_time, resolution, metrics }).await
+    }
+    async fn get_metrics_history_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: GetMetricsHistoryRequest,
+    ) -> RpcResult<GetMetricsHistoryResponse>;
+
     // get_info alternative that carries only version, network_id (full), is_synced, virtual_daa_score
     // these are the only variables needed to negotiate a wRPC connection (besides the wRPC handshake)
     async fn get_server_info(&self) -> RpcResult<GetServerInfoResponse> {
@@ -311,6 +328,18 @@ pub trait RpcApi: Sync + Send + AnySync {
     }
     async fn shutdown_call(&self, connection: Option<&DynRpcConnection>, request: ShutdownRequest) -> RpcResult<ShutdownResponse>;
 
+    /// Starts creating a checkpoint of the node databases in `directory`, or in a timestamped directory
+    /// under the node backup directory if `None`. Returns the directory of the checkpoint, whose progress
+    /// is reported by `DatabaseCheckpoint` notifications.
+    async fn create_database_checkpoint(&self, directory: Option<String>) -> RpcResult<String> {
+        Ok(self.create_database_checkpoint_call(None, CreateDatabaseCheckpointRequest { directory }).await?.directory)
+    }
+    async fn create_database_checkpoint_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: CreateDatabaseCheckpointRequest,
+    ) -> RpcResult<CreateDatabaseCheckpointResponse>;
+
     /// Requests headers between the given `start_hash` and the current virtual, up to the given limit.
     async fn get_headers(&self, start_hash: RpcHash, limit: u64, is_ascending: bool) -> RpcResult<Vec<RpcHeader>> {
         Ok(self.get_headers_call(None, GetHeadersRequest::new(start_hash, limit, is_ascending)).await?.headers)
@@ -469,6 +498,80 @@ pub trait RpcApi: Sync + Send + AnySync {
         request: GetCurrentBlockColorRequest,
     ) -> RpcResult<GetCurrentBlockColorResponse>;
 
+    /// Requests the GHOSTDAG data of the blocks of the sub-DAG spanned by `range`.
+    async fn export_dag(&self, range: RpcDagRange) -> RpcResult<ExportDagResponse> {
+        self.export_dag_call(None, ExportDagRequest { range }).await
+    }
+    async fn export_dag_call(&self, connection: Option<&DynRpcConnection>, request: ExportDagRequest) -> RpcResult<ExportDagResponse>;
+
+    /// Requests whether the block `low_hash` is in the past of the block `high_hash`.
+    async fn is_dag_ancestor_of(&self, low_hash: RpcHash, high_hash: RpcHash) -> RpcResult<IsDagAncestorOfResponse> {
+        self.is_dag_ancestor_of_call(None, IsDagAncestorOfRequest { low_hash, high_hash }).await
+    }
+    async fn is_dag_ancestor_of_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: IsDagAncestorOfRequest,
+    ) -> RpcResult<IsDagAncestorOfResponse>;
+
+    /// Requests the GHOSTDAG merge set of the block `hash`.
+    async fn get_block_mergeset(&self, hash: RpcHash) -> RpcResult<GetBlockMergesetResponse> {
+        self.get_block_mergeset_call(None, GetBlockMergesetRequest { hash }).await
+    }
+    async fn get_block_mergeset_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: GetBlockMergesetRequest,
+    ) -> RpcResult<GetBlockMergesetResponse>;
+
+    /// Requests the size of the anticone of the block `hash` from the point of view of the virtual block.
+    async fn get_anticone_size(&self, hash: RpcHash) -> RpcResult<u64> {
+        Ok(self.get_anticone_size_call(None, GetAnticoneSizeRequest { hash }).await?.anticone_size)
+    }
+    async fn get_anticone_size_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: GetAnticoneSizeRequest,
+    ) -> RpcResult<GetAnticoneSizeResponse>;
+
+    /// Requests the selected chain path from the block `from_hash` to the block `to_hash`.
+    async fn get_chain_path_between(&self, from_hash: RpcHash, to_hash: RpcHash) -> RpcResult<GetChainPathBetweenResponse> {
+        self.get_chain_path_between_call(None, GetChainPathBetweenRequest { from_hash, to_hash }).await
+    }
+    async fn get_chain_path_between_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: GetChainPathBetweenRequest,
+    ) -> RpcResult<GetChainPathBetweenResponse>;
+
+    /// Requests the selected chain block with the highest DAA score not above `daa_score`.
+    async fn get_chain_block_by_daa_score(&self, daa_score: u64) -> RpcResult<RpcIndexedChainBlock> {
+        Ok(self.get_chain_block_by_daa_score_call(None, GetChainBlockByDaaScoreRequest { daa_score }).await?.block)
+    }
+    async fn get_chain_block_by_daa_score_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: GetChainBlockByDaaScoreRequest,
+    ) -> RpcResult<GetChainBlockByDaaScoreResponse>;
+
+    /// Requests a page of the selected chain blocks with a timestamp in `[start_timestamp, end_timestamp)`
+    /// together with the blocks they merged.
+    async fn get_blocks_by_time_range(
+        &self,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        after_hash: Option<RpcHash>,
+        limit: u32,
+    ) -> RpcResult<GetBlocksByTimeRangeResponse> {
+        self.get_blocks_by_time_range_call(None, GetBlocksByTimeRangeRequest { start_timestamp, end_timestamp, after_hash, limit })
+            .await
+    }
+    async fn get_blocks_by_time_range_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: GetBlocksByTimeRangeRequest,
+    ) -> RpcResult<GetBlocksByTimeRangeResponse>;
+
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
 
@@ -1,8 +1,10 @@
 //! Conversion of Notification related types
 
 use crate::{
-    convert::utxo::utxo_set_into_rpc, BlockAddedNotification, FinalityConflictNotification, FinalityConflictResolvedNotification,
-    NewBlockTemplateNotification, Notification, PruningPointUtxoSetOverrideNotification, RpcAcceptedTransactionIds,
+    convert::utxo::utxo_set_into_rpc, BlockAddedNotification, ConsensusAuditViolationNotification, DatabaseCheckpointNotification,
+    FinalityConflictNotification, FinalityConflictResolvedNotification, MempoolTransactionAddedNotification,
+    MempoolTransactionRemovedNotification, NewBlockTemplateNotification, Notification, PruningPointUtxoSetOverrideNotification,
+    RpcAcceptedTransactionIds, RpcConsensusAuditCheck, RpcDatabaseCheckpointState, RpcMempoolRemovalReason,
     SinkBlueScoreChangedNotification, UtxosChangedNotification, VirtualChainChangedNotification, VirtualDaaScoreChangedNotification,
 };
 use calico_consensus_notify::notification as consensus_notify;
@@ -31,6 +33,10 @@ impl From<&consensus_notify::Notification> for Notification {
             consensus_notify::Notification::VirtualDaaScoreChanged(msg) => Notification::VirtualDaaScoreChanged(msg.into()),
             consensus_notify::Notification::PruningPointUtxoSetOverride(msg) => Notification::PruningPointUtxoSetOverride(msg.into()),
             consensus_notify::Notification::NewBlockTemplate(msg) => Notification::NewBlockTemplate(msg.into()),
+            consensus_notify::Notification::MempoolTransactionAdded(msg) => Notification::MempoolTransactionAdded(msg.into()),
+            consensus_notify::Notification::MempoolTransactionRemoved(msg) => Notification::MempoolTransactionRemoved(msg.into()),
+            consensus_notify::Notification::DatabaseCheckpoint(msg) => Notification::DatabaseCheckpoint(msg.into()),
+            consensus_notify::Notification::ConsensusAuditViolation(msg) => Notification::ConsensusAuditViolation(msg.into()),
         }
     }
 }
@@ -65,6 +71,7 @@ impl From<&consensus_notify::VirtualChainChangedNotification> for VirtualChainCh
                     })
                     .collect()
             }),
+            cursor: None,
         }
     }
 }
@@ -112,6 +119,75 @@ impl From<&consensus_notify::NewBlockTemplateNotification> for NewBlockTemplateN
     }
 }
 
+impl From<&consensus_notify::MempoolTransactionAddedNotification> for MempoolTransactionAddedNotification {
+    fn from(item: &consensus_notify::MempoolTransactionAddedNotification) -> Self {
+        Self { transaction_id: item.transaction_id, addresses: item.addresses.clone() }
+    }
+}
+
+impl From<consensus_notify::MempoolRemovalReason> for RpcMempoolRemovalReason {
+    fn from(item: consensus_notify::MempoolRemovalReason) -> Self {
+        match item {
+            consensus_notify::MempoolRemovalReason::Accepted => RpcMempoolRemovalReason::Accepted,
+            consensus_notify::MempoolRemovalReason::Evicted => RpcMempoolRemovalReason::Evicted,
+            consensus_notify::MempoolRemovalReason::ReplacedByFee => RpcMempoolRemovalReason::ReplacedByFee,
+            consensus_notify::MempoolRemovalReason::Expired => RpcMempoolRemovalReason::Expired,
+            consensus_notify::MempoolRemovalReason::Invalidated => RpcMempoolRemovalReason::Invalidated,
+            consensus_notify::MempoolRemovalReason::MissingOutpoints => RpcMempoolRemovalReason::MissingOutpoints,
+            consensus_notify::MempoolRemovalReason::AncestorRemoved => RpcMempoolRemovalReason::AncestorRemoved,
+        }
+    }
+}
+
+impl From<&consensus_notify::MempoolTransactionRemovedNotification> for MempoolTransactionRemovedNotification {
+    fn from(item: &consensus_notify::MempoolTransactionRemovedNotification) -> Self {
+        Self { transaction_id: item.transaction_id, addresses: item.addresses.clone(), reason: item.reason.into() }
+    }
+}
+
+impl From<consensus_notify::DatabaseCheckpointState> for RpcDatabaseCheckpointState {
+    fn from(item: consensus_notify::DatabaseCheckpointState) -> Self {
+        match item {
+            consensus_notify::DatabaseCheckpointState::Started => RpcDatabaseCheckpointState::Started,
+            consensus_notify::DatabaseCheckpointState::InProgress => RpcDatabaseCheckpointState::InProgress,
+            consensus_notify::DatabaseCheckpointState::Completed => RpcDatabaseCheckpointState::Completed,
+            consensus_notify::DatabaseCheckpointState::Failed => RpcDatabaseCheckpointState::Failed,
+        }
+    }
+}
+
+impl From<&consensus_notify::DatabaseCheckpointNotification> for DatabaseCheckpointNotification {
+    fn from(item: &consensus_notify::DatabaseCheckpointNotification) -> Self {
+        Self {
+            directory: item.directory.clone(),
+            state: item.state.into(),
+            completed_databases: item.completed_databases,
+            total_databases: item.total_databases,
+            sink: item.sink,
+            virtual_daa_score: item.virtual_daa_score,
+            error: item.error.clone(),
+        }
+    }
+}
+
+impl From<consensus_notify::ConsensusAuditCheck> for RpcConsensusAuditCheck {
+    fn from(item: consensus_notify::ConsensusAuditCheck) -> Self {
+        match item {
+            consensus_notify::ConsensusAuditCheck::Ghostdag => RpcConsensusAuditCheck::Ghostdag,
+            consensus_notify::ConsensusAuditCheck::Reachability => RpcConsensusAuditCheck::Reachability,
+            consensus_notify::ConsensusAuditCheck::DaaWindow => RpcConsensusAuditCheck::DaaWindow,
+            consensus_notify::ConsensusAuditCheck::CoinbaseReward => RpcConsensusAuditCheck::CoinbaseReward,
+            consensus_notify::ConsensusAuditCheck::UtxoCommitment => RpcConsensusAuditCheck::UtxoCommitment,
+        }
+    }
+}
+
+impl From<&consensus_notify::ConsensusAuditViolationNotification> for ConsensusAuditViolationNotification {
+    fn from(item: &consensus_notify::ConsensusAuditViolationNotification) -> Self {
+        Self { check: item.check.into(), block_hash: item.block_hash, details: item.details.clone() }
+    }
+}
+
 // ----------------------------------------------------------------------------
 // index to rpc_core
 // ----------------------------------------------------------------------------
@@ -141,6 +217,10 @@ impl From<&index_notify::UtxosChangedNotification> for UtxosChangedNotification
     // This is not intended to be ever called because no address prefix is available.
     // Use calico_rpc_service::converter::index::IndexConverter instead.
     fn from(item: &index_notify::UtxosChangedNotification) -> Self {
-        Self { added: Arc::new(utxo_set_into_rpc(&item.added, None)), removed: Arc::new(utxo_set_into_rpc(&item.removed, None)) }
+        Self {
+            added: Arc::new(utxo_set_into_rpc(&item.added, None)),
+            removed: Arc::new(utxo_set_into_rpc(&item.removed, None)),
+            cursor: None,
+        }
     }
 }
@@ -1,9 +1,10 @@
 //! Conversion of Notification Scope related types
 
 use crate::{
-    NotifyBlockAddedRequest, NotifyFinalityConflictRequest, NotifyNewBlockTemplateRequest, NotifyPruningPointUtxoSetOverrideRequest,
-    NotifySinkBlueScoreChangedRequest, NotifyUtxosChangedRequest, NotifyVirtualChainChangedRequest,
-    NotifyVirtualDaaScoreChangedRequest,
+    NotifyBlockAddedRequest, NotifyConsensusAuditViolationRequest, NotifyDatabaseCheckpointRequest, NotifyFinalityConflictRequest,
+    NotifyMempoolTransactionAddedRequest, NotifyMempoolTransactionRemovedRequest, NotifyNewBlockTemplateRequest,
+    NotifyPruningPointUtxoSetOverrideRequest, NotifySinkBlueScoreChangedRequest, NotifyUtxosChangedRequest,
+    NotifyVirtualChainChangedRequest, NotifyVirtualDaaScoreChangedRequest,
 };
 use calico_notify::scope::*;
 
@@ -47,7 +48,7 @@ macro_rules! from {
 
 from!(BlockAdded);
 from!(item: VirtualChainChanged, {
-    Self::new(item.include_accepted_transaction_ids)
+    Self::new(item.include_accepted_transaction_ids).with_resume_from(item.resume_from)
 });
 from!(FinalityConflict);
 impl From<&NotifyFinalityConflictRequest> for FinalityConflictResolvedScope {
@@ -56,9 +57,13 @@ impl From<&NotifyFinalityConflictRequest> for FinalityConflictResolvedScope {
     }
 }
 from!(item: UtxosChanged, {
-    Self::new(item.addresses.clone())
+    Self::new(item.addresses.clone()).with_filter(item.filter.clone()).with_resume_from(item.resume_from)
 });
 from!(SinkBlueScoreChanged);
 from!(VirtualDaaScoreChanged);
 from!(PruningPointUtxoSetOverride);
 from!(NewBlockTemplate);
+from!(item: MempoolTransactionAdded, { Self::new(item.addresses) });
+from!(item: MempoolTransactionRemoved, { Self::new(item.addresses) });
+from!(DatabaseCheckpoint);
+from!(ConsensusAuditViolation);
@@ -63,6 +63,9 @@ pub enum RpcError {
     #[error("Method unavailable. Run the node with the --utxoindex argument.")]
     NoUtxoIndex,
 
+    #[error("Method unavailable. Run the node with the --chainindex argument.")]
+    NoChainIndex,
+
     #[error("Method unavailable. No connection manager is currently available.")]
     NoConnectionManager,
 
@@ -75,6 +78,15 @@ pub enum RpcError {
     #[error("Method unavailable in safe mode. Run the node with --unsaferpc argument.")]
     UnavailableInSafeMode,
 
+    #[error("Authentication failed: unknown API key.")]
+    AuthenticationFailed,
+
+    #[error("Authorization denied: method {0} requires the {1} role.")]
+    AuthorizationDenied(String, String),
+
+    #[error("Rate limit exceeded: method {0} costs {1} units, retry in {2} ms.")]
+    RateLimitExceeded(String, u64, u64),
+
     #[error("Cannot ban IP {0} because it has some permanent connection.")]
     IpHasPermanentConnection(IpAddress),
 
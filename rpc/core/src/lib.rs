@@ -17,6 +17,8 @@
 pub mod api;
 pub mod convert;
 pub mod error;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod model;
 pub mod notify;
 pub mod wasm;
@@ -1,3 +1,13 @@
+//!
+//! Mock of the RPC service shared by the unit tests of the RPC servers and clients.
+//!
+//! Most methods fail with [`RpcError::NotImplemented`]. `GetInfo`, `Ping` and `Shutdown` succeed, UTXOs
+//! added by the test are served by `GetUtxosByAddresses` and notifications go through a real notifier.
+//!
+
+use crate::api::ctl::RpcCtl;
+use crate::{api::connection::DynRpcConnection, api::rpc::RpcApi, *};
+use crate::{notify::connection::ChannelConnection, RpcResult};
 use async_channel::{unbounded, Receiver};
 use async_trait::async_trait;
 use calico_notify::events::EVENT_TYPE_ARRAY;
@@ -6,61 +16,90 @@ use calico_notify::notifier::{Notifier, Notify};
 use calico_notify::scope::Scope;
 use calico_notify::subscription::context::SubscriptionContext;
 use calico_notify::subscription::{MutationPolicies, UtxosChangedMutationPolicy};
-use calico_rpc_core::{api::connection::DynRpcConnection, api::rpc::RpcApi, *};
-use calico_rpc_core::{notify::connection::ChannelConnection, RpcResult};
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
 
-pub(super) type RpcCoreNotifier = Notifier<Notification, ChannelConnection>;
+pub type RpcCoreNotifier = Notifier<Notification, ChannelConnection>;
 
-pub(super) struct RpcCoreMock {
+pub struct RpcCoreMock {
+    ctl: RpcCtl,
     core_notifier: Arc<RpcCoreNotifier>,
     _sync_receiver: Receiver<()>,
+    utxos: Mutex<Vec<RpcUtxosByAddressesEntry>>,
+    utxos_by_addresses_requests: AtomicUsize,
 }
 
 impl RpcCoreMock {
-    pub(super) fn new() -> Self {
+    pub fn new() -> Self {
         let (sync_sender, sync_receiver) = unbounded();
         let policies = MutationPolicies::new(UtxosChangedMutationPolicy::AddressSet);
-        let subscription_context = SubscriptionContext::new();
         let core_notifier: Arc<RpcCoreNotifier> = Arc::new(Notifier::with_sync(
             "rpc-core",
             EVENT_TYPE_ARRAY[..].into(),
             vec![],
             vec![],
-            subscription_context,
+            SubscriptionContext::new(),
             10,
             policies,
             Some(sync_sender),
         ));
-        Self { core_notifier, _sync_receiver: sync_receiver }
+        Self {
+            core_notifier,
+            _sync_receiver: sync_receiver,
+            ctl: RpcCtl::new(),
+            utxos: Mutex::new(vec![]),
+            utxos_by_addresses_requests: AtomicUsize::new(0),
+        }
     }
 
-    pub(super) fn core_notifier(&self) -> Arc<RpcCoreNotifier> {
+    pub fn core_notifier(&self) -> Arc<RpcCoreNotifier> {
         self.core_notifier.clone()
     }
 
-    pub(super) fn subscription_context(&self) -> SubscriptionContext {
+    pub fn subscription_context(&self) -> SubscriptionContext {
         self.core_notifier.subscription_context().clone()
     }
 
-    #[allow(dead_code)]
-    pub(super) fn notify_new_block_template(&self) -> calico_notify::error::Result<()> {
+    pub fn notify_new_block_template(&self) -> calico_notify::error::Result<()> {
         let notification = Notification::NewBlockTemplate(NewBlockTemplateNotification {});
         self.core_notifier.notify(notification)
     }
 
-    #[allow(dead_code)]
-    pub(super) async fn notify_complete(&self) {
+    pub async fn notify_complete(&self) {
         assert!(self._sync_receiver.recv().await.is_ok(), "the notifier sync channel is unexpectedly empty and closed");
     }
 
-    pub(super) fn start(&self) {
+    pub fn start(&self) {
         self.core_notifier.clone().start();
     }
 
-    pub(super) async fn join(&self) {
+    pub async fn join(&self) {
         self.core_notifier.join().await.expect("core notifier shutdown")
     }
+
+    /// Add UTXO entries returned by `get_utxos_by_addresses` for their respective addresses
+    pub fn add_utxos(&self, entries: Vec<RpcUtxosByAddressesEntry>) {
+        self.utxos.lock().unwrap().extend(entries);
+    }
+
+    /// Number of `get_utxos_by_addresses` requests processed so far
+    pub fn utxos_by_addresses_requests(&self) -> usize {
+        self.utxos_by_addresses_requests.load(Ordering::Relaxed)
+    }
+
+    // ---
+
+    pub fn ctl(&self) -> RpcCtl {
+        self.ctl.clone()
+    }
+}
+
+impl Default for RpcCoreMock {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[async_trait]
@@ -79,7 +118,7 @@ impl RpcApi for RpcCoreMock {
     }
 
     async fn ping_call(&self, _connection: Option<&DynRpcConnection>, _request: PingRequest) -> RpcResult<PingResponse> {
-        Err(RpcError::NotImplemented)
+        Ok(PingResponse {})
     }
 
     async fn get_metrics_call(
@@ -90,19 +129,19 @@ impl RpcApi for RpcCoreMock {
         Err(RpcError::NotImplemented)
     }
 
-    async fn get_connections_call(
+    async fn get_metrics_history_call(
         &self,
         _connection: Option<&DynRpcConnection>,
-        _request: GetConnectionsRequest,
-    ) -> RpcResult<GetConnectionsResponse> {
+        _request: GetMetricsHistoryRequest,
+    ) -> RpcResult<GetMetricsHistoryResponse> {
         Err(RpcError::NotImplemented)
     }
 
-    async fn get_system_info_call(
+    async fn get_connections_call(
         &self,
         _connection: Option<&DynRpcConnection>,
-        _request: GetSystemInfoRequest,
-    ) -> RpcResult<GetSystemInfoResponse> {
+        _request: GetConnectionsRequest,
+    ) -> RpcResult<GetConnectionsResponse> {
         Err(RpcError::NotImplemented)
     }
 
@@ -114,6 +153,14 @@ impl RpcApi for RpcCoreMock {
         Err(RpcError::NotImplemented)
     }
 
+    async fn get_system_info_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetSystemInfoRequest,
+    ) -> RpcResult<GetSystemInfoResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
     async fn get_sync_status_call(
         &self,
         _connection: Option<&DynRpcConnection>,
@@ -230,14 +277,6 @@ impl RpcApi for RpcCoreMock {
         Err(RpcError::NotImplemented)
     }
 
-    async fn get_current_block_color_call(
-        &self,
-        _connection: Option<&DynRpcConnection>,
-        _request: GetCurrentBlockColorRequest,
-    ) -> RpcResult<GetCurrentBlockColorResponse> {
-        Err(RpcError::NotImplemented)
-    }
-
     async fn get_block_count_call(
         &self,
         _connection: Option<&DynRpcConnection>,
@@ -263,6 +302,14 @@ impl RpcApi for RpcCoreMock {
     }
 
     async fn shutdown_call(&self, _connection: Option<&DynRpcConnection>, _request: ShutdownRequest) -> RpcResult<ShutdownResponse> {
+        Ok(ShutdownResponse {})
+    }
+
+    async fn create_database_checkpoint_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: CreateDatabaseCheckpointRequest,
+    ) -> RpcResult<CreateDatabaseCheckpointResponse> {
         Err(RpcError::NotImplemented)
     }
 
@@ -293,9 +340,18 @@ impl RpcApi for RpcCoreMock {
     async fn get_utxos_by_addresses_call(
         &self,
         _connection: Option<&DynRpcConnection>,
-        _request: GetUtxosByAddressesRequest,
+        request: GetUtxosByAddressesRequest,
     ) -> RpcResult<GetUtxosByAddressesResponse> {
-        Err(RpcError::NotImplemented)
+        self.utxos_by_addresses_requests.fetch_add(1, Ordering::Relaxed);
+        let entries = self
+            .utxos
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|entry| entry.address.as_ref().is_some_and(|address| request.addresses.contains(address)))
+            .cloned()
+            .collect();
+        Ok(GetUtxosByAddressesResponse { entries })
     }
 
     async fn get_sink_blue_score_call(
@@ -362,6 +418,70 @@ impl RpcApi for RpcCoreMock {
         Err(RpcError::NotImplemented)
     }
 
+    async fn get_current_block_color_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetCurrentBlockColorRequest,
+    ) -> RpcResult<GetCurrentBlockColorResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn export_dag_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: ExportDagRequest,
+    ) -> RpcResult<ExportDagResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn is_dag_ancestor_of_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: IsDagAncestorOfRequest,
+    ) -> RpcResult<IsDagAncestorOfResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_block_mergeset_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetBlockMergesetRequest,
+    ) -> RpcResult<GetBlockMergesetResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_anticone_size_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetAnticoneSizeRequest,
+    ) -> RpcResult<GetAnticoneSizeResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_chain_path_between_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetChainPathBetweenRequest,
+    ) -> RpcResult<GetChainPathBetweenResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_chain_block_by_daa_score_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetChainBlockByDaaScoreRequest,
+    ) -> RpcResult<GetChainBlockByDaaScoreResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_blocks_by_time_range_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetBlocksByTimeRangeRequest,
+    ) -> RpcResult<GetBlocksByTimeRangeResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
 
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use workflow_serializer::prelude::*;
 
 pub type RpcAddress = calico_addresses::Address;
+pub type RpcUtxoFilter = calico_notify::subscription::filter::UtxoFilter;
 
 /// Represents a UTXO entry of an address returned by the `GetUtxosByAddresses` RPC.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -1956,17 +1956,20 @@ pub struct GetMetricsRequest {
     pub consensus_metrics: bool,
     pub storage_metrics: bool,
     pub custom_metrics: bool,
+    #[serde(default)]
+    pub rpc_method_metrics: bool,
 }
 
 impl Serializer for GetMetricsRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
         store!(bool, &self.process_metrics, writer)?;
         store!(bool, &self.connection_metrics, writer)?;
         store!(bool, &self.bandwidth_metrics, writer)?;
         store!(bool, &self.consensus_metrics, writer)?;
         store!(bool, &self.storage_metrics, writer)?;
         store!(bool, &self.custom_metrics, writer)?;
+        store!(bool, &self.rpc_method_metrics, writer)?;
 
         Ok(())
     }
@@ -1974,15 +1977,24 @@ impl Serializer for GetMetricsRequest {
 
 impl Deserializer for GetMetricsRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let payload_version = load!(u16, reader)?;
         let process_metrics = load!(bool, reader)?;
         let connection_metrics = load!(bool, reader)?;
         let bandwidth_metrics = load!(bool, reader)?;
         let consensus_metrics = load!(bool, reader)?;
         let storage_metrics = load!(bool, reader)?;
         let custom_metrics = load!(bool, reader)?;
+        let rpc_method_metrics = if payload_version > 1 { load!(bool, reader)? } else { false };
 
-        Ok(Self { process_metrics, connection_metrics, bandwidth_metrics, consensus_metrics, storage_metrics, custom_metrics })
+        Ok(Self {
+            process_metrics,
+            connection_metrics,
+            bandwidth_metrics,
+            consensus_metrics,
+            storage_metrics,
+            custom_metrics,
+            rpc_method_metrics,
+        })
     }
 }
 
@@ -2257,6 +2269,46 @@ impl Deserializer for StorageMetrics {
     }
 }
 
+/// Usage counters of an RPC method, accumulated since the node started
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcMethodMetrics {
+    pub method: String,
+    pub calls: u64,
+    pub rate_limited: u64,
+    pub total_cost: u64,
+    pub total_latency_micros: u64,
+    pub max_latency_micros: u64,
+}
+
+impl Serializer for RpcMethodMetrics {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(String, &self.method, writer)?;
+        store!(u64, &self.calls, writer)?;
+        store!(u64, &self.rate_limited, writer)?;
+        store!(u64, &self.total_cost, writer)?;
+        store!(u64, &self.total_latency_micros, writer)?;
+        store!(u64, &self.max_latency_micros, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcMethodMetrics {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let method = load!(String, reader)?;
+        let calls = load!(u64, reader)?;
+        let rate_limited = load!(u64, reader)?;
+        let total_cost = load!(u64, reader)?;
+        let total_latency_micros = load!(u64, reader)?;
+        let max_latency_micros = load!(u64, reader)?;
+
+        Ok(Self { method, calls, rate_limited, total_cost, total_latency_micros, max_latency_micros })
+    }
+}
+
 // TODO: Custom metrics dictionary
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum CustomMetricValue {
@@ -2290,6 +2342,7 @@ pub struct GetMetricsResponse {
     pub storage_metrics: Option<StorageMetrics>,
     // TODO: this is currently a placeholder
     pub custom_metrics: Option<HashMap<String, CustomMetricValue>>,
+    pub rpc_method_metrics: Option<Vec<RpcMethodMetrics>>,
 }
 
 impl GetMetricsResponse {
@@ -2301,6 +2354,7 @@ impl GetMetricsResponse {
         consensus_metrics: Option<ConsensusMetrics>,
         storage_metrics: Option<StorageMetrics>,
         custom_metrics: Option<HashMap<String, CustomMetricValue>>,
+        rpc_method_metrics: Option<Vec<RpcMethodMetrics>>,
     ) -> Self {
         Self {
             process_metrics,
@@ -2310,13 +2364,14 @@ impl GetMetricsResponse {
             storage_metrics,
             server_time,
             custom_metrics,
+            rpc_method_metrics,
         }
     }
 }
 
 impl Serializer for GetMetricsResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
         store!(u64, &self.server_time, writer)?;
         serialize!(Option<ProcessMetrics>, &self.process_metrics, writer)?;
         serialize!(Option<ConnectionMetrics>, &self.connection_metrics, writer)?;
@@ -2324,6 +2379,7 @@ impl Serializer for GetMetricsResponse {
         serialize!(Option<ConsensusMetrics>, &self.consensus_metrics, writer)?;
         serialize!(Option<StorageMetrics>, &self.storage_metrics, writer)?;
         serialize!(Option<HashMap<String, CustomMetricValue>>, &self.custom_metrics, writer)?;
+        serialize!(Option<Vec<RpcMethodMetrics>>, &self.rpc_method_metrics, writer)?;
 
         Ok(())
     }
@@ -2331,7 +2387,7 @@ impl Serializer for GetMetricsResponse {
 
 impl Deserializer for GetMetricsResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let payload_version = load!(u16, reader)?;
         let server_time = load!(u64, reader)?;
         let process_metrics = deserialize!(Option<ProcessMetrics>, reader)?;
         let connection_metrics = deserialize!(Option<ConnectionMetrics>, reader)?;
@@ -2339,6 +2395,7 @@ impl Deserializer for GetMetricsResponse {
         let consensus_metrics = deserialize!(Option<ConsensusMetrics>, reader)?;
         let storage_metrics = deserialize!(Option<StorageMetrics>, reader)?;
         let custom_metrics = deserialize!(Option<HashMap<String, CustomMetricValue>>, reader)?;
+        let rpc_method_metrics = if payload_version > 1 { deserialize!(Option<Vec<RpcMethodMetrics>>, reader)? } else { None };
 
         Ok(Self {
             server_time,
@@ -2348,6 +2405,7 @@ impl Deserializer for GetMetricsResponse {
             consensus_metrics,
             storage_metrics,
             custom_metrics,
+            rpc_method_metrics,
         })
     }
 }
@@ -2393,11 +2451,15 @@ pub struct GetServerInfoResponse {
     pub has_utxo_index: bool,
     pub is_synced: bool,
     pub virtual_daa_score: u64,
+    /// Block data kept by the node for blocks in the past of the pruning point
+    pub retention_policy: RpcRetentionPolicy,
+    /// Number of days block bodies are kept for, set with [`RpcRetentionPolicy::BodiesForDays`]
+    pub body_retention_days: Option<u64>,
 }
 
 impl Serializer for GetServerInfoResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
 
         store!(u16, &self.rpc_api_version, writer)?;
         store!(u16, &self.rpc_api_revision, writer)?;
@@ -2407,6 +2469,8 @@ impl Serializer for GetServerInfoResponse {
         store!(bool, &self.has_utxo_index, writer)?;
         store!(bool, &self.is_synced, writer)?;
         store!(u64, &self.virtual_daa_score, writer)?;
+        store!(RpcRetentionPolicy, &self.retention_policy, writer)?;
+        store!(Option<u64>, &self.body_retention_days, writer)?;
 
         Ok(())
     }
@@ -2414,7 +2478,7 @@ impl Serializer for GetServerInfoResponse {
 
 impl Deserializer for GetServerInfoResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let payload_version = load!(u16, reader)?;
 
         let rpc_api_version = load!(u16, reader)?;
         let rpc_api_revision = load!(u16, reader)?;
@@ -2424,11 +2488,46 @@ impl Deserializer for GetServerInfoResponse {
         let has_utxo_index = load!(bool, reader)?;
         let is_synced = load!(bool, reader)?;
         let virtual_daa_score = load!(u64, reader)?;
+        let (retention_policy, body_retention_days) = if payload_version > 1 {
+            (load!(RpcRetentionPolicy, reader)?, load!(Option<u64>, reader)?)
+        } else {
+            (RpcRetentionPolicy::Unknown, None)
+        };
 
-        Ok(Self { rpc_api_version, rpc_api_revision, server_version, network_id, has_utxo_index, is_synced, virtual_daa_score })
+        Ok(Self {
+            rpc_api_version,
+            rpc_api_revision,
+            server_version,
+            network_id,
+            has_utxo_index,
+            is_synced,
+            virtual_daa_score,
+            retention_policy,
+            body_retention_days,
+        })
     }
 }
 
+/// Block data kept by a node for blocks in the past of its pruning point
+#[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+#[borsh(use_discriminant = true)]
+pub enum RpcRetentionPolicy {
+    /// Reported by servers which predate retention policies
+    #[default]
+    Unknown = 0,
+    /// Only the headers of past pruning points and the data required for validation are kept
+    Pruned = 1,
+    /// All block data is kept
+    Archival = 2,
+    /// Headers are kept, block bodies only for a limited number of days
+    BodiesForDays = 3,
+    /// Headers are kept, along with the bodies and acceptance data of selected chain blocks
+    ChainBodies = 4,
+    /// Headers are kept, along with the acceptance data of selected chain blocks
+    AcceptanceData = 5,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetSyncStatusRequest {}
@@ -2666,614 +2765,1776 @@ impl Deserializer for GetCurrentBlockColorResponse {
     }
 }
 
-// ----------------------------------------------------------------------------
-// Subscriptions & notifications
-// ----------------------------------------------------------------------------
-
-// ~~~~~~~~~~~~~~~~~~~~~~
-// BlockAddedNotification
-
-/// NotifyBlockAddedRequest registers this connection for blockAdded notifications.
-///
-/// See: BlockAddedNotification
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Resolution of the samples of the node metrics history
+#[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyBlockAddedRequest {
-    pub command: Command,
+#[borsh(use_discriminant = true)]
+pub enum RpcMetricsResolution {
+    /// One sample per second
+    #[default]
+    Second = 0,
+    /// One sample per minute, averaging the samples of the minute
+    Minute = 1,
+    /// One sample per hour, averaging the samples of the hour
+    Hour = 2,
+}
+
+impl RpcMetricsResolution {
+    /// Time span covered by a sample, in milliseconds
+    pub fn duration_millis(&self) -> u64 {
+        match self {
+            RpcMetricsResolution::Second => 1_000,
+            RpcMetricsResolution::Minute => 60_000,
+            RpcMetricsResolution::Hour => 3_600_000,
+        }
+    }
 }
-impl NotifyBlockAddedRequest {
-    pub fn new(command: Command) -> Self {
-        Self { command }
+
+impl Display for RpcMetricsResolution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RpcMetricsResolution::Second => f.write_str("second"),
+            RpcMetricsResolution::Minute => f.write_str("minute"),
+            RpcMetricsResolution::Hour => f.write_str("hour"),
+        }
     }
 }
 
-impl Serializer for NotifyBlockAddedRequest {
+/// GetMetricsHistoryRequest requests the samples of node metrics retained by the node
+/// over a time range, allowing dashboards to backfill their graphs after connecting.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GetMetricsHistoryRequest {
+    /// Start of the time range in unix milliseconds, from the oldest retained sample if `None`
+    pub start_time: Option<u64>,
+    /// End of the time range in unix milliseconds, up to the latest sample if `None`
+    pub end_time: Option<u64>,
+    pub resolution: RpcMetricsResolution,
+    /// Names of the requested metrics, all metrics if empty
+    pub metrics: Vec<String>,
+}
+
+impl Serializer for GetMetricsHistoryRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(Command, &self.command, writer)?;
+        store!(Option<u64>, &self.start_time, writer)?;
+        store!(Option<u64>, &self.end_time, writer)?;
+        store!(RpcMetricsResolution, &self.resolution, writer)?;
+        store!(Vec<String>, &self.metrics, writer)?;
+
         Ok(())
     }
 }
 
-impl Deserializer for NotifyBlockAddedRequest {
+impl Deserializer for GetMetricsHistoryRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let command = load!(Command, reader)?;
-        Ok(Self { command })
+        let start_time = load!(Option<u64>, reader)?;
+        let end_time = load!(Option<u64>, reader)?;
+        let resolution = load!(RpcMetricsResolution, reader)?;
+        let metrics = load!(Vec<String>, reader)?;
+
+        Ok(Self { start_time, end_time, resolution, metrics })
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Values of the node metrics at a point in time
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyBlockAddedResponse {}
+pub struct RpcMetricsHistorySample {
+    /// Start of the time span covered by the sample, in unix milliseconds
+    pub timestamp: u64,
+    /// Metric values, in the order of the metric names of the response
+    pub values: Vec<f64>,
+}
 
-impl Serializer for NotifyBlockAddedResponse {
+impl Serializer for RpcMetricsHistorySample {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
+        store!(u64, &self.timestamp, writer)?;
+        store!(Vec<f64>, &self.values, writer)?;
+
         Ok(())
     }
 }
 
-impl Deserializer for NotifyBlockAddedResponse {
+impl Deserializer for RpcMetricsHistorySample {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        Ok(Self {})
+        let timestamp = load!(u64, reader)?;
+        let values = load!(Vec<f64>, reader)?;
+
+        Ok(Self { timestamp, values })
     }
 }
 
-/// BlockAddedNotification is sent whenever a blocks has been added (NOT accepted)
-/// into the DAG.
-///
-/// See: NotifyBlockAddedRequest
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct BlockAddedNotification {
-    pub block: Arc<RpcBlock>,
+pub struct GetMetricsHistoryResponse {
+    pub resolution: RpcMetricsResolution,
+    /// Names of the metrics, in the order of the sample values
+    pub metrics: Vec<String>,
+    /// Samples ordered by ascending timestamp
+    pub samples: Vec<RpcMetricsHistorySample>,
 }
 
-impl Serializer for BlockAddedNotification {
+impl Serializer for GetMetricsHistoryResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        serialize!(RpcBlock, &self.block, writer)?;
+        store!(RpcMetricsResolution, &self.resolution, writer)?;
+        store!(Vec<String>, &self.metrics, writer)?;
+        serialize!(Vec<RpcMetricsHistorySample>, &self.samples, writer)?;
+
         Ok(())
     }
 }
 
-impl Deserializer for BlockAddedNotification {
+impl Deserializer for GetMetricsHistoryResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let block = deserialize!(RpcBlock, reader)?;
-        Ok(Self { block: block.into() })
-    }
-}
-
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// VirtualChainChangedNotification
+        let resolution = load!(RpcMetricsResolution, reader)?;
+        let metrics = load!(Vec<String>, reader)?;
+        let samples = deserialize!(Vec<RpcMetricsHistorySample>, reader)?;
 
-// NotifyVirtualChainChangedRequest registers this connection for
-// virtualDaaScoreChanged notifications.
-//
-// See: VirtualChainChangedNotification
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct NotifyVirtualChainChangedRequest {
-    pub include_accepted_transaction_ids: bool,
-    pub command: Command,
+        Ok(Self { resolution, metrics, samples })
+    }
 }
 
-impl NotifyVirtualChainChangedRequest {
-    pub fn new(include_accepted_transaction_ids: bool, command: Command) -> Self {
-        Self { include_accepted_transaction_ids, command }
-    }
+/// CreateDatabaseCheckpointRequest starts creating a point-in-time copy of the active consensus
+/// and meta databases of the node. The checkpoint is taken at a consistent virtual state and
+/// its progress is reported by `DatabaseCheckpointNotification`s.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CreateDatabaseCheckpointRequest {
+    /// Target directory of the checkpoint, which must not exist. A timestamped directory
+    /// under the node backup directory is used if `None`.
+    pub directory: Option<String>,
 }
 
-impl Serializer for NotifyVirtualChainChangedRequest {
+impl Serializer for CreateDatabaseCheckpointRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(bool, &self.include_accepted_transaction_ids, writer)?;
-        store!(Command, &self.command, writer)?;
+        store!(Option<String>, &self.directory, writer)?;
+
         Ok(())
     }
 }
 
-impl Deserializer for NotifyVirtualChainChangedRequest {
+impl Deserializer for CreateDatabaseCheckpointRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let include_accepted_transaction_ids = load!(bool, reader)?;
-        let command = load!(Command, reader)?;
-        Ok(Self { include_accepted_transaction_ids, command })
+        let directory = load!(Option<String>, reader)?;
+
+        Ok(Self { directory })
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyVirtualChainChangedResponse {}
+pub struct CreateDatabaseCheckpointResponse {
+    /// Directory the checkpoint is being written to
+    pub directory: String,
+}
 
-impl Serializer for NotifyVirtualChainChangedResponse {
+impl Serializer for CreateDatabaseCheckpointResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
+        store!(String, &self.directory, writer)?;
+
         Ok(())
     }
 }
 
-impl Deserializer for NotifyVirtualChainChangedResponse {
+impl Deserializer for CreateDatabaseCheckpointResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        Ok(Self {})
+        let directory = load!(String, reader)?;
+
+        Ok(Self { directory })
     }
 }
 
-// VirtualChainChangedNotification is sent whenever the DAG's selected parent
-// chain had changed.
-//
-// See: NotifyVirtualChainChangedRequest
+/// Range of blocks of an exported sub-DAG
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum RpcDagRange {
+    /// The blocks in the future of `low_hash` and in the past of `high_hash`, both included
+    #[serde(rename_all = "camelCase")]
+    Hashes { low_hash: RpcHash, high_hash: RpcHash },
+    /// The blocks between the highest selected chain blocks with a DAA score lower than or
+    /// equal to `low_daa_score` and to `high_daa_score`, both included
+    #[serde(rename_all = "camelCase")]
+    DaaScores { low_daa_score: u64, high_daa_score: u64 },
+}
+
+impl Display for RpcDagRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RpcDagRange::Hashes { low_hash, high_hash } => write!(f, "{low_hash}..{high_hash}"),
+            RpcDagRange::DaaScores { low_daa_score, high_daa_score } => write!(f, "DAA score {low_daa_score}..{high_daa_score}"),
+        }
+    }
+}
+
+/// ExportDagRequest requests the GHOSTDAG data of the blocks of a sub-DAG, allowing
+/// to analyze the colouring, merge sets and selected chain over a range of blocks.
+/// At most [`ExportDagRequest::MAX_BLOCKS`] blocks are exported.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct VirtualChainChangedNotification {
-    pub removed_chain_block_hashes: Arc<Vec<RpcHash>>,
-    pub added_chain_block_hashes: Arc<Vec<RpcHash>>,
-    pub accepted_transaction_ids: Arc<Vec<RpcAcceptedTransactionIds>>,
+pub struct ExportDagRequest {
+    pub range: RpcDagRange,
 }
 
-impl Serializer for VirtualChainChangedNotification {
+impl ExportDagRequest {
+    /// Largest number of blocks exported by a single request
+    pub const MAX_BLOCKS: u64 = 5_000;
+}
+
+impl Serializer for ExportDagRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(Vec<RpcHash>, &self.removed_chain_block_hashes, writer)?;
-        store!(Vec<RpcHash>, &self.added_chain_block_hashes, writer)?;
-        store!(Vec<RpcAcceptedTransactionIds>, &self.accepted_transaction_ids, writer)?;
+        store!(RpcDagRange, &self.range, writer)?;
+
         Ok(())
     }
 }
 
-impl Deserializer for VirtualChainChangedNotification {
+impl Deserializer for ExportDagRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let removed_chain_block_hashes = load!(Vec<RpcHash>, reader)?;
-        let added_chain_block_hashes = load!(Vec<RpcHash>, reader)?;
-        let accepted_transaction_ids = load!(Vec<RpcAcceptedTransactionIds>, reader)?;
-        Ok(Self {
-            removed_chain_block_hashes: removed_chain_block_hashes.into(),
-            added_chain_block_hashes: added_chain_block_hashes.into(),
-            accepted_transaction_ids: accepted_transaction_ids.into(),
-        })
+        let range = load!(RpcDagRange, reader)?;
+
+        Ok(Self { range })
     }
 }
 
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// FinalityConflictNotification
-
+/// A block of an exported sub-DAG with its GHOSTDAG data
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyFinalityConflictRequest {
-    pub command: Command,
-}
-
-impl NotifyFinalityConflictRequest {
-    pub fn new(command: Command) -> Self {
-        Self { command }
-    }
+pub struct RpcDagBlock {
+    pub hash: RpcHash,
+    /// Direct parents of the block, which may lie outside of the exported sub-DAG
+    pub parents: Vec<RpcHash>,
+    pub selected_parent: RpcHash,
+    pub blue_score: u64,
+    pub blue_work: RpcBlueWorkType,
+    pub daa_score: u64,
+    /// Blue blocks of the merge set, the selected parent being the first
+    pub mergeset_blues: Vec<RpcHash>,
+    pub mergeset_reds: Vec<RpcHash>,
+    /// Whether the block is on the selected chain of the sink
+    pub is_chain_block: bool,
 }
 
-impl Serializer for NotifyFinalityConflictRequest {
+impl Serializer for RpcDagBlock {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(Command, &self.command, writer)?;
+        store!(RpcHash, &self.hash, writer)?;
+        store!(Vec<RpcHash>, &self.parents, writer)?;
+        store!(RpcHash, &self.selected_parent, writer)?;
+        store!(u64, &self.blue_score, writer)?;
+        store!(RpcBlueWorkType, &self.blue_work, writer)?;
+        store!(u64, &self.daa_score, writer)?;
+        store!(Vec<RpcHash>, &self.mergeset_blues, writer)?;
+        store!(Vec<RpcHash>, &self.mergeset_reds, writer)?;
+        store!(bool, &self.is_chain_block, writer)?;
+
         Ok(())
     }
 }
 
-impl Deserializer for NotifyFinalityConflictRequest {
+impl Deserializer for RpcDagBlock {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let command = load!(Command, reader)?;
-        Ok(Self { command })
+        let hash = load!(RpcHash, reader)?;
+        let parents = load!(Vec<RpcHash>, reader)?;
+        let selected_parent = load!(RpcHash, reader)?;
+        let blue_score = load!(u64, reader)?;
+        let blue_work = load!(RpcBlueWorkType, reader)?;
+        let daa_score = load!(u64, reader)?;
+        let mergeset_blues = load!(Vec<RpcHash>, reader)?;
+        let mergeset_reds = load!(Vec<RpcHash>, reader)?;
+        let is_chain_block = load!(bool, reader)?;
+
+        Ok(Self { hash, parents, selected_parent, blue_score, blue_work, daa_score, mergeset_blues, mergeset_reds, is_chain_block })
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyFinalityConflictResponse {}
+pub struct ExportDagResponse {
+    /// Lowest block of the sub-DAG, a DAG ancestor of all the exported blocks
+    pub low_hash: RpcHash,
+    /// Highest block of the sub-DAG, a DAG descendant of all the exported blocks
+    pub high_hash: RpcHash,
+    /// Blocks of the sub-DAG ordered topologically by blue work
+    pub blocks: Vec<RpcDagBlock>,
+}
 
-impl Serializer for NotifyFinalityConflictResponse {
+impl Serializer for ExportDagResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
+        store!(RpcHash, &self.low_hash, writer)?;
+        store!(RpcHash, &self.high_hash, writer)?;
+        serialize!(Vec<RpcDagBlock>, &self.blocks, writer)?;
+
         Ok(())
     }
 }
 
-impl Deserializer for NotifyFinalityConflictResponse {
+impl Deserializer for ExportDagResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        Ok(Self {})
+        let low_hash = load!(RpcHash, reader)?;
+        let high_hash = load!(RpcHash, reader)?;
+        let blocks = deserialize!(Vec<RpcDagBlock>, reader)?;
+
+        Ok(Self { low_hash, high_hash, blocks })
     }
 }
 
+/// IsDagAncestorOfRequest requests whether a block is in the past of another block,
+/// as determined by the reachability index of the node.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct FinalityConflictNotification {
-    pub violating_block_hash: RpcHash,
+pub struct IsDagAncestorOfRequest {
+    pub low_hash: RpcHash,
+    pub high_hash: RpcHash,
 }
 
-impl Serializer for FinalityConflictNotification {
+impl Serializer for IsDagAncestorOfRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(RpcHash, &self.violating_block_hash, writer)?;
+        store!(RpcHash, &self.low_hash, writer)?;
+        store!(RpcHash, &self.high_hash, writer)?;
+
         Ok(())
     }
 }
 
-impl Deserializer for FinalityConflictNotification {
+impl Deserializer for IsDagAncestorOfRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let violating_block_hash = load!(RpcHash, reader)?;
-        Ok(Self { violating_block_hash })
+        let low_hash = load!(RpcHash, reader)?;
+        let high_hash = load!(RpcHash, reader)?;
+
+        Ok(Self { low_hash, high_hash })
     }
 }
 
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// FinalityConflictResolvedNotification
-
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyFinalityConflictResolvedRequest {
-    pub command: Command,
-}
-
-impl NotifyFinalityConflictResolvedRequest {
-    pub fn new(command: Command) -> Self {
-        Self { command }
-    }
+pub struct IsDagAncestorOfResponse {
+    /// Whether the low block is in the past of the high block, a block being its own ancestor
+    pub is_dag_ancestor_of: bool,
+    /// Whether the low block is on the selected chain of the high block
+    pub is_chain_ancestor_of: bool,
+    /// Blue score of the high block minus the blue score of the low block, set if the low block is a DAG ancestor
+    pub blue_score_distance: Option<u64>,
 }
 
-impl Serializer for NotifyFinalityConflictResolvedRequest {
+impl Serializer for IsDagAncestorOfResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(Command, &self.command, writer)?;
+        store!(bool, &self.is_dag_ancestor_of, writer)?;
+        store!(bool, &self.is_chain_ancestor_of, writer)?;
+        store!(Option<u64>, &self.blue_score_distance, writer)?;
+
         Ok(())
     }
 }
 
-impl Deserializer for NotifyFinalityConflictResolvedRequest {
+impl Deserializer for IsDagAncestorOfResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let command = load!(Command, reader)?;
-        Ok(Self { command })
+        let is_dag_ancestor_of = load!(bool, reader)?;
+        let is_chain_ancestor_of = load!(bool, reader)?;
+        let blue_score_distance = load!(Option<u64>, reader)?;
+
+        Ok(Self { is_dag_ancestor_of, is_chain_ancestor_of, blue_score_distance })
     }
 }
 
+/// GetBlockMergesetRequest requests the merge set of a block, as coloured by GHOSTDAG.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyFinalityConflictResolvedResponse {}
+pub struct GetBlockMergesetRequest {
+    pub hash: RpcHash,
+}
 
-impl Serializer for NotifyFinalityConflictResolvedResponse {
+impl Serializer for GetBlockMergesetRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
+        store!(RpcHash, &self.hash, writer)?;
+
         Ok(())
     }
 }
 
-impl Deserializer for NotifyFinalityConflictResolvedResponse {
+impl Deserializer for GetBlockMergesetRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        Ok(Self {})
+        let hash = load!(RpcHash, reader)?;
+
+        Ok(Self { hash })
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct FinalityConflictResolvedNotification {
-    pub finality_block_hash: RpcHash,
+pub struct GetBlockMergesetResponse {
+    pub selected_parent: RpcHash,
+    /// Blue blocks of the merge set, the selected parent being the first
+    pub mergeset_blues: Vec<RpcHash>,
+    pub mergeset_reds: Vec<RpcHash>,
 }
 
-impl Serializer for FinalityConflictResolvedNotification {
+impl Serializer for GetBlockMergesetResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(RpcHash, &self.finality_block_hash, writer)?;
+        store!(RpcHash, &self.selected_parent, writer)?;
+        store!(Vec<RpcHash>, &self.mergeset_blues, writer)?;
+        store!(Vec<RpcHash>, &self.mergeset_reds, writer)?;
+
         Ok(())
     }
 }
 
-impl Deserializer for FinalityConflictResolvedNotification {
+impl Deserializer for GetBlockMergesetResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let finality_block_hash = load!(RpcHash, reader)?;
-        Ok(Self { finality_block_hash })
+        let selected_parent = load!(RpcHash, reader)?;
+        let mergeset_blues = load!(Vec<RpcHash>, reader)?;
+        let mergeset_reds = load!(Vec<RpcHash>, reader)?;
+
+        Ok(Self { selected_parent, mergeset_blues, mergeset_reds })
     }
 }
 
-// ~~~~~~~~~~~~~~~~~~~~~~~~
-// UtxosChangedNotification
+/// GetAnticoneSizeRequest requests the size of the anticone of a block from the point of view
+/// of the virtual block. Unless the node runs with unsafe RPC enabled, the request fails if the
+/// anticone holds more than [`GetAnticoneSizeRequest::MAX_TRAVERSAL`] blocks.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAnticoneSizeRequest {
+    pub hash: RpcHash,
+}
 
-// NotifyUtxosChangedRequestMessage registers this connection for utxoChanged notifications
-// for the given addresses. Depending on the provided `command`, notifications will
-// start or stop for the provided `addresses`.
-//
-// If `addresses` is empty, the notifications will start or stop for all addresses.
+impl GetAnticoneSizeRequest {
+    /// Largest number of blocks traversed by a single request
+    pub const MAX_TRAVERSAL: u64 = 5_000;
+}
+
+impl Serializer for GetAnticoneSizeRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcHash, &self.hash, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetAnticoneSizeRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let hash = load!(RpcHash, reader)?;
+
+        Ok(Self { hash })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAnticoneSizeResponse {
+    pub anticone_size: u64,
+}
+
+impl Serializer for GetAnticoneSizeResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u64, &self.anticone_size, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetAnticoneSizeResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let anticone_size = load!(u64, reader)?;
+
+        Ok(Self { anticone_size })
+    }
+}
+
+/// GetChainPathBetweenRequest requests the selected chain path from a block to another block:
+/// the chain blocks to remove from the chain of `from_hash` down to the common chain ancestor,
+/// then the chain blocks to add from there up to `to_hash`. Unless the node runs with unsafe RPC
+/// enabled, the added chain blocks are limited to [`GetChainPathBetweenRequest::MAX_CHAIN_BLOCKS`]
+/// and the request fails if more chain blocks are to be removed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetChainPathBetweenRequest {
+    pub from_hash: RpcHash,
+    pub to_hash: RpcHash,
+}
+
+impl GetChainPathBetweenRequest {
+    /// Largest number of chain blocks added or removed by a single request
+    pub const MAX_CHAIN_BLOCKS: u64 = 5_000;
+}
+
+impl Serializer for GetChainPathBetweenRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcHash, &self.from_hash, writer)?;
+        store!(RpcHash, &self.to_hash, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetChainPathBetweenRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let from_hash = load!(RpcHash, reader)?;
+        let to_hash = load!(RpcHash, reader)?;
+
+        Ok(Self { from_hash, to_hash })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetChainPathBetweenResponse {
+    /// Chain blocks of `from_hash` down to the common chain ancestor (excluded), from the highest
+    pub removed_chain_block_hashes: Vec<RpcHash>,
+    /// Chain blocks from the common chain ancestor (excluded) up to `to_hash`, from the lowest
+    pub added_chain_block_hashes: Vec<RpcHash>,
+    /// Whether the added chain blocks were limited and stop below `to_hash`
+    pub truncated: bool,
+}
+
+impl Serializer for GetChainPathBetweenResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Vec<RpcHash>, &self.removed_chain_block_hashes, writer)?;
+        store!(Vec<RpcHash>, &self.added_chain_block_hashes, writer)?;
+        store!(bool, &self.truncated, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetChainPathBetweenResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let removed_chain_block_hashes = load!(Vec<RpcHash>, reader)?;
+        let added_chain_block_hashes = load!(Vec<RpcHash>, reader)?;
+        let truncated = load!(bool, reader)?;
+
+        Ok(Self { removed_chain_block_hashes, added_chain_block_hashes, truncated })
+    }
+}
+
+/// A selected chain block located by the chain index
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcIndexedChainBlock {
+    pub hash: RpcHash,
+    pub daa_score: u64,
+    pub timestamp: u64,
+}
+
+impl Serializer for RpcIndexedChainBlock {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcHash, &self.hash, writer)?;
+        store!(u64, &self.daa_score, writer)?;
+        store!(u64, &self.timestamp, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcIndexedChainBlock {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let hash = load!(RpcHash, reader)?;
+        let daa_score = load!(u64, reader)?;
+        let timestamp = load!(u64, reader)?;
+
+        Ok(Self { hash, daa_score, timestamp })
+    }
+}
+
+/// GetChainBlockByDaaScoreRequest requests the selected chain block with the highest
+/// DAA score not above `daa_score`. Requires the node to run with the chain index enabled.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetChainBlockByDaaScoreRequest {
+    pub daa_score: u64,
+}
+
+impl Serializer for GetChainBlockByDaaScoreRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u64, &self.daa_score, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetChainBlockByDaaScoreRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let daa_score = load!(u64, reader)?;
+
+        Ok(Self { daa_score })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetChainBlockByDaaScoreResponse {
+    pub block: RpcIndexedChainBlock,
+}
+
+impl Serializer for GetChainBlockByDaaScoreResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(RpcIndexedChainBlock, &self.block, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetChainBlockByDaaScoreResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let block = deserialize!(RpcIndexedChainBlock, reader)?;
+
+        Ok(Self { block })
+    }
+}
+
+/// GetBlocksByTimeRangeRequest requests the blocks between two timestamps: the selected chain blocks
+/// with a timestamp in `[start_timestamp, end_timestamp)`, ordered by timestamp and then hash, together
+/// with the blocks merged by them. Pages are counted in chain blocks. To fetch the next page, pass the
+/// timestamp and hash of the last returned chain block as `start_timestamp` and `after_hash`. A `limit`
+/// of 0 requests the largest page size the node allows. Requires the node to run with the chain index
+/// enabled.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBlocksByTimeRangeRequest {
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+    pub after_hash: Option<RpcHash>,
+    pub limit: u32,
+}
+
+impl GetBlocksByTimeRangeRequest {
+    /// Largest number of chain blocks returned in a single page
+    pub const MAX_LIMIT: u32 = 1_000;
+
+    /// Page size served for this request, where a `limit` of 0 stands for the largest page size
+    pub fn effective_limit(&self) -> u32 {
+        match self.limit {
+            0 => Self::MAX_LIMIT,
+            limit => limit.min(Self::MAX_LIMIT),
+        }
+    }
+}
+
+impl Serializer for GetBlocksByTimeRangeRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u64, &self.start_timestamp, writer)?;
+        store!(u64, &self.end_timestamp, writer)?;
+        store!(Option<RpcHash>, &self.after_hash, writer)?;
+        store!(u32, &self.limit, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetBlocksByTimeRangeRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let start_timestamp = load!(u64, reader)?;
+        let end_timestamp = load!(u64, reader)?;
+        let after_hash = load!(Option<RpcHash>, reader)?;
+        let limit = load!(u32, reader)?;
+
+        Ok(Self { start_timestamp, end_timestamp, after_hash, limit })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBlocksByTimeRangeResponse {
+    pub blocks: Vec<RpcIndexedChainBlock>,
+    /// Hashes of the non-chain blocks merged by `blocks`, in chain order and then mergeset order
+    pub merged_block_hashes: Vec<RpcHash>,
+    /// Whether more chain blocks fall in the requested range after the last returned block
+    pub has_more: bool,
+}
+
+impl Serializer for GetBlocksByTimeRangeResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(Vec<RpcIndexedChainBlock>, &self.blocks, writer)?;
+        store!(Vec<RpcHash>, &self.merged_block_hashes, writer)?;
+        store!(bool, &self.has_more, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetBlocksByTimeRangeResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let blocks = deserialize!(Vec<RpcIndexedChainBlock>, reader)?;
+        let merged_block_hashes = load!(Vec<RpcHash>, reader)?;
+        let has_more = load!(bool, reader)?;
+
+        Ok(Self { blocks, merged_block_hashes, has_more })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Subscriptions & notifications
+// ----------------------------------------------------------------------------
+
+// ~~~~~~~~~~~~~~~~~~~~~~
+// BlockAddedNotification
+
+/// NotifyBlockAddedRequest registers this connection for blockAdded notifications.
+///
+/// See: BlockAddedNotification
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyBlockAddedRequest {
+    pub command: Command,
+}
+impl NotifyBlockAddedRequest {
+    pub fn new(command: Command) -> Self {
+        Self { command }
+    }
+}
+
+impl Serializer for NotifyBlockAddedRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Command, &self.command, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyBlockAddedRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let command = load!(Command, reader)?;
+        Ok(Self { command })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyBlockAddedResponse {}
+
+impl Serializer for NotifyBlockAddedResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyBlockAddedResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+/// BlockAddedNotification is sent whenever a blocks has been added (NOT accepted)
+/// into the DAG.
+///
+/// See: NotifyBlockAddedRequest
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockAddedNotification {
+    pub block: Arc<RpcBlock>,
+}
+
+impl Serializer for BlockAddedNotification {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(RpcBlock, &self.block, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for BlockAddedNotification {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let block = deserialize!(RpcBlock, reader)?;
+        Ok(Self { block: block.into() })
+    }
+}
+
+/// Position of a notification in the stream of a server supporting resumable subscriptions
+pub type RpcNotificationCursor = calico_notify::replay::Cursor;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// VirtualChainChangedNotification
+
+// NotifyVirtualChainChangedRequest registers this connection for
+// virtualDaaScoreChanged notifications.
+//
+// When starting notifications, an optional `resume_from` cursor asks the server
+// to first replay the notifications following this cursor. The request fails
+// with a notification gap error if the server does not retain them anymore.
+//
+// See: VirtualChainChangedNotification
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyVirtualChainChangedRequest {
+    pub include_accepted_transaction_ids: bool,
+    pub command: Command,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resume_from: Option<RpcNotificationCursor>,
+}
+
+impl NotifyVirtualChainChangedRequest {
+    pub fn new(include_accepted_transaction_ids: bool, command: Command) -> Self {
+        Self { include_accepted_transaction_ids, command, resume_from: None }
+    }
+
+    pub fn with_resume_from(mut self, resume_from: Option<RpcNotificationCursor>) -> Self {
+        self.resume_from = resume_from;
+        self
+    }
+}
+
+impl Serializer for NotifyVirtualChainChangedRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &2, writer)?;
+        store!(bool, &self.include_accepted_transaction_ids, writer)?;
+        store!(Command, &self.command, writer)?;
+        store!(Option<RpcNotificationCursor>, &self.resume_from, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyVirtualChainChangedRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let payload_version = load!(u16, reader)?;
+        let include_accepted_transaction_ids = load!(bool, reader)?;
+        let command = load!(Command, reader)?;
+        let resume_from = if payload_version > 1 { load!(Option<RpcNotificationCursor>, reader)? } else { None };
+        Ok(Self { include_accepted_transaction_ids, command, resume_from })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyVirtualChainChangedResponse {}
+
+impl Serializer for NotifyVirtualChainChangedResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyVirtualChainChangedResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+// VirtualChainChangedNotification is sent whenever the DAG's selected parent
+// chain had changed.
+//
+// See: NotifyVirtualChainChangedRequest
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VirtualChainChangedNotification {
+    pub removed_chain_block_hashes: Arc<Vec<RpcHash>>,
+    pub added_chain_block_hashes: Arc<Vec<RpcHash>>,
+    pub accepted_transaction_ids: Arc<Vec<RpcAcceptedTransactionIds>>,
+    /// Position of the notification in the stream, usable for resuming it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<RpcNotificationCursor>,
+}
+
+impl Serializer for VirtualChainChangedNotification {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &2, writer)?;
+        store!(Vec<RpcHash>, &self.removed_chain_block_hashes, writer)?;
+        store!(Vec<RpcHash>, &self.added_chain_block_hashes, writer)?;
+        store!(Vec<RpcAcceptedTransactionIds>, &self.accepted_transaction_ids, writer)?;
+        store!(Option<RpcNotificationCursor>, &self.cursor, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for VirtualChainChangedNotification {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let payload_version = load!(u16, reader)?;
+        let removed_chain_block_hashes = load!(Vec<RpcHash>, reader)?;
+        let added_chain_block_hashes = load!(Vec<RpcHash>, reader)?;
+        let accepted_transaction_ids = load!(Vec<RpcAcceptedTransactionIds>, reader)?;
+        let cursor = if payload_version > 1 { load!(Option<RpcNotificationCursor>, reader)? } else { None };
+        Ok(Self {
+            removed_chain_block_hashes: removed_chain_block_hashes.into(),
+            added_chain_block_hashes: added_chain_block_hashes.into(),
+            accepted_transaction_ids: accepted_transaction_ids.into(),
+            cursor,
+        })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FinalityConflictNotification
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyFinalityConflictRequest {
+    pub command: Command,
+}
+
+impl NotifyFinalityConflictRequest {
+    pub fn new(command: Command) -> Self {
+        Self { command }
+    }
+}
+
+impl Serializer for NotifyFinalityConflictRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Command, &self.command, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyFinalityConflictRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let command = load!(Command, reader)?;
+        Ok(Self { command })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyFinalityConflictResponse {}
+
+impl Serializer for NotifyFinalityConflictResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyFinalityConflictResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinalityConflictNotification {
+    pub violating_block_hash: RpcHash,
+}
+
+impl Serializer for FinalityConflictNotification {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcHash, &self.violating_block_hash, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for FinalityConflictNotification {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let violating_block_hash = load!(RpcHash, reader)?;
+        Ok(Self { violating_block_hash })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FinalityConflictResolvedNotification
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyFinalityConflictResolvedRequest {
+    pub command: Command,
+}
+
+impl NotifyFinalityConflictResolvedRequest {
+    pub fn new(command: Command) -> Self {
+        Self { command }
+    }
+}
+
+impl Serializer for NotifyFinalityConflictResolvedRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Command, &self.command, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyFinalityConflictResolvedRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let command = load!(Command, reader)?;
+        Ok(Self { command })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyFinalityConflictResolvedResponse {}
+
+impl Serializer for NotifyFinalityConflictResolvedResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyFinalityConflictResolvedResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinalityConflictResolvedNotification {
+    pub finality_block_hash: RpcHash,
+}
+
+impl Serializer for FinalityConflictResolvedNotification {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcHash, &self.finality_block_hash, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for FinalityConflictResolvedNotification {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let finality_block_hash = load!(RpcHash, reader)?;
+        Ok(Self { finality_block_hash })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~
+// UtxosChangedNotification
+
+// NotifyUtxosChangedRequestMessage registers this connection for utxoChanged notifications
+// for the given addresses. Depending on the provided `command`, notifications will
+// start or stop for the provided `addresses`.
+//
+// If `addresses` is empty, the notifications will start or stop for all addresses.
+//
+// When starting notifications, an optional `filter` further restricts the UTXOs
+// notified by script class, amount range and script public key prefix. A blanket
+// subscription is allowed in safe RPC mode only if its filter is selective, i.e.
+// excludes the pay-to-pubkey script classes or selects long enough script prefixes.
+//
+// When starting notifications, an optional `resume_from` cursor asks the server
+// to first replay the notifications following this cursor. The request fails
+// with a notification gap error if the server does not retain them anymore.
+//
+// This call is only available when this calicod was started with `--utxoindex`
+//
+// See: UtxosChangedNotification
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyUtxosChangedRequest {
+    pub addresses: Vec<RpcAddress>,
+    pub command: Command,
+    #[serde(default)]
+    pub filter: RpcUtxoFilter,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resume_from: Option<RpcNotificationCursor>,
+}
+
+impl NotifyUtxosChangedRequest {
+    pub fn new(addresses: Vec<RpcAddress>, command: Command) -> Self {
+        Self { addresses, command, filter: Default::default(), resume_from: None }
+    }
+
+    pub fn with_filter(mut self, filter: RpcUtxoFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn with_resume_from(mut self, resume_from: Option<RpcNotificationCursor>) -> Self {
+        self.resume_from = resume_from;
+        self
+    }
+}
+
+impl Serializer for NotifyUtxosChangedRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &3, writer)?;
+        store!(Vec<RpcAddress>, &self.addresses, writer)?;
+        store!(Command, &self.command, writer)?;
+        serialize!(RpcUtxoFilter, &self.filter, writer)?;
+        store!(Option<RpcNotificationCursor>, &self.resume_from, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyUtxosChangedRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let payload_version = load!(u16, reader)?;
+        let addresses = load!(Vec<RpcAddress>, reader)?;
+        let command = load!(Command, reader)?;
+        let filter = if payload_version > 1 { deserialize!(RpcUtxoFilter, reader)? } else { Default::default() };
+        let resume_from = if payload_version > 2 { load!(Option<RpcNotificationCursor>, reader)? } else { None };
+        Ok(Self { addresses, command, filter, resume_from })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyUtxosChangedResponse {}
+
+impl Serializer for NotifyUtxosChangedResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyUtxosChangedResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+// UtxosChangedNotificationMessage is sent whenever the UTXO index had been updated.
 //
-// This call is only available when this calicod was started with `--utxoindex`
+// See: NotifyUtxosChangedRequest
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UtxosChangedNotification {
+    pub added: Arc<Vec<RpcUtxosByAddressesEntry>>,
+    pub removed: Arc<Vec<RpcUtxosByAddressesEntry>>,
+    /// Position of the notification in the stream, usable for resuming it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<RpcNotificationCursor>,
+}
+
+impl UtxosChangedNotification {
+    pub(crate) fn apply_utxos_changed_subscription(
+        &self,
+        subscription: &UtxosChangedSubscription,
+        context: &SubscriptionContext,
+    ) -> Option<Self> {
+        if subscription.to_all() {
+            Some(self.clone())
+        } else {
+            let added = Self::filter_utxos(&self.added, subscription, context);
+            let removed = Self::filter_utxos(&self.removed, subscription, context);
+            if added.is_empty() && removed.is_empty() {
+                None
+            } else {
+                debug!("CRPC, Creating UtxosChanged notifications with {} added and {} removed utxos", added.len(), removed.len());
+                Some(Self { added: Arc::new(added), removed: Arc::new(removed), cursor: self.cursor })
+            }
+        }
+    }
+
+    fn filter_utxos(
+        utxo_set: &[RpcUtxosByAddressesEntry],
+        subscription: &UtxosChangedSubscription,
+        context: &SubscriptionContext,
+    ) -> Vec<RpcUtxosByAddressesEntry> {
+        let subscription_data = subscription.data();
+        utxo_set
+            .iter()
+            .filter(|x| subscription_data.selects(&x.utxo_entry.script_public_key, x.utxo_entry.amount, context))
+            .cloned()
+            .collect()
+    }
+}
+
+impl Serializer for UtxosChangedNotification {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &2, writer)?;
+        serialize!(Vec<RpcUtxosByAddressesEntry>, &self.added, writer)?;
+        serialize!(Vec<RpcUtxosByAddressesEntry>, &self.removed, writer)?;
+        store!(Option<RpcNotificationCursor>, &self.cursor, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for UtxosChangedNotification {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let payload_version = load!(u16, reader)?;
+        let added = deserialize!(Vec<RpcUtxosByAddressesEntry>, reader)?;
+        let removed = deserialize!(Vec<RpcUtxosByAddressesEntry>, reader)?;
+        let cursor = if payload_version > 1 { load!(Option<RpcNotificationCursor>, reader)? } else { None };
+        Ok(Self { added: added.into(), removed: removed.into(), cursor })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// SinkBlueScoreChangedNotification
+
+// NotifySinkBlueScoreChangedRequest registers this connection for
+// sinkBlueScoreChanged notifications.
 //
-// See: UtxosChangedNotification
+// See: SinkBlueScoreChangedNotification
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyUtxosChangedRequest {
-    pub addresses: Vec<RpcAddress>,
+pub struct NotifySinkBlueScoreChangedRequest {
+    pub command: Command,
+}
+
+impl NotifySinkBlueScoreChangedRequest {
+    pub fn new(command: Command) -> Self {
+        Self { command }
+    }
+}
+
+impl Serializer for NotifySinkBlueScoreChangedRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Command, &self.command, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifySinkBlueScoreChangedRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let command = load!(Command, reader)?;
+        Ok(Self { command })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifySinkBlueScoreChangedResponse {}
+
+impl Serializer for NotifySinkBlueScoreChangedResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifySinkBlueScoreChangedResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+// SinkBlueScoreChangedNotification is sent whenever the blue score
+// of the virtual's selected parent changes.
+//
+/// See: NotifySinkBlueScoreChangedRequest
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SinkBlueScoreChangedNotification {
+    pub sink_blue_score: u64,
+}
+
+impl Serializer for SinkBlueScoreChangedNotification {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u64, &self.sink_blue_score, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for SinkBlueScoreChangedNotification {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let sink_blue_score = load!(u64, reader)?;
+        Ok(Self { sink_blue_score })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// VirtualDaaScoreChangedNotification
+
+// NotifyVirtualDaaScoreChangedRequest registers this connection for
+// virtualDaaScoreChanged notifications.
+//
+// See: VirtualDaaScoreChangedNotification
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyVirtualDaaScoreChangedRequest {
+    pub command: Command,
+}
+
+impl NotifyVirtualDaaScoreChangedRequest {
+    pub fn new(command: Command) -> Self {
+        Self { command }
+    }
+}
+
+impl Serializer for NotifyVirtualDaaScoreChangedRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Command, &self.command, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyVirtualDaaScoreChangedRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let command = load!(Command, reader)?;
+        Ok(Self { command })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyVirtualDaaScoreChangedResponse {}
+
+impl Serializer for NotifyVirtualDaaScoreChangedResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyVirtualDaaScoreChangedResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+// VirtualDaaScoreChangedNotification is sent whenever the DAA score
+// of the virtual changes.
+//
+// See NotifyVirtualDaaScoreChangedRequest
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VirtualDaaScoreChangedNotification {
+    pub virtual_daa_score: u64,
+}
+
+impl Serializer for VirtualDaaScoreChangedNotification {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u64, &self.virtual_daa_score, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for VirtualDaaScoreChangedNotification {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let virtual_daa_score = load!(u64, reader)?;
+        Ok(Self { virtual_daa_score })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// PruningPointUtxoSetOverrideNotification
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyPruningPointUtxoSetOverrideRequest {
     pub command: Command,
 }
 
-impl NotifyUtxosChangedRequest {
-    pub fn new(addresses: Vec<RpcAddress>, command: Command) -> Self {
-        Self { addresses, command }
+impl NotifyPruningPointUtxoSetOverrideRequest {
+    pub fn new(command: Command) -> Self {
+        Self { command }
+    }
+}
+
+impl Serializer for NotifyPruningPointUtxoSetOverrideRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Command, &self.command, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyPruningPointUtxoSetOverrideRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let command = load!(Command, reader)?;
+        Ok(Self { command })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyPruningPointUtxoSetOverrideResponse {}
+
+impl Serializer for NotifyPruningPointUtxoSetOverrideResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyPruningPointUtxoSetOverrideResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
     }
 }
 
-impl Serializer for NotifyUtxosChangedRequest {
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruningPointUtxoSetOverrideNotification {}
+
+impl Serializer for PruningPointUtxoSetOverrideNotification {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(Vec<RpcAddress>, &self.addresses, writer)?;
-        store!(Command, &self.command, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for NotifyUtxosChangedRequest {
+impl Deserializer for PruningPointUtxoSetOverrideNotification {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let addresses = load!(Vec<RpcAddress>, reader)?;
-        let command = load!(Command, reader)?;
-        Ok(Self { addresses, command })
+        Ok(Self {})
     }
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// NewBlockTemplateNotification
+
+/// NotifyNewBlockTemplateRequest registers this connection for blockAdded notifications.
+///
+/// See: NewBlockTemplateNotification
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyUtxosChangedResponse {}
+pub struct NotifyNewBlockTemplateRequest {
+    pub command: Command,
+}
+impl NotifyNewBlockTemplateRequest {
+    pub fn new(command: Command) -> Self {
+        Self { command }
+    }
+}
 
-impl Serializer for NotifyUtxosChangedResponse {
+impl Serializer for NotifyNewBlockTemplateRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
+        store!(Command, &self.command, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for NotifyUtxosChangedResponse {
+impl Deserializer for NotifyNewBlockTemplateRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        Ok(Self {})
+        let command = load!(Command, reader)?;
+        Ok(Self { command })
     }
 }
 
-// UtxosChangedNotificationMessage is sent whenever the UTXO index had been updated.
-//
-// See: NotifyUtxosChangedRequest
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct UtxosChangedNotification {
-    pub added: Arc<Vec<RpcUtxosByAddressesEntry>>,
-    pub removed: Arc<Vec<RpcUtxosByAddressesEntry>>,
-}
+pub struct NotifyNewBlockTemplateResponse {}
 
-impl UtxosChangedNotification {
-    pub(crate) fn apply_utxos_changed_subscription(
-        &self,
-        subscription: &UtxosChangedSubscription,
-        context: &SubscriptionContext,
-    ) -> Option<Self> {
-        if subscription.to_all() {
-            Some(self.clone())
-        } else {
-            let added = Self::filter_utxos(&self.added, subscription, context);
-            let removed = Self::filter_utxos(&self.removed, subscription, context);
-            if added.is_empty() && removed.is_empty() {
-                None
-            } else {
-                debug!("CRPC, Creating UtxosChanged notifications with {} added and {} removed utxos", added.len(), removed.len());
-                Some(Self { added: Arc::new(added), removed: Arc::new(removed) })
-            }
-        }
+impl Serializer for NotifyNewBlockTemplateResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
     }
+}
 
-    fn filter_utxos(
-        utxo_set: &[RpcUtxosByAddressesEntry],
-        subscription: &UtxosChangedSubscription,
-        context: &SubscriptionContext,
-    ) -> Vec<RpcUtxosByAddressesEntry> {
-        let subscription_data = subscription.data();
-        utxo_set.iter().filter(|x| subscription_data.contains(&x.utxo_entry.script_public_key, context)).cloned().collect()
+impl Deserializer for NotifyNewBlockTemplateResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
     }
 }
 
-impl Serializer for UtxosChangedNotification {
+/// NewBlockTemplateNotification is sent whenever a blocks has been added (NOT accepted)
+/// into the DAG.
+///
+/// See: NotifyNewBlockTemplateRequest
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewBlockTemplateNotification {}
+
+impl Serializer for NewBlockTemplateNotification {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        serialize!(Vec<RpcUtxosByAddressesEntry>, &self.added, writer)?;
-        serialize!(Vec<RpcUtxosByAddressesEntry>, &self.removed, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for UtxosChangedNotification {
+impl Deserializer for NewBlockTemplateNotification {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let added = deserialize!(Vec<RpcUtxosByAddressesEntry>, reader)?;
-        let removed = deserialize!(Vec<RpcUtxosByAddressesEntry>, reader)?;
-        Ok(Self { added: added.into(), removed: removed.into() })
+        Ok(Self {})
     }
 }
 
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// SinkBlueScoreChangedNotification
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// MempoolTransactionAddedNotification
 
-// NotifySinkBlueScoreChangedRequest registers this connection for
-// sinkBlueScoreChanged notifications.
+// NotifyMempoolTransactionAddedRequest registers this connection for mempoolTransactionAdded
+// notifications about the transactions entering the mempool. Depending on the provided
+// `command`, notifications will start or stop for the provided `addresses`.
 //
-// See: SinkBlueScoreChangedNotification
+// A transaction is notified if any of its inputs or outputs involves one of the addresses.
+// If `addresses` is empty, the notifications will start or stop for all transactions.
+//
+// See: MempoolTransactionAddedNotification
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifySinkBlueScoreChangedRequest {
+pub struct NotifyMempoolTransactionAddedRequest {
+    pub addresses: Vec<RpcAddress>,
     pub command: Command,
 }
 
-impl NotifySinkBlueScoreChangedRequest {
-    pub fn new(command: Command) -> Self {
-        Self { command }
+impl NotifyMempoolTransactionAddedRequest {
+    pub fn new(addresses: Vec<RpcAddress>, command: Command) -> Self {
+        Self { addresses, command }
     }
 }
 
-impl Serializer for NotifySinkBlueScoreChangedRequest {
+impl Serializer for NotifyMempoolTransactionAddedRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
+        store!(Vec<RpcAddress>, &self.addresses, writer)?;
         store!(Command, &self.command, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for NotifySinkBlueScoreChangedRequest {
+impl Deserializer for NotifyMempoolTransactionAddedRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
+        let addresses = load!(Vec<RpcAddress>, reader)?;
         let command = load!(Command, reader)?;
-        Ok(Self { command })
+        Ok(Self { addresses, command })
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifySinkBlueScoreChangedResponse {}
+pub struct NotifyMempoolTransactionAddedResponse {}
 
-impl Serializer for NotifySinkBlueScoreChangedResponse {
+impl Serializer for NotifyMempoolTransactionAddedResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for NotifySinkBlueScoreChangedResponse {
+impl Deserializer for NotifyMempoolTransactionAddedResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
         Ok(Self {})
     }
 }
 
-// SinkBlueScoreChangedNotification is sent whenever the blue score
-// of the virtual's selected parent changes.
+// MempoolTransactionAddedNotification is sent whenever a transaction enters the mempool
+// transaction pool. Orphan transactions are only notified once they get unorphaned.
 //
-/// See: NotifySinkBlueScoreChangedRequest
+// See: NotifyMempoolTransactionAddedRequest
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SinkBlueScoreChangedNotification {
-    pub sink_blue_score: u64,
+pub struct MempoolTransactionAddedNotification {
+    pub transaction_id: RpcTransactionId,
+    /// Addresses involved in the transaction inputs and outputs
+    pub addresses: Arc<Vec<RpcAddress>>,
 }
 
-impl Serializer for SinkBlueScoreChangedNotification {
+impl Serializer for MempoolTransactionAddedNotification {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(u64, &self.sink_blue_score, writer)?;
+        store!(RpcTransactionId, &self.transaction_id, writer)?;
+        store!(Vec<RpcAddress>, &self.addresses, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for SinkBlueScoreChangedNotification {
+impl Deserializer for MempoolTransactionAddedNotification {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let sink_blue_score = load!(u64, reader)?;
-        Ok(Self { sink_blue_score })
+        let transaction_id = load!(RpcTransactionId, reader)?;
+        let addresses = load!(Vec<RpcAddress>, reader)?;
+        Ok(Self { transaction_id, addresses: addresses.into() })
     }
 }
 
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// VirtualDaaScoreChangedNotification
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// MempoolTransactionRemovedNotification
 
-// NotifyVirtualDaaScoreChangedRequest registers this connection for
-// virtualDaaScoreChanged notifications.
+// NotifyMempoolTransactionRemovedRequest registers this connection for mempoolTransactionRemoved
+// notifications about the transactions leaving the mempool. Depending on the provided
+// `command`, notifications will start or stop for the provided `addresses`.
 //
-// See: VirtualDaaScoreChangedNotification
+// A transaction is notified if any of its inputs or outputs involves one of the addresses.
+// If `addresses` is empty, the notifications will start or stop for all transactions.
+//
+// See: MempoolTransactionRemovedNotification
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyVirtualDaaScoreChangedRequest {
+pub struct NotifyMempoolTransactionRemovedRequest {
+    pub addresses: Vec<RpcAddress>,
     pub command: Command,
 }
 
-impl NotifyVirtualDaaScoreChangedRequest {
-    pub fn new(command: Command) -> Self {
-        Self { command }
+impl NotifyMempoolTransactionRemovedRequest {
+    pub fn new(addresses: Vec<RpcAddress>, command: Command) -> Self {
+        Self { addresses, command }
     }
 }
 
-impl Serializer for NotifyVirtualDaaScoreChangedRequest {
+impl Serializer for NotifyMempoolTransactionRemovedRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
+        store!(Vec<RpcAddress>, &self.addresses, writer)?;
         store!(Command, &self.command, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for NotifyVirtualDaaScoreChangedRequest {
+impl Deserializer for NotifyMempoolTransactionRemovedRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
+        let addresses = load!(Vec<RpcAddress>, reader)?;
         let command = load!(Command, reader)?;
-        Ok(Self { command })
+        Ok(Self { addresses, command })
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyVirtualDaaScoreChangedResponse {}
+pub struct NotifyMempoolTransactionRemovedResponse {}
 
-impl Serializer for NotifyVirtualDaaScoreChangedResponse {
+impl Serializer for NotifyMempoolTransactionRemovedResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for NotifyVirtualDaaScoreChangedResponse {
+impl Deserializer for NotifyMempoolTransactionRemovedResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
         Ok(Self {})
     }
 }
 
-// VirtualDaaScoreChangedNotification is sent whenever the DAA score
-// of the virtual changes.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+#[borsh(use_discriminant = true)]
+pub enum RpcMempoolRemovalReason {
+    /// Included in a block accepted by the virtual chain
+    Accepted = 0,
+    /// Evicted to make room for a transaction with a higher fee rate
+    Evicted = 1,
+    /// Replaced by a double spending transaction paying a higher fee (RBF)
+    ReplacedByFee = 2,
+    /// Expired before being included in a block
+    Expired = 3,
+    /// No longer valid in the virtual state, as a double spend or by failing validation
+    Invalidated = 4,
+    /// Spending outputs missing from the virtual UTXO set, most likely because the transaction itself was
+    /// included in a block not yet handled by the mempool, possibly also because of a double spend
+    MissingOutpoints = 5,
+    /// Removed along with an ancestor transaction it spends outputs of
+    AncestorRemoved = 6,
+}
+
+impl RpcMempoolRemovalReason {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RpcMempoolRemovalReason::Accepted => "accepted",
+            RpcMempoolRemovalReason::Evicted => "evicted",
+            RpcMempoolRemovalReason::ReplacedByFee => "replaced by fee",
+            RpcMempoolRemovalReason::Expired => "expired",
+            RpcMempoolRemovalReason::Invalidated => "invalidated",
+            RpcMempoolRemovalReason::MissingOutpoints => "missing outpoints",
+            RpcMempoolRemovalReason::AncestorRemoved => "ancestor removed",
+        }
+    }
+}
+
+impl Display for RpcMempoolRemovalReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// MempoolTransactionRemovedNotification is sent whenever a transaction leaves the mempool
+// transaction pool, along with the reason of its removal.
 //
-// See NotifyVirtualDaaScoreChangedRequest
+// See: NotifyMempoolTransactionRemovedRequest
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct VirtualDaaScoreChangedNotification {
-    pub virtual_daa_score: u64,
+pub struct MempoolTransactionRemovedNotification {
+    pub transaction_id: RpcTransactionId,
+    /// Addresses involved in the transaction inputs and outputs
+    pub addresses: Arc<Vec<RpcAddress>>,
+    pub reason: RpcMempoolRemovalReason,
 }
 
-impl Serializer for VirtualDaaScoreChangedNotification {
+impl Serializer for MempoolTransactionRemovedNotification {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(u64, &self.virtual_daa_score, writer)?;
+        store!(RpcTransactionId, &self.transaction_id, writer)?;
+        store!(Vec<RpcAddress>, &self.addresses, writer)?;
+        store!(RpcMempoolRemovalReason, &self.reason, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for VirtualDaaScoreChangedNotification {
+impl Deserializer for MempoolTransactionRemovedNotification {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let virtual_daa_score = load!(u64, reader)?;
-        Ok(Self { virtual_daa_score })
+        let transaction_id = load!(RpcTransactionId, reader)?;
+        let addresses = load!(Vec<RpcAddress>, reader)?;
+        let reason = load!(RpcMempoolRemovalReason, reader)?;
+        Ok(Self { transaction_id, addresses: addresses.into(), reason })
     }
 }
 
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// PruningPointUtxoSetOverrideNotification
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// DatabaseCheckpointNotification
 
+/// NotifyDatabaseCheckpointRequest registers this connection for databaseCheckpoint notifications.
+///
+/// See: DatabaseCheckpointNotification
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyPruningPointUtxoSetOverrideRequest {
+pub struct NotifyDatabaseCheckpointRequest {
     pub command: Command,
 }
 
-impl NotifyPruningPointUtxoSetOverrideRequest {
+impl NotifyDatabaseCheckpointRequest {
     pub fn new(command: Command) -> Self {
         Self { command }
     }
 }
 
-impl Serializer for NotifyPruningPointUtxoSetOverrideRequest {
+impl Serializer for NotifyDatabaseCheckpointRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
         store!(Command, &self.command, writer)?;
@@ -3281,7 +4542,7 @@ impl Serializer for NotifyPruningPointUtxoSetOverrideRequest {
     }
 }
 
-impl Deserializer for NotifyPruningPointUtxoSetOverrideRequest {
+impl Deserializer for NotifyDatabaseCheckpointRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
         let command = load!(Command, reader)?;
@@ -3291,58 +4552,118 @@ impl Deserializer for NotifyPruningPointUtxoSetOverrideRequest {
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyPruningPointUtxoSetOverrideResponse {}
+pub struct NotifyDatabaseCheckpointResponse {}
 
-impl Serializer for NotifyPruningPointUtxoSetOverrideResponse {
+impl Serializer for NotifyDatabaseCheckpointResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for NotifyPruningPointUtxoSetOverrideResponse {
+impl Deserializer for NotifyDatabaseCheckpointResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
         Ok(Self {})
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct PruningPointUtxoSetOverrideNotification {}
+#[borsh(use_discriminant = true)]
+pub enum RpcDatabaseCheckpointState {
+    /// The checkpoint was requested and is about to be written
+    Started = 0,
+    /// Some of the databases were written to the checkpoint
+    InProgress = 1,
+    /// All the databases were written to the checkpoint
+    Completed = 2,
+    /// The checkpoint failed and its partial content was removed
+    Failed = 3,
+}
+
+impl RpcDatabaseCheckpointState {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RpcDatabaseCheckpointState::Started => "started",
+            RpcDatabaseCheckpointState::InProgress => "in progress",
+            RpcDatabaseCheckpointState::Completed => "completed",
+            RpcDatabaseCheckpointState::Failed => "failed",
+        }
+    }
+}
 
-impl Serializer for PruningPointUtxoSetOverrideNotification {
+impl Display for RpcDatabaseCheckpointState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// DatabaseCheckpointNotification reports the progress and the result of a database checkpoint.
+///
+/// See: NotifyDatabaseCheckpointRequest
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseCheckpointNotification {
+    pub directory: String,
+    pub state: RpcDatabaseCheckpointState,
+    pub completed_databases: u32,
+    pub total_databases: u32,
+    /// Sink of the virtual state captured by the checkpoint, set once completed
+    pub sink: Option<RpcHash>,
+    /// Virtual DAA score captured by the checkpoint, set once completed
+    pub virtual_daa_score: Option<u64>,
+    /// Reason of the failure, set if `state` is `Failed`
+    pub error: Option<String>,
+}
+
+impl Serializer for DatabaseCheckpointNotification {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
+        store!(String, &self.directory, writer)?;
+        store!(RpcDatabaseCheckpointState, &self.state, writer)?;
+        store!(u32, &self.completed_databases, writer)?;
+        store!(u32, &self.total_databases, writer)?;
+        store!(Option<RpcHash>, &self.sink, writer)?;
+        store!(Option<u64>, &self.virtual_daa_score, writer)?;
+        store!(Option<String>, &self.error, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for PruningPointUtxoSetOverrideNotification {
+impl Deserializer for DatabaseCheckpointNotification {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        Ok(Self {})
+        let directory = load!(String, reader)?;
+        let state = load!(RpcDatabaseCheckpointState, reader)?;
+        let completed_databases = load!(u32, reader)?;
+        let total_databases = load!(u32, reader)?;
+        let sink = load!(Option<RpcHash>, reader)?;
+        let virtual_daa_score = load!(Option<u64>, reader)?;
+        let error = load!(Option<String>, reader)?;
+        Ok(Self { directory, state, completed_databases, total_databases, sink, virtual_daa_score, error })
     }
 }
 
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// NewBlockTemplateNotification
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ConsensusAuditViolationNotification
 
-/// NotifyNewBlockTemplateRequest registers this connection for blockAdded notifications.
+/// NotifyConsensusAuditViolationRequest registers this connection for consensusAuditViolation notifications.
 ///
-/// See: NewBlockTemplateNotification
+/// See: ConsensusAuditViolationNotification
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyNewBlockTemplateRequest {
+pub struct NotifyConsensusAuditViolationRequest {
     pub command: Command,
 }
-impl NotifyNewBlockTemplateRequest {
+
+impl NotifyConsensusAuditViolationRequest {
     pub fn new(command: Command) -> Self {
         Self { command }
     }
 }
 
-impl Serializer for NotifyNewBlockTemplateRequest {
+impl Serializer for NotifyConsensusAuditViolationRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
         store!(Command, &self.command, writer)?;
@@ -3350,7 +4671,7 @@ impl Serializer for NotifyNewBlockTemplateRequest {
     }
 }
 
-impl Deserializer for NotifyNewBlockTemplateRequest {
+impl Deserializer for NotifyConsensusAuditViolationRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
         let command = load!(Command, reader)?;
@@ -3360,41 +4681,85 @@ impl Deserializer for NotifyNewBlockTemplateRequest {
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NotifyNewBlockTemplateResponse {}
+pub struct NotifyConsensusAuditViolationResponse {}
 
-impl Serializer for NotifyNewBlockTemplateResponse {
+impl Serializer for NotifyConsensusAuditViolationResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for NotifyNewBlockTemplateResponse {
+impl Deserializer for NotifyConsensusAuditViolationResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
         Ok(Self {})
     }
 }
 
-/// NewBlockTemplateNotification is sent whenever a blocks has been added (NOT accepted)
-/// into the DAG.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+#[borsh(use_discriminant = true)]
+pub enum RpcConsensusAuditCheck {
+    /// The stored GHOSTDAG data differs from the recomputed one or violates the k-cluster bounds
+    Ghostdag = 0,
+    /// The reachability tree position or interval of the block is inconsistent
+    Reachability = 1,
+    /// The DAA score, non-DAA mergeset or difficulty differs from the recomputed DAA window
+    DaaWindow = 2,
+    /// The coinbase pays more than (or, for a full DAA mergeset, differently from) the mergeset subsidies and fees
+    CoinbaseReward = 3,
+    /// The UTXO commitment differs from the recomputed UTXO multiset
+    UtxoCommitment = 4,
+}
+
+impl RpcConsensusAuditCheck {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RpcConsensusAuditCheck::Ghostdag => "ghostdag",
+            RpcConsensusAuditCheck::Reachability => "reachability",
+            RpcConsensusAuditCheck::DaaWindow => "daa window",
+            RpcConsensusAuditCheck::CoinbaseReward => "coinbase reward",
+            RpcConsensusAuditCheck::UtxoCommitment => "utxo commitment",
+        }
+    }
+}
+
+impl Display for RpcConsensusAuditCheck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// ConsensusAuditViolationNotification is sent when the consensus audit mode finds a block
+/// violating a consensus invariant.
 ///
-/// See: NotifyNewBlockTemplateRequest
+/// See: NotifyConsensusAuditViolationRequest
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NewBlockTemplateNotification {}
+pub struct ConsensusAuditViolationNotification {
+    pub check: RpcConsensusAuditCheck,
+    pub block_hash: RpcHash,
+    pub details: String,
+}
 
-impl Serializer for NewBlockTemplateNotification {
+impl Serializer for ConsensusAuditViolationNotification {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
+        store!(RpcConsensusAuditCheck, &self.check, writer)?;
+        store!(RpcHash, &self.block_hash, writer)?;
+        store!(String, &self.details, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for NewBlockTemplateNotification {
+impl Deserializer for ConsensusAuditViolationNotification {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        Ok(Self {})
+        let check = load!(RpcConsensusAuditCheck, reader)?;
+        let block_hash = load!(RpcHash, reader)?;
+        let details = load!(String, reader)?;
+        Ok(Self { check, block_hash, details })
     }
 }
 
@@ -327,6 +327,15 @@ mod mockery {
         }
     }
 
+    impl Mock for RpcUtxoFilter {
+        fn mock() -> Self {
+            RpcUtxoFilter::default()
+                .with_script_classes(vec![RpcScriptClass::PubKey, RpcScriptClass::ScriptHash])
+                .with_amount_range(Some(1000), Some(rand::thread_rng().gen_range(1000..u64::MAX)))
+                .with_script_prefixes(mock())
+        }
+    }
+
     impl Mock for RpcUtxoEntry {
         fn mock() -> Self {
             RpcUtxoEntry { amount: mock(), script_public_key: mock(), block_daa_score: mock(), is_coinbase: true }
@@ -418,6 +427,19 @@ mod mockery {
         }
     }
 
+    impl Mock for RpcMethodMetrics {
+        fn mock() -> Self {
+            RpcMethodMetrics {
+                method: "GetInfo".to_string(),
+                calls: mock(),
+                rate_limited: mock(),
+                total_cost: mock(),
+                total_latency_micros: mock(),
+                max_latency_micros: mock(),
+            }
+        }
+    }
+
     // --------------------------------------------
     // implementations for all the rpc request
     // and response data structures.
@@ -755,6 +777,156 @@ mod mockery {
 
     test!(ShutdownResponse);
 
+    impl Mock for CreateDatabaseCheckpointRequest {
+        fn mock() -> Self {
+            CreateDatabaseCheckpointRequest { directory: Some("checkpoint".to_string()) }
+        }
+    }
+
+    test!(CreateDatabaseCheckpointRequest);
+
+    impl Mock for CreateDatabaseCheckpointResponse {
+        fn mock() -> Self {
+            CreateDatabaseCheckpointResponse { directory: "checkpoint".to_string() }
+        }
+    }
+
+    test!(CreateDatabaseCheckpointResponse);
+
+    impl Mock for ExportDagRequest {
+        fn mock() -> Self {
+            ExportDagRequest { range: RpcDagRange::Hashes { low_hash: mock(), high_hash: mock() } }
+        }
+    }
+
+    test!(ExportDagRequest);
+
+    impl Mock for RpcDagBlock {
+        fn mock() -> Self {
+            RpcDagBlock {
+                hash: mock(),
+                parents: mock(),
+                selected_parent: mock(),
+                blue_score: mock(),
+                blue_work: mock(),
+                daa_score: mock(),
+                mergeset_blues: mock(),
+                mergeset_reds: mock(),
+                is_chain_block: mock(),
+            }
+        }
+    }
+
+    impl Mock for ExportDagResponse {
+        fn mock() -> Self {
+            ExportDagResponse { low_hash: mock(), high_hash: mock(), blocks: mock() }
+        }
+    }
+
+    test!(ExportDagResponse);
+
+    impl Mock for IsDagAncestorOfRequest {
+        fn mock() -> Self {
+            IsDagAncestorOfRequest { low_hash: mock(), high_hash: mock() }
+        }
+    }
+
+    test!(IsDagAncestorOfRequest);
+
+    impl Mock for IsDagAncestorOfResponse {
+        fn mock() -> Self {
+            IsDagAncestorOfResponse { is_dag_ancestor_of: mock(), is_chain_ancestor_of: mock(), blue_score_distance: mock() }
+        }
+    }
+
+    test!(IsDagAncestorOfResponse);
+
+    impl Mock for GetBlockMergesetRequest {
+        fn mock() -> Self {
+            GetBlockMergesetRequest { hash: mock() }
+        }
+    }
+
+    test!(GetBlockMergesetRequest);
+
+    impl Mock for GetBlockMergesetResponse {
+        fn mock() -> Self {
+            GetBlockMergesetResponse { selected_parent: mock(), mergeset_blues: mock(), mergeset_reds: mock() }
+        }
+    }
+
+    test!(GetBlockMergesetResponse);
+
+    impl Mock for GetAnticoneSizeRequest {
+        fn mock() -> Self {
+            GetAnticoneSizeRequest { hash: mock() }
+        }
+    }
+
+    test!(GetAnticoneSizeRequest);
+
+    impl Mock for GetAnticoneSizeResponse {
+        fn mock() -> Self {
+            GetAnticoneSizeResponse { anticone_size: mock() }
+        }
+    }
+
+    test!(GetAnticoneSizeResponse);
+
+    impl Mock for GetChainPathBetweenRequest {
+        fn mock() -> Self {
+            GetChainPathBetweenRequest { from_hash: mock(), to_hash: mock() }
+        }
+    }
+
+    test!(GetChainPathBetweenRequest);
+
+    impl Mock for GetChainPathBetweenResponse {
+        fn mock() -> Self {
+            GetChainPathBetweenResponse { removed_chain_block_hashes: mock(), added_chain_block_hashes: mock(), truncated: mock() }
+        }
+    }
+
+    test!(GetChainPathBetweenResponse);
+
+    impl Mock for RpcIndexedChainBlock {
+        fn mock() -> Self {
+            RpcIndexedChainBlock { hash: mock(), daa_score: mock(), timestamp: mock() }
+        }
+    }
+
+    impl Mock for GetChainBlockByDaaScoreRequest {
+        fn mock() -> Self {
+            GetChainBlockByDaaScoreRequest { daa_score: mock() }
+        }
+    }
+
+    test!(GetChainBlockByDaaScoreRequest);
+
+    impl Mock for GetChainBlockByDaaScoreResponse {
+        fn mock() -> Self {
+            GetChainBlockByDaaScoreResponse { block: mock() }
+        }
+    }
+
+    test!(GetChainBlockByDaaScoreResponse);
+
+    impl Mock for GetBlocksByTimeRangeRequest {
+        fn mock() -> Self {
+            GetBlocksByTimeRangeRequest { start_timestamp: mock(), end_timestamp: mock(), after_hash: mock(), limit: mock() }
+        }
+    }
+
+    test!(GetBlocksByTimeRangeRequest);
+
+    impl Mock for GetBlocksByTimeRangeResponse {
+        fn mock() -> Self {
+            GetBlocksByTimeRangeResponse { blocks: mock(), merged_block_hashes: mock(), has_more: mock() }
+        }
+    }
+
+    test!(GetBlocksByTimeRangeResponse);
+
     impl Mock for GetHeadersRequest {
         fn mock() -> Self {
             GetHeadersRequest { start_hash: mock(), limit: mock(), is_ascending: mock() }
@@ -986,6 +1158,7 @@ mod mockery {
                 consensus_metrics: true,
                 storage_metrics: true,
                 custom_metrics: false,
+                rpc_method_metrics: true,
             }
         }
     }
@@ -1002,6 +1175,7 @@ mod mockery {
                 consensus_metrics: mock(),
                 storage_metrics: mock(),
                 custom_metrics: None,
+                rpc_method_metrics: mock(),
             }
         }
     }
@@ -1026,6 +1200,8 @@ mod mockery {
                 has_utxo_index: true,
                 is_synced: false,
                 virtual_daa_score: mock(),
+                retention_policy: RpcRetentionPolicy::BodiesForDays,
+                body_retention_days: Some(30),
             }
         }
     }
@@ -1090,7 +1266,7 @@ mod mockery {
 
     impl Mock for NotifyVirtualChainChangedRequest {
         fn mock() -> Self {
-            NotifyVirtualChainChangedRequest { command: Command::Start, include_accepted_transaction_ids: true }
+            NotifyVirtualChainChangedRequest { command: Command::Start, include_accepted_transaction_ids: true, resume_from: mock() }
         }
     }
 
@@ -1110,6 +1286,7 @@ mod mockery {
                 removed_chain_block_hashes: mock(),
                 added_chain_block_hashes: mock(),
                 accepted_transaction_ids: mock(),
+                cursor: mock(),
             }
         }
     }
@@ -1166,7 +1343,7 @@ mod mockery {
 
     impl Mock for NotifyUtxosChangedRequest {
         fn mock() -> Self {
-            NotifyUtxosChangedRequest { addresses: mock(), command: Command::Start }
+            NotifyUtxosChangedRequest { addresses: mock(), command: Command::Start, filter: mock(), resume_from: mock() }
         }
     }
 
@@ -1182,7 +1359,7 @@ mod mockery {
 
     impl Mock for UtxosChangedNotification {
         fn mock() -> Self {
-            UtxosChangedNotification { added: mock(), removed: mock() }
+            UtxosChangedNotification { added: mock(), removed: mock(), cursor: mock() }
         }
     }
 
@@ -1284,6 +1461,118 @@ mod mockery {
 
     test!(NewBlockTemplateNotification);
 
+    impl Mock for NotifyMempoolTransactionAddedRequest {
+        fn mock() -> Self {
+            NotifyMempoolTransactionAddedRequest { addresses: mock(), command: Command::Start }
+        }
+    }
+
+    test!(NotifyMempoolTransactionAddedRequest);
+
+    impl Mock for NotifyMempoolTransactionAddedResponse {
+        fn mock() -> Self {
+            NotifyMempoolTransactionAddedResponse {}
+        }
+    }
+
+    test!(NotifyMempoolTransactionAddedResponse);
+
+    impl Mock for MempoolTransactionAddedNotification {
+        fn mock() -> Self {
+            MempoolTransactionAddedNotification { transaction_id: mock(), addresses: mock() }
+        }
+    }
+
+    test!(MempoolTransactionAddedNotification);
+
+    impl Mock for NotifyMempoolTransactionRemovedRequest {
+        fn mock() -> Self {
+            NotifyMempoolTransactionRemovedRequest { addresses: mock(), command: Command::Stop }
+        }
+    }
+
+    test!(NotifyMempoolTransactionRemovedRequest);
+
+    impl Mock for NotifyMempoolTransactionRemovedResponse {
+        fn mock() -> Self {
+            NotifyMempoolTransactionRemovedResponse {}
+        }
+    }
+
+    test!(NotifyMempoolTransactionRemovedResponse);
+
+    impl Mock for MempoolTransactionRemovedNotification {
+        fn mock() -> Self {
+            MempoolTransactionRemovedNotification {
+                transaction_id: mock(),
+                addresses: mock(),
+                reason: RpcMempoolRemovalReason::ReplacedByFee,
+            }
+        }
+    }
+
+    test!(MempoolTransactionRemovedNotification);
+
+    impl Mock for NotifyDatabaseCheckpointRequest {
+        fn mock() -> Self {
+            NotifyDatabaseCheckpointRequest { command: Command::Start }
+        }
+    }
+
+    test!(NotifyDatabaseCheckpointRequest);
+
+    impl Mock for NotifyDatabaseCheckpointResponse {
+        fn mock() -> Self {
+            NotifyDatabaseCheckpointResponse {}
+        }
+    }
+
+    test!(NotifyDatabaseCheckpointResponse);
+
+    impl Mock for DatabaseCheckpointNotification {
+        fn mock() -> Self {
+            DatabaseCheckpointNotification {
+                directory: "checkpoint".to_string(),
+                state: RpcDatabaseCheckpointState::Completed,
+                completed_databases: mock(),
+                total_databases: mock(),
+                sink: mock(),
+                virtual_daa_score: mock(),
+                error: None,
+            }
+        }
+    }
+
+    test!(DatabaseCheckpointNotification);
+
+    impl Mock for NotifyConsensusAuditViolationRequest {
+        fn mock() -> Self {
+            NotifyConsensusAuditViolationRequest { command: Command::Start }
+        }
+    }
+
+    test!(NotifyConsensusAuditViolationRequest);
+
+    impl Mock for NotifyConsensusAuditViolationResponse {
+        fn mock() -> Self {
+            NotifyConsensusAuditViolationResponse {}
+        }
+    }
+
+    test!(NotifyConsensusAuditViolationResponse);
+
+    impl Mock for ConsensusAuditViolationNotification {
+        fn mock() -> Self {
+            ConsensusAuditViolationNotification {
+                check: RpcConsensusAuditCheck::CoinbaseReward,
+                block_hash: mock(),
+                details: "coinbase pays too much".to_string(),
+            }
+        }
+    }
+
+    test!(ConsensusAuditViolationNotification);
+
     impl Mock for SubscribeResponse {
         fn mock() -> Self {
             SubscribeResponse::new(mock())
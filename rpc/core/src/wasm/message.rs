@@ -321,6 +321,51 @@ try_from! ( args: GetMetricsResponse, IGetMetricsResponse, {
 
 // ---
 
+declare! {
+    IGetMetricsHistoryRequest,
+    r#"
+    /**
+     * Requests the samples of node metrics retained by the node over a time range.
+     *
+     * @category Node RPC
+     */
+    export interface IGetMetricsHistoryRequest {
+        /** Start of the time range in unix milliseconds (oldest retained sample if omitted) */
+        startTime?: number;
+        /** End of the time range in unix milliseconds (latest sample if omitted) */
+        endTime?: number;
+        resolution?: "second" | "minute" | "hour";
+        /** Names of the requested metrics (all metrics if empty) */
+        metrics?: string[];
+    }
+    "#,
+}
+
+try_from! ( args: IGetMetricsHistoryRequest, GetMetricsHistoryRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetMetricsHistoryResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetMetricsHistoryResponse {
+        resolution: "second" | "minute" | "hour";
+        /** Names of the metrics, in the order of the sample values */
+        metrics: string[];
+        samples: { timestamp: number, values: number[] }[];
+    }
+    "#,
+}
+
+try_from! ( args: GetMetricsHistoryResponse, IGetMetricsHistoryResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
 declare! {
     IGetConnectionsRequest,
     r#"
@@ -447,6 +492,42 @@ try_from! ( args: ShutdownResponse, IShutdownResponse, {
 
 // ---
 
+declare! {
+    ICreateDatabaseCheckpointRequest,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface ICreateDatabaseCheckpointRequest {
+        /** Target directory of the checkpoint, which must not exist (timestamped directory under the node backup directory if omitted) */
+        directory?: string;
+    }
+    "#,
+}
+
+try_from! ( args: ICreateDatabaseCheckpointRequest, CreateDatabaseCheckpointRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    ICreateDatabaseCheckpointResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface ICreateDatabaseCheckpointResponse {
+        /** Directory the checkpoint is being written to */
+        directory : string;
+    }
+    "#,
+}
+
+try_from! ( args: CreateDatabaseCheckpointResponse, ICreateDatabaseCheckpointResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
 declare! {
     IGetServerInfoRequest,
     r#"
@@ -474,6 +555,14 @@ declare! {
         hasUtxoIndex : boolean;
         isSynced : boolean;
         virtualDaaScore : bigint;
+        /**
+         * Block data kept by the node for blocks in the past of the pruning point.
+         */
+        retentionPolicy : "unknown" | "pruned" | "archival" | "bodiesForDays" | "chainBodies" | "acceptanceData";
+        /**
+         * Number of days block bodies are kept for, set with the "bodiesForDays" retention policy.
+         */
+        bodyRetentionDays? : bigint;
     }
     "#,
 }
@@ -898,6 +987,308 @@ try_from! ( args: GetCurrentBlockColorResponse, IGetCurrentBlockColorResponse, {
 
 // ---
 
+declare! {
+    IExportDagRequest,
+    r#"
+    /**
+     * Requests the GHOSTDAG data of the blocks of a sub-DAG, delimited either by
+     * a low and a high block hash or by a DAA score range of the selected chain.
+     *
+     * @category Node RPC
+     */
+    export interface IExportDagRequest {
+        range: { type: "hashes", lowHash: HexString, highHash: HexString }
+            | { type: "daaScores", lowDaaScore: bigint, highDaaScore: bigint };
+    }
+    "#,
+}
+
+try_from! ( args: IExportDagRequest, ExportDagRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IExportDagResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IExportDagResponse {
+        lowHash: HexString;
+        highHash: HexString;
+        /** Blocks of the sub-DAG ordered topologically by blue work */
+        blocks: {
+            hash: HexString,
+            parents: HexString[],
+            selectedParent: HexString,
+            blueScore: bigint,
+            blueWork: HexString,
+            daaScore: bigint,
+            mergesetBlues: HexString[],
+            mergesetReds: HexString[],
+            isChainBlock: boolean,
+        }[];
+    }
+    "#,
+}
+
+try_from! ( args: ExportDagResponse, IExportDagResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IIsDagAncestorOfRequest,
+    r#"
+    /**
+     * Requests whether a block is in the past of another block.
+     *
+     * @category Node RPC
+     */
+    export interface IIsDagAncestorOfRequest {
+        lowHash: HexString;
+        highHash: HexString;
+    }
+    "#,
+}
+
+try_from! ( args: IIsDagAncestorOfRequest, IsDagAncestorOfRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IIsDagAncestorOfResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IIsDagAncestorOfResponse {
+        /** Whether the low block is in the past of the high block (a block being its own ancestor) */
+        isDagAncestorOf: boolean;
+        /** Whether the low block is on the selected chain of the high block */
+        isChainAncestorOf: boolean;
+        /** Blue score distance from the low block to the high block, set if the low block is a DAG ancestor */
+        blueScoreDistance?: bigint;
+    }
+    "#,
+}
+
+try_from! ( args: IsDagAncestorOfResponse, IIsDagAncestorOfResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IGetBlockMergesetRequest,
+    r#"
+    /**
+     * Requests the GHOSTDAG merge set of a block.
+     *
+     * @category Node RPC
+     */
+    export interface IGetBlockMergesetRequest {
+        hash: HexString;
+    }
+    "#,
+}
+
+try_from! ( args: IGetBlockMergesetRequest, GetBlockMergesetRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetBlockMergesetResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetBlockMergesetResponse {
+        selectedParent: HexString;
+        /** Blue blocks of the merge set, the selected parent being the first */
+        mergesetBlues: HexString[];
+        mergesetReds: HexString[];
+    }
+    "#,
+}
+
+try_from! ( args: GetBlockMergesetResponse, IGetBlockMergesetResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IGetAnticoneSizeRequest,
+    r#"
+    /**
+     * Requests the size of the anticone of a block from the point of view of the virtual block.
+     *
+     * @category Node RPC
+     */
+    export interface IGetAnticoneSizeRequest {
+        hash: HexString;
+    }
+    "#,
+}
+
+try_from! ( args: IGetAnticoneSizeRequest, GetAnticoneSizeRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetAnticoneSizeResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetAnticoneSizeResponse {
+        anticoneSize: bigint;
+    }
+    "#,
+}
+
+try_from! ( args: GetAnticoneSizeResponse, IGetAnticoneSizeResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IGetChainPathBetweenRequest,
+    r#"
+    /**
+     * Requests the selected chain path from a block to another block.
+     *
+     * @category Node RPC
+     */
+    export interface IGetChainPathBetweenRequest {
+        fromHash: HexString;
+        toHash: HexString;
+    }
+    "#,
+}
+
+try_from! ( args: IGetChainPathBetweenRequest, GetChainPathBetweenRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetChainPathBetweenResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetChainPathBetweenResponse {
+        /** Chain blocks of the source block down to the common chain ancestor (excluded) */
+        removedChainBlockHashes: HexString[];
+        /** Chain blocks from the common chain ancestor (excluded) up to the target block */
+        addedChainBlockHashes: HexString[];
+        /** Whether the added chain blocks were limited and stop below the target block */
+        truncated: boolean;
+    }
+    "#,
+}
+
+try_from! ( args: GetChainPathBetweenResponse, IGetChainPathBetweenResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IGetChainBlockByDaaScoreRequest,
+    r#"
+    /**
+     * Requests the selected chain block with the highest DAA score not above `daaScore`.
+     * Requires the node to run with the chain index enabled.
+     *
+     * @category Node RPC
+     */
+    export interface IGetChainBlockByDaaScoreRequest {
+        daaScore: bigint;
+    }
+    "#,
+}
+
+try_from! ( args: IGetChainBlockByDaaScoreRequest, GetChainBlockByDaaScoreRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetChainBlockByDaaScoreResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetChainBlockByDaaScoreResponse {
+        block: {
+            hash: HexString;
+            daaScore: bigint;
+            timestamp: bigint;
+        };
+    }
+    "#,
+}
+
+try_from! ( args: GetChainBlockByDaaScoreResponse, IGetChainBlockByDaaScoreResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IGetBlocksByTimeRangeRequest,
+    r#"
+    /**
+     * Requests the selected chain blocks with a timestamp in `[startTimestamp, endTimestamp)`
+     * together with the blocks merged by them. Pages are counted in chain blocks. To fetch the
+     * next page, pass the timestamp and hash of the last returned chain block as `startTimestamp`
+     * and `afterHash`. Requires the node to run with the chain index enabled.
+     *
+     * @category Node RPC
+     */
+    export interface IGetBlocksByTimeRangeRequest {
+        startTimestamp: bigint;
+        endTimestamp: bigint;
+        afterHash?: HexString;
+        /** Page size, 0 for the largest page size the node allows */
+        limit: number;
+    }
+    "#,
+}
+
+try_from! ( args: IGetBlocksByTimeRangeRequest, GetBlocksByTimeRangeRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetBlocksByTimeRangeResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetBlocksByTimeRangeResponse {
+        blocks: {
+            hash: HexString;
+            daaScore: bigint;
+            timestamp: bigint;
+        }[];
+        /** Hashes of the non-chain blocks merged by `blocks`, in chain order and then mergeset order */
+        mergedBlockHashes: HexString[];
+        /** Whether more chain blocks fall in the requested range after the last returned block */
+        hasMore: boolean;
+    }
+    "#,
+}
+
+try_from! ( args: GetBlocksByTimeRangeResponse, IGetBlocksByTimeRangeResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
 declare! {
     IGetDaaScoreTimestampEstimateRequest,
     r#"
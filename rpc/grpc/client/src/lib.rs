@@ -50,6 +50,8 @@ use std::{
 };
 use tokio::sync::Mutex;
 use tonic::codec::CompressionEncoding;
+use tonic::transport::Endpoint;
+pub use tonic::transport::{Certificate, ClientTlsConfig, Identity};
 use tonic::Streaming;
 
 mod connection_event;
@@ -81,11 +83,14 @@ pub struct GrpcClient {
 
 const GRPC_CLIENT: &str = "grpc-client";
 
+/// Request metadata key carrying the client API key (`Bearer <token>`)
+const AUTHORIZATION_METADATA_KEY: &str = "authorization";
+
 impl GrpcClient {
     pub const DIRECT_MODE_LISTENER_ID: ListenerId = 0;
 
     pub async fn connect(url: String) -> Result<GrpcClient> {
-        Self::connect_with_args(NotificationMode::Direct, url, None, false, None, false, None, Default::default()).await
+        Self::connect_with_args(NotificationMode::Direct, url, None, false, None, false, None, Default::default(), None, None).await
     }
 
     /// Connects to a gRPC server.
@@ -112,6 +117,12 @@ impl GrpcClient {
     /// `timeout_duration`: request timeout duration
     ///
     /// `counters`: collects some bandwidth metrics
+    ///
+    /// `tls_config`: connects over TLS using the provided config (custom CA, client identity for mutual TLS).
+    /// A `grpcs://` url connects over TLS verifying the server certificate against the WebPKI roots by default.
+    ///
+    /// `auth_token`: the API key sent as `authorization: Bearer <token>` request metadata to servers requiring
+    /// authentication. Clients connect anonymously if `None`.
     pub async fn connect_with_args(
         notification_mode: NotificationMode,
         url: String,
@@ -121,8 +132,10 @@ impl GrpcClient {
         override_handle_stop_notify: bool,
         timeout_duration: Option<u64>,
         counters: Arc<TowerConnectionCounters>,
+        tls_config: Option<ClientTlsConfig>,
+        auth_token: Option<String>,
     ) -> Result<GrpcClient> {
-        let schema = Regex::new(r"^grpc://").unwrap();
+        let schema = Regex::new(r"^grpcs?://").unwrap();
         if !schema.is_match(&url) {
             return Err(Error::GrpcAddressSchema(url));
         }
@@ -132,6 +145,8 @@ impl GrpcClient {
             override_handle_stop_notify,
             timeout_duration.unwrap_or(REQUEST_TIMEOUT_DURATION),
             counters,
+            tls_config,
+            auth_token,
         )
         .await?;
         let converter = Arc::new(RpcCoreConverter::new());
@@ -227,6 +242,23 @@ impl GrpcClient {
     pub fn notification_mode(&self) -> NotificationMode {
         self.notification_mode
     }
+
+    /// Returns the scopes of the active subscriptions in `Direct` mode.
+    ///
+    /// Subscriptions are only tracked by a client featuring automatic reconnection.
+    pub async fn active_scopes(&self) -> Vec<Scope> {
+        match self.subscriptions.as_ref() {
+            Some(subscriptions) => {
+                let subscriptions = subscriptions.lock().await;
+                EVENT_TYPE_ARRAY
+                    .into_iter()
+                    .filter(|event| subscriptions[*event].active())
+                    .map(|event| subscriptions[event].scope(&self.subscription_context))
+                    .collect()
+            }
+            None => vec![],
+        }
+    }
 }
 
 #[async_trait]
@@ -276,6 +308,15 @@ impl RpcApi for GrpcClient {
     route!(get_fee_estimate_call, GetFeeEstimate);
     route!(get_fee_estimate_experimental_call, GetFeeEstimateExperimental);
     route!(get_current_block_color_call, GetCurrentBlockColor);
+    route!(get_metrics_history_call, GetMetricsHistory);
+    route!(create_database_checkpoint_call, CreateDatabaseCheckpoint);
+    route!(export_dag_call, ExportDag);
+    route!(is_dag_ancestor_of_call, IsDagAncestorOf);
+    route!(get_block_mergeset_call, GetBlockMergeset);
+    route!(get_anticone_size_call, GetAnticoneSize);
+    route!(get_chain_path_between_call, GetChainPathBetween);
+    route!(get_chain_block_by_daa_score_call, GetChainBlockByDaaScore);
+    route!(get_blocks_by_time_range_call, GetBlocksByTimeRange);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
@@ -438,6 +479,12 @@ struct Inner {
 
     // bandwidth counters
     counters: Arc<TowerConnectionCounters>,
+
+    // TLS config of the transport (`None` for `grpc://` urls with no explicit TLS config)
+    tls_config: Option<ClientTlsConfig>,
+
+    // API key presented to the server (`None` for anonymous access)
+    auth_token: Option<String>,
 }
 
 impl Inner {
@@ -450,6 +497,8 @@ impl Inner {
         override_handle_stop_notify: bool,
         timeout_duration: u64,
         counters: Arc<TowerConnectionCounters>,
+        tls_config: Option<ClientTlsConfig>,
+        auth_token: Option<String>,
     ) -> Self {
         let resolver: DynResolver = match server_features.handle_message_id {
             true => Arc::new(IdResolver::new()),
@@ -475,6 +524,8 @@ impl Inner {
             connection_event_sender,
             override_handle_stop_notify,
             counters,
+            tls_config,
+            auth_token,
         }
     }
 
@@ -485,14 +536,26 @@ impl Inner {
         override_handle_stop_notify: bool,
         timeout_duration: u64,
         counters: Arc<TowerConnectionCounters>,
+        tls_config: Option<ClientTlsConfig>,
+        auth_token: Option<String>,
     ) -> Result<Arc<Self>> {
         // Request channel
         let (request_sender, request_receiver) = async_channel::unbounded();
 
+        // The TLS config is resolved once so reconnections use the same transport
+        let tls_config = tls_config.or_else(|| url.starts_with("grpcs://").then(|| ClientTlsConfig::new().with_webpki_roots()));
+
         // Try to connect to the server
-        let (stream, server_features) =
-            Inner::try_connect(url.clone(), request_sender.clone(), request_receiver.clone(), timeout_duration, counters.clone())
-                .await?;
+        let (stream, server_features) = Inner::try_connect(
+            url.clone(),
+            request_sender.clone(),
+            request_receiver.clone(),
+            timeout_duration,
+            counters.clone(),
+            tls_config.clone(),
+            auth_token.clone(),
+        )
+        .await?;
 
         // create the inner object
         let inner = Arc::new(Inner::new(
@@ -504,6 +567,8 @@ impl Inner {
             override_handle_stop_notify,
             timeout_duration,
             counters,
+            tls_config,
+            auth_token,
         ));
 
         // Start the request timeout cleaner
@@ -516,6 +581,18 @@ impl Inner {
         Ok(inner)
     }
 
+    /// Builds the transport endpoint of `url`. TLS connections use the `https` scheme expected by the transport.
+    fn endpoint(url: &str, tls_config: Option<ClientTlsConfig>) -> Result<Endpoint> {
+        match tls_config {
+            Some(tls_config) => {
+                let address = url.split_once("://").map_or(url, |(_, address)| address);
+                let uri = format!("https://{address}").parse::<tonic::transport::Uri>().map_err(|e| Error::String(e.to_string()))?;
+                Ok(Endpoint::from(uri).tls_config(tls_config)?)
+            }
+            None => Ok(Endpoint::from(url.parse::<tonic::transport::Uri>().map_err(|e| Error::String(e.to_string()))?)),
+        }
+    }
+
     #[allow(unused_variables)]
     async fn try_connect(
         url: String,
@@ -523,21 +600,21 @@ impl Inner {
         request_receiver: CalicodRequestReceiver,
         request_timeout: u64,
         counters: Arc<TowerConnectionCounters>,
+        tls_config: Option<ClientTlsConfig>,
+        auth_token: Option<String>,
     ) -> Result<(Streaming<CalicodResponse>, ServerFeatures)> {
         // gRPC endpoint
+        let endpoint = Self::endpoint(&url, tls_config)?;
+
         #[cfg(not(feature = "heap"))]
-        let channel =
-            tonic::transport::Channel::builder(url.parse::<tonic::transport::Uri>().map_err(|e| Error::String(e.to_string()))?)
-                .timeout(tokio::time::Duration::from_millis(request_timeout))
-                .connect_timeout(tokio::time::Duration::from_millis(CONNECT_TIMEOUT_DURATION))
-                .connect()
-                .await?;
+        let channel = endpoint
+            .timeout(tokio::time::Duration::from_millis(request_timeout))
+            .connect_timeout(tokio::time::Duration::from_millis(CONNECT_TIMEOUT_DURATION))
+            .connect()
+            .await?;
 
         #[cfg(feature = "heap")]
-        let channel =
-            tonic::transport::Channel::builder(url.parse::<tonic::transport::Uri>().map_err(|e| Error::String(e.to_string()))?)
-                .connect()
-                .await?;
+        let channel = endpoint.connect().await?;
 
         let bytes_rx = &counters.bytes_rx;
         let bytes_tx = &counters.bytes_tx;
@@ -571,8 +648,15 @@ impl Inner {
             }
         };
 
+        // Present the API key to servers requiring authentication
+        let mut request = tonic::Request::new(request_stream);
+        if let Some(token) = auth_token {
+            let value = format!("Bearer {token}").parse().map_err(|_| Error::String("invalid API key".to_string()))?;
+            request.metadata_mut().insert(AUTHORIZATION_METADATA_KEY, value);
+        }
+
         // Actual CalicodRequest to CalicodResponse stream
-        let mut stream: Streaming<CalicodResponse> = client.message_stream(request_stream).await?.into_inner();
+        let mut stream: Streaming<CalicodResponse> = client.message_stream(request).await?.into_inner();
 
         // Collect server capabilities as stated in GetInfoResponse
         let mut server_features = ServerFeatures::default();
@@ -615,6 +699,8 @@ impl Inner {
             self.request_receiver.clone(),
             self.timeout_duration,
             self.counters.clone(),
+            self.tls_config.clone(),
+            self.auth_token.clone(),
         )
         .await?;
 
@@ -915,18 +1001,27 @@ impl Inner {
     /// Start sending notifications of some type to the client.
     async fn start_notify_to_client(&self, scope: Scope) -> RpcResult<()> {
         let request = calicod_request::Payload::from_notification_type(&scope, Command::Start);
-        self.call((&request).into(), request).await?;
-        Ok(())
+        let response = self.call((&request).into(), request).await?;
+        Self::subscription_result(&response)
     }
 
     /// Stop sending notifications of some type to the client.
     async fn stop_notify_to_client(&self, scope: Scope) -> RpcResult<()> {
         if self.handle_stop_notify() {
             let request = calicod_request::Payload::from_notification_type(&scope, Command::Stop);
-            self.call((&request).into(), request).await?;
+            let response = self.call((&request).into(), request).await?;
+            Self::subscription_result(&response)?;
         }
         Ok(())
     }
+
+    /// Turns the error of a subscription command response, such as an authorization failure, into an error
+    fn subscription_result(response: &CalicodResponse) -> RpcResult<()> {
+        match response.payload.as_ref().and_then(|payload| payload.subscription_error()) {
+            Some(error) => Err(error.into()),
+            None => Ok(()),
+        }
+    }
 }
 
 #[async_trait]
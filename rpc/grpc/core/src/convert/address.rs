@@ -10,6 +10,15 @@ from!(item: &calico_rpc_core::RpcBalancesByAddressesEntry, protowire::RpcBalance
     Self { address: (&item.address).into(), balance: item.balance.unwrap_or_default(), error: None }
 });
 
+from!(item: &calico_rpc_core::RpcUtxoFilter, protowire::RpcUtxoFilter, {
+    Self {
+        script_classes: item.script_classes.iter().map(|x| x.to_string()).collect(),
+        min_amount: item.min_amount.unwrap_or_default(),
+        max_amount: item.max_amount.unwrap_or_default(),
+        script_prefixes: item.script_prefixes.iter().map(|x| x.into()).collect(),
+    }
+});
+
 // ----------------------------------------------------------------------------
 // protowire to rpc_core
 // ----------------------------------------------------------------------------
@@ -18,3 +27,12 @@ try_from!(item: &protowire::RpcBalancesByAddressesEntry, calico_rpc_core::RpcBal
     let balance = if item.error.is_some() { None } else { Some(item.balance) };
     Self { address: item.address.as_str().try_into()?, balance }
 });
+
+try_from!(item: &protowire::RpcUtxoFilter, calico_rpc_core::RpcUtxoFilter, {
+    Self {
+        script_classes: item.script_classes.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?,
+        min_amount: (item.min_amount > 0).then_some(item.min_amount),
+        max_amount: (item.max_amount > 0).then_some(item.max_amount),
+        script_prefixes: item.script_prefixes.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
@@ -1,6 +1,6 @@
 use crate::protowire;
 use crate::{from, try_from};
-use calico_rpc_core::{RpcError, RpcHash};
+use calico_rpc_core::{FromRpcHex, RpcError, RpcHash, ToRpcHex};
 use std::str::FromStr;
 
 // ----------------------------------------------------------------------------
@@ -38,6 +38,24 @@ from!(item: &calico_rpc_core::RpcBlockVerboseData, protowire::RpcBlockVerboseDat
     }
 });
 
+from!(item: &calico_rpc_core::RpcDagBlock, protowire::RpcDagBlock, {
+    Self {
+        hash: item.hash.to_string(),
+        parents: item.parents.iter().map(|x| x.to_string()).collect(),
+        selected_parent: item.selected_parent.to_string(),
+        blue_score: item.blue_score,
+        blue_work: item.blue_work.to_rpc_hex(),
+        daa_score: item.daa_score,
+        mergeset_blues: item.mergeset_blues.iter().map(|x| x.to_string()).collect(),
+        mergeset_reds: item.mergeset_reds.iter().map(|x| x.to_string()).collect(),
+        is_chain_block: item.is_chain_block,
+    }
+});
+
+from!(item: &calico_rpc_core::RpcIndexedChainBlock, protowire::RpcIndexedChainBlock, {
+    Self { hash: item.hash.to_string(), daa_score: item.daa_score, timestamp: item.timestamp }
+});
+
 // ----------------------------------------------------------------------------
 // protowire to rpc_core
 // ----------------------------------------------------------------------------
@@ -95,3 +113,21 @@ try_from!(item: &protowire::RpcBlockVerboseData, calico_rpc_core::RpcBlockVerbos
         is_chain_block: item.is_chain_block,
     }
 });
+
+try_from!(item: &protowire::RpcDagBlock, calico_rpc_core::RpcDagBlock, {
+    Self {
+        hash: RpcHash::from_str(&item.hash)?,
+        parents: item.parents.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, faster_hex::Error>>()?,
+        selected_parent: RpcHash::from_str(&item.selected_parent)?,
+        blue_score: item.blue_score,
+        blue_work: calico_rpc_core::RpcBlueWorkType::from_rpc_hex(&item.blue_work)?,
+        daa_score: item.daa_score,
+        mergeset_blues: item.mergeset_blues.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, faster_hex::Error>>()?,
+        mergeset_reds: item.mergeset_reds.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, faster_hex::Error>>()?,
+        is_chain_block: item.is_chain_block,
+    }
+});
+
+try_from!(item: &protowire::RpcIndexedChainBlock, calico_rpc_core::RpcIndexedChainBlock, {
+    Self { hash: RpcHash::from_str(&item.hash)?, daa_score: item.daa_score, timestamp: item.timestamp }
+});
@@ -63,6 +63,15 @@ pub mod calicod_request_convert {
     impl_into_calicod_request!(GetFeeEstimate);
     impl_into_calicod_request!(GetFeeEstimateExperimental);
     impl_into_calicod_request!(GetCurrentBlockColor);
+    impl_into_calicod_request!(GetMetricsHistory);
+    impl_into_calicod_request!(CreateDatabaseCheckpoint);
+    impl_into_calicod_request!(ExportDag);
+    impl_into_calicod_request!(IsDagAncestorOf);
+    impl_into_calicod_request!(GetBlockMergeset);
+    impl_into_calicod_request!(GetAnticoneSize);
+    impl_into_calicod_request!(GetChainPathBetween);
+    impl_into_calicod_request!(GetChainBlockByDaaScore);
+    impl_into_calicod_request!(GetBlocksByTimeRange);
 
     impl_into_calicod_request!(NotifyBlockAdded);
     impl_into_calicod_request!(NotifyNewBlockTemplate);
@@ -71,6 +80,10 @@ pub mod calicod_request_convert {
     impl_into_calicod_request!(NotifyFinalityConflict);
     impl_into_calicod_request!(NotifyVirtualDaaScoreChanged);
     impl_into_calicod_request!(NotifyVirtualChainChanged);
+    impl_into_calicod_request!(NotifyMempoolTransactionAdded);
+    impl_into_calicod_request!(NotifyMempoolTransactionRemoved);
+    impl_into_calicod_request!(NotifyDatabaseCheckpoint);
+    impl_into_calicod_request!(NotifyConsensusAuditViolation);
     impl_into_calicod_request!(NotifySinkBlueScoreChanged);
 
     macro_rules! impl_into_calicod_request {
@@ -200,6 +213,15 @@ pub mod calicod_response_convert {
     impl_into_calicod_response!(GetFeeEstimate);
     impl_into_calicod_response!(GetFeeEstimateExperimental);
     impl_into_calicod_response!(GetCurrentBlockColor);
+    impl_into_calicod_response!(GetMetricsHistory);
+    impl_into_calicod_response!(CreateDatabaseCheckpoint);
+    impl_into_calicod_response!(ExportDag);
+    impl_into_calicod_response!(IsDagAncestorOf);
+    impl_into_calicod_response!(GetBlockMergeset);
+    impl_into_calicod_response!(GetAnticoneSize);
+    impl_into_calicod_response!(GetChainPathBetween);
+    impl_into_calicod_response!(GetChainBlockByDaaScore);
+    impl_into_calicod_response!(GetBlocksByTimeRange);
 
     impl_into_calicod_notify_response!(NotifyBlockAdded);
     impl_into_calicod_notify_response!(NotifyNewBlockTemplate);
@@ -208,6 +230,10 @@ pub mod calicod_response_convert {
     impl_into_calicod_notify_response!(NotifyFinalityConflict);
     impl_into_calicod_notify_response!(NotifyVirtualDaaScoreChanged);
     impl_into_calicod_notify_response!(NotifyVirtualChainChanged);
+    impl_into_calicod_notify_response!(NotifyMempoolTransactionAdded);
+    impl_into_calicod_notify_response!(NotifyMempoolTransactionRemoved);
+    impl_into_calicod_notify_response!(NotifyDatabaseCheckpoint);
+    impl_into_calicod_notify_response!(NotifyConsensusAuditViolation);
     impl_into_calicod_notify_response!(NotifySinkBlueScoreChanged);
 
     impl_into_calicod_notify_response!(NotifyUtxosChanged, StopNotifyingUtxosChanged);
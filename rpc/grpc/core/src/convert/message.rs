@@ -23,7 +23,7 @@ use calico_consensus_core::network::NetworkId;
 use calico_core::debug;
 use calico_notify::subscription::Command;
 use calico_rpc_core::{
-    RpcContextualPeerAddress, RpcError, RpcExtraData, RpcHash, RpcIpAddress, RpcNetworkType, RpcPeerAddress, RpcResult,
+    RpcContextualPeerAddress, RpcDagRange, RpcError, RpcExtraData, RpcHash, RpcIpAddress, RpcNetworkType, RpcPeerAddress, RpcResult,
     SubmitBlockRejectReason, SubmitBlockReport,
 };
 use calico_utils::hex::*;
@@ -193,6 +193,26 @@ from!(item: &calico_rpc_core::NotifyNewBlockTemplateRequest, protowire::NotifyNe
 });
 from!(RpcResult<&calico_rpc_core::NotifyNewBlockTemplateResponse>, protowire::NotifyNewBlockTemplateResponseMessage);
 
+from!(item: &calico_rpc_core::NotifyMempoolTransactionAddedRequest, protowire::NotifyMempoolTransactionAddedRequestMessage, {
+    Self { addresses: item.addresses.iter().map(|x| x.into()).collect(), command: item.command.into() }
+});
+from!(RpcResult<&calico_rpc_core::NotifyMempoolTransactionAddedResponse>, protowire::NotifyMempoolTransactionAddedResponseMessage);
+
+from!(item: &calico_rpc_core::NotifyMempoolTransactionRemovedRequest, protowire::NotifyMempoolTransactionRemovedRequestMessage, {
+    Self { addresses: item.addresses.iter().map(|x| x.into()).collect(), command: item.command.into() }
+});
+from!(RpcResult<&calico_rpc_core::NotifyMempoolTransactionRemovedResponse>, protowire::NotifyMempoolTransactionRemovedResponseMessage);
+
+from!(item: &calico_rpc_core::NotifyDatabaseCheckpointRequest, protowire::NotifyDatabaseCheckpointRequestMessage, {
+    Self { command: item.command.into() }
+});
+from!(RpcResult<&calico_rpc_core::NotifyDatabaseCheckpointResponse>, protowire::NotifyDatabaseCheckpointResponseMessage);
+
+from!(item: &calico_rpc_core::NotifyConsensusAuditViolationRequest, protowire::NotifyConsensusAuditViolationRequestMessage, {
+    Self { command: item.command.into() }
+});
+from!(RpcResult<&calico_rpc_core::NotifyConsensusAuditViolationResponse>, protowire::NotifyConsensusAuditViolationResponseMessage);
+
 // ~~~
 
 from!(&calico_rpc_core::GetCurrentNetworkRequest, protowire::GetCurrentNetworkRequestMessage);
@@ -430,6 +450,116 @@ from!(item: RpcResult<&calico_rpc_core::GetCurrentBlockColorResponse>, protowire
     Self { blue: item.blue, error: None }
 });
 
+from!(item: &calico_rpc_core::GetMetricsHistoryRequest, protowire::GetMetricsHistoryRequestMessage, {
+    Self {
+        start_time: item.start_time.unwrap_or_default(),
+        end_time: item.end_time.unwrap_or_default(),
+        resolution: protowire::RpcMetricsResolution::from(item.resolution) as i32,
+        metrics: item.metrics.clone(),
+    }
+});
+from!(item: RpcResult<&calico_rpc_core::GetMetricsHistoryResponse>, protowire::GetMetricsHistoryResponseMessage, {
+    Self {
+        resolution: protowire::RpcMetricsResolution::from(item.resolution) as i32,
+        metrics: item.metrics.clone(),
+        samples: item.samples.iter().map(|x| x.into()).collect(),
+        error: None,
+    }
+});
+
+from!(item: &calico_rpc_core::CreateDatabaseCheckpointRequest, protowire::CreateDatabaseCheckpointRequestMessage, {
+    Self { directory: item.directory.clone() }
+});
+from!(item: RpcResult<&calico_rpc_core::CreateDatabaseCheckpointResponse>, protowire::CreateDatabaseCheckpointResponseMessage, {
+    Self { directory: item.directory.clone(), error: None }
+});
+
+from!(item: &calico_rpc_core::ExportDagRequest, protowire::ExportDagRequestMessage, {
+    use protowire::export_dag_request_message::{DaaScoreRange, HashRange, Range};
+    let range = match item.range {
+        RpcDagRange::Hashes { low_hash, high_hash } => {
+            Range::Hashes(HashRange { low_hash: low_hash.to_string(), high_hash: high_hash.to_string() })
+        }
+        RpcDagRange::DaaScores { low_daa_score, high_daa_score } => Range::DaaScores(DaaScoreRange { low_daa_score, high_daa_score }),
+    };
+    Self { range: Some(range) }
+});
+from!(item: RpcResult<&calico_rpc_core::ExportDagResponse>, protowire::ExportDagResponseMessage, {
+    Self {
+        low_hash: item.low_hash.to_string(),
+        high_hash: item.high_hash.to_string(),
+        blocks: item.blocks.iter().map(|x| x.into()).collect(),
+        error: None,
+    }
+});
+
+from!(item: &calico_rpc_core::IsDagAncestorOfRequest, protowire::IsDagAncestorOfRequestMessage, {
+    Self { low_hash: item.low_hash.to_string(), high_hash: item.high_hash.to_string() }
+});
+from!(item: RpcResult<&calico_rpc_core::IsDagAncestorOfResponse>, protowire::IsDagAncestorOfResponseMessage, {
+    Self {
+        is_dag_ancestor_of: item.is_dag_ancestor_of,
+        is_chain_ancestor_of: item.is_chain_ancestor_of,
+        blue_score_distance: item.blue_score_distance,
+        error: None,
+    }
+});
+
+from!(item: &calico_rpc_core::GetBlockMergesetRequest, protowire::GetBlockMergesetRequestMessage, {
+    Self { hash: item.hash.to_string() }
+});
+from!(item: RpcResult<&calico_rpc_core::GetBlockMergesetResponse>, protowire::GetBlockMergesetResponseMessage, {
+    Self {
+        selected_parent: item.selected_parent.to_string(),
+        mergeset_blues: item.mergeset_blues.iter().map(|x| x.to_string()).collect(),
+        mergeset_reds: item.mergeset_reds.iter().map(|x| x.to_string()).collect(),
+        error: None,
+    }
+});
+
+from!(item: &calico_rpc_core::GetAnticoneSizeRequest, protowire::GetAnticoneSizeRequestMessage, {
+    Self { hash: item.hash.to_string() }
+});
+from!(item: RpcResult<&calico_rpc_core::GetAnticoneSizeResponse>, protowire::GetAnticoneSizeResponseMessage, {
+    Self { anticone_size: item.anticone_size, error: None }
+});
+
+from!(item: &calico_rpc_core::GetChainPathBetweenRequest, protowire::GetChainPathBetweenRequestMessage, {
+    Self { from_hash: item.from_hash.to_string(), to_hash: item.to_hash.to_string() }
+});
+from!(item: RpcResult<&calico_rpc_core::GetChainPathBetweenResponse>, protowire::GetChainPathBetweenResponseMessage, {
+    Self {
+        removed_chain_block_hashes: item.removed_chain_block_hashes.iter().map(|x| x.to_string()).collect(),
+        added_chain_block_hashes: item.added_chain_block_hashes.iter().map(|x| x.to_string()).collect(),
+        truncated: item.truncated,
+        error: None,
+    }
+});
+
+from!(item: &calico_rpc_core::GetChainBlockByDaaScoreRequest, protowire::GetChainBlockByDaaScoreRequestMessage, {
+    Self { daa_score: item.daa_score }
+});
+from!(item: RpcResult<&calico_rpc_core::GetChainBlockByDaaScoreResponse>, protowire::GetChainBlockByDaaScoreResponseMessage, {
+    Self { block: Some((&item.block).into()), error: None }
+});
+
+from!(item: &calico_rpc_core::GetBlocksByTimeRangeRequest, protowire::GetBlocksByTimeRangeRequestMessage, {
+    Self {
+        start_timestamp: item.start_timestamp,
+        end_timestamp: item.end_timestamp,
+        after_hash: item.after_hash.map_or(Default::default(), |x| x.to_string()),
+        limit: item.limit,
+    }
+});
+from!(item: RpcResult<&calico_rpc_core::GetBlocksByTimeRangeResponse>, protowire::GetBlocksByTimeRangeResponseMessage, {
+    Self {
+        blocks: item.blocks.iter().map(|x| x.into()).collect(),
+        has_more: item.has_more,
+        merged_block_hashes: item.merged_block_hashes.iter().map(|x| x.to_string()).collect(),
+        error: None,
+    }
+});
+
 from!(&calico_rpc_core::PingRequest, protowire::PingRequestMessage);
 from!(RpcResult<&calico_rpc_core::PingResponse>, protowire::PingResponseMessage);
 
@@ -441,6 +571,7 @@ from!(item: &calico_rpc_core::GetMetricsRequest, protowire::GetMetricsRequestMes
         consensus_metrics: item.consensus_metrics,
         storage_metrics: item.storage_metrics,
         custom_metrics: item.custom_metrics,
+        rpc_method_metrics: item.rpc_method_metrics,
     }
 });
 from!(item: RpcResult<&calico_rpc_core::GetMetricsResponse>, protowire::GetMetricsResponseMessage, {
@@ -453,6 +584,7 @@ from!(item: RpcResult<&calico_rpc_core::GetMetricsResponse>, protowire::GetMetri
         storage_metrics: item.storage_metrics.as_ref().map(|x| x.into()),
         // TODO
         // custom_metrics : None,
+        rpc_method_metrics: item.rpc_method_metrics.iter().flatten().map(|x| x.into()).collect(),
         error: None,
     }
 });
@@ -485,6 +617,17 @@ from!(item: RpcResult<&calico_rpc_core::GetSystemInfoResponse>, protowire::GetSy
     }
 });
 
+from!(item: calico_rpc_core::RpcRetentionPolicy, protowire::RpcRetentionPolicy, {
+    match item {
+        calico_rpc_core::RpcRetentionPolicy::Unknown => Self::Unknown,
+        calico_rpc_core::RpcRetentionPolicy::Pruned => Self::Pruned,
+        calico_rpc_core::RpcRetentionPolicy::Archival => Self::Archival,
+        calico_rpc_core::RpcRetentionPolicy::BodiesForDays => Self::BodiesForDays,
+        calico_rpc_core::RpcRetentionPolicy::ChainBodies => Self::ChainBodies,
+        calico_rpc_core::RpcRetentionPolicy::AcceptanceData => Self::AcceptanceData,
+    }
+});
+
 from!(&calico_rpc_core::GetServerInfoRequest, protowire::GetServerInfoRequestMessage);
 from!(item: RpcResult<&calico_rpc_core::GetServerInfoResponse>, protowire::GetServerInfoResponseMessage, {
     Self {
@@ -495,6 +638,8 @@ from!(item: RpcResult<&calico_rpc_core::GetServerInfoResponse>, protowire::GetSe
         has_utxo_index: item.has_utxo_index,
         is_synced: item.is_synced,
         virtual_daa_score: item.virtual_daa_score,
+        retention_policy: protowire::RpcRetentionPolicy::from(item.retention_policy) as i32,
+        body_retention_days: item.body_retention_days,
         error: None,
     }
 });
@@ -508,7 +653,12 @@ from!(item: RpcResult<&calico_rpc_core::GetSyncStatusResponse>, protowire::GetSy
 });
 
 from!(item: &calico_rpc_core::NotifyUtxosChangedRequest, protowire::NotifyUtxosChangedRequestMessage, {
-    Self { addresses: item.addresses.iter().map(|x| x.into()).collect(), command: item.command.into() }
+    Self {
+        addresses: item.addresses.iter().map(|x| x.into()).collect(),
+        filter: (!item.filter.is_empty()).then(|| (&item.filter).into()),
+        resume_from: item.resume_from.unwrap_or_default(),
+        command: item.command.into(),
+    }
 });
 from!(item: &calico_rpc_core::NotifyUtxosChangedRequest, protowire::StopNotifyingUtxosChangedRequestMessage, {
     Self { addresses: item.addresses.iter().map(|x| x.into()).collect() }
@@ -540,7 +690,11 @@ from!(item: &calico_rpc_core::NotifyVirtualDaaScoreChangedRequest, protowire::No
 from!(RpcResult<&calico_rpc_core::NotifyVirtualDaaScoreChangedResponse>, protowire::NotifyVirtualDaaScoreChangedResponseMessage);
 
 from!(item: &calico_rpc_core::NotifyVirtualChainChangedRequest, protowire::NotifyVirtualChainChangedRequestMessage, {
-    Self { include_accepted_transaction_ids: item.include_accepted_transaction_ids, command: item.command.into() }
+    Self {
+        include_accepted_transaction_ids: item.include_accepted_transaction_ids,
+        resume_from: item.resume_from.unwrap_or_default(),
+        command: item.command.into(),
+    }
 });
 from!(RpcResult<&calico_rpc_core::NotifyVirtualChainChangedResponse>, protowire::NotifyVirtualChainChangedResponseMessage);
 
@@ -647,6 +801,35 @@ try_from!(item: &protowire::NotifyNewBlockTemplateRequestMessage, calico_rpc_cor
 });
 try_from!(&protowire::NotifyNewBlockTemplateResponseMessage, RpcResult<calico_rpc_core::NotifyNewBlockTemplateResponse>);
 
+try_from!(item: &protowire::NotifyMempoolTransactionAddedRequestMessage, calico_rpc_core::NotifyMempoolTransactionAddedRequest, {
+    Self {
+        addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?,
+        command: item.command.into(),
+    }
+});
+try_from!(&protowire::NotifyMempoolTransactionAddedResponseMessage, RpcResult<calico_rpc_core::NotifyMempoolTransactionAddedResponse>);
+
+try_from!(item: &protowire::NotifyMempoolTransactionRemovedRequestMessage, calico_rpc_core::NotifyMempoolTransactionRemovedRequest, {
+    Self {
+        addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?,
+        command: item.command.into(),
+    }
+});
+try_from!(
+    &protowire::NotifyMempoolTransactionRemovedResponseMessage,
+    RpcResult<calico_rpc_core::NotifyMempoolTransactionRemovedResponse>
+);
+
+try_from!(item: &protowire::NotifyDatabaseCheckpointRequestMessage, calico_rpc_core::NotifyDatabaseCheckpointRequest, {
+    Self { command: item.command.into() }
+});
+try_from!(&protowire::NotifyDatabaseCheckpointResponseMessage, RpcResult<calico_rpc_core::NotifyDatabaseCheckpointResponse>);
+
+try_from!(item: &protowire::NotifyConsensusAuditViolationRequestMessage, calico_rpc_core::NotifyConsensusAuditViolationRequest, {
+    Self { command: item.command.into() }
+});
+try_from!(&protowire::NotifyConsensusAuditViolationResponseMessage, RpcResult<calico_rpc_core::NotifyConsensusAuditViolationResponse>);
+
 // ~~~
 
 try_from!(&protowire::GetCurrentNetworkRequestMessage, calico_rpc_core::GetCurrentNetworkRequest);
@@ -917,6 +1100,130 @@ try_from!(item: &protowire::GetCurrentBlockColorResponseMessage, RpcResult<calic
     }
 });
 
+try_from!(item: &protowire::GetMetricsHistoryRequestMessage, calico_rpc_core::GetMetricsHistoryRequest, {
+    Self {
+        start_time: (item.start_time > 0).then_some(item.start_time),
+        end_time: (item.end_time > 0).then_some(item.end_time),
+        resolution: protowire::RpcMetricsResolution::try_from(item.resolution)
+            .map_err(|_| RpcError::PrimitiveToEnumConversionError)?
+            .into(),
+        metrics: item.metrics.clone(),
+    }
+});
+try_from!(item: &protowire::GetMetricsHistoryResponseMessage, RpcResult<calico_rpc_core::GetMetricsHistoryResponse>, {
+    Self {
+        resolution: protowire::RpcMetricsResolution::try_from(item.resolution)
+            .map_err(|_| RpcError::PrimitiveToEnumConversionError)?
+            .into(),
+        metrics: item.metrics.clone(),
+        samples: item.samples.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
+try_from!(item: &protowire::CreateDatabaseCheckpointRequestMessage, calico_rpc_core::CreateDatabaseCheckpointRequest, {
+    Self { directory: item.directory.clone() }
+});
+try_from!(item: &protowire::CreateDatabaseCheckpointResponseMessage, RpcResult<calico_rpc_core::CreateDatabaseCheckpointResponse>, {
+    Self { directory: item.directory.clone() }
+});
+
+try_from!(item: &protowire::ExportDagRequestMessage, calico_rpc_core::ExportDagRequest, {
+    use protowire::export_dag_request_message::Range;
+    let range = match item
+        .range
+        .as_ref()
+        .ok_or_else(|| RpcError::MissingRpcFieldError("ExportDagRequestMessage".to_string(), "range".to_string()))?
+    {
+        Range::Hashes(range) => {
+            RpcDagRange::Hashes { low_hash: RpcHash::from_str(&range.low_hash)?, high_hash: RpcHash::from_str(&range.high_hash)? }
+        }
+        Range::DaaScores(range) => {
+            RpcDagRange::DaaScores { low_daa_score: range.low_daa_score, high_daa_score: range.high_daa_score }
+        }
+    };
+    Self { range }
+});
+try_from!(item: &protowire::ExportDagResponseMessage, RpcResult<calico_rpc_core::ExportDagResponse>, {
+    Self {
+        low_hash: RpcHash::from_str(&item.low_hash)?,
+        high_hash: RpcHash::from_str(&item.high_hash)?,
+        blocks: item.blocks.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
+try_from!(item: &protowire::IsDagAncestorOfRequestMessage, calico_rpc_core::IsDagAncestorOfRequest, {
+    Self { low_hash: RpcHash::from_str(&item.low_hash)?, high_hash: RpcHash::from_str(&item.high_hash)? }
+});
+try_from!(item: &protowire::IsDagAncestorOfResponseMessage, RpcResult<calico_rpc_core::IsDagAncestorOfResponse>, {
+    Self {
+        is_dag_ancestor_of: item.is_dag_ancestor_of,
+        is_chain_ancestor_of: item.is_chain_ancestor_of,
+        blue_score_distance: item.blue_score_distance,
+    }
+});
+
+try_from!(item: &protowire::GetBlockMergesetRequestMessage, calico_rpc_core::GetBlockMergesetRequest, {
+    Self { hash: RpcHash::from_str(&item.hash)? }
+});
+try_from!(item: &protowire::GetBlockMergesetResponseMessage, RpcResult<calico_rpc_core::GetBlockMergesetResponse>, {
+    Self {
+        selected_parent: RpcHash::from_str(&item.selected_parent)?,
+        mergeset_blues: item.mergeset_blues.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, _>>()?,
+        mergeset_reds: item.mergeset_reds.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
+try_from!(item: &protowire::GetAnticoneSizeRequestMessage, calico_rpc_core::GetAnticoneSizeRequest, {
+    Self { hash: RpcHash::from_str(&item.hash)? }
+});
+try_from!(item: &protowire::GetAnticoneSizeResponseMessage, RpcResult<calico_rpc_core::GetAnticoneSizeResponse>, {
+    Self { anticone_size: item.anticone_size }
+});
+
+try_from!(item: &protowire::GetChainPathBetweenRequestMessage, calico_rpc_core::GetChainPathBetweenRequest, {
+    Self { from_hash: RpcHash::from_str(&item.from_hash)?, to_hash: RpcHash::from_str(&item.to_hash)? }
+});
+try_from!(item: &protowire::GetChainPathBetweenResponseMessage, RpcResult<calico_rpc_core::GetChainPathBetweenResponse>, {
+    Self {
+        removed_chain_block_hashes: item
+            .removed_chain_block_hashes
+            .iter()
+            .map(|x| RpcHash::from_str(x))
+            .collect::<Result<Vec<_>, _>>()?,
+        added_chain_block_hashes: item.added_chain_block_hashes.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, _>>()?,
+        truncated: item.truncated,
+    }
+});
+
+try_from!(item: &protowire::GetChainBlockByDaaScoreRequestMessage, calico_rpc_core::GetChainBlockByDaaScoreRequest, {
+    Self { daa_score: item.daa_score }
+});
+try_from!(item: &protowire::GetChainBlockByDaaScoreResponseMessage, RpcResult<calico_rpc_core::GetChainBlockByDaaScoreResponse>, {
+    Self {
+        block: item
+            .block
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetChainBlockByDaaScoreResponseMessage".to_string(), "block".to_string()))?
+            .try_into()?,
+    }
+});
+
+try_from!(item: &protowire::GetBlocksByTimeRangeRequestMessage, calico_rpc_core::GetBlocksByTimeRangeRequest, {
+    Self {
+        start_timestamp: item.start_timestamp,
+        end_timestamp: item.end_timestamp,
+        after_hash: if item.after_hash.is_empty() { None } else { Some(RpcHash::from_str(&item.after_hash)?) },
+        limit: item.limit,
+    }
+});
+try_from!(item: &protowire::GetBlocksByTimeRangeResponseMessage, RpcResult<calico_rpc_core::GetBlocksByTimeRangeResponse>, {
+    Self {
+        blocks: item.blocks.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+        merged_block_hashes: item.merged_block_hashes.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, _>>()?,
+        has_more: item.has_more,
+    }
+});
+
 try_from!(&protowire::PingRequestMessage, calico_rpc_core::PingRequest);
 try_from!(&protowire::PingResponseMessage, RpcResult<calico_rpc_core::PingResponse>);
 
@@ -928,9 +1235,12 @@ try_from!(item: &protowire::GetMetricsRequestMessage, calico_rpc_core::GetMetric
         consensus_metrics: item.consensus_metrics,
         storage_metrics: item.storage_metrics,
         custom_metrics : item.custom_metrics,
+        rpc_method_metrics: item.rpc_method_metrics,
     }
 });
 try_from!(item: &protowire::GetMetricsResponseMessage, RpcResult<calico_rpc_core::GetMetricsResponse>, {
+    let rpc_method_metrics =
+        item.rpc_method_metrics.iter().map(calico_rpc_core::RpcMethodMetrics::try_from).collect::<Result<Vec<_>, _>>()?;
     Self {
         server_time: item.server_time,
         process_metrics: item.process_metrics.as_ref().map(|x| x.try_into()).transpose()?,
@@ -940,6 +1250,7 @@ try_from!(item: &protowire::GetMetricsResponseMessage, RpcResult<calico_rpc_core
         storage_metrics: item.storage_metrics.as_ref().map(|x| x.try_into()).transpose()?,
         // TODO
         custom_metrics: None,
+        rpc_method_metrics: (!rpc_method_metrics.is_empty()).then_some(rpc_method_metrics),
     }
 });
 
@@ -967,6 +1278,17 @@ try_from!(item: &protowire::GetSystemInfoResponseMessage, RpcResult<calico_rpc_c
     }
 });
 
+from!(item: protowire::RpcRetentionPolicy, calico_rpc_core::RpcRetentionPolicy, {
+    match item {
+        protowire::RpcRetentionPolicy::Unknown => Self::Unknown,
+        protowire::RpcRetentionPolicy::Pruned => Self::Pruned,
+        protowire::RpcRetentionPolicy::Archival => Self::Archival,
+        protowire::RpcRetentionPolicy::BodiesForDays => Self::BodiesForDays,
+        protowire::RpcRetentionPolicy::ChainBodies => Self::ChainBodies,
+        protowire::RpcRetentionPolicy::AcceptanceData => Self::AcceptanceData,
+    }
+});
+
 try_from!(&protowire::GetServerInfoRequestMessage, calico_rpc_core::GetServerInfoRequest);
 try_from!(item: &protowire::GetServerInfoResponseMessage, RpcResult<calico_rpc_core::GetServerInfoResponse>, {
     Self {
@@ -977,6 +1299,10 @@ try_from!(item: &protowire::GetServerInfoResponseMessage, RpcResult<calico_rpc_c
         has_utxo_index: item.has_utxo_index,
         is_synced: item.is_synced,
         virtual_daa_score: item.virtual_daa_score,
+        retention_policy: protowire::RpcRetentionPolicy::try_from(item.retention_policy)
+            .map_err(|_| RpcError::PrimitiveToEnumConversionError)?
+            .into(),
+        body_retention_days: item.body_retention_days,
     }
 });
 
@@ -991,12 +1317,16 @@ try_from!(item: &protowire::NotifyUtxosChangedRequestMessage, calico_rpc_core::N
     Self {
         addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?,
         command: item.command.into(),
+        filter: item.filter.as_ref().map(calico_rpc_core::RpcUtxoFilter::try_from).transpose()?.unwrap_or_default(),
+        resume_from: (item.resume_from != 0).then_some(item.resume_from),
     }
 });
 try_from!(item: &protowire::StopNotifyingUtxosChangedRequestMessage, calico_rpc_core::NotifyUtxosChangedRequest, {
     Self {
         addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?,
         command: Command::Stop,
+        filter: Default::default(),
+        resume_from: None,
     }
 });
 try_from!(&protowire::NotifyUtxosChangedResponseMessage, RpcResult<calico_rpc_core::NotifyUtxosChangedResponse>);
@@ -1032,7 +1362,11 @@ try_from!(item: &protowire::NotifyVirtualDaaScoreChangedRequestMessage, calico_r
 try_from!(&protowire::NotifyVirtualDaaScoreChangedResponseMessage, RpcResult<calico_rpc_core::NotifyVirtualDaaScoreChangedResponse>);
 
 try_from!(item: &protowire::NotifyVirtualChainChangedRequestMessage, calico_rpc_core::NotifyVirtualChainChangedRequest, {
-    Self { include_accepted_transaction_ids: item.include_accepted_transaction_ids, command: item.command.into() }
+    Self {
+        include_accepted_transaction_ids: item.include_accepted_transaction_ids,
+        command: item.command.into(),
+        resume_from: (item.resume_from != 0).then_some(item.resume_from),
+    }
 });
 try_from!(&protowire::NotifyVirtualChainChangedResponseMessage, RpcResult<calico_rpc_core::NotifyVirtualChainChangedResponse>);
 
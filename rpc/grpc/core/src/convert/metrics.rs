@@ -80,6 +80,29 @@ from!(item: &calico_rpc_core::StorageMetrics, protowire::StorageMetrics, {
     }
 });
 
+from!(item: &calico_rpc_core::RpcMethodMetrics, protowire::RpcMethodMetrics, {
+    Self {
+        method: item.method.clone(),
+        calls: item.calls,
+        rate_limited: item.rate_limited,
+        total_cost: item.total_cost,
+        total_latency_micros: item.total_latency_micros,
+        max_latency_micros: item.max_latency_micros,
+    }
+});
+
+from!(item: calico_rpc_core::RpcMetricsResolution, protowire::RpcMetricsResolution, {
+    match item {
+        calico_rpc_core::RpcMetricsResolution::Second => Self::Second,
+        calico_rpc_core::RpcMetricsResolution::Minute => Self::Minute,
+        calico_rpc_core::RpcMetricsResolution::Hour => Self::Hour,
+    }
+});
+
+from!(item: &calico_rpc_core::RpcMetricsHistorySample, protowire::RpcMetricsHistorySample, {
+    Self { timestamp: item.timestamp, values: item.values.clone() }
+});
+
 // ----------------------------------------------------------------------------
 // protowire to rpc_core
 // ----------------------------------------------------------------------------
@@ -153,3 +176,26 @@ try_from!(item: &protowire::StorageMetrics, calico_rpc_core::StorageMetrics, {
         storage_size_bytes: item.storage_size_bytes,
     }
 });
+
+try_from!(item: &protowire::RpcMethodMetrics, calico_rpc_core::RpcMethodMetrics, {
+    Self {
+        method: item.method.clone(),
+        calls: item.calls,
+        rate_limited: item.rate_limited,
+        total_cost: item.total_cost,
+        total_latency_micros: item.total_latency_micros,
+        max_latency_micros: item.max_latency_micros,
+    }
+});
+
+from!(item: protowire::RpcMetricsResolution, calico_rpc_core::RpcMetricsResolution, {
+    match item {
+        protowire::RpcMetricsResolution::Second => Self::Second,
+        protowire::RpcMetricsResolution::Minute => Self::Minute,
+        protowire::RpcMetricsResolution::Hour => Self::Hour,
+    }
+});
+
+try_from!(item: &protowire::RpcMetricsHistorySample, calico_rpc_core::RpcMetricsHistorySample, {
+    Self { timestamp: item.timestamp, values: item.values.clone() }
+});
@@ -1,5 +1,9 @@
 use crate::protowire::{
-    calicod_response::Payload, BlockAddedNotificationMessage, CalicodResponse, NewBlockTemplateNotificationMessage, RpcNotifyCommand,
+    calicod_response::Payload, consensus_audit_violation_notification_message::Check as AuditCheck,
+    database_checkpoint_notification_message::State as CheckpointState,
+    mempool_transaction_removed_notification_message::RemovalReason, BlockAddedNotificationMessage, CalicodResponse,
+    ConsensusAuditViolationNotificationMessage, DatabaseCheckpointNotificationMessage, MempoolTransactionAddedNotificationMessage,
+    MempoolTransactionRemovedNotificationMessage, NewBlockTemplateNotificationMessage, RpcNotifyCommand,
 };
 use crate::protowire::{
     FinalityConflictNotificationMessage, FinalityConflictResolvedNotificationMessage, NotifyPruningPointUtxoSetOverrideRequestMessage,
@@ -11,7 +15,7 @@ use crate::protowire::{
 };
 use crate::{from, try_from};
 use calico_notify::subscription::Command;
-use calico_rpc_core::{Notification, RpcError, RpcHash};
+use calico_rpc_core::{Notification, RpcConsensusAuditCheck, RpcDatabaseCheckpointState, RpcError, RpcHash, RpcMempoolRemovalReason};
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -34,6 +38,14 @@ from!(item: &calico_rpc_core::Notification, Payload, {
         Notification::PruningPointUtxoSetOverride(ref notification) => {
             Payload::PruningPointUtxoSetOverrideNotification(notification.into())
         }
+        Notification::MempoolTransactionAdded(ref notification) => Payload::MempoolTransactionAddedNotification(notification.into()),
+        Notification::MempoolTransactionRemoved(ref notification) => {
+            Payload::MempoolTransactionRemovedNotification(notification.into())
+        }
+        Notification::DatabaseCheckpoint(ref notification) => Payload::DatabaseCheckpointNotification(notification.into()),
+        Notification::ConsensusAuditViolation(ref notification) => {
+            Payload::ConsensusAuditViolationNotification(notification.into())
+        }
     }
 });
 
@@ -46,6 +58,7 @@ from!(item: &calico_rpc_core::VirtualChainChangedNotification, VirtualChainChang
         removed_chain_block_hashes: item.removed_chain_block_hashes.iter().map(|x| x.to_string()).collect(),
         added_chain_block_hashes: item.added_chain_block_hashes.iter().map(|x| x.to_string()).collect(),
         accepted_transaction_ids: item.accepted_transaction_ids.iter().map(|x| x.into()).collect(),
+        cursor: item.cursor.unwrap_or_default(),
     }
 });
 
@@ -61,6 +74,7 @@ from!(item: &calico_rpc_core::UtxosChangedNotification, UtxosChangedNotification
     Self {
         added: item.added.iter().map(|x| x.into()).collect::<Vec<_>>(),
         removed: item.removed.iter().map(|x| x.into()).collect::<Vec<_>>(),
+        cursor: item.cursor.unwrap_or_default(),
     }
 });
 
@@ -82,13 +96,72 @@ from!(item: Command, RpcNotifyCommand, {
 });
 
 from!(item: &StopNotifyingUtxosChangedRequestMessage, NotifyUtxosChangedRequestMessage, {
-    Self { addresses: item.addresses.clone(), command: Command::Stop.into() }
+    Self { addresses: item.addresses.clone(), filter: None, resume_from: 0, command: Command::Stop.into() }
 });
 
 from!(_item: &StopNotifyingPruningPointUtxoSetOverrideRequestMessage, NotifyPruningPointUtxoSetOverrideRequestMessage, {
     Self { command: Command::Stop.into() }
 });
 
+from!(item: &calico_rpc_core::MempoolTransactionAddedNotification, MempoolTransactionAddedNotificationMessage, {
+    Self { transaction_id: item.transaction_id.to_string(), addresses: item.addresses.iter().map(|x| x.into()).collect() }
+});
+
+from!(item: RpcMempoolRemovalReason, RemovalReason, {
+    match item {
+        RpcMempoolRemovalReason::Accepted => RemovalReason::Accepted,
+        RpcMempoolRemovalReason::Evicted => RemovalReason::Evicted,
+        RpcMempoolRemovalReason::ReplacedByFee => RemovalReason::ReplacedByFee,
+        RpcMempoolRemovalReason::Expired => RemovalReason::Expired,
+        RpcMempoolRemovalReason::Invalidated => RemovalReason::Invalidated,
+        RpcMempoolRemovalReason::MissingOutpoints => RemovalReason::MissingOutpoints,
+        RpcMempoolRemovalReason::AncestorRemoved => RemovalReason::AncestorRemoved,
+    }
+});
+
+from!(item: &calico_rpc_core::MempoolTransactionRemovedNotification, MempoolTransactionRemovedNotificationMessage, {
+    Self {
+        transaction_id: item.transaction_id.to_string(),
+        addresses: item.addresses.iter().map(|x| x.into()).collect(),
+        reason: RemovalReason::from(item.reason) as i32,
+    }
+});
+
+from!(item: RpcDatabaseCheckpointState, CheckpointState, {
+    match item {
+        RpcDatabaseCheckpointState::Started => CheckpointState::Started,
+        RpcDatabaseCheckpointState::InProgress => CheckpointState::InProgress,
+        RpcDatabaseCheckpointState::Completed => CheckpointState::Completed,
+        RpcDatabaseCheckpointState::Failed => CheckpointState::Failed,
+    }
+});
+
+from!(item: &calico_rpc_core::DatabaseCheckpointNotification, DatabaseCheckpointNotificationMessage, {
+    Self {
+        directory: item.directory.clone(),
+        state: CheckpointState::from(item.state) as i32,
+        completed_databases: item.completed_databases,
+        total_databases: item.total_databases,
+        sink: item.sink.map(|x| x.to_string()),
+        virtual_daa_score: item.virtual_daa_score,
+        error: item.error.clone(),
+    }
+});
+
+from!(item: RpcConsensusAuditCheck, AuditCheck, {
+    match item {
+        RpcConsensusAuditCheck::Ghostdag => AuditCheck::Ghostdag,
+        RpcConsensusAuditCheck::Reachability => AuditCheck::Reachability,
+        RpcConsensusAuditCheck::DaaWindow => AuditCheck::DaaWindow,
+        RpcConsensusAuditCheck::CoinbaseReward => AuditCheck::CoinbaseReward,
+        RpcConsensusAuditCheck::UtxoCommitment => AuditCheck::UtxoCommitment,
+    }
+});
+
+from!(item: &calico_rpc_core::ConsensusAuditViolationNotification, ConsensusAuditViolationNotificationMessage, {
+    Self { check: AuditCheck::from(item.check) as i32, block_hash: item.block_hash.to_string(), details: item.details.clone() }
+});
+
 // ----------------------------------------------------------------------------
 // protowire to rpc_core
 // ----------------------------------------------------------------------------
@@ -117,6 +190,16 @@ try_from!(item: &Payload, calico_rpc_core::Notification, {
         Payload::PruningPointUtxoSetOverrideNotification(ref notification) => {
             Notification::PruningPointUtxoSetOverride(notification.try_into()?)
         }
+        Payload::MempoolTransactionAddedNotification(ref notification) => {
+            Notification::MempoolTransactionAdded(notification.try_into()?)
+        }
+        Payload::MempoolTransactionRemovedNotification(ref notification) => {
+            Notification::MempoolTransactionRemoved(notification.try_into()?)
+        }
+        Payload::DatabaseCheckpointNotification(ref notification) => Notification::DatabaseCheckpoint(notification.try_into()?),
+        Payload::ConsensusAuditViolationNotification(ref notification) => {
+            Notification::ConsensusAuditViolation(notification.try_into()?)
+        }
         _ => Err(RpcError::UnsupportedFeature)?,
     }
 });
@@ -143,6 +226,7 @@ try_from!(item: &VirtualChainChangedNotificationMessage, calico_rpc_core::Virtua
             item.added_chain_block_hashes.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, _>>()?,
         ),
         accepted_transaction_ids: Arc::new(item.accepted_transaction_ids.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?),
+        cursor: (item.cursor != 0).then_some(item.cursor),
     }
 });
 
@@ -158,6 +242,7 @@ try_from!(item: &UtxosChangedNotificationMessage, calico_rpc_core::UtxosChangedN
     Self {
         added: Arc::new(item.added.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?),
         removed: Arc::new(item.removed.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?),
+        cursor: (item.cursor != 0).then_some(item.cursor),
     }
 });
 
@@ -171,6 +256,72 @@ try_from!(item: &VirtualDaaScoreChangedNotificationMessage, calico_rpc_core::Vir
 
 try_from!(&PruningPointUtxoSetOverrideNotificationMessage, calico_rpc_core::PruningPointUtxoSetOverrideNotification);
 
+try_from!(item: &MempoolTransactionAddedNotificationMessage, calico_rpc_core::MempoolTransactionAddedNotification, {
+    Self {
+        transaction_id: RpcHash::from_str(&item.transaction_id)?,
+        addresses: Arc::new(item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?),
+    }
+});
+
+from!(item: RemovalReason, RpcMempoolRemovalReason, {
+    match item {
+        RemovalReason::Accepted => RpcMempoolRemovalReason::Accepted,
+        RemovalReason::Evicted => RpcMempoolRemovalReason::Evicted,
+        RemovalReason::ReplacedByFee => RpcMempoolRemovalReason::ReplacedByFee,
+        RemovalReason::Expired => RpcMempoolRemovalReason::Expired,
+        RemovalReason::Invalidated => RpcMempoolRemovalReason::Invalidated,
+        RemovalReason::MissingOutpoints => RpcMempoolRemovalReason::MissingOutpoints,
+        RemovalReason::AncestorRemoved => RpcMempoolRemovalReason::AncestorRemoved,
+    }
+});
+
+try_from!(item: &MempoolTransactionRemovedNotificationMessage, calico_rpc_core::MempoolTransactionRemovedNotification, {
+    Self {
+        transaction_id: RpcHash::from_str(&item.transaction_id)?,
+        addresses: Arc::new(item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?),
+        reason: RemovalReason::try_from(item.reason).map_err(|_| RpcError::PrimitiveToEnumConversionError)?.into(),
+    }
+});
+
+from!(item: CheckpointState, RpcDatabaseCheckpointState, {
+    match item {
+        CheckpointState::Started => RpcDatabaseCheckpointState::Started,
+        CheckpointState::InProgress => RpcDatabaseCheckpointState::InProgress,
+        CheckpointState::Completed => RpcDatabaseCheckpointState::Completed,
+        CheckpointState::Failed => RpcDatabaseCheckpointState::Failed,
+    }
+});
+
+try_from!(item: &DatabaseCheckpointNotificationMessage, calico_rpc_core::DatabaseCheckpointNotification, {
+    Self {
+        directory: item.directory.clone(),
+        state: CheckpointState::try_from(item.state).map_err(|_| RpcError::PrimitiveToEnumConversionError)?.into(),
+        completed_databases: item.completed_databases,
+        total_databases: item.total_databases,
+        sink: item.sink.as_deref().map(RpcHash::from_str).transpose()?,
+        virtual_daa_score: item.virtual_daa_score,
+        error: item.error.clone(),
+    }
+});
+
+from!(item: AuditCheck, RpcConsensusAuditCheck, {
+    match item {
+        AuditCheck::Ghostdag => RpcConsensusAuditCheck::Ghostdag,
+        AuditCheck::Reachability => RpcConsensusAuditCheck::Reachability,
+        AuditCheck::DaaWindow => RpcConsensusAuditCheck::DaaWindow,
+        AuditCheck::CoinbaseReward => RpcConsensusAuditCheck::CoinbaseReward,
+        AuditCheck::UtxoCommitment => RpcConsensusAuditCheck::UtxoCommitment,
+    }
+});
+
+try_from!(item: &ConsensusAuditViolationNotificationMessage, calico_rpc_core::ConsensusAuditViolationNotification, {
+    Self {
+        check: AuditCheck::try_from(item.check).map_err(|_| RpcError::PrimitiveToEnumConversionError)?.into(),
+        block_hash: RpcHash::from_str(&item.block_hash)?,
+        details: item.details.clone(),
+    }
+});
+
 from!(item: RpcNotifyCommand, Command, {
     match item {
         RpcNotifyCommand::NotifyStart => Command::Start,
@@ -2,9 +2,10 @@ use calico_notify::{scope::Scope, subscription::Command};
 
 use crate::protowire::{
     calicod_request, calicod_response, CalicodRequest, CalicodResponse, NotifyBlockAddedRequestMessage,
-    NotifyFinalityConflictRequestMessage, NotifyNewBlockTemplateRequestMessage, NotifyPruningPointUtxoSetOverrideRequestMessage,
-    NotifySinkBlueScoreChangedRequestMessage, NotifyUtxosChangedRequestMessage, NotifyVirtualChainChangedRequestMessage,
-    NotifyVirtualDaaScoreChangedRequestMessage,
+    NotifyConsensusAuditViolationRequestMessage, NotifyDatabaseCheckpointRequestMessage, NotifyFinalityConflictRequestMessage,
+    NotifyMempoolTransactionAddedRequestMessage, NotifyMempoolTransactionRemovedRequestMessage, NotifyNewBlockTemplateRequestMessage,
+    NotifyPruningPointUtxoSetOverrideRequestMessage, NotifySinkBlueScoreChangedRequestMessage, NotifyUtxosChangedRequestMessage,
+    NotifyVirtualChainChangedRequestMessage, NotifyVirtualDaaScoreChangedRequestMessage, RpcError,
 };
 
 impl CalicodRequest {
@@ -33,6 +34,7 @@ impl calicod_request::Payload {
                 calicod_request::Payload::NotifyVirtualChainChangedRequest(NotifyVirtualChainChangedRequestMessage {
                     command: command.into(),
                     include_accepted_transaction_ids: scope.include_accepted_transaction_ids,
+                    resume_from: scope.resume_from.unwrap_or_default(),
                 })
             }
             Scope::FinalityConflict(_) => {
@@ -47,6 +49,8 @@ impl calicod_request::Payload {
             }
             Scope::UtxosChanged(ref scope) => calicod_request::Payload::NotifyUtxosChangedRequest(NotifyUtxosChangedRequestMessage {
                 addresses: scope.addresses.iter().map(|x| x.into()).collect::<Vec<String>>(),
+                filter: (!scope.filter.is_empty()).then(|| (&scope.filter).into()),
+                resume_from: scope.resume_from.unwrap_or_default(),
                 command: command.into(),
             }),
             Scope::SinkBlueScoreChanged(_) => {
@@ -64,6 +68,28 @@ impl calicod_request::Payload {
                     command: command.into(),
                 })
             }
+            Scope::MempoolTransactionAdded(ref scope) => {
+                calicod_request::Payload::NotifyMempoolTransactionAddedRequest(NotifyMempoolTransactionAddedRequestMessage {
+                    addresses: scope.addresses.iter().map(|x| x.into()).collect::<Vec<String>>(),
+                    command: command.into(),
+                })
+            }
+            Scope::MempoolTransactionRemoved(ref scope) => {
+                calicod_request::Payload::NotifyMempoolTransactionRemovedRequest(NotifyMempoolTransactionRemovedRequestMessage {
+                    addresses: scope.addresses.iter().map(|x| x.into()).collect::<Vec<String>>(),
+                    command: command.into(),
+                })
+            }
+            Scope::DatabaseCheckpoint(_) => {
+                calicod_request::Payload::NotifyDatabaseCheckpointRequest(NotifyDatabaseCheckpointRequestMessage {
+                    command: command.into(),
+                })
+            }
+            Scope::ConsensusAuditViolation(_) => {
+                calicod_request::Payload::NotifyConsensusAuditViolationRequest(NotifyConsensusAuditViolationRequestMessage {
+                    command: command.into(),
+                })
+            }
         }
     }
 
@@ -79,6 +105,10 @@ impl calicod_request::Payload {
                 | Payload::NotifyVirtualDaaScoreChangedRequest(_)
                 | Payload::NotifyPruningPointUtxoSetOverrideRequest(_)
                 | Payload::NotifyNewBlockTemplateRequest(_)
+                | Payload::NotifyMempoolTransactionAddedRequest(_)
+                | Payload::NotifyMempoolTransactionRemovedRequest(_)
+                | Payload::NotifyDatabaseCheckpointRequest(_)
+                | Payload::NotifyConsensusAuditViolationRequest(_)
                 | Payload::StopNotifyingUtxosChangedRequest(_)
                 | Payload::StopNotifyingPruningPointUtxoSetOverrideRequest(_)
         )
@@ -96,6 +126,28 @@ impl CalicodResponse {
 
 #[allow(clippy::match_like_matches_macro)]
 impl calicod_response::Payload {
+    /// Error of a subscription command response, `None` for successful commands and other payloads
+    pub fn subscription_error(&self) -> Option<&RpcError> {
+        use crate::protowire::calicod_response::Payload;
+        match self {
+            Payload::NotifyBlockAddedResponse(response) => response.error.as_ref(),
+            Payload::NotifyVirtualChainChangedResponse(response) => response.error.as_ref(),
+            Payload::NotifyFinalityConflictResponse(response) => response.error.as_ref(),
+            Payload::NotifyUtxosChangedResponse(response) => response.error.as_ref(),
+            Payload::NotifySinkBlueScoreChangedResponse(response) => response.error.as_ref(),
+            Payload::StopNotifyingUtxosChangedResponse(response) => response.error.as_ref(),
+            Payload::NotifyPruningPointUtxoSetOverrideResponse(response) => response.error.as_ref(),
+            Payload::StopNotifyingPruningPointUtxoSetOverrideResponse(response) => response.error.as_ref(),
+            Payload::NotifyVirtualDaaScoreChangedResponse(response) => response.error.as_ref(),
+            Payload::NotifyNewBlockTemplateResponse(response) => response.error.as_ref(),
+            Payload::NotifyMempoolTransactionAddedResponse(response) => response.error.as_ref(),
+            Payload::NotifyMempoolTransactionRemovedResponse(response) => response.error.as_ref(),
+            Payload::NotifyDatabaseCheckpointResponse(response) => response.error.as_ref(),
+            Payload::NotifyConsensusAuditViolationResponse(response) => response.error.as_ref(),
+            _ => None,
+        }
+    }
+
     pub fn is_notification(&self) -> bool {
         use crate::protowire::calicod_response::Payload;
         match self {
@@ -108,6 +160,10 @@ impl calicod_response::Payload {
             Payload::VirtualDaaScoreChangedNotification(_) => true,
             Payload::PruningPointUtxoSetOverrideNotification(_) => true,
             Payload::NewBlockTemplateNotification(_) => true,
+            Payload::MempoolTransactionAddedNotification(_) => true,
+            Payload::MempoolTransactionRemovedNotification(_) => true,
+            Payload::DatabaseCheckpointNotification(_) => true,
+            Payload::ConsensusAuditViolationNotification(_) => true,
             _ => false,
         }
     }
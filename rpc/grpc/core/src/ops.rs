@@ -87,6 +87,15 @@ pub enum CalicodPayloadOps {
     GetFeeEstimate,
     GetFeeEstimateExperimental,
     GetCurrentBlockColor,
+    GetMetricsHistory,
+    CreateDatabaseCheckpoint,
+    ExportDag,
+    IsDagAncestorOf,
+    GetBlockMergeset,
+    GetAnticoneSize,
+    GetChainPathBetween,
+    GetChainBlockByDaaScore,
+    GetBlocksByTimeRange,
 
     // Subscription commands for starting/stopping notifications
     NotifyBlockAdded,
@@ -97,6 +106,10 @@ pub enum CalicodPayloadOps {
     NotifyPruningPointUtxoSetOverride,
     NotifyVirtualDaaScoreChanged,
     NotifyVirtualChainChanged,
+    NotifyMempoolTransactionAdded,
+    NotifyMempoolTransactionRemoved,
+    NotifyDatabaseCheckpoint,
+    NotifyConsensusAuditViolation,
 
     // Legacy stop subscription commands
     StopNotifyingUtxosChanged,
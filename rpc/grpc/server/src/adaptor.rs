@@ -1,7 +1,8 @@
-use crate::{connection_handler::ConnectionHandler, manager::Manager};
+use crate::{connection_handler::ConnectionHandler, error::GrpcServerResult, manager::Manager};
 use calico_core::debug;
 use calico_notify::{notifier::Notifier, subscription::context::SubscriptionContext};
 use calico_rpc_core::{api::rpc::DynRpcService, notify::connection::ChannelConnection, Notification, RpcResult};
+use calico_rpc_service::{auth::RpcAuthorizer, rate_limit::RpcRateLimiter, tls::RpcTlsConfig};
 use calico_utils::networking::NetAddress;
 use calico_utils_tower::counters::TowerConnectionCounters;
 use std::{ops::Deref, sync::Arc};
@@ -40,7 +41,10 @@ impl Adaptor {
         subscription_context: SubscriptionContext,
         broadcasters: usize,
         counters: Arc<TowerConnectionCounters>,
-    ) -> Arc<Self> {
+        authorizer: Option<Arc<RpcAuthorizer>>,
+        rate_limiter: Arc<RpcRateLimiter>,
+        tls: Option<Arc<RpcTlsConfig>>,
+    ) -> GrpcServerResult<Arc<Self>> {
         let (manager_sender, manager_receiver) = mpsc_channel(Self::manager_channel_size());
         let connection_handler = ConnectionHandler::new(
             network_bps,
@@ -50,12 +54,14 @@ impl Adaptor {
             subscription_context,
             broadcasters,
             counters,
+            authorizer,
+            rate_limiter,
         );
-        let server_termination = connection_handler.serve(serve_address);
+        let server_termination = connection_handler.serve(serve_address, tls)?;
         let adaptor = Arc::new(Adaptor::new(Some(server_termination), connection_handler, manager, serve_address));
         adaptor.manager.clone().start_event_loop(manager_receiver);
         adaptor.start();
-        adaptor
+        Ok(adaptor)
     }
 
     pub fn serve_address(&self) -> NetAddress {
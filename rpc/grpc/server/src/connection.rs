@@ -21,6 +21,7 @@ use calico_notify::{
     notifier::Notifier,
 };
 use calico_rpc_core::Notification;
+use calico_rpc_service::{auth::RpcCredentials, rate_limit::RpcConnectionLimiter};
 use itertools::Itertools;
 use parking_lot::Mutex;
 use std::{
@@ -69,6 +70,12 @@ struct Inner {
     /// The socket address of this client
     net_address: SocketAddr,
 
+    /// The authenticated client identity (`None` if authorization is disabled)
+    credentials: Option<RpcCredentials>,
+
+    /// The rate limiter of the RPC method calls of this client
+    limiter: RpcConnectionLimiter,
+
     /// The outgoing route for sending messages to this client
     outgoing_route: GrpcSender,
 
@@ -215,6 +222,7 @@ impl Display for Connection {
 impl Connection {
     pub(crate) fn new(
         net_address: SocketAddr,
+        credentials: Option<RpcCredentials>,
         server_context: ServerContext,
         interface: Arc<Interface>,
         manager_sender: MpscSender<ManagerEvent>,
@@ -223,10 +231,13 @@ impl Connection {
     ) -> Self {
         let (shutdown_sender, mut shutdown_receiver) = oneshot_channel();
         let mut router = Router::new(server_context.clone(), interface.clone());
+        let limiter = server_context.rate_limiter.connection(net_address.ip());
         let connection = Self {
             inner: Arc::new(Inner {
                 connection_id: Uuid::new_v4(),
                 net_address,
+                credentials,
+                limiter,
                 outgoing_route,
                 manager_sender,
                 server_context,
@@ -310,6 +321,14 @@ impl Connection {
         self.inner.net_address
     }
 
+    pub fn credentials(&self) -> Option<&RpcCredentials> {
+        self.inner.credentials.as_ref()
+    }
+
+    pub fn limiter(&self) -> &RpcConnectionLimiter {
+        &self.inner.limiter
+    }
+
     pub fn identity(&self) -> ConnectionId {
         self.inner.connection_id
     }
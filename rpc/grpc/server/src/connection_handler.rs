@@ -1,6 +1,7 @@
 use crate::{
     collector::{GrpcServiceCollector, GrpcServiceConverter},
     connection::Connection,
+    error::{GrpcServerError, GrpcServerResult},
     manager::{ManagerEvent, RegistrationRequest},
     request_handler::{factory::Factory, interface::Interface},
 };
@@ -21,9 +22,14 @@ use calico_notify::{
     subscription::{context::SubscriptionContext, MutationPolicies, UtxosChangedMutationPolicy},
 };
 use calico_rpc_core::{
-    api::rpc::DynRpcService,
+    api::{ops::RpcApiOps, rpc::DynRpcService},
     notify::{channel::NotificationChannel, connection::ChannelConnection},
-    Notification, RpcResult,
+    Notification, RpcError, RpcResult,
+};
+use calico_rpc_service::{
+    auth::RpcAuthorizer,
+    rate_limit::RpcRateLimiter,
+    tls::{RpcTlsConfig, ALPN_H2},
 };
 use calico_utils::networking::NetAddress;
 use calico_utils_tower::{
@@ -33,6 +39,7 @@ use calico_utils_tower::{
 use futures::{FutureExt, Stream};
 use std::fmt::Debug;
 use std::{
+    net::SocketAddr,
     pin::Pin,
     sync::{
         atomic::{AtomicBool, Ordering},
@@ -42,10 +49,14 @@ use std::{
 };
 use tokio::sync::mpsc::{channel as mpsc_channel, Sender as MpscSender};
 use tokio::{
+    net::TcpListener,
     sync::oneshot::{channel as oneshot_channel, Sender as OneshotSender},
     time::timeout,
 };
-use tokio_stream::{wrappers::ReceiverStream, StreamExt};
+use tokio_stream::{
+    wrappers::{ReceiverStream, TcpListenerStream},
+    StreamExt,
+};
 use tonic::{codec::CompressionEncoding, transport::Server as TonicServer, Request, Response};
 
 #[derive(Clone)]
@@ -54,11 +65,39 @@ pub struct ServerContext {
     pub core_service: DynRpcService,
     /// The notifier relaying RPC core notifications to connections
     pub notifier: Arc<Notifier<Notification, Connection>>,
+    /// The authorizer of RPC method calls (`None` if all calls are allowed)
+    pub authorizer: Option<Arc<RpcAuthorizer>>,
+    /// The rate limiter and cost accounting of RPC method calls
+    pub rate_limiter: Arc<RpcRateLimiter>,
 }
 
 impl ServerContext {
-    pub fn new(core_service: DynRpcService, notifier: Arc<Notifier<Notification, Connection>>) -> Self {
-        Self { core_service, notifier }
+    pub fn new(
+        core_service: DynRpcService,
+        notifier: Arc<Notifier<Notification, Connection>>,
+        authorizer: Option<Arc<RpcAuthorizer>>,
+        rate_limiter: Arc<RpcRateLimiter>,
+    ) -> Self {
+        Self { core_service, notifier, authorizer, rate_limiter }
+    }
+
+    /// Checks that the `connection` client is allowed to call `method`
+    pub fn authorize(&self, connection: &Connection, method: RpcApiOps) -> RpcResult<()> {
+        match (self.authorizer.as_ref(), connection.credentials()) {
+            (Some(authorizer), Some(credentials)) => authorizer.authorize(credentials, connection, method),
+            (Some(_), None) => Err(RpcError::AuthenticationFailed),
+            (None, _) => Ok(()),
+        }
+    }
+
+    /// Charges a call to `method` costing `cost` to the `connection` rate limits
+    pub fn admit(&self, connection: &Connection, method: RpcApiOps, cost: u64) -> RpcResult<()> {
+        self.rate_limiter.admit(connection.limiter(), connection, method, cost)
+    }
+
+    /// Charges a notification subscription command to the `connection` rate limits
+    pub fn admit_subscription(&self, connection: &Connection) -> RpcResult<()> {
+        self.admit(connection, RpcApiOps::Subscribe, RpcApiOps::Subscribe.cost())
     }
 }
 
@@ -80,6 +119,9 @@ pub struct ConnectionHandler {
 
 const GRPC_SERVER: &str = "grpc-server";
 
+/// Request metadata key carrying the client API key (`Bearer <token>`)
+pub const AUTHORIZATION_METADATA_KEY: &str = "authorization";
+
 impl ConnectionHandler {
     pub(crate) fn new(
         network_bps: u64,
@@ -89,6 +131,8 @@ impl ConnectionHandler {
         subscription_context: SubscriptionContext,
         broadcasters: usize,
         counters: Arc<TowerConnectionCounters>,
+        authorizer: Option<Arc<RpcAuthorizer>>,
+        rate_limiter: Arc<RpcRateLimiter>,
     ) -> Self {
         // This notifier UTXOs subscription granularity to rpc-core notifier
         let policies = MutationPolicies::new(UtxosChangedMutationPolicy::AddressSet);
@@ -114,19 +158,31 @@ impl ConnectionHandler {
             broadcasters,
             policies,
         ));
-        let server_context = ServerContext::new(core_service, notifier);
+        let server_context = ServerContext::new(core_service, notifier, authorizer, rate_limiter);
         let interface = Arc::new(Factory::new_interface(server_context.clone(), network_bps));
         let running = Default::default();
 
         Self { manager_sender, server_context, interface, running, counters }
     }
 
-    /// Launches a gRPC server listener loop
-    pub(crate) fn serve(&self, serve_address: NetAddress) -> OneshotSender<()> {
+    /// Launches a gRPC server listener loop, terminating TLS connections if `tls` is provided
+    pub(crate) fn serve(&self, serve_address: NetAddress, tls: Option<Arc<RpcTlsConfig>>) -> GrpcServerResult<OneshotSender<()>> {
+        // Set up TLS and bind the listener upfront so that startup errors are reported to the caller
+        let tls = tls
+            .map(|tls| tls.acceptor(&[ALPN_H2]).map(|acceptor| (tls, acceptor)))
+            .transpose()
+            .map_err(|err| GrpcServerError::StartupError(format!("TLS error: {err}")))?;
+        let listener = std::net::TcpListener::bind(SocketAddr::from(serve_address))
+            .and_then(|listener| {
+                listener.set_nonblocking(true)?;
+                TcpListener::from_std(listener)
+            })
+            .map_err(|err| GrpcServerError::StartupError(format!("bind error on {serve_address}: {err}")))?;
+
         let (termination_sender, termination_receiver) = oneshot_channel::<()>();
         let (signal_sender, signal_receiver) = oneshot_channel::<()>();
         let connection_handler = self.clone();
-        info!("GRPC Server starting on: {}", serve_address);
+        info!("GRPC Server starting on: {}{}", serve_address, if tls.is_some() { " (TLS)" } else { "" });
 
         let bytes_tx = self.counters.bytes_tx.clone();
         let bytes_rx = self.counters.bytes_rx.clone();
@@ -141,19 +197,23 @@ impl ConnectionHandler {
             // TODO: check whether we should set tcp_keepalive
             // const GRPC_KEEP_ALIVE_PING_INTERVAL: Duration = Duration::from_secs(5);
             // const GRPC_KEEP_ALIVE_PING_TIMEOUT: Duration = Duration::from_secs(120);
-            let serve_result = TonicServer::builder()
+            let server = TonicServer::builder()
                 // .http2_keepalive_interval(Some(GRPC_KEEP_ALIVE_PING_INTERVAL))
                 // .http2_keepalive_timeout(Some(GRPC_KEEP_ALIVE_PING_TIMEOUT))
                 .layer(MapRequestBodyLayer::new(move |body| CountBytesBody::new(body, bytes_rx.clone()).boxed_unsync()))
                 .layer(MapResponseBodyLayer::new(move |body| CountBytesBody::new(body, bytes_tx.clone())))
-                .add_service(protowire_server)
-                .serve_with_shutdown(
-                    serve_address.into(),
-                    signal_receiver.map(|_| {
-                        debug!("GRPC, Server received the shutdown signal");
-                    }),
-                )
-                .await;
+                .add_service(protowire_server);
+            let shutdown_signal = signal_receiver.map(|_| {
+                debug!("GRPC, Server received the shutdown signal");
+            });
+
+            let serve_result = match tls {
+                Some((tls, acceptor)) => {
+                    let incoming = ReceiverStream::new(tls.accept(GRPC_SERVER, listener, acceptor)).map(Ok::<_, std::io::Error>);
+                    server.serve_with_incoming_shutdown(incoming, shutdown_signal).await
+                }
+                None => server.serve_with_incoming_shutdown(TcpListenerStream::new(listener), shutdown_signal).await,
+            };
 
             match serve_result {
                 Ok(_) => info!("GRPC Server stopped on: {}", serve_address),
@@ -169,7 +229,7 @@ impl ConnectionHandler {
                 warn!("GRPC Server stopped forcefully on: {}", serve_address);
             }
         });
-        termination_sender
+        Ok(termination_sender)
     }
 
     #[inline(always)]
@@ -254,6 +314,25 @@ impl Rpc for ConnectionHandler {
 
         debug!("GRPC, Incoming message stream from {:?}", remote_address);
 
+        // Authenticate the client by the API key provided in the request metadata
+        let credentials = match self.server_context.authorizer.as_ref() {
+            Some(authorizer) => {
+                let token = request
+                    .metadata()
+                    .get(AUTHORIZATION_METADATA_KEY)
+                    .and_then(|value| value.to_str().ok())
+                    .map(|value| value.strip_prefix("Bearer ").unwrap_or(value).trim());
+                match authorizer.authenticate(token) {
+                    Ok(credentials) => Some(credentials),
+                    Err(err) => {
+                        warn!("GRPC, refusing incoming message stream from {:?} - {}", remote_address, err);
+                        return Err(tonic::Status::new(tonic::Code::Unauthenticated, err.to_string()));
+                    }
+                }
+            }
+            None => None,
+        };
+
         // Build the in/out pipes
         let (outgoing_route, outgoing_receiver) = mpsc_channel(Self::outgoing_route_channel_size());
         let incoming_stream = request.into_inner();
@@ -261,6 +340,7 @@ impl Rpc for ConnectionHandler {
         // Build the connection object
         let connection = Connection::new(
             remote_address,
+            credentials,
             self.server_context(),
             self.interface(),
             self.manager_sender(),
@@ -27,6 +27,9 @@ pub enum GrpcServerError {
 
     #[error("outgoing route capacity has been reached (client: {0})")]
     OutgoingRouteCapacityReached(String),
+
+    #[error("server startup error: {0}")]
+    StartupError(String),
 }
 
 impl From<GrpcServerError> for calico_rpc_core::error::RpcError {
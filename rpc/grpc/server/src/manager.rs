@@ -1,10 +1,11 @@
 use crate::connection::{Connection, ConnectionId};
 use calico_core::{debug, info, warn};
 use calico_notify::connection::Connection as ConnectionT;
+use calico_rpc_service::metrics::RpcConnectionCounters;
 use parking_lot::RwLock;
 use std::{
     collections::{hash_map::Entry::Occupied, HashMap, HashSet},
-    sync::Arc,
+    sync::{atomic::Ordering, Arc},
     time::Duration,
 };
 use thiserror::Error;
@@ -38,11 +39,12 @@ pub(crate) enum ManagerEvent {
 pub struct Manager {
     connections: Arc<RwLock<HashMap<ConnectionId, Connection>>>,
     max_connections: usize,
+    counters: Arc<RpcConnectionCounters>,
 }
 
 impl Manager {
-    pub fn new(max_connections: usize) -> Self {
-        Self { connections: Default::default(), max_connections }
+    pub fn new(max_connections: usize, counters: Arc<RpcConnectionCounters>) -> Self {
+        Self { connections: Default::default(), max_connections, counters }
     }
 
     /// Starts a loop for receiving central manager events from all connections. This mechanism is used for
@@ -75,12 +77,15 @@ impl Manager {
 
         // Check if there is room for a new connection
         if connections_write.len() >= self.max_connections {
+            self.counters.rejected_connections.fetch_add(1, Ordering::Relaxed);
             return Err(RegistrationError::CapacityReached(self.max_connections));
         }
 
         debug!("GRPC, Registering a new connection from {connection}");
         let previous_connection = connections_write.insert(connection.identity(), connection.clone());
         info!("GRPC, new incoming connection {} #{}", connection, connections_write.len());
+        self.counters.active_connections.store(connections_write.len(), Ordering::Relaxed);
+        self.counters.total_connections.fetch_add(1, Ordering::Relaxed);
 
         // Release the write lock to prevent a deadlock if a previous connection exists and must be closed
         drop(connections_write);
@@ -103,6 +108,7 @@ impl Manager {
             if Connection::ptr_eq(entry.get(), &connection) {
                 entry.remove_entry();
                 info!("GRPC, end connection {} #{}", connection, connection_count);
+                self.counters.active_connections.store(connections_write.len(), Ordering::Relaxed);
             }
         }
     }
@@ -14,7 +14,7 @@ use crate::{
 use calico_grpc_core::protowire::{calicod_request::Payload, *};
 use calico_grpc_core::{ops::CalicodPayloadOps, protowire::NotifyFinalityConflictResponseMessage};
 use calico_notify::{scope::FinalityConflictResolvedScope, subscriber::SubscriptionManager};
-use calico_rpc_core::{SubmitBlockRejectReason, SubmitBlockReport, SubmitBlockResponse};
+use calico_rpc_core::{api::ops::RpcApiOps, SubmitBlockRejectReason, SubmitBlockReport, SubmitBlockResponse};
 use calico_rpc_macros::build_grpc_server_interface;
 
 pub struct Factory {}
@@ -81,6 +81,15 @@ impl Factory {
                 GetFeeEstimate,
                 GetFeeEstimateExperimental,
                 GetCurrentBlockColor,
+                GetMetricsHistory,
+                CreateDatabaseCheckpoint,
+                ExportDag,
+                IsDagAncestorOf,
+                GetBlockMergeset,
+                GetAnticoneSize,
+                GetChainPathBetween,
+                GetChainBlockByDaaScore,
+                GetBlocksByTimeRange,
                 NotifyBlockAdded,
                 NotifyNewBlockTemplate,
                 NotifyFinalityConflict,
@@ -89,6 +98,10 @@ impl Factory {
                 NotifyPruningPointUtxoSetOverride,
                 NotifyVirtualDaaScoreChanged,
                 NotifyVirtualChainChanged,
+                NotifyMempoolTransactionAdded,
+                NotifyMempoolTransactionRemoved,
+                NotifyDatabaseCheckpoint,
+                NotifyConsensusAuditViolation,
                 StopNotifyingUtxosChanged,
                 StopNotifyingPruningPointUtxoSetOverride,
             ]
@@ -100,7 +113,10 @@ impl Factory {
             Box::pin(async move {
                 let mut response: CalicodResponse = match request.payload {
                     Some(Payload::NotifyFinalityConflictRequest(ref request)) => {
-                        match calico_rpc_core::NotifyFinalityConflictRequest::try_from(request) {
+                        match calico_rpc_core::NotifyFinalityConflictRequest::try_from(request)
+                            .and_then(|request| server_ctx.authorize(&connection, RpcApiOps::NotifyFinalityConflict).map(|_| request))
+                            .and_then(|request| server_ctx.admit_subscription(&connection).map(|_| request))
+                        {
                             Ok(request) => {
                                 let listener_id = connection.get_or_register_listener_id()?;
                                 let command = request.command;
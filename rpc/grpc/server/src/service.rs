@@ -2,10 +2,10 @@ use crate::{adaptor::Adaptor, manager::Manager};
 use calico_consensus_core::config::Config;
 use calico_core::{
     debug,
-    task::service::{AsyncService, AsyncServiceFuture},
+    task::service::{AsyncService, AsyncServiceError, AsyncServiceFuture},
     trace, warn,
 };
-use calico_rpc_service::service::RpcCoreService;
+use calico_rpc_service::{auth::RpcAuthorizer, service::RpcCoreService, tls::RpcTlsConfig};
 use calico_utils::{networking::NetAddress, triggers::SingleTrigger};
 use calico_utils_tower::counters::TowerConnectionCounters;
 use std::sync::Arc;
@@ -20,6 +20,8 @@ pub struct GrpcService {
     started: SingleTrigger,
     shutdown: SingleTrigger,
     counters: Arc<TowerConnectionCounters>,
+    authorizer: Option<Arc<RpcAuthorizer>>,
+    tls: Option<Arc<RpcTlsConfig>>,
 }
 
 impl GrpcService {
@@ -32,6 +34,8 @@ impl GrpcService {
         rpc_max_clients: usize,
         broadcasters: usize,
         counters: Arc<TowerConnectionCounters>,
+        authorizer: Option<Arc<RpcAuthorizer>>,
+        tls: Option<Arc<RpcTlsConfig>>,
     ) -> Self {
         Self {
             net_address: address,
@@ -42,6 +46,8 @@ impl GrpcService {
             started: Default::default(),
             shutdown: Default::default(),
             counters,
+            authorizer,
+            tls,
         }
     }
 
@@ -61,7 +67,7 @@ impl AsyncService for GrpcService {
         // Prepare a shutdown signal receiver
         let shutdown_signal = self.shutdown.listener.clone();
 
-        let manager = Manager::new(self.rpc_max_clients);
+        let manager = Manager::new(self.rpc_max_clients, self.core_service.grpc_connection_counters());
         let grpc_adaptor = Adaptor::server(
             self.net_address,
             self.config.bps(),
@@ -71,7 +77,14 @@ impl AsyncService for GrpcService {
             self.core_service.subscription_context(),
             self.broadcasters,
             self.counters.clone(),
+            self.authorizer.clone(),
+            self.core_service.rate_limiter(),
+            self.tls.clone(),
         );
+        let grpc_adaptor = match grpc_adaptor {
+            Ok(grpc_adaptor) => grpc_adaptor,
+            Err(err) => return Box::pin(async move { Err(AsyncServiceError::Service(format!("{} {}", Self::IDENT, err))) }),
+        };
 
         // Signal the server was started
         self.started.trigger.trigger();
@@ -1,9 +1,13 @@
-use super::rpc_core_mock::RpcCoreMock;
-use crate::{adaptor::Adaptor, manager::Manager};
+use crate::{
+    adaptor::Adaptor,
+    error::{GrpcServerError, GrpcServerResult},
+    manager::Manager,
+};
 use calico_core::info;
 use calico_grpc_client::GrpcClient;
 use calico_notify::scope::{NewBlockTemplateScope, Scope};
 use calico_rpc_core::api::rpc::RpcApi;
+use calico_rpc_core::mock::RpcCoreMock;
 use calico_utils::networking::{ContextualNetAddress, NetAddress};
 use std::sync::Arc;
 
@@ -188,10 +192,28 @@ async fn test_client_server_notifications() {
     tokio::time::sleep(std::time::Duration::from_millis(50)).await;
 }
 
+#[tokio::test]
+async fn test_server_startup_error() {
+    let rpc_core_service = Arc::new(RpcCoreMock::new());
+    let server = create_server(rpc_core_service.clone());
+
+    // Binding a second server to the same address is reported as a startup error
+    let result = create_server_on(server.serve_address(), rpc_core_service);
+    assert!(matches!(result, Err(GrpcServerError::StartupError(_))), "the bind error should be reported to the caller");
+
+    assert!(server.stop().await.is_ok(), "error stopping the server");
+    drop(server);
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+}
+
 fn create_server(core_service: Arc<RpcCoreMock>) -> Arc<Adaptor> {
-    let manager = Manager::new(128);
+    create_server_on(get_free_net_address(), core_service).unwrap()
+}
+
+fn create_server_on(serve_address: NetAddress, core_service: Arc<RpcCoreMock>) -> GrpcServerResult<Arc<Adaptor>> {
+    let manager = Manager::new(128, Default::default());
     Adaptor::server(
-        get_free_net_address(),
+        serve_address,
         1,
         manager,
         core_service.clone(),
@@ -199,6 +221,9 @@ fn create_server(core_service: Arc<RpcCoreMock>) -> Arc<Adaptor> {
         core_service.subscription_context(),
         3,
         Default::default(),
+        None,
+        Default::default(),
+        None,
     )
 }
 
@@ -0,0 +1,149 @@
+//!
+//! JSON-RPC 2.0 message types.
+//!
+//! Method names are the camelCase [`RpcApiOps`](calico_rpc_core::api::ops::RpcApiOps)
+//! names (e.g. `getBlockDagInfo`) and params are the JSON serialization of the
+//! method request, exactly as sent by wRPC JSON clients. Only by-name params
+//! (a JSON object) are supported.
+//!
+
+use calico_rpc_core::RpcError;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::fmt::Display;
+
+pub const JSONRPC_VERSION: &str = "2.0";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct JsonRpcRequest {
+    pub jsonrpc: String,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+    /// Request id (`None` for notifications, which get no response)
+    #[serde(default, deserialize_with = "deserialize_id")]
+    pub id: Option<Value>,
+}
+
+/// Distinguishes a `null` id, which is valid, from a missing one
+fn deserialize_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Value>, D::Error> {
+    Value::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonRpcResponse {
+    pub jsonrpc: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<JsonRpcError>,
+    pub id: Value,
+}
+
+impl JsonRpcResponse {
+    pub fn new(id: Value, result: JsonRpcResult<Value>) -> Self {
+        let (result, error) = match result {
+            Ok(result) => (Some(result), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self { jsonrpc: JSONRPC_VERSION.to_string(), result, error, id }
+    }
+
+    pub fn error(id: Value, error: JsonRpcError) -> Self {
+        Self::new(id, Err(error))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonRpcError {
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+pub type JsonRpcResult<T> = std::result::Result<T, JsonRpcError>;
+
+impl JsonRpcError {
+    pub const PARSE_ERROR: i64 = -32700;
+    pub const INVALID_REQUEST: i64 = -32600;
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    pub const INVALID_PARAMS: i64 = -32602;
+    pub const INTERNAL_ERROR: i64 = -32603;
+    /// The node failed processing the call
+    pub const SERVER_ERROR: i64 = -32000;
+    /// The client is not authenticated or not allowed to call the method
+    pub const UNAUTHORIZED: i64 = -32001;
+    /// The client exceeded its rate limit, `data.retryAfterMs` tells when to retry
+    pub const RATE_LIMITED: i64 = -32002;
+
+    pub fn new(code: i64, message: impl Display) -> Self {
+        Self { code, message: message.to_string(), data: None }
+    }
+
+    pub fn parse_error(err: impl Display) -> Self {
+        Self::new(Self::PARSE_ERROR, format!("Parse error: {err}"))
+    }
+
+    pub fn invalid_request(err: impl Display) -> Self {
+        Self::new(Self::INVALID_REQUEST, format!("Invalid request: {err}"))
+    }
+
+    pub fn method_not_found(method: &str) -> Self {
+        Self::new(Self::METHOD_NOT_FOUND, format!("Method not found: {method}"))
+    }
+
+    pub fn invalid_params(err: impl Display) -> Self {
+        Self::new(Self::INVALID_PARAMS, format!("Invalid params: {err}"))
+    }
+
+    pub fn internal_error(err: impl Display) -> Self {
+        Self::new(Self::INTERNAL_ERROR, format!("Internal error: {err}"))
+    }
+}
+
+impl From<RpcError> for JsonRpcError {
+    fn from(err: RpcError) -> Self {
+        match err {
+            RpcError::AuthenticationFailed | RpcError::AuthorizationDenied(..) => Self::new(Self::UNAUTHORIZED, err),
+            RpcError::RateLimitExceeded(_, _, retry_after_ms) => {
+                Self { data: Some(serde_json::json!({ "retryAfterMs": retry_after_ms })), ..Self::new(Self::RATE_LIMITED, err) }
+            }
+            err => Self::new(Self::SERVER_ERROR, err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_jsonrpc_messages() {
+        let request: JsonRpcRequest = serde_json::from_value(json!({"jsonrpc": "2.0", "method": "getInfo", "id": 1})).unwrap();
+        assert_eq!(request.id, Some(json!(1)));
+        assert!(request.params.is_null());
+        let request: JsonRpcRequest = serde_json::from_value(json!({"jsonrpc": "2.0", "method": "getInfo", "id": null})).unwrap();
+        assert_eq!(request.id, Some(Value::Null));
+        let notification: JsonRpcRequest =
+            serde_json::from_value(json!({"jsonrpc": "2.0", "method": "getInfo", "params": {}})).unwrap();
+        assert_eq!(notification.id, None);
+
+        let response = JsonRpcResponse::new(json!(1), Ok(json!({"isSynced": true})));
+        assert_eq!(serde_json::to_value(response).unwrap(), json!({"jsonrpc": "2.0", "result": {"isSynced": true}, "id": 1}));
+        let response = JsonRpcResponse::error(json!("a"), RpcError::RateLimitExceeded("GetBlocks".to_string(), 220, 1500).into());
+        assert_eq!(
+            serde_json::to_value(response).unwrap(),
+            json!({
+                "jsonrpc": "2.0",
+                "error": {
+                    "code": JsonRpcError::RATE_LIMITED,
+                    "message": "Rate limit exceeded: method GetBlocks costs 220 units, retry in 1500 ms.",
+                    "data": {"retryAfterMs": 1500}
+                },
+                "id": "a"
+            })
+        );
+    }
+}
@@ -0,0 +1,19 @@
+//!
+//! JSON-RPC 2.0 over HTTP server.
+//!
+//! Exposes the RPC methods of the node to plain HTTP clients. Method calls are
+//! dispatched to the same [`RpcApi`](calico_rpc_core::api::rpc::RpcApi)
+//! implementation as wRPC JSON, with the same request and response formats.
+//! Notification subscriptions are only available over wRPC and gRPC.
+//!
+//! Also hosts the OpenMetrics exporter of the node metrics.
+//!
+
+pub mod jsonrpc;
+pub mod metrics;
+pub mod router;
+pub mod server;
+pub mod service;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,129 @@
+use bytes::Bytes;
+use calico_core::{
+    debug, info,
+    task::service::{AsyncService, AsyncServiceError, AsyncServiceFuture},
+    trace,
+};
+use calico_rpc_service::{metrics::OPEN_METRICS_CONTENT_TYPE, service::RpcCoreService};
+use calico_utils::triggers::SingleTrigger;
+use http_body_util::Full;
+use hyper::{
+    body::Incoming,
+    header::{HeaderValue, ALLOW, CONTENT_TYPE},
+    server::conn::http1,
+    service::service_fn,
+    Method, Request, Response, StatusCode,
+};
+use hyper_util::rt::TokioIo;
+use std::{convert::Infallible, net::SocketAddr, sync::Arc};
+use tokio::net::{TcpListener, TcpStream};
+
+const METRICS_SERVER: &str = "metrics-server";
+
+/// Options for configuring the metrics exporter
+#[derive(Default)]
+pub struct MetricsOptions {
+    pub listen_address: String,
+}
+
+/// Exporter of the node metrics in the OpenMetrics text format, scraped on `GET /metrics`.
+///
+/// The endpoint is unauthenticated and is meant to be exposed to the monitoring
+/// infrastructure only.
+pub struct MetricsService {
+    core_service: Arc<RpcCoreService>,
+    options: MetricsOptions,
+    shutdown: SingleTrigger,
+}
+
+impl MetricsService {
+    pub fn new(core_service: Arc<RpcCoreService>, options: MetricsOptions) -> Self {
+        Self { core_service, options, shutdown: Default::default() }
+    }
+
+    async fn serve(self: Arc<Self>) -> Result<(), AsyncServiceError> {
+        let listener = TcpListener::bind(&self.options.listen_address)
+            .await
+            .map_err(|err| AsyncServiceError::Service(format!("unable to listen on {}: {err}", self.options.listen_address)))?;
+        info!("Metrics exporter starting on: http://{}/metrics", self.options.listen_address);
+        let shutdown_signal = self.shutdown.listener.clone();
+        tokio::pin!(shutdown_signal);
+        loop {
+            tokio::select! {
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, peer)) => {
+                        tokio::spawn(self.clone().serve_connection(stream, peer));
+                    }
+                    Err(err) => debug!("{} accept error: {}", METRICS_SERVER, err),
+                },
+                _ = &mut shutdown_signal => break,
+            }
+        }
+        trace!("{} accept loop exited", METRICS_SERVER);
+        Ok(())
+    }
+
+    async fn serve_connection(self: Arc<Self>, stream: TcpStream, peer: SocketAddr) {
+        let this = self.clone();
+        let service = service_fn(move |request| {
+            let this = this.clone();
+            async move { Ok::<_, Infallible>(this.handle(request).await) }
+        });
+        let http_connection = http1::Builder::new().serve_connection(TokioIo::new(stream), service);
+        tokio::pin!(http_connection);
+        tokio::select! {
+            result = http_connection.as_mut() => {
+                if let Err(err) = result {
+                    debug!("{} connection error with {}: {}", METRICS_SERVER, peer, err);
+                }
+            }
+            _ = self.shutdown.listener.clone() => {
+                http_connection.as_mut().graceful_shutdown();
+                let _ = http_connection.await;
+            }
+        }
+    }
+
+    async fn handle(&self, request: Request<Incoming>) -> Response<Full<Bytes>> {
+        if request.uri().path() != "/metrics" {
+            return Self::status(StatusCode::NOT_FOUND);
+        }
+        if request.method() != Method::GET {
+            let mut response = Self::status(StatusCode::METHOD_NOT_ALLOWED);
+            response.headers_mut().insert(ALLOW, HeaderValue::from_static("GET"));
+            return response;
+        }
+        let mut response = Response::new(Full::new(Bytes::from(self.core_service.open_metrics().await)));
+        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static(OPEN_METRICS_CONTENT_TYPE));
+        response
+    }
+
+    fn status(status: StatusCode) -> Response<Full<Bytes>> {
+        let mut response = Response::new(Full::default());
+        *response.status_mut() = status;
+        response
+    }
+}
+
+impl AsyncService for MetricsService {
+    fn ident(self: Arc<Self>) -> &'static str {
+        METRICS_SERVER
+    }
+
+    fn start(self: Arc<Self>) -> AsyncServiceFuture {
+        trace!("{} starting", METRICS_SERVER);
+        Box::pin(async move { self.serve().await })
+    }
+
+    fn signal_exit(self: Arc<Self>) {
+        trace!("sending an exit signal to {}", METRICS_SERVER);
+        self.shutdown.trigger.trigger();
+    }
+
+    fn stop(self: Arc<Self>) -> AsyncServiceFuture {
+        Box::pin(async move {
+            trace!("{} stopped", METRICS_SERVER);
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    jsonrpc::{JsonRpcError, JsonRpcRequest, JsonRpcResponse, JsonRpcResult, JSONRPC_VERSION},
+    server::{Connection, Server},
+};
+use calico_rpc_core::{api::ops::RpcApiOps, prelude::*, with_rpc_methods};
+use calico_rpc_macros::build_http_server_interface;
+use futures::future::{join_all, BoxFuture};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Maximum number of calls in a batch request
+pub const MAX_BATCH_SIZE: usize = 256;
+
+type MethodFn = dyn Fn(Server, Connection, Value) -> BoxFuture<'static, JsonRpcResult<Value>> + Send + Sync;
+
+/// JSON-RPC methods dispatching calls to the RPC service of a [`Server`]
+pub struct Interface {
+    server: Server,
+    methods: HashMap<RpcApiOps, Box<MethodFn>>,
+}
+
+impl Interface {
+    pub fn new(server: Server) -> Self {
+        Self { server, methods: HashMap::new() }
+    }
+
+    pub fn method<F>(&mut self, op: RpcApiOps, method: F)
+    where
+        F: Fn(Server, Connection, Value) -> BoxFuture<'static, JsonRpcResult<Value>> + Send + Sync + 'static,
+    {
+        self.methods.insert(op, Box::new(method));
+    }
+
+    pub fn server(&self) -> &Server {
+        &self.server
+    }
+
+    /// Processes the JSON body of an HTTP request, either a single call or a batch.
+    /// Returns `None` if no response is expected (notifications only).
+    pub async fn call(&self, connection: &Connection, body: &[u8]) -> Option<Value> {
+        let message = match serde_json::from_slice::<Value>(body) {
+            Ok(message) => message,
+            Err(err) => return Some(to_value(JsonRpcResponse::error(Value::Null, JsonRpcError::parse_error(err)))),
+        };
+        match message {
+            Value::Array(batch) if batch.is_empty() => {
+                Some(to_value(JsonRpcResponse::error(Value::Null, JsonRpcError::invalid_request("empty batch"))))
+            }
+            Value::Array(batch) if batch.len() > MAX_BATCH_SIZE => Some(to_value(JsonRpcResponse::error(
+                Value::Null,
+                JsonRpcError::invalid_request(format!("batch exceeds {MAX_BATCH_SIZE} calls")),
+            ))),
+            Value::Array(batch) => {
+                let responses = join_all(batch.into_iter().map(|call| self.call_one(connection, call))).await;
+                let responses = responses.into_iter().flatten().map(to_value).collect::<Vec<_>>();
+                (!responses.is_empty()).then_some(Value::Array(responses))
+            }
+            call => self.call_one(connection, call).await.map(to_value),
+        }
+    }
+
+    async fn call_one(&self, connection: &Connection, call: Value) -> Option<JsonRpcResponse> {
+        let request = match serde_json::from_value::<JsonRpcRequest>(call) {
+            Ok(request) if request.jsonrpc == JSONRPC_VERSION => request,
+            Ok(request) => {
+                let err = JsonRpcError::invalid_request(format!("unsupported version {}", request.jsonrpc));
+                return Some(JsonRpcResponse::error(request.id.unwrap_or_default(), err));
+            }
+            Err(err) => return Some(JsonRpcResponse::error(Value::Null, JsonRpcError::invalid_request(err))),
+        };
+        let method =
+            serde_json::from_value::<RpcApiOps>(Value::String(request.method.clone())).ok().and_then(|op| self.methods.get(&op));
+        let result = match method {
+            Some(method) => method(self.server.clone(), connection.clone(), request.params).await,
+            None => Err(JsonRpcError::method_not_found(&request.method)),
+        };
+        request.id.map(|id| JsonRpcResponse::new(id, result))
+    }
+}
+
+fn to_value(response: JsonRpcResponse) -> Value {
+    serde_json::to_value(response).expect("JSON-RPC responses are serializable")
+}
+
+pub struct Router {
+    pub interface: Interface,
+}
+
+impl Router {
+    pub fn new(server: Server) -> Self {
+        // The following macro iterates the supplied enum variants taking the variant
+        // name and creating a JSON-RPC handler using that name, in the same way as
+        // the wRPC router does. Notification subscriptions are only available to
+        // wRPC and gRPC clients.
+        #[allow(unreachable_patterns)]
+        let interface = with_rpc_methods!(build_http_server_interface!(server, Server, Connection, RpcApiOps,));
+
+        Router { interface }
+    }
+}
@@ -0,0 +1,96 @@
+use calico_rpc_core::{
+    api::{ops::RpcApiOps, rpc::DynRpcService},
+    RpcError, RpcResult,
+};
+use calico_rpc_service::{
+    auth::{RpcAuthorizer, RpcCredentials},
+    rate_limit::RpcRateLimiter,
+};
+use std::{
+    fmt::{Display, Formatter},
+    net::SocketAddr,
+    sync::Arc,
+    time::Duration,
+};
+
+/// Context of the HTTP requests received on a single TCP connection
+#[derive(Debug, Clone)]
+pub struct Connection {
+    peer: SocketAddr,
+    /// Identity authenticated by the `authorization` header of the current request
+    credentials: Option<RpcCredentials>,
+}
+
+impl Connection {
+    pub fn new(peer: SocketAddr) -> Self {
+        Self { peer, credentials: None }
+    }
+
+    pub fn with_credentials(&self, credentials: Option<RpcCredentials>) -> Self {
+        Self { credentials, ..self.clone() }
+    }
+
+    pub fn peer(&self) -> SocketAddr {
+        self.peer
+    }
+
+    pub fn credentials(&self) -> Option<&RpcCredentials> {
+        self.credentials.as_ref()
+    }
+}
+
+impl Display for Connection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "http://{}", self.peer)
+    }
+}
+
+/// Server context supplied to every JSON-RPC method call
+#[derive(Clone)]
+pub struct Server {
+    rpc_service: DynRpcService,
+    authorizer: Option<Arc<RpcAuthorizer>>,
+    rate_limiter: Arc<RpcRateLimiter>,
+}
+
+impl Server {
+    pub fn new(rpc_service: DynRpcService, authorizer: Option<Arc<RpcAuthorizer>>, rate_limiter: Arc<RpcRateLimiter>) -> Self {
+        Self { rpc_service, authorizer, rate_limiter }
+    }
+
+    pub fn rpc_service(&self) -> DynRpcService {
+        self.rpc_service.clone()
+    }
+
+    pub fn authorizer(&self) -> Option<&Arc<RpcAuthorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    /// Creates the context of a new connection from `peer`
+    pub fn connect(&self, peer: SocketAddr) -> Connection {
+        Connection::new(peer)
+    }
+
+    /// Checks that the `connection` client is allowed to call `method`
+    pub fn authorize(&self, connection: &Connection, method: RpcApiOps) -> RpcResult<()> {
+        match (self.authorizer.as_ref(), connection.credentials()) {
+            (Some(authorizer), Some(credentials)) => authorizer.authorize(credentials, connection, method),
+            (Some(_), None) => Err(RpcError::AuthenticationFailed),
+            (None, _) => Ok(()),
+        }
+    }
+
+    /// Charges a call to `method` costing `cost` to the rate limits of the API key authenticating
+    /// the request or, for anonymous requests, of the peer IP address (rather than of the TCP
+    /// connection, which a client can renew at will)
+    pub fn admit(&self, connection: &Connection, method: RpcApiOps, cost: u64) -> RpcResult<()> {
+        let name = connection.credentials().and_then(|credentials| credentials.name.as_deref());
+        let limiter = self.rate_limiter.client(connection.peer().ip(), name);
+        self.rate_limiter.admit(&limiter, connection, method, cost)
+    }
+
+    /// Records a completed call to `method`
+    pub fn record(&self, method: RpcApiOps, cost: u64, latency: Duration) {
+        self.rate_limiter.record(method, cost, latency)
+    }
+}
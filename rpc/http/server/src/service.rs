@@ -0,0 +1,215 @@
+use crate::{
+    jsonrpc::{JsonRpcError, JsonRpcResponse},
+    router::Router,
+    server::{Connection, Server},
+};
+use bytes::Bytes;
+use calico_core::{
+    debug, info,
+    task::service::{AsyncService, AsyncServiceError, AsyncServiceFuture},
+    trace, warn,
+};
+use calico_rpc_service::{
+    auth::RpcAuthorizer,
+    service::RpcCoreService,
+    tls::{RpcTlsConfig, ALPN_HTTP1},
+};
+use calico_utils::triggers::SingleTrigger;
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::{
+    body::Incoming,
+    header::{HeaderValue, ALLOW, AUTHORIZATION, CONTENT_TYPE},
+    server::conn::http1,
+    service::service_fn,
+    Method, Request, Response, StatusCode,
+};
+use hyper_util::rt::TokioIo;
+use serde_json::Value;
+use std::{convert::Infallible, net::SocketAddr, sync::Arc};
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::TcpListener,
+};
+
+const HTTP_SERVER: &str = "http-server";
+
+/// Maximum size of a request body
+const MAX_REQUEST_BODY_SIZE: usize = 32 * 1024 * 1024; // 32MB
+
+/// Options for configuring the HTTP JSON-RPC server
+#[derive(Default)]
+pub struct Options {
+    pub listen_address: String,
+    /// Authorizer of RPC method calls (all calls are allowed if `None`)
+    pub authorizer: Option<Arc<RpcAuthorizer>>,
+    /// TLS termination config (plaintext connections if `None`)
+    pub tls: Option<Arc<RpcTlsConfig>>,
+}
+
+/// JSON-RPC 2.0 over HTTP server.
+///
+/// Calls are POSTed as JSON-RPC requests (or batches of requests) on any path
+/// and authenticated by the `authorization: Bearer <token>` header when an
+/// authorizer is configured.
+pub struct HttpService {
+    router: Arc<Router>,
+    options: Options,
+    shutdown: SingleTrigger,
+}
+
+impl HttpService {
+    pub fn new(core_service: Arc<RpcCoreService>, options: Options) -> Self {
+        let server = Server::new(core_service.clone(), options.authorizer.clone(), core_service.rate_limiter());
+        Self { router: Arc::new(Router::new(server)), options, shutdown: Default::default() }
+    }
+
+    async fn serve(self: Arc<Self>) -> Result<(), AsyncServiceError> {
+        let listener = TcpListener::bind(&self.options.listen_address)
+            .await
+            .map_err(|err| AsyncServiceError::Service(format!("unable to listen on {}: {err}", self.options.listen_address)))?;
+        info!("HTTP JSON-RPC Server starting on: {}", self.options.listen_address);
+        let shutdown_signal = self.shutdown.listener.clone();
+        tokio::pin!(shutdown_signal);
+
+        match self.options.tls.clone() {
+            Some(tls) => {
+                let acceptor = tls.acceptor(&[ALPN_HTTP1]).map_err(|err| AsyncServiceError::Service(err.to_string()))?;
+                let mut incoming = tls.accept(HTTP_SERVER, listener, acceptor);
+                loop {
+                    tokio::select! {
+                        stream = incoming.recv() => match stream {
+                            Some(stream) => {
+                                let Ok(peer) = stream.get_ref().0.peer_addr() else { continue };
+                                tokio::spawn(self.clone().serve_connection(stream, peer));
+                            }
+                            None => break,
+                        },
+                        _ = &mut shutdown_signal => break,
+                    }
+                }
+            }
+            None => loop {
+                tokio::select! {
+                    accepted = listener.accept() => match accepted {
+                        Ok((stream, peer)) => {
+                            tokio::spawn(self.clone().serve_connection(stream, peer));
+                        }
+                        Err(err) => debug!("{} accept error: {}", HTTP_SERVER, err),
+                    },
+                    _ = &mut shutdown_signal => break,
+                }
+            },
+        }
+        trace!("{} accept loop exited", HTTP_SERVER);
+        Ok(())
+    }
+
+    async fn serve_connection<S>(self: Arc<Self>, stream: S, peer: SocketAddr)
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
+        let connection = self.router.interface.server().connect(peer);
+        let this = self.clone();
+        let service = service_fn(move |request| {
+            let this = this.clone();
+            let connection = connection.clone();
+            async move { Ok::<_, Infallible>(this.handle(connection, request).await) }
+        });
+        let http_connection = http1::Builder::new().serve_connection(TokioIo::new(stream), service);
+        tokio::pin!(http_connection);
+        tokio::select! {
+            result = http_connection.as_mut() => {
+                if let Err(err) = result {
+                    debug!("{} connection error with {}: {}", HTTP_SERVER, peer, err);
+                }
+            }
+            _ = self.shutdown.listener.clone() => {
+                // Let the request in progress, if any, complete
+                http_connection.as_mut().graceful_shutdown();
+                let _ = http_connection.await;
+            }
+        }
+    }
+
+    async fn handle(&self, connection: Connection, request: Request<Incoming>) -> Response<Full<Bytes>> {
+        if request.method() != Method::POST {
+            let mut response = Self::response(StatusCode::METHOD_NOT_ALLOWED, None);
+            response.headers_mut().insert(ALLOW, HeaderValue::from_static("POST"));
+            return response;
+        }
+
+        // Authenticate the client by the API key provided in the request headers
+        let connection = match self.router.interface.server().authorizer() {
+            Some(authorizer) => {
+                let token = request
+                    .headers()
+                    .get(AUTHORIZATION)
+                    .and_then(|value| value.to_str().ok())
+                    .map(|value| value.strip_prefix("Bearer ").unwrap_or(value).trim());
+                match authorizer.authenticate(token) {
+                    Ok(credentials) => connection.with_credentials(Some(credentials)),
+                    Err(err) => {
+                        warn!("{} refusing request from {} - {}", HTTP_SERVER, connection.peer(), err);
+                        let body = JsonRpcResponse::error(Value::Null, err.into());
+                        return Self::response(StatusCode::UNAUTHORIZED, Some(serde_json::to_value(body).unwrap()));
+                    }
+                }
+            }
+            None => connection,
+        };
+
+        let body = match Limited::new(request.into_body(), MAX_REQUEST_BODY_SIZE).collect().await {
+            Ok(body) => body.to_bytes(),
+            Err(err) => {
+                let status = if err.downcast_ref::<LengthLimitError>().is_some() {
+                    StatusCode::PAYLOAD_TOO_LARGE
+                } else {
+                    StatusCode::BAD_REQUEST
+                };
+                let body = JsonRpcResponse::error(Value::Null, JsonRpcError::invalid_request(err));
+                return Self::response(status, Some(serde_json::to_value(body).unwrap()));
+            }
+        };
+
+        match self.router.interface.call(&connection, &body).await {
+            Some(body) => Self::response(StatusCode::OK, Some(body)),
+            None => Self::response(StatusCode::NO_CONTENT, None),
+        }
+    }
+
+    fn response(status: StatusCode, body: Option<Value>) -> Response<Full<Bytes>> {
+        let mut response = match body {
+            Some(body) => {
+                let mut response = Response::new(Full::new(Bytes::from(body.to_string())));
+                response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                response
+            }
+            None => Response::new(Full::default()),
+        };
+        *response.status_mut() = status;
+        response
+    }
+}
+
+impl AsyncService for HttpService {
+    fn ident(self: Arc<Self>) -> &'static str {
+        HTTP_SERVER
+    }
+
+    fn start(self: Arc<Self>) -> AsyncServiceFuture {
+        trace!("{} starting", HTTP_SERVER);
+        Box::pin(async move { self.serve().await })
+    }
+
+    fn signal_exit(self: Arc<Self>) {
+        trace!("sending an exit signal to {}", HTTP_SERVER);
+        self.shutdown.trigger.trigger();
+    }
+
+    fn stop(self: Arc<Self>) -> AsyncServiceFuture {
+        Box::pin(async move {
+            trace!("{} stopped", HTTP_SERVER);
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{
+    jsonrpc::JsonRpcError,
+    router::{Router, MAX_BATCH_SIZE},
+    server::{Connection, Server},
+};
+use calico_rpc_core::{api::ops::RpcApiOps, mock::RpcCoreMock};
+use calico_rpc_service::{
+    auth::{RpcApiKey, RpcAuthConfig, RpcAuthorizer, RpcRole},
+    rate_limit::{RpcRateLimitConfig, RpcRateLimiter},
+};
+use serde_json::{json, Value};
+use std::{net::SocketAddr, sync::Arc};
+
+fn create_router(authorizer: Option<RpcAuthorizer>, rate_limits: RpcRateLimitConfig) -> Router {
+    let server = Server::new(Arc::new(RpcCoreMock::new()), authorizer.map(Arc::new), Arc::new(RpcRateLimiter::new(rate_limits)));
+    Router::new(server)
+}
+
+fn peer() -> SocketAddr {
+    "127.0.0.1:22110".parse().unwrap()
+}
+
+fn error_code(response: &Value) -> i64 {
+    response["error"]["code"].as_i64().unwrap_or_else(|| panic!("expected an error response, got {response}"))
+}
+
+async fn call(router: &Router, connection: &Connection, body: Value) -> Option<Value> {
+    router.interface.call(connection, &serde_json::to_vec(&body).unwrap()).await
+}
+
+#[tokio::test]
+async fn test_http_single_calls() {
+    let router = create_router(None, Default::default());
+    let connection = Connection::new(peer());
+
+    let response = call(&router, &connection, json!({"jsonrpc": "2.0", "method": "getInfo", "params": {}, "id": 1})).await.unwrap();
+    assert_eq!(response["id"], json!(1));
+    assert_eq!(response["result"]["serverVersion"], json!("mock"));
+    assert!(response.get("error").is_none());
+
+    // Methods without arguments may omit params
+    let response = call(&router, &connection, json!({"jsonrpc": "2.0", "method": "ping", "id": "a"})).await.unwrap();
+    assert_eq!(response["id"], json!("a"));
+    assert_eq!(response["result"], json!({}));
+
+    // A null id is answered, a missing one is a notification
+    let response = call(&router, &connection, json!({"jsonrpc": "2.0", "method": "ping", "id": null})).await.unwrap();
+    assert_eq!(response["id"], Value::Null);
+    assert!(call(&router, &connection, json!({"jsonrpc": "2.0", "method": "ping"})).await.is_none());
+
+    let response = call(&router, &connection, json!({"jsonrpc": "2.0", "method": "getFoo", "id": 2})).await.unwrap();
+    assert_eq!(error_code(&response), JsonRpcError::METHOD_NOT_FOUND);
+    assert_eq!(response["id"], json!(2));
+
+    let response = call(&router, &connection, json!({"jsonrpc": "1.0", "method": "ping", "id": 3})).await.unwrap();
+    assert_eq!(error_code(&response), JsonRpcError::INVALID_REQUEST);
+    assert_eq!(response["id"], json!(3));
+
+    let response = call(&router, &connection, json!({"jsonrpc": "2.0", "id": 4})).await.unwrap();
+    assert_eq!(error_code(&response), JsonRpcError::INVALID_REQUEST);
+
+    let response =
+        call(&router, &connection, json!({"jsonrpc": "2.0", "method": "getBlock", "params": {"hash": 7}, "id": 5})).await.unwrap();
+    assert_eq!(error_code(&response), JsonRpcError::INVALID_PARAMS);
+
+    // Errors of the RPC service
+    let response = call(&router, &connection, json!({"jsonrpc": "2.0", "method": "getBlockCount", "id": 6})).await.unwrap();
+    assert_eq!(error_code(&response), JsonRpcError::SERVER_ERROR);
+
+    let response = router.interface.call(&connection, b"{\"jsonrpc\": \"2.0\", ").await.unwrap();
+    assert_eq!(error_code(&response), JsonRpcError::PARSE_ERROR);
+    assert_eq!(response["id"], Value::Null);
+}
+
+#[tokio::test]
+async fn test_http_dispatches_every_method() {
+    let router = create_router(None, Default::default());
+    let connection = Connection::new(peer());
+
+    // Request/response methods are numbered from Ping on, every one of them must be routed
+    let methods = RpcApiOps::iter().filter(|op| u32::from(*op) >= u32::from(RpcApiOps::Ping));
+    for (id, op) in methods.enumerate() {
+        let method = serde_json::to_value(op).unwrap();
+        let response = call(&router, &connection, json!({"jsonrpc": "2.0", "method": method, "params": {}, "id": id})).await.unwrap();
+        assert_ne!(
+            response["error"]["code"].as_i64(),
+            Some(JsonRpcError::METHOD_NOT_FOUND),
+            "{op:?} is not dispatched by the JSON-RPC router, list it in with_rpc_methods!"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_http_batch_calls() {
+    let router = create_router(None, Default::default());
+    let connection = Connection::new(peer());
+
+    // Responses are returned in order, notifications get none
+    let response = call(
+        &router,
+        &connection,
+        json!([
+            {"jsonrpc": "2.0", "method": "ping", "id": 1},
+            {"jsonrpc": "2.0", "method": "ping"},
+            {"jsonrpc": "2.0", "method": "getFoo", "id": 2},
+            {"jsonrpc": "2.0", "method": "getInfo", "id": 3},
+            42,
+        ]),
+    )
+    .await
+    .unwrap();
+    let responses = response.as_array().unwrap();
+    assert_eq!(responses.len(), 4);
+    assert_eq!(responses[0]["id"], json!(1));
+    assert_eq!(responses[0]["result"], json!({}));
+    assert_eq!(responses[1]["id"], json!(2));
+    assert_eq!(error_code(&responses[1]), JsonRpcError::METHOD_NOT_FOUND);
+    assert_eq!(responses[2]["id"], json!(3));
+    assert_eq!(responses[2]["result"]["p2pId"], json!("p2p-mock"));
+    assert_eq!(error_code(&responses[3]), JsonRpcError::INVALID_REQUEST);
+
+    assert!(call(&router, &connection, json!([{"jsonrpc": "2.0", "method": "ping"}, {"jsonrpc": "2.0", "method": "ping"}]))
+        .await
+        .is_none());
+
+    let response = call(&router, &connection, json!([])).await.unwrap();
+    assert!(response.is_object());
+    assert_eq!(error_code(&response), JsonRpcError::INVALID_REQUEST);
+
+    let batch = (0..=MAX_BATCH_SIZE).map(|id| json!({"jsonrpc": "2.0", "method": "ping", "id": id})).collect::<Vec<_>>();
+    let response = call(&router, &connection, Value::Array(batch)).await.unwrap();
+    assert!(response.is_object());
+    assert_eq!(error_code(&response), JsonRpcError::INVALID_REQUEST);
+
+    let batch = (0..MAX_BATCH_SIZE).map(|id| json!({"jsonrpc": "2.0", "method": "ping", "id": id})).collect::<Vec<_>>();
+    let response = call(&router, &connection, Value::Array(batch)).await.unwrap();
+    assert_eq!(response.as_array().unwrap().len(), MAX_BATCH_SIZE);
+}
+
+#[tokio::test]
+async fn test_http_authorization() {
+    let config = RpcAuthConfig {
+        anonymous: Some(RpcRole::ReadOnly),
+        keys: vec![RpcApiKey { name: "ops".to_string(), token: "ops-token".to_string(), role: RpcRole::Admin }],
+        ..Default::default()
+    };
+    let authorizer = RpcAuthorizer::new(config);
+    let anonymous = authorizer.authenticate(None).unwrap();
+    let ops = authorizer.authenticate(Some("ops-token")).unwrap();
+    let router = create_router(Some(authorizer), Default::default());
+    let get_info = serde_json::to_vec(&json!({"jsonrpc": "2.0", "method": "getInfo", "id": 1})).unwrap();
+    let shutdown = serde_json::to_vec(&json!({"jsonrpc": "2.0", "method": "shutdown", "id": 1})).unwrap();
+
+    let connection = Connection::new(peer()).with_credentials(Some(anonymous));
+    let response = router.interface.call(&connection, &get_info).await.unwrap();
+    assert!(response.get("result").is_some());
+    let response = router.interface.call(&connection, &shutdown).await.unwrap();
+    assert_eq!(error_code(&response), JsonRpcError::UNAUTHORIZED);
+
+    let connection = connection.with_credentials(Some(ops));
+    let response = router.interface.call(&connection, &shutdown).await.unwrap();
+    assert!(response.get("result").is_some());
+
+    // Requests reaching a server with an authorizer must have been authenticated
+    let connection = connection.with_credentials(None);
+    let response = router.interface.call(&connection, &get_info).await.unwrap();
+    assert_eq!(error_code(&response), JsonRpcError::UNAUTHORIZED);
+
+    // Calls of a batch are authorized one by one
+    let connection =
+        Connection::new(peer()).with_credentials(Some(RpcAuthorizer::new(Default::default()).authenticate(None).unwrap()));
+    let batch = serde_json::to_vec(&json!([
+        {"jsonrpc": "2.0", "method": "getInfo", "id": 1},
+        {"jsonrpc": "2.0", "method": "getFoo", "id": 2},
+    ]))
+    .unwrap();
+    let response = router.interface.call(&connection, &batch).await.unwrap();
+    assert_eq!(error_code(&response[0]), JsonRpcError::UNAUTHORIZED);
+    assert_eq!(error_code(&response[1]), JsonRpcError::METHOD_NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_http_rate_limit() {
+    let router = create_router(None, RpcRateLimitConfig { connection_rate: 1, ip_rate: 0 });
+    let ping = serde_json::to_vec(&json!({"jsonrpc": "2.0", "method": "ping", "id": 1})).unwrap();
+
+    // The bucket of a client holds BURST_SECONDS worth of calls, also shared by its new connections
+    for _ in 0..calico_rpc_service::rate_limit::BURST_SECONDS {
+        let response = router.interface.call(&Connection::new(peer()), &ping).await.unwrap();
+        assert!(response.get("result").is_some());
+    }
+    let response = router.interface.call(&Connection::new(peer()), &ping).await.unwrap();
+    assert_eq!(error_code(&response), JsonRpcError::RATE_LIMITED);
+    assert!(response["error"]["data"]["retryAfterMs"].as_u64().unwrap() > 0);
+
+    // Other clients have their own bucket
+    let response = router.interface.call(&Connection::new("127.0.0.2:22110".parse().unwrap()), &ping).await.unwrap();
+    assert!(response.get("result").is_some());
+}
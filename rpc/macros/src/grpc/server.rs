@@ -68,12 +68,26 @@ impl ToTokens for RpcTable {
                     targets.push(quote! {
                         #payload_ops::#handler => {
                             let method: Method<#server_ctx_type, #connection_ctx_type, #calicod_request_type, #calicod_response_type> =
-                            Method::new(|server_ctx: #server_ctx_type, _: #connection_ctx_type, request: #calicod_request_type| {
+                            Method::new(|server_ctx: #server_ctx_type, connection: #connection_ctx_type, request: #calicod_request_type| {
                                 Box::pin(async move {
                                     let mut response: #calicod_response_type = match request.payload {
-                                        Some(Payload::#request_type(ref request)) => match request.try_into() {
+                                        Some(Payload::#request_type(ref request)) => match calico_rpc_core::#request_type::try_from(request) {
                                             // TODO: RPC-CONNECTION
-                                            Ok(request) => server_ctx.core_service.#fn_call(None,request).await.into(),
+                                            Ok(request) => {
+                                                let op = calico_rpc_core::api::ops::RpcApiOps::#handler;
+                                                let cost = calico_rpc_core::api::cost::request_cost(op, &request);
+                                                match server_ctx
+                                                    .authorize(&connection, op)
+                                                    .and_then(|_| server_ctx.admit(&connection, op, cost)) {
+                                                    Ok(()) => {
+                                                        let start = std::time::Instant::now();
+                                                        let result = server_ctx.core_service.#fn_call(None,request).await;
+                                                        server_ctx.rate_limiter.record(op, cost, start.elapsed());
+                                                        result.into()
+                                                    }
+                                                    Err(err) => #response_message_type::from(err).into(),
+                                                }
+                                            }
                                             Err(err) => #response_message_type::from(err).into(),
                                         },
                                         _ => {
@@ -96,14 +110,20 @@ impl ToTokens for RpcTable {
                                 Box::pin(async move {
                                     let mut response: #calicod_response_type = match request.payload {
                                         Some(Payload::#request_type(ref request)) => {
-                                            match calico_rpc_core::#fallback_request_type::try_from(request) {
-                                                Ok(request) => {
+                                            // Starting and stopping a subscription are both authorized as its Notify<Event> method
+                                            match calico_rpc_core::#fallback_request_type::try_from(request)
+                                                .map(|request| (request.command, calico_notify::scope::Scope::from(request)))
+                                                .and_then(|(command, scope)| {
+                                                    let op = calico_rpc_core::api::ops::RpcApiOps::subscription(scope.event_type());
+                                                    server_ctx.authorize(&connection, op).map(|_| (command, scope))
+                                                })
+                                                .and_then(|subscription| server_ctx.admit_subscription(&connection).map(|_| subscription)) {
+                                                Ok((command, scope)) => {
                                                     let listener_id = connection.get_or_register_listener_id()?;
-                                                    let command = request.command;
                                                     let result = server_ctx
                                                         .notifier
                                                         .clone()
-                                                        .execute_subscribe_command(listener_id, request.into(), command)
+                                                        .execute_subscribe_command(listener_id, scope, command)
                                                         .await;
                                                     #response_message_type::from(result).into()
                                                 }
@@ -0,0 +1,96 @@
+use crate::handler::*;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use std::convert::Into;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    Error, Expr, ExprArray, Result, Token,
+};
+
+#[derive(Debug)]
+struct RpcTable {
+    server_ctx: Expr,
+    server_ctx_type: Expr,
+    connection_ctx_type: Expr,
+    rpc_api_ops: Expr,
+    handlers: ExprArray,
+}
+
+impl Parse for RpcTable {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let parsed = Punctuated::<Expr, Token![,]>::parse_terminated(input).unwrap();
+        if parsed.len() != 5 {
+            return Err(Error::new_spanned(
+                parsed,
+                "usage: build_http_server_interface!(server_instance,ServerType,ConnectionType,RpcApiOps,[GetInfo, ..])".to_string(),
+            ));
+        }
+
+        let mut iter = parsed.iter();
+        let server_ctx = iter.next().unwrap().clone();
+        let server_ctx_type = iter.next().unwrap().clone();
+        let connection_ctx_type = iter.next().unwrap().clone();
+        let rpc_api_ops = iter.next().unwrap().clone();
+        let handlers = get_handlers(iter.next().unwrap().clone())?;
+
+        Ok(RpcTable { server_ctx, server_ctx_type, connection_ctx_type, rpc_api_ops, handlers })
+    }
+}
+
+impl ToTokens for RpcTable {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut targets = Vec::new();
+        let server_ctx = &self.server_ctx;
+        let server_ctx_type = &self.server_ctx_type;
+        let connection_ctx_type = &self.connection_ctx_type;
+        let rpc_api_ops = &self.rpc_api_ops;
+
+        for handler in self.handlers.elems.iter() {
+            let Handler { fn_call, request_type, .. } = Handler::new(handler);
+
+            targets.push(quote! {
+                #rpc_api_ops::#handler => {
+                    interface.method(#rpc_api_ops::#handler, |server_ctx: #server_ctx_type, connection: #connection_ctx_type, params: serde_json::Value| {
+                        Box::pin(async move {
+                            // Methods without arguments may be called with omitted params
+                            let params = if params.is_null() { serde_json::Value::Object(Default::default()) } else { params };
+                            let request: #request_type = serde_json::from_value(params).map_err(JsonRpcError::invalid_params)?;
+                            server_ctx.authorize(&connection, #rpc_api_ops::#handler)?;
+                            let cost = calico_rpc_core::api::cost::request_cost(#rpc_api_ops::#handler, &request);
+                            server_ctx.admit(&connection, #rpc_api_ops::#handler, cost)?;
+                            let start = std::time::Instant::now();
+                            let result = server_ctx.rpc_service().#fn_call(None, request).await;
+                            server_ctx.record(#rpc_api_ops::#handler, cost, start.elapsed());
+                            serde_json::to_value(result?).map_err(JsonRpcError::internal_error)
+                        })
+                    });
+                }
+            });
+        }
+
+        quote! {
+            {
+                let mut interface = Interface::new(#server_ctx);
+
+                for op in #rpc_api_ops::iter() {
+                    match op {
+                        #(#targets)*
+                        _ => { }
+                    }
+                }
+
+                interface
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub fn build_http_server_interface(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let rpc_table = parse_macro_input!(input as RpcTable);
+    let ts = rpc_table.to_token_stream();
+    // println!("MACRO: {}", ts.to_string());
+    ts.into()
+}
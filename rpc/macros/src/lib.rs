@@ -2,6 +2,7 @@ use proc_macro::TokenStream;
 use proc_macro_error::proc_macro_error;
 mod grpc;
 mod handler;
+mod http;
 mod wrpc;
 
 #[proc_macro]
@@ -40,6 +41,12 @@ pub fn build_grpc_server_interface(input: TokenStream) -> TokenStream {
     grpc::server::build_grpc_server_interface(input)
 }
 
+#[proc_macro]
+#[proc_macro_error]
+pub fn build_http_server_interface(input: TokenStream) -> TokenStream {
+    http::server::build_http_server_interface(input)
+}
+
 #[proc_macro]
 #[proc_macro_error]
 pub fn test_wrpc_serializer(input: TokenStream) -> TokenStream {
@@ -53,9 +53,15 @@ impl ToTokens for RpcTable {
                     interface.method(#rpc_api_ops::#handler, method!(|server_ctx: #server_ctx_type, connection_ctx: #connection_ctx_type, request: Serializable<#request_type>| async move {
                         let verbose = server_ctx.verbose();
                         if verbose { workflow_log::log_info!("request: {:?}",request); }
+                        server_ctx.authorize(&connection_ctx, #rpc_api_ops::#handler).map_err(|e|ServerError::Text(e.to_string()))?;
+                        let request = request.into_inner();
+                        let cost = calico_rpc_core::api::cost::request_cost(#rpc_api_ops::#handler, &request);
+                        server_ctx.admit(&connection_ctx, #rpc_api_ops::#handler, cost).map_err(|e|ServerError::Text(e.to_string()))?;
                         // TODO: RPC-CONNECT
-                        let response: #response_type = server_ctx.rpc_service(&connection_ctx).#fn_call(None, request.into_inner()).await
-                            .map_err(|e|ServerError::Text(e.to_string()))?;
+                        let start = std::time::Instant::now();
+                        let result = server_ctx.rpc_service(&connection_ctx).#fn_call(None, request).await;
+                        server_ctx.record(#rpc_api_ops::#handler, cost, start.elapsed());
+                        let response: #response_type = result.map_err(|e|ServerError::Text(e.to_string()))?;
                         if verbose { workflow_log::log_info!("response: {:?}",response); }
                         Ok(Serializable(response))
                     }));
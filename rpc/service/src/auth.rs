@@ -0,0 +1,224 @@
+//!
+//! Role-based authorization of RPC method calls.
+//!
+//! RPC clients authenticate with an API key (token) when opening a connection
+//! (gRPC: `authorization: Bearer <token>` request metadata, wRPC: the first
+//! WebSocket text message, which is empty for anonymous clients). Every key
+//! grants a [`RpcRole`] and every RPC method requires a minimal role, which
+//! can be overridden per method in the authorization config file.
+//!
+//! Methods are named after their [`RpcApiOps`] variant. Notification subscriptions
+//! are authorized as their `Notify<Event>` method (such as `NotifyBlockAdded`) for
+//! starting and stopping them alike, over gRPC and wRPC.
+//!
+//! Note that methods disabled in safe mode still require the node to run with
+//! `--unsaferpc`. Roles restrict which clients may call them.
+//!
+
+use calico_core::warn;
+use calico_rpc_core::{api::ops::RpcApiOps, RpcError, RpcResult};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+/// RPC client role. Roles are ordered, a role includes
+/// permissions of all lower roles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RpcRole {
+    /// Queries and notification subscriptions
+    ReadOnly,
+    /// Block and transaction submission, block templates
+    Submit,
+    /// Node administration (shutdown, peer management, database checkpoints)
+    Admin,
+}
+
+impl RpcRole {
+    /// Default role required to call `method`
+    pub fn default_for_method(method: RpcApiOps) -> Self {
+        match method {
+            RpcApiOps::Shutdown
+            | RpcApiOps::AddPeer
+            | RpcApiOps::Ban
+            | RpcApiOps::Unban
+            | RpcApiOps::ResolveFinalityConflict
+            | RpcApiOps::CreateDatabaseCheckpoint => RpcRole::Admin,
+            RpcApiOps::SubmitBlock
+            | RpcApiOps::SubmitTransaction
+            | RpcApiOps::SubmitTransactionReplacement
+            | RpcApiOps::GetBlockTemplate => RpcRole::Submit,
+            _ => RpcRole::ReadOnly,
+        }
+    }
+}
+
+impl Display for RpcRole {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let role = match self {
+            RpcRole::ReadOnly => "read-only",
+            RpcRole::Submit => "submit",
+            RpcRole::Admin => "admin",
+        };
+        f.write_str(role)
+    }
+}
+
+/// API key granting a role to the RPC clients presenting it
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RpcApiKey {
+    pub name: String,
+    pub token: String,
+    pub role: RpcRole,
+}
+
+/// RPC authorization config file
+///
+/// ```toml
+/// # role of clients not presenting an API key (omit to deny all calls)
+/// anonymous = "read-only"
+///
+/// [[keys]]
+/// name = "pool"
+/// token = "<secret>"
+/// role = "submit"
+///
+/// # per-method required role overrides
+/// [methods]
+/// GetConnectedPeerInfo = "admin"
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RpcAuthConfig {
+    pub anonymous: Option<RpcRole>,
+    pub keys: Vec<RpcApiKey>,
+    pub methods: HashMap<String, RpcRole>,
+}
+
+/// Identity and role of an authenticated RPC client
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcCredentials {
+    /// API key name (`None` for anonymous clients)
+    pub name: Option<String>,
+    /// Granted role (`None` if the client is not allowed to call any method)
+    pub role: Option<RpcRole>,
+}
+
+impl Display for RpcCredentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.name.as_ref() {
+            Some(name) => write!(f, "key '{name}'"),
+            None => f.write_str("anonymous client"),
+        }
+    }
+}
+
+/// Authenticates RPC clients and authorizes their method calls
+#[derive(Debug)]
+pub struct RpcAuthorizer {
+    keys: HashMap<String, RpcCredentials>,
+    methods: HashMap<RpcApiOps, RpcRole>,
+    anonymous: RpcCredentials,
+}
+
+impl RpcAuthorizer {
+    pub fn new(config: RpcAuthConfig) -> Self {
+        let keys =
+            config.keys.into_iter().map(|key| (key.token, RpcCredentials { name: Some(key.name), role: Some(key.role) })).collect();
+        let methods = config
+            .methods
+            .into_iter()
+            .filter_map(|(name, role)| match RpcApiOps::iter().find(|op| format!("{op:?}") == name) {
+                Some(op) => Some((op, role)),
+                None => {
+                    warn!("RPC authorization config: ignoring the role override of unknown method {}", name);
+                    None
+                }
+            })
+            .collect();
+        Self { keys, methods, anonymous: RpcCredentials { name: None, role: config.anonymous } }
+    }
+
+    /// Authenticates a client presenting the API key `token` (`None` or an empty token for anonymous clients)
+    pub fn authenticate(&self, token: Option<&str>) -> RpcResult<RpcCredentials> {
+        match token.filter(|token| !token.is_empty()) {
+            Some(token) => self.keys.get(token).cloned().ok_or(RpcError::AuthenticationFailed),
+            None => Ok(self.anonymous.clone()),
+        }
+    }
+
+    /// Role required to call `method`
+    pub fn required_role(&self, method: RpcApiOps) -> RpcRole {
+        self.methods.get(&method).copied().unwrap_or_else(|| RpcRole::default_for_method(method))
+    }
+
+    /// Checks that `credentials` allow calling `method`. Rejected calls are logged.
+    pub fn authorize(&self, credentials: &RpcCredentials, peer: impl Display, method: RpcApiOps) -> RpcResult<()> {
+        let required = self.required_role(method);
+        if credentials.role.is_some_and(|role| role >= required) {
+            Ok(())
+        } else {
+            warn!("RPC authorization denied: {} at {} called {:?} requiring the {} role", credentials, peer, method, required);
+            Err(RpcError::AuthorizationDenied(format!("{method:?}"), required.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calico_notify::events::EventType;
+
+    #[test]
+    fn test_rpc_authorizer() {
+        let config: RpcAuthConfig = toml::from_str(
+            r#"
+            anonymous = "read-only"
+
+            [[keys]]
+            name = "pool"
+            token = "pool-token"
+            role = "submit"
+
+            [[keys]]
+            name = "ops"
+            token = "ops-token"
+            role = "admin"
+
+            [methods]
+            GetConnectedPeerInfo = "admin"
+            NotifyVirtualDaaScoreChanged = "submit"
+            GetUnknown = "admin"
+            "#,
+        )
+        .unwrap();
+        let authorizer = RpcAuthorizer::new(config);
+
+        let anonymous = authorizer.authenticate(None).unwrap();
+        assert_eq!(anonymous, authorizer.authenticate(Some("")).unwrap());
+        let pool = authorizer.authenticate(Some("pool-token")).unwrap();
+        let ops = authorizer.authenticate(Some("ops-token")).unwrap();
+        assert!(matches!(authorizer.authenticate(Some("unknown")), Err(RpcError::AuthenticationFailed)));
+
+        let peer = "127.0.0.1:22110";
+        assert!(authorizer.authorize(&anonymous, peer, RpcApiOps::GetInfo).is_ok());
+        assert!(authorizer.authorize(&anonymous, peer, RpcApiOps::SubmitTransaction).is_err());
+        assert!(authorizer.authorize(&pool, peer, RpcApiOps::SubmitTransaction).is_ok());
+        assert!(authorizer.authorize(&pool, peer, RpcApiOps::Shutdown).is_err());
+        assert!(authorizer.authorize(&pool, peer, RpcApiOps::GetConnectedPeerInfo).is_err());
+        assert!(authorizer.authorize(&ops, peer, RpcApiOps::GetConnectedPeerInfo).is_ok());
+        assert!(authorizer.authorize(&ops, peer, RpcApiOps::Shutdown).is_ok());
+        let daa_score_subscription = RpcApiOps::subscription(EventType::VirtualDaaScoreChanged);
+        assert!(authorizer.authorize(&anonymous, peer, daa_score_subscription).is_err());
+        assert!(authorizer.authorize(&pool, peer, daa_score_subscription).is_ok());
+        assert!(authorizer.authorize(&anonymous, peer, RpcApiOps::subscription(EventType::BlockAdded)).is_ok());
+
+        // no anonymous access unless configured
+        let authorizer = RpcAuthorizer::new(RpcAuthConfig::default());
+        let anonymous = authorizer.authenticate(None).unwrap();
+        assert!(matches!(authorizer.authorize(&anonymous, peer, RpcApiOps::GetInfo), Err(RpcError::AuthorizationDenied(..))));
+    }
+}
@@ -0,0 +1,198 @@
+//!
+//! Online checkpoints of the node databases.
+//!
+//! [`DatabaseCheckpointService`] creates RocksDB checkpoints of the active consensus
+//! database and of the meta database while the node is running, either on request
+//! of the `CreateDatabaseCheckpoint` RPC method or on SIGUSR1. The checkpoint
+//! reproduces the layout of the data directory, so it can be restored at startup
+//! with `--restore-from`. Progress is reported by `DatabaseCheckpoint` notifications.
+//!
+
+use calico_consensus_notify::{
+    notification::{DatabaseCheckpointNotification, DatabaseCheckpointState, Notification},
+    root::ConsensusNotificationRoot,
+};
+use calico_consensusmanager::{spawn_blocking, ConsensusManager};
+use calico_core::{
+    error, info,
+    task::service::{AsyncService, AsyncServiceFuture},
+    time::unix_now,
+    trace, warn,
+};
+use calico_notify::notifier::Notify;
+use calico_utils::triggers::SingleTrigger;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc,
+    },
+};
+use thiserror::Error;
+
+/// Number of databases included in a checkpoint: the active consensus and the meta databases
+const CHECKPOINT_DATABASES: u32 = 2;
+
+#[derive(Debug, Error)]
+pub enum CheckpointError {
+    #[error("a database checkpoint is already in progress")]
+    AlreadyRunning,
+
+    #[error("the checkpoint directory {} already exists", .0.display())]
+    DirectoryExists(PathBuf),
+}
+
+pub type CheckpointResult<T> = std::result::Result<T, CheckpointError>;
+
+pub struct DatabaseCheckpointService {
+    consensus_manager: Arc<ConsensusManager>,
+    notification_root: Arc<ConsensusNotificationRoot>,
+    /// Directory under which the checkpoints without an explicit target directory are created
+    backup_dir: PathBuf,
+    running: Arc<AtomicBool>,
+    shutdown: SingleTrigger,
+}
+
+impl DatabaseCheckpointService {
+    pub const IDENT: &'static str = "database-checkpoint-service";
+
+    pub fn new(
+        consensus_manager: Arc<ConsensusManager>,
+        notification_root: Arc<ConsensusNotificationRoot>,
+        backup_dir: PathBuf,
+    ) -> Self {
+        Self { consensus_manager, notification_root, backup_dir, running: Default::default(), shutdown: Default::default() }
+    }
+
+    /// Starts creating a checkpoint in `directory`, which must not exist, or in a timestamped directory
+    /// under the backup directory if `None`. Returns the directory of the checkpoint once started.
+    pub fn start_checkpoint(&self, directory: Option<PathBuf>) -> CheckpointResult<PathBuf> {
+        let directory = directory.unwrap_or_else(|| self.backup_dir.join(format!("checkpoint-{}", unix_now())));
+        if directory.exists() {
+            return Err(CheckpointError::DirectoryExists(directory));
+        }
+        if self.running.swap(true, Ordering::SeqCst) {
+            return Err(CheckpointError::AlreadyRunning);
+        }
+
+        info!("Creating a database checkpoint in {}", directory.display());
+        let job = CheckpointJob {
+            consensus_manager: self.consensus_manager.clone(),
+            notification_root: self.notification_root.clone(),
+            directory: directory.clone(),
+            running: self.running.clone(),
+        };
+        spawn_blocking(move || job.run());
+        Ok(directory)
+    }
+}
+
+struct CheckpointJob {
+    consensus_manager: Arc<ConsensusManager>,
+    notification_root: Arc<ConsensusNotificationRoot>,
+    directory: PathBuf,
+    running: Arc<AtomicBool>,
+}
+
+impl CheckpointJob {
+    fn run(self) {
+        self.notify(self.notification(DatabaseCheckpointState::Started, 0));
+
+        let completed = AtomicU32::new(0);
+        let on_checkpointed = |database: &str| {
+            let completed = completed.fetch_add(1, Ordering::SeqCst) + 1;
+            info!("Database checkpoint: {} written ({}/{})", database, completed, CHECKPOINT_DATABASES);
+            self.notify(self.notification(DatabaseCheckpointState::InProgress, completed));
+        };
+        let result = fs::create_dir_all(&self.directory)
+            .map_err(|err| err.to_string())
+            .and_then(|_| self.consensus_manager.create_checkpoint(&self.directory, &on_checkpointed).map_err(|err| err.to_string()));
+
+        match result {
+            Ok(checkpoint) => {
+                info!(
+                    "Database checkpoint completed in {} (sink {}, virtual DAA score {})",
+                    self.directory.display(),
+                    checkpoint.sink,
+                    checkpoint.virtual_daa_score
+                );
+                let mut notification = self.notification(DatabaseCheckpointState::Completed, CHECKPOINT_DATABASES);
+                notification.sink = Some(checkpoint.sink);
+                notification.virtual_daa_score = Some(checkpoint.virtual_daa_score);
+                self.notify(notification);
+            }
+            Err(err) => {
+                error!("Database checkpoint in {} failed: {}", self.directory.display(), err);
+                remove_partial_checkpoint(&self.directory);
+                let mut notification = self.notification(DatabaseCheckpointState::Failed, completed.load(Ordering::SeqCst));
+                notification.error = Some(err);
+                self.notify(notification);
+            }
+        }
+        self.running.store(false, Ordering::SeqCst);
+    }
+
+    fn notification(&self, state: DatabaseCheckpointState, completed_databases: u32) -> DatabaseCheckpointNotification {
+        DatabaseCheckpointNotification::new(self.directory.display().to_string(), state, completed_databases, CHECKPOINT_DATABASES)
+    }
+
+    fn notify(&self, notification: DatabaseCheckpointNotification) {
+        let _ = self.notification_root.notify(Notification::DatabaseCheckpoint(notification));
+    }
+}
+
+fn remove_partial_checkpoint(directory: &Path) {
+    if directory.exists() {
+        if let Err(err) = fs::remove_dir_all(directory) {
+            warn!("Unable to remove the partial database checkpoint {}: {}", directory.display(), err);
+        }
+    }
+}
+
+// service trait implementation creating a checkpoint on SIGUSR1
+impl AsyncService for DatabaseCheckpointService {
+    fn ident(self: Arc<Self>) -> &'static str {
+        Self::IDENT
+    }
+
+    fn start(self: Arc<Self>) -> AsyncServiceFuture {
+        trace!("{} starting", Self::IDENT);
+        let shutdown_signal = self.shutdown.listener.clone();
+        Box::pin(async move {
+            #[cfg(unix)]
+            {
+                use calico_core::task::service::AsyncServiceError;
+                use tokio::signal::unix::{signal, SignalKind};
+                let mut user_defined1 =
+                    signal(SignalKind::user_defined1()).map_err(|err| AsyncServiceError::Service(err.to_string()))?;
+                tokio::pin!(shutdown_signal);
+                loop {
+                    tokio::select! {
+                        _ = user_defined1.recv() => {
+                            if let Err(err) = self.start_checkpoint(None) {
+                                warn!("Database checkpoint requested by SIGUSR1 was not started: {}", err);
+                            }
+                        }
+                        _ = &mut shutdown_signal => break,
+                    }
+                }
+            }
+            #[cfg(not(unix))]
+            shutdown_signal.await;
+            Ok(())
+        })
+    }
+
+    fn signal_exit(self: Arc<Self>) {
+        trace!("sending an exit signal to {}", Self::IDENT);
+        self.shutdown.trigger.trigger();
+    }
+
+    fn stop(self: Arc<Self>) -> AsyncServiceFuture {
+        Box::pin(async move {
+            trace!("{} stopped", Self::IDENT);
+            Ok(())
+        })
+    }
+}
@@ -3,6 +3,7 @@ use calico_addresses::Address;
 use calico_consensus_core::{
     block::Block,
     config::Config,
+    errors::consensus::{ConsensusError, ConsensusResult},
     hashing::tx::hash,
     header::Header,
     tx::{MutableTransaction, Transaction, TransactionId, TransactionInput, TransactionOutput},
@@ -14,9 +15,9 @@ use calico_math::Uint256;
 use calico_mining::model::{owner_txs::OwnerTransactions, TransactionIdSet};
 use calico_notify::converter::Converter;
 use calico_rpc_core::{
-    BlockAddedNotification, Notification, RpcAcceptedTransactionIds, RpcBlock, RpcBlockVerboseData, RpcHash, RpcMempoolEntry,
-    RpcMempoolEntryByAddress, RpcResult, RpcTransaction, RpcTransactionInput, RpcTransactionOutput, RpcTransactionOutputVerboseData,
-    RpcTransactionVerboseData,
+    BlockAddedNotification, Notification, RpcAcceptedTransactionIds, RpcBlock, RpcBlockVerboseData, RpcDagBlock, RpcHash,
+    RpcMempoolEntry, RpcMempoolEntryByAddress, RpcResult, RpcTransaction, RpcTransactionInput, RpcTransactionOutput,
+    RpcTransactionOutputVerboseData, RpcTransactionVerboseData,
 };
 use calico_txscript::{extract_script_pub_key_address, script_class::ScriptClass};
 use std::{collections::HashMap, fmt::Debug, sync::Arc};
@@ -84,6 +85,34 @@ impl ConsensusConverter {
         Ok(RpcBlock { header: block.header.as_ref().into(), transactions, verbose_data })
     }
 
+    /// Converts the blocks of a sub-DAG into [`RpcDagBlock`]s carrying their GHOSTDAG data.
+    /// All the blocks are queried in a single blocking task.
+    pub async fn get_dag_blocks(&self, consensus: &ConsensusProxy, hashes: Vec<RpcHash>) -> RpcResult<Vec<RpcDagBlock>> {
+        let blocks = consensus
+            .clone()
+            .spawn_blocking(move |c| {
+                hashes
+                    .into_iter()
+                    .map(|hash| {
+                        let ghostdag_data = c.get_ghostdag_data(hash)?;
+                        Ok(RpcDagBlock {
+                            hash,
+                            parents: c.get_block_parents(hash).ok_or(ConsensusError::MissingData(hash))?.to_vec(),
+                            selected_parent: ghostdag_data.selected_parent,
+                            blue_score: ghostdag_data.blue_score,
+                            blue_work: ghostdag_data.blue_work,
+                            daa_score: c.get_header(hash)?.daa_score,
+                            mergeset_blues: ghostdag_data.mergeset_blues,
+                            mergeset_reds: ghostdag_data.mergeset_reds,
+                            is_chain_block: c.is_chain_block(hash)?,
+                        })
+                    })
+                    .collect::<ConsensusResult<Vec<_>>>()
+            })
+            .await?;
+        Ok(blocks)
+    }
+
     pub fn get_mempool_entry(&self, consensus: &ConsensusProxy, transaction: &MutableTransaction) -> RpcMempoolEntry {
         let is_orphan = !transaction.is_fully_populated();
         let rpc_transaction = self.get_transaction(consensus, &transaction.tx, None, true);
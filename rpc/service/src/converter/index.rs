@@ -21,6 +21,7 @@ impl IndexConverter {
         UtxosChangedNotification {
             added: Arc::new(self.get_utxos_by_addresses_entries(&utxo_changed.added)),
             removed: Arc::new(self.get_utxos_by_addresses_entries(&utxo_changed.removed)),
+            cursor: None,
         }
     }
 
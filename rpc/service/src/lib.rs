@@ -1,3 +1,8 @@
+pub mod auth;
+pub mod checkpoint;
 pub mod collector;
 pub mod converter;
+pub mod metrics;
+pub mod rate_limit;
 pub mod service;
+pub mod tls;
@@ -0,0 +1,133 @@
+//!
+//! Encoder of node metrics in the OpenMetrics text format, along with the
+//! connection counters of the RPC servers which only the metrics report.
+//!
+//! Metric names are part of the public interface of the node: renaming a metric
+//! breaks the dashboards and alerts of operators scraping it.
+//!
+
+use std::{
+    fmt::{Display, Write},
+    sync::atomic::AtomicUsize,
+};
+
+/// Content type of an OpenMetrics text exposition
+pub const OPEN_METRICS_CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Prefix of all the metric names exported by the node
+const PREFIX: &str = "calico";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricType {
+    Counter,
+    Gauge,
+}
+
+impl MetricType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MetricType::Counter => "counter",
+            MetricType::Gauge => "gauge",
+        }
+    }
+}
+
+/// Connection counters of an RPC server
+#[derive(Default)]
+pub struct RpcConnectionCounters {
+    /// Number of live connections
+    pub active_connections: AtomicUsize,
+    /// Number of connections accepted since startup
+    pub total_connections: AtomicUsize,
+    /// Number of connections refused since the server was at capacity
+    pub rejected_connections: AtomicUsize,
+}
+
+/// Writes metric families to an OpenMetrics text exposition
+#[derive(Default)]
+pub struct OpenMetricsEncoder {
+    buffer: String,
+}
+
+impl OpenMetricsEncoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writes a counter metric family with a single sample
+    pub fn counter(&mut self, name: &str, help: &str, value: impl Display) {
+        self.family(name, MetricType::Counter, help, [(&[][..], value)]);
+    }
+
+    /// Writes a gauge metric family with a single sample
+    pub fn gauge(&mut self, name: &str, help: &str, value: impl Display) {
+        self.family(name, MetricType::Gauge, help, [(&[][..], value)]);
+    }
+
+    /// Writes a metric family with samples distinguished by their labels
+    pub fn family<'a, V: Display>(
+        &mut self,
+        name: &str,
+        metric_type: MetricType,
+        help: &str,
+        samples: impl IntoIterator<Item = (&'a [(&'a str, &'a str)], V)>,
+    ) {
+        let name = format!("{PREFIX}_{name}");
+        let suffix = match metric_type {
+            MetricType::Counter => "_total",
+            MetricType::Gauge => "",
+        };
+        let _ = writeln!(self.buffer, "# TYPE {name} {}", metric_type.as_str());
+        let _ = writeln!(self.buffer, "# HELP {name} {help}");
+        for (labels, value) in samples {
+            let _ = write!(self.buffer, "{name}{suffix}");
+            if !labels.is_empty() {
+                let labels = labels.iter().map(|(key, value)| format!("{key}=\"{}\"", escape(value))).collect::<Vec<_>>();
+                let _ = write!(self.buffer, "{{{}}}", labels.join(","));
+            }
+            let _ = writeln!(self.buffer, " {value}");
+        }
+    }
+
+    /// Terminates the exposition
+    pub fn finish(mut self) -> String {
+        self.buffer.push_str("# EOF\n");
+        self.buffer
+    }
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_metrics_encoding() {
+        let mut encoder = OpenMetricsEncoder::new();
+        encoder.counter("blocks_submitted", "Number of blocks submitted", 12);
+        encoder.gauge("mempool_transactions", "Number of transactions in the mempool", 3);
+        encoder.family(
+            "rpc_method_calls",
+            MetricType::Counter,
+            "Number of RPC calls",
+            [(&[("method", "GetInfo")][..], 5), (&[("method", "Get\"Odd\"")][..], 1)],
+        );
+        let expected = "\
+# TYPE calico_blocks_submitted counter
+# HELP calico_blocks_submitted Number of blocks submitted
+calico_blocks_submitted_total 12
+# TYPE calico_mempool_transactions gauge
+# HELP calico_mempool_transactions Number of transactions in the mempool
+calico_mempool_transactions 3
+# TYPE calico_rpc_method_calls counter
+# HELP calico_rpc_method_calls Number of RPC calls
+calico_rpc_method_calls_total{method=\"GetInfo\"} 5
+calico_rpc_method_calls_total{method=\"Get\\\"Odd\\\"\"} 1
+# EOF
+";
+        assert_eq!(encoder.finish(), expected);
+    }
+}
@@ -0,0 +1,311 @@
+//!
+//! Rate limiting and cost accounting of RPC method calls.
+//!
+//! Every call is charged its cost (see [`calico_rpc_core::api::cost`]) against a
+//! token bucket of the client and a token bucket shared by all clients of the
+//! same IP address. Both buckets refill continuously at their configured rate and
+//! hold up to [`BURST_SECONDS`] of refill. A call costing more than a full bucket
+//! is admitted when the bucket is full and leaves it in debt, so that expensive
+//! methods remain callable under low limits.
+//!
+//! gRPC and wRPC clients are charged per connection. HTTP requests are stateless,
+//! so they are charged to the authenticated API key or, for anonymous requests,
+//! to the peer IP address. Keyed buckets outlive the connections using them until
+//! they are refilled completely, so that reconnecting does not reset a limit.
+//!
+//! Limits are enforced per RPC message rather than by a tower layer reusing
+//! `calico-utils-tower` (which only provides byte counting middleware): gRPC
+//! clients multiplex all their calls over a single bidirectional stream and wRPC
+//! calls travel over a WebSocket, so HTTP middleware never sees individual methods.
+//!
+
+use calico_core::warn;
+use calico_rpc_core::{api::ops::RpcApiOps, RpcError, RpcMethodMetrics, RpcResult};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    hash::Hash,
+    net::IpAddr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Number of seconds of refill a token bucket can accumulate
+pub const BURST_SECONDS: u64 = 10;
+
+/// RPC rate limits, in cost units per second (`0` disables the limit)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RpcRateLimitConfig {
+    /// Limit of every single connection (of every API key or IP address for HTTP requests)
+    pub connection_rate: u64,
+    /// Limit shared by all connections from the same IP address
+    pub ip_rate: u64,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    rate: f64,
+    capacity: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: u64) -> Self {
+        let capacity = (rate * BURST_SECONDS) as f64;
+        Self { rate: rate as f64, capacity, tokens: capacity, updated: Instant::now() }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.updated = now;
+    }
+
+    /// Time to wait before a call costing `cost` can be admitted
+    fn wait_time(&self, cost: u64) -> Duration {
+        let missing = (cost as f64).min(self.capacity) - self.tokens;
+        if missing > 0.0 {
+            Duration::from_secs_f64(missing / self.rate)
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    fn consume(&mut self, cost: u64) {
+        self.tokens -= cost as f64;
+    }
+
+    /// Whether the bucket is refilled completely at `now`, and thus equivalent to a new one
+    fn is_full(&self, now: Instant) -> bool {
+        self.tokens + now.saturating_duration_since(self.updated).as_secs_f64() * self.rate >= self.capacity
+    }
+}
+
+/// Token buckets shared by the calls of a same client, retained until they are refilled completely
+#[derive(Debug)]
+struct TokenBuckets<K> {
+    buckets: HashMap<K, Arc<Mutex<TokenBucket>>>,
+    pruned: Instant,
+}
+
+impl<K: Hash + Eq> Default for TokenBuckets<K> {
+    fn default() -> Self {
+        Self { buckets: Default::default(), pruned: Instant::now() }
+    }
+}
+
+impl<K: Hash + Eq> TokenBuckets<K> {
+    fn get(&mut self, key: K, rate: u64) -> Arc<Mutex<TokenBucket>> {
+        let now = Instant::now();
+        if now.saturating_duration_since(self.pruned) >= Duration::from_secs(BURST_SECONDS) {
+            self.buckets.retain(|_, bucket| Arc::strong_count(bucket) > 1 || !bucket.lock().unwrap().is_full(now));
+            self.pruned = now;
+        }
+        self.buckets.entry(key).or_insert_with(|| Arc::new(Mutex::new(TokenBucket::new(rate)))).clone()
+    }
+}
+
+/// Rate limiter of a single RPC connection or HTTP client
+#[derive(Debug, Default)]
+pub struct RpcConnectionLimiter {
+    connection: Option<Arc<Mutex<TokenBucket>>>,
+    ip: Option<Arc<Mutex<TokenBucket>>>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct MethodCounters {
+    calls: u64,
+    rate_limited: u64,
+    total_cost: u64,
+    total_latency: Duration,
+    max_latency: Duration,
+}
+
+/// Key of the bucket shared by the calls of a stateless client
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum RpcClientKey {
+    Ip(IpAddr),
+    ApiKey(String),
+}
+
+/// Rate limiter shared by the RPC servers, also collecting per-method call metrics
+#[derive(Debug, Default)]
+pub struct RpcRateLimiter {
+    config: RpcRateLimitConfig,
+    ips: Mutex<TokenBuckets<IpAddr>>,
+    clients: Mutex<TokenBuckets<RpcClientKey>>,
+    methods: Mutex<HashMap<RpcApiOps, MethodCounters>>,
+}
+
+impl RpcRateLimiter {
+    pub fn new(config: RpcRateLimitConfig) -> Self {
+        Self { config, ..Default::default() }
+    }
+
+    pub fn config(&self) -> RpcRateLimitConfig {
+        self.config
+    }
+
+    /// Creates the limiter of a new connection from `ip`
+    pub fn connection(&self, ip: IpAddr) -> RpcConnectionLimiter {
+        let connection =
+            (self.config.connection_rate > 0).then(|| Arc::new(Mutex::new(TokenBucket::new(self.config.connection_rate))));
+        RpcConnectionLimiter { connection, ip: self.ip_bucket(ip) }
+    }
+
+    /// Gets the limiter of a stateless client from `ip`, authenticated by the API key `name` if any.
+    /// Calls of the same API key, or of the same IP address for anonymous clients, share a bucket.
+    pub fn client(&self, ip: IpAddr, name: Option<&str>) -> RpcConnectionLimiter {
+        let connection = (self.config.connection_rate > 0).then(|| {
+            let key = name.map_or(RpcClientKey::Ip(ip), |name| RpcClientKey::ApiKey(name.to_string()));
+            self.clients.lock().unwrap().get(key, self.config.connection_rate)
+        });
+        RpcConnectionLimiter { connection, ip: self.ip_bucket(ip) }
+    }
+
+    fn ip_bucket(&self, ip: IpAddr) -> Option<Arc<Mutex<TokenBucket>>> {
+        (self.config.ip_rate > 0).then(|| self.ips.lock().unwrap().get(ip, self.config.ip_rate))
+    }
+
+    /// Charges a call to `method` costing `cost` to the connection of `peer`.
+    /// Rejected calls are logged and counted.
+    pub fn admit(&self, limiter: &RpcConnectionLimiter, peer: impl Display, method: RpcApiOps, cost: u64) -> RpcResult<()> {
+        let now = Instant::now();
+        let mut connection = limiter.connection.as_ref().map(|bucket| bucket.lock().unwrap());
+        let mut ip = limiter.ip.as_ref().map(|bucket| bucket.lock().unwrap());
+        let mut wait_time = Duration::ZERO;
+        for bucket in connection.iter_mut().chain(ip.iter_mut()) {
+            bucket.refill(now);
+            wait_time = wait_time.max(bucket.wait_time(cost));
+        }
+        if wait_time > Duration::ZERO {
+            self.methods.lock().unwrap().entry(method).or_default().rate_limited += 1;
+            warn!(
+                "RPC rate limit exceeded: {} called {:?} costing {} units, retry in {} ms",
+                peer,
+                method,
+                cost,
+                wait_time.as_millis()
+            );
+            return Err(RpcError::RateLimitExceeded(format!("{method:?}"), cost, wait_time.as_millis() as u64));
+        }
+        for bucket in connection.iter_mut().chain(ip.iter_mut()) {
+            bucket.consume(cost);
+        }
+        Ok(())
+    }
+
+    /// Records a completed call to `method`
+    pub fn record(&self, method: RpcApiOps, cost: u64, latency: Duration) {
+        let mut methods = self.methods.lock().unwrap();
+        let counters = methods.entry(method).or_default();
+        counters.calls += 1;
+        counters.total_cost += cost;
+        counters.total_latency += latency;
+        counters.max_latency = counters.max_latency.max(latency);
+    }
+
+    /// Per-method call metrics, sorted by method name
+    pub fn metrics(&self) -> Vec<RpcMethodMetrics> {
+        let mut metrics = self
+            .methods
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(method, counters)| RpcMethodMetrics {
+                method: format!("{method:?}"),
+                calls: counters.calls,
+                rate_limited: counters.rate_limited,
+                total_cost: counters.total_cost,
+                total_latency_micros: counters.total_latency.as_micros() as u64,
+                max_latency_micros: counters.max_latency.as_micros() as u64,
+            })
+            .collect::<Vec<_>>();
+        metrics.sort_by(|a, b| a.method.cmp(&b.method));
+        metrics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rpc_rate_limiter() {
+        let limiter = RpcRateLimiter::new(RpcRateLimitConfig { connection_rate: 10, ip_rate: 15 });
+        let ip: IpAddr = "127.0.0.1".parse().unwrap();
+        let peer = "127.0.0.1:22110";
+
+        // buckets hold 100 units per connection and 150 per IP
+        let first = limiter.connection(ip);
+        assert!(limiter.admit(&first, peer, RpcApiOps::GetBlocks, 90).is_ok());
+        assert!(matches!(limiter.admit(&first, peer, RpcApiOps::GetBlocks, 20), Err(RpcError::RateLimitExceeded(..))));
+
+        // the IP bucket is shared across connections
+        let second = limiter.connection(ip);
+        assert!(limiter.admit(&second, peer, RpcApiOps::GetInfo, 50).is_ok());
+        assert!(matches!(limiter.admit(&second, peer, RpcApiOps::GetInfo, 20), Err(RpcError::RateLimitExceeded(..))));
+
+        // other IPs have their own bucket
+        let other = limiter.connection("127.0.0.2".parse().unwrap());
+        assert!(limiter.admit(&other, peer, RpcApiOps::GetInfo, 20).is_ok());
+
+        // a call costing more than a full bucket is admitted once
+        let unlimited_ip = RpcRateLimiter::new(RpcRateLimitConfig { connection_rate: 1, ip_rate: 0 });
+        let connection = unlimited_ip.connection(ip);
+        assert!(unlimited_ip.admit(&connection, peer, RpcApiOps::GetBlocks, 220).is_ok());
+        assert!(unlimited_ip.admit(&connection, peer, RpcApiOps::GetInfo, 1).is_err());
+
+        limiter.record(RpcApiOps::GetInfo, 1, Duration::from_micros(100));
+        limiter.record(RpcApiOps::GetInfo, 1, Duration::from_micros(300));
+        let metrics = limiter.metrics();
+        assert_eq!(metrics.len(), 2);
+        assert_eq!(metrics[0].method, "GetBlocks");
+        assert_eq!(metrics[0].rate_limited, 1);
+        assert_eq!(metrics[1].calls, 2);
+        assert_eq!(metrics[1].total_latency_micros, 400);
+        assert_eq!(metrics[1].max_latency_micros, 300);
+    }
+
+    #[test]
+    fn test_rpc_rate_limiter_clients() {
+        let limiter = RpcRateLimiter::new(RpcRateLimitConfig { connection_rate: 10, ip_rate: 0 });
+        let ip: IpAddr = "127.0.0.1".parse().unwrap();
+        let peer = "127.0.0.1:22110";
+
+        // anonymous requests of the same IP share a bucket, even when sent over new connections
+        assert!(limiter.admit(&limiter.client(ip, None), peer, RpcApiOps::GetBlocks, 90).is_ok());
+        assert!(matches!(
+            limiter.admit(&limiter.client(ip, None), peer, RpcApiOps::GetBlocks, 20),
+            Err(RpcError::RateLimitExceeded(..))
+        ));
+
+        // authenticated requests are charged to their API key, wherever they come from
+        assert!(limiter.admit(&limiter.client(ip, Some("miner")), peer, RpcApiOps::GetBlocks, 90).is_ok());
+        let other_ip = "127.0.0.2".parse().unwrap();
+        assert!(limiter.admit(&limiter.client(other_ip, Some("miner")), peer, RpcApiOps::GetBlocks, 20).is_err());
+        assert!(limiter.admit(&limiter.client(other_ip, None), peer, RpcApiOps::GetBlocks, 20).is_ok());
+
+        // a bucket is retained while not refilled completely
+        let mut buckets = TokenBuckets::default();
+        buckets.get(ip, 10).lock().unwrap().consume(50);
+        buckets.pruned -= Duration::from_secs(BURST_SECONDS);
+        let bucket = buckets.get(ip, 10);
+        assert!(bucket.lock().unwrap().tokens < 60.0);
+        drop(bucket);
+        buckets.buckets.get(&ip).unwrap().lock().unwrap().updated -= Duration::from_secs(BURST_SECONDS);
+        buckets.pruned -= Duration::from_secs(BURST_SECONDS);
+        buckets.get("127.0.0.2".parse().unwrap(), 10);
+        assert!(!buckets.buckets.contains_key(&ip));
+    }
+
+    #[test]
+    fn test_rpc_rate_limiter_disabled() {
+        let limiter = RpcRateLimiter::default();
+        let connection = limiter.connection("127.0.0.1".parse().unwrap());
+        for _ in 0..1000 {
+            assert!(limiter.admit(&connection, "peer", RpcApiOps::GetBlocks, 220).is_ok());
+        }
+    }
+}
@@ -1,16 +1,20 @@
 //! Core server implementation for ClientAPI
 
 use super::collector::{CollectorFromConsensus, CollectorFromIndex};
+use crate::checkpoint::DatabaseCheckpointService;
 use crate::converter::feerate_estimate::{FeeEstimateConverter, FeeEstimateVerboseConverter};
 use crate::converter::{consensus::ConsensusConverter, index::IndexConverter, protocol::ProtocolConverter};
+use crate::metrics::{MetricType, OpenMetricsEncoder, RpcConnectionCounters};
+use crate::rate_limit::RpcRateLimiter;
 use crate::service::NetworkType::{Mainnet, Testnet};
 use async_trait::async_trait;
-use calico_consensus_core::api::counters::ProcessingCounters;
+use calico_chainindex::{api::ChainIndexProxy, model::IndexedChainBlock};
+use calico_consensus_core::api::{counters::ProcessingCounters, stats::StorageStats};
 use calico_consensus_core::errors::block::RuleError;
 use calico_consensus_core::{
     block::Block,
     coinbase::MinerData,
-    config::Config,
+    config::{retention::RetentionPolicy, Config},
     constants::MAX_SOMPI,
     network::NetworkType,
     tx::{Transaction, COINBASE_TRANSACTION_INDEX},
@@ -35,6 +39,10 @@ use calico_index_core::{
     connection::IndexChannelConnection, indexed_utxos::UtxoSetByScriptPublicKey, notification::Notification as IndexNotification,
     notifier::IndexNotifier,
 };
+use calico_metrics_core::{
+    history::{MetricsHistory, MetricsHistoryConfig},
+    Metrics, MetricsSinkFn,
+};
 use calico_mining::feerate::FeeEstimateVerbose;
 use calico_mining::model::tx_query::TransactionQuery;
 use calico_mining::{manager::MiningManagerProxy, mempool::tx::Orphan};
@@ -73,7 +81,11 @@ use std::time::Duration;
 use std::{
     collections::HashMap,
     iter::once,
-    sync::{atomic::Ordering, Arc},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
     vec,
 };
 use tokio::join;
@@ -102,6 +114,7 @@ pub struct RpcCoreService {
     mining_manager: MiningManagerProxy,
     flow_context: Arc<FlowContext>,
     utxoindex: Option<UtxoIndexProxy>,
+    chainindex: Option<ChainIndexProxy>,
     config: Arc<Config>,
     consensus_converter: Arc<ConsensusConverter>,
     index_converter: Arc<IndexConverter>,
@@ -115,7 +128,12 @@ pub struct RpcCoreService {
     perf_monitor: Arc<PerfMonitor<Arc<TickService>>>,
     p2p_tower_counters: Arc<TowerConnectionCounters>,
     grpc_tower_counters: Arc<TowerConnectionCounters>,
+    grpc_connection_counters: Arc<RpcConnectionCounters>,
     system_info: SystemInfo,
+    rate_limiter: Arc<RpcRateLimiter>,
+    metrics_sampler: Arc<Metrics>,
+    metrics_history: Option<Arc<MetricsHistory>>,
+    checkpoint_service: Arc<DatabaseCheckpointService>,
     fee_estimate_cache: ExpiringCache<RpcFeeEstimate>,
     fee_estimate_verbose_cache: ExpiringCache<calico_mining::errors::MiningManagerResult<GetFeeEstimateExperimentalResponse>>,
 }
@@ -134,6 +152,7 @@ impl RpcCoreService {
         flow_context: Arc<FlowContext>,
         subscription_context: SubscriptionContext,
         utxoindex: Option<UtxoIndexProxy>,
+        chainindex: Option<ChainIndexProxy>,
         config: Arc<Config>,
         core: Arc<Core>,
         processing_counters: Arc<ProcessingCounters>,
@@ -143,6 +162,9 @@ impl RpcCoreService {
         p2p_tower_counters: Arc<TowerConnectionCounters>,
         grpc_tower_counters: Arc<TowerConnectionCounters>,
         system_info: SystemInfo,
+        rate_limiter: Arc<RpcRateLimiter>,
+        metrics_history_config: MetricsHistoryConfig,
+        checkpoint_service: Arc<DatabaseCheckpointService>,
     ) -> Self {
         // This notifier UTXOs subscription granularity to index-processor or consensus notifier
         let policies = match index_notifier {
@@ -205,6 +227,7 @@ impl RpcCoreService {
             mining_manager,
             flow_context,
             utxoindex,
+            chainindex,
             config,
             consensus_converter,
             index_converter,
@@ -218,7 +241,12 @@ impl RpcCoreService {
             perf_monitor,
             p2p_tower_counters,
             grpc_tower_counters,
+            grpc_connection_counters: Default::default(),
             system_info,
+            rate_limiter,
+            metrics_sampler: Default::default(),
+            metrics_history: metrics_history_config.is_enabled().then(|| Arc::new(MetricsHistory::new(metrics_history_config))),
+            checkpoint_service,
             fee_estimate_cache: ExpiringCache::new(Duration::from_millis(500), Duration::from_millis(1000)),
             fee_estimate_verbose_cache: ExpiringCache::new(Duration::from_millis(500), Duration::from_millis(1000)),
         }
@@ -228,6 +256,36 @@ impl RpcCoreService {
         self.notifier().start();
     }
 
+    /// Starts sampling the node metrics every second into the metrics history, if enabled
+    async fn start_metrics_history(self: &Arc<Self>) {
+        let Some(history) = self.metrics_history.clone() else { return };
+        self.metrics_sampler.bind_rpc(Some(self.clone()));
+        let sink: MetricsSinkFn = Arc::new(Box::new(move |snapshot| {
+            history.record(&snapshot);
+            None
+        }));
+        self.metrics_sampler.register_sink(sink);
+        if let Err(err) = self.metrics_sampler.start_task().await {
+            warn!("Error while starting the metrics history sampler: {}", err);
+        }
+    }
+
+    async fn stop_metrics_history(&self) {
+        if self.metrics_history.is_none() {
+            return;
+        }
+        if let Err(err) = self.metrics_sampler.stop_task().await {
+            warn!("Error while stopping the metrics history sampler: {}", err);
+        }
+        // Break the reference cycle between the service and its sampler
+        self.metrics_sampler.unregister_sink();
+        self.metrics_sampler.bind_rpc(None);
+    }
+
+    fn get_indexed_chain_block(block: IndexedChainBlock) -> RpcIndexedChainBlock {
+        RpcIndexedChainBlock { hash: block.hash, daa_score: block.daa_score, timestamp: block.timestamp }
+    }
+
     pub async fn join(&self) -> RpcResult<()> {
         trace!("{} joining notifier", Self::IDENT);
         self.notifier().join().await?;
@@ -244,6 +302,196 @@ impl RpcCoreService {
         self.notifier.subscription_context().clone()
     }
 
+    /// Rate limiter of the RPC method calls, shared by the RPC servers
+    #[inline(always)]
+    pub fn rate_limiter(&self) -> Arc<RpcRateLimiter> {
+        self.rate_limiter.clone()
+    }
+
+    /// Connection counters of the gRPC server, maintained by the server
+    #[inline(always)]
+    pub fn grpc_connection_counters(&self) -> Arc<RpcConnectionCounters> {
+        self.grpc_connection_counters.clone()
+    }
+
+    /// Exports the node metrics in the OpenMetrics text format
+    pub async fn open_metrics(&self) -> String {
+        use MetricType::{Counter, Gauge};
+        let mut encoder = OpenMetricsEncoder::new();
+
+        // Consensus
+        let counters = self.processing_counters.snapshot();
+        encoder.counter("consensus_blocks_submitted", "Number of blocks submitted to consensus", counters.blocks_submitted);
+        encoder.counter("consensus_headers_processed", "Number of processed headers", counters.header_counts);
+        encoder.counter("consensus_dependencies_processed", "Number of processed header dependencies", counters.dep_counts);
+        encoder.counter("consensus_mergesets_processed", "Cumulated size of the processed mergesets", counters.mergeset_counts);
+        encoder.counter("consensus_bodies_processed", "Number of processed block bodies", counters.body_counts);
+        encoder.counter("consensus_transactions_processed", "Number of processed transactions", counters.txs_counts);
+        encoder.counter("consensus_chain_blocks_processed", "Number of processed chain blocks", counters.chain_block_counts);
+        encoder.counter(
+            "consensus_chain_blocks_disqualified",
+            "Number of chain blocks disqualified from the chain",
+            counters.chain_disqualified_counts,
+        );
+        encoder.counter("consensus_mass_processed", "Cumulated mass of the processed transactions", counters.mass_counts);
+        encoder.counter(
+            "consensus_assumed_valid_blocks",
+            "Number of chain blocks UTXO-validated without script checks since they are in the past of the assume-valid block",
+            counters.assumed_valid_block_counts,
+        );
+        encoder.counter(
+            "consensus_script_skipped_transactions",
+            "Number of transactions whose script checks were skipped by assume-valid",
+            counters.script_skipped_txs_counts,
+        );
+
+        let stats = self.consensus_manager.consensus().unguarded_session().async_get_stats().await;
+        encoder.gauge("consensus_blocks", "Estimated number of blocks stored", stats.block_counts.block_count);
+        encoder.gauge("consensus_headers", "Estimated number of headers stored", stats.block_counts.header_count);
+        encoder.gauge("consensus_tips", "Number of DAG tips", stats.num_tips);
+        encoder.gauge("consensus_virtual_parents", "Number of direct parents of virtual", stats.virtual_stats.num_parents);
+        encoder.gauge("consensus_virtual_daa_score", "DAA score of virtual", stats.virtual_stats.daa_score);
+        encoder.gauge(
+            "consensus_difficulty",
+            "Difficulty of virtual",
+            self.consensus_converter.get_difficulty_ratio(stats.virtual_stats.bits),
+        );
+        encoder.gauge(
+            "consensus_past_median_time_seconds",
+            "Past median time of virtual",
+            stats.virtual_stats.past_median_time as f64 / 1000.0,
+        );
+
+        // Storage. The stats not reported by a DB backend (e.g. all of them for the in-memory backend) are left out
+        // rather than exported as zeros
+        let meta_storage_stats = self.consensus_manager.meta_storage_stats();
+        let storage = [(&[("database", "consensus")][..], &stats.storage_stats), (&[("database", "meta")][..], &meta_storage_stats)];
+        let mut storage_family = |name: &str, help: &str, value: fn(&StorageStats) -> Option<u64>| {
+            let samples = storage.iter().filter_map(|(labels, stats)| Some((*labels, value(stats)?))).collect::<Vec<_>>();
+            if !samples.is_empty() {
+                encoder.family(name, Gauge, help, samples);
+            }
+        };
+        storage_family("storage_sst_files_bytes", "Total size of the database SST files", |stats| stats.sst_files_size);
+        storage_family("storage_live_data_bytes", "Estimated size of the database live data", |stats| stats.live_data_size);
+        storage_family("storage_keys", "Estimated number of keys in the database", |stats| stats.num_keys);
+        storage_family("storage_memtables_bytes", "Size of the database memtables", |stats| stats.memtables_size);
+        storage_family(
+            "storage_pending_compaction_bytes",
+            "Estimated number of bytes the database compaction needs to rewrite",
+            |stats| stats.pending_compaction_bytes,
+        );
+        storage_family("storage_running_compactions", "Number of running database compactions", |stats| stats.running_compactions);
+
+        // Mempool
+        encoder.gauge(
+            "mempool_transactions",
+            "Number of transactions in the mempool",
+            self.mining_manager.transaction_count_sample(TransactionQuery::TransactionsOnly),
+        );
+        encoder.gauge(
+            "mempool_orphans",
+            "Number of orphan transactions in the mempool",
+            self.mining_manager.transaction_count_sample(TransactionQuery::OrphansOnly),
+        );
+
+        // Process
+        let process = self.perf_monitor.snapshot();
+        encoder.gauge("process_resident_memory_bytes", "Resident memory size", process.resident_set_size);
+        encoder.gauge("process_virtual_memory_bytes", "Virtual memory size", process.virtual_memory_size);
+        encoder.gauge("process_cpu_cores", "Number of CPU cores", process.core_num);
+        encoder.gauge("process_cpu_usage", "CPU usage, in cores", process.cpu_usage);
+        encoder.gauge("process_open_fds", "Number of open file descriptors", process.fd_num);
+        encoder.counter("process_disk_read_bytes", "Number of bytes read from disk", process.disk_io_read_bytes);
+        encoder.counter("process_disk_written_bytes", "Number of bytes written to disk", process.disk_io_write_bytes);
+
+        // P2P
+        let peers = self.flow_context.hub().active_peers();
+        let outbound = peers.iter().filter(|peer| peer.is_outbound()).count();
+        encoder.family(
+            "p2p_peers",
+            Gauge,
+            "Number of active peers",
+            [(&[("direction", "outbound")][..], outbound), (&[("direction", "inbound")][..], peers.len() - outbound)],
+        );
+
+        // RPC
+        let wrpc =
+            [(&[("encoding", "borsh")][..], &self.wrpc_borsh_counters), (&[("encoding", "json")][..], &self.wrpc_json_counters)];
+        let wrpc_samples = |value: fn(&WrpcServerCounters) -> usize| {
+            wrpc.iter().map(move |(labels, counters)| (*labels, value(counters))).collect::<Vec<_>>()
+        };
+        encoder.family(
+            "rpc_wrpc_connections",
+            Gauge,
+            "Number of live wRPC connections",
+            wrpc_samples(|counters| counters.active_connections.load(Ordering::Relaxed)),
+        );
+        encoder.family(
+            "rpc_wrpc_connection_attempts",
+            Counter,
+            "Number of wRPC connection attempts",
+            wrpc_samples(|counters| counters.total_connections.load(Ordering::Relaxed)),
+        );
+        encoder.family(
+            "rpc_wrpc_handshake_failures",
+            Counter,
+            "Number of failed wRPC handshakes",
+            wrpc_samples(|counters| counters.handshake_failures.load(Ordering::Relaxed)),
+        );
+
+        let grpc = &self.grpc_connection_counters;
+        encoder.gauge("rpc_grpc_connections", "Number of live gRPC connections", grpc.active_connections.load(Ordering::Relaxed));
+        encoder.counter(
+            "rpc_grpc_connections_accepted",
+            "Number of accepted gRPC connections",
+            grpc.total_connections.load(Ordering::Relaxed),
+        );
+        encoder.counter(
+            "rpc_grpc_connections_rejected",
+            "Number of gRPC connections refused since the server was at capacity",
+            grpc.rejected_connections.load(Ordering::Relaxed),
+        );
+
+        let methods = self.rate_limiter.metrics();
+        let method_labels = methods.iter().map(|metrics| [("method", metrics.method.as_str())]).collect::<Vec<_>>();
+        let method_samples = |value: fn(&RpcMethodMetrics) -> u64| {
+            method_labels.iter().zip(methods.iter()).map(move |(labels, metrics)| (&labels[..], value(metrics)))
+        };
+        encoder.family("rpc_method_calls", Counter, "Number of RPC calls", method_samples(|metrics| metrics.calls));
+        encoder.family(
+            "rpc_method_rate_limited",
+            Counter,
+            "Number of RPC calls rejected by the rate limits",
+            method_samples(|metrics| metrics.rate_limited),
+        );
+        encoder.family("rpc_method_cost", Counter, "Cumulated cost of the RPC calls", method_samples(|metrics| metrics.total_cost));
+        encoder.family(
+            "rpc_method_latency_microseconds",
+            Counter,
+            "Cumulated latency of the RPC calls",
+            method_samples(|metrics| metrics.total_latency_micros),
+        );
+
+        // Bandwidth
+        let load = |counter: &AtomicUsize| counter.load(Ordering::Relaxed) as u64;
+        let bandwidth = [
+            (&[("protocol", "p2p")][..], load(&self.p2p_tower_counters.bytes_tx), load(&self.p2p_tower_counters.bytes_rx)),
+            (&[("protocol", "grpc")][..], load(&self.grpc_tower_counters.bytes_tx), load(&self.grpc_tower_counters.bytes_rx)),
+            (&[("protocol", "wrpc_borsh")][..], load(&self.wrpc_borsh_counters.tx_bytes), load(&self.wrpc_borsh_counters.rx_bytes)),
+            (&[("protocol", "wrpc_json")][..], load(&self.wrpc_json_counters.tx_bytes), load(&self.wrpc_json_counters.rx_bytes)),
+        ];
+        encoder.family("network_sent_bytes", Counter, "Number of bytes sent", bandwidth.iter().map(|(labels, tx, _)| (*labels, *tx)));
+        encoder.family(
+            "network_received_bytes",
+            Counter,
+            "Number of bytes received",
+            bandwidth.iter().map(|(labels, _, rx)| (*labels, *rx)),
+        );
+
+        encoder.finish()
+    }
+
     pub fn core_shutdown_request_listener(&self) -> triggered::Listener {
         self.core_shutdown_request.listener.clone()
     }
@@ -274,6 +522,19 @@ impl RpcCoreService {
         !matches!(self.flow_context.config.net.network_type, Mainnet | Testnet) || self.flow_context.hub().has_peers()
     }
 
+    /// Returns the retention policy of the node along with the number of days block bodies are kept for, if limited
+    fn retention_policy(&self) -> (RpcRetentionPolicy, Option<u64>) {
+        if !self.config.is_archival {
+            return (RpcRetentionPolicy::Pruned, None);
+        }
+        match self.config.retention {
+            RetentionPolicy::Full => (RpcRetentionPolicy::Archival, None),
+            RetentionPolicy::BodiesForDays(days) => (RpcRetentionPolicy::BodiesForDays, Some(days)),
+            RetentionPolicy::ChainBodies => (RpcRetentionPolicy::ChainBodies, None),
+            RetentionPolicy::AcceptanceData => (RpcRetentionPolicy::AcceptanceData, None),
+        }
+    }
+
     fn extract_tx_query(&self, filter_transaction_pool: bool, include_orphan_pool: bool) -> RpcResult<TransactionQuery> {
         match (filter_transaction_pool, include_orphan_pool) {
             (true, true) => Ok(TransactionQuery::OrphansOnly),
@@ -399,6 +660,151 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         }
     }
 
+    async fn export_dag_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: ExportDagRequest,
+    ) -> RpcResult<ExportDagResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        let (low_hash, high_hash) = match request.range {
+            RpcDagRange::Hashes { low_hash, high_hash } => (low_hash, high_hash),
+            RpcDagRange::DaaScores { low_daa_score, high_daa_score } => {
+                if low_daa_score > high_daa_score {
+                    return Err(RpcError::General(format!("invalid DAA score range {low_daa_score}..{high_daa_score}")));
+                }
+                (
+                    session.async_get_chain_block_by_daa_score(low_daa_score).await?,
+                    session.async_get_chain_block_by_daa_score(high_daa_score).await?,
+                )
+            }
+        };
+
+        let hashes = session.async_get_sub_dag(low_hash, high_hash, ExportDagRequest::MAX_BLOCKS).await?;
+        let blocks = self.consensus_converter.get_dag_blocks(&session, hashes).await?;
+        Ok(ExportDagResponse { low_hash, high_hash, blocks })
+    }
+
+    async fn is_dag_ancestor_of_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: IsDagAncestorOfRequest,
+    ) -> RpcResult<IsDagAncestorOfResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        let is_dag_ancestor_of = session.async_is_dag_ancestor_of(request.low_hash, request.high_hash).await?;
+        let is_chain_ancestor_of = session.async_is_chain_ancestor_of(request.low_hash, request.high_hash).await?;
+        let blue_score_distance = if is_dag_ancestor_of {
+            let low_blue_score = session.async_get_header(request.low_hash).await?.blue_score;
+            let high_blue_score = session.async_get_header(request.high_hash).await?.blue_score;
+            Some(high_blue_score.saturating_sub(low_blue_score))
+        } else {
+            None
+        };
+        Ok(IsDagAncestorOfResponse { is_dag_ancestor_of, is_chain_ancestor_of, blue_score_distance })
+    }
+
+    async fn get_block_mergeset_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetBlockMergesetRequest,
+    ) -> RpcResult<GetBlockMergesetResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        let ghostdag_data = session.async_get_ghostdag_data(request.hash).await?;
+        Ok(GetBlockMergesetResponse {
+            selected_parent: ghostdag_data.selected_parent,
+            mergeset_blues: ghostdag_data.mergeset_blues,
+            mergeset_reds: ghostdag_data.mergeset_reds,
+        })
+    }
+
+    async fn get_anticone_size_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetAnticoneSizeRequest,
+    ) -> RpcResult<GetAnticoneSizeResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        let max_traversal = (!self.config.unsafe_rpc).then_some(GetAnticoneSizeRequest::MAX_TRAVERSAL);
+        let anticone_size = session.async_get_anticone_size(request.hash, max_traversal).await?;
+        Ok(GetAnticoneSizeResponse { anticone_size })
+    }
+
+    async fn get_chain_path_between_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetChainPathBetweenRequest,
+    ) -> RpcResult<GetChainPathBetweenResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        let chain_path_limit = (!self.config.unsafe_rpc).then_some(GetChainPathBetweenRequest::MAX_CHAIN_BLOCKS as usize);
+        let chain_path = session.async_get_chain_path(request.from_hash, request.to_hash, chain_path_limit).await?;
+        // A full path ends at the target, unless the target is the common chain ancestor itself
+        let truncated = chain_path.added.last().is_some_and(|&last| last != request.to_hash);
+        Ok(GetChainPathBetweenResponse {
+            removed_chain_block_hashes: chain_path.removed,
+            added_chain_block_hashes: chain_path.added,
+            truncated,
+        })
+    }
+
+    async fn get_chain_block_by_daa_score_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetChainBlockByDaaScoreRequest,
+    ) -> RpcResult<GetChainBlockByDaaScoreResponse> {
+        if !self.config.chainindex {
+            return Err(RpcError::NoChainIndex);
+        }
+        let block = self
+            .chainindex
+            .clone()
+            .unwrap()
+            .get_chain_block_by_daa_score(request.daa_score)
+            .await
+            .map_err(|e| RpcError::General(e.to_string()))?
+            .ok_or_else(|| RpcError::General(format!("no indexed chain block with a DAA score at or below {}", request.daa_score)))?;
+        Ok(GetChainBlockByDaaScoreResponse { block: Self::get_indexed_chain_block(block) })
+    }
+
+    async fn get_blocks_by_time_range_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetBlocksByTimeRangeRequest,
+    ) -> RpcResult<GetBlocksByTimeRangeResponse> {
+        if !self.config.chainindex {
+            return Err(RpcError::NoChainIndex);
+        }
+        if request.start_timestamp > request.end_timestamp {
+            return Err(RpcError::General(format!(
+                "start timestamp {} is greater than end timestamp {}",
+                request.start_timestamp, request.end_timestamp
+            )));
+        }
+        let limit = request.effective_limit() as usize;
+        let page = self
+            .chainindex
+            .clone()
+            .unwrap()
+            .get_chain_blocks_by_time_range(request.start_timestamp, request.end_timestamp, request.after_hash, limit)
+            .await
+            .map_err(|e| RpcError::General(e.to_string()))?;
+        // Expand each chain block into the blocks it merged, leaving out its selected parent which is a chain block itself
+        let session = self.consensus_manager.consensus().session().await;
+        let mut merged_block_hashes = Vec::new();
+        for block in page.blocks.iter() {
+            let ghostdag_data = session.async_get_ghostdag_data(block.hash).await?;
+            merged_block_hashes.extend(
+                ghostdag_data
+                    .mergeset_blues
+                    .into_iter()
+                    .chain(ghostdag_data.mergeset_reds)
+                    .filter(|&hash| hash != ghostdag_data.selected_parent),
+            );
+        }
+        Ok(GetBlocksByTimeRangeResponse {
+            blocks: page.blocks.into_iter().map(Self::get_indexed_chain_block).collect(),
+            merged_block_hashes,
+            has_more: page.has_more,
+        })
+    }
+
     async fn get_block_call(&self, _connection: Option<&DynRpcConnection>, request: GetBlockRequest) -> RpcResult<GetBlockResponse> {
         // TODO: test
         let session = self.consensus_manager.consensus().session().await;
@@ -945,6 +1351,22 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         Ok(ShutdownResponse {})
     }
 
+    async fn create_database_checkpoint_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: CreateDatabaseCheckpointRequest,
+    ) -> RpcResult<CreateDatabaseCheckpointResponse> {
+        if !self.config.unsafe_rpc {
+            warn!("CreateDatabaseCheckpoint RPC command called while node in safe RPC mode -- ignoring.");
+            return Err(RpcError::UnavailableInSafeMode);
+        }
+        let directory = self
+            .checkpoint_service
+            .start_checkpoint(request.directory.map(PathBuf::from))
+            .map_err(|err| RpcError::General(err.to_string()))?;
+        Ok(CreateDatabaseCheckpointResponse { directory: directory.display().to_string() })
+    }
+
     async fn resolve_finality_conflict_call(
         &self,
         _connection: Option<&DynRpcConnection>,
@@ -1053,6 +1475,8 @@ NOTE: This error usually indicates an RPC conversion error between the node and
 
         let custom_metrics: Option<HashMap<String, CustomMetricValue>> = None;
 
+        let rpc_method_metrics = req.rpc_method_metrics.then(|| self.rate_limiter.metrics());
+
         let server_time = unix_now();
 
         let response = GetMetricsResponse {
@@ -1063,6 +1487,7 @@ NOTE: This error usually indicates an RPC conversion error between the node and
             consensus_metrics,
             storage_metrics,
             custom_metrics,
+            rpc_method_metrics,
         };
 
         Ok(response)
@@ -1086,6 +1511,35 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         Ok(response)
     }
 
+    async fn get_metrics_history_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetMetricsHistoryRequest,
+    ) -> RpcResult<GetMetricsHistoryResponse> {
+        let Some(history) = self.metrics_history.as_ref() else {
+            return Err(RpcError::General("the metrics history is disabled on this node".to_string()));
+        };
+        let metrics = if request.metrics.is_empty() {
+            history.metrics().to_vec()
+        } else {
+            request
+                .metrics
+                .iter()
+                .map(|name| history.metric(name).ok_or_else(|| RpcError::General(format!("unknown metric {name}"))))
+                .collect::<RpcResult<Vec<_>>>()?
+        };
+        let samples = history
+            .samples(request.resolution, request.start_time, request.end_time, &metrics)
+            .into_iter()
+            .map(|sample| RpcMetricsHistorySample { timestamp: sample.timestamp, values: sample.values })
+            .collect();
+        Ok(GetMetricsHistoryResponse {
+            resolution: request.resolution,
+            metrics: metrics.iter().map(|metric| metric.as_str().to_string()).collect(),
+            samples,
+        })
+    }
+
     async fn get_server_info_call(
         &self,
         _connection: Option<&DynRpcConnection>,
@@ -1094,6 +1548,7 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         let session = self.consensus_manager.consensus().unguarded_session();
         let is_synced: bool = self.has_sufficient_peer_connectivity() && session.async_is_nearly_synced().await;
         let virtual_daa_score = session.get_virtual_daa_score();
+        let (retention_policy, body_retention_days) = self.retention_policy();
 
         Ok(GetServerInfoResponse {
             rpc_api_version: RPC_API_VERSION,
@@ -1103,6 +1558,8 @@ NOTE: This error usually indicates an RPC conversion error between the node and
             has_utxo_index: self.config.utxoindex,
             is_synced,
             virtual_daa_score,
+            retention_policy,
+            body_retention_days,
         })
     }
 
@@ -1135,9 +1592,14 @@ NOTE: This error usually indicates an RPC conversion error between the node and
     /// Start sending notifications of some type to a listener.
     async fn start_notify(&self, id: ListenerId, scope: Scope) -> RpcResult<()> {
         match scope {
-            Scope::UtxosChanged(ref utxos_changed_scope) if !self.config.unsafe_rpc && utxos_changed_scope.addresses.is_empty() => {
+            Scope::UtxosChanged(ref utxos_changed_scope)
+                if !self.config.unsafe_rpc
+                    && utxos_changed_scope.addresses.is_empty()
+                    && !utxos_changed_scope.filter.is_selective() =>
+            {
                 // The subscription to blanket UtxosChanged notifications is restricted to unsafe mode only
-                // since the notifications yielded are highly resource intensive.
+                // since the notifications yielded are highly resource intensive. A blanket subscription
+                // is allowed if its filter is selective, rejecting most UTXOs on cheap script checks.
                 //
                 // Please note that unsubscribing to blanket UtxosChanged is always allowed and cancels
                 // the whole subscription no matter if blanket or targeting specified addresses.
@@ -1176,7 +1638,9 @@ impl AsyncService for RpcCoreService {
         // Launch the service and wait for a shutdown signal
         Box::pin(async move {
             service.clone().start_impl();
+            service.start_metrics_history().await;
             shutdown_signal.await;
+            service.stop_metrics_history().await;
             match service.join().await {
                 Ok(_) => Ok(()),
                 Err(err) => {
@@ -0,0 +1,339 @@
+//!
+//! Native TLS termination for the RPC servers.
+//!
+//! [`RpcTlsConfig`] loads the server certificate chain and private key from
+//! PEM files and, when a client CA is provided, requires clients to present a
+//! certificate signed by it (mutual TLS). The certificate and key are reloaded
+//! on SIGHUP without dropping established connections.
+//!
+
+use calico_core::{
+    debug, info,
+    task::service::{AsyncService, AsyncServiceFuture},
+    trace, warn,
+};
+use calico_utils::triggers::SingleTrigger;
+use rustls::{
+    crypto::{ring::default_provider, CryptoProvider},
+    pki_types::{CertificateDer, PrivateKeyDer},
+    server::{danger::ClientCertVerifier, ClientHello, ResolvesServerCert, WebPkiClientVerifier},
+    sign::CertifiedKey,
+    RootCertStore, ServerConfig,
+};
+use std::{
+    fs::File,
+    io::BufReader,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+use thiserror::Error;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::mpsc::{channel as mpsc_channel, Receiver as MpscReceiver},
+    time::timeout,
+};
+use tokio_rustls::{server::TlsStream, TlsAcceptor};
+
+#[derive(Debug, Error)]
+pub enum TlsError {
+    #[error("unable to read {}: {1}", .0.display())]
+    Io(PathBuf, std::io::Error),
+
+    #[error("no certificate found in {}", .0.display())]
+    NoCertificate(PathBuf),
+
+    #[error("no private key found in {}", .0.display())]
+    NoPrivateKey(PathBuf),
+
+    #[error(transparent)]
+    Rustls(#[from] rustls::Error),
+
+    #[error(transparent)]
+    ClientVerifier(#[from] rustls::server::VerifierBuilderError),
+}
+
+pub type TlsResult<T> = std::result::Result<T, TlsError>;
+
+/// ALPN protocol of gRPC (HTTP/2) connections
+pub const ALPN_H2: &[u8] = b"h2";
+/// ALPN protocol of WebSocket (HTTP/1.1) connections
+pub const ALPN_HTTP1: &[u8] = b"http/1.1";
+
+/// Time allowed to a client for completing the TLS handshake
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum number of established TLS connections waiting to be picked by the RPC server
+const TLS_ACCEPT_CHANNEL_SIZE: usize = 128;
+
+/// Paths of the PEM files used by the RPC servers TLS termination
+#[derive(Debug, Clone)]
+pub struct RpcTlsOptions {
+    /// Server certificate chain
+    pub cert: PathBuf,
+    /// Server private key
+    pub key: PathBuf,
+    /// CA certificates verifying client certificates (mutual TLS is disabled if `None`)
+    pub client_ca: Option<PathBuf>,
+}
+
+/// Server certificate resolver always serving the last loaded certificate
+#[derive(Debug)]
+struct ReloadableCertResolver {
+    certified_key: RwLock<Arc<CertifiedKey>>,
+}
+
+impl ResolvesServerCert for ReloadableCertResolver {
+    fn resolve(&self, _client_hello: ClientHello) -> Option<Arc<CertifiedKey>> {
+        Some(self.certified_key.read().unwrap().clone())
+    }
+}
+
+/// TLS configuration shared by the RPC servers
+pub struct RpcTlsConfig {
+    options: RpcTlsOptions,
+    provider: Arc<CryptoProvider>,
+    resolver: Arc<ReloadableCertResolver>,
+    client_verifier: Option<Arc<dyn ClientCertVerifier>>,
+    shutdown: SingleTrigger,
+}
+
+impl RpcTlsConfig {
+    pub const IDENT: &'static str = "rpc-tls";
+
+    pub fn new(options: RpcTlsOptions) -> TlsResult<Self> {
+        let provider = Arc::new(default_provider());
+        let certified_key = Self::load_certified_key(&options, &provider)?;
+        let resolver = Arc::new(ReloadableCertResolver { certified_key: RwLock::new(Arc::new(certified_key)) });
+        let client_verifier = match options.client_ca.as_ref() {
+            Some(client_ca) => {
+                let mut roots = RootCertStore::empty();
+                for cert in load_certs(client_ca)? {
+                    roots.add(cert)?;
+                }
+                Some(WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider.clone()).build()?)
+            }
+            None => None,
+        };
+        Ok(Self { options, provider, resolver, client_verifier, shutdown: Default::default() })
+    }
+
+    pub fn is_mutual(&self) -> bool {
+        self.client_verifier.is_some()
+    }
+
+    /// Reloads the server certificate chain and private key. On failure the current certificate is kept.
+    pub fn reload(&self) -> TlsResult<()> {
+        let certified_key = Self::load_certified_key(&self.options, &self.provider)?;
+        *self.resolver.certified_key.write().unwrap() = Arc::new(certified_key);
+        Ok(())
+    }
+
+    fn load_certified_key(options: &RpcTlsOptions, provider: &CryptoProvider) -> TlsResult<CertifiedKey> {
+        let certs = load_certs(&options.cert)?;
+        let key = load_private_key(&options.key)?;
+        let signing_key = provider.key_provider.load_private_key(key)?;
+        Ok(CertifiedKey::new(certs, signing_key))
+    }
+
+    /// Builds a TLS acceptor negotiating one of the `alpn_protocols`
+    pub fn acceptor(&self, alpn_protocols: &[&[u8]]) -> TlsResult<TlsAcceptor> {
+        let builder = ServerConfig::builder_with_provider(self.provider.clone()).with_safe_default_protocol_versions()?;
+        let builder = match self.client_verifier.clone() {
+            Some(client_verifier) => builder.with_client_cert_verifier(client_verifier),
+            None => builder.with_no_client_auth(),
+        };
+        let mut config = builder.with_cert_resolver(self.resolver.clone());
+        config.alpn_protocols = alpn_protocols.iter().map(|protocol| protocol.to_vec()).collect();
+        Ok(TlsAcceptor::from(Arc::new(config)))
+    }
+
+    /// Accepts incoming connections on `listener` and relays the streams having completed
+    /// the TLS handshake. The accept loop stops when the returned receiver is dropped.
+    pub fn accept(&self, ident: &'static str, listener: TcpListener, acceptor: TlsAcceptor) -> MpscReceiver<TlsStream<TcpStream>> {
+        let (sender, receiver) = mpsc_channel(TLS_ACCEPT_CHANNEL_SIZE);
+        tokio::spawn(async move {
+            loop {
+                let (stream, peer) = tokio::select! {
+                    _ = sender.closed() => break,
+                    accepted = listener.accept() => match accepted {
+                        Ok(accepted) => accepted,
+                        Err(err) => {
+                            debug!("{} TLS, accept error: {}", ident, err);
+                            continue;
+                        }
+                    },
+                };
+                let acceptor = acceptor.clone();
+                let sender = sender.clone();
+                tokio::spawn(async move {
+                    match timeout(TLS_HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+                        Ok(Ok(tls_stream)) => {
+                            let _ = sender.send(tls_stream).await;
+                        }
+                        Ok(Err(err)) => debug!("{} TLS, handshake with {} failed: {}", ident, peer, err),
+                        Err(_) => debug!("{} TLS, handshake with {} timed out", ident, peer),
+                    }
+                });
+            }
+            trace!("{} TLS, accept loop exited", ident);
+        });
+        receiver
+    }
+
+    fn reload_and_log(&self) {
+        match self.reload() {
+            Ok(()) => info!("RPC TLS certificate reloaded from {}", self.options.cert.display()),
+            Err(err) => warn!("RPC TLS certificate reload failed, keeping the current certificate: {}", err),
+        }
+    }
+}
+
+fn load_certs(path: &Path) -> TlsResult<Vec<CertificateDer<'static>>> {
+    let file = File::open(path).map_err(|err| TlsError::Io(path.to_path_buf(), err))?;
+    let certs = rustls_pemfile::certs(&mut BufReader::new(file))
+        .collect::<std::io::Result<Vec<_>>>()
+        .map_err(|err| TlsError::Io(path.to_path_buf(), err))?;
+    if certs.is_empty() {
+        return Err(TlsError::NoCertificate(path.to_path_buf()));
+    }
+    Ok(certs)
+}
+
+fn load_private_key(path: &Path) -> TlsResult<PrivateKeyDer<'static>> {
+    let file = File::open(path).map_err(|err| TlsError::Io(path.to_path_buf(), err))?;
+    rustls_pemfile::private_key(&mut BufReader::new(file))
+        .map_err(|err| TlsError::Io(path.to_path_buf(), err))?
+        .ok_or_else(|| TlsError::NoPrivateKey(path.to_path_buf()))
+}
+
+// service trait implementation reloading the certificate on SIGHUP
+impl AsyncService for RpcTlsConfig {
+    fn ident(self: Arc<Self>) -> &'static str {
+        Self::IDENT
+    }
+
+    fn start(self: Arc<Self>) -> AsyncServiceFuture {
+        trace!("{} starting", Self::IDENT);
+        let shutdown_signal = self.shutdown.listener.clone();
+        Box::pin(async move {
+            #[cfg(unix)]
+            {
+                use calico_core::task::service::AsyncServiceError;
+                use tokio::signal::unix::{signal, SignalKind};
+                let mut hangup = signal(SignalKind::hangup()).map_err(|err| AsyncServiceError::Service(err.to_string()))?;
+                tokio::pin!(shutdown_signal);
+                loop {
+                    tokio::select! {
+                        _ = hangup.recv() => self.reload_and_log(),
+                        _ = &mut shutdown_signal => break,
+                    }
+                }
+            }
+            #[cfg(not(unix))]
+            shutdown_signal.await;
+            Ok(())
+        })
+    }
+
+    fn signal_exit(self: Arc<Self>) {
+        trace!("sending an exit signal to {}", Self::IDENT);
+        self.shutdown.trigger.trigger();
+    }
+
+    fn stop(self: Arc<Self>) -> AsyncServiceFuture {
+        Box::pin(async move {
+            trace!("{} stopped", Self::IDENT);
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustls::{pki_types::ServerName, ClientConfig};
+    use std::io::Write;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio_rustls::TlsConnector;
+
+    fn test_data(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("test-data/tls").join(name)
+    }
+
+    fn client_connector(with_identity: bool) -> TlsConnector {
+        let mut roots = RootCertStore::empty();
+        for cert in load_certs(&test_data("ca.pem")).unwrap() {
+            roots.add(cert).unwrap();
+        }
+        let builder = ClientConfig::builder_with_provider(Arc::new(default_provider()))
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_root_certificates(roots);
+        let mut config = match with_identity {
+            true => builder
+                .with_client_auth_cert(
+                    load_certs(&test_data("client.pem")).unwrap(),
+                    load_private_key(&test_data("client.key")).unwrap(),
+                )
+                .unwrap(),
+            false => builder.with_no_client_auth(),
+        };
+        config.alpn_protocols = vec![ALPN_HTTP1.to_vec()];
+        TlsConnector::from(Arc::new(config))
+    }
+
+    #[test]
+    fn test_rpc_tls_config_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("missing.pem");
+        let empty = dir.path().join("empty.pem");
+        File::create(&empty).unwrap().write_all(b"not a pem file\n").unwrap();
+
+        let options = |cert: &Path, key: &Path| RpcTlsOptions { cert: cert.into(), key: key.into(), client_ca: None };
+        assert!(matches!(RpcTlsConfig::new(options(&missing, &missing)), Err(TlsError::Io(..))));
+        assert!(matches!(RpcTlsConfig::new(options(&empty, &empty)), Err(TlsError::NoCertificate(..))));
+        assert!(matches!(load_private_key(&empty), Err(TlsError::NoPrivateKey(..))));
+    }
+
+    #[tokio::test]
+    async fn test_rpc_tls_handshake() {
+        let options =
+            RpcTlsOptions { cert: test_data("server.pem"), key: test_data("server.key"), client_ca: Some(test_data("ca.pem")) };
+        let tls = RpcTlsConfig::new(options).unwrap();
+        assert!(tls.is_mutual());
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let mut incoming = tls.accept("test", listener, tls.acceptor(&[ALPN_HTTP1]).unwrap());
+        let server_name = ServerName::try_from("localhost").unwrap();
+
+        // A client presenting a certificate signed by the client CA completes the handshake
+        let stream = TcpStream::connect(address).await.unwrap();
+        let mut client = client_connector(true).connect(server_name.clone(), stream).await.unwrap();
+        client.write_all(b"ping").await.unwrap();
+        let mut server = incoming.recv().await.unwrap();
+        assert_eq!(server.get_ref().1.alpn_protocol(), Some(ALPN_HTTP1));
+        let mut buffer = [0u8; 4];
+        server.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(&buffer, b"ping");
+
+        // The certificate reloaded on SIGHUP is served to new connections
+        tls.reload().unwrap();
+        let stream = TcpStream::connect(address).await.unwrap();
+        let mut client = client_connector(true).connect(server_name.clone(), stream).await.unwrap();
+        client.write_all(b"pong").await.unwrap();
+        let mut server = incoming.recv().await.unwrap();
+        server.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(&buffer, b"pong");
+
+        // A client without a certificate is refused by mutual TLS
+        let refused = async {
+            let stream = TcpStream::connect(address).await?;
+            let mut client = client_connector(false).connect(server_name, stream).await?;
+            client.write_all(b"ping").await?;
+            client.flush().await?;
+            client.read_exact(&mut buffer).await
+        };
+        assert!(refused.await.is_err());
+    }
+}
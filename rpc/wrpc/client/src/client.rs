@@ -11,6 +11,7 @@ use calico_notify::{
 use calico_rpc_core::{
     api::ctl::RpcCtl,
     notify::collector::{RpcCoreCollector, RpcCoreConverter},
+    with_rpc_methods,
 };
 pub use calico_rpc_macros::build_wrpc_client_interface;
 use std::fmt::Debug;
@@ -21,6 +22,7 @@ pub use workflow_rpc::client::{
     ConnectOptions, ConnectResult, ConnectStrategy, Resolver as RpcResolver, ResolverResult, WebSocketConfig, WebSocketError,
 };
 use workflow_serializer::prelude::*;
+use workflow_websocket::client::{Handshake, Message as WebSocketMessage};
 type RpcClientNotifier = Arc<Notifier<Notification, ChannelConnection>>;
 
 struct Inner {
@@ -48,6 +50,13 @@ struct Inner {
     resolver: Mutex<Option<Resolver>>,
     network_id: Mutex<Option<NetworkId>>,
     node_descriptor: Mutex<Option<Arc<NodeDescriptor>>>,
+    // API key presented to servers that require authentication
+    // (an empty key requests anonymous access).
+    auth_token: Mutex<Option<String>>,
+    // TLS config of `wss://` connections requiring a custom CA
+    // or a client certificate (native platforms only).
+    #[cfg(not(target_arch = "wasm32"))]
+    tls_config: Mutex<Option<Arc<rustls::ClientConfig>>>,
 }
 
 impl Inner {
@@ -76,6 +85,10 @@ impl Inner {
             RpcApiOps::VirtualDaaScoreChangedNotification,
             RpcApiOps::PruningPointUtxoSetOverrideNotification,
             RpcApiOps::NewBlockTemplateNotification,
+            RpcApiOps::MempoolTransactionAddedNotification,
+            RpcApiOps::MempoolTransactionRemovedNotification,
+            RpcApiOps::DatabaseCheckpointNotification,
+            RpcApiOps::ConsensusAuditViolationNotification,
         ]
         .into_iter()
         .for_each(|notification_op| {
@@ -119,6 +132,9 @@ impl Inner {
             resolver: Mutex::new(resolver),
             network_id: Mutex::new(network_id),
             node_descriptor: Mutex::new(None),
+            auth_token: Mutex::new(None),
+            #[cfg(not(target_arch = "wasm32"))]
+            tls_config: Mutex::new(None),
         };
         Ok(client)
     }
@@ -155,6 +171,10 @@ impl Inner {
         *self.default_url.lock().unwrap() = url.map(String::from);
     }
 
+    fn auth_token(&self) -> Option<String> {
+        self.auth_token.lock().unwrap().clone()
+    }
+
     fn current_url(&self) -> Option<String> {
         self.current_url.lock().unwrap().clone()
     }
@@ -242,10 +262,49 @@ impl RpcResolver for Inner {
 
         self.rpc_ctl.set_descriptor(Some(url.clone()));
         self.set_current_url(Some(&url));
+
+        // Connections with a custom TLS config go through a TLS bridge
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(tls_config) = self.tls_config.lock().unwrap().clone() {
+            if url.starts_with("wss://") {
+                return crate::tls::open_bridge(tls_config, &url).await.map_err(WebSocketError::custom);
+            }
+        }
+
         Ok(url)
     }
 }
 
+/// Reply sent by the server once the authentication frame has been accepted.
+const AUTHENTICATION_ACCEPTED: &str = "ok";
+
+/// Presents the API key as the first text frame of the connection,
+/// as expected by wRPC servers that have authentication enabled.
+struct AuthHandshake {
+    token: String,
+}
+
+#[async_trait]
+impl Handshake for AuthHandshake {
+    async fn handshake(
+        &self,
+        sender: &Sender<WebSocketMessage>,
+        receiver: &Receiver<WebSocketMessage>,
+    ) -> std::result::Result<(), WebSocketError> {
+        sender.send(WebSocketMessage::Text(self.token.clone())).await.map_err(WebSocketError::custom)?;
+        loop {
+            match receiver.recv().await.map_err(WebSocketError::custom)? {
+                WebSocketMessage::Text(reply) if reply == AUTHENTICATION_ACCEPTED => return Ok(()),
+                WebSocketMessage::Open => continue,
+                WebSocketMessage::Text(reply) => {
+                    return Err(WebSocketError::custom(format!("wRPC authentication rejected: {reply}")));
+                }
+                _ => return Err(WebSocketError::custom("wRPC authentication rejected by the server")),
+            }
+        }
+    }
+}
+
 const WRPC_CLIENT: &str = "wrpc-client";
 
 /// # [`CalicoRpcClient`] connects to Calico wRPC endpoint via binary Borsh or JSON protocols.
@@ -355,6 +414,27 @@ impl CalicoRpcClient {
         Ok(())
     }
 
+    /// API key sent to the server when connecting, if any.
+    pub fn auth_token(&self) -> Option<String> {
+        self.inner.auth_token()
+    }
+
+    /// Set the API key sent as the first frame of each connection.
+    /// An empty key requests anonymous access; [`Option::None`]
+    /// connects without an authentication frame.
+    pub fn set_auth_token(&self, token: Option<&str>) -> Result<()> {
+        *self.inner.auth_token.lock().unwrap() = token.map(String::from);
+        Ok(())
+    }
+
+    /// Set the TLS config (custom CA, client identity for mutual TLS) of `wss://` connections.
+    /// `wss://` urls are verified against the WebPKI roots if [`Option::None`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_tls_config(&self, tls_config: Option<crate::tls::WrpcTlsConfig>) -> Result<()> {
+        *self.inner.tls_config.lock().unwrap() = tls_config.map(|tls_config| tls_config.client_config()).transpose()?;
+        Ok(())
+    }
+
     pub fn is_connected(&self) -> bool {
         self.inner.rpc_client.is_connected()
     }
@@ -444,6 +524,7 @@ impl CalicoRpcClient {
             max_frame_size: Some(1024 * 1024 * 1024),
             accept_unmasked_frames: false,
             resolver: Some(self.inner.clone()),
+            handshake: self.inner.auth_token().map(|token| Arc::new(AuthHandshake { token }) as Arc<dyn Handshake>),
             ..Default::default()
         };
 
@@ -609,51 +690,7 @@ impl RpcApi for CalicoRpcClient {
     //     Ok(response.map_err(|e| e.to_string())?)
     // }
 
-    build_wrpc_client_interface!(
-        RpcApiOps,
-        [
-            Ping,
-            AddPeer,
-            Ban,
-            EstimateNetworkHashesPerSecond,
-            GetBalanceByAddress,
-            GetBalancesByAddresses,
-            GetBlock,
-            GetBlockCount,
-            GetBlockDagInfo,
-            GetBlocks,
-            GetBlockTemplate,
-            GetCurrentBlockColor,
-            GetCoinSupply,
-            GetConnectedPeerInfo,
-            GetConnections,
-            GetCurrentNetwork,
-            GetDaaScoreTimestampEstimate,
-            GetFeeEstimate,
-            GetFeeEstimateExperimental,
-            GetHeaders,
-            GetInfo,
-            GetMempoolEntries,
-            GetMempoolEntriesByAddresses,
-            GetMempoolEntry,
-            GetMetrics,
-            GetPeerAddresses,
-            GetServerInfo,
-            GetSink,
-            GetSinkBlueScore,
-            GetSubnetwork,
-            GetSyncStatus,
-            GetSystemInfo,
-            GetUtxosByAddresses,
-            GetVirtualChainFromBlock,
-            ResolveFinalityConflict,
-            Shutdown,
-            SubmitBlock,
-            SubmitTransaction,
-            SubmitTransactionReplacement,
-            Unban,
-        ]
-    );
+    with_rpc_methods!(build_wrpc_client_interface!(RpcApiOps,));
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
@@ -0,0 +1,276 @@
+//!
+//! TLS configuration of native wRPC client connections.
+//!
+//! The WebSocket transport of the native client connects over TLS verifying
+//! the server against the WebPKI roots only. Connections requiring a custom CA
+//! or a client certificate (mutual TLS) are established through a TLS bridge:
+//! a loopback listener opening the TLS connection with the [`WrpcTlsConfig`]
+//! and forwarding the WebSocket stream over it. The bridge serves a single
+//! connection whose request path carries a random nonce, so that other local
+//! processes can not reach the server through it with the client identity.
+//!
+
+use crate::error::Error;
+use crate::parse::{parse_host, Host};
+use crate::result::Result;
+use rustls::{crypto::ring::default_provider, pki_types::ServerName, ClientConfig, RootCertStore};
+use std::{sync::Arc, time::Duration};
+use tokio::{
+    io::{copy_bidirectional, AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    time::{timeout, Instant},
+};
+use tokio_rustls::TlsConnector;
+use workflow_log::log_warn;
+
+/// ALPN protocol of WebSocket (HTTP/1.1) connections
+const ALPN_HTTP1: &[u8] = b"http/1.1";
+
+/// Time allowed to the WebSocket for connecting to the bridge
+const TLS_BRIDGE_ACCEPT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Maximum size of the WebSocket upgrade request head read by the bridge
+const MAX_REQUEST_HEAD_SIZE: usize = 16 * 1024;
+
+/// TLS options of a wRPC client: custom CA certificates and a client identity for mutual TLS.
+#[derive(Debug, Clone, Default)]
+pub struct WrpcTlsConfig {
+    ca_certificate: Option<Vec<u8>>,
+    identity: Option<(Vec<u8>, Vec<u8>)>,
+}
+
+impl WrpcTlsConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Verifies the server certificate against the PEM encoded CA certificates instead of the WebPKI roots
+    pub fn with_ca_certificate(mut self, pem: impl AsRef<[u8]>) -> Self {
+        self.ca_certificate = Some(pem.as_ref().to_vec());
+        self
+    }
+
+    /// Presents the PEM encoded certificate chain and private key to servers requiring mutual TLS
+    pub fn with_identity(mut self, cert_pem: impl AsRef<[u8]>, key_pem: impl AsRef<[u8]>) -> Self {
+        self.identity = Some((cert_pem.as_ref().to_vec(), key_pem.as_ref().to_vec()));
+        self
+    }
+
+    pub(crate) fn client_config(&self) -> Result<Arc<ClientConfig>> {
+        let mut roots = RootCertStore::empty();
+        match self.ca_certificate.as_ref() {
+            Some(pem) => {
+                for cert in rustls_pemfile::certs(&mut pem.as_slice()) {
+                    roots.add(cert.map_err(Error::custom)?).map_err(Error::custom)?;
+                }
+                if roots.is_empty() {
+                    return Err(Error::custom("wRPC TLS: no CA certificate found"));
+                }
+            }
+            None => roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned()),
+        }
+        let builder = ClientConfig::builder_with_provider(Arc::new(default_provider()))
+            .with_safe_default_protocol_versions()
+            .map_err(Error::custom)?
+            .with_root_certificates(roots);
+        let mut config = match self.identity.as_ref() {
+            Some((cert_pem, key_pem)) => {
+                let certs =
+                    rustls_pemfile::certs(&mut cert_pem.as_slice()).collect::<std::io::Result<Vec<_>>>().map_err(Error::custom)?;
+                let key = rustls_pemfile::private_key(&mut key_pem.as_slice())
+                    .map_err(Error::custom)?
+                    .ok_or_else(|| Error::custom("wRPC TLS: no private key found in the client identity"))?;
+                builder.with_client_auth_cert(certs, key).map_err(Error::custom)?
+            }
+            None => builder.with_no_client_auth(),
+        };
+        config.alpn_protocols = vec![ALPN_HTTP1.to_vec()];
+        Ok(Arc::new(config))
+    }
+}
+
+/// Target of a bridged `wss://` url
+struct BridgeTarget {
+    server_name: ServerName<'static>,
+    address: String,
+    port: u16,
+    authority: String,
+    path: String,
+}
+
+impl BridgeTarget {
+    fn try_new(url: &str) -> Result<Self> {
+        let parsed = parse_host(url).map_err(|err| Error::UrlError(format!("{url}: {err}")))?;
+        let address = match &parsed.host {
+            Host::Ipv6(ip) => ip.to_string(),
+            host => host.to_string(),
+        };
+        let port = parsed.port.unwrap_or(443);
+        let server_name = ServerName::try_from(address.clone()).map_err(|err| Error::UrlError(format!("{url}: {err}")))?;
+        let authority = match parsed.port {
+            Some(port) => format!("{}:{port}", parsed.host),
+            None => parsed.host.to_string(),
+        };
+        let path = if parsed.path.starts_with('/') { parsed.path.to_string() } else { format!("/{}", parsed.path) };
+        Ok(Self { server_name, address, port, authority, path })
+    }
+
+    /// Rewrites the upgrade request head received on the bridge for the target server,
+    /// or returns `None` if the request path does not start with the bridge `nonce`
+    fn rewrite_request_head(&self, head: &str, nonce: &str) -> Option<String> {
+        let mut lines = head.split("\r\n");
+        let request_line = lines.next()?;
+        let (method, rest) = request_line.split_once(' ')?;
+        let rest = rest.strip_prefix('/')?.strip_prefix(nonce)?;
+        let mut rewritten = format!("{method} {rest}\r\n");
+        for line in lines {
+            if line.split_once(':').is_some_and(|(name, _)| name.trim().eq_ignore_ascii_case("host")) {
+                rewritten.push_str(&format!("Host: {}\r\n", self.authority));
+            } else {
+                rewritten.push_str(line);
+                rewritten.push_str("\r\n");
+            }
+        }
+        // `head` ends with an empty line, already restored by the loop above
+        rewritten.truncate(rewritten.len() - 2);
+        Some(rewritten)
+    }
+}
+
+/// Opens a TLS bridge to the `wss://` `url` and returns the loopback `ws://` url the WebSocket has to connect to
+pub(crate) async fn open_bridge(config: Arc<ClientConfig>, url: &str) -> Result<String> {
+    let target = BridgeTarget::try_new(url)?;
+    let listener = TcpListener::bind("127.0.0.1:0").await.map_err(Error::custom)?;
+    let bridge_address = listener.local_addr().map_err(Error::custom)?;
+    let nonce = format!("{:032x}", rand::random::<u128>());
+    let bridge_url = format!("ws://{bridge_address}/{nonce}{}", target.path);
+
+    tokio::spawn(async move {
+        let deadline = Instant::now() + TLS_BRIDGE_ACCEPT_TIMEOUT;
+        while let Ok(Ok((mut stream, _))) = timeout(deadline.saturating_duration_since(Instant::now()), listener.accept()).await {
+            let head = match timeout(deadline.saturating_duration_since(Instant::now()), read_request_head(&mut stream)).await {
+                Ok(Ok(head)) => head,
+                _ => continue,
+            };
+            // Connections not presenting the nonce did not originate from this client
+            let Some(head) = target.rewrite_request_head(&head, &nonce) else {
+                continue;
+            };
+            if let Err(err) = relay(config, &target, stream, head).await {
+                log_warn!("wRPC TLS connection to {}:{} failed: {err}", target.address, target.port);
+            }
+            break;
+        }
+    });
+
+    Ok(bridge_url)
+}
+
+async fn read_request_head(stream: &mut TcpStream) -> std::io::Result<String> {
+    let mut head = Vec::new();
+    let mut byte = [0u8; 1];
+    while !head.ends_with(b"\r\n\r\n") {
+        if head.len() >= MAX_REQUEST_HEAD_SIZE {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "request head too large"));
+        }
+        stream.read_exact(&mut byte).await?;
+        head.push(byte[0]);
+    }
+    String::from_utf8(head).map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+}
+
+async fn relay(config: Arc<ClientConfig>, target: &BridgeTarget, mut stream: TcpStream, head: String) -> std::io::Result<()> {
+    let server = TcpStream::connect((target.address.as_str(), target.port)).await?;
+    let mut server = TlsConnector::from(config).connect(target.server_name.clone(), server).await?;
+    server.write_all(head.as_bytes()).await?;
+    copy_bidirectional(&mut stream, &mut server).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustls::{
+        pki_types::{CertificateDer, PrivateKeyDer},
+        server::WebPkiClientVerifier,
+        ServerConfig,
+    };
+    use std::path::{Path, PathBuf};
+    use tokio_rustls::TlsAcceptor;
+
+    fn test_data(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../../service/test-data/tls").join(name)
+    }
+
+    fn read(name: &str) -> Vec<u8> {
+        std::fs::read(test_data(name)).unwrap()
+    }
+
+    fn certs(name: &str) -> Vec<CertificateDer<'static>> {
+        rustls_pemfile::certs(&mut read(name).as_slice()).collect::<std::io::Result<_>>().unwrap()
+    }
+
+    fn key(name: &str) -> PrivateKeyDer<'static> {
+        rustls_pemfile::private_key(&mut read(name).as_slice()).unwrap().unwrap()
+    }
+
+    /// Server requiring a client certificate signed by the test CA
+    fn acceptor() -> TlsAcceptor {
+        let provider = Arc::new(default_provider());
+        let mut roots = RootCertStore::empty();
+        roots.add_parsable_certificates(certs("ca.pem"));
+        let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider.clone()).build().unwrap();
+        let config = ServerConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_client_cert_verifier(verifier)
+            .with_single_cert(certs("server.pem"), key("server.key"))
+            .unwrap();
+        TlsAcceptor::from(Arc::new(config))
+    }
+
+    #[test]
+    fn test_wrpc_tls_config() {
+        assert!(WrpcTlsConfig::new().client_config().is_ok());
+        assert!(WrpcTlsConfig::new().with_ca_certificate(b"not a certificate").client_config().is_err());
+        assert!(WrpcTlsConfig::new().with_identity(read("client.pem"), b"").client_config().is_err());
+        let config = WrpcTlsConfig::new().with_ca_certificate(read("ca.pem")).with_identity(read("client.pem"), read("client.key"));
+        assert!(config.client_config().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_wrpc_tls_bridge() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = acceptor().accept(stream).await.unwrap();
+            let mut head = Vec::new();
+            while !head.ends_with(b"\r\n\r\n") {
+                head.push(stream.read_u8().await.unwrap());
+            }
+            stream.write_all(b"upgraded").await.unwrap();
+            String::from_utf8(head).unwrap()
+        });
+
+        let config = WrpcTlsConfig::new().with_ca_certificate(read("ca.pem")).with_identity(read("client.pem"), read("client.key"));
+        let bridge_url = open_bridge(config.client_config().unwrap(), &format!("wss://localhost:{port}/calico")).await.unwrap();
+        let (bridge_address, path) = bridge_url.strip_prefix("ws://").unwrap().split_once('/').unwrap();
+
+        // A local connection not knowing the nonce is not relayed
+        let mut intruder = TcpStream::connect(bridge_address).await.unwrap();
+        intruder.write_all(format!("GET /calico HTTP/1.1\r\nHost: {bridge_address}\r\n\r\n").as_bytes()).await.unwrap();
+        let mut buffer = Vec::new();
+        assert_eq!(intruder.read_to_end(&mut buffer).await.unwrap(), 0);
+
+        let mut client = TcpStream::connect(bridge_address).await.unwrap();
+        client
+            .write_all(format!("GET /{path} HTTP/1.1\r\nHost: {bridge_address}\r\nUpgrade: websocket\r\n\r\n").as_bytes())
+            .await
+            .unwrap();
+        let mut buffer = [0u8; 8];
+        client.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(&buffer, b"upgraded");
+        assert_eq!(server.await.unwrap(), format!("GET /calico HTTP/1.1\r\nHost: localhost:{port}\r\nUpgrade: websocket\r\n\r\n"));
+    }
+}
@@ -1,17 +1,19 @@
 mod error;
 mod result;
+mod status;
 
 use calico_consensus_core::network::NetworkType;
 use calico_rpc_core::api::ops::RpcApiOps;
 use calico_wrpc_server::{
     connection::Connection,
+    gateway::{Gateway, GatewayOptions},
     router::Router,
     server::Server,
     service::{CalicoRpcHandler, Options},
 };
 use clap::Parser;
 use result::Result;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use workflow_log::*;
 use workflow_rpc::server::prelude::*;
 use workflow_rpc::server::WebSocketCounters;
@@ -30,9 +32,19 @@ struct Args {
     #[clap(long)]
     devnet: bool,
 
-    /// proxy:port for gRPC server (grpc://127.0.0.1:22110)
+    /// proxy:port for gRPC servers of the backend nodes (grpc://127.0.0.1:22110)
     #[clap(name = "grpc")]
-    grpc_proxy_address: Option<String>,
+    grpc_proxy_addresses: Vec<String>,
+
+    /// Interval in seconds between two health checks of the backend nodes
+    #[clap(long, default_value_t = 5)]
+    health_check_interval: u64,
+    /// Maximum DAA score distance to the most advanced backend node for a node to keep receiving calls
+    #[clap(long, default_value_t = 600)]
+    max_daa_score_lag: u64,
+    /// interface:port for serving the gateway status over HTTP (127.0.0.1:23120)
+    #[clap(long)]
+    status: Option<String>,
 
     // /// wRPC port
     /// interface:port for wRPC server (wrpc://127.0.0.1:23110)
@@ -51,7 +63,19 @@ struct Args {
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let Args { testnet, simnet, devnet, grpc_proxy_address, interface, verbose, threads, encoding } = Args::parse();
+    let Args {
+        testnet,
+        simnet,
+        devnet,
+        grpc_proxy_addresses,
+        health_check_interval,
+        max_daa_score_lag,
+        status,
+        interface,
+        verbose,
+        threads,
+        encoding,
+    } = Args::parse();
 
     let network_type = if testnet {
         NetworkType::Testnet
@@ -71,19 +95,46 @@ async fn main() -> Result<()> {
         Encoding::SerdeJson => network_type.default_json_rpc_port(),
     };
 
+    let backends = match grpc_proxy_addresses.is_empty() {
+        true => vec![format!("grpc://127.0.0.1:{calicod_port}")],
+        false => grpc_proxy_addresses,
+    };
+    let gateway = Arc::new(Gateway::new(GatewayOptions {
+        backends,
+        network_type,
+        health_check_interval: Duration::from_secs(health_check_interval.max(1)),
+        max_daa_score_lag,
+    }));
+
     let options = Arc::new(Options {
         listen_address: interface.unwrap_or_else(|| format!("wrpc://127.0.0.1:{proxy_port}")),
-        grpc_proxy_address: Some(grpc_proxy_address.unwrap_or_else(|| format!("grpc://127.0.0.1:{calicod_port}"))),
+        gateway: Some(gateway.clone()),
         verbose,
+        authorizer: None,
+        tls: None,
+        notification_replay: None,
         // ..Options::default()
     });
     log_info!("");
-    log_info!("Proxy routing to `{}` on {}", network_type, options.grpc_proxy_address.as_ref().unwrap());
+    for backend in gateway.backends() {
+        log_info!("Proxy routing to `{}` on {}", network_type, backend.url());
+    }
+    gateway.start().await;
+    if let Some(status) = status {
+        let gateway = gateway.clone();
+        tokio::spawn(async move {
+            if let Err(err) = status::serve(status, gateway).await {
+                log_error!("Gateway status server error: {err}");
+            }
+        });
+    }
 
     let counters = Arc::new(WebSocketCounters::default());
     let tasks = threads.unwrap_or_else(num_cpus::get);
     let rpc_handler = Arc::new(CalicoRpcHandler::new(tasks, encoding, None, options.clone()));
 
+    rpc_handler.server.start();
+
     let router = Arc::new(Router::new(rpc_handler.server.clone()));
     let server = RpcServer::new_with_encoding::<Server, Connection, RpcApiOps, Id64>(
         encoding,
@@ -98,7 +149,10 @@ async fn main() -> Result<()> {
 
     let config = WebSocketConfig { max_message_size: Some(1024 * 1024 * 1024), ..Default::default() };
     let listener = server.bind(&options.listen_address).await?;
-    server.listen(listener, Some(config)).await?;
+    let result = server.listen(listener, Some(config)).await;
+    rpc_handler.server.stop();
+    gateway.stop();
+    result?;
 
     Ok(())
 }
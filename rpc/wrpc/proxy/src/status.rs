@@ -0,0 +1,51 @@
+use bytes::Bytes;
+use calico_wrpc_server::gateway::Gateway;
+use http_body_util::Full;
+use hyper::{
+    body::Incoming,
+    header::{HeaderValue, CONTENT_TYPE},
+    server::conn::http1,
+    service::service_fn,
+    Method, Request, Response, StatusCode,
+};
+use hyper_util::rt::TokioIo;
+use std::{convert::Infallible, sync::Arc};
+use tokio::net::TcpListener;
+use workflow_log::*;
+
+/// Serves the aggregated status of the gateway as JSON on `GET /status`.
+///
+/// The response status is `503 Service Unavailable` while no backend node is healthy,
+/// so that the endpoint can directly serve as a health probe.
+pub async fn serve(listen_address: String, gateway: Arc<Gateway>) -> std::io::Result<()> {
+    let listener = TcpListener::bind(&listen_address).await?;
+    log_info!("Gateway status is served on http://{listen_address}/status");
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let gateway = gateway.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |request| {
+                let gateway = gateway.clone();
+                async move { Ok::<_, Infallible>(handle(&gateway, request)) }
+            });
+            if let Err(err) = http1::Builder::new().serve_connection(TokioIo::new(stream), service).await {
+                log_trace!("Gateway status connection error with {peer}: {err}");
+            }
+        });
+    }
+}
+
+fn handle(gateway: &Gateway, request: Request<Incoming>) -> Response<Full<Bytes>> {
+    if request.method() != Method::GET || request.uri().path() != "/status" {
+        let mut response = Response::new(Full::default());
+        *response.status_mut() = StatusCode::NOT_FOUND;
+        return response;
+    }
+    let status = gateway.status();
+    let mut response = Response::new(Full::new(Bytes::from(serde_json::to_string(&status).unwrap())));
+    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    if status.healthy_backends == 0 {
+        *response.status_mut() = StatusCode::SERVICE_UNAVAILABLE;
+    }
+    response
+}
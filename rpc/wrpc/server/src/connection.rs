@@ -1,3 +1,4 @@
+use crate::gateway::Upstream;
 use calico_grpc_client::{GrpcClient, GrpcClientNotify};
 use calico_notify::{
     connection::Connection as ConnectionT,
@@ -7,9 +8,10 @@ use calico_notify::{
     notifier::Notify,
 };
 use calico_rpc_core::{api::ops::RpcApiOps, notify::mode::NotificationMode, Notification};
+use calico_rpc_service::{auth::RpcCredentials, rate_limit::RpcConnectionLimiter};
 use std::{
     fmt::{Debug, Display},
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, MutexGuard},
 };
 use workflow_log::log_trace;
 use workflow_rpc::{
@@ -48,9 +50,14 @@ struct ConnectionInner {
     pub id: u64,
     pub peer: SocketAddr,
     pub messenger: Arc<Messenger>,
-    pub grpc_client: Option<Arc<GrpcClient>>,
+    /// Backend node relaying the calls and notifications of the connection in gateway mode
+    pub upstream: Option<Mutex<Upstream>>,
+    pub credentials: Option<RpcCredentials>,
+    pub limiter: RpcConnectionLimiter,
     // not using an atomic in case an Id will change type in the future...
     pub listener_id: Mutex<Option<ListenerId>>,
+    /// Serializes the subscription changes relayed upstream with the failover of the connection to another backend
+    pub subscriptions: tokio::sync::Mutex<()>,
 }
 
 impl ConnectionInner {
@@ -83,12 +90,32 @@ pub struct Connection {
 }
 
 impl Connection {
-    pub fn new(id: u64, peer: &SocketAddr, messenger: Arc<Messenger>, grpc_client: Option<Arc<GrpcClient>>) -> Connection {
-        // If a GrpcClient is provided, it has to come configured in direct mode
-        assert!(grpc_client.is_none() || grpc_client.as_ref().unwrap().notification_mode() == NotificationMode::Direct);
-        // Should a gRPC client be provided, no listener_id is required for subscriptions so the listener id is set to default
-        let listener_id = Mutex::new(grpc_client.clone().map(|_| ListenerId::default()));
-        Connection { inner: Arc::new(ConnectionInner { id, peer: *peer, messenger, grpc_client, listener_id }) }
+    pub fn new(
+        id: u64,
+        peer: &SocketAddr,
+        messenger: Arc<Messenger>,
+        upstream: Option<Upstream>,
+        credentials: Option<RpcCredentials>,
+        limiter: RpcConnectionLimiter,
+    ) -> Connection {
+        // If an upstream is provided, its GrpcClient has to come configured in direct mode
+        assert!(upstream.as_ref().map_or(true, |upstream| upstream.client().notification_mode() == NotificationMode::Direct));
+        // Should an upstream be provided, no listener_id is required for subscriptions so the listener id is set to default
+        let listener_id = Mutex::new(upstream.as_ref().map(|_| ListenerId::default()));
+        let upstream = upstream.map(Mutex::new);
+        let subscriptions = tokio::sync::Mutex::new(());
+        Connection {
+            inner: Arc::new(ConnectionInner {
+                id,
+                peer: *peer,
+                messenger,
+                upstream,
+                credentials,
+                limiter,
+                listener_id,
+                subscriptions,
+            }),
+        }
     }
 
     /// Obtain the connection id
@@ -102,10 +129,28 @@ impl Connection {
     }
 
     pub fn grpc_client(&self) -> Arc<GrpcClient> {
+        self.upstream().client().clone()
+    }
+
+    pub fn upstream(&self) -> Upstream {
+        self.upstream_lock().clone()
+    }
+
+    /// Binds the connection to another backend node, returning the previous upstream
+    pub fn replace_upstream(&self, upstream: Upstream) -> Upstream {
+        std::mem::replace(&mut self.upstream_lock(), upstream)
+    }
+
+    /// Locks the subscriptions of the connection against a concurrent failover
+    pub async fn subscriptions_lock(&self) -> tokio::sync::MutexGuard<'_, ()> {
+        self.inner.subscriptions.lock().await
+    }
+
+    fn upstream_lock(&self) -> MutexGuard<'_, Upstream> {
         self.inner
-            .grpc_client
+            .upstream
             .as_ref()
-            .cloned()
+            .map(|upstream| upstream.lock().unwrap())
             .unwrap_or_else(|| panic!("Incorrect use: `server::Connection` does not carry RpcApi references"))
     }
 
@@ -125,6 +170,15 @@ impl Connection {
         &self.inner.peer
     }
 
+    /// The authenticated client identity (`None` if authorization is disabled)
+    pub fn credentials(&self) -> Option<&RpcCredentials> {
+        self.inner.credentials.as_ref()
+    }
+
+    pub fn limiter(&self) -> &RpcConnectionLimiter {
+        &self.inner.limiter
+    }
+
     /// Creates a WebSocket [`Message`] that can be posted to the connection ([`Messenger`]) sink
     /// directly.
     pub fn create_serialized_notification_message<Ops, Msg>(encoding: Encoding, op: Ops, msg: Msg) -> WrpcResult<Message>
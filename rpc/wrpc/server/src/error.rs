@@ -20,6 +20,18 @@ pub enum Error {
 
     #[error("Notify error: {0}")]
     NotifyError(#[from] NotifyError),
+
+    #[error("gRPC client error: {0}")]
+    GrpcClientError(#[from] calico_grpc_client::error::Error),
+
+    #[error("Backend node {0} is unreachable")]
+    BackendUnreachable(String),
+
+    #[error("No backend node is available")]
+    NoBackendAvailable,
+
+    #[error("server startup error: {0}")]
+    StartupError(String),
 }
 
 impl<T> From<PoisonError<T>> for Error {
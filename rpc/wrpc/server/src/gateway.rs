@@ -0,0 +1,429 @@
+//!
+//! Gateway mode of the wRPC server, relaying the clients to a pool of gRPC backend nodes.
+//!
+//! The gateway periodically checks the health of every backend with `get_server_info` and
+//! `get_sync_status`. A backend is healthy when it is reachable, on the expected network, synced
+//! and not lagging behind the most advanced backend by more than a configured DAA score distance.
+//!
+//! Connections are sticky: every wRPC connection is bound to the least loaded healthy backend,
+//! relaying both its RPC calls and its notifications, so that the replies a client reads are
+//! consistent with the notifications it receives. When this backend turns unhealthy, the
+//! connection is moved to another healthy backend and its active subscriptions are renewed there
+//! transparently.
+//!
+//! Calls are not routed per request: all the calls of a connection, reads included, go to the
+//! backend it is bound to, even if other healthy backends are less loaded at the time. The load
+//! is thus only balanced when connections are opened or failed over.
+//!
+
+use crate::{error::Error, result::Result};
+use calico_consensus_core::network::NetworkType;
+use calico_core::{debug, info, warn};
+use calico_grpc_client::GrpcClient;
+use calico_rpc_core::{api::rpc::RpcApi, notify::mode::NotificationMode};
+use calico_utils::triggers::SingleTrigger;
+use futures::future::join_all;
+use serde::Serialize;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::sync::Notify;
+
+/// Options for configuring the gateway mode
+#[derive(Clone, Debug)]
+pub struct GatewayOptions {
+    /// gRPC addresses of the backend nodes
+    pub backends: Vec<String>,
+    /// Network the backend nodes are expected to run on
+    pub network_type: NetworkType,
+    /// Interval between two health checks of the backend nodes
+    pub health_check_interval: Duration,
+    /// Maximum distance in DAA score to the most advanced backend node for a node to be considered synced
+    pub max_daa_score_lag: u64,
+}
+
+/// Health status of a backend node
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackendStatus {
+    pub url: String,
+    pub healthy: bool,
+    pub reachable: bool,
+    pub is_synced: bool,
+    pub network_id: Option<String>,
+    pub server_version: Option<String>,
+    pub virtual_daa_score: u64,
+    /// Number of wRPC connections bound to the node
+    pub connections: usize,
+    pub last_error: Option<String>,
+}
+
+/// Aggregated status of the gateway
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GatewayStatus {
+    pub healthy_backends: usize,
+    pub is_synced: bool,
+    pub virtual_daa_score: u64,
+    pub connections: usize,
+    pub backends: Vec<BackendStatus>,
+}
+
+/// A backend gRPC node of the gateway
+pub struct Backend {
+    url: String,
+    /// Client running the health checks
+    client: Mutex<Option<Arc<GrpcClient>>>,
+    status: Mutex<BackendStatus>,
+    connections: AtomicUsize,
+}
+
+impl Backend {
+    fn new(url: String) -> Self {
+        let status = BackendStatus { url: url.clone(), ..Default::default() };
+        Self { url, client: Mutex::new(None), status: Mutex::new(status), connections: AtomicUsize::new(0) }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn is_healthy(&self) -> bool {
+        self.status.lock().unwrap().healthy
+    }
+
+    fn is_reachable(&self) -> bool {
+        self.status.lock().unwrap().reachable
+    }
+
+    fn client(&self) -> Option<Arc<GrpcClient>> {
+        self.client.lock().unwrap().clone()
+    }
+
+    pub fn status(&self) -> BackendStatus {
+        BackendStatus { connections: self.connections.load(Ordering::Relaxed), ..self.status.lock().unwrap().clone() }
+    }
+
+    async fn connect(&self, reconnect: bool) -> Result<GrpcClient> {
+        Ok(GrpcClient::connect_with_args(
+            NotificationMode::Direct,
+            self.url.clone(),
+            None,
+            reconnect,
+            None,
+            true,
+            None,
+            Default::default(),
+            None,
+            None,
+        )
+        .await?)
+    }
+
+    /// Refreshes the health status of the node, not yet evaluating the DAA score lag
+    async fn check(&self, network_type: NetworkType) {
+        let status = match self.query(network_type).await {
+            Ok(status) => status,
+            Err(err) => BackendStatus { url: self.url.clone(), last_error: Some(err.to_string()), ..Default::default() },
+        };
+        // Health is only re-evaluated once all the nodes are checked
+        let mut current = self.status.lock().unwrap();
+        *current = BackendStatus { healthy: current.healthy, ..status };
+    }
+
+    async fn query(&self, network_type: NetworkType) -> Result<BackendStatus> {
+        let client = match self.client() {
+            Some(client) => client,
+            None => {
+                // The client reconnects by itself once created
+                let client = Arc::new(self.connect(true).await?);
+                client.start(None).await;
+                self.client.lock().unwrap().replace(client.clone());
+                client
+            }
+        };
+        if !client.is_connected() {
+            return Err(Error::BackendUnreachable(self.url.clone()));
+        }
+        let info = client.get_server_info().await?;
+        let is_synced = client.get_sync_status().await?;
+        let last_error = (info.network_id.network_type != network_type)
+            .then(|| format!("network mismatch: expected {network_type}, found {}", info.network_id));
+        Ok(BackendStatus {
+            url: self.url.clone(),
+            healthy: false,
+            reachable: true,
+            is_synced: is_synced && last_error.is_none(),
+            network_id: Some(info.network_id.to_string()),
+            server_version: Some(info.server_version),
+            virtual_daa_score: info.virtual_daa_score,
+            connections: 0,
+            last_error,
+        })
+    }
+}
+
+/// Binding of a wRPC connection to the backend node relaying its calls and notifications
+#[derive(Clone)]
+pub struct Upstream {
+    backend: Arc<Backend>,
+    client: Arc<GrpcClient>,
+}
+
+impl Upstream {
+    pub fn backend(&self) -> &Arc<Backend> {
+        &self.backend
+    }
+
+    pub fn client(&self) -> &Arc<GrpcClient> {
+        &self.client
+    }
+}
+
+/// Pool of backend gRPC nodes serving the clients of a wRPC server
+pub struct Gateway {
+    options: GatewayOptions,
+    backends: Vec<Arc<Backend>>,
+    checked: Notify,
+    shutdown: SingleTrigger,
+}
+
+impl Gateway {
+    pub fn new(options: GatewayOptions) -> Self {
+        assert!(!options.backends.is_empty(), "a gateway requires at least one backend node");
+        let backends = options.backends.iter().cloned().map(|url| Arc::new(Backend::new(url))).collect();
+        Self { options, backends, checked: Notify::new(), shutdown: Default::default() }
+    }
+
+    pub fn backends(&self) -> &[Arc<Backend>] {
+        &self.backends
+    }
+
+    /// Runs a first health check of the backend nodes and spawns a task repeating it periodically
+    pub async fn start(self: &Arc<Self>) {
+        self.check().await;
+        let this = self.clone();
+        tokio::spawn(async move {
+            let shutdown = this.shutdown.listener.clone();
+            tokio::pin!(shutdown);
+            loop {
+                tokio::select! {
+                    _ = tokio::time::sleep(this.options.health_check_interval) => this.check().await,
+                    _ = &mut shutdown => break,
+                }
+            }
+            debug!("gateway health check task exited");
+        });
+    }
+
+    pub fn stop(&self) {
+        self.shutdown.trigger.trigger();
+    }
+
+    /// Waits for the gateway to be stopped
+    pub async fn stopped(&self) {
+        self.shutdown.listener.clone().await
+    }
+
+    /// Waits for the completion of the next health check round
+    pub async fn checked(&self) {
+        self.checked.notified().await
+    }
+
+    async fn check(&self) {
+        join_all(self.backends.iter().map(|backend| backend.check(self.options.network_type))).await;
+        self.evaluate();
+        self.checked.notify_one();
+    }
+
+    /// Re-evaluates the health of the backend nodes from their last checked status
+    fn evaluate(&self) {
+        // A synced node lagging too far behind the most advanced one is actually still syncing
+        let best_daa_score = self
+            .backends
+            .iter()
+            .filter_map(|backend| {
+                let status = backend.status.lock().unwrap();
+                status.is_synced.then_some(status.virtual_daa_score)
+            })
+            .max()
+            .unwrap_or_default();
+        for backend in self.backends.iter() {
+            let mut status = backend.status.lock().unwrap();
+            let was_healthy = status.healthy;
+            status.healthy = status.is_synced && status.virtual_daa_score + self.options.max_daa_score_lag >= best_daa_score;
+            match (was_healthy, status.healthy) {
+                (false, true) => info!("Gateway backend {} is healthy (DAA score {})", backend.url, status.virtual_daa_score),
+                (true, false) => warn!(
+                    "Gateway backend {} is unhealthy: {}",
+                    backend.url,
+                    status.last_error.as_deref().unwrap_or(if status.reachable { "not synced" } else { "unreachable" })
+                ),
+                _ => {}
+            }
+        }
+    }
+
+    pub fn has_healthy_backend(&self) -> bool {
+        self.backends.iter().any(|backend| backend.is_healthy())
+    }
+
+    /// Binds a connection to the least loaded healthy backend node, or to a reachable one should no node be healthy.
+    ///
+    /// The upstream client still has to be started with the notification target of the connection.
+    pub async fn bind(&self) -> Result<Upstream> {
+        let backend = self.candidate().ok_or(Error::NoBackendAvailable)?;
+        // Reconnecting makes the client track its subscriptions, so that they can be renewed on another backend
+        let client = Arc::new(backend.connect(true).await?);
+        backend.connections.fetch_add(1, Ordering::Relaxed);
+        Ok(Upstream { backend, client })
+    }
+
+    fn candidate(&self) -> Option<Arc<Backend>> {
+        let least_loaded = |candidates: Vec<&Arc<Backend>>| {
+            candidates.into_iter().min_by_key(|backend| backend.connections.load(Ordering::Relaxed)).cloned()
+        };
+        least_loaded(self.backends.iter().filter(|backend| backend.is_healthy()).collect())
+            .or_else(|| least_loaded(self.backends.iter().filter(|backend| backend.is_reachable()).collect()))
+    }
+
+    /// Closes the upstream client of a connection
+    pub async fn release(&self, upstream: &Upstream) {
+        let _ = upstream.client.disconnect().await;
+        let _ = upstream.client.join().await;
+        upstream.backend.connections.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub fn status(&self) -> GatewayStatus {
+        let backends = self.backends.iter().map(|backend| backend.status()).collect::<Vec<_>>();
+        let healthy = backends.iter().filter(|status| status.healthy);
+        GatewayStatus {
+            healthy_backends: healthy.clone().count(),
+            is_synced: healthy.clone().next().is_some(),
+            virtual_daa_score: healthy.map(|status| status.virtual_daa_score).max().unwrap_or_default(),
+            connections: backends.iter().map(|status| status.connections).sum(),
+            backends,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gateway(backends: usize) -> Gateway {
+        Gateway::new(GatewayOptions {
+            backends: (0..backends).map(|i| format!("grpc://127.0.0.1:{}", 16110 + i)).collect(),
+            network_type: NetworkType::Simnet,
+            health_check_interval: Duration::from_secs(1),
+            max_daa_score_lag: 10,
+        })
+    }
+
+    fn set_status(backend: &Backend, reachable: bool, is_synced: bool, virtual_daa_score: u64) {
+        let mut status = backend.status.lock().unwrap();
+        *status = BackendStatus { reachable, is_synced, virtual_daa_score, healthy: status.healthy, ..status.clone() };
+    }
+
+    fn healthy(gateway: &Gateway) -> Vec<bool> {
+        gateway.backends().iter().map(|backend| backend.is_healthy()).collect()
+    }
+
+    #[test]
+    fn test_gateway_health_evaluation() {
+        let gateway = gateway(4);
+        let backends = gateway.backends();
+        assert_eq!(healthy(&gateway), vec![false; 4], "backends are unhealthy until checked");
+
+        set_status(&backends[0], true, true, 1000);
+        set_status(&backends[1], true, true, 990);
+        set_status(&backends[2], true, false, 1500);
+        set_status(&backends[3], false, false, 0);
+        gateway.evaluate();
+        // An unsynced node does not raise the best DAA score
+        assert_eq!(healthy(&gateway), vec![true, true, false, false]);
+
+        // A synced node lagging too far behind the most advanced one is unhealthy
+        set_status(&backends[2], true, true, 1010);
+        gateway.evaluate();
+        assert_eq!(healthy(&gateway), vec![true, false, true, false]);
+
+        set_status(&backends[0], false, false, 0);
+        set_status(&backends[2], false, false, 0);
+        gateway.evaluate();
+        assert_eq!(healthy(&gateway), vec![false, true, false, false], "the lagging node is the only synced one left");
+
+        set_status(&backends[1], true, false, 990);
+        gateway.evaluate();
+        assert!(!gateway.has_healthy_backend());
+    }
+
+    #[test]
+    fn test_gateway_binding_candidate() {
+        let gateway = gateway(3);
+        let backends = gateway.backends();
+        assert!(gateway.candidate().is_none(), "no backend is available until checked");
+
+        // Falls back to a reachable node when no node is healthy
+        set_status(&backends[2], true, false, 0);
+        gateway.evaluate();
+        assert_eq!(gateway.candidate().unwrap().url(), backends[2].url());
+
+        // Prefers the least loaded healthy node
+        set_status(&backends[0], true, true, 100);
+        set_status(&backends[1], true, true, 100);
+        gateway.evaluate();
+        backends[0].connections.store(2, Ordering::Relaxed);
+        backends[1].connections.store(1, Ordering::Relaxed);
+        assert_eq!(gateway.candidate().unwrap().url(), backends[1].url());
+        backends[1].connections.store(3, Ordering::Relaxed);
+        assert_eq!(gateway.candidate().unwrap().url(), backends[0].url());
+
+        // Turning unhealthy, the least loaded node is skipped
+        set_status(&backends[0], false, false, 0);
+        gateway.evaluate();
+        assert_eq!(gateway.candidate().unwrap().url(), backends[1].url());
+    }
+
+    #[test]
+    fn test_gateway_status() {
+        let gateway = gateway(3);
+        let backends = gateway.backends();
+        set_status(&backends[0], true, true, 100);
+        set_status(&backends[1], true, true, 105);
+        set_status(&backends[2], true, false, 200);
+        gateway.evaluate();
+        backends[0].connections.store(2, Ordering::Relaxed);
+        backends[2].connections.store(1, Ordering::Relaxed);
+
+        let status = gateway.status();
+        assert_eq!(status.healthy_backends, 2);
+        assert!(status.is_synced);
+        assert_eq!(status.virtual_daa_score, 105, "the DAA score of an unhealthy node is ignored");
+        assert_eq!(status.connections, 3);
+        assert_eq!(status.backends.iter().map(|backend| backend.connections).collect::<Vec<_>>(), vec![2, 0, 1]);
+
+        set_status(&backends[0], false, false, 0);
+        set_status(&backends[1], false, false, 0);
+        gateway.evaluate();
+        let status = gateway.status();
+        assert_eq!(status.healthy_backends, 0);
+        assert!(!status.is_synced);
+        assert_eq!(status.virtual_daa_score, 0);
+    }
+
+    #[tokio::test]
+    async fn test_gateway_stop() {
+        let gateway = Arc::new(gateway(1));
+        let stopped = tokio::spawn({
+            let gateway = gateway.clone();
+            async move { gateway.stopped().await }
+        });
+        gateway.stop();
+        tokio::time::timeout(Duration::from_secs(1), stopped).await.expect("the gateway stops").unwrap();
+    }
+}
@@ -3,7 +3,9 @@ pub mod address;
 pub mod collector;
 pub mod connection;
 pub mod error;
+pub mod gateway;
 pub mod result;
 pub mod router;
 pub mod server;
 pub mod service;
+pub mod tls;
@@ -1,6 +1,6 @@
 use crate::{connection::*, server::*};
 use calico_notify::scope::Scope;
-use calico_rpc_core::{api::ops::RpcApiOps, prelude::*};
+use calico_rpc_core::{api::ops::RpcApiOps, prelude::*, with_rpc_methods};
 use calico_rpc_macros::build_wrpc_server_interface;
 use std::sync::Arc;
 use workflow_rpc::server::prelude::*;
@@ -27,61 +27,16 @@ impl Router {
         // it will create the RPC method handler.
         // ... `GetInfo` yields: get_info_call() + GetInfoRequest + GetInfoResponse
         #[allow(unreachable_patterns)]
-        let mut interface = build_wrpc_server_interface!(
-            server_context.clone(),
-            Server,
-            Connection,
-            RpcApiOps,
-            [
-                Ping,
-                AddPeer,
-                Ban,
-                EstimateNetworkHashesPerSecond,
-                GetBalanceByAddress,
-                GetBalancesByAddresses,
-                GetBlock,
-                GetBlockCount,
-                GetBlockDagInfo,
-                GetBlocks,
-                GetBlockTemplate,
-                GetCurrentBlockColor,
-                GetCoinSupply,
-                GetConnectedPeerInfo,
-                GetCurrentNetwork,
-                GetDaaScoreTimestampEstimate,
-                GetFeeEstimate,
-                GetFeeEstimateExperimental,
-                GetHeaders,
-                GetInfo,
-                GetInfo,
-                GetMempoolEntries,
-                GetMempoolEntriesByAddresses,
-                GetMempoolEntry,
-                GetMetrics,
-                GetConnections,
-                GetPeerAddresses,
-                GetServerInfo,
-                GetSink,
-                GetSinkBlueScore,
-                GetSubnetwork,
-                GetSyncStatus,
-                GetSystemInfo,
-                GetUtxosByAddresses,
-                GetVirtualChainFromBlock,
-                ResolveFinalityConflict,
-                Shutdown,
-                SubmitBlock,
-                SubmitTransaction,
-                SubmitTransactionReplacement,
-                Unban,
-            ]
-        );
+        let mut interface = with_rpc_methods!(build_wrpc_server_interface!(server_context.clone(), Server, Connection, RpcApiOps,));
 
         interface.method(
             RpcApiOps::Subscribe,
             workflow_rpc::server::Method::new(move |manager: Server, connection: Connection, scope: Serializable<Scope>| {
                 Box::pin(async move {
-                    manager.start_notify(&connection, scope.into_inner()).await.map_err(|err| err.to_string())?;
+                    let scope = scope.into_inner();
+                    manager.authorize(&connection, RpcApiOps::subscription(scope.event_type())).map_err(|err| err.to_string())?;
+                    manager.admit(&connection, RpcApiOps::Subscribe, RpcApiOps::Subscribe.cost()).map_err(|err| err.to_string())?;
+                    manager.start_notify(&connection, scope).await.map_err(|err| err.to_string())?;
                     Ok(Serializable(SubscribeResponse::new(connection.id())))
                 })
             }),
@@ -91,7 +46,12 @@ impl Router {
             RpcApiOps::Unsubscribe,
             workflow_rpc::server::Method::new(move |manager: Server, connection: Connection, scope: Serializable<Scope>| {
                 Box::pin(async move {
-                    manager.stop_notify(&connection, scope.into_inner()).await.unwrap_or_else(|err| {
+                    let scope = scope.into_inner();
+                    manager.authorize(&connection, RpcApiOps::subscription(scope.event_type())).map_err(|err| err.to_string())?;
+                    manager
+                        .admit(&connection, RpcApiOps::Unsubscribe, RpcApiOps::Unsubscribe.cost())
+                        .map_err(|err| err.to_string())?;
+                    manager.stop_notify(&connection, scope).await.unwrap_or_else(|err| {
                         workflow_log::log_trace!("wRPC server -> error calling stop_notify(): {err}");
                     });
                     Ok(Serializable(UnsubscribeResponse {}))
@@ -1,31 +1,36 @@
 use crate::{
     collector::{WrpcServiceCollector, WrpcServiceConverter},
     connection::Connection,
+    gateway::Gateway,
     result::Result,
     service::Options,
 };
-use calico_grpc_client::GrpcClient;
 use calico_notify::{
     connection::ChannelType,
     events::EVENT_TYPE_ARRAY,
-    listener::ListenerLifespan,
+    listener::{ListenerId, ListenerLifespan},
     notifier::Notifier,
     scope::Scope,
     subscriber::Subscriber,
     subscription::{MutationPolicies, UtxosChangedMutationPolicy},
 };
 use calico_rpc_core::{
-    api::rpc::{DynRpcService, RpcApi},
-    notify::{channel::NotificationChannel, connection::ChannelConnection, mode::NotificationMode},
-    Notification, RpcResult,
+    api::{
+        ops::RpcApiOps,
+        rpc::{DynRpcService, RpcApi},
+    },
+    notify::{channel::NotificationChannel, connection::ChannelConnection},
+    Notification, RpcError, RpcResult,
 };
-use calico_rpc_service::service::RpcCoreService;
+use calico_rpc_service::{auth::RpcCredentials, rate_limit::RpcRateLimiter, service::RpcCoreService};
+use calico_utils::triggers::SingleTrigger;
 use std::{
     collections::HashMap,
     sync::{
         atomic::{AtomicU64, Ordering},
         Arc, Mutex,
     },
+    time::Duration,
 };
 use workflow_log::*;
 use workflow_rpc::server::prelude::*;
@@ -43,6 +48,8 @@ struct ServerInner {
     pub sockets: Mutex<HashMap<u64, Connection>>,
     pub rpc_core: Option<RpcCore>,
     pub options: Arc<Options>,
+    pub rate_limiter: Arc<RpcRateLimiter>,
+    pub shutdown: SingleTrigger,
 }
 
 #[derive(Clone)]
@@ -60,10 +67,13 @@ impl Server {
         // Either get a core service or be called from the proxy and rely each connection having its own gRPC client
         assert_eq!(
             core_service.is_none(),
-            options.grpc_proxy_address.is_some(),
-            "invalid setup: Server must exclusively get either a core service or a gRPC server address"
+            options.gateway.is_some(),
+            "invalid setup: Server must exclusively get either a core service or a gateway of gRPC servers"
         );
 
+        // The proxy does not limit the calls it relays to the gRPC server
+        let rate_limiter = core_service.as_ref().map(|service| service.rate_limiter()).unwrap_or_default();
+
         let rpc_core = if let Some(service) = core_service {
             // Prepare rpc service objects
             let notification_channel = NotificationChannel::default();
@@ -77,15 +87,27 @@ impl Server {
             let converter = Arc::new(WrpcServiceConverter::new());
             let collector = Arc::new(WrpcServiceCollector::new(WRPC_SERVER, notification_channel.receiver(), converter));
             let subscriber = Arc::new(Subscriber::new(WRPC_SERVER, enabled_events, service.notifier(), listener_id));
-            let wrpc_notifier = Arc::new(Notifier::new(
-                WRPC_SERVER,
-                enabled_events,
-                vec![collector],
-                vec![subscriber],
-                service.subscription_context(),
-                tasks,
-                policies,
-            ));
+            let wrpc_notifier = match options.notification_replay.clone() {
+                None => Notifier::new(
+                    WRPC_SERVER,
+                    enabled_events,
+                    vec![collector],
+                    vec![subscriber],
+                    service.subscription_context(),
+                    tasks,
+                    policies,
+                ),
+                Some(replay) => Notifier::with_replay(
+                    WRPC_SERVER,
+                    enabled_events,
+                    vec![collector],
+                    vec![subscriber],
+                    service.subscription_context(),
+                    policies,
+                    replay,
+                ),
+            };
+            let wrpc_notifier = Arc::new(wrpc_notifier);
             Some(RpcCore { service, wrpc_notifier })
         } else {
             None
@@ -98,6 +120,8 @@ impl Server {
                 sockets: Mutex::new(HashMap::new()),
                 rpc_core,
                 options,
+                rate_limiter,
+                shutdown: SingleTrigger::default(),
             }),
         }
     }
@@ -107,35 +131,92 @@ impl Server {
             // Start the internal notifier
             rpc_core.wrpc_notifier.clone().start();
         }
+        if let Some(gateway) = self.inner.options.gateway.clone() {
+            // Move the connections away from the backends found unhealthy by every health check
+            let server = self.clone();
+            tokio::spawn(async move {
+                let shutdown = server.inner.shutdown.listener.clone();
+                tokio::pin!(shutdown);
+                loop {
+                    tokio::select! {
+                        _ = gateway.checked() => server.failover(&gateway).await,
+                        _ = &mut shutdown => break,
+                        _ = gateway.stopped() => break,
+                    }
+                }
+                log_trace!("WebSocket failover task exited");
+            });
+        }
     }
 
-    pub async fn connect(&self, peer: &SocketAddr, messenger: Arc<Messenger>) -> Result<Connection> {
+    /// Signals the server tasks to exit
+    pub fn stop(&self) {
+        self.inner.shutdown.trigger.trigger();
+    }
+
+    async fn failover(&self, gateway: &Gateway) {
+        if !gateway.has_healthy_backend() {
+            return;
+        }
+        let connections = self
+            .inner
+            .sockets
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|connection| !connection.upstream().backend().is_healthy())
+            .cloned()
+            .collect::<Vec<_>>();
+        for connection in connections {
+            if let Err(err) = self.rebind(gateway, &connection).await {
+                log_warn!("WebSocket {} failover error: {err}", connection.peer());
+            }
+        }
+    }
+
+    /// Binds `connection` to another backend node, renewing its active subscriptions there
+    async fn rebind(&self, gateway: &Gateway, connection: &Connection) -> Result<()> {
+        // No subscription change may land between the snapshot of the active scopes and the swap of the upstream
+        let _subscriptions = connection.subscriptions_lock().await;
+        let upstream = gateway.bind().await?;
+        upstream.client().start(Some(connection.grpc_client_notify_target())).await;
+        for scope in connection.grpc_client().active_scopes().await {
+            if let Err(err) = upstream.client().start_notify(ListenerId::default(), scope).await {
+                gateway.release(&upstream).await;
+                return Err(err.into());
+            }
+        }
+        let previous = connection.replace_upstream(upstream);
+        log_info!(
+            "Routing wrpc://{} -> {} (failover from {})",
+            connection.peer(),
+            connection.upstream().backend().url(),
+            previous.backend().url()
+        );
+        gateway.release(&previous).await;
+        Ok(())
+    }
+
+    pub async fn connect(
+        &self,
+        peer: &SocketAddr,
+        messenger: Arc<Messenger>,
+        credentials: Option<RpcCredentials>,
+    ) -> Result<Connection> {
         // log_trace!("WebSocket connected: {}", peer);
         let id = self.inner.next_connection_id.fetch_add(1, Ordering::SeqCst);
 
-        let grpc_client = if let Some(grpc_proxy_address) = &self.inner.options.grpc_proxy_address {
+        let upstream = if let Some(gateway) = &self.inner.options.gateway {
             // Provider::GrpcClient
-
-            log_info!("Routing wrpc://{peer} -> {grpc_proxy_address}");
-            let grpc_client = GrpcClient::connect_with_args(
-                NotificationMode::Direct,
-                grpc_proxy_address.to_owned(),
-                None,
-                false,
-                None,
-                true,
-                None,
-                Default::default(),
-            )
-            .await
-            .map_err(|e| WebSocketError::Other(e.to_string()))?;
-            // log_trace!("Creating proxy relay...");
-            Some(Arc::new(grpc_client))
+            let upstream = gateway.bind().await.map_err(|e| WebSocketError::Other(e.to_string()))?;
+            log_info!("Routing wrpc://{peer} -> {}", upstream.backend().url());
+            Some(upstream)
         } else {
             None
         };
-        let connection = Connection::new(id, peer, messenger, grpc_client);
-        if self.inner.options.grpc_proxy_address.is_some() {
+        let limiter = self.inner.rate_limiter.connection(peer.ip());
+        let connection = Connection::new(id, peer, messenger, upstream, credentials, limiter);
+        if self.inner.options.gateway.is_some() {
             // log_trace!("starting gRPC");
             connection.grpc_client().start(Some(connection.grpc_client_notify_target())).await;
             // log_trace!("gRPC started...");
@@ -152,9 +233,8 @@ impl Server {
                     log_error!("WebSocket {} (disconnected) error unregistering the notification listener: {err}", connection.peer());
                 });
             }
-        } else {
-            let _ = connection.grpc_client().disconnect().await;
-            let _ = connection.grpc_client().join().await;
+        } else if let Some(gateway) = &self.inner.options.gateway {
+            gateway.release(&connection.upstream()).await;
         }
 
         self.inner.sockets.lock().unwrap().remove(&connection.id());
@@ -172,6 +252,7 @@ impl Server {
         if let Some(rpc_core) = &self.inner.rpc_core {
             rpc_core.service.clone()
         } else {
+            // Calls are relayed to the backend the connection is bound to, which also relays its notifications
             connection.grpc_client()
         }
     }
@@ -181,7 +262,7 @@ impl Server {
             listener_id
         } else {
             // The only possible case here is a server connected to rpc core.
-            // If the proxy is used, the connection has a gRPC client and the listener id
+            // If the proxy is used, the connection has an upstream gRPC client and the listener id
             // is always set to Some(ListenerId::default()) by the connection ctor.
             let notifier =
                 self.notifier().unwrap_or_else(|| panic!("Incorrect use: `server::Server` does not carry an internal notifier"));
@@ -193,6 +274,7 @@ impl Server {
         if let Some(rpc_core) = &self.inner.rpc_core {
             rpc_core.wrpc_notifier.clone().try_start_notify(listener_id, scope)?;
         } else {
+            let _subscriptions = connection.subscriptions_lock().await;
             connection.grpc_client().start_notify(listener_id, scope).await?;
         }
         Ok(())
@@ -204,6 +286,7 @@ impl Server {
             if let Some(rpc_core) = &self.inner.rpc_core {
                 rpc_core.wrpc_notifier.clone().try_stop_notify(listener_id, scope)?;
             } else {
+                let _subscriptions = connection.subscriptions_lock().await;
                 connection.grpc_client().stop_notify(listener_id, scope).await?;
             }
         } else {
@@ -216,6 +299,25 @@ impl Server {
         self.inner.options.verbose
     }
 
+    /// Checks that the `connection` client is allowed to call `method`
+    pub fn authorize(&self, connection: &Connection, method: RpcApiOps) -> RpcResult<()> {
+        match (self.inner.options.authorizer.as_ref(), connection.credentials()) {
+            (Some(authorizer), Some(credentials)) => authorizer.authorize(credentials, connection, method),
+            (Some(_), None) => Err(RpcError::AuthenticationFailed),
+            (None, _) => Ok(()),
+        }
+    }
+
+    /// Charges a call to `method` costing `cost` to the `connection` rate limits
+    pub fn admit(&self, connection: &Connection, method: RpcApiOps, cost: u64) -> RpcResult<()> {
+        self.inner.rate_limiter.admit(connection.limiter(), connection, method, cost)
+    }
+
+    /// Records a completed call to `method`
+    pub fn record(&self, method: RpcApiOps, cost: u64, latency: Duration) {
+        self.inner.rate_limiter.record(method, cost, latency)
+    }
+
     pub async fn join(&self) -> Result<()> {
         if let Some(rpc_core) = &self.inner.rpc_core {
             // Wait for the internal notifier to stop
@@ -1,33 +1,60 @@
-use crate::{connection::*, router::*, server::*};
+use crate::{connection::*, error::Error, gateway::Gateway, result::Result, router::*, server::*, tls::TlsRelay};
 use async_trait::async_trait;
 use calico_core::{
-    info,
+    error, info,
     task::service::{AsyncService, AsyncServiceError, AsyncServiceFuture},
     trace, warn,
 };
+use calico_notify::replay::ReplayOptions;
 use calico_rpc_core::api::ops::RpcApiOps;
-use calico_rpc_service::service::RpcCoreService;
+use calico_rpc_service::{
+    auth::{RpcAuthorizer, RpcCredentials},
+    service::RpcCoreService,
+    tls::RpcTlsConfig,
+};
 use calico_utils::triggers::SingleTrigger;
-use std::sync::Arc;
+use futures::{SinkExt, StreamExt};
+use std::{sync::Arc, time::Duration};
 use tokio::sync::oneshot::{channel as oneshot_channel, Sender as OneshotSender};
+use tokio::time::timeout;
 use workflow_rpc::server::prelude::*;
 pub use workflow_rpc::server::{Encoding as WrpcEncoding, WebSocketConfig, WebSocketCounters};
 
 static MAX_WRPC_MESSAGE_SIZE: usize = 1024 * 1024 * 128; // 128MB
 
+/// Listening address of the wRPC server behind the TLS relay
+const TLS_RELAY_TARGET_ADDRESS: &str = "127.0.0.1:0";
+
 /// Options for configuring the wRPC server
 pub struct Options {
     pub listen_address: String,
-    pub grpc_proxy_address: Option<String>,
+    /// Pool of gRPC backend nodes the server relays its clients to, in place of a core service
+    pub gateway: Option<Arc<Gateway>>,
     pub verbose: bool,
+    /// Authorizer of RPC method calls (all calls are allowed if `None`)
+    pub authorizer: Option<Arc<RpcAuthorizer>>,
+    /// TLS termination config (plaintext connections if `None`)
+    pub tls: Option<Arc<RpcTlsConfig>>,
+    /// Retention of the notifications for clients resuming a subscription (replay disabled if `None`)
+    pub notification_replay: Option<ReplayOptions>,
 }
 
 impl Default for Options {
     fn default() -> Self {
-        Options { listen_address: "127.0.0.1:23110".to_owned(), verbose: false, grpc_proxy_address: None }
+        Options {
+            listen_address: "127.0.0.1:23110".to_owned(),
+            verbose: false,
+            gateway: None,
+            authorizer: None,
+            tls: None,
+            notification_replay: None,
+        }
     }
 }
 
+/// Time allowed to a client for sending its API key after connecting
+const AUTHENTICATION_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// ### CalicoRpcHandler
 ///
 /// [`CalicoRpcHandler`] is a handler struct that implements the [`RpcHandler`] trait
@@ -46,6 +73,7 @@ impl Default for Options {
 pub struct CalicoRpcHandler {
     pub server: Server,
     pub options: Arc<Options>,
+    pub tls_relay: Option<Arc<TlsRelay>>,
 }
 
 impl CalicoRpcHandler {
@@ -55,7 +83,38 @@ impl CalicoRpcHandler {
         core_service: Option<Arc<RpcCoreService>>,
         options: Arc<Options>,
     ) -> CalicoRpcHandler {
-        CalicoRpcHandler { server: Server::new(tasks, encoding, core_service, options.clone()), options }
+        let tls_relay = options.tls.clone().map(|tls| Arc::new(TlsRelay::new(tls)));
+        CalicoRpcHandler { server: Server::new(tasks, encoding, core_service, options.clone()), options, tls_relay }
+    }
+}
+
+impl CalicoRpcHandler {
+    /// Authenticates the client by the API key sent as the first WebSocket text
+    /// message (an empty message for anonymous clients). The server acknowledges
+    /// a successful authentication by replying with an `ok` text message.
+    async fn authenticate(
+        authorizer: &RpcAuthorizer,
+        peer: &SocketAddr,
+        sender: &mut WebSocketSender,
+        receiver: &mut WebSocketReceiver,
+    ) -> WebSocketResult<RpcCredentials> {
+        let token = match timeout(AUTHENTICATION_TIMEOUT, receiver.next()).await {
+            Ok(Some(Ok(Message::Text(token)))) => token,
+            _ => {
+                warn!("wRPC, refusing connection from {peer} - no API key received");
+                return Err(WebSocketError::NegotiationFailure);
+            }
+        };
+        match authorizer.authenticate(Some(token.trim())) {
+            Ok(credentials) => {
+                sender.send(Message::Text("ok".into())).await?;
+                Ok(credentials)
+            }
+            Err(err) => {
+                warn!("wRPC, refusing connection from {peer} - {err}");
+                Err(WebSocketError::NegotiationFailure)
+            }
+        }
     }
 }
 
@@ -66,8 +125,8 @@ impl RpcHandler for CalicoRpcHandler {
     async fn handshake(
         self: Arc<Self>,
         peer: &SocketAddr,
-        _sender: &mut WebSocketSender,
-        _receiver: &mut WebSocketReceiver,
+        sender: &mut WebSocketSender,
+        receiver: &mut WebSocketReceiver,
         messenger: Arc<Messenger>,
     ) -> WebSocketResult<Connection> {
         // TODO - discuss and implement handshake
@@ -79,7 +138,22 @@ impl RpcHandler for CalicoRpcHandler {
         // )
         // .await
 
-        let connection = self.server.connect(peer, messenger).await.map_err(|err| err.to_string())?;
+        // Connections terminated by the TLS relay are reported with the actual client address.
+        // Any other connection to the loopback port of the relay would bypass TLS and is refused.
+        let peer = &match self.tls_relay.as_ref() {
+            Some(relay) => relay.peer(peer).ok_or_else(|| {
+                warn!("wRPC, refusing connection from {peer} - not received through the TLS relay");
+                WebSocketError::NegotiationFailure
+            })?,
+            None => *peer,
+        };
+
+        let credentials = match self.options.authorizer.as_ref() {
+            Some(authorizer) => Some(Self::authenticate(authorizer, peer, sender, receiver).await?),
+            None => None,
+        };
+
+        let connection = self.server.connect(peer, messenger, credentials).await.map_err(|err| err.to_string())?;
         Ok(connection)
     }
 
@@ -129,37 +203,46 @@ impl WrpcService {
         WrpcService { options, server, rpc_handler, shutdown: SingleTrigger::default() }
     }
 
-    /// Start listening on the configured address (will panic if the socket listen() fails)
-    pub fn serve(self: Arc<Self>) -> OneshotSender<()> {
-        let (termination_sender, termination_receiver) = oneshot_channel::<()>();
+    /// Start listening on the configured address, reporting bind and TLS setup errors to the caller
+    pub async fn serve(self: Arc<Self>) -> Result<OneshotSender<()>> {
         let listen_address = self.options.listen_address.clone();
+        // With TLS, the server listens on a loopback port receiving the connections terminated by the relay
+        let bind_address = if self.rpc_handler.tls_relay.is_some() { TLS_RELAY_TARGET_ADDRESS } else { listen_address.as_str() };
+        let listener =
+            self.server.bind(bind_address).await.map_err(|err| Error::StartupError(format!("bind error on {bind_address}: {err}")))?;
+        if let Some(relay) = self.rpc_handler.tls_relay.clone() {
+            let target = listener.local_addr().map_err(|err| Error::StartupError(format!("TLS relay error: {err}")))?;
+            relay
+                .start(&listen_address, target)
+                .await
+                .map_err(|err| Error::StartupError(format!("TLS error on {listen_address}: {err}")))?;
+        }
+
+        let (termination_sender, termination_receiver) = oneshot_channel::<()>();
         self.rpc_handler.server.start();
 
         // Spawn a task stopping the server on termination signal
         let service = self.clone();
         tokio::spawn(async move {
             let _ = termination_receiver.await;
+            if let Some(relay) = service.rpc_handler.tls_relay.as_ref() {
+                relay.shutdown();
+            }
             service.server.stop().unwrap_or_else(|err| warn!("wRPC unable to signal shutdown: `{err}`"));
             service.server.join().await.unwrap_or_else(|err| warn!("wRPC error: `{err}"));
         });
 
         // Spawn a task running the server
-        info!("WRPC Server starting on: {}", listen_address);
+        info!("WRPC Server starting on: {}{}", listen_address, if self.options.tls.is_some() { " (TLS)" } else { "" });
         tokio::spawn(async move {
             let config = WebSocketConfig { max_message_size: Some(MAX_WRPC_MESSAGE_SIZE), ..Default::default() };
-            match self.server.bind(&listen_address).await {
-                Ok(listener) => {
-                    let serve_result = self.server.listen(listener, Some(config)).await;
-                    match serve_result {
-                        Ok(_) => info!("WRPC Server stopped on: {}", listen_address),
-                        Err(err) => panic!("WRPC Server {listen_address} stopped with error: {err:?}"),
-                    }
-                }
-                Err(err) => panic!("WRPC Server bind error on {listen_address}: {err:?}"),
+            match self.server.listen(listener, Some(config)).await {
+                Ok(_) => info!("WRPC Server stopped on: {}", listen_address),
+                Err(err) => error!("WRPC Server {listen_address} stopped with error: {err:?}"),
             }
         });
 
-        termination_sender
+        Ok(termination_sender)
     }
 }
 
@@ -176,13 +259,15 @@ impl AsyncService for WrpcService {
         // Prepare a shutdown signal receiver
         let shutdown_signal = self.shutdown.listener.clone();
 
-        // Run the server
-        trace!("{} running the wRPC server", WRPC_SERVER);
-        let terminate_server = self.clone().serve();
-
         Box::pin(async move {
+            // Run the server
+            trace!("{} running the wRPC server", WRPC_SERVER);
+            let terminate_server =
+                self.clone().serve().await.map_err(|err| AsyncServiceError::Service(format!("{} {}", WRPC_SERVER, err)))?;
+
             // Keep the gRPC server running until a service shutdown signal is received
             shutdown_signal.await;
+            self.rpc_handler.server.stop();
 
             // Wait for the notifier to shutdown
             self.clone()
@@ -211,3 +296,31 @@ impl AsyncService for WrpcService {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gateway::GatewayOptions;
+    use calico_consensus_core::network::NetworkType;
+
+    #[tokio::test]
+    async fn test_serve_bind_error() {
+        let busy_listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let gateway = Gateway::new(GatewayOptions {
+            backends: vec!["grpc://127.0.0.1:16110".to_owned()],
+            network_type: NetworkType::Simnet,
+            health_check_interval: Duration::from_secs(1),
+            max_daa_score_lag: 10,
+        });
+        let options = Options {
+            listen_address: busy_listener.local_addr().unwrap().to_string(),
+            gateway: Some(Arc::new(gateway)),
+            ..Default::default()
+        };
+        let service = Arc::new(WrpcService::new(1, None, &WrpcEncoding::Borsh, Default::default(), options));
+
+        // Binding an address already in use is reported to the caller
+        let result = service.serve().await;
+        assert!(matches!(result, Err(Error::StartupError(_))), "the bind error should be reported to the caller");
+    }
+}
@@ -0,0 +1,93 @@
+//!
+//! TLS termination of wRPC connections.
+//!
+//! The WebSocket server only accepts plain TCP streams, so TLS connections are
+//! terminated by a relay forwarding the decrypted streams to the wRPC server
+//! listening on an ephemeral loopback port. The relay keeps track of the
+//! connections it opened so that they are reported with their actual client
+//! address, and so that connections reaching the loopback port without going
+//! through the relay (bypassing TLS) can be refused.
+//!
+
+use calico_core::{debug, trace};
+use calico_rpc_service::tls::{RpcTlsConfig, ALPN_HTTP1};
+use calico_utils::triggers::SingleTrigger;
+use std::{
+    collections::HashMap,
+    io::{Error, Result},
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+use tokio::{
+    io::copy_bidirectional,
+    net::{TcpListener, TcpStream},
+};
+use tokio_rustls::server::TlsStream;
+
+const WRPC_TLS: &str = "wrpc-tls";
+
+pub struct TlsRelay {
+    tls: Arc<RpcTlsConfig>,
+    /// Client addresses of the relayed connections, keyed by the relay side address
+    peers: Mutex<HashMap<SocketAddr, SocketAddr>>,
+    shutdown: SingleTrigger,
+}
+
+impl TlsRelay {
+    pub fn new(tls: Arc<RpcTlsConfig>) -> Self {
+        Self { tls, peers: Default::default(), shutdown: Default::default() }
+    }
+
+    /// Client address of a connection coming from `peer`, or `None` if the connection was not opened by the relay
+    pub fn peer(&self, peer: &SocketAddr) -> Option<SocketAddr> {
+        self.peers.lock().unwrap().get(peer).copied()
+    }
+
+    /// Sets up TLS and binds `listen_address`, then relays the TLS connections accepted there to the wRPC server
+    /// listening on `target` until shutdown. Setup errors are reported to the caller.
+    pub async fn start(self: Arc<Self>, listen_address: &str, target: SocketAddr) -> Result<()> {
+        let acceptor = self.tls.acceptor(&[ALPN_HTTP1]).map_err(Error::other)?;
+        let listener = TcpListener::bind(listen_address).await?;
+        let mut incoming = self.tls.accept(WRPC_TLS, listener, acceptor);
+        let shutdown_signal = self.shutdown.listener.clone();
+        tokio::spawn(async move {
+            tokio::pin!(shutdown_signal);
+            loop {
+                tokio::select! {
+                    stream = incoming.recv() => match stream {
+                        Some(stream) => {
+                            tokio::spawn(self.clone().relay(stream, target));
+                        }
+                        None => break,
+                    },
+                    _ = &mut shutdown_signal => break,
+                }
+            }
+            trace!("{} relay exited", WRPC_TLS);
+        });
+        Ok(())
+    }
+
+    async fn relay(self: Arc<Self>, mut tls_stream: TlsStream<TcpStream>, target: SocketAddr) {
+        let Ok(peer) = tls_stream.get_ref().0.peer_addr() else {
+            return;
+        };
+        let mut stream = match TcpStream::connect(target).await {
+            Ok(stream) => stream,
+            Err(err) => {
+                debug!("{} unable to relay the connection from {}: {}", WRPC_TLS, peer, err);
+                return;
+            }
+        };
+        let Ok(local) = stream.local_addr() else {
+            return;
+        };
+        self.peers.lock().unwrap().insert(local, peer);
+        let _ = copy_bidirectional(&mut tls_stream, &mut stream).await;
+        self.peers.lock().unwrap().remove(&local);
+    }
+
+    pub fn shutdown(&self) {
+        self.shutdown.trigger.trigger();
+    }
+}
@@ -59,6 +59,11 @@ declare! {
          * `networkId` is required when using a resolver.
          */
         networkId?: NetworkId | string;
+        /**
+         * API key presented to nodes that require RPC authentication.
+         * An empty string requests anonymous access.
+         */
+        authToken?: string;
     }
     "#,
 }
@@ -68,11 +73,12 @@ pub struct RpcConfig {
     pub url: Option<String>,
     pub encoding: Option<Encoding>,
     pub network_id: Option<NetworkId>,
+    pub auth_token: Option<String>,
 }
 
 impl Default for RpcConfig {
     fn default() -> Self {
-        RpcConfig { url: None, encoding: Some(Encoding::Borsh), network_id: None, resolver: None }
+        RpcConfig { url: None, encoding: Some(Encoding::Borsh), network_id: None, resolver: None, auth_token: None }
     }
 }
 
@@ -83,12 +89,13 @@ impl TryFrom<IRpcConfig> for RpcConfig {
         let url = config.try_get_string("url")?;
         let encoding = config.try_get::<Encoding>("encoding")?;
         let network_id = config.try_get::<NetworkId>("networkId")?;
+        let auth_token = config.try_get_string("authToken")?;
 
         if resolver.is_some() && network_id.is_none() {
             return Err(Error::custom("networkId is required when using a resolver"));
         }
 
-        Ok(RpcConfig { resolver, url, encoding, network_id })
+        Ok(RpcConfig { resolver, url, encoding, network_id, auth_token })
     }
 }
 
@@ -100,6 +107,7 @@ impl TryFrom<RpcConfig> for IRpcConfig {
         object.set("url", &config.url.into())?;
         object.set("encoding", &config.encoding.into())?;
         object.set("networkId", &config.network_id.into())?;
+        object.set("authToken", &config.auth_token.into())?;
         Ok(object)
     }
 }
@@ -279,7 +287,7 @@ cfg_if! {
 
 impl RpcClient {
     pub fn new(config: Option<RpcConfig>) -> Result<RpcClient> {
-        let RpcConfig { resolver, url, encoding, network_id } = config.unwrap_or_default();
+        let RpcConfig { resolver, url, encoding, network_id, auth_token } = config.unwrap_or_default();
 
         let encoding = encoding.unwrap_or(Encoding::Borsh);
 
@@ -299,6 +307,7 @@ impl RpcClient {
             CalicoRpcClient::new(encoding, url.as_deref(), resolver.clone().map(Into::into), network_id, None)
                 .unwrap_or_else(|err| panic!("{err}")),
         );
+        client.set_auth_token(auth_token.as_deref())?;
 
         let rpc_client = RpcClient {
             inner: Arc::new(Inner {
@@ -699,7 +708,7 @@ impl RpcClient {
                                     let notification_event = NotificationEvent::Notification(event_type);
                                     if let Some(handlers) = this.inner.notification_callbacks(notification_event) {
 
-                                        let UtxosChangedNotification { added, removed } = utxos_changed_notification;
+                                        let UtxosChangedNotification { added, removed, .. } = utxos_changed_notification;
                                         let added = js_sys::Array::from_iter(added.iter().map(UtxoEntryReference::from).map(JsValue::from));
                                         let removed = js_sys::Array::from_iter(removed.iter().map(UtxoEntryReference::from).map(JsValue::from));
                                         let notification = Object::new();
@@ -820,7 +829,7 @@ impl RpcClient {
     pub async fn subscribe_utxos_changed(&self, addresses: AddressOrStringArrayT) -> Result<()> {
         if let Some(listener_id) = self.listener_id() {
             let addresses: Vec<Address> = addresses.try_into()?;
-            self.inner.client.start_notify(listener_id, Scope::UtxosChanged(UtxosChangedScope { addresses })).await?;
+            self.inner.client.start_notify(listener_id, Scope::UtxosChanged(UtxosChangedScope::new(addresses))).await?;
         } else {
             log_error!("RPC subscribe on a closed connection");
         }
@@ -834,7 +843,77 @@ impl RpcClient {
     pub async fn unsubscribe_utxos_changed(&self, addresses: AddressOrStringArrayT) -> Result<()> {
         if let Some(listener_id) = self.listener_id() {
             let addresses: Vec<Address> = addresses.try_into()?;
-            self.inner.client.stop_notify(listener_id, Scope::UtxosChanged(UtxosChangedScope { addresses })).await?;
+            self.inner.client.stop_notify(listener_id, Scope::UtxosChanged(UtxosChangedScope::new(addresses))).await?;
+        } else {
+            log_error!("RPC unsubscribe on a closed connection");
+        }
+        Ok(())
+    }
+
+    /// Subscribe for a mempool transaction added notification event.
+    /// Mempool transaction added notification event is produced when a
+    /// transaction is accepted into the mempool. The event notification
+    /// will be scoped to the provided list of addresses, an empty list
+    /// selecting all transactions.
+    #[wasm_bindgen(js_name = subscribeMempoolTransactionAdded)]
+    pub async fn subscribe_mempool_transaction_added(&self, addresses: AddressOrStringArrayT) -> Result<()> {
+        if let Some(listener_id) = self.listener_id() {
+            let addresses: Vec<Address> = addresses.try_into()?;
+            self.inner
+                .client
+                .start_notify(listener_id, Scope::MempoolTransactionAdded(MempoolTransactionAddedScope::new(addresses)))
+                .await?;
+        } else {
+            log_error!("RPC subscribe on a closed connection");
+        }
+        Ok(())
+    }
+
+    /// Unsubscribe from mempool transaction added notification event
+    /// for a specific set of addresses.
+    #[wasm_bindgen(js_name = unsubscribeMempoolTransactionAdded)]
+    pub async fn unsubscribe_mempool_transaction_added(&self, addresses: AddressOrStringArrayT) -> Result<()> {
+        if let Some(listener_id) = self.listener_id() {
+            let addresses: Vec<Address> = addresses.try_into()?;
+            self.inner
+                .client
+                .stop_notify(listener_id, Scope::MempoolTransactionAdded(MempoolTransactionAddedScope::new(addresses)))
+                .await?;
+        } else {
+            log_error!("RPC unsubscribe on a closed connection");
+        }
+        Ok(())
+    }
+
+    /// Subscribe for a mempool transaction removed notification event.
+    /// Mempool transaction removed notification event is produced when a
+    /// transaction leaves the mempool, along with the reason of its removal.
+    /// The event notification will be scoped to the provided list of addresses,
+    /// an empty list selecting all transactions.
+    #[wasm_bindgen(js_name = subscribeMempoolTransactionRemoved)]
+    pub async fn subscribe_mempool_transaction_removed(&self, addresses: AddressOrStringArrayT) -> Result<()> {
+        if let Some(listener_id) = self.listener_id() {
+            let addresses: Vec<Address> = addresses.try_into()?;
+            self.inner
+                .client
+                .start_notify(listener_id, Scope::MempoolTransactionRemoved(MempoolTransactionRemovedScope::new(addresses)))
+                .await?;
+        } else {
+            log_error!("RPC subscribe on a closed connection");
+        }
+        Ok(())
+    }
+
+    /// Unsubscribe from mempool transaction removed notification event
+    /// for a specific set of addresses.
+    #[wasm_bindgen(js_name = unsubscribeMempoolTransactionRemoved)]
+    pub async fn unsubscribe_mempool_transaction_removed(&self, addresses: AddressOrStringArrayT) -> Result<()> {
+        if let Some(listener_id) = self.listener_id() {
+            let addresses: Vec<Address> = addresses.try_into()?;
+            self.inner
+                .client
+                .stop_notify(listener_id, Scope::MempoolTransactionRemoved(MempoolTransactionRemovedScope::new(addresses)))
+                .await?;
         } else {
             log_error!("RPC unsubscribe on a closed connection");
         }
@@ -851,7 +930,7 @@ impl RpcClient {
         if let Some(listener_id) = self.listener_id() {
             self.inner
                 .client
-                .start_notify(listener_id, Scope::VirtualChainChanged(VirtualChainChangedScope { include_accepted_transaction_ids }))
+                .start_notify(listener_id, Scope::VirtualChainChanged(VirtualChainChangedScope::new(include_accepted_transaction_ids)))
                 .await?;
         } else {
             log_error!("RPC subscribe on a closed connection");
@@ -867,7 +946,7 @@ impl RpcClient {
         if let Some(listener_id) = self.listener_id() {
             self.inner
                 .client
-                .stop_notify(listener_id, Scope::VirtualChainChanged(VirtualChainChangedScope { include_accepted_transaction_ids }))
+                .stop_notify(listener_id, Scope::VirtualChainChanged(VirtualChainChangedScope::new(include_accepted_transaction_ids)))
                 .await?;
         } else {
             log_error!("RPC unsubscribe on a closed connection");
@@ -881,6 +960,8 @@ build_wrpc_wasm_bindgen_subscriptions!([
     // Manually implemented subscriptions (above)
     // - VirtualChainChanged, // can't used this here due to non-C-style enum variant
     // - UtxosChanged, // can't used this here due to non-C-style enum variant
+    // - MempoolTransactionAdded, // can't used this here due to non-C-style enum variant
+    // - MempoolTransactionRemoved, // can't used this here due to non-C-style enum variant
     // - VirtualDaaScoreChanged,
     /// Manage subscription for a block added notification event.
     /// Block added notification event is produced when a new
@@ -907,6 +988,14 @@ build_wrpc_wasm_bindgen_subscriptions!([
     /// New block template notification event is produced when a new block
     /// template is generated for mining in the Calico BlockDAG.
     NewBlockTemplate,
+    /// Manage subscription for a database checkpoint notification event.
+    /// Database checkpoint notification event is produced when a checkpoint
+    /// of the node databases starts, progresses, completes or fails.
+    DatabaseCheckpoint,
+    /// Manage subscription for a consensus audit violation notification event.
+    /// Consensus audit violation notification event is produced when the consensus
+    /// audit mode of the node finds a block violating a consensus invariant.
+    ConsensusAuditViolation,
 ]);
 
 // Build RPC method invocation functions. This macro
@@ -969,6 +1058,10 @@ build_wrpc_wasm_bindgen_interface!(
         /// Gracefully shuts down the Calico node.
         /// Returned information: None.
         Shutdown,
+        /// Starts creating a checkpoint of the node databases, whose
+        /// progress is reported by database checkpoint notification events.
+        /// Returned information: Directory of the checkpoint.
+        CreateDatabaseCheckpoint,
         /// Retrieves information about the Calico server.
         /// Returned information: Version of the Calico server, protocol
         /// version, network identifier.
@@ -1011,6 +1104,36 @@ build_wrpc_wasm_bindgen_interface!(
         /// Checks if block is blue or not.
         /// Returned information: Block blueness.
         GetCurrentBlockColor,
+        /// Retrieves the samples of node metrics retained by the node
+        /// over a time range, at a second, minute or hour resolution.
+        /// Returned information: Metric names and timestamped samples.
+        GetMetricsHistory,
+        /// Retrieves the GHOSTDAG data of the blocks of a sub-DAG, delimited
+        /// by a low and a high block hash or by a DAA score range.
+        /// Returned information: Parents, merge sets, blue score and work,
+        /// selected parent and chain membership of the blocks.
+        ExportDag,
+        /// Checks whether a block is in the past of another block.
+        /// Returned information: DAG and chain ancestry, blue score distance.
+        IsDagAncestorOf,
+        /// Retrieves the GHOSTDAG merge set of a block.
+        /// Returned information: Selected parent, blue and red merge set blocks.
+        GetBlockMergeset,
+        /// Retrieves the size of the anticone of a block from the
+        /// point of view of the virtual block.
+        /// Returned information: Anticone size.
+        GetAnticoneSize,
+        /// Retrieves the selected chain path between two blocks.
+        /// Returned information: Removed and added chain block hashes.
+        GetChainPathBetween,
+        /// Retrieves the selected chain block with the highest DAA score
+        /// not above a DAA score. Requires the chain index.
+        /// Returned information: Hash, DAA score and timestamp of the block.
+        GetChainBlockByDaaScore,
+        /// Retrieves a page of the selected chain blocks within a timestamp
+        /// range. Requires the chain index.
+        /// Returned information: Hash, DAA score and timestamp of the blocks.
+        GetBlocksByTimeRange,
         /// Retrieves the estimated DAA (Difficulty Adjustment Algorithm)
         /// score timestamp estimate.
         /// Returned information: DAA score timestamp estimate.
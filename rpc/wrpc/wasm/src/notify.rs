@@ -25,6 +25,10 @@ export enum RpcEventType {
     VirtualDaaScoreChanged = "virtual-daa-score-changed",
     PruningPointUtxoSetOverride = "pruning-point-utxo-set-override",
     NewBlockTemplate = "new-block-template",
+    MempoolTransactionAdded = "mempool-transaction-added",
+    MempoolTransactionRemoved = "mempool-transaction-removed",
+    DatabaseCheckpoint = "database-checkpoint",
+    ConsensusAuditViolation = "consensus-audit-violation",
 }
 
 /**
@@ -40,7 +44,11 @@ export type RpcEventData = IBlockAdded
     | ISinkBlueScoreChanged 
     | IVirtualDaaScoreChanged 
     | IPruningPointUtxoSetOverride 
-    | INewBlockTemplate;
+    | INewBlockTemplate 
+    | IMempoolTransactionAdded 
+    | IMempoolTransactionRemoved 
+    | IDatabaseCheckpoint 
+    | IConsensusAuditViolation;
 
 /**
  * RPC notification event data map.
@@ -59,6 +67,10 @@ export type RpcEventMap = {
     "virtual-daa-score-changed" : IVirtualDaaScoreChanged,
     "pruning-point-utxo-set-override" : IPruningPointUtxoSetOverride,
     "new-block-template" : INewBlockTemplate,
+    "mempool-transaction-added" : IMempoolTransactionAdded,
+    "mempool-transaction-removed" : IMempoolTransactionRemoved,
+    "database-checkpoint" : IDatabaseCheckpoint,
+    "consensus-audit-violation" : IConsensusAuditViolation,
 }
 
 /**
@@ -84,6 +96,10 @@ export type RpcEvent = {
  * {@link RpcClient.subscribeSinkBlueScoreChanged},
  * {@link RpcClient.subscribePruningPointUtxoSetOverride},
  * {@link RpcClient.subscribeNewBlockTemplate},
+ * {@link RpcClient.subscribeMempoolTransactionAdded},
+ * {@link RpcClient.subscribeMempoolTransactionRemoved},
+ * {@link RpcClient.subscribeDatabaseCheckpoint},
+ * {@link RpcClient.subscribeConsensusAuditViolation},
  * 
  * @category Node RPC
  */
@@ -241,3 +257,66 @@ declare! {
     }
     "#,
 }
+
+declare! {
+    IMempoolTransactionAdded,
+    r#"
+    /**
+     * Mempool transaction added notification event is produced when a
+     * transaction is accepted into the mempool. The event notification is
+     * scoped to the monitored list of addresses specified during the subscription.
+     * 
+     * @category Node RPC
+     */
+    export interface IMempoolTransactionAdded {
+        [key: string]: any;
+    }
+    "#,
+}
+
+declare! {
+    IMempoolTransactionRemoved,
+    r#"
+    /**
+     * Mempool transaction removed notification event is produced when a
+     * transaction leaves the mempool, carrying the reason of its removal.
+     * The event notification is scoped to the monitored list of addresses
+     * specified during the subscription.
+     * 
+     * @category Node RPC
+     */
+    export interface IMempoolTransactionRemoved {
+        [key: string]: any;
+    }
+    "#,
+}
+
+declare! {
+    IDatabaseCheckpoint,
+    r#"
+    /**
+     * Database checkpoint notification event is produced when a checkpoint
+     * of the node databases starts, progresses, completes or fails.
+     * 
+     * @category Node RPC
+     */
+    export interface IDatabaseCheckpoint {
+        [key: string]: any;
+    }
+    "#,
+}
+
+declare! {
+    IConsensusAuditViolation,
+    r#"
+    /**
+     * Consensus audit violation notification event is produced when the
+     * consensus audit mode of the node finds a block violating a consensus invariant.
+     * 
+     * @category Node RPC
+     */
+    export interface IConsensusAuditViolation {
+        [key: string]: any;
+    }
+    "#,
+}
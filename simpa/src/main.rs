@@ -24,7 +24,7 @@ use calico_core::{
     trace, warn,
 };
 use calico_database::prelude::ConnBuilder;
-use calico_database::{create_temp_db, load_existing_db};
+use calico_database::{create_memory_db, create_temp_db, load_existing_db};
 use calico_hashes::Hash;
 use calico_perf_monitor::{builder::Builder, counters::CountersSnapshot};
 use calico_utils::fd_budget;
@@ -122,6 +122,11 @@ struct Args {
     rocksdb_files_limit: Option<i32>,
     #[arg(long)]
     rocksdb_mem_budget: Option<usize>,
+
+    /// Keep the simulation databases in memory rather than in RocksDB instances on disk
+    /// (the output DB, if requested, is still written to disk)
+    #[arg(long, default_value_t = false)]
+    in_memory: bool,
 }
 
 #[cfg(feature = "heap")]
@@ -245,6 +250,7 @@ fn main_impl(mut args: Args) {
                 args.rocksdb_stats_period_sec,
                 args.rocksdb_files_limit,
                 args.rocksdb_mem_budget,
+                args.in_memory,
             )
             .run(until);
         consensus.shutdown(handles);
@@ -257,7 +263,8 @@ fn main_impl(mut args: Args) {
     }
 
     // Benchmark the DAG validation time
-    let (_lifetime2, db2) = create_temp_db!(ConnBuilder::default().with_parallelism(num_cpus::get()).with_files_limit(default_fd));
+    let conn_builder2 = ConnBuilder::default().with_parallelism(num_cpus::get()).with_files_limit(default_fd);
+    let (_lifetime2, db2) = if args.in_memory { create_memory_db!(conn_builder2) } else { create_temp_db!(conn_builder2) };
     let (dummy_notification_sender, _) = unbounded();
     let notification_root = Arc::new(ConsensusNotificationRoot::new(dummy_notification_sender));
     let consensus2 = Arc::new(Consensus::new(
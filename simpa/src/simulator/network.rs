@@ -11,7 +11,7 @@ use calico_consensus::consensus::Consensus;
 use calico_consensus_core::block::Block;
 use calico_database::prelude::ConnBuilder;
 use calico_database::utils::DbLifetime;
-use calico_database::{create_permanent_db, create_temp_db};
+use calico_database::{create_memory_db, create_permanent_db, create_temp_db};
 use calico_utils::fd_budget;
 use calico_utils::sim::Simulation;
 
@@ -42,6 +42,7 @@ impl CalicoNetworkSimulator {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn init(
         &mut self,
         num_miners: u64,
@@ -50,6 +51,7 @@ impl CalicoNetworkSimulator {
         rocksdb_stats_period_sec: Option<u32>,
         rocksdb_files_limit: Option<i32>,
         rocksdb_mem_budget: Option<usize>,
+        in_memory: bool,
     ) -> &mut Self {
         let secp = secp256k1::Secp256k1::new();
         let mut rng = rand::thread_rng();
@@ -68,6 +70,8 @@ impl CalicoNetworkSimulator {
                 (true, Some(dir), true, None) => create_permanent_db!(dir, builder.enable_stats()),
                 (true, Some(dir), false, _) => create_permanent_db!(dir, builder),
 
+                _ if in_memory => create_memory_db!(builder),
+
                 (_, _, true, Some(rocksdb_stats_period_sec)) => {
                     create_temp_db!(builder.enable_stats().with_stats_period(rocksdb_stats_period_sec))
                 }
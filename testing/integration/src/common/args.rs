@@ -61,6 +61,11 @@ impl ArgsBuilder {
         self
     }
 
+    pub fn chainindex(mut self, chainindex: bool) -> Self {
+        self.args.chainindex = chainindex;
+        self
+    }
+
     pub fn apply_args<F>(mut self, edit_func: F) -> Self
     where
         F: Fn(&mut Args),
@@ -48,6 +48,8 @@ impl ClientManager {
             false,
             Some(500_000),
             Default::default(),
+            None,
+            None,
         )
         .await
         .unwrap()
@@ -71,6 +73,8 @@ impl ClientManager {
             false,
             Some(500_000),
             Default::default(),
+            None,
+            None,
         )
         .await
         .unwrap()
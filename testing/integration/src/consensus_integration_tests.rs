@@ -6,7 +6,7 @@ use async_channel::unbounded;
 use calico_alloc::init_allocator_with_default_settings;
 use calico_consensus::config::genesis::GENESIS;
 use calico_consensus::config::{Config, ConfigBuilder};
-use calico_consensus::consensus::factory::Factory as ConsensusFactory;
+use calico_consensus::consensus::factory::{Factory as ConsensusFactory, MultiConsensusManagementStore};
 use calico_consensus::consensus::test_consensus::{TestConsensus, TestConsensusFactory};
 use calico_consensus::model::stores::block_transactions::{
     BlockTransactionsStore, BlockTransactionsStoreReader, DbBlockTransactionsStore,
@@ -32,7 +32,7 @@ use calico_consensus_core::network::{NetworkId, NetworkType::Mainnet};
 use calico_consensus_core::subnets::SubnetworkId;
 use calico_consensus_core::trusted::{ExternalGhostdagData, TrustedBlock};
 use calico_consensus_core::tx::{ScriptPublicKey, Transaction, TransactionInput, TransactionOutpoint, TransactionOutput, UtxoEntry};
-use calico_consensus_core::{blockhash, hashing, BlockHashMap, BlueWorkType};
+use calico_consensus_core::{blockhash, hashing, BlockHashMap, BlockHashSet, BlueWorkType};
 use calico_consensus_notify::root::ConsensusNotificationRoot;
 use calico_consensus_notify::service::NotifyService;
 use calico_consensusmanager::ConsensusManager;
@@ -46,7 +46,7 @@ use calico_core::signals::Shutdown;
 use calico_core::task::runtime::AsyncRuntime;
 use calico_core::{assert_match, info};
 use calico_database::create_temp_db;
-use calico_database::prelude::{CachePolicy, ConnBuilder};
+use calico_database::prelude::{CachePolicy, ConnBuilder, DbBackend, DbIterator, DbSlice, StoreResult, WriteBatch, DB};
 use calico_index_processor::service::IndexService;
 use calico_math::Uint256;
 use calico_muhash::MuHash;
@@ -61,7 +61,7 @@ use serde::{Deserialize, Serialize};
 use std::cmp::{max, Ordering};
 use std::collections::HashSet;
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, Barrier};
 use std::time::Duration;
 use std::{
     collections::HashMap,
@@ -962,6 +962,7 @@ async fn json_test(file_path: &str, concurrency: bool) {
         &notify_service.notifier(),
         subscription_context.clone(),
         Some(UtxoIndexProxy::new(utxoindex.clone())),
+        None,
     ));
 
     let async_runtime = Arc::new(AsyncRuntime::new(2));
@@ -1788,6 +1789,87 @@ async fn selected_chain_test() {
     consensus.shutdown(wait_handles);
 }
 
+#[tokio::test]
+async fn sub_dag_test() {
+    init_allocator_with_default_settings();
+    let config = ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().build();
+    let consensus = TestConsensus::new(&config);
+    let wait_handles = consensus.init();
+
+    consensus.add_utxo_valid_block_with_parents(1.into(), vec![config.genesis.hash], vec![]).await.unwrap();
+    consensus.add_utxo_valid_block_with_parents(2.into(), vec![1.into()], vec![]).await.unwrap();
+    consensus.add_utxo_valid_block_with_parents(3.into(), vec![config.genesis.hash], vec![]).await.unwrap();
+    consensus.add_utxo_valid_block_with_parents(4.into(), vec![2.into(), 3.into()], vec![]).await.unwrap();
+    consensus.add_utxo_valid_block_with_parents(5.into(), vec![4.into()], vec![]).await.unwrap();
+
+    // Block 3 is not in the future of block 1
+    let sub_dag = consensus.get_sub_dag(1.into(), 5.into(), 10).unwrap();
+    let expected: BlockHashSet = [1.into(), 2.into(), 4.into(), 5.into()].into_iter().collect();
+    assert_eq!(sub_dag.len(), expected.len());
+    assert!(sub_dag.iter().all(|hash| expected.contains(hash)));
+    assert_eq!(sub_dag.first(), Some(&1.into()));
+
+    // The blocks are ordered topologically
+    let sub_dag = consensus.get_sub_dag(config.genesis.hash, 5.into(), 10).unwrap();
+    assert_eq!(sub_dag.len(), 6);
+    assert_eq!(sub_dag.first(), Some(&config.genesis.hash));
+    assert_eq!(sub_dag[sub_dag.len() - 2..], [Hash::from(4), Hash::from(5)]);
+
+    // The traversal is bounded and requires low to be in the past of high
+    assert!(consensus.get_sub_dag(config.genesis.hash, 5.into(), 5).is_err());
+    assert!(consensus.get_sub_dag(3.into(), 2.into(), 10).is_err());
+
+    assert_eq!(consensus.get_chain_block_by_daa_score(u64::MAX).unwrap(), 5.into());
+    assert_eq!(consensus.get_chain_block_by_daa_score(0).unwrap(), config.genesis.hash);
+    for chain_block in [1, 2, 4, 5].map(Hash::from) {
+        let daa_score = consensus.get_header(chain_block).unwrap().daa_score;
+        assert_eq!(consensus.get_chain_block_by_daa_score(daa_score).unwrap(), chain_block);
+    }
+    // Block 4 merges block 3, so the DAA score of its selected parent is two below its own
+    let daa_score = consensus.get_header(4.into()).unwrap().daa_score;
+    assert_eq!(consensus.get_chain_block_by_daa_score(daa_score - 1).unwrap(), 2.into());
+
+    consensus.shutdown(wait_handles);
+}
+
+#[tokio::test]
+async fn reachability_queries_test() {
+    init_allocator_with_default_settings();
+    let config = ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().build();
+    let consensus = TestConsensus::new(&config);
+    let wait_handles = consensus.init();
+
+    consensus.add_utxo_valid_block_with_parents(1.into(), vec![config.genesis.hash], vec![]).await.unwrap();
+    consensus.add_utxo_valid_block_with_parents(2.into(), vec![1.into()], vec![]).await.unwrap();
+    consensus.add_utxo_valid_block_with_parents(3.into(), vec![config.genesis.hash], vec![]).await.unwrap();
+    consensus.add_utxo_valid_block_with_parents(4.into(), vec![2.into(), 3.into()], vec![]).await.unwrap();
+    consensus.add_utxo_valid_block_with_parents(5.into(), vec![4.into()], vec![]).await.unwrap();
+
+    assert!(consensus.is_dag_ancestor_of(3.into(), 5.into()).unwrap());
+    assert!(!consensus.is_dag_ancestor_of(3.into(), 2.into()).unwrap());
+    assert!(consensus.is_dag_ancestor_of(6.into(), 5.into()).is_err());
+
+    // Blocks 1 and 2 are in the anticone of block 3
+    assert_eq!(consensus.get_anticone_size(3.into(), None).unwrap(), 2);
+    assert_eq!(consensus.get_anticone_size(5.into(), None).unwrap(), 0);
+    assert!(consensus.get_anticone_size(3.into(), Some(1)).is_err());
+
+    // Block 3 is not on the selected chain of block 5, which goes through block 2
+    let chain_path = consensus.get_chain_path(3.into(), 5.into(), None).unwrap();
+    assert_eq!(chain_path.removed, vec![Hash::from(3)]);
+    assert_eq!(chain_path.added, vec![Hash::from(1), Hash::from(2), Hash::from(4), Hash::from(5)]);
+    let chain_path = consensus.get_chain_path(3.into(), 5.into(), Some(2)).unwrap();
+    assert_eq!(chain_path.added, vec![Hash::from(1), Hash::from(2)]);
+
+    // The limit bounds the removed chain blocks as well, failing rather than truncating them
+    let chain_path = consensus.get_chain_path(5.into(), 3.into(), Some(4)).unwrap();
+    assert_eq!(chain_path.removed, vec![Hash::from(5), Hash::from(4), Hash::from(2), Hash::from(1)]);
+    assert_eq!(chain_path.added, vec![Hash::from(3)]);
+    assert!(consensus.get_chain_path(5.into(), 3.into(), Some(3)).is_err());
+
+    consensus.shutdown(wait_handles);
+}
+
 fn assert_selected_chain_store_matches_virtual_chain(consensus: &TestConsensus) {
     let pruning_point = consensus.pruning_point();
     let iter1 = selected_chain_store_iterator(consensus, pruning_point);
@@ -1842,3 +1924,345 @@ async fn staging_consensus_test() {
     core.shutdown();
     core.join(joins);
 }
+
+#[tokio::test]
+async fn database_checkpoint_test() {
+    init_allocator_with_default_settings();
+    let config = ConfigBuilder::new(MAINNET_PARAMS).build();
+
+    let db_tempdir = get_calico_tempdir();
+    let db_path = db_tempdir.path().to_owned();
+    let consensus_db_dir = db_path.join("consensus");
+    let meta_db_dir = db_path.join("meta");
+
+    let meta_db = calico_database::prelude::ConnBuilder::default().with_db_path(meta_db_dir).with_files_limit(5).build().unwrap();
+
+    let (notification_send, _notification_recv) = unbounded();
+    let notification_root = Arc::new(ConsensusNotificationRoot::new(notification_send));
+    let counters = Arc::new(ProcessingCounters::default());
+    let tx_script_cache_counters = Arc::new(TxScriptCacheCounters::default());
+
+    let consensus_factory = Arc::new(ConsensusFactory::new(
+        meta_db,
+        &config,
+        consensus_db_dir,
+        4,
+        notification_root,
+        counters,
+        tx_script_cache_counters,
+        200,
+    ));
+    let consensus_manager = Arc::new(ConsensusManager::new(consensus_factory));
+
+    let core = Arc::new(Core::new());
+    core.bind(consensus_manager.clone());
+    let joins = core.start();
+
+    let checkpoint_tempdir = get_calico_tempdir();
+    let checkpoint_dir = checkpoint_tempdir.path().join("checkpoint");
+    let checkpointed = std::sync::Mutex::new(Vec::new());
+    let info = consensus_manager
+        .create_checkpoint(&checkpoint_dir, &|database| checkpointed.lock().unwrap().push(database.to_string()))
+        .unwrap();
+    assert_eq!(info.sink, config.genesis.hash);
+
+    // The checkpoint reproduces the layout of the data directory
+    let checkpointed = checkpointed.into_inner().unwrap();
+    assert_eq!(checkpointed.len(), 2);
+    assert!(checkpoint_dir.join("consensus").join(&checkpointed[0]).is_dir());
+    assert_eq!(checkpointed[1], "meta");
+    let meta_db = calico_database::prelude::ConnBuilder::default()
+        .with_db_path(checkpoint_dir.join("meta"))
+        .with_files_limit(5)
+        .build()
+        .unwrap();
+    let active_dir_name = MultiConsensusManagementStore::new(meta_db).active_consensus_dir_name().unwrap();
+    assert_eq!(active_dir_name.as_ref(), Some(&checkpointed[0]));
+
+    // The target directory of a checkpoint must not exist
+    assert!(consensus_manager.create_checkpoint(&checkpoint_dir, &|_| {}).is_err());
+
+    core.shutdown();
+    core.join(joins);
+}
+
+/// Delegates to a DB and pauses checkpoint creation until released, so that the test can act while a checkpoint is in progress
+struct PausingCheckpointBackend {
+    inner: Arc<DB>,
+    checkpoint_started: Arc<Barrier>,
+    checkpoint_released: Arc<Barrier>,
+}
+
+impl DbBackend for PausingCheckpointBackend {
+    fn get_pinned(&self, key: &[u8]) -> Result<Option<DbSlice<'_>>, rocksdb::Error> {
+        self.inner.get_pinned(key)
+    }
+
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<(), rocksdb::Error> {
+        self.inner.put(key, value)
+    }
+
+    fn delete(&self, key: &[u8]) -> Result<(), rocksdb::Error> {
+        self.inner.delete(key)
+    }
+
+    fn write(&self, batch: WriteBatch) -> Result<(), rocksdb::Error> {
+        self.inner.write(batch)
+    }
+
+    fn prefix_iterator(&self, prefix: &[u8], seek_from: Option<&[u8]>) -> DbIterator<'_> {
+        self.inner.prefix_iterator(prefix, seek_from)
+    }
+
+    fn snapshot_prefix_iterator(&self, prefix: &[u8]) -> DbIterator<'_> {
+        self.inner.snapshot_prefix_iterator(prefix)
+    }
+
+    fn path(&self) -> Option<&Path> {
+        self.inner.path()
+    }
+
+    fn create_checkpoint(&self, path: &Path) -> StoreResult<()> {
+        self.checkpoint_started.wait();
+        self.checkpoint_released.wait();
+        self.inner.create_checkpoint(path)
+    }
+}
+
+#[tokio::test]
+async fn database_checkpoint_concurrent_processing_test() {
+    init_allocator_with_default_settings();
+    let config = ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().build();
+    let (_db_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+    let checkpoint_started = Arc::new(Barrier::new(2));
+    let checkpoint_released = Arc::new(Barrier::new(2));
+    let backend = PausingCheckpointBackend {
+        inner: db,
+        checkpoint_started: checkpoint_started.clone(),
+        checkpoint_released: checkpoint_released.clone(),
+    };
+    let (notification_send, _notification_recv) = unbounded();
+    let consensus = TestConsensus::with_db(Arc::new(DB::with_backend(Box::new(backend), None)), &config, notification_send);
+    let wait_handles = consensus.init();
+
+    let checkpoint_tempdir = get_calico_tempdir();
+    let checkpoint_dir = checkpoint_tempdir.path().join("checkpoint");
+    let checkpoint = {
+        let consensus = consensus.consensus_clone();
+        let checkpoint_dir = checkpoint_dir.clone();
+        std::thread::spawn(move || consensus.create_checkpoint(&checkpoint_dir))
+    };
+    checkpoint_started.wait();
+
+    // Virtual processing keeps moving while the checkpoint is in progress
+    let mut tip = config.genesis.hash;
+    for i in 1..=10u64 {
+        let hash = i.into();
+        tokio::time::timeout(Duration::from_secs(10), consensus.add_empty_utxo_valid_block_with_parents(hash, vec![tip]))
+            .await
+            .expect("virtual processing is stalled by the checkpoint")
+            .unwrap();
+        tip = hash;
+    }
+    assert_eq!(consensus.get_sink(), tip);
+
+    // The checkpoint info describes the virtual state held by the checkpoint
+    checkpoint_released.wait();
+    let info = checkpoint.join().unwrap().unwrap();
+    assert_eq!(info.sink, tip);
+    assert_eq!(info.virtual_daa_score, consensus.get_virtual_daa_score());
+
+    consensus.shutdown(wait_handles);
+}
+
+struct EmptyTxSelector;
+
+impl calico_consensus_core::block::TemplateTransactionSelector for EmptyTxSelector {
+    fn select_transactions(&mut self) -> Vec<Transaction> {
+        vec![]
+    }
+
+    fn reject_selection(&mut self, _tx_id: calico_consensus_core::tx::TransactionId) {
+        unimplemented!()
+    }
+
+    fn is_successful(&self) -> bool {
+        true
+    }
+}
+
+/// Mines `rows` rows of `width` parallel blocks from block templates and returns their hashes
+async fn mine_template_rows(consensus: &dyn ConsensusApi, config: &Config, rows: usize, width: u64) -> Vec<Vec<Hash>> {
+    use calico_consensus_core::{block::TemplateBuildMode, coinbase::MinerData};
+
+    let mut timestamp = config.genesis.timestamp;
+    let mut hashes = vec![];
+    for _ in 0..rows {
+        timestamp += config.target_time_per_block;
+        let row = (0..width)
+            .map(|nonce| {
+                // Distinct extra data keeps the coinbase transactions of sibling blocks apart
+                let miner_data = MinerData::new(ScriptPublicKey::from_vec(0, vec![]), vec![nonce as u8]);
+                let mut template =
+                    consensus.build_block_template(miner_data, Box::new(EmptyTxSelector), TemplateBuildMode::Standard).unwrap();
+                template.block.header.timestamp = timestamp;
+                template.block.header.nonce = nonce;
+                template.block.header.finalize();
+                template.block.to_immutable()
+            })
+            .collect_vec();
+        hashes.push(row.iter().map(|block| block.hash()).collect_vec());
+        for block in row {
+            assert!(consensus.validate_and_insert_block(block).virtual_state_task.await.unwrap().has_block_body());
+        }
+    }
+    hashes
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reindex_test() {
+    use calico_consensusmanager::ConsensusFactory as _;
+    use calico_database::registry::DatabaseStorePrefixes;
+    use calicod_lib::reindex::reindex;
+
+    init_allocator_with_default_settings();
+    let config = ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().build();
+
+    let db_tempdir = get_calico_tempdir();
+    let db_path = db_tempdir.path().to_owned();
+    let consensus_db_dir = db_path.join("consensus");
+    let meta_db = ConnBuilder::default().with_db_path(db_path.join("meta")).with_files_limit(5).build().unwrap();
+    let (notification_send, _notification_recv) = unbounded();
+    let notification_root = Arc::new(ConsensusNotificationRoot::new(notification_send));
+    let consensus_factory = ConsensusFactory::new(
+        meta_db.clone(),
+        &config,
+        consensus_db_dir.clone(),
+        4,
+        notification_root,
+        Default::default(),
+        Default::default(),
+        200,
+    );
+    let active_dir_name = || MultiConsensusManagementStore::new(meta_db.clone()).active_consensus_dir_name().unwrap().unwrap();
+    let delete_block_entry = |prefix: Vec<u8>, hash: Hash| {
+        let db = ConnBuilder::default().with_db_path(consensus_db_dir.join(active_dir_name())).with_files_limit(10).build().unwrap();
+        db.delete(prefix.into_iter().chain(hash.as_bytes()).collect::<Vec<_>>()).unwrap();
+    };
+
+    // Mine rows of parallel blocks
+    let (consensus, ctl) = consensus_factory.new_active_consensus();
+    let handles = ctl.start();
+    let session = consensus.unguarded_session_blocking();
+    let rows = mine_template_rows(&*session, &config, 20, 3).await;
+    let sink = session.get_sink();
+    let tips = session.get_tips().into_iter().collect::<BlockHashSet>();
+    let utxos = session.get_virtual_utxos(None, usize::MAX, false).into_iter().map(|(outpoint, _)| outpoint).collect::<HashSet<_>>();
+    drop(session);
+    ctl.stop();
+    handles.into_iter().for_each(|handle| handle.join().unwrap());
+    drop((consensus, ctl));
+
+    // Corrupted GHOSTDAG data is rebuilt rather than read
+    let corrupted = rows[10][1];
+    delete_block_entry(DatabaseStorePrefixes::Ghostdag.into_iter().chain([0]).collect(), corrupted);
+    delete_block_entry(DatabaseStorePrefixes::GhostdagCompact.into_iter().chain([0]).collect(), corrupted);
+    let replaced_dir_name = active_dir_name();
+    tokio::task::block_in_place(|| reindex(&consensus_factory, &config)).unwrap();
+    assert_ne!(active_dir_name(), replaced_dir_name);
+    assert!(!consensus_db_dir.join(replaced_dir_name).exists(), "the replaced consensus is deleted by a non archival node");
+
+    let (consensus, ctl) = consensus_factory.new_active_consensus();
+    let session = consensus.unguarded_session_blocking();
+    assert_eq!(session.get_sink(), sink);
+    assert_eq!(session.get_tips().into_iter().collect::<BlockHashSet>(), tips);
+    assert!(session.get_ghostdag_data(corrupted).is_ok());
+    for row in rows.iter() {
+        for &hash in row.iter() {
+            assert!(session.get_block_status(hash).is_some_and(|status| status.has_block_body()));
+        }
+    }
+    let reindexed_utxos = session.get_virtual_utxos(None, usize::MAX, false);
+    assert_eq!(reindexed_utxos.into_iter().map(|(outpoint, _)| outpoint).collect::<HashSet<_>>(), utxos);
+    drop(session);
+    drop((consensus, ctl));
+
+    // A failing reindex leaves the active consensus untouched and deletes the staging one
+    delete_block_entry(DatabaseStorePrefixes::Headers.into(), rows[5][0]);
+    let active = active_dir_name();
+    assert!(tokio::task::block_in_place(|| reindex(&consensus_factory, &config)).is_err());
+    assert_eq!(active_dir_name(), active);
+    assert!(MultiConsensusManagementStore::new(meta_db).staging_consensus_entry().is_none());
+    assert_eq!(std::fs::read_dir(&consensus_db_dir).unwrap().count(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn restore_test() {
+    use calico_consensus::config::genesis::TESTNET_GENESIS;
+    use calico_consensusmanager::ConsensusFactory as _;
+    use calicod_lib::{
+        daemon::{CONSENSUS_DB, META_DB},
+        restore::{restore, RestoreError},
+    };
+
+    init_allocator_with_default_settings();
+    let config = ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().build();
+    let tempdir = get_calico_tempdir();
+    let new_consensus_factory = |db_dir: &Path| {
+        let meta_db = ConnBuilder::default().with_db_path(db_dir.join(META_DB)).with_files_limit(5).build().unwrap();
+        let (notification_send, _notification_recv) = unbounded();
+        let notification_root = Arc::new(ConsensusNotificationRoot::new(notification_send));
+        ConsensusFactory::new(
+            meta_db,
+            &config,
+            db_dir.join(CONSENSUS_DB),
+            1,
+            notification_root,
+            Default::default(),
+            Default::default(),
+            200,
+        )
+    };
+
+    // Checkpoint a node holding a few blocks
+    let checkpoint_dir = tempdir.path().join("checkpoint");
+    let factory = new_consensus_factory(&tempdir.path().join("node"));
+    let (consensus, ctl) = factory.new_active_consensus();
+    let handles = ctl.start();
+    let session = consensus.unguarded_session_blocking();
+    mine_template_rows(&*session, &config, 5, 2).await;
+    let sink = session.get_sink();
+    drop(session);
+    factory.create_checkpoint(&consensus, &checkpoint_dir, &|_| {}).unwrap();
+    ctl.stop();
+    handles.into_iter().for_each(|handle| handle.join().unwrap());
+    drop((consensus, ctl, factory));
+
+    // A failed restore leaves the current databases in place
+    let db_dir = tempdir.path().join("datadir");
+    std::fs::create_dir_all(&db_dir).unwrap();
+    std::fs::write(db_dir.join("current"), b"").unwrap();
+    let empty_dir = tempdir.path().join("empty");
+    std::fs::create_dir_all(&empty_dir).unwrap();
+    assert!(matches!(restore(&empty_dir, &db_dir, config.genesis.hash), Err(RestoreError::NotACheckpoint(_, _))));
+    assert!(matches!(restore(&checkpoint_dir, &db_dir, TESTNET_GENESIS.hash), Err(RestoreError::NetworkMismatch(_, _))));
+    assert!(db_dir.join("current").exists());
+    assert_eq!(std::fs::read_dir(tempdir.path()).unwrap().count(), 4, "no restore leftovers are expected");
+
+    // The restored databases replace the current ones and the checkpoint is left untouched
+    restore(&checkpoint_dir, &db_dir, config.genesis.hash).unwrap();
+    assert!(!db_dir.join("current").exists());
+    assert!(checkpoint_dir.join(CONSENSUS_DB).is_dir());
+    let factory = new_consensus_factory(&db_dir);
+    let (consensus, _ctl) = factory.new_active_consensus();
+    assert_eq!(consensus.unguarded_session_blocking().get_sink(), sink);
+    drop((consensus, _ctl, factory));
+
+    // The meta database of a checkpoint must list the consensus database it contains
+    let consensus_dirs =
+        std::fs::read_dir(checkpoint_dir.join(CONSENSUS_DB)).unwrap().map(|entry| entry.unwrap().path()).collect_vec();
+    consensus_dirs.into_iter().for_each(|dir| std::fs::remove_dir_all(dir).unwrap());
+    assert!(matches!(restore(&checkpoint_dir, &db_dir, config.genesis.hash), Err(RestoreError::MissingActiveConsensus(_))));
+    let (consensus, _ctl) = new_consensus_factory(&db_dir).new_active_consensus();
+    assert_eq!(consensus.unguarded_session_blocking().get_sink(), sink);
+}
@@ -10,10 +10,23 @@ use calico_consensus::params::SIMNET_PARAMS;
 use calico_consensus_core::header::Header;
 use calico_consensusmanager::ConsensusManager;
 use calico_core::{task::runtime::AsyncRuntime, trace};
+use calico_database::utils::get_calico_tempdir;
 use calico_grpc_client::GrpcClient;
-use calico_notify::scope::{BlockAddedScope, UtxosChangedScope, VirtualDaaScoreChangedScope};
-use calico_rpc_core::{api::rpc::RpcApi, Notification, RpcTransactionId};
+use calico_notify::{
+    connection::ChannelType,
+    listener::ListenerId,
+    scope::{BlockAddedScope, UtxosChangedScope, VirtualDaaScoreChangedScope},
+};
+use calico_rpc_core::{api::rpc::RpcApi, notify::connection::ChannelConnection, Notification, RpcTransactionId};
 use calico_txscript::pay_to_address_script;
+use calico_wrpc_client::{
+    client::{ConnectOptions, ConnectStrategy},
+    CalicoRpcClient, WrpcEncoding,
+};
+use calico_wrpc_server::{
+    gateway::{Gateway, GatewayOptions},
+    service::{Options as WrpcServerOptions, WrpcService},
+};
 use calicod_lib::args::Args;
 use rand::thread_rng;
 use std::{sync::Arc, time::Duration};
@@ -43,6 +56,126 @@ async fn daemon_sanity_test() {
     calicod2.shutdown();
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn daemon_metrics_endpoint_test() {
+    init_allocator_with_default_settings();
+    calico_core::log::try_init_logger("INFO");
+
+    let metrics_port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+    let args = Args {
+        devnet: true,
+        disable_upnp: true,
+        metrics_listen: Some(format!("127.0.0.1:{metrics_port}").try_into().unwrap()),
+        ..Default::default()
+    };
+    let total_fd_limit = 10;
+    let mut calicod = Daemon::new_random_with_args(args, total_fd_limit);
+    let rpc_client = calicod.start().await;
+
+    /// Sends a plain HTTP/1.1 GET request to the metrics endpoint and returns the response head and body
+    async fn scrape(port: u16, path: &str) -> (String, String) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        let mut stream = tokio::net::TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        stream.write_all(format!("GET {path} HTTP/1.1\r\nHost: 127.0.0.1\r\nConnection: close\r\n\r\n").as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        (head.to_owned(), body.to_owned())
+    }
+
+    let (head, body) = scrape(metrics_port, "/metrics").await;
+    assert!(head.starts_with("HTTP/1.1 200"), "unexpected response head {head}");
+    assert!(
+        head.to_lowercase().contains("content-type: application/openmetrics-text; version=1.0.0; charset=utf-8"),
+        "unexpected content type in {head}"
+    );
+    for series in [
+        "calico_consensus_blocks_submitted_total ",
+        "calico_consensus_virtual_daa_score ",
+        "calico_mempool_transactions ",
+        "calico_process_resident_memory_bytes ",
+        "calico_p2p_peers{direction=\"outbound\"} 0\n",
+        "calico_rpc_grpc_connections 1\n",
+        "calico_rpc_wrpc_connections{encoding=\"borsh\"} 0\n",
+        "calico_storage_keys{database=\"consensus\"} ",
+        "calico_storage_keys{database=\"meta\"} ",
+    ] {
+        assert!(body.contains(series), "missing series {series:?} in the exposition:\n{body}");
+    }
+    assert!(body.ends_with("# EOF\n"));
+
+    let (head, _) = scrape(metrics_port, "/other").await;
+    assert!(head.starts_with("HTTP/1.1 404"), "unexpected response head {head}");
+
+    rpc_client.disconnect().await.unwrap();
+    drop(rpc_client);
+    calicod.shutdown();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn daemon_rpc_authorization_test() {
+    init_allocator_with_default_settings();
+    calico_core::log::try_init_logger("INFO");
+
+    let auth_dir = get_calico_tempdir();
+    let auth_config = auth_dir.path().join("rpc-auth.toml");
+    std::fs::write(
+        &auth_config,
+        r#"
+        anonymous = "read-only"
+
+        [methods]
+        GetConnectedPeerInfo = "admin"
+        NotifyVirtualDaaScoreChanged = "submit"
+        "#,
+    )
+    .unwrap();
+    let args = Args {
+        devnet: true,
+        disable_upnp: true,
+        rpc_auth_config: Some(auth_config.to_str().unwrap().to_owned()),
+        ..Default::default()
+    };
+    let total_fd_limit = 10;
+    let mut calicod = Daemon::new_random_with_args(args, total_fd_limit);
+    let grpc_client = calicod.start().await;
+    grpc_client.start(Some(Arc::new(ChannelNotify::new(async_channel::unbounded().0)))).await;
+
+    let borsh_address = calicod.client_manager().args.read().rpclisten_borsh.clone().unwrap();
+    let borsh_port = borsh_address.to_address(&calicod.network.network_type(), &WrpcEncoding::Borsh).normalize(0).port;
+    let wrpc_client =
+        CalicoRpcClient::new(WrpcEncoding::Borsh, Some(&format!("ws://127.0.0.1:{borsh_port}")), None, Some(calicod.network), None)
+            .unwrap();
+    let connect_options = ConnectOptions { block_async_connect: true, strategy: ConnectStrategy::Fallback, ..Default::default() };
+    wrpc_client.connect(Some(connect_options)).await.unwrap();
+    let (sender, _event_receiver) = async_channel::unbounded();
+    let wrpc_listener_id =
+        wrpc_client.rpc_api().register_new_listener(ChannelConnection::new("authorization-test", sender, ChannelType::Persistent));
+
+    /// Whether an anonymous client may call a method, subscribe to notifications and unsubscribe from them
+    async fn permissions(client: Arc<dyn RpcApi>, listener_id: ListenerId) -> Vec<bool> {
+        vec![
+            client.get_info().await.is_ok(),
+            client.get_connected_peer_info().await.is_ok(),
+            client.start_notify(listener_id, BlockAddedScope {}.into()).await.is_ok(),
+            client.start_notify(listener_id, VirtualDaaScoreChangedScope {}.into()).await.is_ok(),
+            client.stop_notify(listener_id, VirtualDaaScoreChangedScope {}.into()).await.is_ok(),
+        ]
+    }
+
+    // The role overrides of the config file apply alike to both protocols, subscriptions
+    // being authorized as their Notify<Event> method
+    let grpc_permissions = permissions(Arc::new(grpc_client.clone()), Default::default()).await;
+    let wrpc_permissions = permissions(wrpc_client.rpc_api().clone(), wrpc_listener_id).await;
+    assert_eq!(grpc_permissions, vec![true, false, true, false, false]);
+    assert_eq!(wrpc_permissions, grpc_permissions);
+
+    wrpc_client.disconnect().await.unwrap();
+    grpc_client.disconnect().await.unwrap();
+    drop(grpc_client);
+    calicod.shutdown();
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn daemon_mining_test() {
     init_allocator_with_default_settings();
@@ -362,3 +495,111 @@ async fn daemon_cleaning_test() {
     assert_eq!(async_runtime.strong_count(), 0);
     assert_eq!(core.strong_count(), 0);
 }
+
+/// `cargo test --release --package calico-testing-integration --lib -- daemon_integration_tests::daemon_gateway_failover_test`
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn daemon_gateway_failover_test() {
+    init_allocator_with_default_settings();
+    calico_core::log::try_init_logger("INFO,calico_wrpc_server=debug");
+
+    let args = Args {
+        simnet: true,
+        unsafe_rpc: true,
+        enable_unsynced_mining: true,
+        disable_upnp: true, // UPnP registration might take some time and is not needed for this test
+        ..Default::default()
+    };
+    let total_fd_limit = 10;
+    let mut calicod1 = Daemon::new_random_with_args(args.clone(), total_fd_limit);
+    let mut calicod2 = Daemon::new_random_with_args(args, total_fd_limit);
+    let rpc_client1 = calicod1.start().await;
+    let rpc_client2 = calicod2.start().await;
+    let p2p_id1 = rpc_client1.get_info().await.unwrap().p2p_id;
+    let p2p_id2 = rpc_client2.get_info().await.unwrap().p2p_id;
+    let pay_address = Address::new(calicod1.network.into(), calico_addresses::Version::PubKey, &[0; 32]);
+    let mine = |client: GrpcClient| {
+        let pay_address = pay_address.clone();
+        async move {
+            let template = client.get_block_template(pay_address, vec![]).await.unwrap();
+            client.submit_block(template.block, false).await.unwrap();
+        }
+    };
+
+    // Mine a block on each node, so that both get synced
+    mine(rpc_client1.clone()).await;
+    mine(rpc_client2.clone()).await;
+
+    // Run a wRPC server relaying its clients to both nodes
+    let gateway = Arc::new(Gateway::new(GatewayOptions {
+        backends: vec![format!("grpc://localhost:{}", calicod1.rpc_port), format!("grpc://localhost:{}", calicod2.rpc_port)],
+        network_type: calicod1.network.network_type(),
+        health_check_interval: Duration::from_millis(200),
+        max_daa_score_lag: 10,
+    }));
+    gateway.start().await;
+    assert_eq!(gateway.status().healthy_backends, 2, "both nodes should be healthy");
+    let wrpc_port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+    let options =
+        WrpcServerOptions { listen_address: format!("127.0.0.1:{wrpc_port}"), gateway: Some(gateway.clone()), ..Default::default() };
+    let wrpc_service = Arc::new(WrpcService::new(2, None, &WrpcEncoding::Borsh, Default::default(), options));
+    let terminate_server = wrpc_service.clone().serve().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let client =
+        CalicoRpcClient::new(WrpcEncoding::Borsh, Some(&format!("ws://127.0.0.1:{wrpc_port}")), None, Some(calicod1.network), None)
+            .unwrap();
+    let connect_options = ConnectOptions { block_async_connect: true, strategy: ConnectStrategy::Fallback, ..Default::default() };
+    client.connect(Some(connect_options)).await.unwrap();
+    let (sender, event_receiver) = async_channel::unbounded();
+    let listener_id = client.rpc_api().register_new_listener(ChannelConnection::new("gateway-test", sender, ChannelType::Persistent));
+    client.rpc_api().start_notify(listener_id, VirtualDaaScoreChangedScope {}.into()).await.unwrap();
+
+    // The connection is bound to the first node, which serves all its calls and notifications
+    let connections = |gateway: &Gateway| gateway.status().backends.iter().map(|backend| backend.connections).collect::<Vec<_>>();
+    assert_eq!(connections(&gateway), vec![1, 0]);
+    for _ in 0..4 {
+        assert_eq!(client.get_info().await.unwrap().p2p_id, p2p_id1, "calls should be relayed to the bound node");
+    }
+    mine(rpc_client1.clone()).await;
+    match tokio::time::timeout(Duration::from_secs(1), event_receiver.recv()).await {
+        Ok(Ok(Notification::VirtualDaaScoreChanged(msg))) => assert_eq!(msg.virtual_daa_score, 2),
+        other => panic!("expected a virtual DAA score notification from the first node, got {other:?}"),
+    }
+
+    // Stopping the first node moves the connection to the second one
+    rpc_client1.disconnect().await.unwrap();
+    drop(rpc_client1);
+    calicod1.shutdown();
+    let check_gateway = gateway.clone();
+    wait_for(
+        100,
+        50,
+        move || {
+            async fn failed_over(gateway: Arc<Gateway>) -> bool {
+                let status = gateway.status();
+                !status.backends[0].healthy && status.backends[1].connections == 1 && status.backends[0].connections == 0
+            }
+            Box::pin(failed_over(check_gateway.clone()))
+        },
+        "the connection did not fail over to the second node",
+    )
+    .await;
+    for _ in 0..4 {
+        assert_eq!(client.get_info().await.unwrap().p2p_id, p2p_id2, "calls should be relayed to the newly bound node");
+    }
+
+    // The subscription is renewed on the second node
+    while event_receiver.try_recv().is_ok() {}
+    mine(rpc_client2.clone()).await;
+    match tokio::time::timeout(Duration::from_secs(1), event_receiver.recv()).await {
+        Ok(Ok(Notification::VirtualDaaScoreChanged(msg))) => assert_eq!(msg.virtual_daa_score, 2),
+        other => panic!("expected a virtual DAA score notification from the second node, got {other:?}"),
+    }
+
+    client.disconnect().await.unwrap();
+    gateway.stop();
+    drop(terminate_server);
+    rpc_client2.disconnect().await.unwrap();
+    drop(rpc_client2);
+    calicod2.shutdown();
+}
@@ -10,8 +10,9 @@ use calico_hashes::Hash;
 use calico_notify::{
     connection::{ChannelConnection, ChannelType},
     scope::{
-        BlockAddedScope, FinalityConflictScope, NewBlockTemplateScope, PruningPointUtxoSetOverrideScope, Scope,
-        SinkBlueScoreChangedScope, UtxosChangedScope, VirtualChainChangedScope, VirtualDaaScoreChangedScope,
+        BlockAddedScope, ConsensusAuditViolationScope, DatabaseCheckpointScope, FinalityConflictScope, MempoolTransactionAddedScope,
+        MempoolTransactionRemovedScope, NewBlockTemplateScope, PruningPointUtxoSetOverrideScope, Scope, SinkBlueScoreChangedScope,
+        UtxosChangedScope, VirtualChainChangedScope, VirtualDaaScoreChangedScope,
     },
 };
 use calico_rpc_core::{api::rpc::RpcApi, model::*, Notification};
@@ -49,6 +50,7 @@ async fn sanity_test() {
         enable_unsynced_mining: true,
         block_template_cache_lifetime: Some(0),
         utxoindex: true,
+        chainindex: true,
         unsafe_rpc: true,
         ..Default::default()
     };
@@ -100,6 +102,7 @@ async fn sanity_test() {
                         .await
                         .unwrap();
                     assert!(response.added_chain_block_hashes.is_empty());
+                    assert!(!response.truncated);
                     assert!(response.removed_chain_block_hashes.is_empty());
 
                     // Get a block template
@@ -186,6 +189,97 @@ async fn sanity_test() {
                 tst!(op, "see SubmitBlock")
             }
 
+            CalicodPayloadOps::ExportDag => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let response = rpc_client
+                        .export_dag(RpcDagRange::Hashes { low_hash: SIMNET_GENESIS.hash, high_hash: SIMNET_GENESIS.hash })
+                        .await
+                        .unwrap();
+                    assert_eq!(response.blocks.len(), 1);
+                    assert_eq!(response.blocks[0].hash, SIMNET_GENESIS.hash);
+                    assert!(response.blocks[0].is_chain_block);
+
+                    let response =
+                        rpc_client.export_dag(RpcDagRange::DaaScores { low_daa_score: 0, high_daa_score: 0 }).await.unwrap();
+                    assert_eq!(response.low_hash, SIMNET_GENESIS.hash);
+                    assert_eq!(response.blocks.first().map(|block| block.hash), Some(SIMNET_GENESIS.hash));
+
+                    // Unknown blocks and inverted ranges are rejected
+                    let result =
+                        rpc_client.export_dag(RpcDagRange::Hashes { low_hash: 0.into(), high_hash: SIMNET_GENESIS.hash }).await;
+                    assert!(result.is_err());
+                    let result = rpc_client.export_dag(RpcDagRange::DaaScores { low_daa_score: 2, high_daa_score: 1 }).await;
+                    assert!(result.is_err());
+                })
+            }
+
+            CalicodPayloadOps::IsDagAncestorOf => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let response = rpc_client.is_dag_ancestor_of(SIMNET_GENESIS.hash, SIMNET_GENESIS.hash).await.unwrap();
+                    assert!(response.is_dag_ancestor_of);
+                    assert!(response.is_chain_ancestor_of);
+                    assert_eq!(response.blue_score_distance, Some(0));
+
+                    let result = rpc_client.is_dag_ancestor_of(0.into(), SIMNET_GENESIS.hash).await;
+                    assert!(result.is_err());
+                })
+            }
+
+            CalicodPayloadOps::GetBlockMergeset => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let response = rpc_client.get_block_mergeset(SIMNET_GENESIS.hash).await.unwrap();
+                    assert!(response.mergeset_reds.is_empty());
+
+                    let result = rpc_client.get_block_mergeset(0.into()).await;
+                    assert!(result.is_err());
+                })
+            }
+
+            CalicodPayloadOps::GetAnticoneSize => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    assert_eq!(rpc_client.get_anticone_size(SIMNET_GENESIS.hash).await.unwrap(), 0);
+                    assert!(rpc_client.get_anticone_size(0.into()).await.is_err());
+                })
+            }
+
+            CalicodPayloadOps::GetChainPathBetween => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let response = rpc_client.get_chain_path_between(SIMNET_GENESIS.hash, SIMNET_GENESIS.hash).await.unwrap();
+                    assert!(response.removed_chain_block_hashes.is_empty());
+                    assert!(response.added_chain_block_hashes.is_empty());
+
+                    let result = rpc_client.get_chain_path_between(SIMNET_GENESIS.hash, 0.into()).await;
+                    assert!(result.is_err());
+                })
+            }
+
+            CalicodPayloadOps::GetChainBlockByDaaScore => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let response = rpc_client.get_chain_block_by_daa_score(0).await.unwrap();
+                    assert_eq!(response.hash, SIMNET_GENESIS.hash);
+                    assert_eq!(response.timestamp, SIMNET_GENESIS.timestamp);
+                })
+            }
+
+            CalicodPayloadOps::GetBlocksByTimeRange => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let response = rpc_client.get_blocks_by_time_range(0, u64::MAX, None, 0).await.unwrap();
+                    assert_eq!(response.blocks.first().map(|block| block.hash), Some(SIMNET_GENESIS.hash));
+                    assert!(response.merged_block_hashes.is_empty());
+                    assert!(!response.has_more);
+
+                    let result = rpc_client.get_blocks_by_time_range(1, 0, None, 0).await;
+                    assert!(result.is_err());
+                })
+            }
+
             CalicodPayloadOps::GetCurrentNetwork => {
                 let rpc_client = client.clone();
                 tst!(op, {
@@ -244,6 +338,21 @@ async fn sanity_test() {
                 tst!(op, "see AddPeer, Ban")
             }
 
+            CalicodPayloadOps::CreateDatabaseCheckpoint => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let tempdir = tempfile::tempdir().unwrap();
+
+                    // The target directory must not exist
+                    let result = rpc_client.create_database_checkpoint(Some(tempdir.path().to_string_lossy().into_owned())).await;
+                    assert!(result.is_err());
+
+                    let directory = tempdir.path().join("checkpoint").to_string_lossy().into_owned();
+                    let response = rpc_client.create_database_checkpoint(Some(directory.clone())).await.unwrap();
+                    assert_eq!(response, directory);
+                })
+            }
+
             CalicodPayloadOps::GetSink => {
                 tst!(op, "see SubmitBlock")
             }
@@ -507,6 +616,29 @@ async fn sanity_test() {
                 })
             }
 
+            CalicodPayloadOps::GetMetricsHistory => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let response = rpc_client
+                        .get_metrics_history(None, None, RpcMetricsResolution::Second, vec!["NetworkMempoolSize".to_string()])
+                        .await
+                        .unwrap();
+                    assert_eq!(response.resolution, RpcMetricsResolution::Second);
+                    assert_eq!(response.metrics, vec!["NetworkMempoolSize".to_string()]);
+                    assert!(response.samples.iter().all(|sample| sample.values.len() == 1));
+                    assert!(response.samples.windows(2).all(|pair| pair[0].timestamp < pair[1].timestamp));
+
+                    let response = rpc_client.get_metrics_history(None, None, RpcMetricsResolution::Hour, vec![]).await.unwrap();
+                    assert!(response.metrics.len() > 1);
+
+                    // Unknown metrics are rejected
+                    let result = rpc_client
+                        .get_metrics_history(None, None, RpcMetricsResolution::Minute, vec!["NoSuchMetric".to_string()])
+                        .await;
+                    assert!(result.is_err());
+                })
+            }
+
             CalicodPayloadOps::GetMetrics => {
                 let rpc_client = client.clone();
                 tst!(op, {
@@ -520,6 +652,7 @@ async fn sanity_test() {
                                 process_metrics: true,
                                 storage_metrics: true,
                                 custom_metrics: true,
+                                rpc_method_metrics: false,
                             },
                         )
                         .await
@@ -537,6 +670,7 @@ async fn sanity_test() {
                                 process_metrics: true,
                                 storage_metrics: true,
                                 custom_metrics: true,
+                                rpc_method_metrics: false,
                             },
                         )
                         .await
@@ -554,6 +688,7 @@ async fn sanity_test() {
                                 process_metrics: false,
                                 storage_metrics: false,
                                 custom_metrics: true,
+                                rpc_method_metrics: false,
                             },
                         )
                         .await
@@ -571,6 +706,7 @@ async fn sanity_test() {
                                 process_metrics: false,
                                 storage_metrics: false,
                                 custom_metrics: true,
+                                rpc_method_metrics: false,
                             },
                         )
                         .await
@@ -711,10 +847,35 @@ async fn sanity_test() {
                 let rpc_client = client.clone();
                 let id = listener_id;
                 tst!(op, {
-                    rpc_client
-                        .start_notify(id, VirtualChainChangedScope { include_accepted_transaction_ids: false }.into())
-                        .await
-                        .unwrap();
+                    rpc_client.start_notify(id, VirtualChainChangedScope::new(false).into()).await.unwrap();
+                })
+            }
+            CalicodPayloadOps::NotifyMempoolTransactionAdded => {
+                let rpc_client = client.clone();
+                let id = listener_id;
+                tst!(op, {
+                    rpc_client.start_notify(id, MempoolTransactionAddedScope::new(vec![]).into()).await.unwrap();
+                })
+            }
+            CalicodPayloadOps::NotifyMempoolTransactionRemoved => {
+                let rpc_client = client.clone();
+                let id = listener_id;
+                tst!(op, {
+                    rpc_client.start_notify(id, MempoolTransactionRemovedScope::new(vec![]).into()).await.unwrap();
+                })
+            }
+            CalicodPayloadOps::NotifyDatabaseCheckpoint => {
+                let rpc_client = client.clone();
+                let id = listener_id;
+                tst!(op, {
+                    rpc_client.start_notify(id, DatabaseCheckpointScope {}.into()).await.unwrap();
+                })
+            }
+            CalicodPayloadOps::NotifyConsensusAuditViolation => {
+                let rpc_client = client.clone();
+                let id = listener_id;
+                tst!(op, {
+                    rpc_client.start_notify(id, ConsensusAuditViolationScope {}.into()).await.unwrap();
                 })
             }
             CalicodPayloadOps::StopNotifyingUtxosChanged => {
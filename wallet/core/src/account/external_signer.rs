@@ -0,0 +1,186 @@
+//!
+//! Client side of the external signer protocol
+//! (see [`calico_wallet_psst::external`]) used by
+//! [`External`](crate::account::variants::external::External) accounts.
+//!
+
+use crate::imports::*;
+use calico_wallet_psst::bundle::Bundle;
+use calico_wallet_psst::external::{InfoResponse, Request, Response, ResponseResult, PROTOCOL_VERSION};
+
+/// External signer endpoint. Endpoints are specified as strings
+/// in the form of `stdio:<command line>`, `tcp:<host:port>` or
+/// `unix:<path>` (UNIX domain sockets are available on unix platforms only).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignerEndpoint {
+    /// Spawn the signer process and communicate over its stdin/stdout.
+    Stdio(String),
+    /// Connect to a signer listening on a TCP socket.
+    Tcp(String),
+    /// Connect to a signer listening on a UNIX domain socket.
+    Unix(String),
+}
+
+impl FromStr for SignerEndpoint {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let (scheme, target) = s.trim().split_once(':').ok_or_else(|| Error::InvalidExternalSignerEndpoint(s.to_string()))?;
+        let target = target.trim();
+        if target.is_empty() {
+            return Err(Error::InvalidExternalSignerEndpoint(s.to_string()));
+        }
+
+        match scheme {
+            "stdio" => Ok(SignerEndpoint::Stdio(target.to_string())),
+            "tcp" => Ok(SignerEndpoint::Tcp(target.to_string())),
+            "unix" => Ok(SignerEndpoint::Unix(target.to_string())),
+            _ => Err(Error::InvalidExternalSignerEndpoint(s.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for SignerEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SignerEndpoint::Stdio(command) => write!(f, "stdio:{command}"),
+            SignerEndpoint::Tcp(address) => write!(f, "tcp:{address}"),
+            SignerEndpoint::Unix(path) => write!(f, "unix:{path}"),
+        }
+    }
+}
+
+/// External signer client. Each call establishes a new session
+/// with the signer (spawning the signer process or opening a
+/// socket connection), verifies the protocol version and the
+/// key fingerprint and submits the bundle for signing.
+pub struct ExternalSignerClient {
+    endpoint: SignerEndpoint,
+}
+
+impl ExternalSignerClient {
+    pub fn new(endpoint: SignerEndpoint) -> Self {
+        Self { endpoint }
+    }
+
+    pub fn endpoint(&self) -> &SignerEndpoint {
+        &self.endpoint
+    }
+
+    /// Obtain signer information.
+    pub async fn info(&self) -> Result<InfoResponse> {
+        let mut responses = self.session(vec![Request::info(1)]).await?;
+        match responses.remove(0).into_result()? {
+            ResponseResult::Info(info) => Ok(info),
+            _ => Err(Error::ExternalSigner("unexpected response to 'info' request".to_string())),
+        }
+    }
+
+    /// Submit `bundle` for signing by a signer holding a key with the supplied
+    /// hex-encoded `fingerprint`. Returns the signed bundle.
+    pub async fn sign(&self, network_id: &NetworkId, fingerprint: &str, bundle: &Bundle) -> Result<Bundle> {
+        let requests = vec![Request::info(1), Request::sign(2, network_id.to_string(), bundle.serialize()?)];
+        let mut responses = self.session(requests).await?.into_iter();
+
+        match responses.next().map(Response::into_result).transpose()? {
+            Some(ResponseResult::Info(info)) => {
+                if info.version != PROTOCOL_VERSION {
+                    return Err(Error::ExternalSigner(format!("unsupported protocol version {}", info.version)));
+                }
+                if !info.fingerprints.iter().any(|f| f.eq_ignore_ascii_case(fingerprint)) {
+                    return Err(Error::ExternalSigner(format!("signer does not hold the account key '{fingerprint}'")));
+                }
+            }
+            _ => return Err(Error::ExternalSigner("unexpected response to 'info' request".to_string())),
+        }
+
+        match responses.next().map(Response::into_result).transpose()? {
+            Some(ResponseResult::Sign(response)) => Ok(Bundle::deserialize(&response.bundle)?),
+            _ => Err(Error::ExternalSigner("unexpected response to 'sign' request".to_string())),
+        }
+    }
+
+    async fn session(&self, requests: Vec<Request>) -> Result<Vec<Response>> {
+        cfg_if! {
+            if #[cfg(not(target_arch = "wasm32"))] {
+                let endpoint = self.endpoint.clone();
+                async_std::task::spawn_blocking(move || native::session(&endpoint, requests)).await
+            } else {
+                let _ = requests;
+                Err(Error::NotImplemented)
+            }
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::process::{Command, Stdio};
+
+    pub fn session(endpoint: &SignerEndpoint, requests: Vec<Request>) -> Result<Vec<Response>> {
+        match endpoint {
+            SignerEndpoint::Stdio(command) => {
+                let mut args = command.split_whitespace();
+                let program = args.next().ok_or_else(|| Error::InvalidExternalSignerEndpoint(endpoint.to_string()))?;
+                let mut child = Command::new(program).args(args).stdin(Stdio::piped()).stdout(Stdio::piped()).spawn()?;
+                let stdin = child.stdin.take().expect("signer process stdin");
+                let stdout = child.stdout.take().expect("signer process stdout");
+                // dropping stdin closes the pipe, signaling the signer to terminate
+                let responses = exchange(stdin, stdout, requests);
+                let _ = child.wait();
+                responses
+            }
+            SignerEndpoint::Tcp(address) => {
+                let stream = std::net::TcpStream::connect(address)?;
+                exchange(stream.try_clone()?, stream, requests)
+            }
+            #[cfg(unix)]
+            SignerEndpoint::Unix(path) => {
+                let stream = std::os::unix::net::UnixStream::connect(path)?;
+                exchange(stream.try_clone()?, stream, requests)
+            }
+            #[cfg(not(unix))]
+            SignerEndpoint::Unix(_) => Err(Error::NotImplemented),
+        }
+    }
+
+    fn exchange<W: Write, R: Read>(mut writer: W, reader: R, requests: Vec<Request>) -> Result<Vec<Response>> {
+        let mut reader = BufReader::new(reader);
+        let mut responses = Vec::with_capacity(requests.len());
+        for request in requests {
+            writer.write_all(request.to_line()?.as_bytes())?;
+            writer.flush()?;
+
+            let mut line = String::new();
+            if reader.read_line(&mut line)? == 0 {
+                return Err(Error::ExternalSigner("signer closed the connection".to_string()));
+            }
+
+            let response = Response::from_line(&line)?;
+            if response.id != request.id {
+                return Err(Error::ExternalSigner(format!("unexpected response id {} (expected {})", response.id, request.id)));
+            }
+            responses.push(response);
+        }
+
+        Ok(responses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_external_signer_endpoint() {
+        assert_eq!(
+            "stdio:calico-signer --key-file key.txt".parse::<SignerEndpoint>().unwrap(),
+            SignerEndpoint::Stdio("calico-signer --key-file key.txt".to_string())
+        );
+        assert_eq!("tcp:127.0.0.1:17420".parse::<SignerEndpoint>().unwrap(), SignerEndpoint::Tcp("127.0.0.1:17420".to_string()));
+        assert_eq!("unix:/tmp/signer.sock".parse::<SignerEndpoint>().unwrap().to_string(), "unix:/tmp/signer.sock");
+        assert!("udp:127.0.0.1:17420".parse::<SignerEndpoint>().is_err());
+        assert!("tcp:".parse::<SignerEndpoint>().is_err());
+    }
+}
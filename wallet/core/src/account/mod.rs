@@ -4,6 +4,7 @@
 //!
 
 pub mod descriptor;
+pub mod external_signer;
 pub mod kind;
 pub mod pssb;
 pub mod variants;
@@ -23,7 +24,9 @@ use crate::storage::account::AccountSettings;
 use crate::storage::AccountMetadata;
 use crate::storage::{PrvKeyData, PrvKeyDataId};
 use crate::tx::PaymentOutput;
-use crate::tx::{Fees, Generator, GeneratorSettings, GeneratorSummary, PaymentDestination, PendingTransaction, Signer};
+use crate::tx::{
+    CoinSelectionStrategy, Fees, Generator, GeneratorSettings, GeneratorSummary, PaymentDestination, PendingTransaction, Signer,
+};
 use crate::utxo::balance::{AtomicBalance, BalanceStrings};
 use crate::utxo::UtxoContextBinding;
 use calico_bip32::{ChildNumber, ExtendedPrivateKey, PrivateKey};
@@ -340,11 +343,38 @@ pub trait Account: AnySync + Send + Sync + 'static {
         payment_secret: Option<Secret>,
         abortable: &Abortable,
         notifier: Option<GenerationNotifier>,
+    ) -> Result<(GeneratorSummary, Vec<calico_hashes::Hash>)> {
+        self.send_with_coin_selection(
+            destination,
+            priority_fee_sompi,
+            payload,
+            CoinSelectionStrategy::Default,
+            wallet_secret,
+            payment_secret,
+            abortable,
+            notifier,
+        )
+        .await
+    }
+
+    /// Send funds to a [`PaymentDestination`] while consuming account UTXOs
+    /// according to the supplied [`CoinSelectionStrategy`].
+    async fn send_with_coin_selection(
+        self: Arc<Self>,
+        destination: PaymentDestination,
+        priority_fee_sompi: Fees,
+        payload: Option<Vec<u8>>,
+        coin_selection: CoinSelectionStrategy,
+        wallet_secret: Secret,
+        payment_secret: Option<Secret>,
+        abortable: &Abortable,
+        notifier: Option<GenerationNotifier>,
     ) -> Result<(GeneratorSummary, Vec<calico_hashes::Hash>)> {
         let keydata = self.prv_key_data(wallet_secret).await?;
         let signer = Arc::new(Signer::new(self.clone().as_dyn_arc(), keydata, payment_secret));
 
-        let settings = GeneratorSettings::try_new_with_account(self.clone().as_dyn_arc(), destination, priority_fee_sompi, payload)?;
+        let settings = GeneratorSettings::try_new_with_account(self.clone().as_dyn_arc(), destination, priority_fee_sompi, payload)?
+            .coin_selection_strategy(coin_selection);
 
         let generator = Generator::try_new(settings, Some(signer), Some(abortable))?;
 
@@ -480,7 +510,21 @@ pub trait Account: AnySync + Send + Sync + 'static {
         payload: Option<Vec<u8>>,
         abortable: &Abortable,
     ) -> Result<GeneratorSummary> {
-        let settings = GeneratorSettings::try_new_with_account(self.as_dyn_arc(), destination, priority_fee_sompi, payload)?;
+        self.estimate_with_coin_selection(destination, priority_fee_sompi, payload, CoinSelectionStrategy::Default, abortable).await
+    }
+
+    /// Estimate transaction fees while consuming account UTXOs
+    /// according to the supplied [`CoinSelectionStrategy`].
+    async fn estimate_with_coin_selection(
+        self: Arc<Self>,
+        destination: PaymentDestination,
+        priority_fee_sompi: Fees,
+        payload: Option<Vec<u8>>,
+        coin_selection: CoinSelectionStrategy,
+        abortable: &Abortable,
+    ) -> Result<GeneratorSummary> {
+        let settings = GeneratorSettings::try_new_with_account(self.as_dyn_arc(), destination, priority_fee_sompi, payload)?
+            .coin_selection_strategy(coin_selection);
 
         let generator = Generator::try_new(settings, None, Some(abortable))?;
 
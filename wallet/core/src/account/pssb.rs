@@ -14,6 +14,7 @@ use calico_consensus_core::tx::{TransactionInput, UtxoEntry};
 use calico_txscript::extract_script_pub_key_address;
 use calico_txscript::opcodes::codes::OpData65;
 use calico_txscript::script_builder::ScriptBuilder;
+use calico_txscript::{htlc_claim_signature_script, htlc_refund_signature_script, time_lock_signature_script, TimeLockCreateError};
 use calico_wallet_core::tx::{Generator, GeneratorSettings, PaymentDestination, PendingTransaction};
 pub use calico_wallet_psst::bundle::Bundle;
 use calico_wallet_psst::prelude::KeySource;
@@ -129,7 +130,7 @@ impl Stream for PSSTStream {
     }
 }
 
-fn convert_pending_tx_to_psst(pending_tx: PendingTransaction) -> Result<PSST<Signer>, Error> {
+pub fn convert_pending_tx_to_psst(pending_tx: PendingTransaction) -> Result<PSST<Signer>, Error> {
     let signable_tx = pending_tx.signable_transaction();
     let verifiable_tx = signable_tx.as_verifiable();
     let populated_inputs: Vec<(&TransactionInput, &UtxoEntry)> = verifiable_tx.populated_inputs().collect();
@@ -275,11 +276,51 @@ pub fn finalize_psst_no_sig_and_redeem_script(psst: PSST<Finalizer>) -> Result<P
     }
 }
 
+/// Finalize a PSST spending HTLC outputs (see [`calico_txscript::HtlcScript`])
+/// by revealing the secret `preimage` (claim path).
+pub fn finalize_psst_htlc_claim(psst: PSST<Finalizer>, preimage: &[u8]) -> Result<PSST<Finalizer>, Error> {
+    finalize_psst_single_sig(psst, |redeem_script, signature| htlc_claim_signature_script(redeem_script, signature, preimage))
+}
+
+/// Finalize a PSST spending HTLC outputs (see [`calico_txscript::HtlcScript`])
+/// after their lock time has passed (refund path).
+pub fn finalize_psst_htlc_refund(psst: PSST<Finalizer>) -> Result<PSST<Finalizer>, Error> {
+    finalize_psst_single_sig(psst, htlc_refund_signature_script)
+}
+
+/// Finalize a PSST spending time-locked outputs (see [`calico_txscript::TimeLockScript`]).
+pub fn finalize_psst_time_lock(psst: PSST<Finalizer>) -> Result<PSST<Finalizer>, Error> {
+    finalize_psst_single_sig(psst, time_lock_signature_script)
+}
+
+fn finalize_psst_single_sig<F>(psst: PSST<Finalizer>, signature_script: F) -> Result<PSST<Finalizer>, Error>
+where
+    F: Fn(&[u8], &[u8]) -> std::result::Result<Vec<u8>, TimeLockCreateError>,
+{
+    let result = psst.finalize_sync(|inner: &Inner| -> Result<Vec<Vec<u8>>, Error> {
+        inner
+            .inputs
+            .iter()
+            .enumerate()
+            .map(|(index, input)| {
+                let redeem_script =
+                    input.redeem_script.as_ref().ok_or_else(|| Error::from(format!("input {index} has no redeem script")))?;
+                let signature =
+                    input.partial_sigs.values().next().ok_or_else(|| Error::from(format!("input {index} is not signed")))?;
+                let signature = signature.into_bytes().into_iter().chain([input.sighash_type.to_u8()]).collect::<Vec<_>>();
+                signature_script(redeem_script, &signature).map_err(|err| Error::from(err.to_string()))
+            })
+            .collect()
+    });
+
+    result.map_err(|err| Error::from(err.to_string()))
+}
+
 pub fn bundle_to_finalizer_stream(bundle: &Bundle) -> impl Stream<Item = Result<PSST<Finalizer>, Error>> + Send {
     stream::iter(bundle.iter().cloned().collect::<Vec<_>>()).map(move |psst_inner| {
         let psst: PSST<Creator> = PSST::from(psst_inner);
         let psst_finalizer = psst.constructor().updater().signer().finalizer();
-        finalize_psst_one_or_more_sig_and_redeem_script(psst_finalizer)
+        psst_finalizer.finalize_standard().map_err(|err| Error::from(err.to_string()))
     })
 }
 
@@ -341,6 +382,7 @@ pub fn psst_to_pending_transaction(
         final_transaction_priority_fee: fee_u.into(),
         final_transaction_destination,
         final_transaction_payload: None,
+        coin_selection: None,
     };
 
     // Create the Generator
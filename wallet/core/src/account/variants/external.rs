@@ -0,0 +1,478 @@
+//!
+//! External signer account implementation. Private keys of this
+//! account are held by an external signer (an air-gapped machine or
+//! a hardware device). Transactions are exported as PSST bundles and
+//! signed either over the external signer protocol or manually by
+//! exchanging PSSB payloads with the signer.
+//!
+
+use crate::account::external_signer::{ExternalSignerClient, SignerEndpoint};
+use crate::account::pssb::convert_pending_tx_to_psst;
+use crate::account::{GenerationNotifier, Inner};
+use crate::derivation::{AddressDerivationManager, AddressDerivationManagerTrait};
+use crate::imports::*;
+use crate::tx::{
+    CoinSelectionStrategy, Fees, Generator, GeneratorSettings, GeneratorSummary, PaymentDestination, PaymentOutput, PendingTransaction,
+};
+use calico_bip32::DerivationPath;
+use calico_hashes::Hash;
+use calico_txscript::extract_script_pub_key_address;
+use calico_wallet_psst::prelude::{Bundle, Finalizer, KeySource, Signer, Updater, PSST};
+use calico_wallet_psst::psst::Inner as PSSTInner;
+use workflow_core::abortable::Abortable;
+
+pub const EXTERNAL_ACCOUNT_KIND: &str = "calico-external-signer";
+
+pub struct Ctor {}
+
+#[async_trait]
+impl Factory for Ctor {
+    fn name(&self) -> String {
+        "external".to_string()
+    }
+
+    fn description(&self) -> String {
+        "Calico Core External Signer Account".to_string()
+    }
+
+    async fn try_load(
+        &self,
+        wallet: &Arc<Wallet>,
+        storage: &AccountStorage,
+        meta: Option<Arc<AccountMetadata>>,
+    ) -> Result<Arc<dyn Account>> {
+        Ok(Arc::new(external::External::try_load(wallet, storage, meta).await?))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct Payload {
+    pub xpub_keys: ExtendedPublicKeys,
+    pub ecdsa: bool,
+    pub endpoint: Option<String>,
+}
+
+impl Payload {
+    pub fn new(xpub_keys: Arc<Vec<ExtendedPublicKeySecp256k1>>, ecdsa: bool, endpoint: Option<String>) -> Self {
+        Self { xpub_keys, ecdsa, endpoint }
+    }
+
+    pub fn try_load(storage: &AccountStorage) -> Result<Self> {
+        Ok(Self::try_from_slice(storage.serialized.as_slice())?)
+    }
+}
+
+impl Storable for Payload {
+    // a unique number used for binary
+    // serialization data alignment check
+    const STORAGE_MAGIC: u32 = 0x4e545845;
+    // binary serialization version
+    const STORAGE_VERSION: u32 = 0;
+}
+
+impl AccountStorable for Payload {}
+
+impl BorshSerialize for Payload {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        StorageHeader::new(Self::STORAGE_MAGIC, Self::STORAGE_VERSION).serialize(writer)?;
+        BorshSerialize::serialize(&self.xpub_keys, writer)?;
+        BorshSerialize::serialize(&self.ecdsa, writer)?;
+        BorshSerialize::serialize(&self.endpoint, writer)?;
+
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for Payload {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let StorageHeader { version: _, .. } =
+            StorageHeader::deserialize_reader(reader)?.try_magic(Self::STORAGE_MAGIC)?.try_version(Self::STORAGE_VERSION)?;
+
+        let xpub_keys = BorshDeserialize::deserialize_reader(reader)?;
+        let ecdsa = BorshDeserialize::deserialize_reader(reader)?;
+        let endpoint = BorshDeserialize::deserialize_reader(reader)?;
+
+        Ok(Self { xpub_keys, ecdsa, endpoint })
+    }
+}
+
+pub struct External {
+    inner: Arc<Inner>,
+    xpub_keys: ExtendedPublicKeys,
+    ecdsa: bool,
+    endpoint: Option<SignerEndpoint>,
+    derivation: Arc<AddressDerivationManager>,
+}
+
+impl External {
+    pub async fn try_new(
+        wallet: &Arc<Wallet>,
+        name: Option<String>,
+        xpub_keys: ExtendedPublicKeys,
+        ecdsa: bool,
+        endpoint: Option<SignerEndpoint>,
+    ) -> Result<Self> {
+        let settings = AccountSettings { name, ..Default::default() };
+
+        let public_key = xpub_keys.first().ok_or_else(|| Error::ExternalXpubRequired)?.public_key();
+
+        let (id, storage_key) = make_account_hashes(from_external(public_key));
+
+        let inner = Arc::new(Inner::new(wallet, id, storage_key, settings));
+
+        let derivation =
+            AddressDerivationManager::new(wallet, EXTERNAL_ACCOUNT_KIND.into(), &xpub_keys, ecdsa, 0, None, 1, Default::default())
+                .await?;
+
+        Ok(Self { inner, xpub_keys, ecdsa, endpoint, derivation })
+    }
+
+    pub async fn try_load(wallet: &Arc<Wallet>, storage: &AccountStorage, meta: Option<Arc<AccountMetadata>>) -> Result<Self> {
+        let storable = Payload::try_load(storage)?;
+        let inner = Arc::new(Inner::from_storage(wallet, storage));
+        let Payload { xpub_keys, ecdsa, endpoint } = storable;
+        let endpoint = endpoint.map(|endpoint| endpoint.parse::<SignerEndpoint>()).transpose()?;
+        let address_derivation_indexes = meta.and_then(|meta| meta.address_derivation_indexes()).unwrap_or_default();
+
+        let derivation = AddressDerivationManager::new(
+            wallet,
+            EXTERNAL_ACCOUNT_KIND.into(),
+            &xpub_keys,
+            ecdsa,
+            0,
+            None,
+            1,
+            address_derivation_indexes,
+        )
+        .await?;
+
+        Ok(Self { inner, xpub_keys, ecdsa, endpoint, derivation })
+    }
+
+    pub fn endpoint(&self) -> Option<&SignerEndpoint> {
+        self.endpoint.as_ref()
+    }
+
+    /// Hex-encoded fingerprint of the account extended public key.
+    /// Used by the external signer to identify the signing key.
+    pub fn fingerprint(&self) -> Result<String> {
+        let xpub = self.xpub_keys.first().ok_or_else(|| Error::ExternalXpubRequired)?;
+        Ok(xpub.fingerprint().as_slice().to_hex())
+    }
+
+    /// Annotate PSST inputs with public keys and derivation paths (`bip32Derivations`)
+    /// required by the external signer to derive the signing keys.
+    pub fn annotate_psst(&self, inner: PSSTInner) -> Result<PSST<Signer>> {
+        let xpub = self.xpub_keys.first().ok_or_else(|| Error::ExternalXpubRequired)?;
+        let key_fingerprint = xpub.fingerprint();
+        let prefix = self.wallet().address_prefix()?;
+
+        let addresses = inner
+            .inputs
+            .iter()
+            .map(|input| {
+                let utxo_entry = input.utxo_entry.as_ref().ok_or(calico_wallet_psst::error::Error::MissingUtxoEntry)?;
+                Ok(extract_script_pub_key_address(&utxo_entry.script_public_key, prefix)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut psst = PSST::<Updater>::from(inner);
+        for (input_index, address) in addresses.iter().enumerate() {
+            let (receive, change) = self.derivation.addresses_indexes(&[address])?;
+            let (address_type, index) = match (receive.first(), change.first()) {
+                (Some((_, index)), _) => (0, *index),
+                (None, Some((_, index))) => (1, *index),
+                (None, None) => return Err(Error::Custom(format!("Address ({address}) index not found."))),
+            };
+
+            let derivation_path = DerivationPath::from_str(&format!("m/{address_type}/{index}"))?;
+            let pub_key = *xpub.clone().derive_path(&derivation_path)?.public_key();
+            psst = psst.set_bip32_derivation(pub_key, KeySource::new(key_fingerprint, derivation_path), input_index)?;
+        }
+
+        Ok(psst.signer())
+    }
+
+    /// Create a PSST bundle containing the supplied pending transactions.
+    pub fn bundle_from_pending_transactions(&self, transactions: &[PendingTransaction]) -> Result<Bundle> {
+        let mut bundle = Bundle::new();
+        for transaction in transactions {
+            let psst = convert_pending_tx_to_psst(transaction.clone())?;
+            bundle.add_psst(self.annotate_psst(psst.deref().clone())?);
+        }
+        Ok(bundle)
+    }
+
+    /// Sign the supplied bundle using the configured external signer.
+    pub async fn sign_bundle(&self, bundle: &Bundle) -> Result<Bundle> {
+        let endpoint = self.endpoint.clone().ok_or(Error::ExternalSignerEndpointRequired)?;
+        let network_id = self.wallet().network_id()?;
+        ExternalSignerClient::new(endpoint).sign(&network_id, &self.fingerprint()?, bundle).await
+    }
+
+    /// Sign a pending transaction using the configured external signer
+    /// and populate its inputs with the resulting signature scripts.
+    pub async fn sign_pending_transaction(&self, transaction: &PendingTransaction) -> Result<()> {
+        let bundle = self.bundle_from_pending_transactions(std::slice::from_ref(transaction))?;
+        let signed = self.sign_bundle(&bundle).await?;
+
+        let inner = signed.iter().next().cloned().ok_or(Error::ExternalSignerBundleMismatch)?;
+        if signed.iter().count() != 1 || PSST::<Signer>::from(inner.clone()).calculate_id() != transaction.id() {
+            return Err(Error::ExternalSignerBundleMismatch);
+        }
+
+        if let Some(index) = inner.inputs.iter().position(|input| input.partial_sigs.is_empty()) {
+            return Err(Error::ExternalSigner(format!("signer did not sign input {index}")));
+        }
+
+        let finalized = PSST::<Finalizer>::from(inner).finalize_standard().map_err(|err| Error::from(err.to_string()))?;
+        for (input_index, input) in finalized.inputs.iter().enumerate() {
+            transaction.fill_input(input_index, input.final_script_sig.clone().unwrap_or_default())?;
+        }
+
+        Ok(())
+    }
+
+    async fn sign_and_submit(
+        &self,
+        settings: GeneratorSettings,
+        abortable: &Abortable,
+        notifier: Option<GenerationNotifier>,
+    ) -> Result<(GeneratorSummary, Vec<Hash>)> {
+        let generator = Generator::try_new(settings, None, Some(abortable))?;
+
+        let mut stream = generator.stream();
+        let mut ids = vec![];
+        while let Some(transaction) = stream.try_next().await? {
+            self.sign_pending_transaction(&transaction).await?;
+            ids.push(transaction.try_submit(&self.wallet().rpc_api()).await?);
+
+            if let Some(notifier) = notifier.as_ref() {
+                notifier(&transaction);
+            }
+            yield_executor().await;
+        }
+
+        Ok((generator.summary(), ids))
+    }
+
+    pub fn get_address_range_for_scan(&self, range: std::ops::Range<u32>) -> Result<Vec<Address>> {
+        let receive_addresses = self.derivation.receive_address_manager().get_range_with_args(range.clone(), false)?;
+        let change_addresses = self.derivation.change_address_manager().get_range_with_args(range, false)?;
+        Ok(receive_addresses.into_iter().chain(change_addresses).collect::<Vec<_>>())
+    }
+}
+
+#[async_trait]
+impl Account for External {
+    fn inner(&self) -> &Arc<Inner> {
+        &self.inner
+    }
+
+    fn account_kind(&self) -> AccountKind {
+        EXTERNAL_ACCOUNT_KIND.into()
+    }
+
+    fn feature(&self) -> Option<String> {
+        let info = "external-signer";
+        Some(info.into())
+    }
+
+    fn xpub_keys(&self) -> Option<&ExtendedPublicKeys> {
+        Some(&self.xpub_keys)
+    }
+
+    fn prv_key_data_id(&self) -> Result<&PrvKeyDataId> {
+        Err(Error::ExternalSignerAccount)
+    }
+
+    fn as_dyn_arc(self: Arc<Self>) -> Arc<dyn Account> {
+        self
+    }
+
+    fn sig_op_count(&self) -> u8 {
+        u8::try_from(self.xpub_keys.len()).unwrap()
+    }
+
+    fn minimum_signatures(&self) -> u16 {
+        1
+    }
+
+    fn receive_address(&self) -> Result<Address> {
+        self.derivation.receive_address_manager().current_address()
+    }
+    fn change_address(&self) -> Result<Address> {
+        self.derivation.change_address_manager().current_address()
+    }
+
+    fn to_storage(&self) -> Result<AccountStorage> {
+        let settings = self.context().settings.clone();
+        let storable = Payload::new(self.xpub_keys.clone(), self.ecdsa, self.endpoint.as_ref().map(|endpoint| endpoint.to_string()));
+
+        let storage = AccountStorage::try_new(
+            EXTERNAL_ACCOUNT_KIND.into(),
+            self.id(),
+            self.storage_key(),
+            AssocPrvKeyDataIds::None,
+            settings,
+            storable,
+        )?;
+
+        Ok(storage)
+    }
+
+    fn metadata(&self) -> Result<Option<AccountMetadata>> {
+        let metadata = AccountMetadata::new(self.inner.id, self.derivation.address_derivation_meta());
+        Ok(Some(metadata))
+    }
+
+    fn descriptor(&self) -> Result<AccountDescriptor> {
+        let descriptor = AccountDescriptor::new(
+            EXTERNAL_ACCOUNT_KIND.into(),
+            *self.id(),
+            self.name(),
+            self.balance(),
+            AssocPrvKeyDataIds::None,
+            self.receive_address().ok(),
+            self.change_address().ok(),
+        )
+        .with_property(AccountDescriptorProperty::XpubKeys, self.xpub_keys.clone().into())
+        .with_property(AccountDescriptorProperty::Ecdsa, self.ecdsa.into())
+        .with_property(AccountDescriptorProperty::DerivationMeta, self.derivation.address_derivation_meta().into());
+
+        Ok(descriptor)
+    }
+
+    async fn sweep(
+        self: Arc<Self>,
+        _wallet_secret: Secret,
+        _payment_secret: Option<Secret>,
+        abortable: &Abortable,
+        notifier: Option<GenerationNotifier>,
+    ) -> Result<(GeneratorSummary, Vec<Hash>)> {
+        let settings =
+            GeneratorSettings::try_new_with_account(self.clone().as_dyn_arc(), PaymentDestination::Change, Fees::None, None)?;
+        self.sign_and_submit(settings, abortable, notifier).await
+    }
+
+    async fn send_with_coin_selection(
+        self: Arc<Self>,
+        destination: PaymentDestination,
+        priority_fee_sompi: Fees,
+        payload: Option<Vec<u8>>,
+        coin_selection: CoinSelectionStrategy,
+        _wallet_secret: Secret,
+        _payment_secret: Option<Secret>,
+        abortable: &Abortable,
+        notifier: Option<GenerationNotifier>,
+    ) -> Result<(GeneratorSummary, Vec<Hash>)> {
+        let settings = GeneratorSettings::try_new_with_account(self.clone().as_dyn_arc(), destination, priority_fee_sompi, payload)?
+            .coin_selection_strategy(coin_selection);
+        self.sign_and_submit(settings, abortable, notifier).await
+    }
+
+    async fn transfer(
+        self: Arc<Self>,
+        destination_account_id: AccountId,
+        transfer_amount_sompi: u64,
+        priority_fee_sompi: Fees,
+        _wallet_secret: Secret,
+        _payment_secret: Option<Secret>,
+        abortable: &Abortable,
+        notifier: Option<GenerationNotifier>,
+        guard: &WalletGuard,
+    ) -> Result<(GeneratorSummary, Vec<Hash>)> {
+        let destination_account = self
+            .wallet()
+            .get_account_by_id(&destination_account_id, guard)
+            .await?
+            .ok_or_else(|| Error::AccountNotFound(destination_account_id))?;
+
+        let destination_address = destination_account.receive_address()?;
+        let final_transaction_destination = PaymentDestination::from(PaymentOutput::new(destination_address, transfer_amount_sompi));
+
+        let settings = GeneratorSettings::try_new_with_account(
+            self.clone().as_dyn_arc(),
+            final_transaction_destination,
+            priority_fee_sompi,
+            None,
+        )?
+        .utxo_context_transfer(destination_account.utxo_context());
+
+        self.sign_and_submit(settings, abortable, notifier).await
+    }
+
+    /// Create an unsigned PSST bundle annotated with key derivation
+    /// information, to be signed by the external signer.
+    async fn pssb_from_send_generator(
+        self: Arc<Self>,
+        destination: PaymentDestination,
+        priority_fee_sompi: Fees,
+        payload: Option<Vec<u8>>,
+        _wallet_secret: Secret,
+        _payment_secret: Option<Secret>,
+        abortable: &Abortable,
+    ) -> Result<Bundle, Error> {
+        let settings = GeneratorSettings::try_new_with_account(self.clone().as_dyn_arc(), destination, priority_fee_sompi, payload)?;
+        let generator = Generator::try_new(settings, None, Some(abortable))?;
+
+        let mut stream = generator.stream();
+        let mut transactions = vec![];
+        while let Some(transaction) = stream.try_next().await? {
+            transactions.push(transaction);
+        }
+
+        self.bundle_from_pending_transactions(&transactions)
+    }
+
+    async fn pssb_sign(
+        self: Arc<Self>,
+        bundle: &Bundle,
+        _wallet_secret: Secret,
+        _payment_secret: Option<Secret>,
+        _sign_for_address: Option<&Address>,
+    ) -> Result<Bundle, Error> {
+        let mut annotated = Bundle::new();
+        for inner in bundle.iter().cloned() {
+            annotated.add_psst(self.annotate_psst(inner)?);
+        }
+        self.sign_bundle(&annotated).await
+    }
+
+    fn as_derivation_capable(self: Arc<Self>) -> Result<Arc<dyn DerivationCapableAccount>> {
+        Ok(self.clone())
+    }
+}
+
+impl DerivationCapableAccount for External {
+    fn derivation(&self) -> Arc<dyn AddressDerivationManagerTrait> {
+        self.derivation.clone()
+    }
+
+    fn account_index(&self) -> u64 {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_storage_external() -> Result<()> {
+        let storable_in = Payload::new(vec![make_xpub()].into(), false, Some("tcp:127.0.0.1:17420".to_string()));
+        let guard = StorageGuard::new(&storable_in);
+        let storable_out = guard.validate()?;
+
+        assert_eq!(storable_in.ecdsa, storable_out.ecdsa);
+        assert_eq!(storable_in.endpoint, storable_out.endpoint);
+        assert_eq!(storable_in.xpub_keys.len(), storable_out.xpub_keys.len());
+        for idx in 0..storable_in.xpub_keys.len() {
+            assert_eq!(storable_in.xpub_keys[idx], storable_out.xpub_keys[idx]);
+        }
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@
 
 pub mod bip32;
 pub mod bip32watch;
+pub mod external;
 pub mod keypair;
 pub mod legacy;
 pub mod multisig;
@@ -11,6 +12,7 @@ pub mod resident;
 
 pub use bip32::BIP32_ACCOUNT_KIND;
 pub use bip32watch::BIP32_WATCH_ACCOUNT_KIND;
+pub use external::EXTERNAL_ACCOUNT_KIND;
 pub use keypair::KEYPAIR_ACCOUNT_KIND;
 pub use legacy::LEGACY_ACCOUNT_KIND;
 pub use multisig::MULTISIG_ACCOUNT_KIND;
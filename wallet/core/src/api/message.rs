@@ -367,6 +367,8 @@ pub struct AccountsDiscoveryRequest {
 #[serde(rename_all = "camelCase")]
 pub struct AccountsDiscoveryResponse {
     pub last_account_index_found: u32,
+    /// Discovered accounts along with their next address derivation indexes
+    pub accounts: Vec<DiscoveredAccount>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
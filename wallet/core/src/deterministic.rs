@@ -2,7 +2,7 @@
 //! Deterministic byte sequence generation (used by Account ids).
 //!
 
-pub use crate::account::{bip32, bip32watch, keypair, legacy, multisig};
+pub use crate::account::{bip32, bip32watch, external, keypair, legacy, multisig};
 use crate::encryption::sha256_hash;
 use crate::imports::*;
 use crate::storage::PrvKeyDataId;
@@ -203,6 +203,19 @@ pub fn from_bip32_watch<const N: usize>(public_key: &PublicKey) -> [Hash; N] {
     make_hashes(hashable)
 }
 
+/// Create deterministic hashes from an external signer account xpub.
+pub fn from_external<const N: usize>(public_key: &PublicKey) -> [Hash; N] {
+    let hashable: DeterministicHashData<[PrvKeyDataId; 0]> = DeterministicHashData {
+        account_kind: &external::EXTERNAL_ACCOUNT_KIND.into(),
+        prv_key_data_ids: &None,
+        ecdsa: None,
+        account_index: Some(0),
+        secp256k1_public_key: Some(public_key.serialize().to_vec()),
+        data: None,
+    };
+    make_hashes(hashable)
+}
+
 /// Create deterministic hashes from arbitrary data (supplied data slice must be deterministic).
 pub fn from_data<const N: usize>(account_kind: &AccountKind, data: &[u8]) -> [Hash; N] {
     let hashable: DeterministicHashData<[PrvKeyDataId; 0]> = DeterministicHashData {
@@ -238,6 +238,24 @@ pub enum Error {
     #[error("At least one xpub is required for a bip32-watch account")]
     Bip32WatchXpubRequired,
 
+    #[error("Not allowed on an external signer account")]
+    ExternalSignerAccount,
+
+    #[error("External signer endpoint is not configured for this account")]
+    ExternalSignerEndpointRequired,
+
+    #[error("An xpub is required for an external signer account")]
+    ExternalXpubRequired,
+
+    #[error("Invalid external signer endpoint '{0}' (expected 'stdio:<command>', 'tcp:<host:port>' or 'unix:<path>')")]
+    InvalidExternalSignerEndpoint(String),
+
+    #[error("External signer error: {0}")]
+    ExternalSigner(String),
+
+    #[error("External signer returned a bundle that does not match the submitted transaction")]
+    ExternalSignerBundleMismatch,
+
     #[error("This feature is not supported by this account type")]
     AccountKindFeature,
 
@@ -277,6 +295,9 @@ pub enum Error {
     #[error("Transaction exceeds the maximum allowed mass")]
     GeneratorTransactionIsTooHeavy,
 
+    #[error("Invalid coin selection strategy: {0}")]
+    InvalidCoinSelectionStrategy(String),
+
     #[error("Storage mass exceeds maximum")]
     StorageMassExceedsMaximumTransactionMass { storage_mass: u64 },
 
@@ -131,6 +131,23 @@ pub enum Events {
     AccountUpdate {
         account_descriptor: AccountDescriptor,
     },
+    /// Emitted during BIP44 account discovery after
+    /// scanning each window of account addresses.
+    AccountDiscoveryProgress {
+        #[serde(rename = "accountIndex")]
+        account_index: u64,
+        /// `true` if the change address chain is being scanned
+        change: bool,
+        /// Number of chain addresses scanned so far
+        scanned: u32,
+        /// Index of the last chain address holding UTXOs
+        #[serde(rename = "lastUsed")]
+        last_used: Option<u32>,
+    },
+    /// BIP44 account discovery has completed
+    AccountDiscoveryComplete {
+        accounts: Vec<DiscoveredAccount>,
+    },
     /// Emitted after successful RPC connection
     /// after the initial state negotiation.
     ServerStatus {
@@ -272,6 +289,8 @@ pub enum EventKind {
     AccountSelection,
     AccountCreate,
     AccountUpdate,
+    AccountDiscoveryProgress,
+    AccountDiscoveryComplete,
     ServerStatus,
     UtxoProcStart,
     UtxoProcStop,
@@ -308,6 +327,8 @@ impl From<&Events> for EventKind {
             Events::AccountSelection { .. } => EventKind::AccountSelection,
             Events::AccountCreate { .. } => EventKind::AccountCreate,
             Events::AccountUpdate { .. } => EventKind::AccountUpdate,
+            Events::AccountDiscoveryProgress { .. } => EventKind::AccountDiscoveryProgress,
+            Events::AccountDiscoveryComplete { .. } => EventKind::AccountDiscoveryComplete,
             Events::ServerStatus { .. } => EventKind::ServerStatus,
             Events::UtxoProcStart => EventKind::UtxoProcStart,
             Events::UtxoProcStop => EventKind::UtxoProcStop,
@@ -347,6 +368,8 @@ impl FromStr for EventKind {
             "account-selection" => Ok(EventKind::AccountSelection),
             "account-create" => Ok(EventKind::AccountCreate),
             "account-update" => Ok(EventKind::AccountUpdate),
+            "account-discovery-progress" => Ok(EventKind::AccountDiscoveryProgress),
+            "account-discovery-complete" => Ok(EventKind::AccountDiscoveryComplete),
             "server-status" => Ok(EventKind::ServerStatus),
             "utxo-proc-start" => Ok(EventKind::UtxoProcStart),
             "utxo-proc-stop" => Ok(EventKind::UtxoProcStop),
@@ -394,6 +417,8 @@ impl std::fmt::Display for EventKind {
             EventKind::AccountSelection => "account-selection",
             EventKind::AccountCreate => "account-create",
             EventKind::AccountUpdate => "account-update",
+            EventKind::AccountDiscoveryProgress => "account-discovery-progress",
+            EventKind::AccountDiscoveryComplete => "account-discovery-complete",
             EventKind::ServerStatus => "server-status",
             EventKind::UtxoProcStart => "utxo-proc-start",
             EventKind::UtxoProcStop => "utxo-proc-stop",
@@ -21,6 +21,8 @@ pub enum WalletSettings {
     Network,
     #[describe("Server address (default: 127.0.0.1)")]
     Server,
+    #[describe("RPC server API key (default: none)")]
+    ApiKey,
     #[describe("Wallet storage or file name (default 'calico')")]
     Wallet,
 }
@@ -94,6 +96,15 @@ where
         Ok(())
     }
 
+    pub async fn clear(&self, key: K) -> Result<()> {
+        let ks = to_value(key).unwrap();
+        let ks = ks.as_str().expect("Unable to convert key to string");
+
+        self.map.remove(ks);
+        self.try_store().await?;
+        Ok(())
+    }
+
     pub async fn try_load(&self) -> Result<()> {
         let list: Option<Value> = if self.storage.exists().await? {
             let v: Result<Value> = workflow_store::fs::read_json(self.storage.filename()).await.map_err(|err| err.into());
@@ -60,8 +60,8 @@
 use crate::imports::*;
 use crate::result::Result;
 use crate::tx::{
-    mass::*, Fees, GeneratorSettings, GeneratorSummary, PaymentDestination, PendingTransaction, PendingTransactionIterator,
-    PendingTransactionStream,
+    mass::*, CoinSelectionParams, Fees, GeneratorSettings, GeneratorSummary, PaymentDestination, PendingTransaction,
+    PendingTransactionIterator, PendingTransactionStream,
 };
 use crate::utxo::{NetworkParams, UtxoContext, UtxoEntryReference};
 use calico_consensus_client::UtxoEntry;
@@ -353,6 +353,7 @@ impl Generator {
             final_transaction_destination,
             final_transaction_payload,
             destination_utxo_context,
+            coin_selection,
         } = settings;
 
         let network_type = NetworkType::from(network_id);
@@ -422,6 +423,29 @@ impl Generator {
             return Err(Error::GeneratorTransactionOutputsAreTooHeavy { mass: mass_sanity_check, kind: "compute mass" });
         }
 
+        // apply coin selection by collecting the source iterator and
+        // re-ordering UTXO entries according to the selected strategy
+        let utxo_iterator = match coin_selection {
+            Some(coin_selection) => {
+                let input = TransactionInput::new(TransactionOutpoint::default(), vec![], 0, sig_op_count);
+                let input_compute_mass =
+                    mass_calculator.calc_compute_mass_for_client_transaction_input(&input) + signature_mass_per_input;
+                let base_compute_mass = mass_calculator.blank_transaction_compute_mass()
+                    + final_transaction_outputs_compute_mass
+                    + final_transaction_payload_mass;
+                let params = CoinSelectionParams {
+                    target: final_transaction.as_ref().map(|final_transaction| final_transaction.value_with_priority_fee),
+                    base_fee: mass_calculator.calc_minimum_transaction_fee_from_mass(base_compute_mass),
+                    fee_per_input: mass_calculator.calc_minimum_transaction_fee_from_mass(input_compute_mass),
+                    cost_of_change: mass_calculator
+                        .calc_minimum_transaction_fee_from_mass(standard_change_output_mass + input_compute_mass),
+                };
+                let entries = coin_selection.select(utxo_iterator.collect(), &params);
+                Box::new(entries.into_iter()) as Box<dyn Iterator<Item = UtxoEntryReference> + Send + Sync + 'static>
+            }
+            None => utxo_iterator,
+        };
+
         let priority_utxo_entry_filter = priority_utxo_entries.as_ref().map(|entries| entries.iter().cloned().collect());
         // remap to VecDeque as this list gets drained
         let priority_utxo_entries = priority_utxo_entries.map(|entries| entries.into_iter().collect::<VecDeque<_>>());
@@ -0,0 +1,306 @@
+//!
+//! Coin selection strategies used by the [`Generator`](crate::tx::Generator)
+//! to determine which UTXO entries are consumed and in which order.
+//!
+//! The [`Generator`](crate::tx::Generator) consumes UTXO entries sequentially
+//! until the requested amount (plus fees) is reached. A [`CoinSelection`]
+//! implementation receives the full set of available UTXO entries and returns
+//! them re-ordered so that the preferred entries are consumed first. Entries
+//! that are not part of the preferred selection are retained at the end of
+//! the list so that the generator can still fall back on them if the fee
+//! estimate used during selection turns out to be insufficient.
+//!
+
+use crate::imports::*;
+use crate::utxo::UtxoEntryReference;
+use std::cmp::Reverse;
+
+/// Maximum number of UTXO entries considered by the branch-and-bound search.
+const BRANCH_AND_BOUND_MAX_CANDIDATES: usize = 1024;
+/// Maximum number of search iterations performed by the branch-and-bound search.
+const BRANCH_AND_BOUND_MAX_TRIES: usize = 100_000;
+
+/// Parameters supplied by the [`Generator`](crate::tx::Generator)
+/// to a [`CoinSelection`] implementation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CoinSelectionParams {
+    /// Total value of the final transaction outputs including the priority fee.
+    /// `None` for sweep transactions, in which case all entries are consumed.
+    pub target: Option<u64>,
+    /// Estimated fee required by a transaction without any inputs.
+    pub base_fee: u64,
+    /// Estimated fee incurred by each additional input.
+    pub fee_per_input: u64,
+    /// Estimated cost of creating a change output and spending it later.
+    pub cost_of_change: u64,
+}
+
+impl CoinSelectionParams {
+    /// Value that the selected inputs need to cover if `count` inputs are consumed.
+    pub fn required(&self, count: usize) -> Option<u64> {
+        self.target.map(|target| target + self.base_fee + self.fee_per_input * count as u64)
+    }
+
+    /// Value contributed by a UTXO entry after deducting the fee of spending it.
+    pub fn effective_value(&self, entry: &UtxoEntryReference) -> u64 {
+        entry.amount().saturating_sub(self.fee_per_input)
+    }
+}
+
+/// Coin selection interface used by the [`Generator`](crate::tx::Generator).
+pub trait CoinSelection: Send + Sync + 'static {
+    /// Re-orders supplied UTXO `entries` so that preferred entries are consumed first.
+    fn select(&self, entries: Vec<UtxoEntryReference>, params: &CoinSelectionParams) -> Vec<UtxoEntryReference>;
+}
+
+/// Built-in coin selection strategies.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CoinSelectionStrategy {
+    /// Consume UTXO entries in the order supplied by the UTXO source.
+    #[default]
+    Default,
+    /// Consume the largest UTXO entries first, minimizing the number of inputs.
+    LargestFirst,
+    /// Search for a set of UTXO entries matching the target amount
+    /// closely enough to avoid the creation of a change output.
+    BranchAndBound,
+    /// Consume the oldest UTXO entries (by DAA score) first.
+    OldestFirst,
+    /// Minimize the number of distinct addresses linked by the transaction.
+    Privacy,
+}
+
+impl CoinSelectionStrategy {
+    pub fn list() -> &'static [CoinSelectionStrategy] {
+        &[
+            CoinSelectionStrategy::Default,
+            CoinSelectionStrategy::LargestFirst,
+            CoinSelectionStrategy::BranchAndBound,
+            CoinSelectionStrategy::OldestFirst,
+            CoinSelectionStrategy::Privacy,
+        ]
+    }
+
+    pub fn describe(&self) -> &'static str {
+        match self {
+            CoinSelectionStrategy::Default => "Use UTXO entries in the order provided by the UTXO context",
+            CoinSelectionStrategy::LargestFirst => "Use the largest UTXO entries first (fewer inputs, lower fees)",
+            CoinSelectionStrategy::BranchAndBound => "Search for an exact match that avoids a change output",
+            CoinSelectionStrategy::OldestFirst => "Use the oldest UTXO entries (by DAA score) first",
+            CoinSelectionStrategy::Privacy => "Minimize the number of addresses linked by the transaction",
+        }
+    }
+}
+
+impl std::fmt::Display for CoinSelectionStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CoinSelectionStrategy::Default => write!(f, "default"),
+            CoinSelectionStrategy::LargestFirst => write!(f, "largest-first"),
+            CoinSelectionStrategy::BranchAndBound => write!(f, "branch-and-bound"),
+            CoinSelectionStrategy::OldestFirst => write!(f, "oldest-first"),
+            CoinSelectionStrategy::Privacy => write!(f, "privacy"),
+        }
+    }
+}
+
+impl FromStr for CoinSelectionStrategy {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "default" => Ok(CoinSelectionStrategy::Default),
+            "largest-first" | "largest" => Ok(CoinSelectionStrategy::LargestFirst),
+            "branch-and-bound" | "bnb" | "exact" => Ok(CoinSelectionStrategy::BranchAndBound),
+            "oldest-first" | "oldest" => Ok(CoinSelectionStrategy::OldestFirst),
+            "privacy" => Ok(CoinSelectionStrategy::Privacy),
+            _ => Err(Error::InvalidCoinSelectionStrategy(s.to_string())),
+        }
+    }
+}
+
+impl CoinSelection for CoinSelectionStrategy {
+    fn select(&self, entries: Vec<UtxoEntryReference>, params: &CoinSelectionParams) -> Vec<UtxoEntryReference> {
+        match self {
+            CoinSelectionStrategy::Default => entries,
+            CoinSelectionStrategy::LargestFirst => LargestFirst.select(entries, params),
+            CoinSelectionStrategy::BranchAndBound => BranchAndBound.select(entries, params),
+            CoinSelectionStrategy::OldestFirst => OldestFirst.select(entries, params),
+            CoinSelectionStrategy::Privacy => PrivacyPreserving.select(entries, params),
+        }
+    }
+}
+
+/// Largest-first coin selection.
+pub struct LargestFirst;
+
+impl CoinSelection for LargestFirst {
+    fn select(&self, mut entries: Vec<UtxoEntryReference>, _params: &CoinSelectionParams) -> Vec<UtxoEntryReference> {
+        entries.sort_by_key(|entry| Reverse(entry.amount()));
+        entries
+    }
+}
+
+/// Oldest-first (by DAA score) coin selection.
+pub struct OldestFirst;
+
+impl CoinSelection for OldestFirst {
+    fn select(&self, mut entries: Vec<UtxoEntryReference>, _params: &CoinSelectionParams) -> Vec<UtxoEntryReference> {
+        entries.sort_by_key(|entry| (entry.block_daa_score(), Reverse(entry.amount())));
+        entries
+    }
+}
+
+/// Branch-and-bound coin selection. Performs a depth-first search for
+/// a set of UTXO entries whose effective value falls within the
+/// `[required, required + cost_of_change]` window, resulting in a
+/// transaction without a change output. Falls back to [`LargestFirst`]
+/// if no such set can be found.
+pub struct BranchAndBound;
+
+impl CoinSelection for BranchAndBound {
+    fn select(&self, entries: Vec<UtxoEntryReference>, params: &CoinSelectionParams) -> Vec<UtxoEntryReference> {
+        let mut entries = LargestFirst.select(entries, params);
+
+        let Some(target) = params.required(0) else {
+            return entries;
+        };
+
+        let candidates = entries
+            .iter()
+            .take(BRANCH_AND_BOUND_MAX_CANDIDATES)
+            .map(|entry| params.effective_value(entry))
+            .take_while(|value| *value > 0)
+            .collect::<Vec<_>>();
+
+        if let Some(selection) = BranchAndBoundSearch::new(&candidates, target, params.cost_of_change).run() {
+            // move the selected entries to the front, preserving
+            // largest-first ordering for the remaining entries
+            let mut selected = Vec::with_capacity(selection.len());
+            for index in selection.into_iter().rev() {
+                selected.push(entries.remove(index));
+            }
+            selected.reverse();
+            selected.extend(entries);
+            entries = selected;
+        }
+
+        entries
+    }
+}
+
+struct BranchAndBoundSearch<'a> {
+    values: &'a [u64],
+    remaining: Vec<u64>,
+    target: u64,
+    upper_bound: u64,
+    tries: usize,
+    current: Vec<usize>,
+    best: Option<(u64, Vec<usize>)>,
+}
+
+impl<'a> BranchAndBoundSearch<'a> {
+    fn new(values: &'a [u64], target: u64, cost_of_change: u64) -> Self {
+        // suffix sums used to prune branches that can not reach the target
+        let mut remaining = vec![0; values.len() + 1];
+        for index in (0..values.len()).rev() {
+            remaining[index] = remaining[index + 1] + values[index];
+        }
+
+        Self {
+            values,
+            remaining,
+            target,
+            upper_bound: target + cost_of_change,
+            tries: BRANCH_AND_BOUND_MAX_TRIES,
+            current: vec![],
+            best: None,
+        }
+    }
+
+    fn run(mut self) -> Option<Vec<usize>> {
+        self.search(0, 0);
+        self.best.map(|(_, selection)| selection)
+    }
+
+    fn search(&mut self, index: usize, sum: u64) {
+        if self.tries == 0 || sum > self.upper_bound {
+            return;
+        }
+        self.tries -= 1;
+
+        if sum >= self.target {
+            let waste = sum - self.target;
+            if self.best.as_ref().map(|(best, _)| waste < *best).unwrap_or(true) {
+                self.best = Some((waste, self.current.clone()));
+                if waste == 0 {
+                    // exact match, no further improvement is possible
+                    self.tries = 0;
+                }
+            }
+            return;
+        }
+
+        if index == self.values.len() || sum + self.remaining[index] < self.target {
+            return;
+        }
+
+        // include the current value
+        self.current.push(index);
+        self.search(index + 1, sum + self.values[index]);
+        self.current.pop();
+
+        // exclude the current value as well as all equivalent values
+        // following it, as they would produce identical branches
+        let mut next = index + 1;
+        while next < self.values.len() && self.values[next] == self.values[index] {
+            next += 1;
+        }
+        self.search(next, sum);
+    }
+}
+
+/// Privacy-preserving coin selection. UTXO entries are grouped by
+/// address and entire groups are consumed together, preferring the
+/// smallest single address group that covers the target amount. If
+/// no single group is sufficient, groups are consumed largest-first
+/// in order to link as few addresses as possible.
+pub struct PrivacyPreserving;
+
+impl CoinSelection for PrivacyPreserving {
+    fn select(&self, entries: Vec<UtxoEntryReference>, params: &CoinSelectionParams) -> Vec<UtxoEntryReference> {
+        let mut groups: Vec<(Option<Address>, Vec<UtxoEntryReference>)> = vec![];
+        let mut index: HashMap<Option<Address>, usize> = HashMap::default();
+        for entry in entries {
+            let address = entry.address();
+            match index.get(&address) {
+                Some(position) => groups[*position].1.push(entry),
+                None => {
+                    index.insert(address.clone(), groups.len());
+                    groups.push((address, vec![entry]));
+                }
+            }
+        }
+
+        let group_value = |group: &Vec<UtxoEntryReference>| group.iter().map(|entry| params.effective_value(entry)).sum::<u64>();
+
+        let mut groups = groups.into_iter().map(|(_, group)| (group_value(&group), group)).collect::<Vec<_>>();
+        groups.sort_by_key(|(value, _)| Reverse(*value));
+
+        if let Some(required) = params.required(0) {
+            // smallest group that covers the target on its own
+            if let Some(position) = groups.iter().rposition(|(value, _)| *value >= required) {
+                let group = groups.remove(position);
+                groups.insert(0, group);
+            }
+        }
+
+        groups
+            .into_iter()
+            .flat_map(|(_, mut group)| {
+                group.sort_by_key(|entry| Reverse(entry.amount()));
+                group
+            })
+            .collect()
+    }
+}
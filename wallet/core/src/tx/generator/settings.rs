@@ -6,7 +6,7 @@
 use crate::events::Events;
 use crate::imports::*;
 use crate::result::Result;
-use crate::tx::{Fees, PaymentDestination};
+use crate::tx::{CoinSelection, CoinSelectionStrategy, Fees, PaymentDestination};
 use crate::utxo::{UtxoContext, UtxoEntryReference, UtxoIterator};
 use calico_addresses::Address;
 use workflow_core::channel::Multiplexer;
@@ -36,6 +36,8 @@ pub struct GeneratorSettings {
     pub final_transaction_payload: Option<Vec<u8>>,
     // transaction is a transfer between accounts
     pub destination_utxo_context: Option<UtxoContext>,
+    // optional coin selection strategy applied to the utxo iterator
+    pub coin_selection: Option<Arc<dyn CoinSelection>>,
 }
 
 // impl std::fmt::Debug for GeneratorSettings {
@@ -85,6 +87,7 @@ impl GeneratorSettings {
             final_transaction_destination,
             final_transaction_payload,
             destination_utxo_context: None,
+            coin_selection: None,
         };
 
         Ok(settings)
@@ -118,6 +121,7 @@ impl GeneratorSettings {
             final_transaction_destination,
             final_transaction_payload,
             destination_utxo_context: None,
+            coin_selection: None,
         };
 
         Ok(settings)
@@ -149,6 +153,7 @@ impl GeneratorSettings {
             final_transaction_destination,
             final_transaction_payload,
             destination_utxo_context: None,
+            coin_selection: None,
         };
 
         Ok(settings)
@@ -158,4 +163,17 @@ impl GeneratorSettings {
         self.destination_utxo_context = Some(destination_utxo_context.clone());
         self
     }
+
+    pub fn coin_selection(mut self, coin_selection: Arc<dyn CoinSelection>) -> Self {
+        self.coin_selection = Some(coin_selection);
+        self
+    }
+
+    pub fn coin_selection_strategy(self, strategy: CoinSelectionStrategy) -> Self {
+        if strategy == CoinSelectionStrategy::Default {
+            self
+        } else {
+            self.coin_selection(Arc::new(strategy))
+        }
+    }
 }
@@ -430,6 +430,7 @@ where
         final_transaction_priority_fee: final_priority_fee,
         final_transaction_destination,
         final_transaction_payload,
+        coin_selection: None,
     };
 
     Generator::try_new(settings, None, None)
@@ -725,3 +726,96 @@ fn test_generator_inputs_250k_outputs_2_sweep() -> Result<()> {
     generator.unwrap().harness().accumulate(2875).finalize();
     Ok(())
 }
+
+fn coin_selection_entries(values: &[f64]) -> Vec<UtxoEntryReference> {
+    values.iter().cloned().map(calico_to_sompi).map(UtxoEntryReference::simulated).collect()
+}
+
+fn coin_selection_amounts(entries: &[UtxoEntryReference]) -> Vec<u64> {
+    entries.iter().map(|entry| entry.amount()).collect()
+}
+
+#[test]
+fn test_coin_selection_largest_first() -> Result<()> {
+    let entries = coin_selection_entries(&[1.0, 5.0, 3.0]);
+    let selected = LargestFirst.select(entries, &CoinSelectionParams::default());
+    assert_eq!(coin_selection_amounts(&selected), [5.0, 3.0, 1.0].map(calico_to_sompi));
+    Ok(())
+}
+
+#[test]
+fn test_coin_selection_oldest_first() -> Result<()> {
+    let entries = [(10.0, 300), (20.0, 100), (30.0, 200)]
+        .into_iter()
+        .map(|(amount, block_daa_score)| {
+            let entry = UtxoEntryReference::simulated(calico_to_sompi(amount));
+            let mut utxo = entry.utxo.as_ref().clone();
+            utxo.block_daa_score = block_daa_score;
+            UtxoEntryReference::from(utxo)
+        })
+        .collect::<Vec<_>>();
+    let selected = OldestFirst.select(entries, &CoinSelectionParams::default());
+    assert_eq!(selected.iter().map(|entry| entry.block_daa_score()).collect::<Vec<_>>(), vec![100, 200, 300]);
+    Ok(())
+}
+
+#[test]
+fn test_coin_selection_branch_and_bound_exact_match() -> Result<()> {
+    let entries = coin_selection_entries(&[8.0, 5.0, 4.0, 2.0, 1.0]);
+    let params = CoinSelectionParams { target: Some(calico_to_sompi(7.0)), ..Default::default() };
+    let selected = BranchAndBound.select(entries, &params);
+    // 5 + 2 is an exact match and is placed ahead of the remaining entries
+    assert_eq!(coin_selection_amounts(&selected), [5.0, 2.0, 8.0, 4.0, 1.0].map(calico_to_sompi));
+
+    let entries = coin_selection_entries(&[8.0, 4.0]);
+    let params = CoinSelectionParams { target: Some(calico_to_sompi(7.0)), ..Default::default() };
+    let selected = BranchAndBound.select(entries, &params);
+    // no exact match available, falls back to largest-first
+    assert_eq!(coin_selection_amounts(&selected), [8.0, 4.0].map(calico_to_sompi));
+    Ok(())
+}
+
+#[test]
+fn test_coin_selection_privacy_single_address() -> Result<()> {
+    let network_type = NetworkType::Testnet;
+    let address_a = change_address(network_type);
+    let address_b = output_address(network_type);
+    let entries = [(3.0, &address_a), (4.0, &address_b), (2.0, &address_a), (9.0, &address_b)]
+        .into_iter()
+        .map(|(amount, address)| UtxoEntryReference::simulated_with_address(calico_to_sompi(amount), address))
+        .collect::<Vec<_>>();
+    let params = CoinSelectionParams { target: Some(calico_to_sompi(5.0)), ..Default::default() };
+    let selected = PrivacyPreserving.select(entries, &params);
+    // the smallest address group covering the target is consumed first
+    assert!(selected[..2].iter().all(|entry| entry.address().as_ref() == Some(&address_a)));
+    assert!(selected[2..].iter().all(|entry| entry.address().as_ref() == Some(&address_b)));
+    Ok(())
+}
+
+#[test]
+fn test_generator_coin_selection_largest_first() -> Result<()> {
+    let network_id = test_network_id();
+    let mut values = vec![1.0; 10];
+    values.push(100.0);
+    let utxo_iterator = Box::new(coin_selection_entries(&values).into_iter());
+    let destination = PaymentOutputs::from((output_address(network_id.into()), calico_to_sompi(50.0)));
+    let settings = GeneratorSettings::try_new_with_iterator(
+        network_id,
+        utxo_iterator,
+        None,
+        change_address(network_id.into()),
+        1,
+        1,
+        destination.into(),
+        Fees::sender(Sompi(0)),
+        None,
+        None,
+    )?
+    .coin_selection_strategy(CoinSelectionStrategy::LargestFirst);
+
+    let generator = Generator::try_new(settings, None, None)?;
+    let transactions = generator.iter().collect::<Result<Vec<_>>>()?;
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(generator.summary().aggregated_utxos(), 1);
+    Ok(())
+}
@@ -309,10 +309,10 @@ impl WalletApi for super::Wallet {
         let AccountsDiscoveryRequest { discovery_kind: _, address_scan_extent, account_scan_extent, bip39_passphrase, bip39_mnemonic } =
             request;
 
-        let last_account_index_found =
-            self.scan_bip44_accounts(bip39_mnemonic, bip39_passphrase, address_scan_extent, account_scan_extent).await?;
+        let accounts = self.scan_bip44_accounts(bip39_mnemonic, bip39_passphrase, address_scan_extent, account_scan_extent).await?;
+        let last_account_index_found = accounts.last().map(|account| account.account_index as u32).unwrap_or_default();
 
-        Ok(AccountsDiscoveryResponse { last_account_index_found })
+        Ok(AccountsDiscoveryResponse { last_account_index_found, accounts })
     }
 
     async fn accounts_create_call(self: Arc<Self>, request: AccountsCreateRequest) -> Result<AccountsCreateResponse> {
@@ -124,6 +124,19 @@ impl AccountCreateArgsBip32Watch {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+pub struct AccountCreateArgsExternal {
+    pub account_name: Option<String>,
+    pub xpub_keys: Vec<String>,
+    pub endpoint: Option<String>,
+}
+
+impl AccountCreateArgsExternal {
+    pub fn new(account_name: Option<String>, xpub_keys: Vec<String>, endpoint: Option<String>) -> Self {
+        Self { account_name, xpub_keys, endpoint }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 pub struct PrvKeyDataArgs {
     pub prv_key_data_id: PrvKeyDataId,
@@ -156,6 +169,9 @@ pub enum AccountCreateArgs {
     Bip32Watch {
         account_args: AccountCreateArgsBip32Watch,
     },
+    External {
+        account_args: AccountCreateArgsExternal,
+    },
 }
 
 impl AccountCreateArgs {
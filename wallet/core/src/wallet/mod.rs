@@ -14,6 +14,7 @@ pub mod args;
 pub mod maps;
 pub use args::*;
 
+use crate::account::external_signer::SignerEndpoint;
 use crate::account::ScanNotifier;
 use crate::api::traits::WalletApi;
 use crate::compat::gen1::decrypt_mnemonic;
@@ -36,6 +37,29 @@ use workflow_core::task::spawn;
 
 pub type WalletGuard<'l> = AsyncMutexGuard<'l, ()>;
 
+/// Default number of consecutive unused addresses scanned
+/// in each account address chain during account discovery.
+pub const DEFAULT_ADDRESS_GAP_LIMIT: u32 = 20;
+/// Default number of consecutive unused accounts
+/// scanned during account discovery.
+pub const DEFAULT_ACCOUNT_GAP_LIMIT: u32 = 1;
+
+/// BIP44 account found during account discovery along with the address
+/// derivation indexes following the last used receive and change addresses.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscoveredAccount {
+    pub account_index: u64,
+    pub receive_index: u32,
+    pub change_index: u32,
+}
+
+impl DiscoveredAccount {
+    pub fn new(account_index: u64, receive_index: u32, change_index: u32) -> Self {
+        Self { account_index, receive_index, change_index }
+    }
+}
+
 #[derive(Debug)]
 pub struct EncryptedMnemonic<T: AsRef<[u8]>> {
     pub cipher: T, // raw
@@ -689,6 +713,7 @@ impl Wallet {
                 self.create_account_multisig(wallet_secret, prv_key_data_args, additional_xpub_keys, name, minimum_signatures).await?
             }
             AccountCreateArgs::Bip32Watch { account_args } => self.create_account_bip32_watch(wallet_secret, account_args).await?,
+            AccountCreateArgs::External { account_args } => self.create_account_external(wallet_secret, account_args).await?,
         };
 
         if notify {
@@ -852,6 +877,38 @@ impl Wallet {
         Ok(account)
     }
 
+    pub async fn create_account_external(
+        self: &Arc<Wallet>,
+        wallet_secret: &Secret,
+        account_args: AccountCreateArgsExternal,
+    ) -> Result<Arc<dyn Account>> {
+        let account_store = self.inner.store.clone().as_account_store()?;
+
+        let AccountCreateArgsExternal { account_name, xpub_keys, endpoint } = account_args;
+
+        let xpub_keys = Arc::new(
+            xpub_keys
+                .into_iter()
+                .map(|xpub_key| {
+                    ExtendedPublicKeySecp256k1::from_str(&xpub_key).map_err(|err| Error::InvalidExtendedPublicKey(xpub_key, err))
+                })
+                .collect::<Result<Vec<_>>>()?,
+        );
+
+        let endpoint = endpoint.map(|endpoint| endpoint.parse::<SignerEndpoint>()).transpose()?;
+
+        let account: Arc<dyn Account> = Arc::new(external::External::try_new(self, account_name, xpub_keys, false, endpoint).await?);
+
+        if account_store.load_single(account.id()).await?.is_some() {
+            return Err(Error::AccountAlreadyExists(*account.id()));
+        }
+
+        self.inner.store.clone().as_account_store()?.store_single(&account.to_storage()?, None).await?;
+        self.inner.store.commit(wallet_secret).await?;
+
+        Ok(account)
+    }
+
     async fn create_account_legacy(
         self: &Arc<Wallet>,
         wallet_secret: &Secret,
@@ -1563,17 +1620,16 @@ impl Wallet {
     }
 
     /// Perform a "2d" scan of account derivations while scanning addresses
-    /// in each account (UTXOs up to `address_scan_extent` address derivation).
-    /// Report back the last account index that has UTXOs. The scan is performed
-    /// until we have encountered at least `account_scan_extent` of empty
-    /// accounts.
+    /// in each account (see [`Wallet::discover_bip44_accounts`]). The
+    /// `address_scan_extent` and `account_scan_extent` are used as address
+    /// and account gap limits respectively. Reports back discovered accounts.
     pub async fn scan_bip44_accounts(
         self: &Arc<Self>,
         bip39_mnemonic: Secret,
         bip39_passphrase: Option<Secret>,
         address_scan_extent: u32,
         account_scan_extent: u32,
-    ) -> Result<u32> {
+    ) -> Result<Vec<DiscoveredAccount>> {
         let bip39_mnemonic = std::str::from_utf8(bip39_mnemonic.as_ref()).map_err(|_| Error::InvalidMnemonicPhrase)?;
         let mnemonic = Mnemonic::new(bip39_mnemonic, Language::English)?;
 
@@ -1581,26 +1637,134 @@ impl Wallet {
         let prv_key_data =
             storage::PrvKeyData::try_new_from_mnemonic(mnemonic, bip39_passphrase.as_ref(), EncryptionKind::XChaCha20Poly1305)?;
 
+        self.discover_bip44_accounts(&prv_key_data, bip39_passphrase.as_ref(), address_scan_extent, account_scan_extent).await
+    }
+
+    /// Discover BIP44 accounts derived from the private key data using gap limit
+    /// scanning. The receive and change address chains of each account are scanned
+    /// until `address_gap_limit` consecutive addresses without UTXOs are encountered.
+    /// Accounts are scanned until `account_gap_limit` consecutive accounts without
+    /// UTXOs are encountered. The first account is always reported.
+    ///
+    /// Progress is posted via [`Events::AccountDiscoveryProgress`] and
+    /// the result via [`Events::AccountDiscoveryComplete`] notifications.
+    pub async fn discover_bip44_accounts(
+        self: &Arc<Self>,
+        prv_key_data: &storage::PrvKeyData,
+        payment_secret: Option<&Secret>,
+        address_gap_limit: u32,
+        account_gap_limit: u32,
+    ) -> Result<Vec<DiscoveredAccount>> {
+        let address_gap_limit = address_gap_limit.max(1);
+        let mut accounts = Vec::new();
         let mut last_account_index = 0;
         let mut account_index = 0;
 
-        while account_index < last_account_index + account_scan_extent {
-            let xpub_key =
-                prv_key_data.create_xpub(bip39_passphrase.as_ref(), BIP32_ACCOUNT_KIND.into(), account_index as u64).await?;
+        while account_index <= last_account_index + account_gap_limit as u64 {
+            let xpub_key = prv_key_data.create_xpub(payment_secret, BIP32_ACCOUNT_KIND.into(), account_index).await?;
             let xpub_keys = Arc::new(vec![xpub_key]);
-            let ecdsa = false;
-            // ---
+            let account = bip32::Bip32::try_new(self, None, prv_key_data.id, account_index, xpub_keys, false).await?;
 
-            let addresses = bip32::Bip32::try_new(self, None, prv_key_data.id, account_index as u64, xpub_keys, ecdsa)
-                .await?
-                .get_address_range_for_scan(0..address_scan_extent)?;
-            if self.rpc_api().get_utxos_by_addresses(addresses).await?.is_not_empty() {
+            let receive = self.scan_address_chain(&account, false, address_gap_limit).await?;
+            let change = self.scan_address_chain(&account, true, address_gap_limit).await?;
+            if account_index == 0 || receive.is_some() || change.is_some() {
                 last_account_index = account_index;
+                let next_index = |last_used: Option<u32>| last_used.map(|index| index + 1).unwrap_or_default();
+                accounts.push(DiscoveredAccount::new(account_index, next_index(receive), next_index(change)));
             }
             account_index += 1;
         }
 
-        Ok(last_account_index)
+        self.notify(Events::AccountDiscoveryComplete { accounts: accounts.clone() }).await?;
+
+        Ok(accounts)
+    }
+
+    /// Scan the account receive or change address chain in windows of `gap_limit`
+    /// addresses until `gap_limit` consecutive addresses without UTXOs are found.
+    /// Returns the index of the last address holding UTXOs.
+    async fn scan_address_chain(self: &Arc<Self>, account: &bip32::Bip32, change: bool, gap_limit: u32) -> Result<Option<u32>> {
+        let derivation = account.derivation();
+        let manager = if change { derivation.change_address_manager() } else { derivation.receive_address_manager() };
+
+        let mut last_used: Option<u32> = None;
+        let mut scanned = 0;
+        while scanned < last_used.map(|index| index + 1).unwrap_or_default() + gap_limit {
+            let addresses = manager.get_range_with_args(scanned..scanned + gap_limit, false)?;
+            let utxos = self.rpc_api().get_utxos_by_addresses(addresses.clone()).await?;
+            let used = utxos
+                .iter()
+                .filter_map(|entry| entry.address.as_ref())
+                .filter_map(|address| addresses.iter().position(|candidate| candidate == address))
+                .max();
+            if let Some(position) = used {
+                last_used = Some(scanned + position as u32);
+            }
+            scanned += gap_limit;
+
+            self.notify(Events::AccountDiscoveryProgress { account_index: account.account_index(), change, scanned, last_used })
+                .await?;
+        }
+
+        Ok(last_used)
+    }
+
+    /// Discover BIP44 accounts of the private key data (see [`Wallet::discover_bip44_accounts`]),
+    /// create discovered accounts missing from the wallet, advance address derivation indexes
+    /// of existing accounts and (re)activate all discovered accounts.
+    pub async fn restore_bip44_accounts(
+        self: &Arc<Wallet>,
+        wallet_secret: &Secret,
+        prv_key_data_id: PrvKeyDataId,
+        payment_secret: Option<&Secret>,
+        address_gap_limit: u32,
+        account_gap_limit: u32,
+        guard: &WalletGuard<'_>,
+    ) -> Result<Vec<DiscoveredAccount>> {
+        let prv_key_data = self
+            .inner
+            .store
+            .as_prv_key_data_store()?
+            .load_key_data(wallet_secret, &prv_key_data_id)
+            .await?
+            .ok_or_else(|| Error::PrivateKeyNotFound(prv_key_data_id))?;
+
+        let discovered = self.discover_bip44_accounts(&prv_key_data, payment_secret, address_gap_limit, account_gap_limit).await?;
+
+        let account_store = self.inner.store.clone().as_account_store()?;
+        let mut account_ids = Vec::with_capacity(discovered.len());
+        for DiscoveredAccount { account_index, receive_index, change_index } in discovered.iter().cloned() {
+            let xpub_key = prv_key_data.create_xpub(payment_secret, BIP32_ACCOUNT_KIND.into(), account_index).await?;
+            let xpub_keys = Arc::new(vec![xpub_key]);
+            let account = Arc::new(bip32::Bip32::try_new(self, None, prv_key_data.id, account_index, xpub_keys, false).await?);
+
+            let stored = account_store.load_single(account.id()).await?;
+            let indexes = stored.as_ref().and_then(|(_, meta)| meta.as_ref()?.address_derivation_indexes()).unwrap_or_default();
+            let derivation = account.derivation();
+            derivation.receive_address_manager().set_index(indexes.receive().max(receive_index))?;
+            derivation.change_address_manager().set_index(indexes.change().max(change_index))?;
+            let metadata = account.metadata()?.expect("derivation accounts must provide metadata");
+
+            if stored.is_some() {
+                account_store.update_metadata(vec![metadata]).await?;
+            } else {
+                account_store.store_single(&account.to_storage()?, Some(&metadata)).await?;
+                self.notify(Events::AccountCreate { account_descriptor: account.descriptor()? }).await?;
+            }
+
+            // active accounts are restarted to pick up updated derivation indexes
+            if let Some(active) = self.active_accounts().get(account.id()) {
+                active.stop().await?;
+            }
+            account_ids.push(*account.id());
+        }
+        self.inner.store.commit(wallet_secret).await?;
+
+        if self.is_open() {
+            self.activate_accounts(Some(&account_ids), guard).await?;
+        }
+
+        Ok(discovered)
     }
 
     pub async fn import_multisig_with_mnemonic(
@@ -1743,9 +1907,58 @@ impl Wallet {
 mod test {
     // use hex_literal::hex;
 
-    // use super::*;
+    use super::*;
+    use crate::tests::RpcCoreMock;
+    use calico_hashes::Hash;
+    use calico_rpc_core::{RpcTransactionOutpoint, RpcUtxoEntry, RpcUtxosByAddressesEntry};
+    use calico_txscript::pay_to_address_script;
     // use calico_addresses::Address;
 
+    fn mock_utxo(address: Address, index: u64) -> RpcUtxosByAddressesEntry {
+        RpcUtxosByAddressesEntry {
+            outpoint: RpcTransactionOutpoint { transaction_id: Hash::from_u64_word(index), index: 0 },
+            utxo_entry: RpcUtxoEntry::new(100_000_000, pay_to_address_script(&address), 0, false),
+            address: Some(address),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_discover_bip44_accounts() -> Result<()> {
+        const ADDRESS_GAP_LIMIT: u32 = 5;
+        const ACCOUNT_GAP_LIMIT: u32 = 2;
+
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 11);
+        let rpc_mock = Arc::new(RpcCoreMock::new());
+        let wallet = Arc::new(Wallet::try_with_rpc(Some(rpc_mock.clone().into()), Wallet::resident_store()?, Some(network_id))?);
+
+        let mnemonic = Mnemonic::random(WordCount::Words12, Language::English)?;
+        let prv_key_data = storage::PrvKeyData::try_new_from_mnemonic(mnemonic, None, EncryptionKind::XChaCha20Poly1305)?;
+
+        // used addresses as (account index, change, address index)
+        let used = [(0, false, 2), (0, false, 7), (2, false, 0), (2, true, 3)];
+        for (utxo_index, (account_index, change, index)) in used.into_iter().enumerate() {
+            let xpub_keys = Arc::new(vec![prv_key_data.create_xpub(None, BIP32_ACCOUNT_KIND.into(), account_index).await?]);
+            let account = bip32::Bip32::try_new(&wallet, None, prv_key_data.id, account_index, xpub_keys, false).await?;
+            let derivation = account.derivation();
+            let manager = if change { derivation.change_address_manager() } else { derivation.receive_address_manager() };
+            let address = manager.get_range_with_args(index..index + 1, false)?.remove(0);
+            rpc_mock.add_utxos(vec![mock_utxo(address, utxo_index as u64 + 1)]);
+        }
+
+        let accounts = wallet.discover_bip44_accounts(&prv_key_data, None, ADDRESS_GAP_LIMIT, ACCOUNT_GAP_LIMIT).await?;
+        let accounts = accounts.into_iter().map(|account| (account.account_index, account.receive_index, account.change_index));
+        // account 1 is unused but followed by a used account within the account gap limit,
+        // the address at index 7 of account 0 is found since index 2 extends the scan
+        assert_eq!(accounts.collect::<Vec<_>>(), vec![(0, 8, 0), (2, 1, 4)]);
+
+        // scanning stops after the gap limit past the last used address and account:
+        // account 0 (3 receive + 1 change windows), account 1 (1 + 1),
+        // account 2 (2 + 2), accounts 3 and 4 (1 + 1 each)
+        assert_eq!(rpc_mock.utxos_by_addresses_requests(), 14);
+
+        Ok(())
+    }
+
     /*
     use workflow_rpc::client::ConnectOptions;
     use std::{str::FromStr, thread::sleep, time};
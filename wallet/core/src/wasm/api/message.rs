@@ -1008,6 +1008,7 @@ declare! {
      */
     export interface IAccountsDiscoveryResponse {
         lastAccountIndexFound : number;
+        accounts : { accountIndex : number, receiveIndex : number, changeIndex : number }[];
     }
     "#,
 }
@@ -119,6 +119,8 @@ cfg_if! {
             AccountSelection = "account-selection",
             AccountCreate = "account-create",
             AccountUpdate = "account-update",
+            AccountDiscoveryProgress = "account-discovery-progress",
+            AccountDiscoveryComplete = "account-discovery-complete",
             ServerStatus = "server-status",
             UtxoProcStart = "utxo-proc-start",
             UtxoProcStop = "utxo-proc-stop",
@@ -155,6 +157,8 @@ cfg_if! {
             "account-selection": IAccountSelectionEvent,
             "account-create": IAccountCreateEvent,
             "account-update": IAccountUpdateEvent,
+            "account-discovery-progress": IAccountDiscoveryProgressEvent,
+            "account-discovery-complete": IAccountDiscoveryCompleteEvent,
             "server-status": IServerStatusEvent,
             "utxo-proc-start": undefined,
             "utxo-proc-stop": undefined,
@@ -445,6 +449,42 @@ declare! {
     "#,
 }
 
+#[cfg(feature = "wasm32-sdk")]
+declare! {
+    IAccountDiscoveryProgressEvent,
+    r#"
+    /**
+     * Emitted by {@link Wallet} during BIP44 account discovery
+     * after scanning each window of account addresses.
+     * 
+     * @category Wallet Events
+     */
+    export interface IAccountDiscoveryProgressEvent {
+        accountIndex : number;
+        change : boolean;
+        scanned : number;
+        lastUsed? : number;
+    }
+    "#,
+}
+
+#[cfg(feature = "wasm32-sdk")]
+declare! {
+    IAccountDiscoveryCompleteEvent,
+    r#"
+    /**
+     * Emitted by {@link Wallet} when BIP44 account discovery has completed.
+     * Contains discovered account indexes along with the address derivation
+     * indexes following the last used receive and change addresses.
+     * 
+     * @category Wallet Events
+     */
+    export interface IAccountDiscoveryCompleteEvent {
+        accounts : { accountIndex : number, receiveIndex : number, changeIndex : number }[];
+    }
+    "#,
+}
+
 declare! {
     IServerStatusEvent,
     r#"
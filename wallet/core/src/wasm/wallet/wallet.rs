@@ -24,6 +24,10 @@ declare! {
         encoding?: Encoding | string;
         url?: string;
         resolver?: Resolver;
+        /**
+         * API key presented to nodes that require RPC authentication.
+         */
+        authToken?: string;
     }
     "#,
 }
@@ -35,6 +39,7 @@ struct WalletCtorArgs {
     encoding: Option<WrpcEncoding>,
     url: Option<String>,
     resolver: Option<Resolver>,
+    auth_token: Option<String>,
 }
 
 impl TryFrom<JsValue> for WalletCtorArgs {
@@ -46,8 +51,9 @@ impl TryFrom<JsValue> for WalletCtorArgs {
             let encoding = object.try_get::<WrpcEncoding>("encoding")?;
             let url = object.get_value("url")?.as_string();
             let resolver = object.try_get("resolver")?;
+            let auth_token = object.get_value("authToken")?.as_string();
 
-            Ok(Self { resident, network_id, encoding, url, resolver })
+            Ok(Self { resident, network_id, encoding, url, resolver, auth_token })
         } else {
             Ok(WalletCtorArgs::default())
         }
@@ -144,11 +150,12 @@ cfg_if! {
 impl Wallet {
     #[wasm_bindgen(constructor)]
     pub fn constructor(config: IWalletConfig) -> Result<Wallet> {
-        let WalletCtorArgs { resident, network_id, encoding, url, resolver } = WalletCtorArgs::try_from(JsValue::from(config))?;
+        let WalletCtorArgs { resident, network_id, encoding, url, resolver, auth_token } =
+            WalletCtorArgs::try_from(JsValue::from(config))?;
 
         let store = Arc::new(LocalStore::try_new(resident)?);
 
-        let rpc_config = RpcConfig { url, resolver, encoding, network_id };
+        let rpc_config = RpcConfig { url, resolver, encoding, network_id, auth_token };
 
         let rpc = RpcClient::new(Some(rpc_config))?;
         let rpc_api: Arc<DynRpcApi> = rpc.client().rpc_api().clone();
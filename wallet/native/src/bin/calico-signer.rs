@@ -0,0 +1,167 @@
+//!
+//! Reference implementation of an external signer for Calico wallet
+//! external signer accounts (`account watch external`). The signer
+//! holds the account keys and signs PSST bundles received over the
+//! external signer protocol (see `calico_wallet_psst::external`).
+//!
+//! The signer can be spawned by the wallet (`stdio:calico-signer --key-file <file>`),
+//! listen on a TCP socket (`--listen 127.0.0.1:17420`) or sign a single
+//! PSSB supplied on the command line (`--sign <PSSB>`) for air-gapped use.
+//!
+
+use calico_bip32::{DerivationPath, ExtendedPrivateKey, Language, Mnemonic, Prefix, SecretKey};
+use calico_wallet_psst::bundle::Bundle;
+use calico_wallet_psst::external::{KeySigner, Request, Response, ERROR_INVALID_REQUEST};
+use clap::{Arg, ArgAction, Command};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::str::FromStr;
+
+struct Args {
+    key_file: String,
+    passphrase: String,
+    account_index: u64,
+    listen: Option<String>,
+    sign: Option<String>,
+    print_xpub: bool,
+}
+
+impl Args {
+    fn parse() -> Self {
+        let m = cli().get_matches();
+        Args {
+            key_file: m.get_one::<String>("key-file").cloned().unwrap(),
+            passphrase: m.get_one::<String>("passphrase").cloned().unwrap_or_default(),
+            account_index: m.get_one::<u64>("account-index").cloned().unwrap(),
+            listen: m.get_one::<String>("listen").cloned(),
+            sign: m.get_one::<String>("sign").cloned(),
+            print_xpub: m.get_one::<bool>("print-xpub").cloned().unwrap_or(false),
+        }
+    }
+}
+
+fn cli() -> Command {
+    Command::new("calico-signer")
+        .about(format!("{} (calico-signer) v{}", env!("CARGO_PKG_DESCRIPTION"), env!("CARGO_PKG_VERSION")))
+        .version(env!("CARGO_PKG_VERSION"))
+        .arg(
+            Arg::new("key-file")
+                .long("key-file")
+                .short('k')
+                .value_name("file")
+                .required(true)
+                .help("File containing a bip39 mnemonic or a master extended private key"),
+        )
+        .arg(Arg::new("passphrase").long("passphrase").value_name("passphrase").help("Optional bip39 mnemonic passphrase"))
+        .arg(
+            Arg::new("account-index")
+                .long("account-index")
+                .value_name("index")
+                .default_value("0")
+                .value_parser(clap::value_parser!(u64))
+                .help("Bip32 account index (m/44'/123579'/<index>')"),
+        )
+        .arg(
+            Arg::new("listen")
+                .long("listen")
+                .value_name("address")
+                .help("Serve signing requests on a TCP socket (e.g. 127.0.0.1:17420)"),
+        )
+        .arg(Arg::new("sign").long("sign").value_name("pssb").help("Sign the supplied PSSB and print the signed PSSB"))
+        .arg(
+            Arg::new("print-xpub")
+                .long("print-xpub")
+                .action(ArgAction::SetTrue)
+                .help("Print the account extended public key used to create the wallet account and exit"),
+        )
+}
+
+fn load_account_key(args: &Args) -> Result<ExtendedPrivateKey<SecretKey>, String> {
+    let data = std::fs::read_to_string(&args.key_file).map_err(|err| format!("unable to read key file '{}': {err}", args.key_file))?;
+    let data = data.trim();
+
+    let master = match ExtendedPrivateKey::<SecretKey>::from_str(data) {
+        Ok(xprv) => xprv,
+        Err(_) => {
+            let mnemonic = Mnemonic::new(data, Language::English).map_err(|err| format!("invalid mnemonic: {err}"))?;
+            ExtendedPrivateKey::<SecretKey>::new(mnemonic.to_seed(&args.passphrase)).map_err(|err| err.to_string())?
+        }
+    };
+
+    let path = DerivationPath::from_str(&format!("m/44'/123579'/{}'", args.account_index)).map_err(|err| err.to_string())?;
+    master.derive_path(&path).map_err(|err| err.to_string())
+}
+
+/// Serve newline-delimited protocol requests until the reader is closed.
+fn serve<R: Read, W: Write>(signer: &KeySigner, reader: R, mut writer: W) -> std::io::Result<()> {
+    for line in BufReader::new(reader).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match Request::from_line(&line) {
+            Ok(request) => {
+                eprintln!("calico-signer: processing request {}", request.id);
+                signer.handle(request)
+            }
+            Err(err) => Response::error(0, ERROR_INVALID_REQUEST, err.to_string()),
+        };
+
+        let line = response.to_line().map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err.to_string()))?;
+        writer.write_all(line.as_bytes())?;
+        writer.flush()?;
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let xprv = match load_account_key(&args) {
+        Ok(xprv) => xprv,
+        Err(err) => {
+            eprintln!("calico-signer: {err}");
+            std::process::exit(1);
+        }
+    };
+
+    if args.print_xpub {
+        println!("{}", xprv.public_key().to_string(Some(Prefix::KPUB)));
+        return;
+    }
+
+    let signer = KeySigner::new(xprv);
+    eprintln!("calico-signer: key fingerprint {}", signer.fingerprint().iter().map(|b| format!("{b:02x}")).collect::<String>());
+
+    let result = if let Some(pssb) = args.sign {
+        Bundle::deserialize(pssb.trim())
+            .and_then(|bundle| signer.sign_bundle(&bundle))
+            .and_then(|bundle| bundle.serialize())
+            .map(|bundle| {
+                println!("{bundle}");
+            })
+            .map_err(|err| err.to_string())
+    } else if let Some(listen) = args.listen {
+        TcpListener::bind(&listen)
+            .and_then(|listener| {
+                eprintln!("calico-signer: listening on {listen}");
+                for stream in listener.incoming() {
+                    let stream = stream?;
+                    if let Err(err) = serve(&signer, stream.try_clone()?, stream) {
+                        eprintln!("calico-signer: connection error: {err}");
+                    }
+                }
+                Ok(())
+            })
+            .map_err(|err| err.to_string())
+    } else {
+        serve(&signer, std::io::stdin(), std::io::stdout()).map_err(|err| err.to_string())
+    };
+
+    if let Err(err) = result {
+        eprintln!("calico-signer: {err}");
+        std::process::exit(1);
+    }
+}
@@ -2,12 +2,12 @@ use calico_consensus_core::{
     hashing::sighash::{calc_schnorr_signature_hash, SigHashReusedValuesUnsync},
     tx::{TransactionId, TransactionOutpoint, UtxoEntry},
 };
-use calico_txscript::{multisig_redeem_script, opcodes::codes::OpData65, pay_to_script_hash_script, script_builder::ScriptBuilder};
+use calico_txscript::{multisig_redeem_script, pay_to_script_hash_script};
 use calico_wallet_psst::prelude::{
-    Combiner, Creator, Extractor, Finalizer, Inner, InputBuilder, SignInputOk, Signature, Signer, Updater, PSST,
+    Combiner, Creator, Extractor, Finalizer, InputBuilder, SignInputOk, Signature, Signer, Updater, PSST,
 };
 use secp256k1::{rand::thread_rng, Keypair};
-use std::{iter, str::FromStr};
+use std::str::FromStr;
 
 fn main() {
     let kps = [Keypair::new(secp256k1::SECP256K1, &mut thread_rng()), Keypair::new(secp256k1::SECP256K1, &mut thread_rng())];
@@ -80,37 +80,7 @@ fn main() {
     let ser_combined_signed = serde_json::to_string_pretty(&combined_signed).expect("Failed to serialize after combining signed");
     println!("Combined Signed: {}", ser_combined_signed);
     let psst_finalizer: PSST<Finalizer> = serde_json::from_str(&ser_combined_signed).expect("Failed to deserialize");
-    let psst_finalizer = psst_finalizer
-        .finalize_sync(|inner: &Inner| -> Result<Vec<Vec<u8>>, String> {
-            Ok(inner
-                .inputs
-                .iter()
-                .map(|input| -> Vec<u8> {
-                    // todo actually required count can be retrieved from redeem_script, sigs can be taken from partial sigs according to required count
-                    // considering xpubs sorted order
-
-                    let signatures: Vec<_> = kps
-                        .iter()
-                        .flat_map(|kp| {
-                            let sig = input.partial_sigs.get(&kp.public_key()).unwrap().into_bytes();
-                            iter::once(OpData65).chain(sig).chain([input.sighash_type.to_u8()])
-                        })
-                        .collect();
-                    signatures
-                        .into_iter()
-                        .chain(
-                            ScriptBuilder::new()
-                                .add_data(input.redeem_script.as_ref().unwrap().as_slice())
-                                .unwrap()
-                                .drain()
-                                .iter()
-                                .cloned(),
-                        )
-                        .collect()
-                })
-                .collect())
-        })
-        .unwrap();
+    let psst_finalizer = psst_finalizer.finalize_standard().unwrap();
     let ser_finalized = serde_json::to_string_pretty(&psst_finalizer).expect("Failed to serialize after finalizing");
     println!("Finalized: {}", ser_finalized);
 
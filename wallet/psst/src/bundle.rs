@@ -5,10 +5,12 @@ use crate::psst::{Inner as PSSTInner, PSST};
 
 use calico_addresses::{Address, Prefix};
 // use calico_bip32::Prefix;
+use calico_consensus_core::config::params::Params;
 use calico_consensus_core::network::{NetworkId, NetworkType};
 use calico_consensus_core::tx::{ScriptPublicKey, TransactionOutpoint, UtxoEntry};
 
-use calico_txscript::{extract_script_pub_key_address, pay_to_address_script, pay_to_script_hash_script};
+use calico_txscript::script_class::ScriptClass;
+use calico_txscript::{extract_script_pub_key_address, pay_to_address_script, pay_to_script_hash_script, TimeLock};
 use hex;
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
@@ -113,6 +115,135 @@ impl Bundle {
     }
 }
 
+impl Bundle {
+    /// Combine the bundle with `other` (Combiner role). Each PSST contained in `other`
+    /// is merged into the PSST of this bundle describing the same transaction.
+    pub fn combine(&self, other: &Bundle) -> Result<Bundle, Error> {
+        let mut pssts = self.0.iter().cloned().map(PSST::<Combiner>::from).collect::<Vec<_>>();
+        for inner in other.iter() {
+            let rhs = PSST::<Signer>::from(inner.clone());
+            let id = rhs.calculate_id();
+            let index = pssts
+                .iter()
+                .position(|psst| PSST::<Signer>::from(psst.deref().clone()).calculate_id() == id)
+                .ok_or(Error::TransactionNotFound(id))?;
+            let lhs = pssts.remove(index);
+            pssts.insert(index, (lhs + rhs)?);
+        }
+        Ok(Bundle::from(pssts))
+    }
+
+    /// Finalize all PSSTs contained in the bundle (Finalizer role).
+    /// Inputs are finalized according to their script type
+    /// (see [`standard`](crate::standard)).
+    pub fn finalize(&self) -> Result<Bundle, Error> {
+        let pssts = self
+            .iter()
+            .map(|inner| PSST::<Finalizer>::from(inner.clone()).finalize_standard().map_err(|err| Error::Finalize(err.to_string())))
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Bundle::from(pssts))
+    }
+
+    /// Extract transactions from a finalized bundle (Extractor role), verifying
+    /// signature scripts, transaction mass and fees.
+    pub fn extract(&self, params: &Params) -> Result<Vec<ExtractedTransaction>, Error> {
+        self.iter()
+            .map(|inner| {
+                let psst = PSST::<Finalizer>::from(inner.clone()).extractor().map_err(ExtractError::from)?;
+                Ok(psst.extract_tx_checked(params)?)
+            })
+            .collect()
+    }
+
+    /// Produce a human-readable description of the bundle, including the signing
+    /// status of each input and the fee paid by each transaction.
+    pub fn inspect<F>(&self, network_id: NetworkId, sompi_formatter: F) -> String
+    where
+        F: Fn(u64, &NetworkType) -> String,
+    {
+        let network_type = NetworkType::from(network_id);
+        let prefix = Prefix::from(network_id);
+        let address = |script_public_key: &ScriptPublicKey| {
+            extract_script_pub_key_address(script_public_key, prefix)
+                .map(String::from)
+                .unwrap_or_else(|_| format!("<{}>", ScriptClass::from_script(script_public_key)))
+        };
+
+        let mut result = String::new();
+        for (psst_index, inner) in self.iter().enumerate() {
+            let psst = PSST::<Signer>::from(inner.clone());
+
+            result.push_str(&format!("PSST #{:02}\n", psst_index + 1));
+            result.push_str(&format!("  transaction id: {}\n", psst.calculate_id()));
+            result.push_str(&format!("  version: {}  lock time: {}\n", psst.global.tx_version, psst.determine_lock_time()));
+
+            let mut total_input = Some(0u64);
+            let mut ready = true;
+            let mut finalized = true;
+            for (index, input) in psst.inputs.iter().enumerate() {
+                result.push_str(&format!("  Input #{:02}\n", index + 1));
+                result.push_str(&format!("    outpoint: {}\n", input.previous_outpoint));
+                match &input.utxo_entry {
+                    Some(utxo_entry) => {
+                        total_input = total_input.map(|total| total + utxo_entry.amount);
+                        result.push_str(&format!("    amount: {}\n", sompi_formatter(utxo_entry.amount, &network_type)));
+                        result.push_str(&format!("    address: {}\n", address(&utxo_entry.script_public_key)));
+                        result.push_str(&format!("    script: {}\n", ScriptClass::from_script(&utxo_entry.script_public_key)));
+                    }
+                    None => {
+                        total_input = None;
+                        result.push_str("    amount: <missing UTXO entry>\n");
+                    }
+                }
+                result.push_str(&format!("    sighash: {:#04x}\n", input.sighash_type.to_u8()));
+
+                let required = crate::standard::required_signatures(input);
+                let signatures = input.partial_sigs.len();
+                match required {
+                    Some(required) => result.push_str(&format!("    signatures: {signatures}/{required}\n")),
+                    None => result.push_str(&format!("    signatures: {signatures}\n")),
+                }
+                if input.final_script_sig.is_some() {
+                    result.push_str("    finalized: yes\n");
+                } else {
+                    finalized = false;
+                    ready &= required.is_some_and(|required| signatures >= required);
+                    result.push_str("    finalized: no\n");
+                }
+            }
+
+            let total_output = psst.outputs.iter().map(|output| output.amount).sum::<u64>();
+            for (index, output) in psst.outputs.iter().enumerate() {
+                result.push_str(&format!("  Output #{:02}\n", index + 1));
+                result.push_str(&format!("    amount: {}\n", sompi_formatter(output.amount, &network_type)));
+                result.push_str(&format!("    address: {}\n", address(&output.script_public_key)));
+            }
+
+            match total_input {
+                Some(total_input) => {
+                    result.push_str(&format!("  total input: {}\n", sompi_formatter(total_input, &network_type)));
+                    result.push_str(&format!("  total output: {}\n", sompi_formatter(total_output, &network_type)));
+                    match total_input.checked_sub(total_output) {
+                        Some(fee) => result.push_str(&format!("  fee: {}\n", sompi_formatter(fee, &network_type))),
+                        None => result.push_str("  fee: <outputs exceed inputs>\n"),
+                    }
+                }
+                None => result.push_str(&format!("  total output: {}\n", sompi_formatter(total_output, &network_type))),
+            }
+
+            let status = if finalized {
+                "finalized"
+            } else if ready {
+                "ready to finalize"
+            } else {
+                "missing signatures"
+            };
+            result.push_str(&format!("  status: {status}\n"));
+        }
+        result
+    }
+}
+
 impl AsRef<[PSSTInner]> for Bundle {
     fn as_ref(&self) -> &[PSSTInner] {
         self.0.as_slice()
@@ -213,6 +344,30 @@ pub fn unlock_utxos_as_pssb(
     }
 }
 
+/// Same as [`unlock_utxos_as_pssb`] for UTXOs locked by a time-locked script.
+/// Transaction lock times (for [`TimeLock::Absolute`]) or input sequences
+/// (for [`TimeLock::Relative`]) are set to satisfy the `lock`.
+pub fn unlock_time_locked_utxos_as_pssb(
+    utxo_references: Vec<(UtxoEntry, TransactionOutpoint)>,
+    recipient: &Address,
+    script_sig: Vec<u8>,
+    priority_fee_sompi_per_transaction: u64,
+    lock: TimeLock,
+) -> Result<Bundle, Error> {
+    let mut bundle = unlock_utxos_as_pssb(utxo_references, recipient, script_sig, priority_fee_sompi_per_transaction)?;
+    for input in bundle.0.iter_mut().flat_map(|inner| inner.inputs.iter_mut()) {
+        match lock {
+            TimeLock::Absolute(lock_time) => {
+                // lock time verification requires a non-finalized input
+                input.min_time = Some(lock_time);
+                input.sequence = Some(0);
+            }
+            TimeLock::Relative(sequence) => input.sequence = Some(sequence),
+        }
+    }
+    Ok(bundle)
+}
+
 pub fn unlock_utxo(
     utxo_entry: &UtxoEntry,
     outpoint: &TransactionOutpoint,
@@ -242,6 +397,7 @@ mod tests {
     use crate::prelude::*;
     use crate::role::Creator;
     use crate::role::*;
+    use calico_consensus_core::hashing::sighash::{calc_schnorr_signature_hash, SigHashReusedValuesUnsync};
     use calico_consensus_core::tx::{TransactionId, TransactionOutpoint, UtxoEntry};
     use calico_txscript::{multisig_redeem_script, pay_to_script_hash_script};
     use secp256k1::Secp256k1;
@@ -319,6 +475,58 @@ mod tests {
         }
     }
 
+    fn sign_psst(psst: PSST<Signer>, kp: &Keypair) -> PSST<Signer> {
+        let reused_values = SigHashReusedValuesUnsync::new();
+        psst.pass_signature_sync(|tx, sighash| -> Result<Vec<SignInputOk>, String> {
+            (0..tx.tx.inputs.len())
+                .map(|idx| {
+                    let hash = calc_schnorr_signature_hash(&tx.as_verifiable(), idx, sighash[idx], &reused_values);
+                    let msg = secp256k1::Message::from_digest_slice(hash.as_bytes().as_slice()).unwrap();
+                    Ok(SignInputOk { signature: Signature::Schnorr(kp.sign_schnorr(msg)), pub_key: kp.public_key(), key_source: None })
+                })
+                .collect()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_pssb_combine_finalize_extract() {
+        let (kps, _) = mock_context();
+        let output = OutputBuilder::default()
+            .amount(12793000000000 - 10000)
+            .script_public_key(pay_to_address_script(&Address::new(
+                Prefix::Mainnet,
+                calico_addresses::Version::PubKey,
+                &kps[0].x_only_public_key().0.serialize(),
+            )))
+            .build()
+            .unwrap();
+        let unsigned = Bundle::from(mock_psst_constructor().output(output));
+        let signer = || PSST::<Signer>::from(unsigned.0[0].clone());
+
+        // a single signature is not sufficient for finalization
+        let signed_0 = Bundle::from(sign_psst(signer(), &kps[0]));
+        assert!(matches!(signed_0.finalize(), Err(Error::Finalize(_))));
+        assert!(signed_0.inspect(NetworkId::new(NetworkType::Mainnet), |sompi, _| sompi.to_string()).contains("missing signatures"));
+
+        // combine signatures supplied by both cosigners
+        let signed_1 = Bundle::from(sign_psst(signer(), &kps[1]));
+        let combined = unsigned.combine(&signed_0).and_then(|bundle| bundle.combine(&signed_1)).unwrap();
+        assert_eq!(combined.0[0].inputs[0].partial_sigs.len(), 2);
+        assert!(combined.inspect(NetworkId::new(NetworkType::Mainnet), |sompi, _| sompi.to_string()).contains("ready to finalize"));
+
+        // finalize and extract
+        let finalized = combined.finalize().unwrap();
+        assert!(finalized.0[0].inputs[0].final_script_sig.is_some());
+        let extracted = finalized.extract(&Params::from(NetworkId::new(NetworkType::Mainnet))).unwrap();
+        assert_eq!(extracted.len(), 1);
+        assert_eq!(extracted[0].fee, 10000);
+        assert_eq!(extracted[0].transaction.mass(), extracted[0].mass);
+
+        // transactions not present in the bundle can not be combined
+        assert!(matches!(Bundle::new().combine(&signed_0), Err(Error::TransactionNotFound(_))));
+    }
+
     #[test]
     fn test_pssb_bundle_creation() {
         let bundle = Bundle::new();
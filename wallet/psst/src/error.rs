@@ -42,6 +42,26 @@ pub enum Error {
     PssbPrefixError,
     #[error("PSST serialization requires 'PSST' prefix")]
     PsstPrefixError,
+    #[error(transparent)]
+    Bip32(#[from] calico_bip32::Error),
+    #[error(transparent)]
+    Secp256k1(#[from] secp256k1::Error),
+    #[error("External signer error ({code}): {message}")]
+    ExternalSigner { code: i32, message: String },
+    #[error("Input {index} requires {required} signature(s), {available} available")]
+    InsufficientSignatures { index: usize, required: usize, available: usize },
+    #[error("Input {0} spends a non-standard script")]
+    NonStandardInput(usize),
+    #[error(transparent)]
+    ScriptBuilder(#[from] calico_txscript::script_builder::ScriptBuilderError),
+    #[error(transparent)]
+    Combine(#[from] crate::psst::CombineError),
+    #[error("Finalize error: {0}")]
+    Finalize(String),
+    #[error(transparent)]
+    Extract(#[from] crate::psst::ExtractError),
+    #[error("Transaction {0} is not present in the bundle")]
+    TransactionNotFound(calico_consensus_core::tx::TransactionId),
 }
 #[derive(thiserror::Error, Debug)]
 pub enum ConstructorError {
@@ -0,0 +1,408 @@
+//!
+//! External signer protocol.
+//!
+//! The external signer protocol allows a wallet to delegate transaction
+//! signing to a separate process or device (for example an air-gapped
+//! machine or a hardware signer) that holds the private keys. The wallet
+//! exports unsigned transactions as a PSST [`Bundle`](crate::bundle::Bundle)
+//! in which each input carries the public key and derivation path
+//! (`bip32Derivations`) required for signing. The signer returns the same
+//! bundle with `partialSigs` populated.
+//!
+//! The protocol is transported as newline-delimited JSON over stdio
+//! (the wallet spawns the signer process) or over a stream socket (TCP or
+//! UNIX domain socket). Each request and each response occupies a single
+//! line. Requests are processed sequentially and each response carries
+//! the `id` of the request it corresponds to.
+//!
+//! Requests:
+//!
+//! ```text
+//! {"id":1,"method":"info"}
+//! {"id":2,"method":"sign","params":{"networkId":"testnet-11","bundle":"PSSB..."}}
+//! ```
+//!
+//! Responses:
+//!
+//! ```text
+//! {"id":1,"result":{"version":1,"fingerprints":["a1b2c3d4"]}}
+//! {"id":2,"result":{"bundle":"PSSB..."}}
+//! {"id":2,"error":{"code":2,"message":"no input can be signed with key a1b2c3d4"}}
+//! ```
+//!
+//! The `info` method returns the protocol version supported by the signer
+//! and hex-encoded fingerprints of the extended keys it holds. The `sign`
+//! method receives a serialized bundle and returns a serialized bundle
+//! containing signatures for every input the signer holds keys for, leaving
+//! the other inputs unsigned, and fails if it holds keys for none of them.
+//! A signer must not modify the transactions contained in the bundle.
+//!
+
+use crate::bundle::Bundle;
+use crate::error::Error;
+use crate::psst::{KeySource, SignInputOk, Signature, Signer, PSST};
+use calico_bip32::{secp256k1, ExtendedPrivateKey, KeyFingerprint, SecretKey};
+use calico_consensus_core::hashing::sighash::{calc_ecdsa_signature_hash, calc_schnorr_signature_hash, SigHashReusedValuesUnsync};
+use calico_consensus_core::tx::VerifiableTransaction;
+use calico_txscript::script_class::ScriptClass;
+use serde::{Deserialize, Serialize};
+
+/// External signer protocol version.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Error code: the request could not be parsed.
+pub const ERROR_INVALID_REQUEST: i32 = 1;
+/// Error code: the signer was unable to sign the supplied bundle.
+pub const ERROR_SIGNING_FAILED: i32 = 2;
+/// Error code: the request has been rejected by the signer operator.
+pub const ERROR_REJECTED: i32 = 3;
+
+/// External signer request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+    pub id: u64,
+    #[serde(flatten)]
+    pub method: Method,
+}
+
+/// External signer request methods.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "method", content = "params", rename_all = "camelCase")]
+pub enum Method {
+    /// Obtain signer information.
+    Info,
+    /// Sign a serialized PSST bundle.
+    Sign(SignRequest),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignRequest {
+    pub network_id: String,
+    pub bundle: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InfoResponse {
+    pub version: u32,
+    pub fingerprints: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignResponse {
+    pub bundle: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ResponseResult {
+    Info(InfoResponse),
+    Sign(SignResponse),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseError {
+    pub code: i32,
+    pub message: String,
+}
+
+/// External signer response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub id: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<ResponseResult>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ResponseError>,
+}
+
+impl Request {
+    pub fn info(id: u64) -> Self {
+        Self { id, method: Method::Info }
+    }
+
+    pub fn sign(id: u64, network_id: String, bundle: String) -> Self {
+        Self { id, method: Method::Sign(SignRequest { network_id, bundle }) }
+    }
+
+    /// Serialize the request into a single protocol line (including the trailing newline).
+    pub fn to_line(&self) -> Result<String, Error> {
+        Ok(format!("{}\n", serde_json::to_string(self)?))
+    }
+
+    pub fn from_line(line: &str) -> Result<Self, Error> {
+        Ok(serde_json::from_str(line.trim())?)
+    }
+}
+
+impl Response {
+    pub fn ok(id: u64, result: ResponseResult) -> Self {
+        Self { id, result: Some(result), error: None }
+    }
+
+    pub fn error(id: u64, code: i32, message: impl Into<String>) -> Self {
+        Self { id, result: None, error: Some(ResponseError { code, message: message.into() }) }
+    }
+
+    /// Serialize the response into a single protocol line (including the trailing newline).
+    pub fn to_line(&self) -> Result<String, Error> {
+        Ok(format!("{}\n", serde_json::to_string(self)?))
+    }
+
+    pub fn from_line(line: &str) -> Result<Self, Error> {
+        Ok(serde_json::from_str(line.trim())?)
+    }
+
+    /// Convert the response into its result, mapping protocol errors to [`Error::ExternalSigner`].
+    pub fn into_result(self) -> Result<ResponseResult, Error> {
+        match (self.result, self.error) {
+            (_, Some(ResponseError { code, message })) => Err(Error::ExternalSigner { code, message }),
+            (Some(result), None) => Ok(result),
+            (None, None) => Err(Error::ExternalSigner { code: ERROR_INVALID_REQUEST, message: "empty response".to_string() }),
+        }
+    }
+}
+
+/// Reference implementation of the signing side of the external signer
+/// protocol, backed by an extended private key. Inputs are signed using
+/// keys derived from the extended private key according to the input
+/// `bip32Derivations` entries matching the key fingerprint.
+pub struct KeySigner {
+    xprv: ExtendedPrivateKey<SecretKey>,
+    fingerprint: KeyFingerprint,
+}
+
+impl KeySigner {
+    pub fn new(xprv: ExtendedPrivateKey<SecretKey>) -> Self {
+        let fingerprint = xprv.public_key().fingerprint();
+        Self { xprv, fingerprint }
+    }
+
+    pub fn fingerprint(&self) -> KeyFingerprint {
+        self.fingerprint
+    }
+
+    /// Process a single protocol request.
+    pub fn handle(&self, request: Request) -> Response {
+        let Request { id, method } = request;
+        match method {
+            Method::Info => Response::ok(
+                id,
+                ResponseResult::Info(InfoResponse { version: PROTOCOL_VERSION, fingerprints: vec![hex::encode(self.fingerprint)] }),
+            ),
+            Method::Sign(SignRequest { bundle, .. }) => {
+                match Bundle::deserialize(&bundle).and_then(|bundle| self.sign_bundle(&bundle)).and_then(|bundle| bundle.serialize()) {
+                    Ok(bundle) => Response::ok(id, ResponseResult::Sign(SignResponse { bundle })),
+                    Err(err) => Response::error(id, ERROR_SIGNING_FAILED, err.to_string()),
+                }
+            }
+        }
+    }
+
+    /// Sign the inputs of all transactions contained in the supplied `bundle` which carry a
+    /// `bip32Derivations` entry matching the key fingerprint. Other inputs are left unsigned
+    /// for other signers, failing only if no input at all could be signed.
+    pub fn sign_bundle(&self, bundle: &Bundle) -> Result<Bundle, Error> {
+        let mut signed = Bundle::new();
+        let mut signed_inputs = 0;
+        for inner in bundle.iter() {
+            let keys = inner
+                .inputs
+                .iter()
+                .enumerate()
+                .map(|(index, input)| {
+                    let Some((pub_key, key_source)) = input.bip32_derivations.iter().find_map(|(pub_key, key_source)| {
+                        key_source
+                            .as_ref()
+                            .filter(|key_source| key_source.key_fingerprint == self.fingerprint)
+                            .map(|key_source| (*pub_key, key_source.clone()))
+                    }) else {
+                        return Ok(None);
+                    };
+
+                    let private_key = *self.xprv.clone().derive_path(&key_source.derivation_path)?.private_key();
+                    if private_key.public_key(secp256k1::SECP256K1) != pub_key {
+                        return Err(Error::Custom(format!("public key mismatch for input {index}")));
+                    }
+
+                    let utxo_entry = input.utxo_entry.as_ref().ok_or(Error::MissingUtxoEntry)?;
+                    let ecdsa = ScriptClass::from_script(&utxo_entry.script_public_key) == ScriptClass::PubKeyECDSA;
+                    Ok(Some((private_key, pub_key, key_source, ecdsa)))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            signed_inputs += keys.iter().flatten().count();
+
+            let psst = PSST::<Signer>::from(inner.clone()).pass_partial_signature_sync(
+                |tx, sighashes| -> Result<Vec<Option<SignInputOk>>, Error> {
+                    let reused_values = SigHashReusedValuesUnsync::new();
+                    keys.into_iter()
+                        .enumerate()
+                        .map(|(index, key)| {
+                            let Some((private_key, pub_key, key_source, ecdsa)) = key else {
+                                return Ok(None);
+                            };
+                            let signature = if ecdsa {
+                                let hash = calc_ecdsa_signature_hash(&tx.as_verifiable(), index, sighashes[index], &reused_values);
+                                let message = secp256k1::Message::from_digest_slice(hash.as_bytes().as_slice())?;
+                                Signature::ECDSA(secp256k1::SECP256K1.sign_ecdsa(&message, &private_key))
+                            } else {
+                                let hash = calc_schnorr_signature_hash(&tx.as_verifiable(), index, sighashes[index], &reused_values);
+                                let message = secp256k1::Message::from_digest_slice(hash.as_bytes().as_slice())?;
+                                let keypair = secp256k1::Keypair::from_secret_key(secp256k1::SECP256K1, &private_key);
+                                Signature::Schnorr(keypair.sign_schnorr(message))
+                            };
+                            Ok(Some(SignInputOk { signature, pub_key, key_source: Some(key_source) }))
+                        })
+                        .collect()
+                },
+            )?;
+
+            signed.add_psst(psst);
+        }
+
+        if signed_inputs == 0 {
+            return Err(Error::Custom(format!("no input can be signed with key {}", hex::encode(self.fingerprint))));
+        }
+        Ok(signed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use calico_addresses::{Address, Prefix, Version};
+    use calico_bip32::DerivationPath;
+    use calico_consensus_core::config::params::Params;
+    use calico_consensus_core::network::{NetworkId, NetworkType};
+    use calico_consensus_core::tx::{TransactionId, TransactionOutpoint, UtxoEntry};
+    use calico_txscript::pay_to_address_script;
+    use std::str::FromStr;
+
+    const AMOUNT: u64 = 12793000000000;
+    const FEE: u64 = 10000;
+
+    /// Builds a bundle holding a transaction spending one input per key, each annotated with its derivation
+    fn mock_unsigned_bundle(keys: &[(&ExtendedPrivateKey<SecretKey>, &str)]) -> Bundle {
+        let mut constructor = PSST::<Creator>::default().constructor();
+        let mut derivations = vec![];
+        for (index, (xprv, path)) in keys.iter().enumerate() {
+            let derivation_path = DerivationPath::from_str(path).unwrap();
+            let private_key = *(*xprv).clone().derive_path(&derivation_path).unwrap().private_key();
+            let pub_key = private_key.public_key(secp256k1::SECP256K1);
+            let address = Address::new(Prefix::Testnet, Version::PubKey, &pub_key.x_only_public_key().0.serialize());
+
+            let input = InputBuilder::default()
+                .utxo_entry(UtxoEntry {
+                    amount: AMOUNT,
+                    script_public_key: pay_to_address_script(&address),
+                    block_daa_score: 36151168,
+                    is_coinbase: false,
+                })
+                .previous_outpoint(TransactionOutpoint {
+                    transaction_id: TransactionId::from_str("63020db736215f8b1105a9281f7bcbb6473d965ecc45bb2fb5da59bd35e6ff84")
+                        .unwrap(),
+                    index: index as u32,
+                })
+                .sig_op_count(1)
+                .build()
+                .unwrap();
+            constructor = constructor.input(input);
+            derivations.push((pub_key, KeySource::new(xprv.public_key().fingerprint(), derivation_path), address));
+        }
+        let amount = AMOUNT * keys.len() as u64 - FEE;
+        let output =
+            OutputBuilder::default().amount(amount).script_public_key(pay_to_address_script(&derivations[0].2)).build().unwrap();
+
+        let mut updater = constructor.output(output).updater();
+        for (index, (pub_key, key_source, _)) in derivations.into_iter().enumerate() {
+            updater = updater.set_bip32_derivation(pub_key, key_source, index).unwrap();
+        }
+        Bundle::from(updater)
+    }
+
+    #[test]
+    fn test_external_signer_sign_finalize_extract() {
+        let xprv = ExtendedPrivateKey::<SecretKey>::new([7u8; 64]).unwrap();
+        let signer = KeySigner::new(xprv.clone());
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 11);
+
+        // the signer reports the fingerprint of the key it holds
+        let info = Response::from_line(&signer.handle(Request::info(1)).to_line().unwrap()).unwrap().into_result().unwrap();
+        assert!(
+            matches!(info, ResponseResult::Info(InfoResponse { version: PROTOCOL_VERSION, fingerprints }) if fingerprints == vec![hex::encode(signer.fingerprint())])
+        );
+
+        // sign the bundle over the protocol, the way the wallet transports it
+        let unsigned = mock_unsigned_bundle(&[(&xprv, "m/44'/111111'/0'/0/3")]);
+        let request =
+            Request::from_line(&Request::sign(2, network_id.to_string(), unsigned.serialize().unwrap()).to_line().unwrap()).unwrap();
+        let response = Response::from_line(&signer.handle(request).to_line().unwrap()).unwrap();
+        assert_eq!(response.id, 2);
+        let ResponseResult::Sign(SignResponse { bundle }) = response.into_result().unwrap() else { panic!("unexpected response") };
+        let signed = Bundle::deserialize(&bundle).unwrap();
+
+        // the signer must not modify the transaction
+        assert_eq!(
+            PSST::<Signer>::from(signed.0[0].clone()).calculate_id(),
+            PSST::<Signer>::from(unsigned.0[0].clone()).calculate_id()
+        );
+        assert_eq!(signed.0[0].inputs[0].partial_sigs.len(), 1);
+
+        // finalize and extract, verifying the signature script
+        let extracted = signed.finalize().unwrap().extract(&Params::from(network_id)).unwrap();
+        assert_eq!(extracted.len(), 1);
+        assert_eq!(extracted[0].fee, FEE);
+        assert_eq!(extracted[0].transaction.outputs[0].value, AMOUNT - FEE);
+    }
+
+    #[test]
+    fn test_external_signer_foreign_key() {
+        let xprv = ExtendedPrivateKey::<SecretKey>::new([7u8; 64]).unwrap();
+        let foreign = KeySigner::new(ExtendedPrivateKey::<SecretKey>::new([8u8; 64]).unwrap());
+        let unsigned = mock_unsigned_bundle(&[(&xprv, "m/44'/111111'/0'/0/0")]);
+
+        let response = foreign.handle(Request::sign(3, "testnet-11".to_string(), unsigned.serialize().unwrap()));
+        assert!(matches!(response.into_result(), Err(Error::ExternalSigner { code: ERROR_SIGNING_FAILED, .. })));
+    }
+
+    #[test]
+    fn test_external_signer_partial_signing() {
+        let xprv = ExtendedPrivateKey::<SecretKey>::new([7u8; 64]).unwrap();
+        let other = ExtendedPrivateKey::<SecretKey>::new([8u8; 64]).unwrap();
+        let unsigned = mock_unsigned_bundle(&[(&other, "m/44'/111111'/0'/0/0"), (&xprv, "m/44'/111111'/0'/0/1")]);
+
+        // inputs of another key are left unsigned for their own signer
+        let signed = KeySigner::new(xprv).sign_bundle(&unsigned).unwrap();
+        assert!(signed.0[0].inputs[0].partial_sigs.is_empty());
+        assert_eq!(signed.0[0].inputs[1].partial_sigs.len(), 1);
+
+        // the other signer completes the transaction
+        let signed = KeySigner::new(other).sign_bundle(&signed).unwrap();
+        assert!(signed.0[0].inputs.iter().all(|input| input.partial_sigs.len() == 1));
+        let extracted = signed.finalize().unwrap().extract(&Params::from(NetworkId::with_suffix(NetworkType::Testnet, 11))).unwrap();
+        assert_eq!(extracted[0].transaction.inputs.len(), 2);
+    }
+
+    #[test]
+    fn test_external_signer_protocol_roundtrip() {
+        let line = Request::sign(7, "testnet-11".to_string(), "PSSB00".to_string()).to_line().unwrap();
+        assert_eq!(line, "{\"id\":7,\"method\":\"sign\",\"params\":{\"networkId\":\"testnet-11\",\"bundle\":\"PSSB00\"}}\n");
+        let request = Request::from_line(&line).unwrap();
+        assert!(matches!(request.method, Method::Sign(SignRequest { bundle, .. }) if bundle == "PSSB00"));
+
+        let request = Request::from_line("{\"id\":1,\"method\":\"info\"}").unwrap();
+        assert!(matches!(request.method, Method::Info));
+
+        let response = Response::from_line("{\"id\":1,\"result\":{\"version\":1,\"fingerprints\":[\"a1b2c3d4\"]}}").unwrap();
+        assert!(matches!(response.into_result().unwrap(), ResponseResult::Info(InfoResponse { version: 1, .. })));
+
+        let response = Response::from_line(&Response::error(2, ERROR_REJECTED, "rejected").to_line().unwrap()).unwrap();
+        assert!(matches!(response.into_result(), Err(Error::ExternalSigner { code: ERROR_REJECTED, .. })));
+    }
+}
@@ -7,11 +7,13 @@
 
 pub mod bundle;
 pub mod error;
+pub mod external;
 pub mod global;
 pub mod input;
 pub mod output;
 pub mod psst;
 pub mod role;
+pub mod standard;
 pub mod wasm;
 
 mod convert;
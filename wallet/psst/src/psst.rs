@@ -13,6 +13,9 @@ pub use crate::global::{Global, GlobalBuilder};
 pub use crate::input::{Input, InputBuilder};
 pub use crate::output::{Output, OutputBuilder};
 pub use crate::role::{Combiner, Constructor, Creator, Extractor, Finalizer, Signer, Updater};
+use calico_consensus_core::config::params::Params;
+use calico_consensus_core::constants::{MAXIMUM_STANDARD_TRANSACTION_MASS, MINIMUM_RELAY_TRANSACTION_FEE};
+use calico_consensus_core::mass::{Kip9Version, MassCalculator};
 use calico_consensus_core::tx::UtxoEntry;
 use calico_consensus_core::{
     hashing::sighash_type::SigHashType,
@@ -160,7 +163,7 @@ impl<R> PSST<R> {
         self.unsigned_tx().tx.id()
     }
 
-    fn determine_lock_time(&self) -> u64 {
+    pub(crate) fn determine_lock_time(&self) -> u64 {
         self.inputs.iter().map(|input: &Input| input.min_time).max().unwrap_or(self.global.fallback_lock_time).unwrap_or(0)
     }
 
@@ -257,6 +260,16 @@ impl PSST<Updater> {
         Ok(self)
     }
 
+    pub fn set_bip32_derivation(
+        mut self,
+        pub_key: secp256k1::PublicKey,
+        key_source: KeySource,
+        input_index: usize,
+    ) -> Result<Self, Error> {
+        self.inner_psst.inputs.get_mut(input_index).ok_or(Error::OutOfBounds)?.bip32_derivations.insert(pub_key, Some(key_source));
+        Ok(self)
+    }
+
     pub fn signer(self) -> PSST<Signer> {
         PSST { inner_psst: self.inner_psst, role: Default::default() }
     }
@@ -284,6 +297,24 @@ impl PSST<Signer> {
 
         Ok(self)
     }
+
+    /// Same as [`Self::pass_signature_sync`], leaving the inputs for which `sign_fn` returns `None` unsigned
+    pub fn pass_partial_signature_sync<SignFn, E>(mut self, sign_fn: SignFn) -> Result<Self, E>
+    where
+        E: Display,
+        SignFn: FnOnce(SignableTransaction, Vec<SigHashType>) -> Result<Vec<Option<SignInputOk>>, E>,
+    {
+        let unsigned_tx = self.unsigned_tx();
+        let sighashes = self.inputs.iter().map(|input| input.sighash_type).collect();
+        self.inner_psst.inputs.iter_mut().zip(sign_fn(unsigned_tx, sighashes)?).for_each(|(input, signed)| {
+            if let Some(SignInputOk { signature, pub_key, key_source }) = signed {
+                input.bip32_derivations.insert(pub_key, key_source);
+                input.partial_sigs.insert(pub_key, signature);
+            }
+        });
+        Ok(self)
+    }
+
     // todo use iterator instead of vector
     pub async fn pass_signature<SignFn, Fut, E>(mut self, sign_fn: SignFn) -> Result<Self, E>
     where
@@ -380,6 +411,12 @@ impl PSST<Finalizer> {
         self.finalize_internal(sigs)
     }
 
+    /// Finalize all inputs spending standard script types
+    /// (see [`standard`](crate::standard) for details).
+    pub fn finalize_standard(self) -> Result<Self, FinalizeError<Error>> {
+        self.finalize_sync(|inner: &Inner| crate::standard::finalize_inputs(&inner.inputs))
+    }
+
     pub fn id(&self) -> Option<TransactionId> {
         self.global.id
     }
@@ -448,6 +485,42 @@ impl PSST<Extractor> {
         };
         Ok(closure)
     }
+
+    /// Extract the transaction, verifying the signature scripts and checking
+    /// that the transaction mass is within the standard limit and that the
+    /// transaction pays at least the minimum relay fee.
+    pub fn extract_tx_checked(self, params: &Params) -> Result<ExtractedTransaction, ExtractError> {
+        if self.inputs.iter().any(|input| input.utxo_entry.is_none()) {
+            return Err(ExtractError::MissingUtxoEntry);
+        }
+
+        let (tx, entries) = self.extract_tx()?(0);
+        let tx = MutableTransaction::with_entries(tx, entries.into_iter().flatten().collect());
+
+        // KIP-9 storage mass is summed with compute mass on networks where it is activated (see virtual processor)
+        let kip9_version = if params.storage_mass_activation_daa_score == u64::MAX { Kip9Version::Beta } else { Kip9Version::Alpha };
+        let calculator = MassCalculator::new_with_consensus_params(params);
+        let compute_mass = calculator.calc_tx_compute_mass(&tx.tx);
+        let mass = calculator
+            .calc_tx_overall_mass(&tx.as_verifiable(), Some(compute_mass), kip9_version)
+            .ok_or(ExtractError::StorageMassIncomputable)?;
+        if mass > MAXIMUM_STANDARD_TRANSACTION_MASS {
+            return Err(ExtractError::MassExceeded { mass, maximum: MAXIMUM_STANDARD_TRANSACTION_MASS });
+        }
+
+        let inputs = tx.entries.iter().flatten().map(|entry| entry.amount).sum::<u64>();
+        let outputs = tx.tx.outputs.iter().map(|output| output.value).sum::<u64>();
+        let fee = inputs.checked_sub(outputs).ok_or(ExtractError::OutputsExceedInputs { inputs, outputs })?;
+        let minimum = mass * MINIMUM_RELAY_TRANSACTION_FEE / 1000;
+        if fee < minimum {
+            return Err(ExtractError::InsufficientFee { fee, minimum });
+        }
+
+        let entries = tx.entries.into_iter().flatten().collect();
+        let transaction = tx.tx;
+        transaction.set_mass(mass);
+        Ok(ExtractedTransaction { transaction, entries, mass, fee })
+    }
 }
 
 /// Error combining psst.
@@ -477,6 +550,25 @@ pub enum ExtractError {
     TxScriptError(#[from] calico_txscript_errors::TxScriptError),
     #[error(transparent)]
     TxNotFinalized(#[from] TxNotFinalized),
+    #[error("Missing UTXO entry")]
+    MissingUtxoEntry,
+    #[error("Unable to compute transaction storage mass")]
+    StorageMassIncomputable,
+    #[error("Transaction mass {mass} exceeds the standard maximum of {maximum}")]
+    MassExceeded { mass: u64, maximum: u64 },
+    #[error("Transaction outputs ({outputs}) exceed inputs ({inputs})")]
+    OutputsExceedInputs { inputs: u64, outputs: u64 },
+    #[error("Transaction fee {fee} is below the minimum relay fee {minimum}")]
+    InsufficientFee { fee: u64, minimum: u64 },
+}
+
+/// Transaction extracted from a finalized PSST.
+#[derive(Debug, Clone)]
+pub struct ExtractedTransaction {
+    pub transaction: Transaction,
+    pub entries: Vec<UtxoEntry>,
+    pub mass: u64,
+    pub fee: u64,
 }
 
 #[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
@@ -0,0 +1,187 @@
+//!
+//! Finalization of PSST inputs spending standard script types.
+//!
+//! The following script types are supported:
+//! - pay-to-pubkey (Schnorr) and pay-to-pubkey-ECDSA inputs, finalized using
+//!   the partial signature of the key contained in the script public key;
+//! - pay-to-script-hash multisig inputs (`OpCheckMultiSig` and `OpCheckMultiSigECDSA`
+//!   redeem scripts), finalized using the required number of partial signatures
+//!   ordered according to the public keys in the redeem script;
+//! - other pay-to-script-hash inputs (including time-locked scripts), finalized
+//!   using all available partial signatures (if any) followed by the redeem script.
+//!
+//! HTLC inputs are not finalized automatically since the spending path (claim
+//! or refund) can not be determined from the PSST. Such inputs are expected to
+//! be finalized by the party spending them, prior to the standard finalization.
+//!
+
+use crate::error::Error;
+use crate::input::Input;
+use calico_bip32::secp256k1;
+use calico_txscript::opcodes::codes::{Op16, OpCheckMultiSig, OpCheckMultiSigECDSA, OpData32, OpData33, OpTrue};
+use calico_txscript::script_builder::ScriptBuilder;
+use calico_txscript::script_class::ScriptClass;
+use calico_txscript::{HtlcScript, TimeLockScript};
+
+/// Multisig redeem script (`<required> <pubkey>... <count> OpCheckMultiSig[ECDSA]`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisigScript {
+    pub required: usize,
+    pub pub_keys: Vec<Vec<u8>>,
+    pub ecdsa: bool,
+}
+
+impl MultisigScript {
+    /// Parse a multisig redeem script. Returns `None` if the script is not a
+    /// standard multisig script or uses more than 16 keys.
+    pub fn parse(script: &[u8]) -> Option<Self> {
+        let small_int = |op: u8| (OpTrue..=Op16).contains(&op).then(|| (op - OpTrue + 1) as usize);
+
+        let (&last, script) = script.split_last()?;
+        let ecdsa = match last {
+            OpCheckMultiSig => false,
+            OpCheckMultiSigECDSA => true,
+            _ => return None,
+        };
+        let (&count, script) = script.split_last()?;
+        let count = small_int(count)?;
+        let (&required, mut script) = script.split_first()?;
+        let required = small_int(required)?;
+
+        let key_len = if ecdsa { 33 } else { 32 };
+        let push_op = if ecdsa { OpData33 } else { OpData32 };
+
+        let mut pub_keys = Vec::with_capacity(count);
+        while let Some((&op, rest)) = script.split_first() {
+            if op != push_op || rest.len() < key_len {
+                return None;
+            }
+            pub_keys.push(rest[..key_len].to_vec());
+            script = &rest[key_len..];
+        }
+
+        (pub_keys.len() == count && required <= count).then_some(Self { required, pub_keys, ecdsa })
+    }
+}
+
+/// Serialize the public key in the format used by the script type (x-only for Schnorr).
+fn script_key(pub_key: &secp256k1::PublicKey, ecdsa: bool) -> Vec<u8> {
+    if ecdsa {
+        pub_key.serialize().to_vec()
+    } else {
+        pub_key.x_only_public_key().0.serialize().to_vec()
+    }
+}
+
+/// Signature data pushed by the signature script: signature bytes followed by the sighash type.
+fn signature_data(input: &Input, pub_key: &[u8], ecdsa: bool) -> Option<Vec<u8>> {
+    input.partial_sigs.iter().find(|(key, _)| script_key(key, ecdsa) == pub_key).map(|(_, signature)| {
+        let mut data = signature.into_bytes().to_vec();
+        data.push(input.sighash_type.to_u8());
+        data
+    })
+}
+
+/// Number of signatures required to finalize the input, if it can be determined.
+pub fn required_signatures(input: &Input) -> Option<usize> {
+    let utxo_entry = input.utxo_entry.as_ref()?;
+    match ScriptClass::from_script(&utxo_entry.script_public_key) {
+        ScriptClass::PubKey | ScriptClass::PubKeyECDSA => Some(1),
+        ScriptClass::ScriptHash => {
+            let redeem_script = input.redeem_script.as_deref()?;
+            if let Some(multisig) = MultisigScript::parse(redeem_script) {
+                Some(multisig.required)
+            } else if HtlcScript::parse(redeem_script).is_some() || TimeLockScript::parse(redeem_script).is_some() {
+                Some(1)
+            } else {
+                None
+            }
+        }
+        ScriptClass::NonStandard => None,
+    }
+}
+
+/// Create the final signature script for the input at `index`.
+/// Inputs that have already been finalized retain their signature script.
+pub fn finalize_input(index: usize, input: &Input) -> Result<Vec<u8>, Error> {
+    if let Some(final_script_sig) = input.final_script_sig.as_ref() {
+        return Ok(final_script_sig.clone());
+    }
+
+    let utxo_entry = input.utxo_entry.as_ref().ok_or(Error::MissingUtxoEntry)?;
+    let script = utxo_entry.script_public_key.script();
+    let mut builder = ScriptBuilder::new();
+    let missing_signature = || Error::InsufficientSignatures { index, required: 1, available: 0 };
+
+    match ScriptClass::from_script(&utxo_entry.script_public_key) {
+        ScriptClass::PubKey => {
+            let signature = signature_data(input, &script[1..33], false).ok_or_else(missing_signature)?;
+            builder.add_data(&signature)?;
+        }
+        ScriptClass::PubKeyECDSA => {
+            let signature = signature_data(input, &script[1..34], true).ok_or_else(missing_signature)?;
+            builder.add_data(&signature)?;
+        }
+        ScriptClass::ScriptHash => {
+            let redeem_script = input.redeem_script.as_ref().ok_or(Error::MissingRedeemScript)?;
+            if HtlcScript::parse(redeem_script).is_some() {
+                // the spending path (claim or refund) can not be determined from the PSST
+                return Err(Error::Finalize(format!("HTLC input {index} requires a claim or refund signature script")));
+            }
+            match MultisigScript::parse(redeem_script) {
+                Some(MultisigScript { required, pub_keys, ecdsa }) => {
+                    let signatures =
+                        pub_keys.iter().filter_map(|pub_key| signature_data(input, pub_key, ecdsa)).take(required).collect::<Vec<_>>();
+                    if signatures.len() < required {
+                        return Err(Error::InsufficientSignatures { index, required, available: signatures.len() });
+                    }
+                    for signature in signatures {
+                        builder.add_data(&signature)?;
+                    }
+                }
+                None => {
+                    for signature in input.partial_sigs.values() {
+                        let mut data = signature.into_bytes().to_vec();
+                        data.push(input.sighash_type.to_u8());
+                        builder.add_data(&data)?;
+                    }
+                }
+            }
+            builder.add_data(redeem_script)?;
+        }
+        ScriptClass::NonStandard => return Err(Error::NonStandardInput(index)),
+    }
+
+    Ok(builder.drain())
+}
+
+/// Create final signature scripts for all inputs.
+pub fn finalize_inputs(inputs: &[Input]) -> Result<Vec<Vec<u8>>, Error> {
+    inputs.iter().enumerate().map(|(index, input)| finalize_input(index, input)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calico_txscript::{multisig_redeem_script, multisig_redeem_script_ecdsa};
+
+    #[test]
+    fn test_parse_multisig_redeem_script() {
+        let keys = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        let script = multisig_redeem_script(keys.iter(), 2).unwrap();
+        let multisig = MultisigScript::parse(&script).unwrap();
+        assert_eq!(multisig.required, 2);
+        assert!(!multisig.ecdsa);
+        assert_eq!(multisig.pub_keys, keys.iter().map(|key| key.to_vec()).collect::<Vec<_>>());
+
+        let keys = [[4u8; 33], [5u8; 33]];
+        let script = multisig_redeem_script_ecdsa(keys.iter(), 1).unwrap();
+        let multisig = MultisigScript::parse(&script).unwrap();
+        assert_eq!(multisig.required, 1);
+        assert!(multisig.ecdsa);
+        assert_eq!(multisig.pub_keys.len(), 2);
+
+        assert!(MultisigScript::parse(&script[..script.len() - 2]).is_none());
+        assert!(MultisigScript::parse(&[]).is_none());
+    }
+}